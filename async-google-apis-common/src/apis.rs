@@ -0,0 +1,53 @@
+//! Sharing one client and one token source among the services of several APIs.
+
+use crate::*;
+
+/// A `TokenSource` shared by several services; cloning it is cheap.
+#[derive(Clone)]
+pub struct SharedTokenSource(Arc<dyn TokenSource + Send + Sync>);
+
+impl TokenSource for SharedTokenSource {
+    fn token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        self.0.token(scopes)
+    }
+}
+
+/// The configuration shared by the services of all APIs used by an application (e.g. Drive,
+/// Sheets and Gmail): one `HttpClient`, including any middleware such as retries or rate limits
+/// (see `TowerClient`), and one token source. Services are created from it with `service()`:
+///
+/// ```ignore
+/// let apis = GoogleApis::new(https_client, authenticator);
+/// let files = apis.service(drive::FilesService::new);
+/// let sheets = apis.service(sheets::SpreadsheetsService::new);
+/// ```
+#[derive(Clone)]
+pub struct GoogleApis<C = TlsClient> {
+    client: C,
+    auth: SharedTokenSource,
+}
+
+impl<C: HttpClient> GoogleApis<C> {
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> GoogleApis<C> {
+        GoogleApis {
+            client,
+            auth: SharedTokenSource(Arc::new(auth)),
+        }
+    }
+
+    /// Create a service using the shared client and token source. `new` is the service's
+    /// constructor, e.g. `drive::FilesService::new`.
+    pub fn service<S, F: FnOnce(C, SharedTokenSource) -> S>(&self, new: F) -> S {
+        new(self.client.clone(), self.auth.clone())
+    }
+
+    /// The shared client, e.g. for services of APIs that don't require authentication.
+    pub fn client(&self) -> C {
+        self.client.clone()
+    }
+
+    /// The shared token source.
+    pub fn token_source(&self) -> SharedTokenSource {
+        self.auth.clone()
+    }
+}
@@ -0,0 +1,145 @@
+//! Sources of OAuth access tokens used by generated services.
+
+use crate::*;
+
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by `TokenSource::token()`.
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>>;
+
+/// Provides access tokens for API requests. Services can use any implementation, e.g. a custom
+/// token cache, GCE metadata tokens, or service-account JWTs.
+///
+/// Implemented for all thread-safe smart pointers (`Box`, `Arc`, `&`) to a yup-oauth2
+/// `Authenticator`.
+pub trait TokenSource {
+    /// Return an access token valid for all of `scopes`.
+    fn token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a>;
+}
+
+impl<D: std::ops::Deref<Target = Authenticator> + Sync> TokenSource for D {
+    fn token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let tok = self.deref().token(scopes).await?;
+            Ok(tok.as_str().to_string())
+        })
+    }
+}
+
+/// A fixed access token, e.g. obtained out of band or for testing.
+#[derive(Clone)]
+pub struct StaticToken(pub String);
+
+impl std::fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StaticToken").field(&"redacted").finish()
+    }
+}
+
+impl TokenSource for StaticToken {
+    fn token<'a>(&'a self, _scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(futures::future::ready(Ok(self.0.clone())))
+    }
+}
+
+/// Counts token requests per service and scope. As every API call requests one token, this is
+/// the number of calls made with each scope, which helps verifying that an application really
+/// needs all of the scopes it asks for.
+///
+/// Cloned handles share their counters. Wrap the token sources of services with `track()`:
+/// `FilesService::new(client, usage.track("files", Arc::new(authenticator)))`.
+#[derive(Debug, Clone, Default)]
+pub struct TokenUsage {
+    counts: Arc<std::sync::Mutex<std::collections::BTreeMap<(String, String), u64>>>,
+}
+
+impl TokenUsage {
+    pub fn new() -> TokenUsage {
+        Default::default()
+    }
+
+    /// Wrap `source` so that the tokens it provides are counted for `service`.
+    pub fn track<T: TokenSource>(&self, service: &str, source: T) -> CountingTokenSource<T> {
+        CountingTokenSource {
+            inner: source,
+            service: service.to_string(),
+            usage: self.clone(),
+        }
+    }
+
+    /// Number of tokens requested so far, by (service, scope).
+    pub fn counts(&self) -> std::collections::BTreeMap<(String, String), u64> {
+        self.counts.lock().unwrap().clone()
+    }
+
+    /// Number of tokens requested so far for `scope`, by any service.
+    pub fn scope_count(&self, scope: &str) -> u64 {
+        self.counts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|((_, s), _)| s == scope)
+            .map(|(_, n)| n)
+            .sum()
+    }
+
+    /// Reset all counters to zero.
+    pub fn reset(&self) {
+        self.counts.lock().unwrap().clear();
+    }
+
+    fn record(&self, service: &str, scopes: &[&str]) {
+        let mut counts = self.counts.lock().unwrap();
+        for scope in scopes {
+            *counts
+                .entry((service.to_string(), scope.to_string()))
+                .or_insert(0) += 1;
+        }
+    }
+}
+
+/// A `TokenSource` counting the tokens it provides in a `TokenUsage`. Created by
+/// `TokenUsage::track()`.
+#[derive(Debug, Clone)]
+pub struct CountingTokenSource<T> {
+    inner: T,
+    service: String,
+    usage: TokenUsage,
+}
+
+impl<T: TokenSource + Sync> TokenSource for CountingTokenSource<T> {
+    fn token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        self.usage.record(&self.service, scopes);
+        self.inner.token(scopes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_usage() {
+        let usage = TokenUsage::new();
+        let files = usage.track("files", StaticToken("tok".into()));
+        let about = usage.track("about", StaticToken("tok".into()));
+        let file_scope = ["https://www.googleapis.com/auth/drive.file"];
+        let read_scope = ["https://www.googleapis.com/auth/drive.readonly"];
+
+        let tok = futures::executor::block_on(files.token(&file_scope)).unwrap();
+        assert_eq!(tok, "tok");
+        futures::executor::block_on(files.token(&file_scope)).unwrap();
+        futures::executor::block_on(about.token(&file_scope)).unwrap();
+        futures::executor::block_on(about.token(&read_scope)).unwrap();
+
+        let counts = usage.counts();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&("files".to_string(), file_scope[0].to_string())], 2);
+        assert_eq!(counts[&("about".to_string(), read_scope[0].to_string())], 1);
+        assert_eq!(usage.scope_count(file_scope[0]), 3);
+
+        usage.reset();
+        assert!(usage.counts().is_empty());
+    }
+}
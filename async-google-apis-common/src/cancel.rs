@@ -0,0 +1,49 @@
+//! Cancellation of long-running operations.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Signals long-running operations (chunked uploads and downloads, listings following page
+/// tokens) to stop. Operations check the token between chunks or pages, and fail with
+/// `ApiError::CancelledError` carrying the progress made so far.
+///
+/// Clones share their state: cancelling one clone cancels all of them.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        Default::default()
+    }
+
+    /// Request cancellation of all operations using this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns `ApiError::CancelledError(progress)` if cancellation was requested.
+    pub fn check(&self, progress: u64) -> crate::Result<()> {
+        if self.is_cancelled() {
+            Err(crate::ApiError::CancelledError(progress).into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Like `CancellationToken::check()`, for operations that may not have a token.
+pub(crate) fn check_cancelled(
+    token: &Option<CancellationToken>,
+    progress: u64,
+) -> crate::Result<()> {
+    match token {
+        Some(t) => t.check(progress),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,365 @@
+//! Recording HTTP interactions into a file ("cassette") and replaying them, so that tests of
+//! code using real APIs can run in CI without credentials or network access.
+//!
+//! Run the tests once with a `CassetteClient::recording()` wrapping a real client, save the
+//! cassette, and commit it; afterwards, use `CassetteClient::replaying()`.
+//! `CassetteClient::from_env()` switches between both depending on the environment variable
+//! `RECORD_CASSETTES`:
+//!
+//! ```ignore
+//! let client = CassetteClient::from_env(https_client(), "tests/cassettes/list.json").await?;
+//! let files = FilesService::new(client.clone(), token_source);
+//! // ... run the test ...
+//! client.finish("tests/cassettes/list.json").await?;
+//! ```
+//!
+//! Access tokens are never recorded: request headers are left out, credentials in the URL and in
+//! URL-valued response headers like `Location` are redacted (see `redact_url()`), as are
+//! credentials in JSON bodies (see `json_trace`). Responses longer than the client's
+//! `max_response_size()` can't be recorded, and fail.
+
+use crate::*;
+
+use anyhow::Context;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Environment variable selecting recording in `CassetteClient::from_env()`.
+pub const RECORD_CASSETTES_VAR: &str = "RECORD_CASSETTES";
+
+/// Response headers that are not recorded.
+const SKIPPED_HEADERS: &[&str] = &["set-cookie", "authorization", "date", "alt-svc"];
+/// Response headers containing URLs, which are recorded with credentials redacted (e.g. the
+/// `upload_id` of resumable upload sessions).
+const URL_HEADERS: &[&str] = &["location", "x-goog-upload-url"];
+
+/// A request and the response received for it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interaction {
+    pub method: String,
+    /// The request URI with credentials redacted.
+    pub uri: String,
+    /// The request body, for reference; it is not used for matching.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    /// The response body; base64-encoded if `body_base64` is set.
+    pub body: String,
+    #[serde(default)]
+    pub body_base64: bool,
+}
+
+impl Interaction {
+    fn response(&self) -> Result<hyper::Response<hyper::Body>> {
+        let body = if self.body_base64 {
+            radix64::STD.decode(&self.body).map_err(|e| {
+                ApiError::InputDataError(format!("Cassette: invalid base64 body: {:?}", e))
+            })?
+        } else {
+            self.body.clone().into_bytes()
+        };
+        let mut response = hyper::Response::builder().status(self.status);
+        for (name, value) in self.headers.iter() {
+            response = response.header(name.as_str(), value.as_str());
+        }
+        Ok(response.body(hyper::Body::from(body))?)
+    }
+}
+
+/// The recorded interactions of a test, in order.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub async fn load<P: AsRef<Path>>(path: P) -> Result<Cassette> {
+        let content = tokio::fs::read(path.as_ref())
+            .await
+            .with_context(|| format!("reading cassette {}", path.as_ref().display()))?;
+        Ok(serde_json::from_slice(&content)?)
+    }
+
+    /// Write the cassette as pretty-printed JSON, creating parent directories.
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        if let Some(dir) = path.as_ref().parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        tokio::fs::write(path.as_ref(), serde_json::to_vec_pretty(self)?).await?;
+        Ok(())
+    }
+}
+
+struct CassetteState {
+    cassette: Cassette,
+    /// Interactions already replayed.
+    used: Vec<bool>,
+}
+
+/// An `HttpClient` recording or replaying a `Cassette`; see the module documentation. Clones
+/// share the cassette.
+///
+/// When replaying, each request is answered with the first interaction not replayed yet that has
+/// the same method and URI. Requests without one fail.
+#[derive(Clone)]
+pub struct CassetteClient<C = TlsClient> {
+    /// The client requests are sent with while recording; `None` while replaying.
+    client: Option<C>,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+impl CassetteClient {
+    /// Answer requests from `cassette`, without network access.
+    pub fn replaying(cassette: Cassette) -> CassetteClient {
+        CassetteClient::with_state(None, cassette)
+    }
+}
+
+impl<C: HttpClient> CassetteClient<C> {
+    /// Send requests with `client`, and record them.
+    pub fn recording(client: C) -> CassetteClient<C> {
+        CassetteClient::with_state(Some(client), Cassette::default())
+    }
+
+    /// Record with `client` if the environment variable `RECORD_CASSETTES` is set, and
+    /// otherwise replay the cassette at `path`.
+    pub async fn from_env<P: AsRef<Path>>(client: C, path: P) -> Result<CassetteClient<C>> {
+        if std::env::var_os(RECORD_CASSETTES_VAR).is_some() {
+            Ok(CassetteClient::recording(client))
+        } else {
+            Ok(CassetteClient::with_state(
+                None,
+                Cassette::load(path).await?,
+            ))
+        }
+    }
+
+    fn with_state(client: Option<C>, cassette: Cassette) -> CassetteClient<C> {
+        let used = vec![false; cassette.interactions.len()];
+        CassetteClient {
+            client,
+            state: Arc::new(Mutex::new(CassetteState { cassette, used })),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// The interactions recorded (or being replayed).
+    pub fn cassette(&self) -> Cassette {
+        self.state.lock().unwrap().cassette.clone()
+    }
+
+    /// When recording, save the cassette to `path`. When replaying, check that all
+    /// interactions have been replayed, so that tests notice requests that are no longer sent.
+    pub async fn finish<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        if self.is_recording() {
+            return self.cassette().save(path).await;
+        }
+        let state = self.state.lock().unwrap();
+        let unused = state.used.iter().filter(|u| !**u).count();
+        if unused > 0 {
+            return Err(ApiError::InputDataError(format!(
+                "CassetteClient: {} of {} interactions not replayed",
+                unused,
+                state.used.len()
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    fn replay(&self, method: &str, uri: &str) -> Result<hyper::Response<hyper::Body>> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        let found = state
+            .cassette
+            .interactions
+            .iter()
+            .zip(state.used.iter_mut())
+            .find(|(i, used)| !**used && i.method == method && i.uri == uri);
+        match found {
+            Some((interaction, used)) => {
+                *used = true;
+                interaction.response()
+            }
+            None => Err(ApiError::InputDataError(format!(
+                "CassetteClient: no recorded response for {} {}",
+                method, uri
+            ))
+            .into()),
+        }
+    }
+}
+
+impl<C: HttpClient> HttpClient for CassetteClient<C> {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        let this = self.clone();
+        Box::pin(async move {
+            let method = request.method().to_string();
+            let uri = redact_url(&request.uri().to_string());
+            let client = match this.client.as_ref() {
+                Some(client) => client,
+                None => return this.replay(&method, &uri),
+            };
+
+            let max = client.max_response_size();
+            let (parts, body) = request.into_parts();
+            // Request bodies are only recorded for reference; long ones are left out.
+            let (request, request_body) = match read_body_within(body, max, 0).await? {
+                Ok(body) => (
+                    hyper::Request::from_parts(parts, hyper::Body::from(body.clone())),
+                    body,
+                ),
+                Err(body) => (hyper::Request::from_parts(parts, body), Default::default()),
+            };
+            let response = client.send(request).await?;
+            let (parts, body) = response.into_parts();
+            let body = match read_body_within(body, max, 0).await? {
+                Ok(body) => body,
+                Err(_) => return Err(ApiError::ResponseSizeLimitError(max).into()),
+            };
+
+            let (recorded_body, body_base64) = match std::str::from_utf8(&body) {
+                Ok(text) => (redact_body(text), false),
+                Err(_) => (radix64::STD.encode(&body), true),
+            };
+            let interaction = Interaction {
+                method,
+                uri,
+                request_body: std::str::from_utf8(&request_body)
+                    .ok()
+                    .filter(|b| !b.is_empty())
+                    .map(redact_body),
+                status: parts.status.as_u16(),
+                headers: parts
+                    .headers
+                    .iter()
+                    .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+                    .filter_map(|(name, value)| {
+                        let value = value.to_str().ok()?;
+                        let value = if URL_HEADERS.contains(&name.as_str()) {
+                            redact_url(value)
+                        } else {
+                            value.to_string()
+                        };
+                        Some((name.to_string(), value))
+                    })
+                    .collect(),
+                body: recorded_body,
+                body_base64,
+            };
+            {
+                let mut state = this.state.lock().unwrap();
+                state.cassette.interactions.push(interaction);
+                state.used.push(true);
+            }
+            Ok(hyper::Response::from_parts(parts, hyper::Body::from(body)))
+        })
+    }
+
+    fn max_url_length(&self) -> usize {
+        self.client
+            .as_ref()
+            .map_or(DEFAULT_MAX_URL_LENGTH, HttpClient::max_url_length)
+    }
+
+    fn max_response_size(&self) -> usize {
+        self.client
+            .as_ref()
+            .map_or(DEFAULT_MAX_RESPONSE_SIZE, HttpClient::max_response_size)
+    }
+}
+
+/// `body` with credentials redacted if it is JSON.
+fn redact_body(body: &str) -> String {
+    let value = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => value,
+        Err(_) => return body.to_string(),
+    };
+    let mut redacted = value.clone();
+    json_trace::redact_credentials(&mut redacted);
+    if redacted == value {
+        body.to_string()
+    } else {
+        redacted.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get<C: HttpClient>(client: &C, uri: &str) -> Result<(u16, String)> {
+        futures::executor::block_on(async {
+            let request = hyper::Request::get(uri).body(hyper::Body::empty())?;
+            let response = client.send(request).await?;
+            let status = response.status().as_u16();
+            let body = hyper::body::to_bytes(response.into_body()).await?;
+            Ok((status, String::from_utf8_lossy(&body).into_owned()))
+        })
+    }
+
+    #[test]
+    fn test_record_replay() {
+        let mock = MockClient::new();
+        mock.push(MockResponse::json(&serde_json::json!({"id": "a"})))
+            .push(MockResponse::json(
+                &serde_json::json!({"access_token": "ya29.secret"}),
+            ))
+            .push(MockResponse::bytes("image/png", vec![0xff, 0xfe]));
+        let recording = CassetteClient::recording(mock);
+        let uri = "https://example.com/files?key=secret&fields=id";
+        assert_eq!(get(&recording, uri).unwrap().0, 200);
+        get(&recording, "https://example.com/token").unwrap();
+        get(&recording, "https://example.com/image").unwrap();
+
+        let cassette = recording.cassette();
+        assert_eq!(
+            cassette.interactions[0].uri,
+            "https://example.com/files?key=redacted&fields=id"
+        );
+        assert!(!cassette.interactions[1].body.contains("ya29"));
+        assert!(cassette.interactions[2].body_base64);
+
+        let json = serde_json::to_string(&cassette).unwrap();
+        let replaying = CassetteClient::replaying(serde_json::from_str(&json).unwrap());
+        assert_eq!(
+            get(&replaying, "https://example.com/files?key=other&fields=id").unwrap(),
+            (200, "{\"id\":\"a\"}".to_string())
+        );
+        assert!(futures::executor::block_on(replaying.finish("unused")).is_err());
+        assert!(get(&replaying, "https://example.com/files?fields=id").is_err());
+    }
+
+    #[test]
+    fn test_record_limits() {
+        let mock = MockClient::new();
+        mock.push(MockResponse::status(200).with_header(
+            hyper::header::LOCATION,
+            "https://example.com/upload?uploadType=resumable&upload_id=secret",
+        ))
+        .push(MockResponse::bytes("text/plain", "x".repeat(100)));
+        let recording =
+            CassetteClient::recording(LimitedClient::new(mock).with_max_response_size(64));
+        get(
+            &recording,
+            "https://example.com/upload?uploadType=resumable",
+        )
+        .unwrap();
+        // Responses too long to be recorded fail.
+        assert!(get(&recording, "https://example.com/long").is_err());
+
+        let cassette = recording.cassette();
+        assert_eq!(cassette.interactions.len(), 1);
+        assert_eq!(
+            cassette.interactions[0].headers,
+            vec![(
+                "location".to_string(),
+                "https://example.com/upload?uploadType=resumable&upload_id=redacted".to_string()
+            )]
+        );
+    }
+}
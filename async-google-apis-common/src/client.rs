@@ -0,0 +1,632 @@
+//! The HTTP client through which services send their requests.
+
+use crate::*;
+
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by `HttpClient::send()`.
+pub type ResponseFuture =
+    Pin<Box<dyn Future<Output = Result<hyper::Response<hyper::Body>>> + Send>>;
+
+/// Sends the HTTP requests of services. Implemented by `hyper::Client` for every `Connector`
+/// (the default is `TlsClient`), and, with the `tower` feature, by `TowerClient`, which sends
+/// requests through a stack of Tower middleware.
+pub trait HttpClient: Clone + Send + Sync + 'static {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture;
+
+    /// The maximum length of request URLs. Requests with longer URLs (typically due to long
+    /// query parameters like `q`) fail with `ApiError::URLTooLongError` before being sent,
+    /// instead of the server responding with an opaque 414 or 400 status. Wrap a client in
+    /// `LimitedClient` to change it.
+    fn max_url_length(&self) -> usize {
+        DEFAULT_MAX_URL_LENGTH
+    }
+
+    /// The maximum size of response bodies that are read into memory, i.e. structured (JSON)
+    /// responses and error bodies. Larger responses fail with `ApiError::ResponseSizeLimitError`
+    /// instead of growing memory without bounds. Downloads streamed to a writer are not
+    /// affected; use `Download::do_it_to_buf_limited()` for limiting buffered downloads. Wrap a
+    /// client in `LimitedClient` to change it.
+    fn max_response_size(&self) -> usize {
+        DEFAULT_MAX_RESPONSE_SIZE
+    }
+}
+
+impl<C: Connector> HttpClient for hyper::Client<C> {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        let response = self.request(request);
+        Box::pin(async move { Ok(response.await?) })
+    }
+}
+
+/// Settings of the hyper client sending requests, for tuning connection reuse. `build()` creates
+/// a `TlsClient`; `builder()` returns a `hyper::client::Builder` for other connectors. The
+/// defaults are hyper's:
+///
+/// ```ignore
+/// let client = ClientOptions { pool_max_idle_per_host: 16, ..Default::default() }.build();
+/// let files = drive::FilesService::new(client, Arc::new(authenticator));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientOptions {
+    /// Speak only HTTP/2, multiplexing concurrent requests over one connection per host. The
+    /// connector must support it; with TLS, it has to negotiate `h2` via ALPN.
+    pub http2_only: bool,
+    /// Send HTTP/2 pings at this interval, keeping connections alive through proxies and NATs
+    /// that drop idle connections. `None` disables pings.
+    pub http2_keep_alive_interval: Option<std::time::Duration>,
+    /// Adjust HTTP/2 flow control windows to the connection's bandwidth-delay product, which
+    /// speeds up large transfers over connections with high latency.
+    pub http2_adaptive_window: bool,
+    /// Close pooled connections after they have been idle for this long. `None` keeps them open.
+    pub pool_idle_timeout: Option<std::time::Duration>,
+    /// Keep at most this many idle connections per host for reuse. 0 disables connection reuse
+    /// (keep-alive).
+    pub pool_max_idle_per_host: usize,
+}
+
+impl Default for ClientOptions {
+    fn default() -> ClientOptions {
+        ClientOptions {
+            http2_only: false,
+            http2_keep_alive_interval: None,
+            http2_adaptive_window: false,
+            pool_idle_timeout: Some(std::time::Duration::from_secs(90)),
+            pool_max_idle_per_host: std::usize::MAX,
+        }
+    }
+}
+
+impl ClientOptions {
+    /// A hyper client builder configured with these options.
+    pub fn builder(&self) -> hyper::client::Builder {
+        let mut builder = hyper::Client::builder();
+        builder
+            .http2_only(self.http2_only)
+            .http2_keep_alive_interval(self.http2_keep_alive_interval)
+            .http2_adaptive_window(self.http2_adaptive_window)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host);
+        builder
+    }
+
+    /// A `TlsClient` configured with these options, using the system's root certificates.
+    pub fn build(&self) -> TlsClient {
+        self.builder().build(TlsConnr::new())
+    }
+}
+
+/// An `HttpClient` sending requests through `client` with other limits than those of `client`,
+/// e.g. for a service accepting longer URLs, or a server handling many requests concurrently
+/// with less memory per response. Other services can keep using `client` with its limits:
+///
+/// ```ignore
+/// let client = LimitedClient::new(https.clone()).with_max_url_length(16 * 1024);
+/// let files = drive::FilesService::new(client, Arc::new(authenticator));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LimitedClient<C = TlsClient> {
+    client: C,
+    max_url_length: usize,
+    max_response_size: usize,
+}
+
+impl<C: HttpClient> LimitedClient<C> {
+    /// A client with the same limits as `client`.
+    pub fn new(client: C) -> LimitedClient<C> {
+        LimitedClient {
+            max_url_length: client.max_url_length(),
+            max_response_size: client.max_response_size(),
+            client,
+        }
+    }
+
+    /// See `HttpClient::max_url_length()`.
+    pub fn with_max_url_length(mut self, max: usize) -> LimitedClient<C> {
+        self.max_url_length = max;
+        self
+    }
+
+    /// See `HttpClient::max_response_size()`.
+    pub fn with_max_response_size(mut self, max: usize) -> LimitedClient<C> {
+        self.max_response_size = max;
+        self
+    }
+}
+
+impl<C: HttpClient> HttpClient for LimitedClient<C> {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        self.client.send(request)
+    }
+
+    fn max_url_length(&self) -> usize {
+        self.max_url_length
+    }
+
+    fn max_response_size(&self) -> usize {
+        self.max_response_size
+    }
+}
+
+/// An `HttpClient` throttling the requests sent through `client` to `qps` requests per second on
+/// average, with bursts of up to `burst` requests. Requests over the limit are delayed, not
+/// rejected. Clones share the limit, so that one client used by all services (e.g. through
+/// `GoogleApis`) keeps a process below per-user quotas, avoiding `userRateLimitExceeded` errors.
+#[derive(Clone)]
+pub struct RateLimitedClient<C = TlsClient> {
+    client: C,
+    limits: Arc<std::sync::Mutex<RateLimits>>,
+}
+
+impl<C: HttpClient> RateLimitedClient<C> {
+    /// `qps` must be positive; a `burst` of 0 is treated as 1.
+    pub fn new(client: C, qps: f64, burst: u32) -> RateLimitedClient<C> {
+        RateLimitedClient::with_limits(client, qps, burst, false)
+    }
+
+    /// Like `new()`, but limiting the requests of each tenant (see `with_tenant()`) separately.
+    /// Requests without tenant label share one limit.
+    pub fn per_tenant(client: C, qps: f64, burst: u32) -> RateLimitedClient<C> {
+        RateLimitedClient::with_limits(client, qps, burst, true)
+    }
+
+    fn with_limits(client: C, qps: f64, burst: u32, per_tenant: bool) -> RateLimitedClient<C> {
+        assert!(qps > 0.0, "RateLimitedClient: qps must be positive");
+        RateLimitedClient {
+            client,
+            limits: Arc::new(std::sync::Mutex::new(RateLimits {
+                qps,
+                burst: burst.max(1) as f64,
+                per_tenant,
+                buckets: HashMap::new(),
+            })),
+        }
+    }
+}
+
+impl<C: HttpClient> HttpClient for RateLimitedClient<C> {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        let wait = self.limits.lock().unwrap().take(
+            request.extensions().get::<Tenant>(),
+            std::time::Instant::now(),
+        );
+        let client = self.client.clone();
+        Box::pin(async move {
+            if wait > std::time::Duration::from_secs(0) {
+                debug!("RateLimitedClient: delaying request by {:?}", wait);
+                tokio::time::delay_for(wait).await;
+            }
+            client.send(request).await
+        })
+    }
+
+    fn max_url_length(&self) -> usize {
+        self.client.max_url_length()
+    }
+
+    fn max_response_size(&self) -> usize {
+        self.client.max_response_size()
+    }
+}
+
+struct RateLimits {
+    qps: f64,
+    burst: f64,
+    per_tenant: bool,
+    /// By tenant if `per_tenant` is set, otherwise only `None`.
+    buckets: HashMap<Option<Tenant>, TokenBucket>,
+}
+
+impl RateLimits {
+    fn take(&mut self, tenant: Option<&Tenant>, now: std::time::Instant) -> std::time::Duration {
+        let key = if self.per_tenant {
+            tenant.cloned()
+        } else {
+            None
+        };
+        let (qps, burst) = (self.qps, self.burst);
+        self.buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket {
+                qps,
+                burst,
+                tokens: burst,
+                last: now,
+            })
+            .take(now)
+    }
+}
+
+struct TokenBucket {
+    qps: f64,
+    burst: f64,
+    /// Negative if requests are waiting for tokens.
+    tokens: f64,
+    last: std::time::Instant,
+}
+
+impl TokenBucket {
+    /// Take a token, and return how long to wait until it is available.
+    fn take(&mut self, now: std::time::Instant) -> std::time::Duration {
+        let refill = now.saturating_duration_since(self.last).as_secs_f64() * self.qps;
+        self.tokens = (self.tokens + refill).min(self.burst) - 1.0;
+        self.last = now;
+        if self.tokens >= 0.0 {
+            std::time::Duration::from_secs(0)
+        } else {
+            std::time::Duration::from_secs_f64(-self.tokens / self.qps)
+        }
+    }
+}
+
+/// An `HttpClient` retrying requests through `client` according to `policy` (see `RetryPolicy`)
+/// when they fail transiently. Requests over the rate limit (429, or 403 with a rate limit reason)
+/// are always retried; requests failing with a 5xx status only if they are idempotent, i.e. not
+/// `POST` requests, which might have been carried out nevertheless, and not chunks of resumable
+/// uploads, which `ResumableUpload` resumes itself. Request bodies are kept in memory for
+/// resending them; requests with bodies longer than `max_response_size()` are sent only once.
+///
+/// Request extensions can't be cloned, so only the first attempt carries all of them; retries
+/// carry just the `Tenant`.
+#[derive(Debug, Clone)]
+pub struct RetryingClient<C = TlsClient> {
+    client: C,
+    policy: RetryPolicy,
+}
+
+impl<C: HttpClient> RetryingClient<C> {
+    pub fn new(client: C, policy: RetryPolicy) -> RetryingClient<C> {
+        RetryingClient { client, policy }
+    }
+}
+
+impl<C: HttpClient> HttpClient for RetryingClient<C> {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        let client = self.client.clone();
+        let policy = self.policy.clone();
+        let max = self.client.max_response_size();
+        Box::pin(async move {
+            let (mut parts, body) = request.into_parts();
+            let body = match read_body_within(body, max, 0).await? {
+                Ok(body) => body,
+                Err(body) => {
+                    debug!("RetryingClient: request body too long for retries");
+                    return client.send(hyper::Request::from_parts(parts, body)).await;
+                }
+            };
+            let idempotent = parts.method != hyper::Method::POST
+                && !parts.headers.contains_key(hyper::header::CONTENT_RANGE);
+            let tenant = parts.extensions.get::<Tenant>().cloned();
+            let mut extensions = Some(std::mem::take(&mut parts.extensions));
+            let mut retry = 0;
+            loop {
+                let mut attempt = hyper::Request::new(hyper::Body::from(body.clone()));
+                *attempt.method_mut() = parts.method.clone();
+                *attempt.uri_mut() = parts.uri.clone();
+                *attempt.version_mut() = parts.version;
+                *attempt.headers_mut() = parts.headers.clone();
+                match (extensions.take(), tenant.as_ref()) {
+                    (Some(extensions), _) => *attempt.extensions_mut() = extensions,
+                    (None, Some(tenant)) => {
+                        attempt.extensions_mut().insert(tenant.clone());
+                    }
+                    (None, None) => {}
+                }
+
+                let response = client.send(attempt).await?;
+                let status = response.status();
+                let candidate = (status.is_server_error() && idempotent)
+                    || status == hyper::StatusCode::TOO_MANY_REQUESTS
+                    || status == hyper::StatusCode::FORBIDDEN;
+                if !candidate || retry + 1 >= policy.max_attempts {
+                    return Ok(response);
+                }
+                let (rparts, rbody) = response.into_parts();
+                let rbody = match read_body_within(rbody, max, 0).await? {
+                    Ok(rbody) => rbody,
+                    // Not an error body of a Google API.
+                    Err(rbody) => return Ok(hyper::Response::from_parts(rparts, rbody)),
+                };
+                let err = response_error(status, &rparts.headers, rbody.clone());
+                if !RetryPolicy::is_retryable(&err) {
+                    return Ok(hyper::Response::from_parts(
+                        rparts,
+                        hyper::Body::from(rbody),
+                    ));
+                }
+                let delay = policy.delay(&err, retry);
+                warn!(
+                    "RetryingClient: retrying in {:?} after error: {}",
+                    delay, err
+                );
+                if delay > std::time::Duration::from_secs(0) {
+                    tokio::time::delay_for(delay).await;
+                }
+                retry += 1;
+            }
+        })
+    }
+
+    fn max_url_length(&self) -> usize {
+        self.client.max_url_length()
+    }
+
+    fn max_response_size(&self) -> usize {
+        self.client.max_response_size()
+    }
+}
+
+/// Average requests per second sent by `default_client()`, the default per-user quota of many
+/// Google APIs.
+pub const DEFAULT_QPS: f64 = 10.0;
+/// Burst of requests sent by `default_client()` before `DEFAULT_QPS` applies.
+pub const DEFAULT_BURST: u32 = 20;
+
+/// The client created by `default_client()`.
+pub type DefaultClient = RetryingClient<RateLimitedClient<TlsClient>>;
+
+/// A `TlsClient` with the default middleware: requests are limited to `DEFAULT_QPS` (with bursts
+/// of `DEFAULT_BURST`), and retried with the default `RetryPolicy` when they fail transiently.
+/// Retries count towards the rate limit. Share one client among all services (e.g. through
+/// `GoogleApis`), so that they share the limit:
+///
+/// ```ignore
+/// let files = drive::FilesService::new(default_client(), Arc::new(authenticator));
+/// ```
+pub fn default_client() -> DefaultClient {
+    let client = ClientOptions::default().build();
+    RetryingClient::new(
+        RateLimitedClient::new(client, DEFAULT_QPS, DEFAULT_BURST),
+        RetryPolicy::default(),
+    )
+}
+
+/// An `HttpClient` sending requests through a `tower::Service`, e.g. a `TlsClient` (which is a
+/// `tower::Service` itself) wrapped in retry, rate limiting, metrics or header-injecting layers:
+///
+/// ```ignore
+/// let client = TowerClient::new(ServiceBuilder::new().rate_limit(10, second).service(https));
+/// let files = drive::FilesService::new(client, Arc::new(authenticator));
+/// ```
+#[cfg(feature = "tower")]
+#[derive(Debug, Clone)]
+pub struct TowerClient<S>(S);
+
+#[cfg(feature = "tower")]
+impl<S> TowerClient<S> {
+    pub fn new(service: S) -> TowerClient<S> {
+        TowerClient(service)
+    }
+
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+#[cfg(feature = "tower")]
+impl<S> HttpClient for TowerClient<S>
+where
+    S: tower_service::Service<hyper::Request<hyper::Body>, Response = hyper::Response<hyper::Body>>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    S::Future: Send,
+    S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        // Tower services must be driven to readiness before each call.
+        let mut service = self.0.clone();
+        Box::pin(async move {
+            futures::future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(tower_error)?;
+            service.call(request).await.map_err(tower_error)
+        })
+    }
+}
+
+/// An `HttpClient` as `tower::Service`, the reverse of `TowerClient`. This allows adding Tower
+/// middleware on top of the clients of this crate, e.g. metrics on top of `default_client()`:
+///
+/// ```ignore
+/// let stack = ServiceBuilder::new().layer(metrics).service(ClientService::new(default_client()));
+/// let files = drive::FilesService::new(TowerClient::new(stack), Arc::new(authenticator));
+/// ```
+#[cfg(feature = "tower")]
+#[derive(Debug, Clone)]
+pub struct ClientService<C>(C);
+
+#[cfg(feature = "tower")]
+impl<C: HttpClient> ClientService<C> {
+    pub fn new(client: C) -> ClientService<C> {
+        ClientService(client)
+    }
+
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+#[cfg(feature = "tower")]
+impl<C: HttpClient> tower_service::Service<hyper::Request<hyper::Body>> for ClientService<C> {
+    type Response = hyper::Response<hyper::Body>;
+    type Error = Error;
+    type Future = ResponseFuture;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::result::Result<(), Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        self.0.send(request)
+    }
+}
+
+#[cfg(feature = "tower")]
+fn tower_error<E: Into<Box<dyn std::error::Error + Send + Sync>>>(e: E) -> Error {
+    let e: Box<dyn std::error::Error + Send + Sync> = e.into();
+    anyhow::anyhow!(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_limited_client() {
+        let mock = MockClient::new();
+        assert_eq!(mock.max_url_length(), DEFAULT_MAX_URL_LENGTH);
+        let limited = LimitedClient::new(mock.clone()).with_max_url_length(100);
+        assert_eq!(limited.max_url_length(), 100);
+        // Wrappers keep the limits of the client they wrap.
+        assert_eq!(
+            RateLimitedClient::new(limited.clone(), 1.0, 1).max_url_length(),
+            100
+        );
+        assert_eq!(LimitedClient::new(limited).max_url_length(), 100);
+
+        assert_eq!(mock.max_response_size(), DEFAULT_MAX_RESPONSE_SIZE);
+        let limited = LimitedClient::new(mock).with_max_response_size(10);
+        assert_eq!(limited.max_response_size(), 10);
+        assert_eq!(limited.max_url_length(), DEFAULT_MAX_URL_LENGTH);
+        assert_eq!(
+            RateLimitedClient::new(limited, 1.0, 1).max_response_size(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_retrying_client() {
+        let mock = MockClient::new();
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_secs(0),
+            ..RetryPolicy::default()
+        };
+        let client = RetryingClient::new(mock.clone(), policy);
+        let send = |method: hyper::Method| {
+            let request = hyper::Request::builder()
+                .method(method)
+                .uri("https://www.googleapis.com/drive/v3/files")
+                .body(hyper::Body::from("body"))
+                .unwrap();
+            futures::executor::block_on(client.send(request)).unwrap()
+        };
+
+        mock.push(MockResponse::error(503, "backendError", "unavailable"))
+            .push(MockResponse::json(&serde_json::json!({})));
+        assert_eq!(send(hyper::Method::GET).status(), 200);
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].body, "body");
+
+        // A POST may have been carried out despite the 5xx status; it is only retried when the
+        // server didn't handle it due to the rate limit.
+        mock.clear_requests();
+        mock.push(MockResponse::error(503, "backendError", "unavailable"));
+        assert_eq!(send(hyper::Method::POST).status(), 503);
+        mock.push(MockResponse::error(
+            403,
+            "userRateLimitExceeded",
+            "slow down",
+        ))
+        .push(MockResponse::json(&serde_json::json!({})));
+        assert_eq!(send(hyper::Method::POST).status(), 200);
+        mock.push(MockResponse::error(403, "forbidden", "no access"));
+        let response = send(hyper::Method::GET);
+        assert_eq!(response.status(), 403);
+        let body = futures::executor::block_on(hyper::body::to_bytes(response.into_body()));
+        assert!(String::from_utf8_lossy(&body.unwrap()).contains("no access"));
+        assert_eq!(mock.requests().len(), 4);
+
+        // The last response is returned after `max_attempts`.
+        mock.clear_requests();
+        for _ in 0..5 {
+            mock.push(MockResponse::status(500));
+        }
+        assert_eq!(send(hyper::Method::GET).status(), 500);
+        assert_eq!(mock.requests().len(), 5);
+    }
+
+    #[test]
+    fn test_retrying_client_limits() {
+        let mock = MockClient::new();
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_secs(0),
+            ..RetryPolicy::default()
+        };
+        let limited = LimitedClient::new(mock.clone()).with_max_response_size(64);
+        let client = RetryingClient::new(limited, policy);
+        let send = |body: String| {
+            let request = hyper::Request::get("https://www.googleapis.com/drive/v3/files")
+                .body(hyper::Body::from(body))
+                .unwrap();
+            futures::executor::block_on(client.send(request)).unwrap()
+        };
+
+        // Error bodies longer than the limit aren't read, and returned as they are.
+        let message = "unavailable ".repeat(10);
+        mock.push(MockResponse::error(503, "backendError", &message));
+        let response = send("body".into());
+        assert_eq!(response.status(), 503);
+        let body = futures::executor::block_on(hyper::body::to_bytes(response.into_body()));
+        assert!(String::from_utf8_lossy(&body.unwrap()).contains(&message));
+        assert_eq!(mock.requests().len(), 1);
+
+        // Request bodies longer than the limit aren't kept for retries.
+        mock.clear_requests();
+        mock.push(MockResponse::status(500));
+        let long = "x".repeat(100);
+        assert_eq!(send(long.clone()).status(), 500);
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].body, long);
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket {
+            qps: 10.0,
+            burst: 2.0,
+            tokens: 2.0,
+            last: start,
+        };
+        assert_eq!(bucket.take(start), Duration::from_secs(0));
+        assert_eq!(bucket.take(start), Duration::from_secs(0));
+        // Requests over the burst are delayed one after the other.
+        assert_eq!(bucket.take(start), Duration::from_millis(100));
+        assert_eq!(bucket.take(start), Duration::from_millis(200));
+        // After waiting, the bucket refills up to the burst size.
+        let later = start + Duration::from_secs(10);
+        assert_eq!(bucket.take(later), Duration::from_secs(0));
+        assert_eq!(bucket.take(later), Duration::from_secs(0));
+        assert_eq!(bucket.take(later), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_rate_limits_per_tenant() {
+        let start = Instant::now();
+        let mut limits = RateLimits {
+            qps: 10.0,
+            burst: 1.0,
+            per_tenant: true,
+            buckets: HashMap::new(),
+        };
+        let (a, b) = (Tenant::from("a"), Tenant::from("b"));
+        assert_eq!(limits.take(Some(&a), start), Duration::from_secs(0));
+        assert_eq!(limits.take(Some(&b), start), Duration::from_secs(0));
+        assert_eq!(limits.take(None, start), Duration::from_secs(0));
+        assert_eq!(limits.take(Some(&a), start), Duration::from_millis(100));
+
+        limits.per_tenant = false;
+        limits.buckets.clear();
+        assert_eq!(limits.take(Some(&a), start), Duration::from_secs(0));
+        assert_eq!(limits.take(Some(&b), start), Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,81 @@
+//! Percent-encoding sets for the different parts of a request URL.
+//!
+//! Generated code encodes path parameters with `PATH_SEGMENT` (or `RESERVED_PATH` for `{+param}`
+//! expansions, which may contain slashes). This leaves characters like `@`, `:` and `,` readable,
+//! instead of encoding every non-alphanumeric character. Query parameters are serialized with
+//! `form_urlencoded` (`Url::query_pairs_mut()`); `QUERY_COMPONENT` remains for query strings
+//! assembled by hand.
+
+use percent_encoding::{AsciiSet, CONTROLS};
+
+/// Characters encoded in a query parameter value: everything that would end the value (`&`, `#`),
+/// be interpreted as a separator or space (`=`, `+`), or is not allowed in a URL at all.
+pub const QUERY_COMPONENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'&')
+    .add(b'+')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Characters encoded in a single path segment, i.e. a `{param}` in a method's path.
+pub const PATH_SEGMENT: &AsciiSet = &QUERY_COMPONENT.add(b'/').add(b'?');
+
+/// Characters encoded in a `{+param}` path expansion, which may span several segments.
+pub const RESERVED_PATH: &AsciiSet = &PATH_SEGMENT.remove(b'/');
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use percent_encoding::percent_encode;
+
+    fn enc(s: &str, set: &'static AsciiSet) -> String {
+        percent_encode(s.as_bytes(), set).to_string()
+    }
+
+    #[test]
+    fn test_query_component() {
+        assert_eq!(
+            enc("'user@example.com' in owners", QUERY_COMPONENT),
+            "'user@example.com'%20in%20owners"
+        );
+        assert_eq!(
+            enc(
+                "modifiedTime > '2020-12-01T10:00:00+01:00'",
+                QUERY_COMPONENT
+            ),
+            "modifiedTime%20%3E%20'2020-12-01T10:00:00%2B01:00'"
+        );
+        assert_eq!(
+            enc("files(id,name),nextPageToken", QUERY_COMPONENT),
+            "files(id,name),nextPageToken"
+        );
+        assert_eq!(enc("a&b=c#d%e", QUERY_COMPONENT), "a%26b%3Dc%23d%25e");
+        assert_eq!(enc("text/plain", QUERY_COMPONENT), "text/plain");
+        assert_eq!(enc("Grüße", QUERY_COMPONENT), "Gr%C3%BC%C3%9Fe");
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            enc("folder/my file?.txt", PATH_SEGMENT),
+            "folder%2Fmy%20file%3F.txt"
+        );
+        assert_eq!(enc("user@example.com", PATH_SEGMENT), "user@example.com");
+        assert_eq!(
+            enc("projects/p-1/topics/t 1", RESERVED_PATH),
+            "projects/p-1/topics/t%201"
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use serde::Deserialize;
+
 #[derive(Debug)]
 pub enum ApiError {
-    /// The API returned a non-OK HTTP response.
+    /// The API returned a non-OK HTTP response, with the given body. Use
+    /// `ApiError::error_response()` to obtain the structured error contained in the body.
     HTTPResponseError(hyper::StatusCode, String),
     /// Returned after being redirected more than five times.
     HTTPTooManyRedirectsError,
@@ -10,6 +13,139 @@ pub enum ApiError {
     InputDataError(String),
     /// Data for download is available, but the caller hasn't supplied a destination to write to.
     DataAvailableError(String),
+    /// A byte range was requested with `Download::set_range()`, but the server responded with the
+    /// complete content.
+    RangeIgnoredError,
+    /// The checksum of downloaded data (second value) doesn't match the one announced by the
+    /// server (first value).
+    ChecksumMismatchError(String, String),
+    /// The downloaded data would exceed the size limit (in bytes) given by the caller.
+    DownloadSizeLimitError(usize),
+    /// An upload of the given size (in bytes) was rejected before starting, because only the
+    /// given number of bytes is left in the user's storage quota.
+    QuotaExceededError(u64, u64),
+    /// A response body exceeded the size limit (in bytes) of the client; see
+    /// `HttpClient::max_response_size()`.
+    ResponseSizeLimitError(usize),
+    /// The request URL is longer (first value) than the limit of the client (second value); see
+    /// `HttpClient::max_url_length()`.
+    URLTooLongError(usize, usize),
+    /// The server indicated that results are incomplete (e.g. `incompleteSearch` in a listing),
+    /// after the given number of items had been received.
+    IncompleteResultsError(u64),
+    /// The operation was cancelled through its `CancellationToken`, after transferring the given
+    /// number of bytes (uploads, downloads) or processing the given number of items (listings).
+    CancelledError(u64),
+}
+
+/// Attached as context to an `ApiError::HTTPResponseError` if the server asked to wait for the
+/// given time before retrying (`Retry-After` header), e.g. with status 429 or 503. Use
+/// `ApiError::retry_after()` to obtain it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryAfter(pub std::time::Duration);
+
+impl std::fmt::Display for RetryAfter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Server asked to retry after {:?}", self.0)
+    }
+}
+
+/// The error payload returned by Google APIs along with non-OK responses.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ErrorResponse {
+    pub code: u16,
+    pub message: String,
+    /// The canonical error code, e.g. `NOT_FOUND` or `RESOURCE_EXHAUSTED`.
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub errors: Vec<ErrorDetail>,
+}
+
+/// One of the individual errors of an `ErrorResponse`.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorDetail {
+    /// E.g. `rateLimitExceeded`, `notFound`, `insufficientFilePermissions`.
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub domain: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub location_type: Option<String>,
+}
+
+impl ErrorResponse {
+    /// Returns true if any of the individual errors has the given `reason`.
+    pub fn has_reason(&self, reason: &str) -> bool {
+        self.errors
+            .iter()
+            .any(|e| e.reason.as_deref() == Some(reason))
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorResponseWrapper {
+    error: ErrorResponse,
+}
+
+impl ApiError {
+    /// The structured error contained in the body of an `HTTPResponseError`, if the body is in
+    /// the standard `{"error": {"code": ..., "message": ..., "errors": [...]}}` format.
+    pub fn error_response(&self) -> Option<ErrorResponse> {
+        match self {
+            ApiError::HTTPResponseError(_, body) => {
+                serde_json::from_str::<ErrorResponseWrapper>(body)
+                    .ok()
+                    .map(|w| w.error)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if `err` is an `HTTPResponseError` whose body lists an error with the given
+    /// `reason`, e.g. `rateLimitExceeded`.
+    pub fn has_reason(err: &anyhow::Error, reason: &str) -> bool {
+        err.downcast_ref::<ApiError>()
+            .and_then(ApiError::error_response)
+            .map(|r| r.has_reason(reason))
+            .unwrap_or(false)
+    }
+
+    /// Returns true if `err` is an `HTTPResponseError` with status 404 Not Found.
+    pub fn is_not_found(err: &anyhow::Error) -> bool {
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::HTTPResponseError(status, _)) => *status == hyper::StatusCode::NOT_FOUND,
+            _ => false,
+        }
+    }
+
+    /// Returns true if `err` is an `HTTPResponseError` with status 403 Forbidden, e.g. for lacking
+    /// permissions or exceeded quotas.
+    pub fn is_forbidden(err: &anyhow::Error) -> bool {
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::HTTPResponseError(status, _)) => *status == hyper::StatusCode::FORBIDDEN,
+            _ => false,
+        }
+    }
+
+    /// Returns the delay the server asked for before retrying, if `err` is an
+    /// `HTTPResponseError` for a response with a `Retry-After` header.
+    pub fn retry_after(err: &anyhow::Error) -> Option<std::time::Duration> {
+        err.downcast_ref::<RetryAfter>().map(|r| r.0)
+    }
+
+    /// Returns the progress made before cancellation if `err` is a `CancelledError`.
+    pub fn cancelled_progress(err: &anyhow::Error) -> Option<u64> {
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::CancelledError(progress)) => Some(*progress),
+            _ => None,
+        }
+    }
 }
 
 impl std::error::Error for ApiError {}
@@ -0,0 +1,288 @@
+//! Injecting faults into HTTP requests, for testing how applications cope with failures.
+//!
+//! A `FaultyClient` wraps the client of services and makes requests fail in the ways they fail
+//! in production: added latency, connections failing before or while the response is received,
+//! bursts of 429 or 500 responses, and truncated response bodies. Faults are chosen randomly
+//! with the configured probabilities; a fixed seed makes a test reproducible:
+//!
+//! ```ignore
+//! let faults = Faults { connection_error: 0.1, error_burst: 0.05, ..Default::default() };
+//! let client = FaultyClient::with_seed(https, faults, 42);
+//! let files = drive::FilesService::new(client, Arc::new(authenticator));
+//! ```
+
+use crate::*;
+
+use std::time::Duration;
+
+/// The faults injected by a `FaultyClient`. Probabilities are between 0 (never) and 1 (for
+/// every request); the default injects no faults.
+#[derive(Debug, Clone)]
+pub struct Faults {
+    /// Delay added before sending a request.
+    pub latency: Duration,
+    /// Probability of delaying a request by `latency`.
+    pub latency_probability: f64,
+    /// Probability of failing a request with an error, as if the connection couldn't be
+    /// established or was reset before a response arrived. The server doesn't see the request.
+    pub connection_error: f64,
+    /// Probability of starting a burst of `error_burst_len` error responses with status
+    /// `error_status`, which the server doesn't see either.
+    pub error_burst: f64,
+    pub error_burst_len: u32,
+    /// 429 (with a `Retry-After` of one second) or a 5xx status.
+    pub error_status: hyper::StatusCode,
+    /// Probability of the connection breaking while the response body is received: reading the
+    /// body fails after half of it.
+    pub dropped_body: f64,
+    /// Probability of a response body ending after half of it, without an error, e.g. leaving
+    /// incomplete JSON.
+    pub truncated_body: f64,
+}
+
+impl Default for Faults {
+    fn default() -> Faults {
+        Faults {
+            latency: Duration::from_secs(0),
+            latency_probability: 0.0,
+            connection_error: 0.0,
+            error_burst: 0.0,
+            error_burst_len: 3,
+            error_status: hyper::StatusCode::TOO_MANY_REQUESTS,
+            dropped_body: 0.0,
+            truncated_body: 0.0,
+        }
+    }
+}
+
+/// An `HttpClient` sending requests through `client`, but injecting `Faults`; see the module
+/// documentation. Clones share the random state and error bursts.
+#[derive(Clone)]
+pub struct FaultyClient<C = TlsClient> {
+    client: C,
+    faults: Arc<Faults>,
+    state: Arc<std::sync::Mutex<FaultState>>,
+}
+
+impl<C: HttpClient> FaultyClient<C> {
+    /// Inject `faults` with a seed depending on the current time.
+    pub fn new(client: C, faults: Faults) -> FaultyClient<C> {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        FaultyClient::with_seed(client, faults, seed)
+    }
+
+    /// Inject `faults`, choosing them in the same sequence for the same `seed`.
+    pub fn with_seed(client: C, faults: Faults, seed: u64) -> FaultyClient<C> {
+        FaultyClient {
+            client,
+            faults: Arc::new(faults),
+            state: Arc::new(std::sync::Mutex::new(FaultState {
+                // xorshift doesn't work with a zero state.
+                rng: seed | 1,
+                burst_remaining: 0,
+            })),
+        }
+    }
+}
+
+/// What to do with a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Plan {
+    delay: bool,
+    connection_error: bool,
+    error_response: bool,
+    dropped_body: bool,
+    truncated_body: bool,
+}
+
+struct FaultState {
+    rng: u64,
+    /// Number of error responses left in the current burst.
+    burst_remaining: u32,
+}
+
+impl FaultState {
+    /// A random number in [0, 1) (xorshift64*).
+    fn next(&mut self) -> f64 {
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        (self.rng.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        probability > 0.0 && self.next() < probability
+    }
+
+    fn plan(&mut self, faults: &Faults) -> Plan {
+        let mut plan = Plan {
+            delay: self.chance(faults.latency_probability),
+            ..Default::default()
+        };
+        if self.burst_remaining == 0 && self.chance(faults.error_burst) {
+            self.burst_remaining = faults.error_burst_len;
+        }
+        if self.burst_remaining > 0 {
+            self.burst_remaining -= 1;
+            plan.error_response = true;
+        } else if self.chance(faults.connection_error) {
+            plan.connection_error = true;
+        } else if self.chance(faults.dropped_body) {
+            plan.dropped_body = true;
+        } else if self.chance(faults.truncated_body) {
+            plan.truncated_body = true;
+        }
+        plan
+    }
+}
+
+impl<C: HttpClient> HttpClient for FaultyClient<C> {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        let plan = self.state.lock().unwrap().plan(&self.faults);
+        let faults = self.faults.clone();
+        let client = self.client.clone();
+        Box::pin(async move {
+            if plan.delay {
+                tokio::time::delay_for(faults.latency).await;
+            }
+            if plan.connection_error {
+                debug!(
+                    "FaultyClient: failing {} {}",
+                    request.method(),
+                    request.uri()
+                );
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::ConnectionReset,
+                    "FaultyClient: injected connection error",
+                )
+                .into());
+            }
+            if plan.error_response {
+                debug!(
+                    "FaultyClient: responding {} to {} {}",
+                    faults.error_status,
+                    request.method(),
+                    request.uri()
+                );
+                return error_response(faults.error_status);
+            }
+            let response = client.send(request).await?;
+            if !(plan.dropped_body || plan.truncated_body) {
+                return Ok(response);
+            }
+            let (mut parts, body) = response.into_parts();
+            let body = hyper::body::to_bytes(body).await?;
+            let half = body.slice(..body.len() / 2);
+            parts.headers.remove(hyper::header::CONTENT_LENGTH);
+            let body = if plan.dropped_body {
+                debug!(
+                    "FaultyClient: dropping connection after {} bytes",
+                    half.len()
+                );
+                let chunks: Vec<std::result::Result<hyper::body::Bytes, std::io::Error>> = vec![
+                    Ok(half),
+                    Err(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionReset,
+                        "FaultyClient: injected connection error while receiving body",
+                    )),
+                ];
+                hyper::Body::wrap_stream(futures::stream::iter(chunks))
+            } else {
+                debug!("FaultyClient: truncating body to {} bytes", half.len());
+                hyper::Body::from(half)
+            };
+            Ok(hyper::Response::from_parts(parts, body))
+        })
+    }
+
+    fn max_url_length(&self) -> usize {
+        self.client.max_url_length()
+    }
+
+    fn max_response_size(&self) -> usize {
+        self.client.max_response_size()
+    }
+}
+
+/// A response with `status` and an error body like Google APIs send.
+fn error_response(status: hyper::StatusCode) -> Result<hyper::Response<hyper::Body>> {
+    let body = serde_json::json!({
+        "error": {
+            "code": status.as_u16(),
+            "message": "Injected by FaultyClient",
+            "errors": [{
+                "domain": "global",
+                "reason": if status == hyper::StatusCode::TOO_MANY_REQUESTS {
+                    "rateLimitExceeded"
+                } else {
+                    "backendError"
+                },
+                "message": "Injected by FaultyClient",
+            }],
+        }
+    });
+    let mut response = hyper::Response::builder()
+        .status(status)
+        .header(hyper::header::CONTENT_TYPE, "application/json");
+    if status == hyper::StatusCode::TOO_MANY_REQUESTS {
+        response = response.header(hyper::header::RETRY_AFTER, "1");
+    }
+    Ok(response.body(hyper::Body::from(body.to_string()))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan() {
+        let mut never = FaultState {
+            rng: 1,
+            burst_remaining: 0,
+        };
+        for _ in 0..100 {
+            assert_eq!(never.plan(&Faults::default()), Plan::default());
+        }
+
+        let faults = Faults {
+            error_burst: 1.0,
+            error_burst_len: 2,
+            connection_error: 1.0,
+            ..Default::default()
+        };
+        let mut state = FaultState {
+            rng: 1,
+            burst_remaining: 0,
+        };
+        let plans: Vec<Plan> = (0..3).map(|_| state.plan(&faults)).collect();
+        assert!(plans
+            .iter()
+            .all(|p| p.error_response && !p.connection_error));
+        assert_eq!(state.burst_remaining, 1);
+
+        let faults = Faults {
+            connection_error: 0.5,
+            ..Default::default()
+        };
+        let failed = (0..1000)
+            .filter(|_| state.plan(&faults).connection_error)
+            .count();
+        assert!(failed > 400 && failed < 600, "{} of 1000 failed", failed);
+    }
+
+    #[test]
+    fn test_error_response() {
+        let response = error_response(hyper::StatusCode::TOO_MANY_REQUESTS).unwrap();
+        assert_eq!(response.headers()[hyper::header::RETRY_AFTER], "1");
+        let body = futures::executor::block_on(hyper::body::to_bytes(response.into_body()));
+        let err: Error = ApiError::HTTPResponseError(
+            hyper::StatusCode::TOO_MANY_REQUESTS,
+            String::from_utf8(body.unwrap().to_vec()).unwrap(),
+        )
+        .into();
+        assert!(ApiError::has_reason(&err, "rateLimitExceeded"));
+    }
+}
@@ -2,8 +2,11 @@ use crate::*;
 
 use anyhow::Context;
 
-fn body_to_str(b: hyper::body::Bytes) -> String {
-    String::from_utf8(b.to_vec()).unwrap_or("[UTF-8 decode failed]".into())
+fn body_to_str(b: &[u8]) -> String {
+    match std::str::from_utf8(b) {
+        Ok(s) => s.to_string(),
+        Err(_) => "[UTF-8 decode failed]".into(),
+    }
 }
 
 /// This type is used as type parameter to the following functions, when `rq` is `None`.
@@ -23,35 +26,117 @@ pub enum DownloadResult<T: DeserializeOwned + std::fmt::Debug> {
     Response(T),
 }
 
-/// The Content-Type header is set automatically to application/json.
-pub async fn do_request<
-    Req: Serialize + std::fmt::Debug,
-    Resp: DeserializeOwned + Clone + Default,
->(
-    cl: &TlsClient,
-    path: &str,
-    headers: &[(hyper::header::HeaderName, String)],
-    http_method: &str,
-    rq: Option<Req>,
-) -> Result<Resp> {
-    use futures::future::FutureExt;
-    do_request_with_headers(cl, path, headers, http_method, rq)
-        .map(|r| r.map(|t| t.0))
-        .await
+/// Metadata of downloaded content, returned by `Download::do_it_with_info()`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DownloadInfo {
+    /// The `Content-Type` sent by the server.
+    pub content_type: Option<String>,
+    /// The `Content-Length` announced by the server, if any.
+    pub content_length: Option<u64>,
+    /// Number of bytes written to `dst`.
+    pub bytes: u64,
 }
 
-/// The Content-Type header is set automatically to application/json. Also returns response
-/// headers.
-pub async fn do_request_with_headers<
-    Req: Serialize + std::fmt::Debug,
-    Resp: DeserializeOwned + Clone + Default,
->(
-    cl: &TlsClient,
+/// Default for `HttpClient::max_url_length()`.
+pub const DEFAULT_MAX_URL_LENGTH: usize = 8192;
+
+fn check_url_length<C: HttpClient>(cl: &C, url: &str) -> Result<()> {
+    let max = cl.max_url_length();
+    if url.len() > max {
+        Err(ApiError::URLTooLongError(url.len(), max).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Default for `HttpClient::max_response_size()`.
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 256 * 1024 * 1024;
+
+/// Read the body of `response` into memory, up to `max` bytes (see
+/// `HttpClient::max_response_size()`).
+async fn read_body(
+    response: hyper::Response<hyper::Body>,
+    max: usize,
+) -> Result<hyper::body::Bytes> {
+    use std::str::FromStr;
+    let announced = response
+        .headers()
+        .get(hyper::header::CONTENT_LENGTH)
+        .and_then(|len| usize::from_str(len.to_str().ok()?).ok());
+    if announced.unwrap_or(0) > max {
+        return Err(ApiError::ResponseSizeLimitError(max).into());
+    }
+    match read_body_within(response.into_body(), max, announced.unwrap_or(0)).await? {
+        Ok(body) => Ok(body),
+        Err(_) => Err(ApiError::ResponseSizeLimitError(max).into()),
+    }
+}
+
+/// Read `body` into memory if it isn't longer than `max` bytes, e.g. for inspecting responses
+/// in an `HttpClient` wrapper. A longer body is returned as `Err`, with the part read so far put
+/// back in front of the rest, so that it can be passed on unchanged. `size_hint` is the expected
+/// size (e.g. from `Content-Length`), or 0.
+pub async fn read_body_within(
+    mut body: hyper::Body,
+    max: usize,
+    size_hint: usize,
+) -> Result<std::result::Result<hyper::body::Bytes, hyper::Body>> {
+    // A body arriving in a single chunk is returned without copying it.
+    let mut first: Option<hyper::body::Bytes> = None;
+    let mut buf = Vec::new();
+    while let Some(chunk) = tokio::stream::StreamExt::next(&mut body).await {
+        let chunk = chunk?;
+        let len = first.as_ref().map(|f| f.len()).unwrap_or(0) + buf.len();
+        if len + chunk.len() > max {
+            let read: Vec<std::result::Result<hyper::body::Bytes, hyper::Error>> = first
+                .into_iter()
+                .chain(Some(buf.into()))
+                .chain(Some(chunk))
+                .filter(|b| !b.is_empty())
+                .map(Ok)
+                .collect();
+            let rest = futures::StreamExt::chain(futures::stream::iter(read), body);
+            return Ok(Err(hyper::Body::wrap_stream(rest)));
+        }
+        if first.is_none() && buf.is_empty() {
+            first = Some(chunk);
+            continue;
+        }
+        if let Some(first) = first.take() {
+            buf.reserve(size_hint.max(first.len() + chunk.len()));
+            buf.extend_from_slice(&first);
+        }
+        buf.extend_from_slice(&chunk);
+    }
+    match first {
+        Some(first) => Ok(Ok(first)),
+        None => Ok(Ok(buf.into())),
+    }
+}
+
+/// Await a delete request, treating a 404 Not Found response as success. Use this for deletions
+/// that may be retried, or run against items already deleted by someone else, e.g.
+/// `idempotent_delete(files_service.delete(&params)).await?`.
+pub async fn idempotent_delete<F: std::future::Future<Output = Result<()>>>(
+    delete: F,
+) -> Result<()> {
+    match delete.await {
+        Err(e) if ApiError::is_not_found(&e) => {
+            debug!("idempotent_delete: item already gone: {}", e);
+            Ok(())
+        }
+        r => r,
+    }
+}
+
+fn build_request<Req: Serialize + std::fmt::Debug, C: HttpClient>(
+    cl: &C,
     path: &str,
     headers: &[(hyper::header::HeaderName, String)],
     http_method: &str,
     rq: Option<Req>,
-) -> Result<(Resp, hyper::HeaderMap)> {
+) -> Result<hyper::Request<hyper::Body>> {
+    check_url_length(cl, path)?;
     let mut reqb = hyper::Request::builder().uri(path).method(http_method);
     for (k, v) in headers {
         reqb = reqb.header(k, v);
@@ -64,6 +149,7 @@ pub async fn do_request_with_headers<
         body_str = "".to_string();
     }
 
+    json_trace::trace_json("do_request: request body", body_str.as_bytes());
     let body;
     if body_str == "null" {
         body = hyper::Body::from("");
@@ -71,11 +157,100 @@ pub async fn do_request_with_headers<
         body = hyper::Body::from(body_str);
     }
 
-    let http_request = reqb.body(body)?;
+    let mut http_request = reqb.body(body)?;
+    protect_headers(http_request.headers_mut());
+
+    debug!(
+        "do_request: Launching HTTP request: {:?}",
+        RedactedRequest(&http_request)
+    );
+    Ok(http_request)
+}
+
+/// A successful response: the parsed body, with the status and headers it was sent with. Returned
+/// by the `..._with_response()` variants of API methods.
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    pub status: hyper::StatusCode,
+    pub headers: hyper::HeaderMap,
+    pub body: T,
+}
+
+impl<T> ApiResponse<T> {
+    /// The value of header `name`, if present and valid UTF-8.
+    pub fn header<K: hyper::header::AsHeaderName>(&self, name: K) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// The `ETag` header, e.g. for conditional requests with `If-None-Match`.
+    pub fn etag(&self) -> Option<&str> {
+        self.header(hyper::header::ETAG)
+    }
+
+    /// The `Content-Length` header.
+    pub fn content_length(&self) -> Option<u64> {
+        self.header(hyper::header::CONTENT_LENGTH)?.parse().ok()
+    }
+
+    /// The `Date` header: the time at which the server created the response.
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        let date = self.header(hyper::header::DATE)?;
+        chrono::DateTime::parse_from_rfc2822(date)
+            .ok()
+            .map(|d| d.with_timezone(&Utc))
+    }
+}
+
+/// The Content-Type header is set automatically to application/json.
+pub async fn do_request<
+    Req: Serialize + std::fmt::Debug,
+    Resp: DeserializeOwned + Clone + Default,
+    C: HttpClient,
+>(
+    cl: &C,
+    path: &str,
+    headers: &[(hyper::header::HeaderName, String)],
+    http_method: &str,
+    rq: Option<Req>,
+) -> Result<Resp> {
+    Ok(do_request_with_response(cl, path, headers, http_method, rq)
+        .await?
+        .body)
+}
+
+/// The Content-Type header is set automatically to application/json. Also returns response
+/// headers.
+pub async fn do_request_with_headers<
+    Req: Serialize + std::fmt::Debug,
+    Resp: DeserializeOwned + Clone + Default,
+    C: HttpClient,
+>(
+    cl: &C,
+    path: &str,
+    headers: &[(hyper::header::HeaderName, String)],
+    http_method: &str,
+    rq: Option<Req>,
+) -> Result<(Resp, hyper::HeaderMap)> {
+    let response = do_request_with_response(cl, path, headers, http_method, rq).await?;
+    Ok((response.body, response.headers))
+}
 
-    debug!("do_request: Launching HTTP request: {:?}", http_request);
+/// The Content-Type header is set automatically to application/json. Also returns the status and
+/// headers of the response.
+pub async fn do_request_with_response<
+    Req: Serialize + std::fmt::Debug,
+    Resp: DeserializeOwned + Clone + Default,
+    C: HttpClient,
+>(
+    cl: &C,
+    path: &str,
+    headers: &[(hyper::header::HeaderName, String)],
+    http_method: &str,
+    rq: Option<Req>,
+) -> Result<ApiResponse<Resp>> {
+    let http_request = build_request(cl, path, headers, http_method, rq)?;
 
-    let http_response = cl.request(http_request).await?;
+    let http_response = cl.send(tag_request(http_request)).await?;
     let status = http_response.status();
 
     debug!(
@@ -84,65 +259,126 @@ pub async fn do_request_with_headers<
     );
 
     let headers = http_response.headers().clone();
-    let response_body = hyper::body::to_bytes(http_response.into_body()).await?;
+    let response_body = read_body(http_response, cl.max_response_size()).await?;
+    json_trace::trace_json("do_request: response body", &response_body);
     if !status.is_success() {
-        Err(ApiError::HTTPResponseError(status, body_to_str(response_body)).into())
+        return Err(response_error(status, &headers, response_body));
+    }
+    // Evaluate body_to_str lazily
+    let body = if response_body.len() > 0 {
+        serde_json::from_slice(&response_body)
+            .map_err(|e| anyhow::Error::from(e).context(body_to_str(&response_body)))?
     } else {
-        // Evaluate body_to_str lazily
-        if response_body.len() > 0 {
-            serde_json::from_reader(response_body.as_ref())
-                .map_err(|e| anyhow::Error::from(e).context(body_to_str(response_body)))
-                .map(|r| (r, headers))
-        } else {
-            Ok((Default::default(), headers))
-        }
+        Default::default()
+    };
+    Ok(ApiResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// Like `do_request_with_headers()`, but returns the body of a successful response without
+/// reading it, e.g. for decoding large responses incrementally with a `JsonArrayDecoder`.
+pub async fn do_request_body<Req: Serialize + std::fmt::Debug, C: HttpClient>(
+    cl: &C,
+    path: &str,
+    headers: &[(hyper::header::HeaderName, String)],
+    http_method: &str,
+    rq: Option<Req>,
+) -> Result<hyper::Body> {
+    let http_request = build_request(cl, path, headers, http_method, rq)?;
+    let http_response = cl.send(tag_request(http_request)).await?;
+    let status = http_response.status();
+
+    debug!(
+        "do_request_body: HTTP response with status {} received: {:?}",
+        status, http_response
+    );
+
+    if !status.is_success() {
+        let headers = http_response.headers().clone();
+        let response_body = read_body(http_response, cl.max_response_size()).await?;
+        Err(response_error(status, &headers, response_body))
+    } else {
+        Ok(http_response.into_body())
     }
 }
 
-/// The Content-Length header is set automatically.
+/// The Content-Length header is set automatically. `content_type` is the MIME type of `data`; if
+/// it is `None`, the server detects it.
 pub async fn do_upload_multipart<
     Req: Serialize + std::fmt::Debug,
     Resp: DeserializeOwned + Clone,
+    C: HttpClient,
 >(
-    cl: &TlsClient,
+    cl: &C,
     path: &str,
     headers: &[(hyper::header::HeaderName, String)],
     http_method: &str,
     req: Option<Req>,
     data: hyper::body::Bytes,
+    content_type: Option<&str>,
 ) -> Result<Resp> {
+    do_upload_multipart_with_response(cl, path, headers, http_method, req, data, content_type)
+        .await
+        .map(|response| response.body)
+}
+
+/// Like `do_upload_multipart()`, but returns the status and headers of the response as well.
+pub async fn do_upload_multipart_with_response<
+    Req: Serialize + std::fmt::Debug,
+    Resp: DeserializeOwned + Clone,
+    C: HttpClient,
+>(
+    cl: &C,
+    path: &str,
+    headers: &[(hyper::header::HeaderName, String)],
+    http_method: &str,
+    req: Option<Req>,
+    data: hyper::body::Bytes,
+    content_type: Option<&str>,
+) -> Result<ApiResponse<Resp>> {
+    check_url_length(cl, path)?;
     let mut reqb = hyper::Request::builder().uri(path).method(http_method);
     for (k, v) in headers {
         reqb = reqb.header(k, v);
     }
 
-    let data = multipart::format_multipart(&req, data)?;
+    let data = multipart::format_multipart(&req, data, content_type)?;
     reqb = reqb.header("Content-Length", data.as_ref().len());
     reqb = reqb.header(
         "Content-Type",
         format!("multipart/related; boundary={}", multipart::MIME_BOUNDARY),
     );
 
-    let body = hyper::Body::from(data.as_ref().to_vec());
-    let http_request = reqb.body(body)?;
+    let body = hyper::Body::from(data);
+    let mut http_request = reqb.body(body)?;
+    protect_headers(http_request.headers_mut());
     debug!(
         "do_upload_multipart: Launching HTTP request: {:?}",
-        http_request
+        RedactedRequest(&http_request)
     );
-    let http_response = cl.request(http_request).await?;
+    let http_response = cl.send(tag_request(http_request)).await?;
     let status = http_response.status();
     debug!(
         "do_upload_multipart: HTTP response with status {} received: {:?}",
         status, http_response
     );
-    let response_body = hyper::body::to_bytes(http_response.into_body()).await?;
+    let headers = http_response.headers().clone();
+    let response_body = read_body(http_response, cl.max_response_size()).await?;
+    json_trace::trace_json("do_upload_multipart: response body", &response_body);
 
     if !status.is_success() {
-        Err(ApiError::HTTPResponseError(status, body_to_str(response_body)).into())
-    } else {
-        serde_json::from_reader(response_body.as_ref())
-            .map_err(|e| anyhow::Error::from(e).context(body_to_str(response_body)))
+        return Err(response_error(status, &headers, response_body));
     }
+    let body = serde_json::from_slice(&response_body)
+        .map_err(|e| anyhow::Error::from(e).context(body_to_str(&response_body)))?;
+    Ok(ApiResponse {
+        status,
+        headers,
+        body,
+    })
 }
 
 /// An ongoing download.
@@ -152,27 +388,74 @@ pub async fn do_upload_multipart<
 /// `Content-Type` sent by the server; frequently, the parameters sent in the request determine
 /// whether the server starts a download (`Content-Type: whatever`) or sends a response
 /// (`Content-Type: application/json`).
-pub struct Download<'a, Request, Response> {
-    cl: &'a TlsClient,
+pub struct Download<'a, Request, Response, C = TlsClient> {
+    cl: &'a C,
     http_method: String,
     uri: hyper::Uri,
     rq: Option<&'a Request>,
     headers: Vec<(hyper::header::HeaderName, String)>,
+    cancel: Option<CancellationToken>,
+    progress: Option<Box<dyn FnMut(u64, Option<u64>) + Send + 'a>>,
+    range: Option<(u64, Option<u64>)>,
 
     _marker: std::marker::PhantomData<Response>,
 }
 
-impl<'a, Request: Serialize + std::fmt::Debug, Response: DeserializeOwned + std::fmt::Debug>
-    Download<'a, Request, Response>
+impl<
+        'a,
+        Request: Serialize + std::fmt::Debug,
+        Response: DeserializeOwned + std::fmt::Debug,
+        C: HttpClient,
+    > Download<'a, Request, Response, C>
 {
+    /// Stop the download when `token` is cancelled. It is checked before writing each chunk;
+    /// the download then fails with `ApiError::CancelledError` and the number of bytes written.
+    pub fn set_cancellation(&mut self, token: CancellationToken) -> &mut Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Call `progress` with the number of bytes written so far and the total size (if announced
+    /// by the server via `Content-Length`) after each chunk of the download has been written.
+    pub fn set_progress<F: FnMut(u64, Option<u64>) + Send + 'a>(
+        &mut self,
+        progress: F,
+    ) -> &mut Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Request only the bytes `first` through `last` (inclusive; or through the end if `last` is
+    /// `None`) of the media, using a `Range` header. If the server then responds with the
+    /// complete content instead of `206 Partial Content`, the download fails with
+    /// `ApiError::RangeIgnoredError`.
+    pub fn set_range(&mut self, first: u64, last: Option<u64>) -> &mut Self {
+        self.range = Some((first, last));
+        self
+    }
+
     /// Trivial adapter for `download()`: Store downloaded data into a `Vec<u8>`.
     pub async fn do_it_to_buf(&mut self, buf: &mut Vec<u8>) -> Result<DownloadResult<Response>> {
         self.do_it(Some(buf)).await
     }
 
+    /// Like `do_it_to_buf()`, but refuses to buffer more than `max_bytes` bytes. If the server
+    /// announces (via `Content-Length`) or sends more data than that, the download is aborted with
+    /// an `ApiError::DownloadSizeLimitError`.
+    pub async fn do_it_to_buf_limited(
+        &mut self,
+        buf: &mut Vec<u8>,
+        max_bytes: usize,
+    ) -> Result<DownloadResult<Response>> {
+        self.download(Some(buf), Some(max_bytes))
+            .await
+            .map(|(result, _)| result)
+    }
+
     /// Run the actual download, streaming the response into the supplied `dst`. If the server
     /// responded with a `Response` object, no download is started; the response is wrapped in the
-    /// `DownloadResult<Response>` object.
+    /// `DownloadResult<Response>` object. Each chunk is written to `dst` completely as it arrives,
+    /// and `dst` is flushed at the end of the download.
     ///
     /// Whether a download takes place or you receive a structured `Response` (i.e. a JSON object)
     /// depends on the `Content-Type` sent by the server. It is an error to attempt a download
@@ -183,120 +466,260 @@ impl<'a, Request: Serialize + std::fmt::Debug, Response: DeserializeOwned + std:
         &mut self,
         dst: Option<&mut (dyn tokio::io::AsyncWrite + std::marker::Unpin)>,
     ) -> Result<DownloadResult<Response>> {
+        self.download(dst, None).await.map(|(result, _)| result)
+    }
+
+    /// Like `do_it()`, but also returns the content type and size of the downloaded content.
+    /// For a structured response, the info describes the JSON body, and `bytes` is 0.
+    pub async fn do_it_with_info(
+        &mut self,
+        dst: Option<&mut (dyn tokio::io::AsyncWrite + std::marker::Unpin)>,
+    ) -> Result<(DownloadResult<Response>, DownloadInfo)> {
+        self.download(dst, None).await
+    }
+
+    /// Return the body of the response as a stream, without reading it, e.g. for reading only
+    /// the beginning of a file. This is meant for downloads of media (`alt = media`); a
+    /// structured response is returned as-is, too. Cancellation and progress callbacks don't
+    /// apply.
+    pub async fn do_it_to_stream(&mut self) -> Result<hyper::Body> {
+        Ok(self.send().await?.into_body())
+    }
+
+    async fn download(
+        &mut self,
+        dst: Option<&mut (dyn tokio::io::AsyncWrite + std::marker::Unpin)>,
+        max_bytes: Option<usize>,
+    ) -> Result<(DownloadResult<Response>, DownloadInfo)> {
         use std::str::FromStr;
 
-        let mut http_response;
+        let http_response = self.send().await?;
+        let headers = http_response.headers();
+        let mut info = DownloadInfo {
+            content_type: headers
+                .get(hyper::header::CONTENT_TYPE)
+                .and_then(|ct| ct.to_str().ok())
+                .map(str::to_string),
+            content_length: headers
+                .get(hyper::header::CONTENT_LENGTH)
+                .and_then(|len| u64::from_str(len.to_str().ok()?).ok()),
+            bytes: 0,
+        };
+
+        // Check if an object was returned.
+        if let Some(ct) = headers.get(hyper::header::CONTENT_TYPE) {
+            if ct.to_str()?.contains("application/json") {
+                let response_body = read_body(http_response, self.cl.max_response_size()).await?;
+                json_trace::trace_json("Download::do_it: response body", &response_body);
+                return serde_json::from_slice(&response_body)
+                    .map_err(|e| anyhow::Error::from(e).context(body_to_str(&response_body)))
+                    .map(|r| (DownloadResult::Response(r), info));
+            }
+        }
+
+        let dst = match dst {
+            Some(dst) => dst,
+            None => {
+                return Err(ApiError::DataAvailableError(format!(
+                    "No `dst` was supplied to download data to. Content-Type: {:?}",
+                    headers.get(hyper::header::CONTENT_TYPE)
+                ))
+                .into())
+            }
+        };
+
+        use tokio::io::AsyncWriteExt;
+        if let (Some(max), Some(len)) = (max_bytes, headers.get(hyper::header::CONTENT_LENGTH)) {
+            if usize::from_str(len.to_str()?).unwrap_or(0) > max {
+                return Err(ApiError::DownloadSizeLimitError(max).into());
+            }
+        }
+        let total = info.content_length;
+        let mut received = 0;
+        let mut response_body = http_response.into_body();
+        while let Some(chunk) = tokio::stream::StreamExt::next(&mut response_body).await {
+            let chunk = chunk?;
+            check_cancelled(&self.cancel, received as u64)?;
+            received += chunk.as_ref().len();
+            if let Some(max) = max_bytes {
+                if received > max {
+                    return Err(ApiError::DownloadSizeLimitError(max).into());
+                }
+            }
+            // Chunks often contain just a few kilobytes.
+            // info!("received chunk with size {}", chunk.as_ref().len());
+            dst.write_all(chunk.as_ref()).await?;
+            if let Some(progress) = self.progress.as_mut() {
+                progress(received as u64, total);
+            }
+        }
+        dst.flush().await?;
+        info.bytes = received as u64;
+        Ok((DownloadResult::Downloaded, info))
+    }
+
+    /// Send the request, following redirects, and return the successful response. The server
+    /// returns data - either a download or a structured response (JSON).
+    async fn send(&mut self) -> Result<hyper::Response<hyper::Body>> {
         let mut n_redirects = 0;
         let mut uri = self.uri.clone();
+        let mut method = self.http_method.clone();
+        let mut send_auth = true;
+
+        let mut body_str = None;
+        if let Some(rq) = self.rq.take() {
+            let b = serde_json::to_string(&rq).context(format!("{:?}", rq))?;
+            json_trace::trace_json("Download::do_it: request body", b.as_bytes());
+            body_str = Some(b);
+        }
 
         // Follow redirects.
         loop {
-            let mut reqb = hyper::Request::builder()
-                .uri(&uri)
-                .method(self.http_method.as_str());
+            let mut reqb = hyper::Request::builder().uri(&uri).method(method.as_str());
             for (k, v) in self.headers.iter() {
+                if *k == hyper::header::AUTHORIZATION && !send_auth {
+                    continue;
+                }
                 reqb = reqb.header(k, v);
             }
-
-            let body;
-            if let Some(rq) = self.rq.take() {
-                body = hyper::Body::from(
-                    serde_json::to_string(&rq).context(format!("{:?}", self.rq))?,
+            if let Some((first, last)) = self.range {
+                reqb = reqb.header(
+                    hyper::header::RANGE,
+                    match last {
+                        Some(last) => format!("bytes={}-{}", first, last),
+                        None => format!("bytes={}-", first),
+                    },
                 );
-            } else {
-                body = hyper::Body::from("");
             }
 
-            let http_request = reqb.body(body)?;
+            let body = match body_str.as_ref() {
+                Some(b) => hyper::Body::from(b.clone()),
+                None => hyper::Body::from(""),
+            };
+            let mut http_request = reqb.body(body)?;
+            protect_headers(http_request.headers_mut());
             debug!(
                 "Download::do_it: Redirect {}, Launching HTTP request: {:?}",
-                n_redirects, http_request
+                n_redirects,
+                RedactedRequest(&http_request)
             );
 
-            http_response = Some(self.cl.request(http_request).await?);
-            let status = http_response.as_ref().unwrap().status();
+            let http_response = self.cl.send(tag_request(http_request)).await?;
+            let status = http_response.status();
             debug!(
                 "Download::do_it: Redirect {}, HTTP response with status {} received: {:?}",
                 n_redirects, status, http_response
             );
 
-            // Server returns data - either download or structured response (JSON).
             if status.is_success() {
-                let headers = http_response.as_ref().unwrap().headers();
-
-                // Check if an object was returned.
-                if let Some(ct) = headers.get(hyper::header::CONTENT_TYPE) {
-                    if ct.to_str()?.contains("application/json") {
-                        let response_body =
-                            hyper::body::to_bytes(http_response.unwrap().into_body()).await?;
-                        return serde_json::from_reader(response_body.as_ref())
-                            .map_err(|e| anyhow::Error::from(e).context(body_to_str(response_body)))
-                            .map(DownloadResult::Response);
-                    }
-                }
-
-                if let Some(dst) = dst {
-                    use tokio::io::AsyncWriteExt;
-                    let mut response_body = http_response.unwrap().into_body();
-                    while let Some(chunk) = tokio::stream::StreamExt::next(&mut response_body).await
-                    {
-                        let chunk = chunk?;
-                        // Chunks often contain just a few kilobytes.
-                        // info!("received chunk with size {}", chunk.as_ref().len());
-                        dst.write(chunk.as_ref()).await?;
-                    }
-                    return Ok(DownloadResult::Downloaded);
-                } else {
-                    return Err(ApiError::DataAvailableError(format!(
-                        "No `dst` was supplied to download data to. Content-Type: {:?}",
-                        headers.get(hyper::header::CONTENT_TYPE)
-                    ))
-                    .into());
+                if self.range.is_some()
+                    && status != hyper::StatusCode::PARTIAL_CONTENT
+                    && !is_json(&http_response)
+                {
+                    return Err(ApiError::RangeIgnoredError.into());
                 }
+                return Ok(http_response);
 
             // Server redirects us.
             } else if status.is_redirection() {
                 n_redirects += 1;
-                let new_location = http_response
-                    .as_ref()
-                    .unwrap()
-                    .headers()
-                    .get(hyper::header::LOCATION);
+                if n_redirects > MAX_REDIRECTS {
+                    return Err(ApiError::HTTPTooManyRedirectsError.into());
+                }
+                let new_location = http_response.headers().get(hyper::header::LOCATION);
                 if new_location.is_none() {
                     return Err(ApiError::RedirectError(format!(
                         "Redirect doesn't contain a Location: header"
                     ))
                     .into());
                 }
-                uri = hyper::Uri::from_str(new_location.unwrap().to_str()?)?;
+                let target = redirect_target(&uri, new_location.unwrap().to_str()?)?;
+                // Once dropped, credentials aren't sent again, even if a redirect leads back.
+                send_auth = send_auth && keeps_credentials(&uri, &target);
+                uri = target;
+                // 303 See Other: fetch the result with GET. Other redirects (including 307 and
+                // 308) repeat the request with the same method and body.
+                if status == hyper::StatusCode::SEE_OTHER {
+                    method = "GET".into();
+                    body_str = None;
+                }
                 continue;
-            } else if !status.is_success() {
-                return Err(ApiError::HTTPResponseError(
-                    status,
-                    body_to_str(hyper::body::to_bytes(http_response.unwrap().into_body()).await?),
-                )
-                .into());
-            }
-
-            // Too many redirects.
-            if n_redirects > 5 {
-                return Err(ApiError::HTTPTooManyRedirectsError.into());
+            } else {
+                let headers = http_response.headers().clone();
+                let body = read_body(http_response, self.cl.max_response_size()).await?;
+                return Err(response_error(status, &headers, body));
             }
         }
     }
 }
 
+fn is_json(response: &hyper::Response<hyper::Body>) -> bool {
+    response
+        .headers()
+        .get(hyper::header::CONTENT_TYPE)
+        .and_then(|ct| ct.to_str().ok())
+        .map(|ct| ct.contains("application/json"))
+        .unwrap_or(false)
+}
+
+/// Maximum number of redirects followed by a `Download`.
+const MAX_REDIRECTS: usize = 5;
+
+/// Whether credentials sent to `from` may be sent along when redirected to `to`: if both are
+/// the same host (without switching from HTTPS to HTTP), or both are Google hosts.
+fn keeps_credentials(from: &hyper::Uri, to: &hyper::Uri) -> bool {
+    let same_host = from.host().map(str::to_ascii_lowercase)
+        == to.host().map(str::to_ascii_lowercase)
+        && from.port_u16() == to.port_u16()
+        && (from.scheme_str() == to.scheme_str() || to.scheme_str() == Some("https"));
+    same_host || (is_google_host(from) && is_google_host(to))
+}
+
+/// Whether `uri` is reached via HTTPS on a Google domain, which includes
+/// `googleusercontent.com`, where media downloads are often redirected to.
+fn is_google_host(uri: &hyper::Uri) -> bool {
+    if uri.scheme_str() != Some("https") {
+        return false;
+    }
+    let host = uri.host().unwrap_or("").to_ascii_lowercase();
+    ["google.com", "googleapis.com", "googleusercontent.com"]
+        .iter()
+        .any(|d| host == *d || host.ends_with(&format!(".{}", d)))
+}
+
+/// Resolve the `Location` of a redirect, which may be relative to the URI of the request.
+fn redirect_target(base: &hyper::Uri, location: &str) -> Result<hyper::Uri> {
+    use std::str::FromStr;
+    let loc = hyper::Uri::from_str(location)?;
+    if loc.scheme().is_some() {
+        return Ok(loc);
+    }
+    let path = if location.starts_with('/') {
+        location.to_string()
+    } else {
+        let dir = base.path().rsplitn(2, '/').nth(1).unwrap_or("");
+        format!("{}/{}", dir, location)
+    };
+    let mut parts = hyper::http::uri::Parts::default();
+    parts.scheme = base.scheme().cloned();
+    parts.authority = base.authority().cloned();
+    parts.path_and_query = Some(hyper::http::uri::PathAndQuery::from_str(&path)?);
+    Ok(hyper::Uri::from_parts(parts)?)
+}
+
 pub async fn do_download<
     'a,
     Req: Serialize + std::fmt::Debug,
     Resp: DeserializeOwned + std::fmt::Debug,
+    C: HttpClient,
 >(
-    cl: &'a TlsClient,
+    cl: &'a C,
     path: &str,
     headers: Vec<(hyper::header::HeaderName, String)>,
     http_method: String,
     rq: Option<&'a Req>,
-) -> Result<Download<'a, Req, Resp>> {
+) -> Result<Download<'a, Req, Resp, C>> {
+    check_url_length(cl, path)?;
     use std::str::FromStr;
     Ok(Download {
         cl: cl,
@@ -304,18 +727,132 @@ pub async fn do_download<
         uri: hyper::Uri::from_str(path)?,
         rq: rq,
         headers: headers,
+        cancel: None,
+        progress: None,
+        range: None,
         _marker: Default::default(),
     })
 }
 
+/// The session of a resumable upload, which can be persisted to continue the upload after a
+/// restart; see `ResumableUpload::session()` and `ResumableUpload::resume()`.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct UploadSession {
+    /// The session URI returned by the server when the upload was started. It is valid for about
+    /// a week, and grants access to the upload without credentials.
+    pub uri: String,
+}
+
+impl std::fmt::Debug for UploadSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadSession")
+            .field("uri", &redact_url(&self.uri))
+            .finish()
+    }
+}
+
 /// A resumable upload in progress, useful for sending large objects.
-pub struct ResumableUpload<'client, Response: DeserializeOwned> {
+pub struct ResumableUpload<'client, Response: DeserializeOwned, C = TlsClient> {
     dest: hyper::Uri,
-    cl: &'client TlsClient,
+    cl: &'client C,
     max_chunksize: usize,
+    adaptive_max_chunksize: Option<usize>,
+    cancel: Option<CancellationToken>,
+    /// Whether the server may already have received data, which is then not sent again.
+    resumed: bool,
     _resp: std::marker::PhantomData<Response>,
 }
 
+/// Chunks of a resumable upload (except the last one) must be multiples of this size.
+pub const CHUNK_GRANULARITY: usize = 256 * 1024;
+
+/// In adaptive mode, chunks are sized to take about this long to send.
+const ADAPTIVE_CHUNK_DURATION: std::time::Duration = std::time::Duration::from_secs(8);
+/// In adaptive mode, a chunk is retried this many times after transient errors.
+const ADAPTIVE_MAX_RETRIES: usize = 3;
+
+/// Chooses the size of each chunk of an upload. In adaptive mode, the size grows while chunks
+/// are sent quickly, and shrinks when they are slow or fail.
+struct ChunkTuner {
+    size: usize,
+    max: Option<usize>,
+    failures: usize,
+}
+
+impl ChunkTuner {
+    fn next(&self, remaining: usize) -> usize {
+        remaining.min(self.size)
+    }
+
+    fn record_success(&mut self, bytes: usize, elapsed: std::time::Duration) {
+        self.failures = 0;
+        let max = match self.max {
+            Some(max) => max,
+            None => return,
+        };
+        if bytes < self.size {
+            return;
+        }
+        if elapsed < ADAPTIVE_CHUNK_DURATION / 2 {
+            self.size = (self.size * 2).min(max);
+        } else if elapsed > ADAPTIVE_CHUNK_DURATION * 2 {
+            self.shrink();
+        }
+    }
+
+    /// Returns true if the failed chunk should be retried.
+    fn record_failure(&mut self) -> bool {
+        if self.max.is_none() || self.failures >= ADAPTIVE_MAX_RETRIES {
+            return false;
+        }
+        self.failures += 1;
+        self.shrink();
+        true
+    }
+
+    fn shrink(&mut self) {
+        self.size =
+            ((self.size / 2) / CHUNK_GRANULARITY * CHUNK_GRANULARITY).max(CHUNK_GRANULARITY);
+    }
+}
+
+/// The `HTTPResponseError` for an unsuccessful response. If the server asked to wait before
+/// retrying, the delay is attached as `RetryAfter` context.
+pub(crate) fn response_error(
+    status: hyper::StatusCode,
+    headers: &hyper::HeaderMap,
+    body: hyper::body::Bytes,
+) -> Error {
+    let err = Error::from(ApiError::HTTPResponseError(status, body_to_str(&body)));
+    match parse_retry_after(headers) {
+        Some(delay) => err.context(RetryAfter(delay)),
+        None => err,
+    }
+}
+
+/// The delay requested by a `Retry-After` header, given in seconds or as a date.
+fn parse_retry_after(headers: &hyper::HeaderMap) -> Option<std::time::Duration> {
+    let value = headers
+        .get(hyper::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(secs));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        date.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+fn is_transient(status: hyper::StatusCode) -> bool {
+    status.is_server_error() || status == hyper::StatusCode::TOO_MANY_REQUESTS
+}
+
 fn format_content_range(from: usize, to: usize, total: usize) -> String {
     format!("bytes {}-{}/{}", from, to, total)
 }
@@ -336,21 +873,61 @@ fn parse_response_range(rng: &str) -> Option<(usize, usize)> {
     }
 }
 
-impl<'client, Response: DeserializeOwned> ResumableUpload<'client, Response> {
+impl<'client, Response: DeserializeOwned, C: HttpClient> ResumableUpload<'client, Response, C> {
+    /// `max_chunksize` is rounded down to a multiple of 256 KiB.
     pub fn new(
         to: hyper::Uri,
-        cl: &'client TlsClient,
+        cl: &'client C,
         max_chunksize: usize,
-    ) -> ResumableUpload<'client, Response> {
+    ) -> ResumableUpload<'client, Response, C> {
+        let rounded =
+            (max_chunksize / CHUNK_GRANULARITY * CHUNK_GRANULARITY).max(CHUNK_GRANULARITY);
+        if rounded != max_chunksize {
+            warn!(
+                "ResumableUpload: max_chunksize {} is not a multiple of 256 KiB; using {}",
+                max_chunksize, rounded
+            );
+        }
         ResumableUpload {
             dest: to,
             cl: cl,
-            max_chunksize: max_chunksize,
+            max_chunksize: rounded,
+            adaptive_max_chunksize: None,
+            cancel: None,
+            resumed: false,
             _resp: Default::default(),
         }
     }
+
+    /// Continue an upload started earlier, possibly by another process. `upload()` and
+    /// `upload_file()` ask the server how much data it has committed, and only send the rest.
+    pub fn resume(
+        session: &UploadSession,
+        cl: &'client C,
+        max_chunksize: usize,
+    ) -> Result<ResumableUpload<'client, Response, C>> {
+        let mut upload = ResumableUpload::new(session.uri.parse()?, cl, max_chunksize);
+        upload.resumed = true;
+        Ok(upload)
+    }
+
+    /// The session of this upload, to be stored for resuming it later.
+    pub fn session(&self) -> UploadSession {
+        UploadSession {
+            uri: self.dest.to_string(),
+        }
+    }
+
+    /// Ask the server how many bytes of the upload (of `size` bytes in total) it has committed.
+    /// Returns `size` if the upload is already complete.
+    pub async fn committed_offset(&self, size: usize) -> Result<usize> {
+        match self.query_received(size).await? {
+            Ok(received) => Ok(received),
+            Err(_) => Ok(size),
+        }
+    }
     pub fn set_max_chunksize(&mut self, size: usize) -> Result<&mut Self> {
-        if size % (1024 * 256) != 0 {
+        if size == 0 || size % CHUNK_GRANULARITY != 0 {
             Err(ApiError::InputDataError(
                 "ResumableUpload: max_chunksize must be multiple of 256 KiB.".into(),
             )
@@ -361,8 +938,161 @@ impl<'client, Response: DeserializeOwned> ResumableUpload<'client, Response> {
         }
     }
 
+    /// Enable adaptive chunk sizes: starting at `max_chunksize`, the chunk size is doubled (up
+    /// to `limit`) while chunks are sent quickly, and halved (down to 256 KiB) when they are
+    /// slow. Chunks failing with a transient error (5xx, 429, or connection errors) are retried
+    /// a few times with a smaller size, instead of aborting the upload. `None` disables
+    /// adaptive mode.
+    pub fn set_adaptive_chunksize(&mut self, limit: Option<usize>) -> Result<&mut Self> {
+        match limit {
+            Some(l) if l == 0 || l % CHUNK_GRANULARITY != 0 => Err(ApiError::InputDataError(
+                "ResumableUpload: chunk size limit must be multiple of 256 KiB.".into(),
+            )
+            .into()),
+            _ => {
+                self.adaptive_max_chunksize = limit;
+                Ok(self)
+            }
+        }
+    }
+
+    /// Stop the upload when `token` is cancelled. It is checked before sending each chunk; the
+    /// upload then fails with `ApiError::CancelledError` and the number of bytes the server has
+    /// received. The upload can be continued later using the same upload URL.
+    pub fn set_cancellation(&mut self, token: CancellationToken) -> &mut Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    fn tuner(&self) -> ChunkTuner {
+        ChunkTuner {
+            size: self.max_chunksize,
+            max: self
+                .adaptive_max_chunksize
+                .map(|m| m.max(self.max_chunksize)),
+            failures: 0,
+        }
+    }
+
+    /// Ask the server how many bytes it has received so far, after an interrupted chunk. If the
+    /// upload turns out to be complete, the final response is returned instead.
+    async fn query_received(
+        &self,
+        size: usize,
+    ) -> Result<std::result::Result<usize, hyper::Response<hyper::Body>>> {
+        let request = hyper::Request::builder()
+            .uri(self.dest.clone())
+            .method(hyper::Method::PUT)
+            .header(hyper::header::CONTENT_LENGTH, 0)
+            .header(hyper::header::CONTENT_RANGE, format!("bytes */{}", size))
+            .body(hyper::Body::empty())?;
+        let response = self.cl.send(tag_request(request)).await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(Err(response));
+        }
+        // 308 means: the upload is incomplete. Other statuses are errors, e.g. 404 for an expired
+        // session.
+        if status.as_u16() != 308 {
+            let headers = response.headers().clone();
+            let body = read_body(response, self.cl.max_response_size()).await?;
+            return Err(response_error(status, &headers, body));
+        }
+        Ok(Ok(Self::received_until(&response, 0, 0)?))
+    }
+
+    /// Send one chunk starting at `current`. Returns the response, or `None` if the chunk failed
+    /// transiently and should be retried (in adaptive mode).
+    async fn send_chunk(
+        &self,
+        buf: Vec<u8>,
+        current: usize,
+        size: usize,
+        tuner: &mut ChunkTuner,
+    ) -> Result<Option<hyper::Response<hyper::Body>>> {
+        let len = buf.len();
+        let reqb = hyper::Request::builder()
+            .uri(self.dest.clone())
+            .method(hyper::Method::PUT)
+            .header(hyper::header::CONTENT_LENGTH, len)
+            .header(
+                hyper::header::CONTENT_RANGE,
+                format_content_range(current, current + len - 1, size),
+            )
+            .header(hyper::header::CONTENT_TYPE, "application/octet-stream");
+        let request = reqb.body(hyper::Body::from(buf))?;
+        debug!(
+            "upload_file: Launching HTTP request: {:?}",
+            RedactedRequest(&request)
+        );
+
+        let start = std::time::Instant::now();
+        let response = match self.cl.send(tag_request(request)).await {
+            Ok(r) => r,
+            Err(e) if tuner.record_failure() => {
+                warn!("upload_file: Retrying chunk after error: {}", e);
+                return Ok(None);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        debug!("upload_file: Received response: {:?}", response);
+
+        let status = response.status();
+        // 308 means: continue upload.
+        if !status.is_success() && status.as_u16() != 308 {
+            debug!("upload_file: Encountered error: {}", status);
+            let retry_after = parse_retry_after(response.headers());
+            if is_transient(status) && tuner.record_failure() {
+                warn!("upload_file: Retrying chunk after status {}", status);
+                if let Some(delay) = retry_after {
+                    tokio::time::delay_for(delay).await;
+                }
+                return Ok(None);
+            }
+            let headers = response.headers().clone();
+            let body = read_body(response, self.cl.max_response_size()).await?;
+            return Err(response_error(status, &headers, body));
+        }
+        tuner.record_success(len, start.elapsed());
+        Ok(Some(response))
+    }
+
+    /// Position up to which the server has received data, according to `response`.
+    fn received_until(
+        response: &hyper::Response<hyper::Body>,
+        current: usize,
+        sent: usize,
+    ) -> Result<usize> {
+        if let Some(rng) = response.headers().get(hyper::header::RANGE) {
+            if let Some((_, to)) = parse_response_range(rng.to_str()?) {
+                return Ok(to + 1);
+            }
+        }
+        // This can also happen if response code is 200.
+        Ok(current + sent)
+    }
+
+    async fn finish(&self, response: hyper::Response<hyper::Body>) -> Result<Response> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let response_body = read_body(response, self.cl.max_response_size()).await?;
+        json_trace::trace_json("upload_file: response body", &response_body);
+
+        if !status.is_success() {
+            Err(response_error(status, &headers, response_body).context(format!("{:?}", headers)))
+        } else {
+            serde_json::from_slice(&response_body).map_err(|e| {
+                anyhow::Error::from(e)
+                    .context(body_to_str(&response_body))
+                    .context(format!("{:?}", headers))
+            })
+        }
+    }
+
     /// Upload data from a reader; use only if the reader cannot be seeked. Memory usage is higher,
-    /// because data needs to be cached if the server hasn't accepted all data.
+    /// because data needs to be cached if the server hasn't accepted all data. When resuming, the
+    /// reader still has to start at the beginning of the data; the part already committed is
+    /// skipped.
     pub async fn upload<R: tokio::io::AsyncRead + std::marker::Unpin>(
         &self,
         mut f: R,
@@ -370,16 +1100,22 @@ impl<'client, Response: DeserializeOwned> ResumableUpload<'client, Response> {
     ) -> Result<Response> {
         use tokio::io::AsyncReadExt;
 
+        let mut tuner = self.tuner();
         // Cursor to current position in stream.
         let mut current = 0;
+        if self.resumed {
+            current = match self.query_received(size).await? {
+                Ok(received) => received,
+                Err(response) => return self.finish(response).await,
+            };
+            // Skip the data the server already has.
+            tokio::io::copy(&mut (&mut f).take(current as u64), &mut tokio::io::sink()).await?;
+        }
         // Buffer portion that we couldn't send previously.
-        let mut previously_unsent = None;
+        let mut previously_unsent: Option<Vec<u8>> = None;
         loop {
-            let chunksize = if (size - current) > self.max_chunksize {
-                self.max_chunksize
-            } else {
-                size - current
-            };
+            check_cancelled(&self.cancel, current as u64)?;
+            let chunksize = tuner.next(size - current);
 
             let mut buf: Vec<u8>;
             let read_from_stream;
@@ -393,69 +1129,43 @@ impl<'client, Response: DeserializeOwned> ResumableUpload<'client, Response> {
                 buf.resize(read_from_stream, 0);
             }
 
-            let reqb = hyper::Request::builder()
-                .uri(self.dest.clone())
-                .method(hyper::Method::PUT)
-                .header(hyper::header::CONTENT_LENGTH, read_from_stream)
-                .header(
-                    hyper::header::CONTENT_RANGE,
-                    format_content_range(current, current + read_from_stream - 1, size),
-                )
-                .header(hyper::header::CONTENT_TYPE, "application/octet-stream");
-            let request = reqb.body(hyper::Body::from(buf[..].to_vec()))?;
-            debug!("upload_file: Launching HTTP request: {:?}", request);
-
-            let response = self.cl.request(request).await?;
-            debug!("upload_file: Received response: {:?}", response);
-
-            let status = response.status();
-            // 308 means: continue upload.
-            if !status.is_success() && status.as_u16() != 308 {
-                debug!("upload_file: Encountered error: {}", status);
-                return Err(ApiError::HTTPResponseError(status, status.to_string())).context(
-                    body_to_str(hyper::body::to_bytes(response.into_body()).await?),
-                );
-            }
-
-            let sent;
-            if let Some(rng) = response.headers().get(hyper::header::RANGE) {
-                if let Some((_, to)) = parse_response_range(rng.to_str()?) {
-                    sent = to + 1 - current;
-                    if sent < read_from_stream {
-                        previously_unsent = Some(buf.split_off(sent));
+            let response = match self
+                .send_chunk(buf.clone(), current, size, &mut tuner)
+                .await?
+            {
+                Some(r) => r,
+                None => {
+                    // Resend whatever the server didn't receive.
+                    let received = match self.query_received(size).await? {
+                        Ok(received) => received.max(current).min(current + buf.len()),
+                        Err(response) => return self.finish(response).await,
+                    };
+                    let unsent = buf.split_off(received - current);
+                    if !unsent.is_empty() {
+                        previously_unsent = Some(unsent);
                     }
-                    current = to + 1;
-                } else {
-                    sent = read_from_stream;
-                    current += read_from_stream;
+                    current = received;
+                    continue;
                 }
-            } else {
-                sent = read_from_stream;
-                current += read_from_stream;
+            };
+
+            let received = Self::received_until(&response, current, read_from_stream)?;
+            let sent = received - current;
+            if sent < read_from_stream {
+                previously_unsent = Some(buf.split_off(sent));
             }
+            current = received;
 
             debug!(
                 "upload_file: Sent {} bytes (successful: {}) of total {} to {}",
-                chunksize, sent, size, self.dest
+                chunksize,
+                sent,
+                size,
+                redact_url(&self.dest.to_string())
             );
 
             if current >= size {
-                let headers = response.headers().clone();
-                let response_body = hyper::body::to_bytes(response.into_body()).await?;
-
-                if !status.is_success() {
-                    return Err(Error::from(ApiError::HTTPResponseError(
-                        status,
-                        body_to_str(response_body),
-                    ))
-                    .context(format!("{:?}", headers)));
-                } else {
-                    return serde_json::from_reader(response_body.as_ref()).map_err(|e| {
-                        anyhow::Error::from(e)
-                            .context(body_to_str(response_body))
-                            .context(format!("{:?}", headers))
-                    });
-                }
+                return self.finish(response).await;
             }
         }
     }
@@ -465,13 +1175,17 @@ impl<'client, Response: DeserializeOwned> ResumableUpload<'client, Response> {
         use tokio::io::AsyncReadExt;
 
         let len = f.metadata().await?.len() as usize;
+        let mut tuner = self.tuner();
         let mut current = 0;
-        loop {
-            let chunksize = if (len - current) > self.max_chunksize {
-                self.max_chunksize
-            } else {
-                len - current
+        if self.resumed {
+            current = match self.query_received(len).await? {
+                Ok(received) => received,
+                Err(response) => return self.finish(response).await,
             };
+        }
+        loop {
+            check_cancelled(&self.cancel, current as u64)?;
+            let chunksize = tuner.next(len - current);
 
             f.seek(std::io::SeekFrom::Start(current as u64)).await?;
 
@@ -480,68 +1194,407 @@ impl<'client, Response: DeserializeOwned> ResumableUpload<'client, Response> {
             let read_from_stream = f.read_exact(&mut buf).await?;
             buf.resize(read_from_stream, 0);
 
-            let reqb = hyper::Request::builder()
-                .uri(self.dest.clone())
-                .method(hyper::Method::PUT)
-                .header(hyper::header::CONTENT_LENGTH, read_from_stream)
-                .header(
-                    hyper::header::CONTENT_RANGE,
-                    format_content_range(current, current + read_from_stream - 1, len),
-                )
-                .header(hyper::header::CONTENT_TYPE, "application/octet-stream");
-            let request = reqb.body(hyper::Body::from(buf))?;
-            debug!("upload_file: Launching HTTP request: {:?}", request);
-
-            let response = self.cl.request(request).await?;
-            debug!("upload_file: Received response: {:?}", response);
-
-            let status = response.status();
-            // 308 means: continue upload.
-            if !status.is_success() && status.as_u16() != 308 {
-                debug!("upload_file: Encountered error: {}", status);
-                return Err(ApiError::HTTPResponseError(status, status.to_string())).context(
-                    body_to_str(hyper::body::to_bytes(response.into_body()).await?),
-                );
-            }
-
-            let sent;
-            if let Some(rng) = response.headers().get(hyper::header::RANGE) {
-                if let Some((_, to)) = parse_response_range(rng.to_str()?) {
-                    sent = to + 1 - current;
-                    current = to + 1;
-                } else {
-                    sent = read_from_stream;
-                    current += read_from_stream;
+            let response = match self.send_chunk(buf, current, len, &mut tuner).await? {
+                Some(r) => r,
+                None => {
+                    current = match self.query_received(len).await? {
+                        Ok(received) => received.max(current),
+                        Err(response) => return self.finish(response).await,
+                    };
+                    continue;
                 }
-            } else {
-                // This can also happen if response code is 200.
-                sent = read_from_stream;
-                current += read_from_stream;
-            }
+            };
+
+            let received = Self::received_until(&response, current, read_from_stream)?;
+            let sent = received - current;
+            current = received;
 
             debug!(
                 "upload_file: Sent {} bytes (successful: {}) of total {} to {}",
-                chunksize, sent, len, self.dest
+                chunksize,
+                sent,
+                len,
+                redact_url(&self.dest.to_string())
             );
 
             if current >= len {
-                let headers = response.headers().clone();
-                let response_body = hyper::body::to_bytes(response.into_body()).await?;
-
-                if !status.is_success() {
-                    return Err(Error::from(ApiError::HTTPResponseError(
-                        status,
-                        body_to_str(response_body),
-                    ))
-                    .context(format!("{:?}", headers)));
-                } else {
-                    return serde_json::from_reader(response_body.as_ref()).map_err(|e| {
-                        anyhow::Error::from(e)
-                            .context(body_to_str(response_body))
-                            .context(format!("{:?}", headers))
-                    });
-                }
+                return self.finish(response).await;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_chunk_tuner_fixed() {
+        let mut tuner = ChunkTuner {
+            size: 4 * CHUNK_GRANULARITY,
+            max: None,
+            failures: 0,
+        };
+        assert_eq!(tuner.next(10), 10);
+        assert_eq!(tuner.next(100 * CHUNK_GRANULARITY), 4 * CHUNK_GRANULARITY);
+        tuner.record_success(4 * CHUNK_GRANULARITY, std::time::Duration::from_millis(1));
+        assert_eq!(tuner.size, 4 * CHUNK_GRANULARITY);
+        tuner.record_success(4 * CHUNK_GRANULARITY, std::time::Duration::from_secs(60));
+        assert_eq!(tuner.size, 4 * CHUNK_GRANULARITY);
+        assert!(!tuner.record_failure());
+        assert_eq!(tuner.size, 4 * CHUNK_GRANULARITY);
+    }
+
+    #[test]
+    fn test_chunk_tuner_adaptive() {
+        let fast = ADAPTIVE_CHUNK_DURATION / 4;
+        let slow = ADAPTIVE_CHUNK_DURATION * 4;
+        let mut tuner = ChunkTuner {
+            size: 3 * CHUNK_GRANULARITY,
+            max: Some(10 * CHUNK_GRANULARITY),
+            failures: 0,
+        };
+
+        // Fast chunks grow the size up to the maximum; short (last) chunks don't count.
+        tuner.record_success(CHUNK_GRANULARITY, fast);
+        assert_eq!(tuner.size, 3 * CHUNK_GRANULARITY);
+        tuner.record_success(3 * CHUNK_GRANULARITY, fast);
+        assert_eq!(tuner.size, 6 * CHUNK_GRANULARITY);
+        tuner.record_success(6 * CHUNK_GRANULARITY, fast);
+        assert_eq!(tuner.size, 10 * CHUNK_GRANULARITY);
+        tuner.record_success(10 * CHUNK_GRANULARITY, ADAPTIVE_CHUNK_DURATION);
+        assert_eq!(tuner.size, 10 * CHUNK_GRANULARITY);
+
+        // Slow chunks shrink it, keeping multiples of the granularity.
+        tuner.record_success(10 * CHUNK_GRANULARITY, slow);
+        assert_eq!(tuner.size, 5 * CHUNK_GRANULARITY);
+        tuner.record_success(5 * CHUNK_GRANULARITY, slow);
+        assert_eq!(tuner.size, 2 * CHUNK_GRANULARITY);
+        tuner.record_success(2 * CHUNK_GRANULARITY, slow);
+        tuner.record_success(CHUNK_GRANULARITY, slow);
+        assert_eq!(tuner.size, CHUNK_GRANULARITY);
+    }
+
+    #[test]
+    fn test_chunk_tuner_failures() {
+        let mut tuner = ChunkTuner {
+            size: 8 * CHUNK_GRANULARITY,
+            max: Some(8 * CHUNK_GRANULARITY),
+            failures: 0,
+        };
+        for size in &[4, 2, 1] {
+            assert!(tuner.record_failure());
+            assert_eq!(tuner.size, size * CHUNK_GRANULARITY);
+        }
+        assert!(!tuner.record_failure());
+
+        // A successful chunk allows retries again.
+        tuner.record_success(CHUNK_GRANULARITY, ADAPTIVE_CHUNK_DURATION);
+        assert!(tuner.record_failure());
+        assert_eq!(tuner.size, CHUNK_GRANULARITY);
+    }
+
+    #[test]
+    fn test_is_google_host() {
+        for (uri, google) in &[
+            ("https://www.googleapis.com/drive/v3/files/x", true),
+            ("https://doc-0s-bk-docs.googleusercontent.com/docs/x", true),
+            ("https://googleusercontent.com/x", true),
+            ("http://www.googleapis.com/x", false),
+            ("https://evilgoogleapis.com/x", false),
+            ("https://googleapis.com.example.com/x", false),
+        ] {
+            assert_eq!(
+                is_google_host(&hyper::Uri::from_str(uri).unwrap()),
+                *google,
+                "{}",
+                uri
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = hyper::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+        headers.insert(hyper::header::RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(
+            parse_retry_after(&headers),
+            Some(std::time::Duration::from_secs(120))
+        );
+        headers.insert(
+            hyper::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(
+            parse_retry_after(&headers),
+            Some(std::time::Duration::from_secs(0))
+        );
+        headers.insert(hyper::header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), None);
+
+        let err = response_error(
+            hyper::StatusCode::TOO_MANY_REQUESTS,
+            &headers,
+            hyper::body::Bytes::new(),
+        );
+        assert_eq!(ApiError::retry_after(&err), None);
+        headers.insert(hyper::header::RETRY_AFTER, "3".parse().unwrap());
+        let err = response_error(
+            hyper::StatusCode::TOO_MANY_REQUESTS,
+            &headers,
+            hyper::body::Bytes::new(),
+        );
+        assert_eq!(
+            ApiError::retry_after(&err),
+            Some(std::time::Duration::from_secs(3))
+        );
+        assert!(err.downcast_ref::<ApiError>().is_some());
+    }
+
+    #[test]
+    fn test_read_body() {
+        let body = hyper::body::Bytes::from_static(b"{\"id\": \"a\"}");
+        let response = hyper::Response::new(hyper::Body::from(body.clone()));
+        let read =
+            futures::executor::block_on(read_body(response, DEFAULT_MAX_RESPONSE_SIZE)).unwrap();
+        assert_eq!(read, body);
+        assert_eq!(read.as_ptr(), body.as_ptr());
+
+        let chunks: Vec<std::io::Result<&'static str>> =
+            vec![Ok("{\"id\""), Ok(": "), Ok("\"a\"}")];
+        let response =
+            hyper::Response::new(hyper::Body::wrap_stream(futures::stream::iter(chunks)));
+        let read =
+            futures::executor::block_on(read_body(response, DEFAULT_MAX_RESPONSE_SIZE)).unwrap();
+        assert_eq!(read, body);
+
+        // Longer bodies are returned unchanged.
+        let chunks: Vec<std::io::Result<&'static str>> =
+            vec![Ok("{\"id\""), Ok(": "), Ok("\"a\"}")];
+        let long = hyper::Body::wrap_stream(futures::stream::iter(chunks));
+        let long = match futures::executor::block_on(read_body_within(long, 8, 0)).unwrap() {
+            Ok(_) => panic!("body longer than the limit read"),
+            Err(long) => long,
+        };
+        let read = futures::executor::block_on(hyper::body::to_bytes(long)).unwrap();
+        assert_eq!(read, body);
+        let response = hyper::Response::new(hyper::Body::from(body.clone()));
+        assert!(futures::executor::block_on(read_body(response, 8)).is_err());
+    }
+
+    #[test]
+    fn test_client_limits() {
+        let mock = MockClient::new();
+        mock.push(MockResponse::json(&serde_json::json!({"id": "a"})));
+        let client = LimitedClient::new(mock.clone()).with_max_response_size(4);
+        let err = futures::executor::block_on(do_request::<_, serde_json::Value, _>(
+            &client,
+            "https://www.googleapis.com/x",
+            &[],
+            "GET",
+            None::<EmptyRequest>,
+        ))
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::ResponseSizeLimitError(4))
+        ));
+
+        let client = LimitedClient::new(mock.clone()).with_max_url_length(20);
+        let err = futures::executor::block_on(do_request::<_, serde_json::Value, _>(
+            &client,
+            "https://www.googleapis.com/x",
+            &[],
+            "GET",
+            None::<EmptyRequest>,
+        ))
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::URLTooLongError(28, 20))
+        ));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_api_response() {
+        let mock = MockClient::new();
+        mock.push(
+            MockResponse::json(&serde_json::json!({"id": "a"}))
+                .with_header(hyper::header::ETAG, "\"v1\"")
+                .with_header(hyper::header::DATE, "Wed, 21 Oct 2020 07:28:00 GMT"),
+        );
+        let response: ApiResponse<serde_json::Value> =
+            futures::executor::block_on(do_request_with_response(
+                &mock,
+                "https://www.googleapis.com/x",
+                &[],
+                "GET",
+                None::<EmptyRequest>,
+            ))
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["id"], "a");
+        assert_eq!(response.etag(), Some("\"v1\""));
+        assert_eq!(response.content_length(), None);
+        assert_eq!(
+            response.date(),
+            Some(Utc.ymd(2020, 10, 21).and_hms(7, 28, 0))
+        );
+
+        mock.push(
+            MockResponse::json(&serde_json::json!({"id": "b"}))
+                .with_header(hyper::header::ETAG, "\"v2\""),
+        );
+        let response: ApiResponse<serde_json::Value> =
+            futures::executor::block_on(do_upload_multipart_with_response(
+                &mock,
+                "https://www.googleapis.com/upload/x?uploadType=multipart",
+                &[],
+                "POST",
+                Some(EmptyRequest {}),
+                hyper::body::Bytes::from_static(b"data"),
+                None,
+            ))
+            .unwrap();
+        assert_eq!(response.body["id"], "b");
+        assert_eq!(response.etag(), Some("\"v2\""));
+    }
+
+    #[test]
+    fn test_upload_content_type() {
+        let mock = MockClient::new();
+        mock.push(MockResponse::json(&serde_json::json!({"id": "a"})))
+            .push(MockResponse::json(&serde_json::json!({"id": "b"})));
+        let upload = |content_type| {
+            futures::executor::block_on(do_upload_multipart::<_, serde_json::Value, _>(
+                &mock,
+                "https://www.googleapis.com/upload/x?uploadType=multipart",
+                &[],
+                "POST",
+                Some(EmptyRequest {}),
+                hyper::body::Bytes::from_static(b"\x89PNG"),
+                content_type,
+            ))
+        };
+        upload(Some("image/png")).unwrap();
+        upload(None).unwrap();
+        let requests = mock.requests();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("Content-Type: image/png\n"), "{}", body);
+        let body = String::from_utf8_lossy(&requests[1].body);
+        assert_eq!(body.matches("Content-Type").count(), 1, "{}", body);
+
+        // A line break would let the content type add headers to the media part.
+        for content_type in &["image/png\nX-Evil: 1", "image/png\r\n"] {
+            let err = upload(Some(content_type)).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<ApiError>(),
+                Some(ApiError::InputDataError(_))
+            ));
+        }
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_resume_upload() {
+        let mock = MockClient::new();
+        let session = UploadSession {
+            uri: "https://www.googleapis.com/upload/x?upload_id=abc".into(),
+        };
+        let data = vec![7u8; 3 * CHUNK_GRANULARITY];
+        mock.push(MockResponse::status(308).with_header(
+            hyper::header::RANGE,
+            &format!("bytes=0-{}", 2 * CHUNK_GRANULARITY - 1),
+        ))
+        .push(MockResponse::json(&serde_json::json!({"id": "a"})));
+        let upload: ResumableUpload<serde_json::Value, _> =
+            ResumableUpload::resume(&session, &mock, CHUNK_GRANULARITY).unwrap();
+        assert_eq!(upload.session(), session);
+        let response = futures::executor::block_on(upload.upload(&data[..], data.len())).unwrap();
+        assert_eq!(response["id"], "a");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].headers[hyper::header::CONTENT_RANGE],
+            format!("bytes */{}", data.len()).as_str()
+        );
+        assert_eq!(
+            requests[1].headers[hyper::header::CONTENT_RANGE],
+            format_content_range(2 * CHUNK_GRANULARITY, data.len() - 1, data.len()).as_str()
+        );
+        assert_eq!(requests[1].body.len(), CHUNK_GRANULARITY);
+
+        mock.push(MockResponse::status(404));
+        assert!(futures::executor::block_on(upload.committed_offset(data.len())).is_err());
+        mock.push(MockResponse::json(&serde_json::json!({"id": "a"})));
+        assert_eq!(
+            futures::executor::block_on(upload.committed_offset(data.len())).unwrap(),
+            data.len()
+        );
+    }
+
+    #[test]
+    fn test_download_credentials() {
+        let download = |uri: &str| {
+            let mock = MockClient::new();
+            mock.push(
+                MockResponse::status(302)
+                    .with_header(hyper::header::LOCATION, "https://storage.example.com/data"),
+            )
+            .push(MockResponse::bytes("application/octet-stream", "data"));
+            let headers = vec![(hyper::header::AUTHORIZATION, "Bearer ya29.a".to_string())];
+            futures::executor::block_on(async {
+                let mut download: Download<EmptyRequest, EmptyResponse, _> =
+                    do_download(&mock, uri, headers, "GET".into(), None).await?;
+                download.do_it_to_buf(&mut vec![]).await
+            })
+            .unwrap();
+            mock.requests()
+                .iter()
+                .map(|r| r.headers.contains_key(hyper::header::AUTHORIZATION))
+                .collect::<Vec<bool>>()
+        };
+        // Emulators and test servers get the credentials, but not other hosts redirected to.
+        assert_eq!(download("http://localhost:8080/files/x"), vec![true, false]);
+        assert_eq!(
+            download("https://www.googleapis.com/drive/v3/files/x"),
+            vec![true, false]
+        );
+        assert_eq!(download("https://storage.example.com/x"), vec![true, true]);
+
+        let uri = |s: &str| hyper::Uri::from_str(s).unwrap();
+        assert!(keeps_credentials(
+            &uri("https://www.googleapis.com/a"),
+            &uri("https://doc-0s.googleusercontent.com/b")
+        ));
+        assert!(keeps_credentials(
+            &uri("http://localhost:8080/a"),
+            &uri("https://localhost:8080/b")
+        ));
+        assert!(!keeps_credentials(
+            &uri("https://localhost:8080/a"),
+            &uri("http://localhost:8080/b")
+        ));
+        assert!(!keeps_credentials(
+            &uri("http://localhost:8080/a"),
+            &uri("http://localhost:9090/b")
+        ));
+    }
+
+    #[test]
+    fn test_redirect_target() {
+        let base = hyper::Uri::from_str("https://www.googleapis.com/a/b?alt=media").unwrap();
+        for (loc, want) in &[
+            ("https://example.com/x", "https://example.com/x"),
+            ("/c/d?x=1", "https://www.googleapis.com/c/d?x=1"),
+            ("c", "https://www.googleapis.com/a/c"),
+        ] {
+            assert_eq!(redirect_target(&base, loc).unwrap().to_string(), *want);
+        }
+    }
+}
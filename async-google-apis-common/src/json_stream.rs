@@ -0,0 +1,193 @@
+//! Incremental decoding of large JSON responses.
+
+use crate::*;
+
+/// Extracts the elements of one array field from a JSON object arriving in chunks, so that they
+/// can be processed before the whole object has been received. This is useful for list responses
+/// with many items, e.g. `{"nextPageToken": "...", "changes": [...]}`.
+///
+/// The remaining fields of the object are returned by `finish()` once all input has been fed.
+pub struct JsonArrayDecoder {
+    field: Vec<u8>,
+    /// The object received so far, with the array left empty.
+    rest: Vec<u8>,
+    /// The array element currently being received.
+    elem: Vec<u8>,
+    /// Nesting depth within the object, or within the current element if `in_array` is set.
+    depth: usize,
+    in_array: bool,
+    in_string: bool,
+    escaped: bool,
+    /// The last string at the object's top level, and whether it was followed by a colon.
+    string: Vec<u8>,
+    key: Vec<u8>,
+    after_key: bool,
+}
+
+impl JsonArrayDecoder {
+    /// Create a decoder extracting the elements of the top-level array `field`.
+    pub fn new(field: &str) -> JsonArrayDecoder {
+        JsonArrayDecoder {
+            field: field.as_bytes().to_vec(),
+            rest: vec![],
+            elem: vec![],
+            depth: 0,
+            in_array: false,
+            in_string: false,
+            escaped: false,
+            string: vec![],
+            key: vec![],
+            after_key: false,
+        }
+    }
+
+    /// Feed the next chunk of input, and return the array elements completed by it.
+    pub fn feed<T: DeserializeOwned>(&mut self, chunk: &[u8]) -> Result<Vec<T>> {
+        let mut elems = vec![];
+        for &c in chunk {
+            if self.in_array {
+                self.feed_array(c, &mut elems)?;
+            } else {
+                self.feed_object(c);
+            }
+        }
+        Ok(elems)
+    }
+
+    /// Decode the object without the array's elements (the array is left empty), after all input
+    /// has been fed.
+    pub fn finish<T: DeserializeOwned>(self) -> Result<T> {
+        serde_json::from_slice(&self.rest).map_err(|e| {
+            anyhow::Error::from(e).context(String::from_utf8_lossy(&self.rest).to_string())
+        })
+    }
+
+    fn feed_array<T: DeserializeOwned>(&mut self, c: u8, elems: &mut Vec<T>) -> Result<()> {
+        if self.in_string {
+            self.elem.push(c);
+            self.scan_string(c);
+            return Ok(());
+        }
+        match c {
+            b'"' => {
+                self.in_string = true;
+                self.elem.push(c);
+            }
+            b'{' | b'[' => {
+                self.depth += 1;
+                self.elem.push(c);
+            }
+            b']' if self.depth == 0 => {
+                self.finish_elem(elems)?;
+                self.in_array = false;
+                self.depth = 1;
+                self.rest.extend_from_slice(b"[]");
+            }
+            b'}' | b']' => {
+                self.depth -= 1;
+                self.elem.push(c);
+            }
+            b',' if self.depth == 0 => self.finish_elem(elems)?,
+            _ => self.elem.push(c),
+        }
+        Ok(())
+    }
+
+    fn feed_object(&mut self, c: u8) {
+        if self.in_string {
+            self.rest.push(c);
+            if self.scan_string(c) {
+                if self.depth == 1 {
+                    self.key = std::mem::take(&mut self.string);
+                    self.after_key = false;
+                }
+                self.string.clear();
+            } else {
+                self.string.push(c);
+            }
+            return;
+        }
+        match c {
+            b'"' => self.in_string = true,
+            b':' if self.depth == 1 => self.after_key = true,
+            b'[' if self.depth == 1 && self.after_key && self.key == self.field => {
+                self.in_array = true;
+                self.depth = 0;
+                return;
+            }
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            b',' => self.after_key = false,
+            _ => {}
+        }
+        self.rest.push(c);
+    }
+
+    /// Track the end of a string. Returns true if `c` closes the string.
+    fn scan_string(&mut self, c: u8) -> bool {
+        if self.escaped {
+            self.escaped = false;
+        } else if c == b'\\' {
+            self.escaped = true;
+        } else if c == b'"' {
+            self.in_string = false;
+            return true;
+        }
+        false
+    }
+
+    fn finish_elem<T: DeserializeOwned>(&mut self, elems: &mut Vec<T>) -> Result<()> {
+        let elem = std::mem::take(&mut self.elem);
+        if elem.iter().all(u8::is_ascii_whitespace) {
+            return Ok(());
+        }
+        elems.push(serde_json::from_slice(&elem).map_err(|e| {
+            anyhow::Error::from(e).context(String::from_utf8_lossy(&elem).to_string())
+        })?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"{"kind": "drive#changeList", "items": [{"id": "a]\"}"}, {"id": "b", "items": [1, 2]} ,
+        {"id": "c"}], "nextPageToken": "tok\"en"}"#;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        id: String,
+    }
+
+    #[derive(Deserialize, Debug)]
+    #[serde(rename_all = "camelCase")]
+    struct List {
+        items: Vec<Item>,
+        next_page_token: String,
+    }
+
+    #[test]
+    fn test_json_array_decoder() {
+        // Split the input at every possible position.
+        for split in 0..DOC.len() {
+            let mut dec = JsonArrayDecoder::new("items");
+            let mut items: Vec<Item> = dec.feed(DOC[..split].as_bytes()).unwrap();
+            items.extend(dec.feed::<Item>(DOC[split..].as_bytes()).unwrap());
+            let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+            assert_eq!(ids, vec!["a]\"}", "b", "c"]);
+
+            let list: List = dec.finish().unwrap();
+            assert!(list.items.is_empty());
+            assert_eq!(list.next_page_token, "tok\"en");
+        }
+    }
+
+    #[test]
+    fn test_json_array_decoder_incomplete() {
+        let mut dec = JsonArrayDecoder::new("items");
+        let items: Vec<Item> = dec.feed(&DOC.as_bytes()[..60]).unwrap();
+        assert_eq!(items, vec![Item { id: "a]\"}".into() }]);
+        assert!(dec.finish::<List>().is_err());
+    }
+}
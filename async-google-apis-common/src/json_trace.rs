@@ -0,0 +1,90 @@
+//! Pretty-printed logging of JSON request and response bodies at trace level, for debugging
+//! e.g. surprising patch semantics. Only active with the `json-trace` feature; without it, the
+//! functions here do nothing.
+//!
+//! Values of well-known credential keys (`access_token`, `refresh_token`, ...) are always
+//! redacted. Install a redactor with `set_json_redactor()` to remove other sensitive data, like
+//! email addresses, before bodies are logged.
+
+use std::sync::Mutex;
+
+/// Modifies a JSON body in place before it is logged.
+pub type JsonRedactor = fn(&mut serde_json::Value);
+
+static REDACTOR: Mutex<Option<JsonRedactor>> = Mutex::new(None);
+
+/// Keys whose values are never logged.
+const CREDENTIAL_KEYS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+    "private_key",
+];
+
+/// Set the function applied to all bodies before logging them, or remove it with `None`. This
+/// applies to all requests made by this process.
+pub fn set_json_redactor(redactor: Option<JsonRedactor>) {
+    *REDACTOR.lock().unwrap() = redactor;
+}
+
+/// Log `body` pretty-printed at trace level, if it is JSON. `what` describes the body, e.g.
+/// `request body`.
+#[cfg(feature = "json-trace")]
+pub(crate) fn trace_json(what: &str, body: &[u8]) {
+    if !log::log_enabled!(log::Level::Trace) || body.is_empty() {
+        return;
+    }
+    let mut value: serde_json::Value = match serde_json::from_slice(body) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+    redact_credentials(&mut value);
+    if let Some(redactor) = *REDACTOR.lock().unwrap() {
+        redactor(&mut value);
+    }
+    if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+        log::trace!("{}:\n{}", what, pretty);
+    }
+}
+
+#[cfg(not(feature = "json-trace"))]
+#[inline]
+pub(crate) fn trace_json(_what: &str, _body: &[u8]) {}
+
+pub(crate) fn redact_credentials(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(obj) => {
+            for (k, v) in obj.iter_mut() {
+                if CREDENTIAL_KEYS.contains(&k.as_str()) {
+                    *v = serde_json::Value::String("[redacted]".into());
+                } else {
+                    redact_credentials(v);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_credentials),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_credentials() {
+        let mut v = serde_json::json!({
+            "access_token": "ya29.secret",
+            "files": [{"name": "a", "refresh_token": "1//secret"}],
+        });
+        redact_credentials(&mut v);
+        assert_eq!(
+            v,
+            serde_json::json!({
+                "access_token": "[redacted]",
+                "files": [{"name": "a", "refresh_token": "[redacted]"}],
+            })
+        );
+    }
+}
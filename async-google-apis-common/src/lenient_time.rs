@@ -0,0 +1,114 @@
+//! (De)serialization of optional timestamps, accepting variations of RFC 3339 found in API
+//! responses. Used by generated code as `#[serde(with = "lenient_time")]` for `date-time` fields.
+//!
+//! Timestamps are serialized in RFC 3339 format (`2020-06-01T12:30:45.123Z`). Besides strict
+//! RFC 3339, deserialization accepts a lowercase `t`/`z` or a space as separator, offsets without
+//! colon (`+0200`) or minutes (`+02`), timestamps without offset (taken as UTC), plain dates
+//! (midnight UTC), and empty strings (no timestamp).
+
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone};
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{DateTime, Utc};
+
+pub fn serialize<S: Serializer>(
+    v: &Option<DateTime<Utc>>,
+    s: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match v {
+        Some(v) => s.serialize_str(&v.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+        None => s.serialize_none(),
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    d: D,
+) -> std::result::Result<Option<DateTime<Utc>>, D::Error> {
+    match Option::<String>::deserialize(d)? {
+        None => Ok(None),
+        Some(s) if s.trim().is_empty() => Ok(None),
+        Some(s) => parse(&s)
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp: {}", s))),
+    }
+}
+
+/// Parse a timestamp in one of the formats described in the module documentation.
+pub fn parse(s: &str) -> Option<DateTime<Utc>> {
+    let mut s = s.trim().to_ascii_uppercase();
+    if s.len() > 10 && s.as_bytes()[10] == b' ' {
+        s.replace_range(10..11, "T");
+    }
+    // An offset with hours only, e.g. `+02`.
+    let b = s.as_bytes();
+    if s.len() > 19 && (b[s.len() - 3] == b'+' || b[s.len() - 3] == b'-') {
+        s.push_str(":00");
+    }
+
+    if let Ok(t) = DateTime::parse_from_rfc3339(&s) {
+        return Some(t.with_timezone(&Utc));
+    }
+    if let Ok(t) = DateTime::<FixedOffset>::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(t.with_timezone(&Utc));
+    }
+    if let Ok(t) = NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(Utc.from_utc_datetime(&t));
+    }
+    NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|t| Utc.from_utc_datetime(&t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    struct File {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(with = "super", default)]
+        modified: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn test_lenient_time() {
+        let t = Utc.with_ymd_and_hms(2020, 6, 1, 12, 30, 45).unwrap();
+        for s in &[
+            "2020-06-01T12:30:45Z",
+            "2020-06-01t12:30:45z",
+            "2020-06-01 12:30:45Z",
+            "2020-06-01T14:30:45+02:00",
+            "2020-06-01T14:30:45+0200",
+            "2020-06-01T14:30:45+02",
+            "2020-06-01T10:30:45-02:00",
+            "2020-06-01T12:30:45",
+        ] {
+            assert_eq!(parse(s), Some(t), "{}", s);
+        }
+        assert_eq!(
+            parse("2020-06-01T12:30:45.123456789Z"),
+            Some(t + chrono::Duration::nanoseconds(123456789))
+        );
+        assert_eq!(
+            parse("2020-06-01"),
+            Some(Utc.with_ymd_and_hms(2020, 6, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(parse("yesterday"), None);
+    }
+
+    #[test]
+    fn test_lenient_time_serde() {
+        let f: File = serde_json::from_str(r#"{"modified": "2020-06-01T12:30:45.5+00"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&f).unwrap(),
+            r#"{"modified":"2020-06-01T12:30:45.500Z"}"#
+        );
+        let f: File = serde_json::from_str(r#"{"modified": ""}"#).unwrap();
+        assert_eq!(f.modified, None);
+        let f: File = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(serde_json::to_string(&f).unwrap(), "{}");
+        assert!(serde_json::from_str::<File>(r#"{"modified": "x"}"#).is_err());
+    }
+}
@@ -1,15 +1,50 @@
 //! Common types, imports, and functions used by generated code, including HTTP requests and error
 //! types.
 
+mod apis;
+pub use apis::*;
+mod auth;
+pub use auth::*;
+mod cancel;
+pub use cancel::*;
+mod cassette;
+pub use cassette::*;
+mod client;
+pub use client::*;
+mod encoding;
+pub use encoding::*;
 mod error;
 pub use error::*;
+mod fault;
+pub use fault::*;
 mod http;
 pub use http::*;
+mod json_stream;
+pub use json_stream::*;
+mod json_trace;
+pub use json_trace::{set_json_redactor, JsonRedactor};
+mod mock;
+pub use mock::*;
+mod push;
+pub use push::*;
+mod redact;
+pub use redact::*;
+mod retry;
+pub use retry::*;
+mod standard_params;
+pub use standard_params::*;
+mod tenant;
+pub use tenant::*;
+mod trace;
+pub use trace::*;
 
+pub mod lenient_time;
 mod multipart;
+pub mod str_num;
 
 pub use hyper;
 pub use log::{debug, error, info, trace, warn};
+pub use radix64;
 pub use serde;
 pub use serde_json;
 pub use yup_oauth2;
@@ -19,8 +54,18 @@ pub use chrono::{DateTime, Utc};
 pub use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
 pub use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use std::collections::HashMap;
+pub use std::sync::Arc;
 pub use tokio::stream::StreamExt;
+pub use url::{form_urlencoded, Url};
+
+/// Connectors that services can send requests with, as a `hyper::Client<C>`. By default,
+/// services use `TlsConnr`; any other connector accepted by hyper works as well, e.g. from
+/// `hyper-tls`, or for Unix sockets.
+pub trait Connector: hyper::client::connect::Connect + Clone + Send + Sync + 'static {}
+impl<C: hyper::client::connect::Connect + Clone + Send + Sync + 'static> Connector for C {}
 
 pub type Authenticator = yup_oauth2::authenticator::Authenticator<TlsConnr>;
+/// The default client of services; `ClientOptions::build()` creates one with tuned connection
+/// settings.
 pub type TlsClient = hyper::Client<TlsConnr, hyper::Body>;
 pub type TlsConnr = hyper_rustls::HttpsConnector<hyper::client::HttpConnector>;
@@ -0,0 +1,251 @@
+//! An `HttpClient` answering requests with canned responses, for testing code that uses services
+//! without network access.
+//!
+//! ```ignore
+//! let client = MockClient::new();
+//! client.on("GET", "/drive/v3/files/abc", MockResponse::json(&json!({"id": "abc"})));
+//! let files = FilesService::new(client.clone(), StaticToken("token".into()));
+//! // ... run the code under test ...
+//! assert_eq!(client.requests()[0].query_param("fields").as_deref(), Some("id,name"));
+//! ```
+
+use crate::*;
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// A response served by `MockClient`.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: hyper::StatusCode,
+    pub headers: Vec<(hyper::header::HeaderName, String)>,
+    pub body: hyper::body::Bytes,
+}
+
+impl MockResponse {
+    /// A `200 OK` response with `value` as JSON body, like most API methods return.
+    pub fn json<T: Serialize>(value: &T) -> MockResponse {
+        MockResponse::bytes(
+            "application/json",
+            serde_json::to_vec(value).expect("MockResponse::json: value not serializable"),
+        )
+    }
+
+    /// A `200 OK` response with `body` of type `content_type`, e.g. downloaded media.
+    pub fn bytes<B: Into<hyper::body::Bytes>>(content_type: &str, body: B) -> MockResponse {
+        MockResponse {
+            status: hyper::StatusCode::OK,
+            headers: vec![(hyper::header::CONTENT_TYPE, content_type.into())],
+            body: body.into(),
+        }
+    }
+
+    /// An empty response with `status`, e.g. `204 No Content` for deletions.
+    pub fn status(status: u16) -> MockResponse {
+        MockResponse {
+            status: hyper::StatusCode::from_u16(status).expect("MockResponse: invalid status"),
+            headers: vec![],
+            body: Default::default(),
+        }
+    }
+
+    /// An error response with `status` and an error body like Google APIs send.
+    pub fn error(status: u16, reason: &str, message: &str) -> MockResponse {
+        let body = serde_json::json!({
+            "error": {
+                "code": status,
+                "message": message,
+                "errors": [{"domain": "global", "reason": reason, "message": message}],
+            }
+        });
+        MockResponse {
+            status: hyper::StatusCode::from_u16(status).expect("MockResponse: invalid status"),
+            ..MockResponse::json(&body)
+        }
+    }
+
+    pub fn with_header(mut self, name: hyper::header::HeaderName, value: &str) -> MockResponse {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    fn to_response(&self) -> Result<hyper::Response<hyper::Body>> {
+        let mut response = hyper::Response::builder().status(self.status);
+        for (name, value) in self.headers.iter() {
+            response = response.header(name, value.as_str());
+        }
+        Ok(response.body(hyper::Body::from(self.body.clone()))?)
+    }
+}
+
+/// A request received by `MockClient`, with its body.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: hyper::Method,
+    pub uri: hyper::Uri,
+    pub headers: hyper::HeaderMap,
+    pub body: hyper::body::Bytes,
+}
+
+impl RecordedRequest {
+    /// The decoded value of the query parameter `name`, if present.
+    pub fn query_param(&self, name: &str) -> Option<String> {
+        self.uri.query()?.split('&').find_map(|pair| {
+            let mut kv = pair.splitn(2, '=');
+            if kv.next()? != name {
+                return None;
+            }
+            let value = kv.next().unwrap_or("").replace('+', " ");
+            Some(
+                percent_encoding::percent_decode_str(&value)
+                    .decode_utf8_lossy()
+                    .into_owned(),
+            )
+        })
+    }
+
+    /// The body parsed as JSON, e.g. the metadata sent by `update()`.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+/// A route registered with `MockClient::on()`.
+#[derive(Debug)]
+struct Route {
+    method: hyper::Method,
+    path: String,
+    response: MockResponse,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<Route>,
+    queue: VecDeque<MockResponse>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// An `HttpClient` serving canned responses and recording the requests it receives; see the
+/// module documentation. Clones share responses and recorded requests.
+///
+/// Requests are answered by the most recently registered route matching them (see `on()`), or
+/// else by the next response queued with `push()`. Requests not answered either way fail.
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockClient {
+    pub fn new() -> MockClient {
+        MockClient::default()
+    }
+
+    /// Answer every `method` request whose URL path ends with `path` with `response`.
+    pub fn on(&self, method: &str, path: &str, response: MockResponse) -> &MockClient {
+        let method = hyper::Method::from_bytes(method.as_bytes()).expect("MockClient: bad method");
+        self.state.lock().unwrap().routes.push(Route {
+            method,
+            path: path.into(),
+            response,
+        });
+        self
+    }
+
+    /// Answer the next request not matching a route with `response`. Queued responses are used
+    /// in order.
+    pub fn push(&self, response: MockResponse) -> &MockClient {
+        self.state.lock().unwrap().queue.push_back(response);
+        self
+    }
+
+    /// All requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Forget recorded requests, keeping routes and queued responses.
+    pub fn clear_requests(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+}
+
+impl HttpClient for MockClient {
+    fn send(&self, request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        let state = self.state.clone();
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let recorded = RecordedRequest {
+                method: parts.method,
+                uri: parts.uri,
+                headers: parts.headers,
+                body: hyper::body::to_bytes(body).await?,
+            };
+            let mut state = state.lock().unwrap();
+            let routed = state
+                .routes
+                .iter()
+                .rev()
+                .find(|r| r.method == recorded.method && recorded.uri.path().ends_with(&r.path))
+                .map(|r| r.response.clone());
+            let response = match routed.or_else(|| state.queue.pop_front()) {
+                Some(response) => response,
+                None => {
+                    return Err(ApiError::InputDataError(format!(
+                        "MockClient: no response for {} {}",
+                        recorded.method,
+                        redact_url(&recorded.uri.to_string())
+                    ))
+                    .into())
+                }
+            };
+            debug!(
+                "MockClient: {} {} -> {}",
+                recorded.method,
+                redact_url(&recorded.uri.to_string()),
+                response.status
+            );
+            state.requests.push(recorded);
+            response.to_response()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(client: &MockClient, uri: &str) -> Result<hyper::Response<hyper::Body>> {
+        let request = hyper::Request::get(uri).body(hyper::Body::empty()).unwrap();
+        futures::executor::block_on(client.send(request))
+    }
+
+    #[test]
+    fn test_mock_client() {
+        let client = MockClient::new();
+        client
+            .on(
+                "GET",
+                "/files/a",
+                MockResponse::json(&serde_json::json!({"id": "a"})),
+            )
+            .push(MockResponse::status(204))
+            .push(MockResponse::error(404, "notFound", "File not found"));
+
+        let uri = "https://example.com/v1/files/a?fields=id%2Cname&q=a+b";
+        assert_eq!(get(&client, uri).unwrap().status(), 200);
+        assert_eq!(get(&client, uri).unwrap().status(), 200);
+        assert_eq!(get(&client, "https://example.com/b").unwrap().status(), 204);
+        assert_eq!(get(&client, "https://example.com/c").unwrap().status(), 404);
+        assert!(get(&client, "https://example.com/d").is_err());
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(
+            requests[0].query_param("fields").as_deref(),
+            Some("id,name")
+        );
+        assert_eq!(requests[0].query_param("q").as_deref(), Some("a b"));
+        assert_eq!(requests[0].query_param("pageToken"), None);
+        assert_eq!(requests[3].uri.path(), "/c");
+    }
+}
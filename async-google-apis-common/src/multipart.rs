@@ -5,14 +5,27 @@ use radix64::STD;
 use serde::Serialize;
 use std::io::Write;
 
+use crate::ApiError;
 use anyhow::Context;
 
 pub const MIME_BOUNDARY: &'static str = "PB0BHe6XN3O6Q4bpnWQgS1pKfMfglTZdifFvh8YIc2APj4Cz3C";
 
+/// `content_type` is the MIME type of `data`; if it is `None`, the server detects it. It must not
+/// contain line breaks, which would end its header within the request body.
 pub fn format_multipart<Req: Serialize + std::fmt::Debug>(
     req: &Req,
     data: Bytes,
+    content_type: Option<&str>,
 ) -> anyhow::Result<Bytes> {
+    if let Some(content_type) = content_type {
+        if content_type.contains(|c| c == '\r' || c == '\n') {
+            return Err(ApiError::InputDataError(format!(
+                "format_multipart: invalid content type {:?}",
+                content_type
+            ))
+            .into());
+        }
+    }
     let meta = serde_json::to_string(req).context(format!("{:?}", req))?;
     let mut buf = Vec::with_capacity(meta.len() + (1.5 * (data.len() as f64)) as usize);
 
@@ -25,6 +38,10 @@ pub fn format_multipart<Req: Serialize + std::fmt::Debug>(
 
     buf.write(format!("\n\n--{}\n", MIME_BOUNDARY).as_bytes())
         .unwrap();
+    if let Some(content_type) = content_type {
+        buf.write(format!("Content-Type: {}\n", content_type).as_bytes())
+            .unwrap();
+    }
     buf.write("Content-Transfer-Encoding: base64\n\n".as_bytes())
         .unwrap();
 
@@ -0,0 +1,306 @@
+//! Receiving push notifications of watch channels (`changes.watch`, `files.watch`,
+//! `events.watch`, ...).
+//!
+//! Google delivers notifications as POST requests to the channel's `address`, describing the
+//! notification in `X-Goog-*` headers. `Notification::from_headers()` parses them, and
+//! `NotificationHandler` is a complete endpoint: it checks the channel token, and passes
+//! notifications to a callback.
+//!
+//! ```ignore
+//! let handler = NotificationHandler::new(|n: Notification| async move {
+//!     info!("{} changed: {:?}", n.resource_id, n.resource_state);
+//!     Ok(())
+//! })
+//! .with_token("secret given to watch()");
+//! handler.serve(([0, 0, 0, 0], 8080).into()).await?;
+//! ```
+//!
+//! Google only delivers to HTTPS endpoints with a valid certificate; `serve()` speaks plain HTTP
+//! and is meant to run behind a TLS-terminating proxy or load balancer.
+
+use crate::*;
+
+use std::future::Future;
+
+/// What happened to the watched resource, from `X-Goog-Resource-State`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// Sent once when the channel has been created.
+    Sync,
+    Add,
+    Remove,
+    Update,
+    Trash,
+    Untrash,
+    /// The watched changes log has new entries.
+    Change,
+    Exists,
+    NotExists,
+    Other(String),
+}
+
+impl From<&str> for ResourceState {
+    fn from(s: &str) -> ResourceState {
+        match s {
+            "sync" => ResourceState::Sync,
+            "add" => ResourceState::Add,
+            "remove" => ResourceState::Remove,
+            "update" => ResourceState::Update,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            "change" => ResourceState::Change,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            other => ResourceState::Other(other.to_string()),
+        }
+    }
+}
+
+/// A push notification, as described by the `X-Goog-*` headers of its request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// The `id` given when creating the channel.
+    pub channel_id: String,
+    /// Increases with every notification of the channel; `1` for the `sync` notification.
+    pub message_number: u64,
+    /// Opaque ID of the watched resource, needed for stopping the channel.
+    pub resource_id: String,
+    pub resource_state: ResourceState,
+    /// API-version-specific URI of the watched resource.
+    pub resource_uri: Option<String>,
+    pub channel_expiration: Option<DateTime<Utc>>,
+    /// The `token` given when creating the channel.
+    pub channel_token: Option<String>,
+    /// Details about an `Update`, e.g. `content`, `properties`, `permissions`.
+    pub changed: Vec<String>,
+}
+
+impl Notification {
+    /// Parse the `X-Goog-*` headers of a notification request.
+    pub fn from_headers(headers: &hyper::HeaderMap) -> Result<Notification> {
+        let header = |name: &str| -> Result<Option<String>> {
+            match headers.get(name) {
+                None => Ok(None),
+                Some(v) => match v.to_str() {
+                    Ok(v) => Ok(Some(v.to_string())),
+                    Err(_) => Err(ApiError::InputDataError(format!(
+                        "Notification: header {} is not valid ASCII",
+                        name
+                    ))
+                    .into()),
+                },
+            }
+        };
+        let required = |name: &str| -> Result<String> {
+            header(name)?.ok_or_else(|| {
+                ApiError::InputDataError(format!("Notification: header {} missing", name)).into()
+            })
+        };
+
+        let message_number = required("x-goog-message-number")?;
+        let message_number = message_number.parse().map_err(|_| {
+            ApiError::InputDataError(format!(
+                "Notification: invalid message number {:?}",
+                message_number
+            ))
+        })?;
+        let channel_expiration = match header("x-goog-channel-expiration")? {
+            None => None,
+            Some(exp) => Some(
+                DateTime::parse_from_rfc2822(&exp)
+                    .map_err(|_| {
+                        ApiError::InputDataError(format!(
+                            "Notification: invalid channel expiration {:?}",
+                            exp
+                        ))
+                    })?
+                    .with_timezone(&Utc),
+            ),
+        };
+        Ok(Notification {
+            channel_id: required("x-goog-channel-id")?,
+            message_number,
+            resource_id: required("x-goog-resource-id")?,
+            resource_state: ResourceState::from(required("x-goog-resource-state")?.as_str()),
+            resource_uri: header("x-goog-resource-uri")?,
+            channel_expiration,
+            channel_token: header("x-goog-channel-token")?,
+            changed: header("x-goog-changed")?
+                .map(|c| c.split(',').map(|s| s.trim().to_string()).collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// An endpoint for push notifications, passing them to a callback; see the module
+/// documentation. Cloning is cheap; clones share the callback.
+pub struct NotificationHandler<F> {
+    callback: Arc<F>,
+    token: Option<Arc<String>>,
+}
+
+impl<F> Clone for NotificationHandler<F> {
+    fn clone(&self) -> Self {
+        NotificationHandler {
+            callback: self.callback.clone(),
+            token: self.token.clone(),
+        }
+    }
+}
+
+impl<F, Fut> NotificationHandler<F>
+where
+    F: Fn(Notification) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    /// Pass every valid notification to `callback`. If the callback fails, the request is
+    /// answered with status 500, and Google retries the notification later.
+    pub fn new(callback: F) -> NotificationHandler<F> {
+        NotificationHandler {
+            callback: Arc::new(callback),
+            token: None,
+        }
+    }
+
+    /// Reject notifications without this channel token (the `token` given when creating the
+    /// channel) with status 403.
+    pub fn with_token<S: Into<String>>(mut self, token: S) -> NotificationHandler<F> {
+        self.token = Some(Arc::new(token.into()));
+        self
+    }
+
+    /// Answer a notification request. Requests other than POST are answered with status 405,
+    /// and requests without valid notification headers with status 400.
+    pub async fn handle(
+        &self,
+        request: hyper::Request<hyper::Body>,
+    ) -> hyper::Response<hyper::Body> {
+        if request.method() != hyper::Method::POST {
+            return status_response(hyper::StatusCode::METHOD_NOT_ALLOWED);
+        }
+        let notification = match Notification::from_headers(request.headers()) {
+            Ok(n) => n,
+            Err(e) => {
+                warn!("NotificationHandler: rejecting request: {}", e);
+                return status_response(hyper::StatusCode::BAD_REQUEST);
+            }
+        };
+        if let Some(token) = self.token.as_ref() {
+            let valid = notification
+                .channel_token
+                .as_ref()
+                .map_or(false, |t| constant_time_eq(t.as_bytes(), token.as_bytes()));
+            if !valid {
+                warn!(
+                    "NotificationHandler: invalid token on channel {}",
+                    notification.channel_id
+                );
+                return status_response(hyper::StatusCode::FORBIDDEN);
+            }
+        }
+        let channel_id = notification.channel_id.clone();
+        match (self.callback)(notification).await {
+            Ok(()) => status_response(hyper::StatusCode::OK),
+            Err(e) => {
+                error!(
+                    "NotificationHandler: callback failed for channel {}: {}",
+                    channel_id, e
+                );
+                status_response(hyper::StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        }
+    }
+
+    /// Serve notifications over plain HTTP on `addr` until an error occurs.
+    pub async fn serve(self, addr: std::net::SocketAddr) -> Result<()> {
+        let make_service = hyper::service::make_service_fn(move |_| {
+            let handler = self.clone();
+            async move {
+                Ok::<_, std::convert::Infallible>(hyper::service::service_fn(move |request| {
+                    let handler = handler.clone();
+                    async move { Ok::<_, std::convert::Infallible>(handler.handle(request).await) }
+                }))
+            }
+        });
+        hyper::Server::try_bind(&addr)?.serve(make_service).await?;
+        Ok(())
+    }
+}
+
+fn status_response(status: hyper::StatusCode) -> hyper::Response<hyper::Body> {
+    let mut response = hyper::Response::new(hyper::Body::empty());
+    *response.status_mut() = status;
+    response
+}
+
+/// Compare without revealing the position of the first difference through timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(headers: &[(&str, &str)]) -> hyper::Request<hyper::Body> {
+        let mut builder = hyper::Request::post("/notify");
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(hyper::Body::empty()).unwrap()
+    }
+
+    const HEADERS: &[(&str, &str)] = &[
+        ("X-Goog-Channel-ID", "channel-1"),
+        ("X-Goog-Channel-Token", "secret"),
+        ("X-Goog-Channel-Expiration", "Tue, 19 Nov 2013 01:13:52 GMT"),
+        ("X-Goog-Resource-ID", "ret08u3rv24htgh289g"),
+        (
+            "X-Goog-Resource-URI",
+            "https://www.googleapis.com/drive/v3/files/ret08u3rv24htgh289g",
+        ),
+        ("X-Goog-Resource-State", "update"),
+        ("X-Goog-Changed", "content,properties"),
+        ("X-Goog-Message-Number", "10"),
+    ];
+
+    #[test]
+    fn test_from_headers() {
+        let n = Notification::from_headers(request(HEADERS).headers()).unwrap();
+        assert_eq!(n.channel_id, "channel-1");
+        assert_eq!(n.message_number, 10);
+        assert_eq!(n.resource_state, ResourceState::Update);
+        assert_eq!(n.channel_token.as_deref(), Some("secret"));
+        assert_eq!(n.changed, vec!["content", "properties"]);
+        assert_eq!(
+            n.channel_expiration.unwrap().to_rfc3339(),
+            "2013-11-19T01:13:52+00:00"
+        );
+
+        let missing = request(&HEADERS[..5]);
+        assert!(Notification::from_headers(missing.headers()).is_err());
+    }
+
+    #[test]
+    fn test_handle() {
+        let received = Arc::new(std::sync::Mutex::new(vec![]));
+        let r = received.clone();
+        let handler = NotificationHandler::new(move |n: Notification| {
+            r.lock().unwrap().push(n.message_number);
+            async { Ok(()) }
+        })
+        .with_token("secret");
+
+        let status = |req| futures::executor::block_on(handler.handle(req)).status();
+        assert_eq!(status(request(HEADERS)), hyper::StatusCode::OK);
+
+        let mut wrong_token = HEADERS.to_vec();
+        wrong_token[1] = ("X-Goog-Channel-Token", "guess");
+        assert_eq!(status(request(&wrong_token)), hyper::StatusCode::FORBIDDEN);
+        assert_eq!(
+            status(request(&HEADERS[2..])),
+            hyper::StatusCode::BAD_REQUEST
+        );
+        assert_eq!(*received.lock().unwrap(), vec![10]);
+    }
+}
@@ -0,0 +1,107 @@
+//! Keeping credentials out of logs and error messages.
+//!
+//! Access tokens are normally sent in the `Authorization` header, but the standard parameters
+//! `oauth_token`, `access_token` and `key` can put credentials into URLs as well. Everything this
+//! crate logs or puts into errors goes through `redact_url()` or `RedactedRequest`, and the
+//! `Authorization` header of outgoing requests is marked as sensitive, so that `hyper` doesn't
+//! show its value in `Debug` output either. Types holding credentials (`StaticToken`,
+//! `UploadSession`, `StandardParams`, and the generated API parameters like `DriveParams`) don't
+//! show them in their `Debug` output.
+
+use crate::*;
+
+/// Query parameters whose values are credentials. The `upload_id` of a resumable upload grants
+/// access to the upload.
+const CREDENTIAL_PARAMS: &[&str] = &["oauth_token", "access_token", "key", "upload_id"];
+
+/// `url` with the values of credential query parameters replaced by `redacted`.
+pub fn redact_url(url: &str) -> String {
+    let (base, query) = match url.find('?') {
+        Some(i) => (&url[..i], &url[i + 1..]),
+        None => return url.to_string(),
+    };
+    let redacted: Vec<String> = query
+        .split('&')
+        .map(|pair| match pair.split('=').next() {
+            Some(name) if CREDENTIAL_PARAMS.contains(&name) => format!("{}=redacted", name),
+            _ => pair.to_string(),
+        })
+        .collect();
+    format!("{}?{}", base, redacted.join("&"))
+}
+
+/// Mark credential headers (`Authorization`, `Proxy-Authorization`) as sensitive, which hides
+/// their values in `Debug` output.
+pub(crate) fn protect_headers(headers: &mut hyper::HeaderMap) {
+    for name in &[
+        hyper::header::AUTHORIZATION,
+        hyper::header::PROXY_AUTHORIZATION,
+    ] {
+        let values: Vec<hyper::header::HeaderValue> =
+            headers.get_all(name).iter().cloned().collect();
+        headers.remove(name);
+        for mut value in values {
+            value.set_sensitive(true);
+            headers.append(name, value);
+        }
+    }
+}
+
+/// Formats a request for logging like its `Debug` implementation, but with credentials in the
+/// URL redacted.
+pub struct RedactedRequest<'a, B>(pub &'a hyper::Request<B>);
+
+impl<'a, B> std::fmt::Debug for RedactedRequest<'a, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("method", self.0.method())
+            .field("uri", &redact_url(&self.0.uri().to_string()))
+            .field("version", &self.0.version())
+            .field("headers", self.0.headers())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_request() {
+        assert_eq!(
+            redact_url("https://example.com/files?oauth_token=ya29.a&fields=id&key=k"),
+            "https://example.com/files?oauth_token=redacted&fields=id&key=redacted"
+        );
+        assert_eq!(
+            redact_url("https://example.com/files?keys=a"),
+            "https://example.com/files?keys=a"
+        );
+
+        let mut request = hyper::Request::get("https://example.com/f?access_token=ya29.b")
+            .header(hyper::header::AUTHORIZATION, "Bearer ya29.c")
+            .body(hyper::Body::empty())
+            .unwrap();
+        protect_headers(request.headers_mut());
+        let logged = format!("{:?}", RedactedRequest(&request));
+        assert!(!logged.contains("ya29"), "{}", logged);
+        assert!(logged.contains("access_token=redacted"), "{}", logged);
+        assert_eq!(
+            request.headers()[hyper::header::AUTHORIZATION],
+            "Bearer ya29.c"
+        );
+    }
+
+    #[test]
+    fn test_redacted_debug() {
+        let token = format!("{:?}", StaticToken("ya29.a".into()));
+        assert_eq!(token, "StaticToken(\"redacted\")");
+        let session = format!(
+            "{:?}",
+            UploadSession {
+                uri: "https://www.googleapis.com/upload/x?uploadType=resumable&upload_id=ABC"
+                    .into()
+            }
+        );
+        assert!(!session.contains("ABC"), "{}", session);
+    }
+}
@@ -0,0 +1,123 @@
+//! Retrying requests that failed transiently.
+
+use crate::*;
+
+use std::future::Future;
+use std::time::Duration;
+
+/// Retries an operation failing with transient errors: 5xx and 429 responses, as well as 403
+/// responses with reason `rateLimitExceeded` or `userRateLimitExceeded`. Before each retry, the
+/// policy waits for as long as the server asked to with a `Retry-After` header, or otherwise with
+/// truncated exponential backoff; either way for at most `max_backoff`.
+///
+/// ```ignore
+/// let list = RetryPolicy::default().run(|| files.list(&params)).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry without `Retry-After`; doubled for each further retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for all delays, including those requested with `Retry-After`.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(64),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether `err` is transient, and the operation should be retried.
+    pub fn is_retryable(err: &Error) -> bool {
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::HTTPResponseError(status, _)) => {
+                status.is_server_error()
+                    || *status == hyper::StatusCode::TOO_MANY_REQUESTS
+                    || ApiError::has_reason(err, "rateLimitExceeded")
+                    || ApiError::has_reason(err, "userRateLimitExceeded")
+            }
+            _ => false,
+        }
+    }
+
+    /// How long to wait before retry number `retry` (starting at 0) after `err`.
+    pub fn delay(&self, err: &Error, retry: u32) -> Duration {
+        let delay = match ApiError::retry_after(err) {
+            Some(delay) => delay,
+            None => self
+                .initial_backoff
+                .checked_mul(1 << retry.min(16))
+                .unwrap_or(self.max_backoff),
+        };
+        delay.min(self.max_backoff)
+    }
+
+    /// Run `op` until it succeeds, fails with an error that isn't retryable, or `max_attempts`
+    /// attempts have been made. Returns the last error in the latter cases.
+    pub async fn run<T, F, Fut>(&self, mut op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut retry = 0;
+        loop {
+            match op().await {
+                Ok(v) => return Ok(v),
+                Err(e) if retry + 1 < self.max_attempts && Self::is_retryable(&e) => {
+                    let delay = self.delay(&e, retry);
+                    warn!("RetryPolicy: retrying in {:?} after error: {}", delay, e);
+                    tokio::time::delay_for(delay).await;
+                    retry += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy() {
+        let policy = RetryPolicy::default();
+        let unavailable = Error::from(ApiError::HTTPResponseError(
+            hyper::StatusCode::SERVICE_UNAVAILABLE,
+            String::new(),
+        ));
+        assert!(RetryPolicy::is_retryable(&unavailable));
+        assert_eq!(policy.delay(&unavailable, 0), Duration::from_secs(1));
+        assert_eq!(policy.delay(&unavailable, 3), Duration::from_secs(8));
+        assert_eq!(policy.delay(&unavailable, 10), Duration::from_secs(64));
+
+        let throttled = unavailable.context(RetryAfter(Duration::from_secs(30)));
+        assert!(RetryPolicy::is_retryable(&throttled));
+        assert_eq!(policy.delay(&throttled, 0), Duration::from_secs(30));
+        // Long delays requested by the server are capped.
+        let throttled = Error::from(ApiError::HTTPResponseError(
+            hyper::StatusCode::TOO_MANY_REQUESTS,
+            String::new(),
+        ))
+        .context(RetryAfter(Duration::from_secs(3600)));
+        assert_eq!(policy.delay(&throttled, 0), Duration::from_secs(64));
+
+        let rate_limited = Error::from(ApiError::HTTPResponseError(
+            hyper::StatusCode::FORBIDDEN,
+            r#"{"error": {"code": 403, "message": "", "errors": [{"reason": "userRateLimitExceeded"}]}}"#.into(),
+        ));
+        assert!(RetryPolicy::is_retryable(&rate_limited));
+        let forbidden = Error::from(ApiError::HTTPResponseError(
+            hyper::StatusCode::FORBIDDEN,
+            String::new(),
+        ));
+        assert!(!RetryPolicy::is_retryable(&forbidden));
+    }
+}
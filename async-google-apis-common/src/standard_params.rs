@@ -0,0 +1,79 @@
+//! Standard query parameters accepted by all Google APIs.
+
+use crate::*;
+
+/// Standard parameters that every Google API accepts, set for all requests of a service with
+/// `set_standard_params()`. A parameter given in a call's parameters (e.g. in `DriveParams`)
+/// takes precedence over the value set here.
+#[derive(Clone, Default, PartialEq)]
+pub struct StandardParams {
+    /// Data format for the response, e.g. `json`.
+    pub alt: Option<String>,
+    /// Selector specifying which fields to include in a partial response.
+    pub fields: Option<String>,
+    /// API key, identifying the project for quota and billing. Required unless an OAuth 2.0 token
+    /// is provided.
+    pub key: Option<String>,
+    /// Returns responses with indentations and line breaks.
+    pub pretty_print: Option<bool>,
+    /// An opaque string that represents a user for quota purposes, of at most 40 characters.
+    pub quota_user: Option<String>,
+    /// IP address of the end user, for quota purposes. Deprecated in favor of `quota_user`.
+    pub user_ip: Option<String>,
+}
+
+impl std::fmt::Debug for StandardParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StandardParams")
+            .field("alt", &self.alt)
+            .field("fields", &self.fields)
+            .field("key", &self.key.as_ref().map(|_| "redacted"))
+            .field("pretty_print", &self.pretty_print)
+            .field("quota_user", &self.quota_user)
+            .field("user_ip", &self.user_ip)
+            .finish()
+    }
+}
+
+impl StandardParams {
+    /// Append the parameters that are set, and not yet contained in the query of `url`, to it.
+    pub fn append_to(&self, url: &mut Url) {
+        let pretty_print = self.pretty_print.map(|p| p.to_string());
+        let params = [
+            ("alt", self.alt.as_ref()),
+            ("fields", self.fields.as_ref()),
+            ("key", self.key.as_ref()),
+            ("prettyPrint", pretty_print.as_ref()),
+            ("quotaUser", self.quota_user.as_ref()),
+            ("userIp", self.user_ip.as_ref()),
+        ];
+        for (name, value) in params.iter() {
+            if let Some(value) = value {
+                if !url.query_pairs().any(|(n, _)| n == *name) {
+                    url.query_pairs_mut().append_pair(name, value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_to() {
+        let params = StandardParams {
+            key: Some("k&y".into()),
+            pretty_print: Some(false),
+            fields: Some("id,name".into()),
+            ..Default::default()
+        };
+        let mut url = Url::parse("https://example.com/files?fields=id").unwrap();
+        params.append_to(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/files?fields=id&key=k%26y&prettyPrint=false"
+        );
+    }
+}
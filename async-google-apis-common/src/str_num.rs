@@ -0,0 +1,65 @@
+//! (De)serialization of optional numbers that the APIs represent as JSON strings, e.g. 64 bit
+//! integers (`"size": "1234"`). Used by generated code as `#[serde(with = "str_num")]`.
+//!
+//! Numbers are serialized as strings; both strings and plain JSON numbers are accepted when
+//! deserializing.
+
+use serde::{Deserialize, Deserializer, Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StrOrNum<T> {
+    Num(T),
+    Str(String),
+}
+
+pub fn serialize<T: Display, S: Serializer>(
+    v: &Option<T>,
+    s: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match v {
+        Some(v) => s.collect_str(v),
+        None => s.serialize_none(),
+    }
+}
+
+pub fn deserialize<'de, T, D>(d: D) -> std::result::Result<Option<T>, D::Error>
+where
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    match Option::<StrOrNum<T>>::deserialize(d)? {
+        None => Ok(None),
+        Some(StrOrNum::Num(n)) => Ok(Some(n)),
+        Some(StrOrNum::Str(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    struct File {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(with = "super", default)]
+        size: Option<i64>,
+    }
+
+    #[test]
+    fn test_str_num() {
+        let f: File = serde_json::from_str(r#"{"size": "9007199254740993"}"#).unwrap();
+        assert_eq!(f.size, Some(9007199254740993));
+        assert_eq!(serde_json::to_string(&f).unwrap(), r#"{"size":"9007199254740993"}"#);
+
+        let f: File = serde_json::from_str(r#"{"size": 12}"#).unwrap();
+        assert_eq!(f.size, Some(12));
+        let f: File = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(f.size, None);
+        assert_eq!(serde_json::to_string(&f).unwrap(), "{}");
+        assert!(serde_json::from_str::<File>(r#"{"size": "12x"}"#).is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! `tracing` instrumentation of generated methods, enabled by the `tracing` feature.
+//!
+//! Each call of a generated method runs in an `info` span named `google_api`, with the fields
+//! `service` (e.g. `Files`), `method` (e.g. `get`), `path` (the request path relative to the
+//! API's base URL, containing IDs like the file ID), `latency_ms`, `ok`, and, for failed
+//! requests, the HTTP `status`. Calls made within `with_tenant()` also record the `tenant`. For
+//! methods returning a `Download` or `ResumableUpload`, the span covers only the initial request,
+//! not the transfer.
+
+use crate::*;
+
+use std::future::Future;
+
+/// Run `call`, the body of the generated method `service.method`, in a span.
+#[cfg(feature = "tracing")]
+pub async fn traced<T, F: Future<Output = Result<T>>>(
+    service: &'static str,
+    method: &'static str,
+    call: F,
+) -> Result<T> {
+    use tracing::Instrument;
+
+    let span = tracing::info_span!(
+        "google_api",
+        service,
+        method,
+        path = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+        ok = tracing::field::Empty,
+        status = tracing::field::Empty,
+        tenant = tracing::field::Empty,
+    );
+    if let Some(tenant) = current_tenant() {
+        span.record("tenant", &tenant.as_str());
+    }
+    let start = std::time::Instant::now();
+    let result = call.instrument(span.clone()).await;
+    span.record("latency_ms", &(start.elapsed().as_millis() as u64));
+    span.record("ok", &result.is_ok());
+    if let Err(e) = result.as_ref() {
+        if let Some(ApiError::HTTPResponseError(status, _)) = e.downcast_ref::<ApiError>() {
+            span.record("status", &status.as_u16());
+        }
+    }
+    result
+}
+
+#[cfg(not(feature = "tracing"))]
+pub async fn traced<T, F: Future<Output = Result<T>>>(
+    _service: &'static str,
+    _method: &'static str,
+    call: F,
+) -> Result<T> {
+    call.await
+}
+
+/// Record the request path in the span of the current generated method.
+pub fn trace_path(_path: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("path", &_path);
+}
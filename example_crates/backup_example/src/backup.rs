@@ -0,0 +1,260 @@
+//! Incremental backups of a local folder.
+//!
+//! A backup compares the size and modification time of every local file with the manifest, and
+//! only uploads new and modified files. Each file is encrypted into a blob, which is uploaded
+//! with a resumable upload into the blob folder; the blob of the previous version is deleted
+//! afterwards. Blobs that were deleted or trashed in Drive since the last backup (found in the
+//! changes log) are uploaded again.
+
+use crate::crypto::{self, Identity};
+use crate::manifest::{Entry, Manifest, ManifestStore};
+
+use async_google_apis_common::*;
+use drive_example::drive_v3_types as drive;
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Name of the Drive folder holding the blobs, created in the root folder by the first backup.
+pub const BLOB_FOLDER_NAME: &str = "Encrypted backup";
+/// The manifest is saved after this many uploads, so that an interrupted backup doesn't start
+/// from scratch.
+const SAVE_EVERY: usize = 50;
+/// Maximum chunk size of resumable uploads.
+const MAX_CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+/// What a backup did.
+#[derive(Debug, Clone, Default)]
+pub struct BackupStats {
+    pub uploaded: usize,
+    pub uploaded_bytes: u64,
+    pub unchanged: usize,
+    /// Files uploaded again because their blob had been removed from Drive.
+    pub repaired: usize,
+    /// Entries removed from the manifest (with `prune`) because the local file is gone.
+    pub pruned: usize,
+}
+
+/// A local file found by `scan()`.
+#[derive(Debug, Clone)]
+struct LocalFile {
+    size: u64,
+    modified: DateTime<Utc>,
+}
+
+pub struct Backup<'a> {
+    pub files: &'a drive::FilesService,
+    pub changes: &'a drive::ChangesService,
+    pub identity: &'a Identity,
+    pub retry: RetryPolicy,
+    /// Delete the blobs of files that don't exist locally anymore.
+    pub prune: bool,
+}
+
+impl<'a> Backup<'a> {
+    /// Back up the folder `root`.
+    pub async fn run(&self, root: &Path) -> Result<BackupStats> {
+        let mut store = ManifestStore::new(self.files, self.identity);
+        let mut manifest = store.load().await?;
+        let mut stats = BackupStats::default();
+
+        let folder = match manifest.blob_folder.clone() {
+            Some(folder) => folder,
+            None => {
+                let folder = self.files.create_folder(BLOB_FOLDER_NAME, None).await?;
+                let id = folder.id.ok_or_else(|| {
+                    ApiError::InputDataError("blob folder created without ID".into())
+                })?;
+                manifest.blob_folder = Some(id.clone());
+                id
+            }
+        };
+        let lost = self.lost_blobs(&manifest).await?;
+        // Changes made by this backup show up in the next one, but are harmless: the blobs
+        // deleted here aren't in the manifest anymore.
+        let next_token = self.start_page_token().await?;
+
+        let local = scan(root).await?;
+        info!("backup: {} local files in {}", local.len(), root.display());
+        for (path, file) in local.iter() {
+            let previous = manifest.entries.get(path).cloned();
+            let is_lost = previous
+                .as_ref()
+                .map_or(false, |e| lost.contains(&e.blob_id));
+            match previous.as_ref() {
+                Some(e) if !is_lost && e.size == file.size && e.modified == file.modified => {
+                    stats.unchanged += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            let blob_id = self
+                .upload(&mut manifest, &folder, &root.join(path))
+                .await
+                .map_err(|e| e.context(format!("backing up {}", path)))?;
+            manifest.entries.insert(
+                path.clone(),
+                Entry {
+                    blob_id,
+                    size: file.size,
+                    modified: file.modified,
+                },
+            );
+            if let Some(previous) = previous.filter(|_| !is_lost) {
+                self.delete_blob(&previous.blob_id).await?;
+            }
+            if is_lost {
+                stats.repaired += 1;
+            }
+            stats.uploaded += 1;
+            stats.uploaded_bytes += file.size;
+            if stats.uploaded % SAVE_EVERY == 0 {
+                store.save(&manifest).await?;
+            }
+        }
+
+        if self.prune {
+            let gone: Vec<String> = manifest
+                .entries
+                .keys()
+                .filter(|p| !local.contains_key(*p))
+                .cloned()
+                .collect();
+            for path in gone {
+                if let Some(entry) = manifest.entries.remove(&path) {
+                    self.delete_blob(&entry.blob_id).await?;
+                    stats.pruned += 1;
+                }
+            }
+        }
+
+        manifest.changes_token = next_token;
+        manifest.last_backup = Some(Utc::now());
+        store.save(&manifest).await?;
+        Ok(stats)
+    }
+
+    /// Encrypt the file at `path` and upload it as a new blob into `folder`. Returns the blob's
+    /// ID. The ciphertext is staged in a temporary file, so that neither the file nor its
+    /// ciphertext has to fit into memory, and so that failed uploads can be resumed.
+    async fn upload(
+        &self,
+        manifest: &mut Manifest,
+        folder: &str,
+        path: &Path,
+    ) -> Result<drive::FileId> {
+        let name = manifest.next_blob_name();
+        let ciphertext = crypto::TempFile::new(&name);
+        // Encryption blocks, but backups process one file at a time anyway.
+        crypto::encrypt_file(self.identity, path, ciphertext.path())?;
+
+        let mut req = drive::File::default();
+        req.name = Some(name);
+        req.parents = Some(vec![folder.into()]);
+        req.mime_type = Some("application/octet-stream".into());
+        let params = drive::FilesCreateParams::default();
+        let blob = self
+            .retry
+            .run(|| async {
+                let mut upload = self.files.create_resumable_upload(&params, &req).await?;
+                upload.set_adaptive_chunksize(Some(MAX_CHUNK_SIZE))?;
+                upload
+                    .upload_file(tokio::fs::File::open(ciphertext.path()).await?)
+                    .await
+            })
+            .await?;
+        debug!("backup: uploaded {} as {:?}", path.display(), blob.id);
+        blob.id
+            .ok_or_else(|| ApiError::InputDataError("blob uploaded without ID".into()).into())
+    }
+
+    /// Delete a blob, ignoring blobs that are already gone.
+    async fn delete_blob(&self, blob_id: &str) -> Result<()> {
+        let mut params = drive::FilesDeleteParams::default();
+        params.file_id = blob_id.into();
+        match self.retry.run(|| self.files.delete(&params)).await {
+            Err(e) if ApiError::is_not_found(&e) => Ok(()),
+            r => r,
+        }
+    }
+
+    /// IDs of blobs in the manifest that were deleted or trashed since the last backup.
+    async fn lost_blobs(&self, manifest: &Manifest) -> Result<HashSet<drive::FileId>> {
+        let token = match manifest.changes_token.as_ref() {
+            Some(token) => token,
+            None => return Ok(HashSet::new()),
+        };
+        let blobs: HashSet<&str> = manifest
+            .entries
+            .values()
+            .map(|e| e.blob_id.as_str())
+            .collect();
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields =
+            Some("nextPageToken,newStartPageToken,changes(fileId,removed,file(trashed))".into());
+        let mut params = drive::ChangesListParams::default();
+        params.drive_params = Some(general_params);
+        params.page_token = token.clone();
+
+        let mut lost = HashSet::new();
+        let mut stream = self.changes.stream(&params);
+        while let Some(change) = stream.next().await? {
+            let removed = change.removed == Some(true)
+                || change.file.as_ref().and_then(|f| f.trashed) == Some(true);
+            match change.file_id {
+                Some(id) if removed && blobs.contains(id.as_str()) => {
+                    warn!(
+                        "backup: blob {} was removed from Drive; uploading it again",
+                        id
+                    );
+                    lost.insert(id);
+                }
+                _ => {}
+            }
+        }
+        Ok(lost)
+    }
+
+    async fn start_page_token(&self) -> Result<Option<String>> {
+        let params = drive::ChangesGetStartPageTokenParams::default();
+        Ok(self
+            .changes
+            .get_start_page_token(&params)
+            .await?
+            .start_page_token)
+    }
+}
+
+/// All regular files below `root`, by path relative to `root` with `/` as separator. Symbolic
+/// links and files with names that aren't valid UTF-8 are skipped.
+async fn scan(root: &Path) -> Result<BTreeMap<String, LocalFile>> {
+    let mut files = BTreeMap::new();
+    let mut dirs = vec![(root.to_path_buf(), String::new())];
+    while let Some((dir, prefix)) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let name = match entry.file_name().into_string() {
+                Ok(name) => name,
+                Err(name) => {
+                    warn!("backup: skipping {:?} in {}", name, dir.display());
+                    continue;
+                }
+            };
+            let path = format!("{}{}", prefix, name);
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push((entry.path(), format!("{}/", path)));
+            } else if file_type.is_file() {
+                let meta = entry.metadata().await?;
+                files.insert(
+                    path,
+                    LocalFile {
+                        size: meta.len(),
+                        modified: DateTime::<Utc>::from(meta.modified()?),
+                    },
+                );
+            }
+        }
+    }
+    Ok(files)
+}
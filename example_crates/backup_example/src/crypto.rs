@@ -0,0 +1,203 @@
+//! Encryption of file contents and the manifest with age (https://age-encryption.org).
+//!
+//! Everything stored in Drive is encrypted to the public key of the identity given on the
+//! command line; only the holder of the identity file can read backups.
+
+use async_google_apis_common::*;
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub use age::x25519::Identity;
+
+/// Read an identity file as written by `age-keygen`: comment lines are skipped, the first
+/// `AGE-SECRET-KEY-` line is used.
+pub async fn read_identity(path: &std::path::Path) -> Result<Identity> {
+    let content = tokio::fs::read_to_string(path).await?;
+    let key = content
+        .lines()
+        .map(str::trim)
+        .find(|l| l.starts_with("AGE-SECRET-KEY-"));
+    match key {
+        Some(key) => Identity::from_str(key)
+            .map_err(|e| anyhow::anyhow!("invalid identity in {}: {}", path.display(), e)),
+        None => Err(anyhow::anyhow!("no identity found in {}", path.display())),
+    }
+}
+
+/// Encrypt everything read from `input` to the public key of `identity`, writing the
+/// ciphertext to `output` as it is produced. Returns `output`.
+pub fn encrypt_stream<R: Read, W: Write>(
+    identity: &Identity,
+    mut input: R,
+    output: W,
+) -> Result<W> {
+    let recipient: Box<dyn age::Recipient> = Box::new(identity.to_public());
+    let encryptor = age::Encryptor::with_recipients(vec![recipient]);
+    let mut writer = encryptor.wrap_output(output)?;
+    std::io::copy(&mut input, &mut writer)?;
+    Ok(writer.finish()?)
+}
+
+/// Decrypt the ciphertext read from `input`, writing at most `limit` bytes of plaintext to
+/// `output`. Returns the size of the plaintext, or an error if it exceeds `limit`.
+pub fn decrypt_stream<R: Read, W: Write>(
+    identity: &Identity,
+    input: R,
+    mut output: W,
+    limit: u64,
+) -> Result<u64> {
+    let decryptor = match age::Decryptor::new(input)? {
+        age::Decryptor::Recipients(d) => d,
+        age::Decryptor::Passphrase(_) => {
+            return Err(anyhow::anyhow!(
+                "data is encrypted with a passphrase, not to an identity"
+            ))
+        }
+    };
+    let reader = decryptor.decrypt(std::iter::once(identity as &dyn age::Identity))?;
+    let size = std::io::copy(&mut reader.take(limit.saturating_add(1)), &mut output)?;
+    if size > limit {
+        return Err(anyhow::anyhow!("plaintext is larger than {} bytes", limit));
+    }
+    output.flush()?;
+    Ok(size)
+}
+
+/// Encrypt `plaintext` to the public key of `identity`.
+pub fn encrypt(identity: &Identity, plaintext: &[u8]) -> Result<Vec<u8>> {
+    let ciphertext = Vec::with_capacity(plaintext.len() + plaintext.len() / 4096 + 1024);
+    encrypt_stream(identity, plaintext, ciphertext)
+}
+
+/// Decrypt `ciphertext` encrypted by `encrypt()`.
+pub fn decrypt(identity: &Identity, ciphertext: &[u8]) -> Result<Vec<u8>> {
+    let mut plaintext = vec![];
+    decrypt_stream(identity, ciphertext, &mut plaintext, u64::MAX)?;
+    Ok(plaintext)
+}
+
+/// Encrypt the file at `src` into a new file at `dst`, one chunk at a time.
+pub fn encrypt_file(identity: &Identity, src: &Path, dst: &Path) -> Result<()> {
+    let input = std::io::BufReader::new(std::fs::File::open(src)?);
+    let output = std::io::BufWriter::new(std::fs::File::create(dst)?);
+    encrypt_stream(identity, input, output)?.flush()?;
+    Ok(())
+}
+
+/// Decrypt the file at `src` into a new file at `dst`, one chunk at a time. Returns the size of
+/// the plaintext; if it differs from `expected_size`, `dst` is removed and an error returned.
+pub fn decrypt_file(
+    identity: &Identity,
+    src: &Path,
+    dst: &Path,
+    expected_size: u64,
+) -> Result<u64> {
+    let input = std::io::BufReader::new(std::fs::File::open(src)?);
+    let output = std::io::BufWriter::new(std::fs::File::create(dst)?);
+    let result = decrypt_stream(identity, input, output, expected_size).and_then(|size| {
+        if size == expected_size {
+            Ok(size)
+        } else {
+            Err(anyhow::anyhow!(
+                "decrypted to {} bytes, expected {}",
+                size,
+                expected_size
+            ))
+        }
+    });
+    if result.is_err() {
+        let _ = std::fs::remove_file(dst);
+    }
+    result
+}
+
+/// A file in the temporary directory holding ciphertext on its way to or from Drive. It is
+/// removed when dropped.
+pub struct TempFile(PathBuf);
+
+impl TempFile {
+    pub fn new(name: &str) -> TempFile {
+        TempFile(std::env::temp_dir().join(format!("backup-{}-{}", std::process::id(), name)))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> Identity {
+        Identity::generate()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let identity = identity();
+        // More than one 64 KiB chunk.
+        let plaintext: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        let ciphertext = encrypt(&identity, &plaintext).unwrap();
+        assert_ne!(&ciphertext[..], &plaintext[..]);
+        assert_eq!(decrypt(&identity, &ciphertext).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_decrypt_wrong_identity() {
+        let ciphertext = encrypt(&identity(), b"secret").unwrap();
+        assert!(decrypt(&identity(), &ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_stream_limit() {
+        let identity = identity();
+        let ciphertext = encrypt(&identity, b"0123456789").unwrap();
+        let mut out = vec![];
+        assert_eq!(
+            decrypt_stream(&identity, &ciphertext[..], &mut out, 10).unwrap(),
+            10
+        );
+        assert_eq!(out, b"0123456789");
+        assert!(decrypt_stream(&identity, &ciphertext[..], vec![], 9).is_err());
+    }
+
+    #[test]
+    fn test_files() {
+        let identity = identity();
+        let src = TempFile::new("test-src");
+        let encrypted = TempFile::new("test-encrypted");
+        let dst = TempFile::new("test-dst");
+        std::fs::write(src.path(), b"file content").unwrap();
+        encrypt_file(&identity, src.path(), encrypted.path()).unwrap();
+        assert_eq!(
+            decrypt_file(&identity, encrypted.path(), dst.path(), 12).unwrap(),
+            12
+        );
+        assert_eq!(std::fs::read(dst.path()).unwrap(), b"file content");
+
+        // A size mismatch leaves no partial file behind.
+        assert!(decrypt_file(&identity, encrypted.path(), dst.path(), 11).is_err());
+        assert!(!dst.path().exists());
+        assert!(decrypt_file(&identity, encrypted.path(), dst.path(), 13).is_err());
+        assert!(!dst.path().exists());
+    }
+
+    #[test]
+    fn test_temp_file_removed() {
+        let path = {
+            let f = TempFile::new("test-removed");
+            std::fs::write(f.path(), b"x").unwrap();
+            f.path().to_path_buf()
+        };
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,140 @@
+//! Client-side encrypted, incremental backups of a local folder to Google Drive.
+//!
+//! File contents are encrypted with age before they leave the machine, and stored as blobs with
+//! meaningless names in a Drive folder. The manifest mapping paths to blobs is encrypted as
+//! well, and kept in the application data folder. See `README.md` for usage.
+
+mod backup;
+mod crypto;
+mod manifest;
+mod restore;
+
+use async_google_apis_common as common;
+use drive_example::drive_v3_types as drive;
+
+use std::path::Path;
+use std::sync::Arc;
+
+/// Create a new HTTPS client.
+fn https_client() -> common::TlsClient {
+    let conn = hyper_rustls::HttpsConnector::new();
+    hyper::Client::builder().build(conn)
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let matches = clap::App::new("backup_example")
+        .version("0.1")
+        .about("Encrypted backups of a local folder to Google Drive.")
+        .arg(
+            clap::Arg::with_name("IDENTITY")
+                .help("age identity file (created by age-keygen) to encrypt and decrypt with")
+                .long("identity")
+                .short("i")
+                .required(true)
+                .takes_value(true),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("backup")
+                .about("Back up new and modified files of a folder.")
+                .arg(
+                    clap::Arg::with_name("PRUNE")
+                        .help("Delete backups of files that don't exist locally anymore")
+                        .long("prune"),
+                )
+                .arg(clap::Arg::with_name("DIR").required(true).index(1)),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("restore")
+                .about("Restore backed-up files into a folder.")
+                .arg(
+                    clap::Arg::with_name("PREFIX")
+                        .help("Only restore files whose path starts with this")
+                        .long("prefix")
+                        .short("p")
+                        .takes_value(true),
+                )
+                .arg(clap::Arg::with_name("DIR").required(true).index(1)),
+        )
+        .subcommand(clap::SubCommand::with_name("status").about("Summarize the last backup."))
+        .get_matches();
+
+    let identity_path = matches.value_of("IDENTITY").unwrap();
+    let identity = crypto::read_identity(Path::new(identity_path))
+        .await
+        .expect("identity couldn't be read");
+
+    let https = https_client();
+    // Put your client secret in the working directory!
+    let sec = common::yup_oauth2::read_application_secret("client_secret.json")
+        .await
+        .expect("client secret couldn't be read.");
+    let auth = common::yup_oauth2::InstalledFlowAuthenticator::builder(
+        sec,
+        common::yup_oauth2::InstalledFlowReturnMethod::HTTPRedirect,
+    )
+    .persist_tokens_to_disk("tokencache.json")
+    .hyper_client(https.clone())
+    .build()
+    .await
+    .expect("InstalledFlowAuthenticator failed to build");
+
+    // Blobs are created by this application, so `drive.file` suffices; the manifest lives in
+    // the application data folder.
+    let scopes = [drive::scopes::DRIVE_FILE, drive::scopes::DRIVE_APPDATA];
+    let apis = common::GoogleApis::new(https, Arc::new(auth));
+    let mut files: drive::FilesService = apis.service(drive::FilesService::new);
+    files.set_scopes(&scopes);
+    let mut changes: drive::ChangesService = apis.service(drive::ChangesService::new);
+    changes.set_scopes(&scopes);
+    let retry = common::RetryPolicy::default();
+
+    match matches.subcommand() {
+        ("backup", Some(args)) => {
+            let backup = backup::Backup {
+                files: &files,
+                changes: &changes,
+                identity: &identity,
+                retry,
+                prune: args.is_present("PRUNE"),
+            };
+            let stats = backup
+                .run(Path::new(args.value_of("DIR").unwrap()))
+                .await
+                .expect("backup failed");
+            println!(
+                "Uploaded {} files ({} bytes, {} repaired), {} unchanged, {} pruned.",
+                stats.uploaded, stats.uploaded_bytes, stats.repaired, stats.unchanged, stats.pruned
+            );
+        }
+        ("restore", Some(args)) => {
+            let restored = restore::restore(
+                &files,
+                &identity,
+                &retry,
+                Path::new(args.value_of("DIR").unwrap()),
+                args.value_of("PREFIX"),
+            )
+            .await
+            .expect("restore failed");
+            println!("Restored {} files.", restored);
+        }
+        _ => {
+            let manifest = manifest::ManifestStore::new(&files, &identity)
+                .load()
+                .await
+                .expect("manifest couldn't be loaded");
+            match manifest.last_backup {
+                Some(time) => println!(
+                    "Last backup at {}: {} files, {} bytes.",
+                    time,
+                    manifest.entries.len(),
+                    manifest.total_size()
+                ),
+                None => println!("No backup yet."),
+            }
+        }
+    }
+}
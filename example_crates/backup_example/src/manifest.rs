@@ -0,0 +1,218 @@
+//! The backup manifest: which local file is stored in which blob, and where the last backup
+//! left off in the changes log.
+//!
+//! The manifest is stored encrypted in the application data folder (`appDataFolder`), which is
+//! hidden from the user and other applications, under the name `MANIFEST_NAME`.
+
+use crate::crypto::{self, Identity};
+
+use async_google_apis_common::*;
+use drive_example::drive_v3_types as drive;
+use drive_example::query::Query;
+use drive_example::spaces::Spaces;
+
+use std::collections::BTreeMap;
+
+pub const MANIFEST_NAME: &str = "backup-manifest.json.age";
+/// Version of the manifest format. Manifests of newer versions are rejected.
+pub const MANIFEST_VERSION: u32 = 1;
+/// Manifests larger than this are not downloaded.
+const MAX_MANIFEST_SIZE: usize = 64 * 1024 * 1024;
+
+/// A backed-up file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// ID of the Drive file holding the encrypted content.
+    pub blob_id: drive::FileId,
+    /// Size and modification time of the local file when it was backed up.
+    pub size: u64,
+    pub modified: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Manifest {
+    pub version: u32,
+    /// ID of the Drive folder holding the blobs.
+    pub blob_folder: Option<drive::FileId>,
+    /// Number of blobs created so far; blobs are named after it, so that names reveal nothing
+    /// about the files.
+    pub blob_counter: u64,
+    /// Changes page token obtained when the last backup started. Changes from there on reveal
+    /// blobs deleted or trashed behind the back of the backup tool.
+    pub changes_token: Option<String>,
+    pub last_backup: Option<DateTime<Utc>>,
+    /// Backed-up files, by path relative to the backup root with `/` as separator.
+    pub entries: BTreeMap<String, Entry>,
+}
+
+impl Default for Manifest {
+    fn default() -> Manifest {
+        Manifest {
+            version: MANIFEST_VERSION,
+            blob_folder: None,
+            blob_counter: 0,
+            changes_token: None,
+            last_backup: None,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl Manifest {
+    /// Name for a new blob.
+    pub fn next_blob_name(&mut self) -> String {
+        self.blob_counter += 1;
+        format!("{:016x}.age", self.blob_counter)
+    }
+
+    /// Total size of the backed-up files.
+    pub fn total_size(&self) -> u64 {
+        self.entries.values().map(|e| e.size).sum()
+    }
+}
+
+/// Loads and saves the manifest in the application data folder.
+pub struct ManifestStore<'a> {
+    files: &'a drive::FilesService,
+    identity: &'a Identity,
+    /// ID of the manifest file, once it exists.
+    file_id: Option<drive::FileId>,
+}
+
+impl<'a> ManifestStore<'a> {
+    pub fn new(files: &'a drive::FilesService, identity: &'a Identity) -> ManifestStore<'a> {
+        ManifestStore {
+            files,
+            identity,
+            file_id: None,
+        }
+    }
+
+    /// Load the manifest, or return an empty one if there is none yet.
+    pub async fn load(&mut self) -> Result<Manifest> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("files(id)".into());
+        let mut params = drive::FilesListParams::default();
+        params.drive_params = Some(general_params);
+        params.spaces = Some(Spaces::APP_DATA_FOLDER);
+        params.q = Some(
+            Query::name()
+                .eq(MANIFEST_NAME)
+                .and(Query::trashed(false))
+                .to_string(),
+        );
+        let list = self.files.list(&params).await?;
+        let id = match manifest_id(list.files.unwrap_or_default())? {
+            Some(id) => id,
+            None => return Ok(Manifest::default()),
+        };
+
+        let ciphertext = self.files.get_media_bytes(&id, MAX_MANIFEST_SIZE).await?;
+        let plaintext = crypto::decrypt(self.identity, &ciphertext)?;
+        let manifest: Manifest = serde_json::from_slice(&plaintext)?;
+        if manifest.version > MANIFEST_VERSION {
+            return Err(ApiError::InputDataError(format!(
+                "manifest version {} is newer than supported ({})",
+                manifest.version, MANIFEST_VERSION
+            ))
+            .into());
+        }
+        self.file_id = Some(id);
+        Ok(manifest)
+    }
+
+    /// Save `manifest`, replacing the stored one.
+    pub async fn save(&mut self, manifest: &Manifest) -> Result<()> {
+        let plaintext = serde_json::to_vec(manifest)?;
+        let ciphertext: hyper::body::Bytes = crypto::encrypt(self.identity, &plaintext)?.into();
+        match self.file_id.as_ref() {
+            Some(id) => {
+                let mut params = drive::FilesUpdateParams::default();
+                params.file_id = id.clone();
+                self.files
+                    .update_upload(&params, &drive::File::default(), ciphertext)
+                    .await?;
+            }
+            None => {
+                let mut req = drive::File::default();
+                req.name = Some(MANIFEST_NAME.into());
+                req.parents = Some(vec!["appDataFolder".into()]);
+                let file = self
+                    .files
+                    .create_upload(&drive::FilesCreateParams::default(), &req, ciphertext)
+                    .await?;
+                self.file_id = file.id;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The ID of the manifest among the files named `MANIFEST_NAME`. More than one manifest is an
+/// error, as picking one at random could lose track of the blobs referenced by the others.
+fn manifest_id(files: Vec<drive::File>) -> Result<Option<drive::FileId>> {
+    let mut ids: Vec<drive::FileId> = files.into_iter().filter_map(|f| f.id).collect();
+    if ids.len() > 1 {
+        return Err(ApiError::InputDataError(format!(
+            "found {} files named {} in the application data folder ({}); remove all but one",
+            ids.len(),
+            MANIFEST_NAME,
+            ids.iter()
+                .map(|id| id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+        .into());
+    }
+    Ok(ids.pop())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(id: &str) -> drive::File {
+        let mut f = drive::File::default();
+        f.id = Some(id.into());
+        f
+    }
+
+    #[test]
+    fn test_manifest_id() {
+        assert_eq!(manifest_id(vec![]).unwrap(), None);
+        assert_eq!(
+            manifest_id(vec![file("m1")]).unwrap(),
+            Some(drive::FileId::from("m1"))
+        );
+        assert!(manifest_id(vec![file("m1"), file("m2")]).is_err());
+    }
+
+    #[test]
+    fn test_next_blob_name() {
+        let mut manifest = Manifest::default();
+        assert_eq!(manifest.next_blob_name(), "0000000000000001.age");
+        assert_eq!(manifest.next_blob_name(), "0000000000000002.age");
+        assert_eq!(manifest.blob_counter, 2);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut manifest = Manifest::default();
+        manifest.entries.insert(
+            "a/b.txt".into(),
+            Entry {
+                blob_id: "blob1".into(),
+                size: 12,
+                modified: "2021-01-02T03:04:05Z".parse().unwrap(),
+            },
+        );
+        let identity = Identity::generate();
+        let ciphertext =
+            crypto::encrypt(&identity, &serde_json::to_vec(&manifest).unwrap()).unwrap();
+        let decrypted: Manifest =
+            serde_json::from_slice(&crypto::decrypt(&identity, &ciphertext).unwrap()).unwrap();
+        assert_eq!(decrypted.version, MANIFEST_VERSION);
+        assert_eq!(decrypted.entries, manifest.entries);
+        assert_eq!(manifest.total_size(), 12);
+    }
+}
@@ -0,0 +1,97 @@
+//! Restoring backed-up files into a local folder.
+
+use crate::crypto::{self, Identity};
+use crate::manifest::{Entry, ManifestStore};
+
+use async_google_apis_common::*;
+use drive_example::drive_v3_types as drive;
+
+use std::path::{Component, Path, PathBuf};
+
+/// Restore all files whose path starts with `prefix` (or all files) below `dest`. Existing files
+/// are overwritten. Returns the number of restored files.
+pub async fn restore(
+    files: &drive::FilesService,
+    identity: &Identity,
+    retry: &RetryPolicy,
+    dest: &Path,
+    prefix: Option<&str>,
+) -> Result<usize> {
+    let manifest = ManifestStore::new(files, identity).load().await?;
+    if manifest.last_backup.is_none() {
+        return Err(ApiError::InputDataError("no backup found".into()).into());
+    }
+    let mut restored = 0;
+    for (path, entry) in manifest.entries.iter() {
+        if !prefix.map_or(true, |p| path.starts_with(p)) {
+            continue;
+        }
+        let target = local_path(dest, path)?;
+        restore_file(files, identity, retry, entry, &target)
+            .await
+            .map_err(|e| e.context(format!("restoring {}", path)))?;
+        restored += 1;
+    }
+    Ok(restored)
+}
+
+/// Download the blob of `entry` into a temporary file and decrypt it to `target`, so that
+/// neither ciphertext nor plaintext has to fit into memory.
+async fn restore_file(
+    files: &drive::FilesService,
+    identity: &Identity,
+    retry: &RetryPolicy,
+    entry: &Entry,
+    target: &Path,
+) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let ciphertext = crypto::TempFile::new(&format!("{}.age", entry.blob_id));
+    retry
+        .run(|| async {
+            let mut f = tokio::fs::File::create(ciphertext.path()).await?;
+            files.get_media(&entry.blob_id, &mut f).await?;
+            f.flush().await?;
+            Ok(())
+        })
+        .await?;
+    if let Some(parent) = target.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    // Decryption blocks, but files are restored one at a time anyway.
+    crypto::decrypt_file(identity, ciphertext.path(), target, entry.size)
+        .map_err(|e| e.context(format!("decrypting blob {}", entry.blob_id)))?;
+    debug!("restore: wrote {}", target.display());
+    Ok(())
+}
+
+/// The location of the backed-up file `path` below `dest`. Paths trying to escape `dest` (which
+/// only a tampered manifest could contain) are rejected.
+fn local_path(dest: &Path, path: &str) -> Result<PathBuf> {
+    let relative = Path::new(path);
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return Err(ApiError::InputDataError(format!("invalid path in manifest: {}", path)).into());
+    }
+    Ok(dest.join(relative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_path() {
+        let dest = Path::new("/restore");
+        assert_eq!(
+            local_path(dest, "a/b.txt").unwrap(),
+            PathBuf::from("/restore/a/b.txt")
+        );
+        assert!(local_path(dest, "../etc/passwd").is_err());
+        assert!(local_path(dest, "a/../../b").is_err());
+        assert!(local_path(dest, "/etc/passwd").is_err());
+        assert!(local_path(dest, "./a").is_err());
+    }
+}
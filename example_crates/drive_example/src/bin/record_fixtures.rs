@@ -0,0 +1,306 @@
+//! Record the response fixtures in `tests/fixtures/` from a real Drive account, sanitizing them
+//! (see `tests/fixtures/README.md`):
+//!
+//! ```shell
+//! $ cargo run --bin record_fixtures -- FILE_ID [DRIVE_ID]
+//! ```
+//!
+//! `FILE_ID` should be a file with permissions, comments with replies, and several revisions;
+//! `DRIVE_ID` a shared drive (by default the first one listed). All fields are requested
+//! (`fields=*`), so that the fixtures contain every field the server returns. `changes.watch`
+//! needs a webhook receiving notifications, and is not recorded.
+//!
+//! Like `drive_example`, this expects `client_secret.json` in the working directory.
+
+use async_google_apis_common as common;
+use drive_example::drive_v3_types as drive;
+
+use common::serde_json::{self, Value};
+use common::{ApiError, EmptyRequest, HashMap, Result};
+use std::path::Path;
+
+const FIXTURES_DIR: &str = "tests/fixtures";
+
+/// Replaces identifying values in responses with placeholders. The same value is replaced by the
+/// same placeholder in all fixtures, so that references between them (e.g. `parents` and `id`)
+/// are kept.
+#[derive(Default)]
+struct Sanitizer {
+    replacements: HashMap<(&'static str, String), String>,
+}
+
+impl Sanitizer {
+    fn sanitize(&mut self, value: &mut Value) {
+        self.walk("", value)
+    }
+
+    fn walk(&mut self, key: &str, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                // Maps keyed by MIME type or by the application, with links or user data.
+                let links = key.ends_with("Links");
+                let properties = key == "properties" || key == "appProperties";
+                for (k, child) in map.iter_mut() {
+                    match child {
+                        Value::String(s) if links || properties => {
+                            *s = if links {
+                                "https://example.com/link"
+                            } else {
+                                "value"
+                            }
+                            .into()
+                        }
+                        _ => self.walk(k, child),
+                    }
+                }
+            }
+            // Elements of arrays (e.g. `parents`) are sanitized like the array's field.
+            Value::Array(items) => items.iter_mut().for_each(|item| self.walk(key, item)),
+            Value::String(s) => {
+                if let Some(replacement) = self.replace(key, s) {
+                    *s = replacement;
+                }
+            }
+            Value::Number(_) if ["latitude", "longitude", "altitude"].contains(&key) => {
+                *value = Value::from(0.0)
+            }
+            _ => {}
+        }
+    }
+
+    /// The placeholder for the value `s` of the field `key`, if it has to be replaced.
+    fn replace(&mut self, key: &str, s: &str) -> Option<String> {
+        let kind = if key == "id" || key.ends_with("Id") || key.ends_with("Ids") {
+            "id"
+        } else if key == "parents" || key == "resourceKey" {
+            "id"
+        } else if key.ends_with("Token") {
+            "token"
+        } else if key == "emailAddress" {
+            "email"
+        } else if key == "domain" {
+            return Some("example.com".into());
+        } else if key == "displayName" {
+            "user"
+        } else if key == "name" || key == "title" || key == "originalFilename" {
+            "name"
+        } else if ["description", "content", "htmlContent", "value"].contains(&key) {
+            return Some("Sanitized text".into());
+        } else if key.ends_with("Link") || key.ends_with("Url") {
+            return Some(format!("https://example.com/{}", key));
+        } else if key.ends_with("Checksum") {
+            return Some("0".repeat(s.len()));
+        } else {
+            return None;
+        };
+        let n = self.replacements.len() + 1;
+        let placeholder = self
+            .replacements
+            .entry((kind, s.to_string()))
+            .or_insert_with(|| match kind {
+                "email" => format!("user{}@example.com", n),
+                "user" => format!("User {}", n),
+                // Keep file extensions, which the server derives fields like `fileExtension` from.
+                "name" => match s.rfind('.').map(|i| &s[i..]) {
+                    Some(ext) if ext.len() <= 5 => format!("name{}{}", n, ext),
+                    _ => format!("name{}", n),
+                },
+                _ => format!("sanitized{}{}", kind, n),
+            });
+        Some(placeholder.clone())
+    }
+}
+
+/// Sends the requests, and writes the sanitized responses to `FIXTURES_DIR`.
+struct Recorder {
+    service: drive::AboutService,
+    sanitizer: Sanitizer,
+}
+
+impl Recorder {
+    /// Request `path` (relative to the Drive API's base URL) with `query` and all fields, and
+    /// save the response as fixture `name`. Returns the unsanitized response.
+    async fn record(&mut self, name: &str, path: &str, query: &str) -> Result<Value> {
+        let query = format!("fields=*&{}", query);
+        let body = self
+            .service
+            .request_raw::<EmptyRequest>("GET", path, &query, &[drive::scopes::DRIVE], None)
+            .await?;
+        let response: Value = serde_json::from_slice(&hyper::body::to_bytes(body).await?)?;
+        let mut sanitized = response.clone();
+        self.sanitizer.sanitize(&mut sanitized);
+        let file = Path::new(FIXTURES_DIR).join(format!("{}.json", name));
+        std::fs::write(&file, serde_json::to_string_pretty(&sanitized)? + "\n")?;
+        println!("Recorded {}", file.display());
+        Ok(response)
+    }
+}
+
+/// The ID of the first element of the array `field` of `list`.
+fn first_id(list: &Value, field: &str) -> Result<String> {
+    list[field][0]["id"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| ApiError::InputDataError(format!("no {} to record", field)).into())
+}
+
+async fn record_all(
+    recorder: &mut Recorder,
+    file_id: &str,
+    drive_id: Option<String>,
+) -> Result<()> {
+    let all_drives = "supportsAllDrives=true";
+    recorder.record("about.get", "about", "").await?;
+
+    let token = recorder
+        .record("changes.getStartPageToken", "changes/startPageToken", "")
+        .await?;
+    let token = token["startPageToken"].as_str().unwrap_or("1").to_string();
+    recorder
+        .record("changes.list", "changes", "pageToken=1&pageSize=3")
+        .await?;
+    recorder
+        .record(
+            "changes.list.last_page",
+            "changes",
+            &format!("pageToken={}", token),
+        )
+        .await?;
+
+    let file = format!("files/{}", file_id);
+    recorder.record("files.get", &file, all_drives).await?;
+    recorder.record("files.list", "files", "pageSize=5").await?;
+    recorder
+        .record("files.generateIds", "files/generateIds", "count=3")
+        .await?;
+
+    let path = format!("{}/permissions", file);
+    let list = recorder
+        .record("permissions.list", &path, all_drives)
+        .await?;
+    let path = format!("{}/{}", path, first_id(&list, "permissions")?);
+    recorder
+        .record("permissions.get", &path, all_drives)
+        .await?;
+
+    let path = format!("{}/comments", file);
+    let list = recorder
+        .record("comments.list", &path, "pageSize=3")
+        .await?;
+    let path = format!("{}/{}", path, first_id(&list, "comments")?);
+    recorder.record("comments.get", &path, "").await?;
+    let path = format!("{}/replies", path);
+    let list = recorder.record("replies.list", &path, "pageSize=3").await?;
+    let path = format!("{}/{}", path, first_id(&list, "replies")?);
+    recorder.record("replies.get", &path, "").await?;
+
+    let path = format!("{}/revisions", file);
+    let list = recorder
+        .record("revisions.list", &path, "pageSize=3")
+        .await?;
+    let path = format!("{}/{}", path, first_id(&list, "revisions")?);
+    recorder.record("revisions.get", &path, "").await?;
+
+    let list = recorder
+        .record("drives.list", "drives", "pageSize=3")
+        .await?;
+    let drive_id = match drive_id {
+        Some(id) => id,
+        None => first_id(&list, "drives")?,
+    };
+    recorder
+        .record("drives.get", &format!("drives/{}", drive_id), "")
+        .await?;
+    recorder
+        .record("teamdrives.list", "teamdrives", "pageSize=3")
+        .await?;
+    recorder
+        .record("teamdrives.get", &format!("teamdrives/{}", drive_id), "")
+        .await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let mut args = std::env::args().skip(1);
+    let file_id = args
+        .next()
+        .expect("usage: record_fixtures FILE_ID [DRIVE_ID]");
+    let drive_id = args.next();
+
+    let https = hyper::Client::builder().build(hyper_rustls::HttpsConnector::new());
+    let sec = common::yup_oauth2::read_application_secret("client_secret.json")
+        .await
+        .expect("client secret couldn't be read.");
+    let auth = common::yup_oauth2::InstalledFlowAuthenticator::builder(
+        sec,
+        common::yup_oauth2::InstalledFlowReturnMethod::HTTPRedirect,
+    )
+    .persist_tokens_to_disk("tokencache.json")
+    .hyper_client(https.clone())
+    .build()
+    .await
+    .expect("InstalledFlowAuthenticator failed to build");
+
+    let mut recorder = Recorder {
+        service: drive::AboutService::new(https, common::Arc::new(auth)),
+        sanitizer: Sanitizer::default(),
+    };
+    record_all(&mut recorder, &file_id, drive_id)
+        .await
+        .expect("Recording fixtures failed");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize() {
+        let mut sanitizer = Sanitizer::default();
+        let mut file = serde_json::json!({
+            "id": "1RealFileId",
+            "name": "Holidays.jpg",
+            "parents": ["0RealFolderId"],
+            "owners": [{"displayName": "Jane Doe", "emailAddress": "jane@corp.com", "me": true}],
+            "exportLinks": {"application/pdf": "https://docs.google.com/real"},
+            "properties": {"album": "private"},
+            "md5Checksum": "d41d8cd98f00b204e9800998ecf8427e",
+            "imageMediaMetadata": {"location": {"latitude": 48.1}, "width": 4032},
+            "size": "2345678"
+        });
+        sanitizer.sanitize(&mut file);
+        let text = file.to_string();
+        for secret in &[
+            "Real",
+            "Holidays",
+            "Jane",
+            "corp.com",
+            "docs.google",
+            "private",
+            "d41d",
+        ] {
+            assert!(!text.contains(secret), "{} in {}", secret, text);
+        }
+        let id = file["id"].as_str().unwrap().to_string();
+        assert!(id.starts_with("sanitizedid"));
+        assert_ne!(file["parents"][0], file["id"]);
+        assert!(file["name"].as_str().unwrap().ends_with(".jpg"));
+        assert!(file["owners"][0]["emailAddress"]
+            .as_str()
+            .unwrap()
+            .ends_with("@example.com"));
+        assert_eq!(file["owners"][0]["me"], true);
+        assert_eq!(file["md5Checksum"].as_str().unwrap().len(), 32);
+        assert_eq!(file["imageMediaMetadata"]["location"]["latitude"], 0.0);
+        assert_eq!(file["imageMediaMetadata"]["width"], 4032);
+        assert_eq!(file["size"], "2345678");
+
+        // References to the same file get the same placeholder.
+        let mut change = serde_json::json!({"fileId": "1RealFileId"});
+        sanitizer.sanitize(&mut change);
+        assert_eq!(change["fileId"], id.as_str());
+    }
+}
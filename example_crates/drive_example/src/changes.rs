@@ -0,0 +1,518 @@
+//! Replaying the changes log for a time window, e.g. to find out what happened to a drive
+//! yesterday.
+//!
+//! The changes API is only addressable by page tokens, not by time. `StartTokenLog` maps times to
+//! tokens: record a start page token regularly (e.g. whenever a sync runs), store the log, and
+//! later use `token_at()` to find where to start replaying.
+//!
+//! `ChangesService::stream()` returns changes as soon as they have been received, instead of
+//! waiting for complete pages.
+//!
+//! `ChangesService::poll()` follows the log indefinitely, for watching a drive: it waits for new
+//! changes once the end of the log is reached, and can keep its page token in a file so that a
+//! restarted watcher continues where the previous one stopped.
+
+use crate::drive_v3_types as drive;
+use crate::pages::PageStream;
+use async_google_apis_common::*;
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Start page tokens and the times they were obtained at, in chronological order.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct StartTokenLog {
+    pub entries: Vec<(DateTime<Utc>, String)>,
+}
+
+impl StartTokenLog {
+    /// Fetch the current start page token and append it to the log.
+    pub async fn record<C: HttpClient>(
+        &mut self,
+        changes: &drive::ChangesService<C>,
+    ) -> Result<()> {
+        let mut params = drive::ChangesGetStartPageTokenParams::default();
+        params.supports_all_drives = Some(true);
+        let tok = changes.get_start_page_token(&params).await?;
+        match tok.start_page_token {
+            Some(tok) => {
+                self.entries.push((Utc::now(), tok));
+                Ok(())
+            }
+            None => Err(ApiError::InputDataError(
+                "record: response contained no startPageToken".into(),
+            )
+            .into()),
+        }
+    }
+
+    /// The latest token recorded at or before `time`. Replaying from it covers all changes since
+    /// `time`.
+    pub fn token_at(&self, time: DateTime<Utc>) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(t, _)| *t <= time)
+            .map(|(_, tok)| tok.as_str())
+    }
+}
+
+/// Changes decoded one by one from `changes.list` responses while they are being received,
+/// following page tokens until the end of the log. Created by `ChangesService::stream()`.
+pub type ChangeStream<'a> = PageStream<'a, drive::ChangeList>;
+
+impl<'a> ChangeStream<'a> {
+    /// The token to continue from later (e.g. with `ChangesService::stream()`), available once
+    /// `next()` has returned `None`. Note that `params.drive_params.fields` must include
+    /// `newStartPageToken` if set.
+    pub fn new_start_page_token(&self) -> Option<&str> {
+        self.last_page()?.new_start_page_token.as_deref()
+    }
+}
+
+/// Options for `ChangesService::poll()`.
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    /// Wait after reaching the end of the log before asking for new changes.
+    pub interval: Duration,
+    /// The wait is doubled after every poll without changes, up to this.
+    pub max_interval: Duration,
+    /// File to keep the page token in. If it exists, polling starts from the token stored in it;
+    /// the token is updated after all changes of a page have been consumed.
+    pub token_path: Option<PathBuf>,
+}
+
+impl Default for PollOptions {
+    fn default() -> PollOptions {
+        PollOptions {
+            interval: Duration::from_secs(10),
+            max_interval: Duration::from_secs(300),
+            token_path: None,
+        }
+    }
+}
+
+impl PollOptions {
+    /// The wait after `idle` polls in a row without changes.
+    fn wait(&self, idle: u32) -> Duration {
+        self.interval
+            .checked_mul(1 << idle.min(16))
+            .map_or(self.max_interval, |d| d.min(self.max_interval))
+    }
+}
+
+/// State of the stream returned by `ChangesService::poll()`.
+struct Poll<'a, C> {
+    changes: &'a drive::ChangesService<C>,
+    params: drive::ChangesListParams,
+    options: PollOptions,
+    pending: VecDeque<drive::Change>,
+    /// Token to continue from once `pending` has been consumed.
+    next_token: Option<String>,
+    /// Whether `next_token` is a `newStartPageToken`, i.e. the end of the log has been reached.
+    at_end: bool,
+    /// Polls in a row at the end of the log without changes.
+    idle: u32,
+}
+
+impl<'a, C: HttpClient> Poll<'a, C> {
+    async fn next(mut self) -> Result<Option<(drive::Change, Poll<'a, C>)>> {
+        loop {
+            if let Some(change) = self.pending.pop_front() {
+                return Ok(Some((change, self)));
+            }
+            match self.next_token.take() {
+                Some(tok) => {
+                    if let Some(path) = self.options.token_path.as_ref() {
+                        save_token(path, &tok).await?;
+                    }
+                    self.params.page_token = tok;
+                }
+                None if self.params.page_token.is_empty() => {
+                    self.params.page_token = self.start_token().await?;
+                }
+                None => {}
+            }
+            if self.at_end {
+                let wait = self.options.wait(self.idle);
+                debug!("poll: end of changes log, waiting {:?}", wait);
+                tokio::time::delay_for(wait).await;
+            }
+
+            let list = self.changes.list(&self.params).await?;
+            self.pending.extend(list.changes.unwrap_or_default());
+            self.at_end = list.next_page_token.is_none();
+            self.idle = match (self.at_end, self.pending.is_empty()) {
+                (true, true) => self.idle + 1,
+                _ => 0,
+            };
+            self.next_token = list.next_page_token.or(list.new_start_page_token);
+            if self.next_token.is_none() {
+                return Err(ApiError::InputDataError(
+                    "poll: response contained neither nextPageToken nor newStartPageToken".into(),
+                )
+                .into());
+            }
+        }
+    }
+
+    /// The token stored in `options.token_path`, or else the current start page token.
+    async fn start_token(&self) -> Result<String> {
+        if let Some(path) = self.options.token_path.as_ref() {
+            match tokio::fs::read_to_string(path).await {
+                Ok(tok) if !tok.trim().is_empty() => return Ok(tok.trim().to_string()),
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let mut params = drive::ChangesGetStartPageTokenParams::default();
+        params.drive_id = self.params.drive_id.clone();
+        params.supports_all_drives = self.params.supports_all_drives;
+        let tok = self.changes.get_start_page_token(&params).await?;
+        let tok = tok.start_page_token.ok_or_else(|| {
+            ApiError::InputDataError("poll: response contained no startPageToken".into())
+        })?;
+        if let Some(path) = self.options.token_path.as_ref() {
+            save_token(path, &tok).await?;
+        }
+        Ok(tok)
+    }
+}
+
+/// Write `token` to `path`, replacing the previous token atomically.
+async fn save_token(path: &Path, token: &str) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    tokio::fs::write(&tmp, token).await?;
+    tokio::fs::rename(&tmp, path).await?;
+    Ok(())
+}
+
+/// A file that was removed (deleted, or access lost) or trashed.
+#[derive(Debug, Clone)]
+pub struct RemovedFile {
+    pub file_id: drive::FileId,
+    /// Not known for files that are gone entirely.
+    pub name: Option<String>,
+    pub time: DateTime<Utc>,
+    /// Trashed, as opposed to removed.
+    pub trashed: bool,
+}
+
+/// A file whose set of permissions changed.
+#[derive(Debug, Clone)]
+pub struct PermissionChange {
+    pub file_id: drive::FileId,
+    pub name: Option<String>,
+    pub time: DateTime<Utc>,
+    /// Permission IDs before and after the change.
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+/// The result of `ChangesService::replay()`.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeReport {
+    pub removed: Vec<RemovedFile>,
+    pub permissions_changed: Vec<PermissionChange>,
+    /// Number of changes in the window not covered by the above.
+    pub other: usize,
+}
+
+impl<C: HttpClient> drive::ChangesService<C> {
+    /// Like `list()`, but returns the changes one by one, as soon as they have been received, and
+    /// continues with the next page after the last change of a page. This reduces the time until
+    /// the first change is available when using a large `page_size`.
+    pub fn stream(&self, params: &drive::ChangesListParams) -> ChangeStream<'_> {
+        let params = params.clone();
+        PageStream::new("changes", Some(params.page_token.clone()), move |token| {
+            let mut params = params.clone();
+            params.page_token = token.unwrap_or_default();
+            Box::pin(async move {
+                let mut query = form_urlencoded::Serializer::new(String::new());
+                params.append_to(&mut query);
+                if let Some(ref api_params) = params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+                let scopes = [drive::scopes::DRIVE_READONLY];
+                self.request_raw::<EmptyRequest>("GET", "changes", &query.finish(), &scopes, None)
+                    .await
+            })
+        })
+    }
+}
+
+impl<C: HttpClient> drive::ChangesService<C> {
+    /// Follow the changes log indefinitely, yielding every change.
+    ///
+    /// Polling starts at `params.page_token`, or if that is empty, at the token stored in
+    /// `options.token_path`, or else at the current start page token (i.e. only changes made
+    /// from now on are returned). After the end of the log has been reached, the stream waits
+    /// `options.interval` before asking again, and longer while nothing changes.
+    ///
+    /// The token is saved after all changes of a page have been consumed; a restarted watcher
+    /// may therefore see some changes again, but never misses any. The stream ends after the
+    /// first error. If `params.drive_params.fields` is set, it must include `nextPageToken` and
+    /// `newStartPageToken`.
+    pub fn poll<'a>(
+        &'a self,
+        params: &drive::ChangesListParams,
+        options: PollOptions,
+    ) -> impl futures::Stream<Item = Result<drive::Change>> + 'a {
+        let poll = Poll {
+            changes: self,
+            params: params.clone(),
+            options,
+            pending: VecDeque::new(),
+            next_token: None,
+            at_end: false,
+            idle: 0,
+        };
+        futures::stream::try_unfold(poll, Poll::next)
+    }
+
+    /// Replay all changes from `start_token` on, and report the files removed, trashed, or with
+    /// changed permissions between `from` and `to`.
+    ///
+    /// The changes log doesn't record what changed about a file, so permission changes are
+    /// detected by comparing a file's permission IDs with those seen in an earlier change. Start
+    /// replaying before `from` (e.g. with `StartTokenLog::token_at()`) so that earlier changes
+    /// provide a baseline. Permissions of files in shared drives are not covered.
+    pub async fn replay(
+        &self,
+        start_token: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<ChangeReport> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(
+            "nextPageToken,newStartPageToken,changes(fileId,removed,time,file(name,trashed,permissionIds))"
+                .into(),
+        );
+        let mut params = drive::ChangesListParams::default();
+        params.drive_params = Some(general_params);
+        params.page_token = start_token.into();
+        params.include_items_from_all_drives = Some(true);
+        params.supports_all_drives = Some(true);
+
+        let mut report = ChangeReport::default();
+        let mut permissions: HashMap<drive::FileId, Vec<String>> = HashMap::new();
+        loop {
+            let list = self.list(&params).await?;
+            for change in list.changes.unwrap_or_default() {
+                let (file_id, time) = match (change.file_id, change.time) {
+                    (Some(id), Some(t)) => (id, t),
+                    _ => continue,
+                };
+                if time > to {
+                    return Ok(report);
+                }
+                let in_window = time >= from;
+                let file = change.file.unwrap_or_default();
+
+                if change.removed.unwrap_or(false) || file.trashed.unwrap_or(false) {
+                    if in_window {
+                        report.removed.push(RemovedFile {
+                            file_id,
+                            name: file.name,
+                            time,
+                            trashed: !change.removed.unwrap_or(false),
+                        });
+                    }
+                    continue;
+                }
+
+                let mut after = file.permission_ids.unwrap_or_default();
+                after.sort();
+                match permissions.insert(file_id.clone(), after.clone()) {
+                    Some(before) if in_window && before != after => {
+                        report.permissions_changed.push(PermissionChange {
+                            file_id,
+                            name: file.name,
+                            time,
+                            before,
+                            after,
+                        })
+                    }
+                    _ if in_window => report.other += 1,
+                    _ => {}
+                }
+            }
+            match list.next_page_token {
+                Some(tok) => params.page_token = tok,
+                None => return Ok(report),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    fn file_ids(changes: &[drive::Change]) -> Vec<String> {
+        changes
+            .iter()
+            .map(|c| c.file_id.as_ref().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_poll_wait() {
+        let options = PollOptions {
+            interval: Duration::from_secs(10),
+            max_interval: Duration::from_secs(30),
+            token_path: None,
+        };
+        assert_eq!(options.wait(0), Duration::from_secs(10));
+        assert_eq!(options.wait(1), Duration::from_secs(20));
+        assert_eq!(options.wait(2), Duration::from_secs(30));
+        assert_eq!(options.wait(100), Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_poll() {
+        let token_path =
+            std::env::temp_dir().join(format!("drive-poll-test-{}", std::process::id()));
+        let _ = tokio::fs::remove_file(&token_path).await;
+        let options = PollOptions {
+            interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(4),
+            token_path: Some(token_path.clone()),
+        };
+        let saved = || std::fs::read_to_string(&token_path).unwrap();
+
+        let mock = MockClient::new();
+        let changes = drive::ChangesService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(MockResponse::json(
+            &serde_json::json!({"startPageToken": "1"}),
+        ))
+        .push(MockResponse::json(&serde_json::json!({
+            "changes": [{"fileId": "a"}, {"fileId": "b"}],
+            "nextPageToken": "2"
+        })))
+        .push(MockResponse::json(&serde_json::json!({
+            "changes": [{"fileId": "c"}],
+            "newStartPageToken": "3"
+        })))
+        // Nothing new at the end of the log.
+        .push(MockResponse::json(
+            &serde_json::json!({"newStartPageToken": "3"}),
+        ))
+        .push(MockResponse::json(&serde_json::json!({
+            "changes": [{"fileId": "d"}],
+            "newStartPageToken": "4"
+        })));
+
+        let params = drive::ChangesListParams::default();
+        let mut stream = Box::pin(changes.poll(&params, options.clone()));
+        let mut received = vec![];
+        for _ in 0..2 {
+            received.push(stream.try_next().await.unwrap().unwrap());
+        }
+        // The token is only saved once all changes of its page have been consumed.
+        assert_eq!(saved(), "1");
+        received.push(stream.try_next().await.unwrap().unwrap());
+        assert_eq!(saved(), "2");
+        received.push(stream.try_next().await.unwrap().unwrap());
+        assert_eq!(saved(), "3");
+        assert_eq!(file_ids(&received), vec!["a", "b", "c", "d"]);
+        let tokens: Vec<Option<String>> = mock
+            .requests()
+            .iter()
+            .map(|r| r.query_param("pageToken"))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                None,
+                Some("1".into()),
+                Some("2".into()),
+                Some("3".into()),
+                Some("3".into())
+            ]
+        );
+        drop(stream);
+
+        // A restarted watcher continues from the saved token, seeing "d" again.
+        mock.clear_requests();
+        mock.push(MockResponse::json(&serde_json::json!({
+            "changes": [{"fileId": "d"}],
+            "newStartPageToken": "4"
+        })))
+        .push(MockResponse::json(&serde_json::json!({"changes": []})));
+        let mut stream = Box::pin(changes.poll(&params, options));
+        let change = stream.try_next().await.unwrap().unwrap();
+        assert_eq!(file_ids(&[change]), vec!["d"]);
+        assert_eq!(
+            mock.requests()[0].query_param("pageToken").as_deref(),
+            Some("3")
+        );
+        // Responses without either token end the stream with an error.
+        assert!(stream.try_next().await.is_err());
+
+        tokio::fs::remove_file(&token_path).await.unwrap();
+    }
+
+    #[test]
+    fn test_replay() {
+        let mock = MockClient::new();
+        let changes = drive::ChangesService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(MockResponse::json(&serde_json::json!({
+            "changes": [
+                // A baseline before the window.
+                {"fileId": "f1", "time": "2021-01-01T00:00:00Z", "file": {"permissionIds": ["p1"]}},
+                {"fileId": "f2", "time": "2021-01-02T00:00:00Z", "removed": true}
+            ],
+            "nextPageToken": "2"
+        })))
+        .push(MockResponse::json(&serde_json::json!({
+            "changes": [
+                {
+                    "fileId": "f1",
+                    "time": "2021-01-03T00:00:00Z",
+                    "file": {"permissionIds": ["p2", "p1"]}
+                },
+                {
+                    "fileId": "f3",
+                    "time": "2021-01-03T00:00:00Z",
+                    "file": {"name": "old", "trashed": true}
+                },
+                {"fileId": "f4", "time": "2021-01-03T00:00:00Z", "file": {"permissionIds": []}},
+                // After the window.
+                {"fileId": "f5", "time": "2021-01-05T00:00:00Z", "removed": true}
+            ],
+            "newStartPageToken": "3"
+        })));
+
+        let report = futures::executor::block_on(changes.replay(
+            "s",
+            "2021-01-02T00:00:00Z".parse().unwrap(),
+            "2021-01-04T00:00:00Z".parse().unwrap(),
+        ))
+        .unwrap();
+        let removed: Vec<(String, bool)> = report
+            .removed
+            .iter()
+            .map(|r| (r.file_id.to_string(), r.trashed))
+            .collect();
+        assert_eq!(
+            removed,
+            vec![("f2".to_string(), false), ("f3".to_string(), true)]
+        );
+        assert_eq!(report.permissions_changed.len(), 1);
+        let changed = &report.permissions_changed[0];
+        assert_eq!(changed.file_id.to_string(), "f1");
+        assert_eq!(changed.before, vec!["p1"]);
+        assert_eq!(changed.after, vec!["p1", "p2"]);
+        assert_eq!(report.other, 1);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query_param("pageToken").as_deref(), Some("s"));
+        assert_eq!(requests[1].query_param("pageToken").as_deref(), Some("2"));
+    }
+}
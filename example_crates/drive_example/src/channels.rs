@@ -0,0 +1,486 @@
+//! Keeping watch channels alive.
+//!
+//! Notification channels created by `changes.watch` and `files.watch` expire after at most a day
+//! (files) or a week (changes), after which notifications silently stop. `ChannelManager`
+//! tracks the channels it created, replaces each with a new channel shortly before it expires
+//! (stopping the old one once the new one exists, so that no notification is lost), and stops
+//! all of them on shutdown.
+//!
+//! ```ignore
+//! let mut manager = ChannelManager::new(&apis, "https://example.com/notify").with_token(token);
+//! manager.watch("root", WatchTarget::Changes(params)).await?;
+//! manager.run(shutdown_signal).await?;
+//! ```
+//!
+//! Notifications can be received with `NotificationHandler`; `ChannelManager::name_of()` maps
+//! their channel ID to the name given to `watch()`.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Counter making channel IDs created within the same nanosecond unique.
+static CHANNEL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// What a channel watches.
+#[derive(Debug, Clone)]
+pub enum WatchTarget {
+    /// The changes log. `page_token` may be empty; every channel is created with the current
+    /// start page token, as notifications only signal that there are new changes.
+    Changes(drive::ChangesWatchParams),
+    /// A single file.
+    File(drive::FilesWatchParams),
+}
+
+/// A channel managed by `ChannelManager`.
+#[derive(Debug, Clone)]
+pub struct ActiveChannel {
+    pub name: String,
+    pub target: WatchTarget,
+    /// The channel as returned by `watch`, with `id` and `resourceId` needed for stopping it.
+    pub channel: drive::Channel,
+    pub created: DateTime<Utc>,
+    pub expiration: DateTime<Utc>,
+}
+
+impl ActiveChannel {
+    /// When the channel is due for renewal; see `renewal_time()`.
+    fn renewal(&self, renew_before: Duration) -> DateTime<Utc> {
+        renewal_time(self.created, self.expiration, renew_before)
+    }
+}
+
+/// Creates watch channels and renews them before they expire; see the module documentation.
+pub struct ChannelManager<C = TlsClient> {
+    changes: drive::ChangesService<C>,
+    files: drive::FilesService<C>,
+    channels: drive::ChannelsService<C>,
+    address: String,
+    token: Option<String>,
+    ttl: Duration,
+    renew_before: Duration,
+    active: Vec<ActiveChannel>,
+}
+
+impl<C: HttpClient> ChannelManager<C> {
+    /// A manager creating channels delivering notifications to `address` (an HTTPS URL). Channels
+    /// are requested with a lifetime of one day, and renewed one hour before they expire.
+    pub fn new<S: Into<String>>(apis: &GoogleApis<C>, address: S) -> ChannelManager<C> {
+        ChannelManager {
+            changes: apis.service(drive::ChangesService::new),
+            files: apis.service(drive::FilesService::new),
+            channels: apis.service(drive::ChannelsService::new),
+            address: address.into(),
+            token: None,
+            ttl: Duration::from_secs(24 * 3600),
+            renew_before: Duration::from_secs(3600),
+            active: vec![],
+        }
+    }
+
+    /// Create channels with this token, which is sent along with every notification (e.g. to
+    /// be checked by `NotificationHandler::with_token()`).
+    pub fn with_token<S: Into<String>>(mut self, token: S) -> ChannelManager<C> {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Request channels living for `ttl`, and renew them `renew_before` they expire. Drive may
+    /// shorten the lifetime; the actual expiration is used for scheduling renewals, and channels
+    /// living shorter than `renew_before` are renewed halfway through their lifetime. Fails if
+    /// `ttl` isn't longer than `renew_before`, as channels would be renewed right away.
+    pub fn with_lifetime(
+        mut self,
+        ttl: Duration,
+        renew_before: Duration,
+    ) -> Result<ChannelManager<C>> {
+        if ttl <= renew_before {
+            return Err(ApiError::InputDataError(format!(
+                "ChannelManager: lifetime {:?} must be longer than renewal margin {:?}",
+                ttl, renew_before
+            ))
+            .into());
+        }
+        self.ttl = ttl;
+        self.renew_before = renew_before;
+        Ok(self)
+    }
+
+    /// Use `scopes` for all requests instead of the default scopes.
+    pub fn set_scopes(&mut self, scopes: &[&str]) {
+        self.changes.set_scopes(scopes);
+        self.files.set_scopes(scopes);
+        self.channels.set_scopes(scopes);
+    }
+
+    /// The channels currently active.
+    pub fn active(&self) -> &[ActiveChannel] {
+        &self.active
+    }
+
+    /// The name of the channel `channel_id` (e.g. `Notification::channel_id`).
+    pub fn name_of(&self, channel_id: &str) -> Option<&str> {
+        self.active
+            .iter()
+            .find(|a| a.channel.id.as_deref() == Some(channel_id))
+            .map(|a| a.name.as_str())
+    }
+
+    /// Create a channel for `target`, and keep it alive under `name`. An existing channel with
+    /// the same name is stopped.
+    pub async fn watch<S: Into<String>>(
+        &mut self,
+        name: S,
+        target: WatchTarget,
+    ) -> Result<&ActiveChannel> {
+        let name = name.into();
+        let (channel, created, expiration) = self.create(&target).await?;
+        let new = ActiveChannel {
+            name: name.clone(),
+            target,
+            channel,
+            created,
+            expiration,
+        };
+        let index = match self.active.iter().position(|a| a.name == name) {
+            Some(i) => {
+                let old = std::mem::replace(&mut self.active[i], new);
+                self.stop(&old.channel).await?;
+                i
+            }
+            None => {
+                self.active.push(new);
+                self.active.len() - 1
+            }
+        };
+        Ok(&self.active[index])
+    }
+
+    /// Stop the channel `name` and forget it.
+    pub async fn unwatch(&mut self, name: &str) -> Result<()> {
+        if let Some(i) = self.active.iter().position(|a| a.name == name) {
+            let old = self.active.remove(i);
+            self.stop(&old.channel).await?;
+        }
+        Ok(())
+    }
+
+    /// When the next channel is due for renewal.
+    pub fn next_renewal(&self) -> Option<DateTime<Utc>> {
+        self.active
+            .iter()
+            .map(|a| a.renewal(self.renew_before))
+            .min()
+    }
+
+    /// Renew all channels that are due, and return how many were renewed.
+    pub async fn renew_due(&mut self) -> Result<usize> {
+        let now = Utc::now();
+        let mut renewed = 0;
+        for i in 0..self.active.len() {
+            if self.active[i].renewal(self.renew_before) > now {
+                continue;
+            }
+            let (channel, created, expiration) = self.create(&self.active[i].target).await?;
+            let old = std::mem::replace(&mut self.active[i].channel, channel);
+            self.active[i].created = created;
+            self.active[i].expiration = expiration;
+            info!(
+                "ChannelManager: renewed channel {} ({:?} replaces {:?})",
+                self.active[i].name, self.active[i].channel.id, old.id
+            );
+            self.stop(&old).await?;
+            renewed += 1;
+        }
+        Ok(renewed)
+    }
+
+    /// Renew channels when they are due until `shutdown` completes, then stop all channels.
+    /// Failed renewals are retried every minute; once a channel has expired meanwhile, all
+    /// channels are stopped and the error is returned.
+    pub async fn run<F: Future<Output = ()>>(&mut self, shutdown: F) -> Result<()> {
+        futures::pin_mut!(shutdown);
+        let mut retry = false;
+        loop {
+            let wait = if retry {
+                Duration::from_secs(60)
+            } else {
+                match self.next_renewal() {
+                    Some(t) => (t - Utc::now()).to_std().unwrap_or_default(),
+                    None => Duration::from_secs(3600),
+                }
+            };
+            let delay = tokio::time::delay_for(wait);
+            futures::pin_mut!(delay);
+            if let futures::future::Either::Left(_) =
+                futures::future::select(shutdown.as_mut(), delay).await
+            {
+                return self.stop_all().await;
+            }
+            retry = match self.renew_due().await {
+                Ok(_) => false,
+                Err(e) => {
+                    let expired = self.active.iter().any(|a| a.expiration <= Utc::now());
+                    error!("ChannelManager: renewal failed: {}", e);
+                    if expired {
+                        self.stop_all().await?;
+                        return Err(e);
+                    }
+                    true
+                }
+            };
+        }
+    }
+
+    /// Stop all channels. All are attempted; the first error is returned.
+    pub async fn stop_all(&mut self) -> Result<()> {
+        let mut result = Ok(());
+        for active in std::mem::take(&mut self.active) {
+            if let Err(e) = self.stop(&active.channel).await {
+                warn!("ChannelManager: stopping {} failed: {}", active.name, e);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Create a new channel for `target`. Returns it with its creation and expiration time.
+    async fn create(
+        &self,
+        target: &WatchTarget,
+    ) -> Result<(drive::Channel, DateTime<Utc>, DateTime<Utc>)> {
+        let created = Utc::now();
+        let requested = DateTime::<Utc>::from(SystemTime::from(created) + self.ttl);
+        let mut req = drive::Channel::default();
+        req.id = Some(channel_id().into());
+        req.typ = Some("web_hook".into());
+        req.address = Some(self.address.clone());
+        req.token = self.token.clone();
+        req.expiration = Some(requested.timestamp_millis());
+
+        let channel = match target {
+            WatchTarget::Changes(params) => {
+                let mut params = params.clone();
+                let mut token_params = drive::ChangesGetStartPageTokenParams::default();
+                token_params.drive_id = params.drive_id.clone();
+                token_params.supports_all_drives = params.supports_all_drives;
+                params.page_token = self
+                    .changes
+                    .get_start_page_token(&token_params)
+                    .await?
+                    .start_page_token
+                    .ok_or_else(|| {
+                        ApiError::InputDataError(
+                            "watch: response contained no startPageToken".into(),
+                        )
+                    })?;
+                self.changes.watch(&params, &req).await?
+            }
+            WatchTarget::File(params) => {
+                match self.files.watch(params, &req).await?.do_it(None).await? {
+                    DownloadResult::Response(channel) => channel,
+                    DownloadResult::Downloaded => {
+                        return Err(ApiError::InputDataError(format!(
+                            "watch: no channel received for {}",
+                            params.file_id
+                        ))
+                        .into())
+                    }
+                }
+            }
+        };
+        let expiration = channel
+            .expiration
+            .filter(|ms| *ms > 0)
+            .map(|ms| DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_millis(ms as u64)))
+            .unwrap_or(requested);
+        debug!(
+            "ChannelManager: created channel {:?}, expiring {}",
+            channel.id, expiration
+        );
+        Ok((channel, created, expiration))
+    }
+
+    /// Stop `channel`, ignoring channels that are already gone.
+    async fn stop(&self, channel: &drive::Channel) -> Result<()> {
+        let mut req = drive::Channel::default();
+        req.id = channel.id.clone();
+        req.resource_id = channel.resource_id.clone();
+        match self
+            .channels
+            .stop(&drive::ChannelsStopParams::default(), &req)
+            .await
+        {
+            Err(e) if ApiError::is_not_found(&e) => Ok(()),
+            r => r,
+        }
+    }
+}
+
+/// When a channel created at `created` and expiring at `expiration` is due for renewal:
+/// `renew_before` its expiration, but not before the middle of its lifetime, so that channels
+/// shortened by Drive aren't renewed over and over again.
+fn renewal_time(
+    created: DateTime<Utc>,
+    expiration: DateTime<Utc>,
+    renew_before: Duration,
+) -> DateTime<Utc> {
+    let renewal = SystemTime::from(expiration).checked_sub(renew_before);
+    let renewal = DateTime::<Utc>::from(renewal.unwrap_or(UNIX_EPOCH));
+    let midpoint = created + (expiration - created) / 2;
+    renewal.max(midpoint)
+}
+
+/// A new, unique channel ID.
+fn channel_id() -> String {
+    let now = Utc::now();
+    format!(
+        "async-google-apis-{:x}-{:x}-{:x}",
+        now.timestamp(),
+        now.timestamp_subsec_nanos(),
+        CHANNEL_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_renewal_time() {
+        let hour = Duration::from_secs(3600);
+        // Renewed `renew_before` the expiration.
+        assert_eq!(
+            renewal_time(at("2021-01-01T00:00:00Z"), at("2021-01-02T00:00:00Z"), hour),
+            at("2021-01-01T23:00:00Z")
+        );
+        // Lifetimes shortened below `renew_before` are renewed halfway.
+        assert_eq!(
+            renewal_time(at("2021-01-01T00:00:00Z"), at("2021-01-01T00:30:00Z"), hour),
+            at("2021-01-01T00:15:00Z")
+        );
+        assert_eq!(
+            renewal_time(at("2021-01-01T00:00:00Z"), at("2021-01-01T02:00:00Z"), hour),
+            at("2021-01-01T01:00:00Z")
+        );
+        // Already expired channels are due right away.
+        assert_eq!(
+            renewal_time(at("2021-01-01T00:00:00Z"), at("2021-01-01T00:00:00Z"), hour),
+            at("2021-01-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_with_lifetime() {
+        let apis = GoogleApis::new(MockClient::new(), StaticToken("t".into()));
+        let hour = Duration::from_secs(3600);
+        assert!(ChannelManager::new(&apis, "https://example.com/")
+            .with_lifetime(2 * hour, hour)
+            .is_ok());
+        assert!(ChannelManager::new(&apis, "https://example.com/")
+            .with_lifetime(hour, hour)
+            .is_err());
+        assert!(ChannelManager::new(&apis, "https://example.com/")
+            .with_lifetime(hour, 2 * hour)
+            .is_err());
+    }
+
+    fn file_target(file_id: &str) -> WatchTarget {
+        let mut params = drive::FilesWatchParams::default();
+        params.file_id = file_id.into();
+        WatchTarget::File(params)
+    }
+
+    fn channel(id: &str, expiration: DateTime<Utc>) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "resourceId": format!("r-{}", id),
+            "expiration": expiration.timestamp_millis().to_string()
+        })
+    }
+
+    #[test]
+    fn test_renew_due() {
+        let mock = MockClient::new();
+        let apis = GoogleApis::new(mock.clone(), StaticToken("t".into()));
+        let mut manager = ChannelManager::new(&apis, "https://example.com/notify");
+        let later = DateTime::<Utc>::from(SystemTime::now() + Duration::from_secs(20 * 3600));
+        // "old" was shortened by Drive and has expired already.
+        mock.push(MockResponse::json(&channel(
+            "old",
+            at("2021-01-01T00:00:00Z"),
+        )))
+        .push(MockResponse::json(&channel("other", later)));
+        futures::executor::block_on(async {
+            manager.watch("a", file_target("f1")).await.unwrap();
+            manager.watch("b", file_target("f2")).await.unwrap();
+        });
+        mock.clear_requests();
+
+        // The new channel is created before the old one is stopped; a channel that is already
+        // gone doesn't fail the renewal.
+        mock.push(MockResponse::json(&channel("new", later)))
+            .push(MockResponse::error(404, "notFound", "Channel not found"));
+        let renewed = futures::executor::block_on(manager.renew_due()).unwrap();
+        assert_eq!(renewed, 1);
+        assert_eq!(manager.name_of("new"), Some("a"));
+        assert_eq!(manager.name_of("old"), None);
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].uri.path(), "/drive/v3/files/f1/watch");
+        let created: drive::Channel = requests[0].json().unwrap();
+        assert_eq!(
+            created.address.as_deref(),
+            Some("https://example.com/notify")
+        );
+        assert_ne!(created.id.as_deref(), Some("old"));
+        assert_eq!(requests[1].uri.path(), "/drive/v3/channels/stop");
+        let stopped: drive::Channel = requests[1].json().unwrap();
+        assert_eq!(stopped.id.as_deref(), Some("old"));
+        assert_eq!(stopped.resource_id.as_deref(), Some("r-old"));
+
+        // Nothing else is due.
+        mock.clear_requests();
+        assert_eq!(futures::executor::block_on(manager.renew_due()).unwrap(), 0);
+        assert!(mock.requests().is_empty());
+    }
+
+    #[test]
+    fn test_stop_all() {
+        let mock = MockClient::new();
+        let apis = GoogleApis::new(mock.clone(), StaticToken("t".into()));
+        let mut manager = ChannelManager::new(&apis, "https://example.com/notify");
+        let later = DateTime::<Utc>::from(SystemTime::now() + Duration::from_secs(20 * 3600));
+        mock.push(MockResponse::json(&channel("c1", later)))
+            .push(MockResponse::json(&channel("c2", later)))
+            .push(MockResponse::json(&channel("c3", later)));
+        futures::executor::block_on(async {
+            manager.watch("a", file_target("f1")).await.unwrap();
+            manager.watch("b", file_target("f2")).await.unwrap();
+            manager.watch("c", file_target("f3")).await.unwrap();
+        });
+        mock.clear_requests();
+
+        // All channels are stopped, also after an error; the first error is returned.
+        mock.push(MockResponse::error(404, "notFound", "Channel not found"))
+            .push(MockResponse::error(500, "backendError", "Oops"))
+            .push(MockResponse::status(204));
+        assert!(futures::executor::block_on(manager.stop_all()).is_err());
+        assert!(manager.active().is_empty());
+        let stopped: Vec<String> = mock
+            .requests()
+            .iter()
+            .map(|r| r.json::<drive::Channel>().unwrap().id.unwrap())
+            .collect();
+        assert_eq!(stopped, vec!["c1", "c2", "c3"]);
+    }
+}
@@ -0,0 +1,161 @@
+//! Reports on the review status of documents: open and resolved comments, authors and the latest
+//! activity of every file in a folder tree.
+
+use crate::drive_v3_types as drive;
+use crate::folders::FOLDER_MIME_TYPE;
+use crate::pages::page_stream;
+use async_google_apis_common::*;
+
+use futures::TryStreamExt;
+
+/// Fields requested for comments in `CommentsService::report()`.
+const REPORT_COMMENT_FIELDS: &str = "nextPageToken,comments(author,createdTime,modifiedTime,\
+                                     deleted,resolved,replies(author,modifiedTime,deleted))";
+
+/// Comment statistics of one file, part of a `CommentsReport`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct FileComments {
+    pub file_id: drive::FileId,
+    pub name: String,
+    /// Number of comments not resolved yet.
+    pub open: usize,
+    pub resolved: usize,
+    /// The latest time a comment or reply was created or modified.
+    pub last_activity: Option<DateTime<Utc>>,
+    /// Authors of comments and replies (email addresses, or display names if the email address
+    /// isn't visible), sorted and without duplicates.
+    pub authors: Vec<String>,
+}
+
+impl FileComments {
+    /// Summarize the (non-deleted) `comments` of `file`.
+    pub fn new(file: &drive::File, comments: &[drive::Comment]) -> FileComments {
+        let mut summary = FileComments {
+            file_id: file.id.clone().unwrap_or_default(),
+            name: file.name.clone().unwrap_or_default(),
+            ..Default::default()
+        };
+        let mut authors = std::collections::BTreeSet::new();
+        for c in comments.iter().filter(|c| c.deleted != Some(true)) {
+            if c.resolved == Some(true) {
+                summary.resolved += 1;
+            } else {
+                summary.open += 1;
+            }
+            authors.extend(c.author.as_ref().and_then(author_name));
+            let replies = c.replies.as_deref().unwrap_or_default();
+            let replies = replies.iter().filter(|r| r.deleted != Some(true));
+            let times = c.created_time.iter().chain(c.modified_time.iter()).copied();
+            let mut latest = times.max();
+            for r in replies {
+                authors.extend(r.author.as_ref().and_then(author_name));
+                latest = latest.max(r.modified_time);
+            }
+            summary.last_activity = summary.last_activity.max(latest);
+        }
+        summary.authors = authors.into_iter().collect();
+        summary
+    }
+}
+
+fn author_name(user: &drive::User) -> Option<String> {
+    user.email_address
+        .clone()
+        .or_else(|| user.display_name.clone())
+}
+
+/// The result of `CommentsService::report()`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CommentsReport {
+    /// ID of the folder the report covers.
+    pub root: String,
+    /// Files with at least one comment, in the order they were found.
+    pub files: Vec<FileComments>,
+}
+
+impl CommentsReport {
+    /// Number of open comments in all files.
+    pub fn open(&self) -> usize {
+        self.files.iter().map(|f| f.open).sum()
+    }
+
+    /// Number of resolved comments in all files.
+    pub fn resolved(&self) -> usize {
+        self.files.iter().map(|f| f.resolved).sum()
+    }
+
+    /// Files with open comments.
+    pub fn with_open_comments(&self) -> impl Iterator<Item = &FileComments> {
+        self.files.iter().filter(|f| f.open > 0)
+    }
+}
+
+impl<C: HttpClient> drive::CommentsService<C> {
+    /// Fetch the pages of comments of a file one after another, following page tokens. Each page
+    /// is only requested once the previous one has been consumed. Note that `comments.list`
+    /// requires `fields` to be set.
+    pub fn list_stream<'a>(
+        &'a self,
+        params: &drive::CommentsListParams,
+    ) -> impl futures::Stream<Item = Result<drive::CommentList>> + 'a {
+        let params = params.clone();
+        page_stream(params.page_token.clone(), move |token| {
+            let mut params = params.clone();
+            params.page_token = token;
+            async move { self.list(&params).await }
+        })
+    }
+
+    /// Like `list_stream()`, but yielding the individual comments. Their replies are included.
+    pub fn list_comments_stream<'a>(
+        &'a self,
+        params: &drive::CommentsListParams,
+    ) -> impl futures::Stream<Item = Result<drive::Comment>> + 'a {
+        self.list_stream(params)
+            .map_ok(|list| {
+                futures::stream::iter(list.comments.unwrap_or_default().into_iter().map(Ok))
+            })
+            .try_flatten()
+    }
+
+    /// Summarize the comments on every file in the folder `root` and its subfolders, skipping
+    /// trashed files. `files` is used for listing the folders.
+    pub async fn report(
+        &self,
+        files: &drive::FilesService<C>,
+        root: &str,
+    ) -> Result<CommentsReport> {
+        let mut report = CommentsReport {
+            root: root.to_string(),
+            files: vec![],
+        };
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("nextPageToken,files(id,name,mimeType)".into());
+        let mut params = drive::FilesListParams::default();
+        params.drive_params = Some(general_params);
+        params.q = Some("trashed = false".into());
+        params.supports_all_drives = Some(true);
+        params.include_items_from_all_drives = Some(true);
+        let mut items = Box::pin(files.walk_folder(root, &params));
+        while let Some((_, file)) = items.try_next().await? {
+            let id = match file.id.as_ref() {
+                Some(id) if file.mime_type.as_deref() != Some(FOLDER_MIME_TYPE) => id,
+                _ => continue,
+            };
+            let comments = self.all_comments(id).await?;
+            if !comments.is_empty() {
+                report.files.push(FileComments::new(&file, &comments));
+            }
+        }
+        Ok(report)
+    }
+
+    async fn all_comments(&self, file_id: &str) -> Result<Vec<drive::Comment>> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(REPORT_COMMENT_FIELDS.into());
+        let mut params = drive::CommentsListParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        self.list_comments_stream(&params).try_collect().await
+    }
+}
@@ -0,0 +1,112 @@
+//! Transparent compression of file content, e.g. for logs or backups stored as large text files.
+//!
+//! Compressed uploads record the codec in the file's `appProperties` (key `contentEncoding`), so
+//! that `FilesService::get_media_decompressed()` knows how to restore the original content.
+//! Codecs are pluggable through the `ContentCodec` trait; `Gzip` is provided.
+
+use crate::drive_v3_types as drive;
+use crate::folders::validate_new_file;
+use async_google_apis_common::*;
+
+use std::io::{Read, Write};
+
+/// Key in `File.app_properties` naming the codec that the content was encoded with.
+pub const CONTENT_ENCODING_PROPERTY: &str = "contentEncoding";
+
+/// A reversible content encoding.
+pub trait ContentCodec: Send + Sync {
+    /// Name recorded in the `contentEncoding` app property, e.g. `gzip`.
+    fn name(&self) -> &str;
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>>;
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// gzip compression, at the given level (0-9).
+#[derive(Debug, Clone, Copy)]
+pub struct Gzip(pub u32);
+
+impl Default for Gzip {
+    fn default() -> Gzip {
+        Gzip(6)
+    }
+}
+
+impl ContentCodec for Gzip {
+    fn name(&self) -> &str {
+        "gzip"
+    }
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut enc = flate2::write::GzEncoder::new(vec![], flate2::Compression::new(self.0));
+        enc.write_all(data)?;
+        Ok(enc.finish()?)
+    }
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        flate2::read::GzDecoder::new(data).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Like `create_upload()`, but encodes `data` with `codec` first, and records the codec in
+    /// the new file's `appProperties`. `req` is checked with `validate_new_file()` first.
+    pub async fn create_upload_compressed(
+        &self,
+        params: &drive::FilesCreateParams,
+        req: &drive::File,
+        data: &[u8],
+        codec: &dyn ContentCodec,
+    ) -> Result<drive::File> {
+        validate_new_file(req, true)?;
+        let mut req = req.clone();
+        req.app_properties
+            .get_or_insert_with(HashMap::new)
+            .insert(CONTENT_ENCODING_PROPERTY.into(), codec.name().into());
+        let encoded = codec.encode(data)?;
+        debug!(
+            "create_upload_compressed: {} encoded {} bytes into {}",
+            codec.name(),
+            data.len(),
+            encoded.len()
+        );
+        self.create_upload(params, &req, encoded.into()).await
+    }
+
+    /// Download the content of `file_id` like `get_media_bytes()`, and decode it with the codec
+    /// from the file's `contentEncoding` app property. Content without that property is returned
+    /// as is; an unknown codec results in an error.
+    ///
+    /// `max_bytes` limits the size of the stored (encoded) content.
+    pub async fn get_media_decompressed(
+        &self,
+        file_id: &str,
+        max_bytes: usize,
+        codecs: &[&dyn ContentCodec],
+    ) -> Result<Vec<u8>> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("appProperties".into());
+        let mut params = drive::FilesGetParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        params.supports_all_drives = Some(true);
+        let encoding = match self.get(&params).await?.do_it(None).await? {
+            DownloadResult::Response(f) => f
+                .app_properties
+                .and_then(|mut p| p.remove(CONTENT_ENCODING_PROPERTY)),
+            DownloadResult::Downloaded => None,
+        };
+
+        let data = self.get_media_bytes(file_id, max_bytes).await?;
+        match encoding {
+            None => Ok(data),
+            Some(name) => match codecs.iter().find(|c| c.name() == name) {
+                Some(codec) => codec.decode(&data),
+                None => Err(ApiError::InputDataError(format!(
+                    "get_media_decompressed: file {} has unknown content encoding {}",
+                    file_id, name
+                ))
+                .into()),
+            },
+        }
+    }
+}
@@ -0,0 +1,662 @@
+//! Uploading a local directory tree, creating the folder structure in Drive, and downloading a
+//! folder tree into a local directory.
+
+use crate::drive_v3_types as drive;
+use crate::folders::FOLDER_MIME_TYPE;
+use crate::listing::{OrderBy, OrderKey};
+use crate::query::Query;
+use crate::upload::numbered_name;
+use async_google_apis_common::*;
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// MIME types by (lower-case) file extension, for `guess_mime_type()`.
+const MIME_TYPES: &[(&str, &str)] = &[
+    ("avi", "video/x-msvideo"),
+    ("bmp", "image/bmp"),
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("doc", "application/msword"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ),
+    ("gif", "image/gif"),
+    ("gz", "application/gzip"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("md", "text/markdown"),
+    ("mov", "video/quicktime"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("odp", "application/vnd.oasis.opendocument.presentation"),
+    ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+    ("odt", "application/vnd.oasis.opendocument.text"),
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("ppt", "application/vnd.ms-powerpoint"),
+    (
+        "pptx",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    ),
+    ("rtf", "application/rtf"),
+    ("svg", "image/svg+xml"),
+    ("tar", "application/x-tar"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("tsv", "text/tab-separated-values"),
+    ("txt", "text/plain"),
+    ("wav", "audio/wav"),
+    ("webm", "video/webm"),
+    ("webp", "image/webp"),
+    ("xls", "application/vnd.ms-excel"),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    ),
+    ("xml", "application/xml"),
+    ("zip", "application/zip"),
+];
+
+/// The MIME type of a file, guessed from the extension of `path`; `application/octet-stream` if
+/// the extension is unknown.
+pub fn guess_mime_type(path: &Path) -> &'static str {
+    mime_type_for_extension(path).unwrap_or("application/octet-stream")
+}
+
+/// The MIME type belonging to the extension of `path`, if it is known.
+pub fn mime_type_for_extension(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    MIME_TYPES
+        .iter()
+        .find(|(e, _)| *e == ext)
+        .map(|(_, mime)| *mime)
+}
+
+/// The result of `FilesService::upload_directory()`.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryUpload {
+    /// The folder created for the uploaded directory.
+    pub root: drive::File,
+    /// Folders created for subdirectories.
+    pub folders: Vec<drive::File>,
+    /// Uploaded files, in the order their uploads finished.
+    pub files: Vec<drive::File>,
+}
+
+/// The formats Google Docs, Sheets etc. are exported to by
+/// `FilesService::download_directory()`, as (native MIME type, export MIME type, file
+/// extension).
+#[derive(Debug, Clone, Default)]
+pub struct ExportFormats {
+    formats: Vec<(String, String, String)>,
+}
+
+impl ExportFormats {
+    /// No exports: native documents are skipped.
+    pub fn none() -> ExportFormats {
+        Default::default()
+    }
+
+    /// Documents, spreadsheets and presentations as Microsoft Office files, drawings as PNG.
+    pub fn office() -> ExportFormats {
+        ExportFormats::none()
+            .with(
+                "application/vnd.google-apps.document",
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+                "docx",
+            )
+            .with(
+                "application/vnd.google-apps.spreadsheet",
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                "xlsx",
+            )
+            .with(
+                "application/vnd.google-apps.presentation",
+                "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+                "pptx",
+            )
+            .with("application/vnd.google-apps.drawing", "image/png", "png")
+    }
+
+    /// Documents, spreadsheets (first sheet only), presentations and drawings as PDF.
+    pub fn pdf() -> ExportFormats {
+        let pdf = |fmts: ExportFormats, native: &str| fmts.with(native, "application/pdf", "pdf");
+        let fmts = pdf(
+            ExportFormats::none(),
+            "application/vnd.google-apps.document",
+        );
+        let fmts = pdf(fmts, "application/vnd.google-apps.spreadsheet");
+        let fmts = pdf(fmts, "application/vnd.google-apps.presentation");
+        pdf(fmts, "application/vnd.google-apps.drawing")
+    }
+
+    /// Export documents of type `native` to `export_mime`, saved with the file extension
+    /// `extension`. Replaces an earlier format for `native`.
+    pub fn with(mut self, native: &str, export_mime: &str, extension: &str) -> ExportFormats {
+        self.formats.retain(|(n, _, _)| n != native);
+        self.formats
+            .push((native.into(), export_mime.into(), extension.into()));
+        self
+    }
+
+    /// Export MIME type and extension for documents of type `native`.
+    pub fn get(&self, native: &str) -> Option<(&str, &str)> {
+        self.formats
+            .iter()
+            .find(|(n, _, _)| n == native)
+            .map(|(_, mime, ext)| (mime.as_str(), ext.as_str()))
+    }
+}
+
+/// The result of `FilesService::download_directory()`.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryDownload {
+    /// Number of local directories created for subfolders.
+    pub folders: usize,
+    /// Local paths of downloaded and exported files, in the order they finished.
+    pub files: Vec<PathBuf>,
+    /// Files skipped because they have no content and no export format (e.g. forms or
+    /// shortcuts).
+    pub skipped: Vec<drive::File>,
+}
+
+/// A file to download (or export, with the export MIME type) to a local path.
+#[derive(Debug, PartialEq)]
+struct PendingDownload {
+    file_id: drive::FileId,
+    export_mime: Option<String>,
+    path: PathBuf,
+}
+
+/// What `download_directory()` does with an item of the folder tree.
+#[derive(Debug, PartialEq)]
+enum LocalItem {
+    /// A subfolder, to be created at this path.
+    Folder(PathBuf),
+    Download(PendingDownload),
+    /// A native document without export format.
+    Skipped,
+}
+
+/// The local paths of the folders being downloaded, and the names taken in each of them.
+struct LocalFolders {
+    folders: HashMap<drive::FileId, (PathBuf, HashSet<String>)>,
+}
+
+impl LocalFolders {
+    fn new(root: &str, dest: &Path) -> LocalFolders {
+        let mut folders = HashMap::new();
+        folders.insert(
+            drive::FileId::from(root),
+            (dest.to_path_buf(), HashSet::new()),
+        );
+        LocalFolders { folders }
+    }
+
+    /// Choose a local path for `file` in the folder `parent`. Returns `None` for files without ID
+    /// or MIME type, and for files in folders not seen before.
+    fn place(
+        &mut self,
+        parent: &drive::FileId,
+        file: &drive::File,
+        exports: &ExportFormats,
+    ) -> Option<LocalItem> {
+        let (id, mime) = match (file.id.clone(), file.mime_type.as_deref()) {
+            (Some(id), Some(mime)) => (id, mime),
+            _ => return None,
+        };
+        let (local, taken) = self.folders.get_mut(parent)?;
+        let name = local_name(file.name.as_deref().unwrap_or(&id));
+        if mime == FOLDER_MIME_TYPE {
+            let path = local.join(unique_name(taken, name));
+            self.folders.insert(id, (path.clone(), HashSet::new()));
+            Some(LocalItem::Folder(path))
+        } else if !mime.starts_with("application/vnd.google-apps.") {
+            Some(LocalItem::Download(PendingDownload {
+                file_id: id,
+                export_mime: None,
+                path: local.join(unique_name(taken, name)),
+            }))
+        } else if let Some((export_mime, ext)) = exports.get(mime) {
+            let name = format!("{}.{}", name, ext);
+            Some(LocalItem::Download(PendingDownload {
+                file_id: id,
+                export_mime: Some(export_mime.into()),
+                path: local.join(unique_name(taken, name)),
+            }))
+        } else {
+            Some(LocalItem::Skipped)
+        }
+    }
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Upload the local directory `dir` with all files and subdirectories into the folder
+    /// `parent` (or the root folder), and return the created folders and files. Names are kept;
+    /// MIME types are guessed with `guess_mime_type()`.
+    ///
+    /// Folders are created first, one after another, then up to `concurrency` files are
+    /// uploaded at the same time with resumable uploads. Symbolic links and names that aren't
+    /// valid UTF-8 are skipped. The first failure stops the upload; folders and files created
+    /// until then are left in place.
+    pub async fn upload_directory(
+        &self,
+        dir: &Path,
+        parent: Option<&str>,
+        concurrency: usize,
+    ) -> Result<DirectoryUpload> {
+        use futures::{StreamExt, TryStreamExt};
+
+        // Resolves `.` and `..`, which have no name.
+        let dir = tokio::fs::canonicalize(dir).await?;
+        let name = entry_name(&dir)?;
+        let root = self.create_directory_folder(&name, parent).await?;
+        let mut result = DirectoryUpload {
+            root: root.clone(),
+            ..Default::default()
+        };
+
+        // Directories still to be read, and files to be uploaded, with their Drive folders.
+        let mut dirs = vec![(dir, root.id.unwrap_or_default())];
+        let mut files: Vec<(PathBuf, drive::FileId)> = vec![];
+        while let Some((local, folder_id)) = dirs.pop() {
+            let mut entries = tokio::fs::read_dir(&local).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let file_type = entry.file_type().await?;
+                if entry.file_name().to_str().is_none() {
+                    warn!("upload_directory: skipping {}", path.display());
+                } else if file_type.is_dir() {
+                    let folder = self
+                        .create_directory_folder(&entry_name(&path)?, Some(folder_id.as_str()))
+                        .await?;
+                    dirs.push((path, folder.id.clone().unwrap_or_default()));
+                    result.folders.push(folder);
+                } else if file_type.is_file() {
+                    files.push((path, folder_id.clone()));
+                }
+            }
+        }
+
+        result.files = futures::stream::iter(files)
+            .map(|(path, folder_id)| async move {
+                self.upload_directory_file(&path, &folder_id)
+                    .await
+                    .map_err(|e| e.context(format!("upload_directory: {}", path.display())))
+            })
+            .buffer_unordered(concurrency.max(1))
+            .try_collect()
+            .await?;
+        Ok(result)
+    }
+
+    async fn create_directory_folder(
+        &self,
+        name: &str,
+        parent: Option<&str>,
+    ) -> Result<drive::File> {
+        let mut folder = drive::File::default();
+        folder.mime_type = Some(FOLDER_MIME_TYPE.into());
+        folder.name = Some(name.into());
+        folder.parents = parent.map(|p| vec![p.to_string()]);
+
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("id,name,mimeType,parents".into());
+        let mut params = drive::FilesCreateParams::default();
+        params.drive_params = Some(general_params);
+        params.supports_all_drives = Some(true);
+        self.create(&params, &folder).await
+    }
+
+    async fn upload_directory_file(&self, path: &Path, folder_id: &str) -> Result<drive::File> {
+        let mut req = drive::File::default();
+        req.name = Some(entry_name(path)?);
+        req.parents = Some(vec![folder_id.to_string()]);
+        req.mime_type = Some(guess_mime_type(path).into());
+
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("id,name,mimeType,parents,size".into());
+        let mut params = drive::FilesCreateParams::default();
+        params.drive_params = Some(general_params);
+        params.supports_all_drives = Some(true);
+        let src = tokio::fs::File::open(path).await?;
+        self.create_resumable_upload(&params, &req)
+            .await?
+            .upload_file(src)
+            .await
+    }
+
+    /// Download the folder `folder_id` with all files and subfolders into the local directory
+    /// `dest`, which is created if necessary, and return what was downloaded. Existing local
+    /// files are overwritten.
+    ///
+    /// Content is streamed into the local files. Native documents are exported according to
+    /// `exports` (with the format's extension appended to the name), or skipped. Characters not
+    /// allowed in local names (`/`) are replaced, and names occurring several times in a folder
+    /// are numbered: `report (1).pdf`. Folders are listed first, then up to `concurrency` files
+    /// are downloaded at the same time. Trashed files are skipped.
+    pub async fn download_directory(
+        &self,
+        folder_id: &str,
+        dest: &Path,
+        exports: &ExportFormats,
+        concurrency: usize,
+    ) -> Result<DirectoryDownload> {
+        use futures::{StreamExt, TryStreamExt};
+
+        let mut result = DirectoryDownload::default();
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("nextPageToken,files(id,name,mimeType)".into());
+        let mut params = drive::FilesListParams::default();
+        params.drive_params = Some(general_params);
+        params.q = Some(Query::trashed(false).to_string());
+        params.order_by = Some(OrderBy::new().asc(OrderKey::Name));
+        params.supports_all_drives = Some(true);
+        params.include_items_from_all_drives = Some(true);
+
+        let mut folders = LocalFolders::new(folder_id, dest);
+        tokio::fs::create_dir_all(dest).await?;
+        let mut pending = vec![];
+        let mut items = Box::pin(self.walk_folder(folder_id, &params));
+        while let Some((parent, file)) = items.try_next().await? {
+            match folders.place(&parent, &file, exports) {
+                Some(LocalItem::Folder(path)) => {
+                    tokio::fs::create_dir_all(&path).await?;
+                    result.folders += 1;
+                }
+                Some(LocalItem::Download(download)) => pending.push(download),
+                Some(LocalItem::Skipped) => result.skipped.push(file),
+                None => {}
+            }
+        }
+
+        result.files = futures::stream::iter(pending)
+            .map(|download| async move {
+                self.download_directory_file(&download).await.map_err(|e| {
+                    e.context(format!("download_directory: {}", download.path.display()))
+                })?;
+                Ok::<PathBuf, Error>(download.path)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .try_collect()
+            .await?;
+        Ok(result)
+    }
+
+    async fn download_directory_file(&self, download: &PendingDownload) -> Result<()> {
+        let mut dst = tokio::fs::File::create(&download.path).await?;
+        match download.export_mime.as_ref() {
+            None => self.get_media(&download.file_id, &mut dst).await,
+            Some(mime) => {
+                self.export_to(&download.file_id, mime, &mut dst).await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The last component of `path`, as a file or folder name.
+fn entry_name(path: &Path) -> Result<String> {
+    match path.file_name().and_then(|n| n.to_str()) {
+        Some(name) => Ok(name.to_string()),
+        None => Err(ApiError::InputDataError(format!(
+            "upload_directory: invalid name {}",
+            path.display()
+        ))
+        .into()),
+    }
+}
+
+/// `name` with characters not allowed in local file names replaced.
+pub(crate) fn local_name(name: &str) -> String {
+    let name = name.replace(|c: char| c == '/' || c == '\\' || c == '\0', "_");
+    match name.as_str() {
+        "" | "." | ".." => "_".to_string(),
+        _ => name,
+    }
+}
+
+/// `name`, numbered if it is already in `taken`; the result is added to `taken`.
+fn unique_name(taken: &mut HashSet<String>, name: String) -> String {
+    let name = if taken.contains(&name) {
+        (1..)
+            .map(|n| numbered_name(&name, n))
+            .find(|n| !taken.contains(n))
+            .unwrap()
+    } else {
+        name
+    };
+    taken.insert(name.clone());
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, name: &str, mime: &str) -> drive::File {
+        let mut f = drive::File::default();
+        f.id = Some(id.into());
+        f.name = Some(name.into());
+        f.mime_type = Some(mime.into());
+        f
+    }
+
+    fn download(id: &str, export_mime: Option<&str>, path: &str) -> Option<LocalItem> {
+        Some(LocalItem::Download(PendingDownload {
+            file_id: id.into(),
+            export_mime: export_mime.map(Into::into),
+            path: path.into(),
+        }))
+    }
+
+    #[test]
+    fn test_local_name() {
+        assert_eq!(local_name("report.pdf"), "report.pdf");
+        assert_eq!(local_name("a/b\\c\0d"), "a_b_c_d");
+        assert_eq!(local_name(""), "_");
+        assert_eq!(local_name("."), "_");
+        assert_eq!(local_name(".."), "_");
+        assert_eq!(local_name("..."), "...");
+    }
+
+    #[test]
+    fn test_unique_name() {
+        let mut taken = HashSet::new();
+        assert_eq!(unique_name(&mut taken, "a.txt".into()), "a.txt");
+        assert_eq!(unique_name(&mut taken, "a.txt".into()), "a (1).txt");
+        assert_eq!(unique_name(&mut taken, "a.txt".into()), "a (2).txt");
+        assert_eq!(unique_name(&mut taken, "a (1).txt".into()), "a (1) (1).txt");
+        assert_eq!(unique_name(&mut taken, "b".into()), "b");
+        assert_eq!(unique_name(&mut taken, "b".into()), "b (1)");
+        assert_eq!(taken.len(), 6);
+    }
+
+    #[test]
+    fn test_export_formats() {
+        let doc = "application/vnd.google-apps.document";
+        assert_eq!(ExportFormats::none().get(doc), None);
+        let office = ExportFormats::office();
+        assert_eq!(
+            office.get(doc),
+            Some((
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+                "docx"
+            ))
+        );
+        assert_eq!(office.get("application/vnd.google-apps.form"), None);
+        // Replaces the earlier format.
+        let fmts = office.with(doc, "text/plain", "txt");
+        assert_eq!(fmts.get(doc), Some(("text/plain", "txt")));
+        assert_eq!(fmts.formats.len(), 4);
+        assert_eq!(
+            ExportFormats::pdf().get("application/vnd.google-apps.drawing"),
+            Some(("application/pdf", "pdf"))
+        );
+    }
+
+    #[test]
+    fn test_local_folders() {
+        let doc = "application/vnd.google-apps.document";
+        let exports = ExportFormats::office();
+        let mut folders = LocalFolders::new("R", Path::new("/d"));
+        let root = drive::FileId::from("R");
+        let sub = drive::FileId::from("S");
+
+        assert_eq!(
+            folders.place(&root, &item("S", "sub", FOLDER_MIME_TYPE), &exports),
+            Some(LocalItem::Folder("/d/sub".into()))
+        );
+        assert_eq!(
+            folders.place(&root, &item("S2", "sub", FOLDER_MIME_TYPE), &exports),
+            Some(LocalItem::Folder("/d/sub (1)".into()))
+        );
+        assert_eq!(
+            folders.place(&root, &item("A", "a.txt", "text/plain"), &exports),
+            download("A", None, "/d/a.txt")
+        );
+        assert_eq!(
+            folders.place(&root, &item("A2", "a.txt", "text/plain"), &exports),
+            download("A2", None, "/d/a (1).txt")
+        );
+        assert_eq!(
+            folders.place(&sub, &item("B", "x/y", "text/plain"), &exports),
+            download("B", None, "/d/sub/x_y")
+        );
+        // Exported documents are named with the extension of the format.
+        assert_eq!(
+            folders.place(&sub, &item("D", "notes", doc), &exports),
+            download(
+                "D",
+                Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+                "/d/sub/notes.docx"
+            )
+        );
+        assert_eq!(
+            folders.place(&sub, &item("D2", "notes", doc), &ExportFormats::none()),
+            Some(LocalItem::Skipped)
+        );
+        assert_eq!(
+            folders.place(&"X".into(), &item("C", "c", "text/plain"), &exports),
+            None
+        );
+        let mut untyped = item("E", "e", "");
+        untyped.mime_type = None;
+        assert_eq!(folders.place(&root, &untyped, &exports), None);
+    }
+
+    #[tokio::test]
+    async fn test_directory_round_trip() {
+        let dir = std::env::temp_dir().join(format!("drive-directory-test-{}", std::process::id()));
+        let src = dir.join("top");
+        tokio::fs::create_dir_all(src.join("sub")).await.unwrap();
+        tokio::fs::write(src.join("a.txt"), "hello").await.unwrap();
+        tokio::fs::write(src.join("sub/b.txt"), "world")
+            .await
+            .unwrap();
+
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        let session = || {
+            MockResponse::status(200).with_header(
+                hyper::header::LOCATION,
+                "https://www.googleapis.com/upload/drive/v3/files?upload_id=u",
+            )
+        };
+        // Folders are created while reading the directories, then files are uploaded.
+        mock.push(MockResponse::json(&serde_json::json!({"id": "T"})))
+            .push(MockResponse::json(&serde_json::json!({"id": "S"})))
+            .push(session())
+            .push(MockResponse::json(&serde_json::json!({"id": "A"})))
+            .push(session())
+            .push(MockResponse::json(&serde_json::json!({"id": "B"})));
+
+        let upload = files.upload_directory(&src, Some("P"), 1).await.unwrap();
+        assert_eq!(upload.root.id.as_deref(), Some("T"));
+        assert_eq!(upload.folders.len(), 1);
+        let ids: Vec<String> = upload
+            .files
+            .iter()
+            .map(|f| f.id.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(ids, vec!["A", "B"]);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 6);
+        let root: drive::File = requests[0].json().unwrap();
+        assert_eq!(root.name.as_deref(), Some("top"));
+        assert_eq!(root.parents, Some(vec!["P".to_string()]));
+        let sub: drive::File = requests[1].json().unwrap();
+        assert_eq!(sub.mime_type.as_deref(), Some(FOLDER_MIME_TYPE));
+        assert_eq!(sub.parents, Some(vec!["T".to_string()]));
+        let a: drive::File = requests[2].json().unwrap();
+        assert_eq!(a.name.as_deref(), Some("a.txt"));
+        assert_eq!(a.mime_type.as_deref(), Some("text/plain"));
+        assert_eq!(a.parents, Some(vec!["T".to_string()]));
+        assert_eq!(&requests[3].body[..], b"hello");
+        let b: drive::File = requests[4].json().unwrap();
+        assert_eq!(b.parents, Some(vec!["S".to_string()]));
+        assert_eq!(&requests[5].body[..], b"world");
+
+        mock.clear_requests();
+        mock.push(MockResponse::json(&serde_json::json!({
+            "files": [
+                {"id": "A", "name": "a.txt", "mimeType": "text/plain"},
+                {"id": "S", "name": "sub", "mimeType": FOLDER_MIME_TYPE},
+                {"id": "G", "name": "notes", "mimeType": "application/vnd.google-apps.document"},
+                {"id": "F", "name": "form", "mimeType": "application/vnd.google-apps.form"}
+            ]
+        })))
+        .push(MockResponse::json(&serde_json::json!({
+            "files": [{"id": "B", "name": "b.txt", "mimeType": "text/plain"}]
+        })))
+        .push(MockResponse::bytes("text/plain", "hello"))
+        .push(MockResponse::bytes("application/octet-stream", "doc"))
+        .push(MockResponse::bytes("text/plain", "world"));
+
+        let dest = dir.join("copy");
+        let download = files
+            .download_directory("T", &dest, &ExportFormats::office(), 1)
+            .await
+            .unwrap();
+        assert_eq!(download.folders, 1);
+        assert_eq!(
+            download.files,
+            vec![
+                dest.join("a.txt"),
+                dest.join("notes.docx"),
+                dest.join("sub/b.txt")
+            ]
+        );
+        assert_eq!(download.skipped.len(), 1);
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert_eq!(read(dest.join("a.txt")), "hello");
+        assert_eq!(read(dest.join("notes.docx")), "doc");
+        assert_eq!(read(dest.join("sub/b.txt")), "world");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 5);
+        assert!(requests[0]
+            .query_param("q")
+            .unwrap()
+            .contains("'T' in parents"));
+        assert!(requests[1]
+            .query_param("q")
+            .unwrap()
+            .contains("'S' in parents"));
+        assert_eq!(requests[2].query_param("alt").as_deref(), Some("media"));
+        assert!(requests[3].uri.path().ends_with("/files/G/export"));
+        assert_eq!(
+            requests[3].query_param("mimeType").as_deref(),
+            Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document")
+        );
+
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
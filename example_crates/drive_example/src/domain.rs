@@ -0,0 +1,133 @@
+//! Listings of the files visible to a whole domain (`corpora=domain`), and of a domain's shared
+//! drives, e.g. for compliance scans run by an administrator.
+//!
+//! Domain searches may be incomplete: the server then sets `incompleteSearch` on a page, and
+//! some matching files are missing. Listings here report this instead of silently returning
+//! partial results.
+
+use crate::drive_v3_types as drive;
+use crate::pages;
+use async_google_apis_common::*;
+
+use futures::TryStreamExt;
+
+/// The result of `FilesService::list_domain()`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainListing {
+    pub files: Vec<drive::File>,
+    /// Number of pages the server marked as incomplete.
+    pub incomplete_pages: usize,
+}
+
+impl DomainListing {
+    /// Whether no page was marked as incomplete, i.e. all matching files have been listed.
+    pub fn is_complete(&self) -> bool {
+        self.incomplete_pages == 0
+    }
+}
+
+/// Check that `params` are valid for a `corpora=domain` listing, and return them with `corpora`
+/// set to `domain`.
+///
+/// A `driveId` only works with `corpora=drive`, and the deprecated `corpus` parameter conflicts
+/// with `corpora`. Items from shared drives are only included if both
+/// `includeItemsFromAllDrives` and `supportsAllDrives` are set.
+pub fn domain_params(params: &drive::FilesListParams) -> Result<drive::FilesListParams> {
+    let invalid =
+        |msg: &str| Err(ApiError::InputDataError(format!("domain_params: {}", msg)).into());
+    match params.corpora.as_ref() {
+        None | Some(drive::FilesListParamsCorpora::Domain) => {}
+        Some(c) => return invalid(&format!("corpora is {}, not domain", c)),
+    }
+    if params.drive_id.is_some() {
+        return invalid(
+            "driveId can't be used with corpora=domain; list the drive with corpora=drive",
+        );
+    }
+    if params.corpus.is_some() {
+        return invalid("the deprecated corpus parameter conflicts with corpora");
+    }
+    if params.include_items_from_all_drives == Some(true)
+        && params.supports_all_drives != Some(true)
+    {
+        return invalid("includeItemsFromAllDrives requires supportsAllDrives");
+    }
+    let mut params = params.clone();
+    params.corpora = Some(drive::FilesListParamsCorpora::Domain);
+    Ok(params)
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Fetch the pages of a `corpora=domain` listing one after another (see `list_stream()`).
+    /// `params` are checked with `domain_params()` first. Pages with `incomplete_search` set lack
+    /// some matching files.
+    pub fn list_domain_stream<'a>(
+        &'a self,
+        params: &drive::FilesListParams,
+    ) -> Result<impl futures::Stream<Item = Result<drive::FileList>> + 'a> {
+        let params = domain_params(params)?;
+        Ok(self.list_stream(&params))
+    }
+
+    /// Fetch all files visible to the domain that match `params`, following page tokens, and
+    /// count the pages marked as incomplete by the server.
+    pub async fn list_domain(&self, params: &drive::FilesListParams) -> Result<DomainListing> {
+        let mut listing = DomainListing::default();
+        let mut pages = Box::pin(self.list_domain_stream(params)?);
+        while let Some(page) = pages.try_next().await? {
+            if page.incomplete_search == Some(true) {
+                listing.incomplete_pages += 1;
+            }
+            listing.files.extend(page.files.unwrap_or_default());
+        }
+        if !listing.is_complete() {
+            warn!(
+                "list_domain: {} pages were incomplete; some files are missing",
+                listing.incomplete_pages
+            );
+        }
+        Ok(listing)
+    }
+
+    /// Like `list_domain()`, but fails with `ApiError::IncompleteResultsError` as soon as a page
+    /// is marked as incomplete, for scans that must not miss any file.
+    pub async fn list_domain_complete(
+        &self,
+        params: &drive::FilesListParams,
+    ) -> Result<Vec<drive::File>> {
+        let mut files = vec![];
+        let mut pages = Box::pin(self.list_domain_stream(params)?);
+        while let Some(page) = pages.try_next().await? {
+            if page.incomplete_search == Some(true) {
+                return Err(ApiError::IncompleteResultsError(files.len() as u64).into());
+            }
+            files.extend(page.files.unwrap_or_default());
+        }
+        Ok(files)
+    }
+}
+
+impl<C: HttpClient> drive::DrivesService<C> {
+    /// List all shared drives of the domain matching the query `q` (if given), as a domain
+    /// administrator (`useDomainAdminAccess`). Fails with a 403 `HTTPResponseError` if the user
+    /// isn't an administrator of the domain. Files of each drive can then be listed with
+    /// `corpora=drive`.
+    pub async fn list_domain_drives(&self, q: Option<&str>) -> Result<Vec<drive::Drive>> {
+        let mut params = drive::DrivesListParams::default();
+        params.q = q.map(|q| q.to_string());
+        params.use_domain_admin_access = Some(true);
+        pages::list_all(None, |token| {
+            let mut params = params.clone();
+            params.page_token = token;
+            async move { self.list(&params).await }
+        })
+        .await
+        .map_err(|e| {
+            if ApiError::is_forbidden(&e) {
+                e.context("list_domain_drives: domain administrator access is required")
+            } else {
+                e
+            }
+        })
+    }
+}
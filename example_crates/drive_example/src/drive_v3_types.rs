@@ -12,11 +12,39 @@
 //! I'd be happy if you let me know about your use case of this code.
 //!
 //! THIS FILE HAS BEEN GENERATED -- SAVE ANY MODIFICATIONS BEFORE REPLACING.
+//!
+//! ## Renamed identifiers
+//!
+//! Names from the API that collide with Rust keywords have been renamed:
+//!
+//! * `type` → `typ`
 
 use async_google_apis_common::*;
 
-/// Scopes of this API. Convertible to their string representation with `AsRef`.
-#[derive(Debug, Clone, Copy)]
+/// The URLs of the scopes of this API, e.g. for `set_scopes(&[scopes::...])`.
+pub mod scopes {
+    /// See, edit, create, and delete all of your Google Drive files
+    pub const DRIVE: &str = "https://www.googleapis.com/auth/drive";
+    /// View and manage its own configuration data in your Google Drive
+    pub const DRIVE_APPDATA: &str = "https://www.googleapis.com/auth/drive.appdata";
+    /// View and manage Google Drive files and folders that you have opened or created with this app
+    pub const DRIVE_FILE: &str = "https://www.googleapis.com/auth/drive.file";
+    /// View and manage metadata of files in your Google Drive
+    pub const DRIVE_METADATA: &str = "https://www.googleapis.com/auth/drive.metadata";
+    /// View metadata for files in your Google Drive
+    pub const DRIVE_METADATA_READONLY: &str =
+        "https://www.googleapis.com/auth/drive.metadata.readonly";
+    /// View the photos, videos and albums in your Google Photos
+    pub const DRIVE_PHOTOS_READONLY: &str = "https://www.googleapis.com/auth/drive.photos.readonly";
+    /// See and download all your Google Drive files
+    pub const DRIVE_READONLY: &str = "https://www.googleapis.com/auth/drive.readonly";
+    /// Modify your Google Apps Script scripts' behavior
+    pub const DRIVE_SCRIPTS: &str = "https://www.googleapis.com/auth/drive.scripts";
+}
+
+/// Scopes of this API. Convertible to their string representation with `as_str()` or `AsRef`.
+/// Select them for a service with `set_scopes(&[...])`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DriveScopes {
     /// See, edit, create, and delete all of your Google Drive files
     ///
@@ -52,27 +80,523 @@ pub enum DriveScopes {
     DriveScripts,
 }
 
-impl std::convert::AsRef<str> for DriveScopes {
-    fn as_ref(&self) -> &'static str {
+/// The scopes of this API, under a name shared by all generated APIs.
+pub type Scope = DriveScopes;
+
+impl DriveScopes {
+    /// The URL identifying this scope.
+    pub fn as_str(&self) -> &'static str {
         match self {
-            DriveScopes::Drive => "https://www.googleapis.com/auth/drive",
-            DriveScopes::DriveAppdata => "https://www.googleapis.com/auth/drive.appdata",
-            DriveScopes::DriveFile => "https://www.googleapis.com/auth/drive.file",
-            DriveScopes::DriveMetadata => "https://www.googleapis.com/auth/drive.metadata",
-            DriveScopes::DriveMetadataReadonly => {
-                "https://www.googleapis.com/auth/drive.metadata.readonly"
-            }
-            DriveScopes::DrivePhotosReadonly => {
-                "https://www.googleapis.com/auth/drive.photos.readonly"
-            }
-            DriveScopes::DriveReadonly => "https://www.googleapis.com/auth/drive.readonly",
-            DriveScopes::DriveScripts => "https://www.googleapis.com/auth/drive.scripts",
+            DriveScopes::Drive => scopes::DRIVE,
+            DriveScopes::DriveAppdata => scopes::DRIVE_APPDATA,
+            DriveScopes::DriveFile => scopes::DRIVE_FILE,
+            DriveScopes::DriveMetadata => scopes::DRIVE_METADATA,
+            DriveScopes::DriveMetadataReadonly => scopes::DRIVE_METADATA_READONLY,
+            DriveScopes::DrivePhotosReadonly => scopes::DRIVE_PHOTOS_READONLY,
+            DriveScopes::DriveReadonly => scopes::DRIVE_READONLY,
+            DriveScopes::DriveScripts => scopes::DRIVE_SCRIPTS,
         }
     }
 }
 
+impl std::convert::AsRef<str> for DriveScopes {
+    fn as_ref(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct ChannelId(pub String);
+
+impl ChannelId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for ChannelId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for ChannelId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ChannelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ChannelId {
+    fn from(s: &str) -> ChannelId {
+        ChannelId(s.to_string())
+    }
+}
+
+impl From<String> for ChannelId {
+    fn from(s: String) -> ChannelId {
+        ChannelId(s)
+    }
+}
+
+impl From<ChannelId> for String {
+    fn from(id: ChannelId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct CommentId(pub String);
+
+impl CommentId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for CommentId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for CommentId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for CommentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for CommentId {
+    fn from(s: &str) -> CommentId {
+        CommentId(s.to_string())
+    }
+}
+
+impl From<String> for CommentId {
+    fn from(s: String) -> CommentId {
+        CommentId(s)
+    }
+}
+
+impl From<CommentId> for String {
+    fn from(id: CommentId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct DriveId(pub String);
+
+impl DriveId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for DriveId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for DriveId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for DriveId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for DriveId {
+    fn from(s: &str) -> DriveId {
+        DriveId(s.to_string())
+    }
+}
+
+impl From<String> for DriveId {
+    fn from(s: String) -> DriveId {
+        DriveId(s)
+    }
+}
+
+impl From<DriveId> for String {
+    fn from(id: DriveId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct FileId(pub String);
+
+impl FileId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for FileId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for FileId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for FileId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for FileId {
+    fn from(s: &str) -> FileId {
+        FileId(s.to_string())
+    }
+}
+
+impl From<String> for FileId {
+    fn from(s: String) -> FileId {
+        FileId(s)
+    }
+}
+
+impl From<FileId> for String {
+    fn from(id: FileId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct PermissionId(pub String);
+
+impl PermissionId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for PermissionId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for PermissionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PermissionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for PermissionId {
+    fn from(s: &str) -> PermissionId {
+        PermissionId(s.to_string())
+    }
+}
+
+impl From<String> for PermissionId {
+    fn from(s: String) -> PermissionId {
+        PermissionId(s)
+    }
+}
+
+impl From<PermissionId> for String {
+    fn from(id: PermissionId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct ReplyId(pub String);
+
+impl ReplyId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for ReplyId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for ReplyId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ReplyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ReplyId {
+    fn from(s: &str) -> ReplyId {
+        ReplyId(s.to_string())
+    }
+}
+
+impl From<String> for ReplyId {
+    fn from(s: String) -> ReplyId {
+        ReplyId(s)
+    }
+}
+
+impl From<ReplyId> for String {
+    fn from(id: ReplyId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for RequestId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for RequestId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for RequestId {
+    fn from(s: &str) -> RequestId {
+        RequestId(s.to_string())
+    }
+}
+
+impl From<String> for RequestId {
+    fn from(s: String) -> RequestId {
+        RequestId(s)
+    }
+}
+
+impl From<RequestId> for String {
+    fn from(id: RequestId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct ResourceId(pub String);
+
+impl ResourceId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for ResourceId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for ResourceId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ResourceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ResourceId {
+    fn from(s: &str) -> ResourceId {
+        ResourceId(s.to_string())
+    }
+}
+
+impl From<String> for ResourceId {
+    fn from(s: String) -> ResourceId {
+        ResourceId(s)
+    }
+}
+
+impl From<ResourceId> for String {
+    fn from(id: ResourceId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct RevisionId(pub String);
+
+impl RevisionId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for RevisionId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for RevisionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RevisionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for RevisionId {
+    fn from(s: &str) -> RevisionId {
+        RevisionId(s.to_string())
+    }
+}
+
+impl From<String> for RevisionId {
+    fn from(s: String) -> RevisionId {
+        RevisionId(s)
+    }
+}
+
+impl From<RevisionId> for String {
+    fn from(id: RevisionId) -> String {
+        id.0
+    }
+}
+
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct ThemeId(pub String);
+
+impl ThemeId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for ThemeId {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for ThemeId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ThemeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ThemeId {
+    fn from(s: &str) -> ThemeId {
+        ThemeId(s.to_string())
+    }
+}
+
+impl From<String> for ThemeId {
+    fn from(s: String) -> ThemeId {
+        ThemeId(s)
+    }
+}
+
+impl From<ThemeId> for String {
+    fn from(id: ThemeId) -> String {
+        id.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct AboutDriveThemes {
     /// A link to this theme's background image.
     #[serde(rename = "backgroundImageLink")]
@@ -89,28 +613,32 @@ pub struct AboutDriveThemes {
 }
 
 /// The user's storage quota limits and usage. All fields are measured in bytes.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct AboutStorageQuota {
     /// i64: The usage limit, if applicable. This will not be present if the user has unlimited storage.
     #[serde(rename = "limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub limit: Option<i64>,
     /// i64: The total usage across all services.
     #[serde(rename = "usage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub usage: Option<i64>,
     /// i64: The usage by all files in Google Drive.
     #[serde(rename = "usageInDrive")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage_in_drive: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub usage_in_drive: Option<i64>,
     /// i64: The usage by trashed files in Google Drive.
     #[serde(rename = "usageInDriveTrash")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage_in_drive_trash: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub usage_in_drive_trash: Option<i64>,
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct AboutTeamDriveThemes {
     /// Deprecated - use driveThemes/backgroundImageLink instead.
     #[serde(rename = "backgroundImageLink")]
@@ -127,7 +655,7 @@ pub struct AboutTeamDriveThemes {
 }
 
 /// Information about the user, the user's Drive, and system capabilities.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct About {
     /// Whether the user has installed the requesting app.
     #[serde(rename = "appInstalled")]
@@ -168,7 +696,8 @@ pub struct About {
     /// i64: The maximum upload size in bytes.
     #[serde(rename = "maxUploadSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_upload_size: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub max_upload_size: Option<i64>,
     /// The user's storage quota limits and usage. All fields are measured in bytes.
     #[serde(rename = "storageQuota")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -183,7 +712,7 @@ pub struct About {
 }
 
 /// A change to a file or shared drive.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct Change {
     /// The type of the change. Possible values are file and drive.
     #[serde(rename = "changeType")]
@@ -195,14 +724,14 @@ pub struct Change {
     /// The ID of the shared drive associated with this change.
     #[serde(rename = "driveId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub drive_id: Option<String>,
+    pub drive_id: Option<DriveId>,
     #[serde(rename = "file")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<File>,
     /// The ID of the file which has changed.
     #[serde(rename = "fileId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_id: Option<String>,
+    pub file_id: Option<FileId>,
     /// Identifies what kind of resource this is. Value: the fixed string "drive#change".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -217,10 +746,11 @@ pub struct Change {
     /// Deprecated - use driveId instead.
     #[serde(rename = "teamDriveId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub team_drive_id: Option<String>,
+    pub team_drive_id: Option<DriveId>,
     /// DateTime: The time of this change (RFC 3339 date-time).
     #[serde(rename = "time")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub time: Option<DateTime<Utc>>,
     /// Deprecated - use changeType instead.
     #[serde(rename = "type")]
@@ -229,7 +759,7 @@ pub struct Change {
 }
 
 /// A list of changes for a user.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct ChangeList {
     /// The list of changes. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
     #[serde(rename = "changes")]
@@ -250,7 +780,7 @@ pub struct ChangeList {
 }
 
 /// An notification channel used to watch for resource changes.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Channel {
     /// The address where notifications are delivered for this channel.
     #[serde(rename = "address")]
@@ -259,11 +789,12 @@ pub struct Channel {
     /// i64: Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
     #[serde(rename = "expiration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub expiration: Option<i64>,
     /// A UUID or similar unique string that identifies this channel.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<ChannelId>,
     /// Identifies this as a notification channel used to watch for changes to a resource, which is "api#channel".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -279,7 +810,7 @@ pub struct Channel {
     /// An opaque ID that identifies the resource being watched on this channel. Stable across different API versions.
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_id: Option<String>,
+    pub resource_id: Option<ResourceId>,
     /// A version-specific identifier for the watched resource.
     #[serde(rename = "resourceUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -295,7 +826,7 @@ pub struct Channel {
 }
 
 /// The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct CommentQuotedFileContent {
     /// The MIME type of the quoted content.
     #[serde(rename = "mimeType")]
@@ -308,7 +839,7 @@ pub struct CommentQuotedFileContent {
 }
 
 /// A comment on a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Comment {
     /// A region of the document represented as a JSON string. See anchor documentation for details on how to define and interpret anchor properties.
     #[serde(rename = "anchor")]
@@ -324,6 +855,7 @@ pub struct Comment {
     /// DateTime: The time at which the comment was created (RFC 3339 date-time).
     #[serde(rename = "createdTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub created_time: Option<DateTime<Utc>>,
     /// Whether the comment has been deleted. A deleted comment has no content.
     #[serde(rename = "deleted")]
@@ -336,7 +868,7 @@ pub struct Comment {
     /// The ID of the comment.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CommentId>,
     /// Identifies what kind of resource this is. Value: the fixed string "drive#comment".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -344,6 +876,7 @@ pub struct Comment {
     /// DateTime: The last time the comment or any of its replies was modified (RFC 3339 date-time).
     #[serde(rename = "modifiedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub modified_time: Option<DateTime<Utc>>,
     /// The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
     #[serde(rename = "quotedFileContent")]
@@ -360,7 +893,7 @@ pub struct Comment {
 }
 
 /// A list of comments on a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct CommentList {
     /// The list of comments. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
     #[serde(rename = "comments")]
@@ -377,7 +910,7 @@ pub struct CommentList {
 }
 
 /// A restriction for accessing the content of the file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ContentRestriction {
     /// Whether the content of the file is read-only. If a file is read-only, a new revision of the file may not be added, comments may not be added or modified, and the title of the file may not be modified.
     #[serde(rename = "readOnly")]
@@ -393,6 +926,7 @@ pub struct ContentRestriction {
     /// DateTime: The time at which the content restriction was set (formatted RFC 3339 timestamp). Only populated if readOnly is true.
     #[serde(rename = "restrictionTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub restriction_time: Option<DateTime<Utc>>,
     /// The type of the content restriction. Currently the only possible value is globalContentRestriction.
     #[serde(rename = "type")]
@@ -401,7 +935,7 @@ pub struct ContentRestriction {
 }
 
 /// An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct DriveBackgroundImageFile {
     /// The ID of an image file in Google Drive to use for the background image.
     #[serde(rename = "id")]
@@ -422,7 +956,7 @@ pub struct DriveBackgroundImageFile {
 }
 
 /// Capabilities the current user has on this shared drive.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DriveCapabilities {
     /// Whether the current user can add children to folders in this shared drive.
     #[serde(rename = "canAddChildren")]
@@ -499,7 +1033,7 @@ pub struct DriveCapabilities {
 }
 
 /// A set of restrictions that apply to this shared drive or items inside this shared drive.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DriveRestrictions {
     /// Whether administrative privileges on this shared drive are required to modify restrictions.
     #[serde(rename = "adminManagedRestrictions")]
@@ -520,7 +1054,7 @@ pub struct DriveRestrictions {
 }
 
 /// Representation of a shared drive.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct Drive {
     /// An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
     #[serde(rename = "backgroundImageFile")]
@@ -541,6 +1075,7 @@ pub struct Drive {
     /// DateTime: The time at which the shared drive was created (RFC 3339 date-time).
     #[serde(rename = "createdTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub created_time: Option<DateTime<Utc>>,
     /// Whether the shared drive is hidden from default view.
     #[serde(rename = "hidden")]
@@ -549,7 +1084,7 @@ pub struct Drive {
     /// The ID of this shared drive which is also the ID of the top level folder of this shared drive.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<DriveId>,
     /// Identifies what kind of resource this is. Value: the fixed string "drive#drive".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -565,11 +1100,11 @@ pub struct Drive {
     /// The ID of the theme from which the background image and color will be set. The set of possible driveThemes can be retrieved from a drive.about.get response. When not specified on a drive.drives.create request, a random theme is chosen from which the background image and color are set. This is a write-only field; it can only be set on requests that don't set colorRgb or backgroundImageFile.
     #[serde(rename = "themeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub theme_id: Option<String>,
+    pub theme_id: Option<ThemeId>,
 }
 
 /// A list of shared drives.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct DriveList {
     /// The list of shared drives. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
     #[serde(rename = "drives")]
@@ -586,7 +1121,7 @@ pub struct DriveList {
 }
 
 /// Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileCapabilities {
     /// Whether the current user can add children to this folder. This is always false when the item is not a folder.
     #[serde(rename = "canAddChildren")]
@@ -727,7 +1262,7 @@ pub struct FileCapabilities {
 }
 
 /// A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileContentHintsThumbnail {
     /// The thumbnail data encoded with URL-safe Base64 (RFC 4648 section 5).
     #[serde(rename = "image")]
@@ -740,7 +1275,7 @@ pub struct FileContentHintsThumbnail {
 }
 
 /// Additional information about the content of the file. These fields are never populated in responses.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileContentHints {
     /// Text to be indexed for the file to improve fullText queries. This is limited to 128KB in length and may contain HTML elements.
     #[serde(rename = "indexableText")]
@@ -753,7 +1288,7 @@ pub struct FileContentHints {
 }
 
 /// Geographic location information stored in the image.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct FileImageMediaMetadataLocation {
     /// The altitude stored in the image.
     #[serde(rename = "altitude")]
@@ -770,7 +1305,7 @@ pub struct FileImageMediaMetadataLocation {
 }
 
 /// Additional metadata about image media, if available.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct FileImageMediaMetadata {
     /// The aperture used to create the photo (f-number).
     #[serde(rename = "aperture")]
@@ -858,13 +1393,26 @@ pub struct FileImageMediaMetadata {
     pub width: Option<i32>,
 }
 
+/// Contains details about the link URLs that clients are using to refer to this item.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct FileLinkShareMetadata {
+    /// Whether the file is eligible for security update.
+    #[serde(rename = "securityUpdateEligible")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_update_eligible: Option<bool>,
+    /// Whether the security update is enabled for this file.
+    #[serde(rename = "securityUpdateEnabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_update_enabled: Option<bool>,
+}
+
 /// Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileShortcutDetails {
     /// The ID of the file that this shortcut points to.
     #[serde(rename = "targetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_id: Option<String>,
+    pub target_id: Option<FileId>,
     /// The MIME type of the file that this shortcut points to. The value of this field is a snapshot of the target's MIME type, captured when the shortcut is created.
     #[serde(rename = "targetMimeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -872,12 +1420,13 @@ pub struct FileShortcutDetails {
 }
 
 /// Additional metadata about video media. This may not be available immediately upon upload.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileVideoMediaMetadata {
     /// i64: The duration of the video in milliseconds.
     #[serde(rename = "durationMillis")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration_millis: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub duration_millis: Option<i64>,
     /// The height of the video in pixels.
     #[serde(rename = "height")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -889,7 +1438,7 @@ pub struct FileVideoMediaMetadata {
 }
 
 /// The metadata for a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct File {
     /// A collection of arbitrary key-value pairs which are private to the requesting app. Entries with null values are cleared in update and copy requests.
     #[serde(rename = "appProperties")]
@@ -914,6 +1463,7 @@ pub struct File {
     /// DateTime: The time at which the file was created (RFC 3339 date-time).
     #[serde(rename = "createdTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub created_time: Option<DateTime<Utc>>,
     /// A short description of the file.
     #[serde(rename = "description")]
@@ -922,7 +1472,7 @@ pub struct File {
     /// ID of the shared drive the file resides in. Only populated for items in shared drives.
     #[serde(rename = "driveId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub drive_id: Option<String>,
+    pub drive_id: Option<DriveId>,
     /// Whether the file has been explicitly trashed, as opposed to recursively trashed from a parent folder.
     #[serde(rename = "explicitlyTrashed")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -954,7 +1504,7 @@ pub struct File {
     /// The ID of the file's head revision. This is currently only available for files with binary content in Google Drive.
     #[serde(rename = "headRevisionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub head_revision_id: Option<String>,
+    pub head_revision_id: Option<RevisionId>,
     /// A static, unauthenticated link to the file's icon.
     #[serde(rename = "iconLink")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -962,7 +1512,7 @@ pub struct File {
     /// The ID of the file.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<FileId>,
     /// Additional metadata about image media, if available.
     #[serde(rename = "imageMediaMetadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -978,6 +1528,10 @@ pub struct File {
     #[serde(rename = "lastModifyingUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_modifying_user: Option<User>,
+    /// Contains details about the link URLs that clients are using to refer to this item.
+    #[serde(rename = "linkShareMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_share_metadata: Option<FileLinkShareMetadata>,
     /// The MD5 checksum for the content of the file. This is only applicable to files with binary content in Google Drive.
     #[serde(rename = "md5Checksum")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -993,10 +1547,12 @@ pub struct File {
     /// DateTime: The last time the file was modified by the user (RFC 3339 date-time).
     #[serde(rename = "modifiedByMeTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub modified_by_me_time: Option<DateTime<Utc>>,
     /// DateTime: The last time the file was modified by anyone (RFC 3339 date-time). Note that setting modifiedTime will also update modifiedByMeTime for the user.
     #[serde(rename = "modifiedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub modified_time: Option<DateTime<Utc>>,
     /// The name of the file. This is not necessarily unique within a folder. Note that for immutable items such as the top level folders of shared drives, My Drive root folder, and Application Data folder the name is constant.
     #[serde(rename = "name")]
@@ -1033,7 +1589,12 @@ pub struct File {
     /// i64: The number of storage quota bytes used by the file. This includes the head revision as well as previous revisions with keepForever enabled.
     #[serde(rename = "quotaBytesUsed")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quota_bytes_used: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub quota_bytes_used: Option<i64>,
+    /// A key needed to access the item via a shared link.
+    #[serde(rename = "resourceKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_key: Option<String>,
     /// Whether the file has been shared. Not populated for items in shared drives.
     #[serde(rename = "shared")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1041,6 +1602,7 @@ pub struct File {
     /// DateTime: The time at which the file was shared with the user, if applicable (RFC 3339 date-time).
     #[serde(rename = "sharedWithMeTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub shared_with_me_time: Option<DateTime<Utc>>,
     #[serde(rename = "sharingUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1052,7 +1614,8 @@ pub struct File {
     /// i64: The size of the file's content in bytes. This is only applicable to files with binary content in Google Drive.
     #[serde(rename = "size")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub size: Option<i64>,
     /// The list of spaces which contain the file. The currently supported values are 'drive', 'appDataFolder' and 'photos'.
     #[serde(rename = "spaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1064,7 +1627,7 @@ pub struct File {
     /// Deprecated - use driveId instead.
     #[serde(rename = "teamDriveId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub team_drive_id: Option<String>,
+    pub team_drive_id: Option<DriveId>,
     /// A short-lived link to the file's thumbnail, if available. Typically lasts on the order of hours. Only populated when the requesting app can access the file's content. If the file isn't shared publicly, the URL returned in Files.thumbnailLink must be fetched using a credentialed request.
     #[serde(rename = "thumbnailLink")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1072,7 +1635,8 @@ pub struct File {
     /// i64: The thumbnail version for use in thumbnail cache invalidation.
     #[serde(rename = "thumbnailVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_version: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub thumbnail_version: Option<i64>,
     /// Whether the file has been trashed, either explicitly or from a trashed parent folder. Only the owner may trash a file. The trashed item is excluded from all files.list responses returned for any user who does not own the file. However, all users with access to the file can see the trashed item metadata in an API response. All users with access can copy, download, export, and share the file.
     #[serde(rename = "trashed")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1080,6 +1644,7 @@ pub struct File {
     /// DateTime: The time that the item was trashed (RFC 3339 date-time). Only populated for items in shared drives.
     #[serde(rename = "trashedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub trashed_time: Option<DateTime<Utc>>,
     #[serde(rename = "trashingUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1087,7 +1652,8 @@ pub struct File {
     /// i64: A monotonically increasing version number for the file. This reflects every change made to the file on the server, even those not visible to the user.
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub version: Option<i64>,
     /// Additional metadata about video media. This may not be available immediately upon upload.
     #[serde(rename = "videoMediaMetadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1099,6 +1665,7 @@ pub struct File {
     /// DateTime: The last time the file was viewed by the user (RFC 3339 date-time).
     #[serde(rename = "viewedByMeTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub viewed_by_me_time: Option<DateTime<Utc>>,
     /// Deprecated - use copyRequiresWriterPermission instead.
     #[serde(rename = "viewersCanCopyContent")]
@@ -1119,7 +1686,7 @@ pub struct File {
 }
 
 /// A list of files.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct FileList {
     /// The list of files. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
     #[serde(rename = "files")]
@@ -1140,7 +1707,7 @@ pub struct FileList {
 }
 
 /// A list of generated file IDs which can be provided in create requests.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct GeneratedIds {
     /// The IDs generated for the requesting user in the specified space.
     #[serde(rename = "ids")]
@@ -1156,8 +1723,153 @@ pub struct GeneratedIds {
     pub space: Option<String>,
 }
 
+/// The permission type for this user. While new values may be added in future, the following are currently possible:   - file  - member
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PermissionPermissionDetailsPermissionType {
+    File,
+    Member,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl PermissionPermissionDetailsPermissionType {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionPermissionDetailsPermissionType::File => "file",
+            PermissionPermissionDetailsPermissionType::Member => "member",
+            PermissionPermissionDetailsPermissionType::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for PermissionPermissionDetailsPermissionType {
+    fn default() -> PermissionPermissionDetailsPermissionType {
+        PermissionPermissionDetailsPermissionType::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for PermissionPermissionDetailsPermissionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PermissionPermissionDetailsPermissionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PermissionPermissionDetailsPermissionType {
+    fn from(v: &str) -> PermissionPermissionDetailsPermissionType {
+        match v {
+            "file" => PermissionPermissionDetailsPermissionType::File,
+            "member" => PermissionPermissionDetailsPermissionType::Member,
+            _ => PermissionPermissionDetailsPermissionType::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for PermissionPermissionDetailsPermissionType {
+    fn from(v: String) -> PermissionPermissionDetailsPermissionType {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for PermissionPermissionDetailsPermissionType {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionPermissionDetailsPermissionType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<PermissionPermissionDetailsPermissionType, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// The primary role for this user. While new values may be added in the future, the following are currently possible:   - organizer  - fileOrganizer  - writer  - commenter  - reader
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PermissionPermissionDetailsRole {
+    Organizer,
+    FileOrganizer,
+    Writer,
+    Commenter,
+    Reader,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl PermissionPermissionDetailsRole {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionPermissionDetailsRole::Organizer => "organizer",
+            PermissionPermissionDetailsRole::FileOrganizer => "fileOrganizer",
+            PermissionPermissionDetailsRole::Writer => "writer",
+            PermissionPermissionDetailsRole::Commenter => "commenter",
+            PermissionPermissionDetailsRole::Reader => "reader",
+            PermissionPermissionDetailsRole::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for PermissionPermissionDetailsRole {
+    fn default() -> PermissionPermissionDetailsRole {
+        PermissionPermissionDetailsRole::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for PermissionPermissionDetailsRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PermissionPermissionDetailsRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PermissionPermissionDetailsRole {
+    fn from(v: &str) -> PermissionPermissionDetailsRole {
+        match v {
+            "organizer" => PermissionPermissionDetailsRole::Organizer,
+            "fileOrganizer" => PermissionPermissionDetailsRole::FileOrganizer,
+            "writer" => PermissionPermissionDetailsRole::Writer,
+            "commenter" => PermissionPermissionDetailsRole::Commenter,
+            "reader" => PermissionPermissionDetailsRole::Reader,
+            _ => PermissionPermissionDetailsRole::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for PermissionPermissionDetailsRole {
+    fn from(v: String) -> PermissionPermissionDetailsRole {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for PermissionPermissionDetailsRole {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionPermissionDetailsRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<PermissionPermissionDetailsRole, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionPermissionDetails {
     /// Whether this permission is inherited. This field is always populated. This is an output-only field.
     #[serde(rename = "inherited")]
@@ -1170,15 +1882,95 @@ pub struct PermissionPermissionDetails {
     /// The permission type for this user. While new values may be added in future, the following are currently possible:   - file  - member
     #[serde(rename = "permissionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permission_type: Option<String>,
+    pub permission_type: Option<PermissionPermissionDetailsPermissionType>,
     /// The primary role for this user. While new values may be added in the future, the following are currently possible:   - organizer  - fileOrganizer  - writer  - commenter  - reader
     #[serde(rename = "role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<PermissionPermissionDetailsRole>,
+}
+
+/// The role granted by this permission. While new values may be supported in the future, the following are currently allowed:   - owner  - organizer  - fileOrganizer  - writer  - commenter  - reader
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PermissionRole {
+    Owner,
+    Organizer,
+    FileOrganizer,
+    Writer,
+    Commenter,
+    Reader,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl PermissionRole {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionRole::Owner => "owner",
+            PermissionRole::Organizer => "organizer",
+            PermissionRole::FileOrganizer => "fileOrganizer",
+            PermissionRole::Writer => "writer",
+            PermissionRole::Commenter => "commenter",
+            PermissionRole::Reader => "reader",
+            PermissionRole::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for PermissionRole {
+    fn default() -> PermissionRole {
+        PermissionRole::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for PermissionRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PermissionRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PermissionRole {
+    fn from(v: &str) -> PermissionRole {
+        match v {
+            "owner" => PermissionRole::Owner,
+            "organizer" => PermissionRole::Organizer,
+            "fileOrganizer" => PermissionRole::FileOrganizer,
+            "writer" => PermissionRole::Writer,
+            "commenter" => PermissionRole::Commenter,
+            "reader" => PermissionRole::Reader,
+            _ => PermissionRole::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for PermissionRole {
+    fn from(v: String) -> PermissionRole {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for PermissionRole {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<PermissionRole, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionTeamDrivePermissionDetails {
     /// Deprecated - use permissionDetails/inherited instead.
     #[serde(rename = "inherited")]
@@ -1198,8 +1990,82 @@ pub struct PermissionTeamDrivePermissionDetails {
     pub team_drive_permission_type: Option<String>,
 }
 
+/// The type of the grantee. Valid values are:   - user  - group  - domain  - anyone  When creating a permission, if type is user or group, you must provide an emailAddress for the user or group. When type is domain, you must provide a domain. There isn't extra information required for a anyone type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PermissionType {
+    User,
+    Group,
+    Domain,
+    Anyone,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl PermissionType {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionType::User => "user",
+            PermissionType::Group => "group",
+            PermissionType::Domain => "domain",
+            PermissionType::Anyone => "anyone",
+            PermissionType::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for PermissionType {
+    fn default() -> PermissionType {
+        PermissionType::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for PermissionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PermissionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PermissionType {
+    fn from(v: &str) -> PermissionType {
+        match v {
+            "user" => PermissionType::User,
+            "group" => PermissionType::Group,
+            "domain" => PermissionType::Domain,
+            "anyone" => PermissionType::Anyone,
+            _ => PermissionType::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for PermissionType {
+    fn from(v: String) -> PermissionType {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for PermissionType {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<PermissionType, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
 /// A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Permission {
     /// Whether the permission allows the file to be discovered through search. This is only applicable for permissions of type domain or anyone.
     #[serde(rename = "allowFileDiscovery")]
@@ -1224,11 +2090,12 @@ pub struct Permission {
     /// DateTime: The time at which this permission will expire (RFC 3339 date-time). Expiration times have the following restrictions:   - They can only be set on user and group permissions  - The time must be in the future  - The time cannot be more than a year in the future
     #[serde(rename = "expirationTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub expiration_time: Option<DateTime<Utc>>,
     /// The ID of this permission. This is a unique identifier for the grantee, and is published in User resources as permissionId. IDs should be treated as opaque values.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<PermissionId>,
     /// Identifies what kind of resource this is. Value: the fixed string "drive#permission".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1244,7 +2111,7 @@ pub struct Permission {
     /// The role granted by this permission. While new values may be supported in the future, the following are currently allowed:   - owner  - organizer  - fileOrganizer  - writer  - commenter  - reader
     #[serde(rename = "role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<PermissionRole>,
     /// Deprecated - use permissionDetails instead.
     #[serde(rename = "teamDrivePermissionDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1252,7 +2119,7 @@ pub struct Permission {
     /// The type of the grantee. Valid values are:   - user  - group  - domain  - anyone  When creating a permission, if type is user or group, you must provide an emailAddress for the user or group. When type is domain, you must provide a domain. There isn't extra information required for a anyone type.
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub typ: Option<String>,
+    pub typ: Option<PermissionType>,
     /// Indicates the view for this permission. Only populated for permissions that belong to a view. published is the only supported value.
     #[serde(rename = "view")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1260,7 +2127,7 @@ pub struct Permission {
 }
 
 /// A list of permissions for a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionList {
     /// Identifies what kind of resource this is. Value: the fixed string "drive#permissionList".
     #[serde(rename = "kind")]
@@ -1276,13 +2143,81 @@ pub struct PermissionList {
     pub permissions: Option<Vec<Permission>>,
 }
 
+/// The action the reply performed to the parent comment. Valid values are:   - resolve  - reopen
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReplyAction {
+    Resolve,
+    Reopen,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ReplyAction {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReplyAction::Resolve => "resolve",
+            ReplyAction::Reopen => "reopen",
+            ReplyAction::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ReplyAction {
+    fn default() -> ReplyAction {
+        ReplyAction::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ReplyAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ReplyAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ReplyAction {
+    fn from(v: &str) -> ReplyAction {
+        match v {
+            "resolve" => ReplyAction::Resolve,
+            "reopen" => ReplyAction::Reopen,
+            _ => ReplyAction::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ReplyAction {
+    fn from(v: String) -> ReplyAction {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ReplyAction {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReplyAction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ReplyAction, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
 /// A reply to a comment on a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Reply {
     /// The action the reply performed to the parent comment. Valid values are:   - resolve  - reopen
     #[serde(rename = "action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<ReplyAction>,
     #[serde(rename = "author")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<User>,
@@ -1293,6 +2228,7 @@ pub struct Reply {
     /// DateTime: The time at which the reply was created (RFC 3339 date-time).
     #[serde(rename = "createdTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub created_time: Option<DateTime<Utc>>,
     /// Whether the reply has been deleted. A deleted reply has no content.
     #[serde(rename = "deleted")]
@@ -1305,7 +2241,7 @@ pub struct Reply {
     /// The ID of the reply.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<ReplyId>,
     /// Identifies what kind of resource this is. Value: the fixed string "drive#reply".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1313,11 +2249,12 @@ pub struct Reply {
     /// DateTime: The last time the reply was modified (RFC 3339 date-time).
     #[serde(rename = "modifiedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub modified_time: Option<DateTime<Utc>>,
 }
 
 /// A list of replies to a comment on a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ReplyList {
     /// Identifies what kind of resource this is. Value: the fixed string "drive#replyList".
     #[serde(rename = "kind")]
@@ -1334,7 +2271,7 @@ pub struct ReplyList {
 }
 
 /// The metadata for a revision to a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Revision {
     /// Links for exporting Google Docs to specific formats.
     #[serde(rename = "exportLinks")]
@@ -1343,7 +2280,7 @@ pub struct Revision {
     /// The ID of the revision.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<RevisionId>,
     /// Whether to keep this revision forever, even if it is no longer the head revision. If not set, the revision will be automatically purged 30 days after newer content is uploaded. This can be set on a maximum of 200 revisions for a file. This field is only applicable to files with binary content in Drive.
     #[serde(rename = "keepForever")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1366,6 +2303,7 @@ pub struct Revision {
     /// DateTime: The last time the revision was modified (RFC 3339 date-time).
     #[serde(rename = "modifiedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub modified_time: Option<DateTime<Utc>>,
     /// The original filename used to create this revision. This is only applicable to files with binary content in Drive.
     #[serde(rename = "originalFilename")]
@@ -1390,11 +2328,12 @@ pub struct Revision {
     /// i64: The size of the revision's content in bytes. This is only applicable to files with binary content in Drive.
     #[serde(rename = "size")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub size: Option<i64>,
 }
 
 /// A list of revisions of a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RevisionList {
     /// Identifies what kind of resource this is. Value: the fixed string "drive#revisionList".
     #[serde(rename = "kind")]
@@ -1411,7 +2350,7 @@ pub struct RevisionList {
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct StartPageToken {
     /// Identifies what kind of resource this is. Value: the fixed string "drive#startPageToken".
     #[serde(rename = "kind")]
@@ -1424,7 +2363,7 @@ pub struct StartPageToken {
 }
 
 /// An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct TeamDriveBackgroundImageFile {
     /// The ID of an image file in Drive to use for the background image.
     #[serde(rename = "id")]
@@ -1445,7 +2384,7 @@ pub struct TeamDriveBackgroundImageFile {
 }
 
 /// Capabilities the current user has on this Team Drive.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TeamDriveCapabilities {
     /// Whether the current user can add children to folders in this Team Drive.
     #[serde(rename = "canAddChildren")]
@@ -1526,7 +2465,7 @@ pub struct TeamDriveCapabilities {
 }
 
 /// A set of restrictions that apply to this Team Drive or items inside this Team Drive.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TeamDriveRestrictions {
     /// Whether administrative privileges on this Team Drive are required to modify restrictions.
     #[serde(rename = "adminManagedRestrictions")]
@@ -1547,7 +2486,7 @@ pub struct TeamDriveRestrictions {
 }
 
 /// Deprecated: use the drive collection instead.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct TeamDrive {
     /// An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
     #[serde(rename = "backgroundImageFile")]
@@ -1568,11 +2507,12 @@ pub struct TeamDrive {
     /// DateTime: The time at which the Team Drive was created (RFC 3339 date-time).
     #[serde(rename = "createdTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub created_time: Option<DateTime<Utc>>,
     /// The ID of this Team Drive which is also the ID of the top level folder of this Team Drive.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<DriveId>,
     /// Identifies what kind of resource this is. Value: the fixed string "drive#teamDrive".
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1588,11 +2528,11 @@ pub struct TeamDrive {
     /// The ID of the theme from which the background image and color will be set. The set of possible teamDriveThemes can be retrieved from a drive.about.get response. When not specified on a drive.teamdrives.create request, a random theme is chosen from which the background image and color are set. This is a write-only field; it can only be set on requests that don't set colorRgb or backgroundImageFile.
     #[serde(rename = "themeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub theme_id: Option<String>,
+    pub theme_id: Option<ThemeId>,
 }
 
 /// A list of Team Drives.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct TeamDriveList {
     /// Identifies what kind of resource this is. Value: the fixed string "drive#teamDriveList".
     #[serde(rename = "kind")]
@@ -1609,7 +2549,7 @@ pub struct TeamDriveList {
 }
 
 /// Information about a Drive user.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct User {
     /// A plain text displayable name for this user.
     #[serde(rename = "displayName")]
@@ -1630,20 +2570,86 @@ pub struct User {
     /// The user's ID as visible in Permission resources.
     #[serde(rename = "permissionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permission_id: Option<String>,
+    pub permission_id: Option<PermissionId>,
     /// A link to the user's profile photo, if available.
     #[serde(rename = "photoLink")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_link: Option<String>,
 }
 
+/// Data format for the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DriveParamsAlt {
+    /// Responses with Content-Type of application/json
+    Json,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl DriveParamsAlt {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DriveParamsAlt::Json => "json",
+            DriveParamsAlt::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for DriveParamsAlt {
+    fn default() -> DriveParamsAlt {
+        DriveParamsAlt::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for DriveParamsAlt {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DriveParamsAlt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for DriveParamsAlt {
+    fn from(v: &str) -> DriveParamsAlt {
+        match v {
+            "json" => DriveParamsAlt::Json,
+            _ => DriveParamsAlt::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for DriveParamsAlt {
+    fn from(v: String) -> DriveParamsAlt {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for DriveParamsAlt {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DriveParamsAlt {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<DriveParamsAlt, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct DriveParams {
     /// Data format for the response.
     #[serde(rename = "alt")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alt: Option<String>,
+    pub alt: Option<DriveParamsAlt>,
     /// Selector specifying which fields to include in a partial response.
     #[serde(rename = "fields")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1670,101 +2676,370 @@ pub struct DriveParams {
     pub user_ip: Option<String>,
 }
 
-/// Parameters for the `about.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct AboutGetParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
-    pub drive_params: Option<DriveParams>,
-}
-
-impl std::fmt::Display for AboutGetParams {
+impl std::fmt::Debug for DriveParams {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+        f.debug_struct("DriveParams")
+            .field("alt", &self.alt)
+            .field("fields", &self.fields)
+            .field("key", &self.key.as_ref().map(|_| "redacted"))
+            .field(
+                "oauth_token",
+                &self.oauth_token.as_ref().map(|_| "redacted"),
+            )
+            .field("pretty_print", &self.pretty_print)
+            .field("quota_user", &self.quota_user)
+            .field("user_ip", &self.user_ip)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod round_trip_tests {
+    use super::*;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(json: &str) {
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+    }
+
+    #[test]
+    fn round_trip_about() {
+        round_trip::<About>(
+            r##"{"appInstalled":true,"canCreateDrives":true,"canCreateTeamDrives":true,"driveThemes":[{"backgroundImageLink":"sample","colorRgb":"sample","id":"sample"}],"exportFormats":{"key":["sample"]},"folderColorPalette":["sample"],"importFormats":{"key":["sample"]},"kind":"sample","maxImportSizes":{"key":"sample"},"maxUploadSize":"123","storageQuota":{"limit":"123","usage":"123","usageInDrive":"123","usageInDriveTrash":"123"},"teamDriveThemes":[{"backgroundImageLink":"sample","colorRgb":"sample","id":"sample"}],"user":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"}}"##,
+        );
     }
-}
 
-/// Parameters for the `changes.getStartPageToken` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct ChangesGetStartPageTokenParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
-    pub drive_params: Option<DriveParams>,
-    /// The ID of the shared drive for which the starting pageToken for listing future changes from that shared drive is returned.
-    #[serde(rename = "driveId")]
-    pub drive_id: Option<String>,
-    /// Whether the requesting application supports both My Drives and shared drives.
-    #[serde(rename = "supportsAllDrives")]
-    pub supports_all_drives: Option<bool>,
-    /// Deprecated use supportsAllDrives instead.
-    #[serde(rename = "supportsTeamDrives")]
-    pub supports_team_drives: Option<bool>,
-    /// Deprecated use driveId instead.
-    #[serde(rename = "teamDriveId")]
-    pub team_drive_id: Option<String>,
-}
+    #[test]
+    fn round_trip_change() {
+        round_trip::<Change>(
+            r##"{"changeType":"sample","drive":{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeDriveBackground":true,"canChangeDriveMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRename":true,"canRenameDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","hidden":true,"id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"driveMembersOnly":true},"themeId":"sample"},"driveId":"sample","file":{"appProperties":{"key":"sample"},"capabilities":{"canAddChildren":true,"canAddFolderFromAnotherDrive":true,"canAddMyDriveParent":true,"canChangeCopyRequiresWriterPermission":true,"canChangeViewersCanCopyContent":true,"canComment":true,"canCopy":true,"canDelete":true,"canDeleteChildren":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canModifyContent":true,"canModifyContentRestriction":true,"canMoveChildrenOutOfDrive":true,"canMoveChildrenOutOfTeamDrive":true,"canMoveChildrenWithinDrive":true,"canMoveChildrenWithinTeamDrive":true,"canMoveItemIntoTeamDrive":true,"canMoveItemOutOfDrive":true,"canMoveItemOutOfTeamDrive":true,"canMoveItemWithinDrive":true,"canMoveItemWithinTeamDrive":true,"canMoveTeamDriveItem":true,"canReadDrive":true,"canReadRevisions":true,"canReadTeamDrive":true,"canRemoveChildren":true,"canRemoveMyDriveParent":true,"canRename":true,"canShare":true,"canTrash":true,"canTrashChildren":true,"canUntrash":true},"contentHints":{"indexableText":"sample","thumbnail":{"image":"sample","mimeType":"sample"}},"contentRestrictions":[{"readOnly":true,"reason":"sample","restrictionTime":"2020-06-01T12:30:45Z","type":"sample"}],"copyRequiresWriterPermission":true,"createdTime":"2020-06-01T12:30:45Z","description":"sample","driveId":"sample","explicitlyTrashed":true,"exportLinks":{"key":"sample"},"fileExtension":"sample","folderColorRgb":"sample","fullFileExtension":"sample","hasAugmentedPermissions":true,"hasThumbnail":true,"headRevisionId":"sample","iconLink":"sample","id":"sample","imageMediaMetadata":{"aperture":1.5,"cameraMake":"sample","cameraModel":"sample","colorSpace":"sample","exposureBias":1.5,"exposureMode":"sample","exposureTime":1.5,"flashUsed":true,"focalLength":1.5,"height":1,"isoSpeed":1,"lens":"sample","location":{"altitude":1.5,"latitude":1.5,"longitude":1.5},"maxApertureValue":1.5,"meteringMode":"sample","rotation":1,"sensor":"sample","subjectDistance":1,"time":"sample","whiteBalance":"sample","width":1},"isAppAuthorized":true,"kind":"sample","lastModifyingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"linkShareMetadata":{"securityUpdateEligible":true,"securityUpdateEnabled":true},"md5Checksum":"sample","mimeType":"sample","modifiedByMe":true,"modifiedByMeTime":"2020-06-01T12:30:45Z","modifiedTime":"2020-06-01T12:30:45Z","name":"sample","originalFilename":"sample","ownedByMe":true,"owners":[{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"}],"parents":["sample"],"permissionIds":["sample"],"permissions":[{"allowFileDiscovery":true,"deleted":true,"displayName":"sample","domain":"sample","emailAddress":"sample","expirationTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","permissionDetails":[{"inherited":true,"inheritedFrom":"sample","permissionType":"sample","role":"sample"}],"photoLink":"sample","role":"sample","teamDrivePermissionDetails":[{"inherited":true,"inheritedFrom":"sample","role":"sample","teamDrivePermissionType":"sample"}],"type":"sample","view":"sample"}],"properties":{"key":"sample"},"quotaBytesUsed":"123","resourceKey":"sample","shared":true,"sharedWithMeTime":"2020-06-01T12:30:45Z","sharingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"shortcutDetails":{"targetId":"sample","targetMimeType":"sample"},"size":"123","spaces":["sample"],"starred":true,"teamDriveId":"sample","thumbnailLink":"sample","thumbnailVersion":"123","trashed":true,"trashedTime":"2020-06-01T12:30:45Z","trashingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"version":"123","videoMediaMetadata":{"durationMillis":"123","height":1,"width":1},"viewedByMe":true,"viewedByMeTime":"2020-06-01T12:30:45Z","viewersCanCopyContent":true,"webContentLink":"sample","webViewLink":"sample","writersCanShare":true},"fileId":"sample","kind":"sample","removed":true,"teamDrive":{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeTeamDriveBackground":true,"canChangeTeamMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteTeamDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRemoveChildren":true,"canRename":true,"canRenameTeamDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"teamMembersOnly":true},"themeId":"sample"},"teamDriveId":"sample","time":"2020-06-01T12:30:45Z","type":"sample"}"##,
+        );
+    }
 
-impl std::fmt::Display for ChangesGetStartPageTokenParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(ref v) = self.drive_id {
-            write!(
-                f,
-                "&driveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.team_drive_id {
-            write!(
-                f,
-                "&teamDriveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        Ok(())
+    #[test]
+    fn round_trip_change_list() {
+        round_trip::<ChangeList>(
+            r##"{"changes":[{"changeType":"sample","drive":{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeDriveBackground":true,"canChangeDriveMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRename":true,"canRenameDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","hidden":true,"id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"driveMembersOnly":true},"themeId":"sample"},"driveId":"sample","file":{"appProperties":{"key":"sample"},"capabilities":{"canAddChildren":true,"canAddFolderFromAnotherDrive":true,"canAddMyDriveParent":true,"canChangeCopyRequiresWriterPermission":true,"canChangeViewersCanCopyContent":true,"canComment":true,"canCopy":true,"canDelete":true,"canDeleteChildren":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canModifyContent":true,"canModifyContentRestriction":true,"canMoveChildrenOutOfDrive":true,"canMoveChildrenOutOfTeamDrive":true,"canMoveChildrenWithinDrive":true,"canMoveChildrenWithinTeamDrive":true,"canMoveItemIntoTeamDrive":true,"canMoveItemOutOfDrive":true,"canMoveItemOutOfTeamDrive":true,"canMoveItemWithinDrive":true,"canMoveItemWithinTeamDrive":true,"canMoveTeamDriveItem":true,"canReadDrive":true,"canReadRevisions":true,"canReadTeamDrive":true,"canRemoveChildren":true,"canRemoveMyDriveParent":true,"canRename":true,"canShare":true,"canTrash":true,"canTrashChildren":true,"canUntrash":true},"contentHints":{"indexableText":"sample","thumbnail":{"image":"sample","mimeType":"sample"}},"contentRestrictions":[],"copyRequiresWriterPermission":true,"createdTime":"2020-06-01T12:30:45Z","description":"sample","driveId":"sample","explicitlyTrashed":true,"exportLinks":{"key":"sample"},"fileExtension":"sample","folderColorRgb":"sample","fullFileExtension":"sample","hasAugmentedPermissions":true,"hasThumbnail":true,"headRevisionId":"sample","iconLink":"sample","id":"sample","imageMediaMetadata":{"aperture":1.5,"cameraMake":"sample","cameraModel":"sample","colorSpace":"sample","exposureBias":1.5,"exposureMode":"sample","exposureTime":1.5,"flashUsed":true,"focalLength":1.5,"height":1,"isoSpeed":1,"lens":"sample","location":{"altitude":1.5,"latitude":1.5,"longitude":1.5},"maxApertureValue":1.5,"meteringMode":"sample","rotation":1,"sensor":"sample","subjectDistance":1,"time":"sample","whiteBalance":"sample","width":1},"isAppAuthorized":true,"kind":"sample","linkShareMetadata":{"securityUpdateEligible":true,"securityUpdateEnabled":true},"md5Checksum":"sample","mimeType":"sample","modifiedByMe":true,"modifiedByMeTime":"2020-06-01T12:30:45Z","modifiedTime":"2020-06-01T12:30:45Z","name":"sample","originalFilename":"sample","ownedByMe":true,"owners":[],"parents":["sample"],"permissionIds":["sample"],"permissions":[],"properties":{"key":"sample"},"quotaBytesUsed":"123","resourceKey":"sample","shared":true,"sharedWithMeTime":"2020-06-01T12:30:45Z","shortcutDetails":{"targetId":"sample","targetMimeType":"sample"},"size":"123","spaces":["sample"],"starred":true,"teamDriveId":"sample","thumbnailLink":"sample","thumbnailVersion":"123","trashed":true,"trashedTime":"2020-06-01T12:30:45Z","version":"123","videoMediaMetadata":{"durationMillis":"123","height":1,"width":1},"viewedByMe":true,"viewedByMeTime":"2020-06-01T12:30:45Z","viewersCanCopyContent":true,"webContentLink":"sample","webViewLink":"sample","writersCanShare":true},"fileId":"sample","kind":"sample","removed":true,"teamDrive":{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeTeamDriveBackground":true,"canChangeTeamMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteTeamDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRemoveChildren":true,"canRename":true,"canRenameTeamDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"teamMembersOnly":true},"themeId":"sample"},"teamDriveId":"sample","time":"2020-06-01T12:30:45Z","type":"sample"}],"kind":"sample","newStartPageToken":"sample","nextPageToken":"sample"}"##,
+        );
     }
-}
 
-/// Parameters for the `changes.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct ChangesListParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
-    pub drive_params: Option<DriveParams>,
-    /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
-    #[serde(rename = "driveId")]
-    pub drive_id: Option<String>,
-    /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
-    #[serde(rename = "includeCorpusRemovals")]
-    pub include_corpus_removals: Option<bool>,
-    /// Whether both My Drive and shared drive items should be included in results.
-    #[serde(rename = "includeItemsFromAllDrives")]
-    pub include_items_from_all_drives: Option<bool>,
-    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-    #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
-    /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
-    #[serde(rename = "includeRemoved")]
-    pub include_removed: Option<bool>,
-    /// Deprecated use includeItemsFromAllDrives instead.
-    #[serde(rename = "includeTeamDriveItems")]
-    pub include_team_drive_items: Option<bool>,
-    /// The maximum number of changes to return per page.
-    #[serde(rename = "pageSize")]
-    pub page_size: Option<i32>,
+    #[test]
+    fn round_trip_channel() {
+        round_trip::<Channel>(
+            r##"{"address":"sample","expiration":"123","id":"sample","kind":"sample","params":{"key":"sample"},"payload":true,"resourceId":"sample","resourceUri":"sample","token":"sample","type":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_comment() {
+        round_trip::<Comment>(
+            r##"{"anchor":"sample","author":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"content":"sample","createdTime":"2020-06-01T12:30:45Z","deleted":true,"htmlContent":"sample","id":"sample","kind":"sample","modifiedTime":"2020-06-01T12:30:45Z","quotedFileContent":{"mimeType":"sample","value":"sample"},"replies":[{"action":"sample","author":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"content":"sample","createdTime":"2020-06-01T12:30:45Z","deleted":true,"htmlContent":"sample","id":"sample","kind":"sample","modifiedTime":"2020-06-01T12:30:45Z"}],"resolved":true}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_comment_list() {
+        round_trip::<CommentList>(
+            r##"{"comments":[{"anchor":"sample","author":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"content":"sample","createdTime":"2020-06-01T12:30:45Z","deleted":true,"htmlContent":"sample","id":"sample","kind":"sample","modifiedTime":"2020-06-01T12:30:45Z","quotedFileContent":{"mimeType":"sample","value":"sample"},"replies":[{"action":"sample","content":"sample","createdTime":"2020-06-01T12:30:45Z","deleted":true,"htmlContent":"sample","id":"sample","kind":"sample","modifiedTime":"2020-06-01T12:30:45Z"}],"resolved":true}],"kind":"sample","nextPageToken":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_content_restriction() {
+        round_trip::<ContentRestriction>(
+            r##"{"readOnly":true,"reason":"sample","restrictingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"restrictionTime":"2020-06-01T12:30:45Z","type":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_drive() {
+        round_trip::<Drive>(
+            r##"{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeDriveBackground":true,"canChangeDriveMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRename":true,"canRenameDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","hidden":true,"id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"driveMembersOnly":true},"themeId":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_drive_list() {
+        round_trip::<DriveList>(
+            r##"{"drives":[{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeDriveBackground":true,"canChangeDriveMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRename":true,"canRenameDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","hidden":true,"id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"driveMembersOnly":true},"themeId":"sample"}],"kind":"sample","nextPageToken":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_file() {
+        round_trip::<File>(
+            r##"{"appProperties":{"key":"sample"},"capabilities":{"canAddChildren":true,"canAddFolderFromAnotherDrive":true,"canAddMyDriveParent":true,"canChangeCopyRequiresWriterPermission":true,"canChangeViewersCanCopyContent":true,"canComment":true,"canCopy":true,"canDelete":true,"canDeleteChildren":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canModifyContent":true,"canModifyContentRestriction":true,"canMoveChildrenOutOfDrive":true,"canMoveChildrenOutOfTeamDrive":true,"canMoveChildrenWithinDrive":true,"canMoveChildrenWithinTeamDrive":true,"canMoveItemIntoTeamDrive":true,"canMoveItemOutOfDrive":true,"canMoveItemOutOfTeamDrive":true,"canMoveItemWithinDrive":true,"canMoveItemWithinTeamDrive":true,"canMoveTeamDriveItem":true,"canReadDrive":true,"canReadRevisions":true,"canReadTeamDrive":true,"canRemoveChildren":true,"canRemoveMyDriveParent":true,"canRename":true,"canShare":true,"canTrash":true,"canTrashChildren":true,"canUntrash":true},"contentHints":{"indexableText":"sample","thumbnail":{"image":"sample","mimeType":"sample"}},"contentRestrictions":[{"readOnly":true,"reason":"sample","restrictingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"restrictionTime":"2020-06-01T12:30:45Z","type":"sample"}],"copyRequiresWriterPermission":true,"createdTime":"2020-06-01T12:30:45Z","description":"sample","driveId":"sample","explicitlyTrashed":true,"exportLinks":{"key":"sample"},"fileExtension":"sample","folderColorRgb":"sample","fullFileExtension":"sample","hasAugmentedPermissions":true,"hasThumbnail":true,"headRevisionId":"sample","iconLink":"sample","id":"sample","imageMediaMetadata":{"aperture":1.5,"cameraMake":"sample","cameraModel":"sample","colorSpace":"sample","exposureBias":1.5,"exposureMode":"sample","exposureTime":1.5,"flashUsed":true,"focalLength":1.5,"height":1,"isoSpeed":1,"lens":"sample","location":{"altitude":1.5,"latitude":1.5,"longitude":1.5},"maxApertureValue":1.5,"meteringMode":"sample","rotation":1,"sensor":"sample","subjectDistance":1,"time":"sample","whiteBalance":"sample","width":1},"isAppAuthorized":true,"kind":"sample","lastModifyingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"linkShareMetadata":{"securityUpdateEligible":true,"securityUpdateEnabled":true},"md5Checksum":"sample","mimeType":"sample","modifiedByMe":true,"modifiedByMeTime":"2020-06-01T12:30:45Z","modifiedTime":"2020-06-01T12:30:45Z","name":"sample","originalFilename":"sample","ownedByMe":true,"owners":[{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"}],"parents":["sample"],"permissionIds":["sample"],"permissions":[{"allowFileDiscovery":true,"deleted":true,"displayName":"sample","domain":"sample","emailAddress":"sample","expirationTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","permissionDetails":[{"inherited":true,"inheritedFrom":"sample","permissionType":"sample","role":"sample"}],"photoLink":"sample","role":"sample","teamDrivePermissionDetails":[{"inherited":true,"inheritedFrom":"sample","role":"sample","teamDrivePermissionType":"sample"}],"type":"sample","view":"sample"}],"properties":{"key":"sample"},"quotaBytesUsed":"123","resourceKey":"sample","shared":true,"sharedWithMeTime":"2020-06-01T12:30:45Z","sharingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"shortcutDetails":{"targetId":"sample","targetMimeType":"sample"},"size":"123","spaces":["sample"],"starred":true,"teamDriveId":"sample","thumbnailLink":"sample","thumbnailVersion":"123","trashed":true,"trashedTime":"2020-06-01T12:30:45Z","trashingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"version":"123","videoMediaMetadata":{"durationMillis":"123","height":1,"width":1},"viewedByMe":true,"viewedByMeTime":"2020-06-01T12:30:45Z","viewersCanCopyContent":true,"webContentLink":"sample","webViewLink":"sample","writersCanShare":true}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_file_list() {
+        round_trip::<FileList>(
+            r##"{"files":[{"appProperties":{"key":"sample"},"capabilities":{"canAddChildren":true,"canAddFolderFromAnotherDrive":true,"canAddMyDriveParent":true,"canChangeCopyRequiresWriterPermission":true,"canChangeViewersCanCopyContent":true,"canComment":true,"canCopy":true,"canDelete":true,"canDeleteChildren":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canModifyContent":true,"canModifyContentRestriction":true,"canMoveChildrenOutOfDrive":true,"canMoveChildrenOutOfTeamDrive":true,"canMoveChildrenWithinDrive":true,"canMoveChildrenWithinTeamDrive":true,"canMoveItemIntoTeamDrive":true,"canMoveItemOutOfDrive":true,"canMoveItemOutOfTeamDrive":true,"canMoveItemWithinDrive":true,"canMoveItemWithinTeamDrive":true,"canMoveTeamDriveItem":true,"canReadDrive":true,"canReadRevisions":true,"canReadTeamDrive":true,"canRemoveChildren":true,"canRemoveMyDriveParent":true,"canRename":true,"canShare":true,"canTrash":true,"canTrashChildren":true,"canUntrash":true},"contentHints":{"indexableText":"sample","thumbnail":{"image":"sample","mimeType":"sample"}},"contentRestrictions":[{"readOnly":true,"reason":"sample","restrictionTime":"2020-06-01T12:30:45Z","type":"sample"}],"copyRequiresWriterPermission":true,"createdTime":"2020-06-01T12:30:45Z","description":"sample","driveId":"sample","explicitlyTrashed":true,"exportLinks":{"key":"sample"},"fileExtension":"sample","folderColorRgb":"sample","fullFileExtension":"sample","hasAugmentedPermissions":true,"hasThumbnail":true,"headRevisionId":"sample","iconLink":"sample","id":"sample","imageMediaMetadata":{"aperture":1.5,"cameraMake":"sample","cameraModel":"sample","colorSpace":"sample","exposureBias":1.5,"exposureMode":"sample","exposureTime":1.5,"flashUsed":true,"focalLength":1.5,"height":1,"isoSpeed":1,"lens":"sample","location":{"altitude":1.5,"latitude":1.5,"longitude":1.5},"maxApertureValue":1.5,"meteringMode":"sample","rotation":1,"sensor":"sample","subjectDistance":1,"time":"sample","whiteBalance":"sample","width":1},"isAppAuthorized":true,"kind":"sample","lastModifyingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"linkShareMetadata":{"securityUpdateEligible":true,"securityUpdateEnabled":true},"md5Checksum":"sample","mimeType":"sample","modifiedByMe":true,"modifiedByMeTime":"2020-06-01T12:30:45Z","modifiedTime":"2020-06-01T12:30:45Z","name":"sample","originalFilename":"sample","ownedByMe":true,"owners":[{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"}],"parents":["sample"],"permissionIds":["sample"],"permissions":[{"allowFileDiscovery":true,"deleted":true,"displayName":"sample","domain":"sample","emailAddress":"sample","expirationTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","permissionDetails":[{"inherited":true,"inheritedFrom":"sample","permissionType":"sample","role":"sample"}],"photoLink":"sample","role":"sample","teamDrivePermissionDetails":[{"inherited":true,"inheritedFrom":"sample","role":"sample","teamDrivePermissionType":"sample"}],"type":"sample","view":"sample"}],"properties":{"key":"sample"},"quotaBytesUsed":"123","resourceKey":"sample","shared":true,"sharedWithMeTime":"2020-06-01T12:30:45Z","sharingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"shortcutDetails":{"targetId":"sample","targetMimeType":"sample"},"size":"123","spaces":["sample"],"starred":true,"teamDriveId":"sample","thumbnailLink":"sample","thumbnailVersion":"123","trashed":true,"trashedTime":"2020-06-01T12:30:45Z","trashingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"version":"123","videoMediaMetadata":{"durationMillis":"123","height":1,"width":1},"viewedByMe":true,"viewedByMeTime":"2020-06-01T12:30:45Z","viewersCanCopyContent":true,"webContentLink":"sample","webViewLink":"sample","writersCanShare":true}],"incompleteSearch":true,"kind":"sample","nextPageToken":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_generated_ids() {
+        round_trip::<GeneratedIds>(r##"{"ids":["sample"],"kind":"sample","space":"sample"}"##);
+    }
+
+    #[test]
+    fn round_trip_permission() {
+        round_trip::<Permission>(
+            r##"{"allowFileDiscovery":true,"deleted":true,"displayName":"sample","domain":"sample","emailAddress":"sample","expirationTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","permissionDetails":[{"inherited":true,"inheritedFrom":"sample","permissionType":"sample","role":"sample"}],"photoLink":"sample","role":"sample","teamDrivePermissionDetails":[{"inherited":true,"inheritedFrom":"sample","role":"sample","teamDrivePermissionType":"sample"}],"type":"sample","view":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_permission_list() {
+        round_trip::<PermissionList>(
+            r##"{"kind":"sample","nextPageToken":"sample","permissions":[{"allowFileDiscovery":true,"deleted":true,"displayName":"sample","domain":"sample","emailAddress":"sample","expirationTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","permissionDetails":[{"inherited":true,"inheritedFrom":"sample","permissionType":"sample","role":"sample"}],"photoLink":"sample","role":"sample","teamDrivePermissionDetails":[{"inherited":true,"inheritedFrom":"sample","role":"sample","teamDrivePermissionType":"sample"}],"type":"sample","view":"sample"}]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_reply() {
+        round_trip::<Reply>(
+            r##"{"action":"sample","author":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"content":"sample","createdTime":"2020-06-01T12:30:45Z","deleted":true,"htmlContent":"sample","id":"sample","kind":"sample","modifiedTime":"2020-06-01T12:30:45Z"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_reply_list() {
+        round_trip::<ReplyList>(
+            r##"{"kind":"sample","nextPageToken":"sample","replies":[{"action":"sample","author":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"content":"sample","createdTime":"2020-06-01T12:30:45Z","deleted":true,"htmlContent":"sample","id":"sample","kind":"sample","modifiedTime":"2020-06-01T12:30:45Z"}]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_revision() {
+        round_trip::<Revision>(
+            r##"{"exportLinks":{"key":"sample"},"id":"sample","keepForever":true,"kind":"sample","lastModifyingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"md5Checksum":"sample","mimeType":"sample","modifiedTime":"2020-06-01T12:30:45Z","originalFilename":"sample","publishAuto":true,"published":true,"publishedLink":"sample","publishedOutsideDomain":true,"size":"123"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_revision_list() {
+        round_trip::<RevisionList>(
+            r##"{"kind":"sample","nextPageToken":"sample","revisions":[{"exportLinks":{"key":"sample"},"id":"sample","keepForever":true,"kind":"sample","lastModifyingUser":{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"},"md5Checksum":"sample","mimeType":"sample","modifiedTime":"2020-06-01T12:30:45Z","originalFilename":"sample","publishAuto":true,"published":true,"publishedLink":"sample","publishedOutsideDomain":true,"size":"123"}]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_start_page_token() {
+        round_trip::<StartPageToken>(r##"{"kind":"sample","startPageToken":"sample"}"##);
+    }
+
+    #[test]
+    fn round_trip_team_drive() {
+        round_trip::<TeamDrive>(
+            r##"{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeTeamDriveBackground":true,"canChangeTeamMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteTeamDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRemoveChildren":true,"canRename":true,"canRenameTeamDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"teamMembersOnly":true},"themeId":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_team_drive_list() {
+        round_trip::<TeamDriveList>(
+            r##"{"kind":"sample","nextPageToken":"sample","teamDrives":[{"backgroundImageFile":{"id":"sample","width":1.5,"xCoordinate":1.5,"yCoordinate":1.5},"backgroundImageLink":"sample","capabilities":{"canAddChildren":true,"canChangeCopyRequiresWriterPermissionRestriction":true,"canChangeDomainUsersOnlyRestriction":true,"canChangeTeamDriveBackground":true,"canChangeTeamMembersOnlyRestriction":true,"canComment":true,"canCopy":true,"canDeleteChildren":true,"canDeleteTeamDrive":true,"canDownload":true,"canEdit":true,"canListChildren":true,"canManageMembers":true,"canReadRevisions":true,"canRemoveChildren":true,"canRename":true,"canRenameTeamDrive":true,"canShare":true,"canTrashChildren":true},"colorRgb":"sample","createdTime":"2020-06-01T12:30:45Z","id":"sample","kind":"sample","name":"sample","restrictions":{"adminManagedRestrictions":true,"copyRequiresWriterPermission":true,"domainUsersOnly":true,"teamMembersOnly":true},"themeId":"sample"}]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_user() {
+        round_trip::<User>(
+            r##"{"displayName":"sample","emailAddress":"sample","kind":"sample","me":true,"permissionId":"sample","photoLink":"sample"}"##,
+        );
+    }
+}
+
+/// Parameters for the `about.get` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct AboutGetParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
+    pub drive_params: Option<DriveParams>,
+}
+
+impl AboutGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl AboutGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+}
+
+/// Parameters for the `changes.getStartPageToken` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangesGetStartPageTokenParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
+    pub drive_params: Option<DriveParams>,
+    /// The ID of the shared drive for which the starting pageToken for listing future changes from that shared drive is returned.
+    #[serde(rename = "driveId")]
+    pub drive_id: Option<DriveId>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    #[serde(rename = "supportsAllDrives")]
+    pub supports_all_drives: Option<bool>,
+    /// Deprecated use supportsAllDrives instead.
+    #[serde(rename = "supportsTeamDrives")]
+    pub supports_team_drives: Option<bool>,
+    /// Deprecated use driveId instead.
+    #[serde(rename = "teamDriveId")]
+    pub team_drive_id: Option<DriveId>,
+}
+
+impl ChangesGetStartPageTokenParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        if let Some(ref v) = self.drive_id {
+            query.append_pair("driveId", &v.to_string());
+        }
+        if let Some(ref v) = self.supports_all_drives {
+            query.append_pair("supportsAllDrives", &v.to_string());
+        }
+        if let Some(ref v) = self.supports_team_drives {
+            query.append_pair("supportsTeamDrives", &v.to_string());
+        }
+        if let Some(ref v) = self.team_drive_id {
+            query.append_pair("teamDriveId", &v.to_string());
+        }
+    }
+}
+
+impl ChangesGetStartPageTokenParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChangesListParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ChangesListParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ChangesListParamsIncludePermissionsForView::Published => "published",
+            ChangesListParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ChangesListParamsIncludePermissionsForView {
+    fn default() -> ChangesListParamsIncludePermissionsForView {
+        ChangesListParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ChangesListParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ChangesListParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ChangesListParamsIncludePermissionsForView {
+    fn from(v: &str) -> ChangesListParamsIncludePermissionsForView {
+        match v {
+            "published" => ChangesListParamsIncludePermissionsForView::Published,
+            _ => ChangesListParamsIncludePermissionsForView::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ChangesListParamsIncludePermissionsForView {
+    fn from(v: String) -> ChangesListParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ChangesListParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChangesListParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ChangesListParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Parameters for the `changes.list` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChangesListParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
+    pub drive_params: Option<DriveParams>,
+    /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
+    #[serde(rename = "driveId")]
+    pub drive_id: Option<DriveId>,
+    /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
+    #[serde(rename = "includeCorpusRemovals")]
+    pub include_corpus_removals: Option<bool>,
+    /// Whether both My Drive and shared drive items should be included in results.
+    #[serde(rename = "includeItemsFromAllDrives")]
+    pub include_items_from_all_drives: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    #[serde(rename = "includePermissionsForView")]
+    pub include_permissions_for_view: Option<ChangesListParamsIncludePermissionsForView>,
+    /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
+    #[serde(rename = "includeRemoved")]
+    pub include_removed: Option<bool>,
+    /// Deprecated use includeItemsFromAllDrives instead.
+    #[serde(rename = "includeTeamDriveItems")]
+    pub include_team_drive_items: Option<bool>,
+    /// The maximum number of changes to return per page.
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i32>,
     /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
     #[serde(rename = "pageToken")]
     pub page_token: String,
@@ -1773,7 +3048,7 @@ pub struct ChangesListParams {
     pub restrict_to_my_drive: Option<bool>,
     /// A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
     #[serde(rename = "spaces")]
-    pub spaces: Option<String>,
+    pub spaces: Option<crate::spaces::Spaces>,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -1782,113 +3057,226 @@ pub struct ChangesListParams {
     pub supports_team_drives: Option<bool>,
     /// Deprecated use driveId instead.
     #[serde(rename = "teamDriveId")]
-    pub team_drive_id: Option<String>,
+    pub team_drive_id: Option<DriveId>,
 }
 
-impl std::fmt::Display for ChangesListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&pageToken={}",
-            percent_encode(format!("{}", self.page_token).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
+impl ChangesListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("pageToken", &self.page_token.to_string());
         if let Some(ref v) = self.drive_id {
-            write!(
-                f,
-                "&driveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("driveId", &v.to_string());
         }
         if let Some(ref v) = self.include_corpus_removals {
-            write!(
-                f,
-                "&includeCorpusRemovals={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeCorpusRemovals", &v.to_string());
         }
         if let Some(ref v) = self.include_items_from_all_drives {
-            write!(
-                f,
-                "&includeItemsFromAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeItemsFromAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.include_removed {
-            write!(
-                f,
-                "&includeRemoved={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeRemoved", &v.to_string());
         }
         if let Some(ref v) = self.include_team_drive_items {
-            write!(
-                f,
-                "&includeTeamDriveItems={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeTeamDriveItems", &v.to_string());
         }
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.restrict_to_my_drive {
-            write!(
-                f,
-                "&restrictToMyDrive={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("restrictToMyDrive", &v.to_string());
         }
         if let Some(ref v) = self.spaces {
-            write!(
-                f,
-                "&spaces={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("spaces", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.team_drive_id {
-            write!(
-                f,
-                "&teamDriveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("teamDriveId", &v.to_string());
+        }
+    }
+}
+
+impl Default for ChangesListParams {
+    fn default() -> ChangesListParams {
+        ChangesListParams {
+            drive_params: Default::default(),
+            drive_id: Default::default(),
+            include_corpus_removals: Default::default(),
+            include_items_from_all_drives: Default::default(),
+            include_permissions_for_view: Default::default(),
+            include_removed: Default::default(),
+            include_team_drive_items: Default::default(),
+            page_size: Some(1000),
+            page_token: Default::default(),
+            restrict_to_my_drive: Default::default(),
+            spaces: Default::default(),
+            supports_all_drives: Default::default(),
+            supports_team_drives: Default::default(),
+            team_drive_id: Default::default(),
+        }
+    }
+}
+
+impl ChangesListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_corpus_removals`.
+    pub fn include_corpus_removals<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_corpus_removals = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_items_from_all_drives`.
+    pub fn include_items_from_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_items_from_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<ChangesListParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_removed`.
+    pub fn include_removed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_removed = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_team_drive_items`.
+    pub fn include_team_drive_items<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_team_drive_items = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = value.into();
+        self
+    }
+    /// Builder-style setter for `restrict_to_my_drive`.
+    pub fn restrict_to_my_drive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.restrict_to_my_drive = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `spaces`.
+    pub fn spaces<T: Into<crate::spaces::Spaces>>(mut self, value: T) -> Self {
+        self.spaces = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChangesWatchParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ChangesWatchParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ChangesWatchParamsIncludePermissionsForView::Published => "published",
+            ChangesWatchParamsIncludePermissionsForView::Other(v) => v.as_str(),
         }
-        Ok(())
+    }
+}
+
+impl Default for ChangesWatchParamsIncludePermissionsForView {
+    fn default() -> ChangesWatchParamsIncludePermissionsForView {
+        ChangesWatchParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ChangesWatchParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ChangesWatchParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ChangesWatchParamsIncludePermissionsForView {
+    fn from(v: &str) -> ChangesWatchParamsIncludePermissionsForView {
+        match v {
+            "published" => ChangesWatchParamsIncludePermissionsForView::Published,
+            _ => ChangesWatchParamsIncludePermissionsForView::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ChangesWatchParamsIncludePermissionsForView {
+    fn from(v: String) -> ChangesWatchParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ChangesWatchParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChangesWatchParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ChangesWatchParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `changes.watch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ChangesWatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
     #[serde(rename = "driveId")]
-    pub drive_id: Option<String>,
+    pub drive_id: Option<DriveId>,
     /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
     #[serde(rename = "includeCorpusRemovals")]
     pub include_corpus_removals: Option<bool>,
@@ -1897,7 +3285,7 @@ pub struct ChangesWatchParams {
     pub include_items_from_all_drives: Option<bool>,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<ChangesWatchParamsIncludePermissionsForView>,
     /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
     #[serde(rename = "includeRemoved")]
     pub include_removed: Option<bool>,
@@ -1915,7 +3303,7 @@ pub struct ChangesWatchParams {
     pub restrict_to_my_drive: Option<bool>,
     /// A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
     #[serde(rename = "spaces")]
-    pub spaces: Option<String>,
+    pub spaces: Option<crate::spaces::Spaces>,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -1924,194 +3312,291 @@ pub struct ChangesWatchParams {
     pub supports_team_drives: Option<bool>,
     /// Deprecated use driveId instead.
     #[serde(rename = "teamDriveId")]
-    pub team_drive_id: Option<String>,
+    pub team_drive_id: Option<DriveId>,
 }
 
-impl std::fmt::Display for ChangesWatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&pageToken={}",
-            percent_encode(format!("{}", self.page_token).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
+impl ChangesWatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("pageToken", &self.page_token.to_string());
         if let Some(ref v) = self.drive_id {
-            write!(
-                f,
-                "&driveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("driveId", &v.to_string());
         }
         if let Some(ref v) = self.include_corpus_removals {
-            write!(
-                f,
-                "&includeCorpusRemovals={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeCorpusRemovals", &v.to_string());
         }
         if let Some(ref v) = self.include_items_from_all_drives {
-            write!(
-                f,
-                "&includeItemsFromAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeItemsFromAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.include_removed {
-            write!(
-                f,
-                "&includeRemoved={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeRemoved", &v.to_string());
         }
         if let Some(ref v) = self.include_team_drive_items {
-            write!(
-                f,
-                "&includeTeamDriveItems={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeTeamDriveItems", &v.to_string());
         }
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.restrict_to_my_drive {
-            write!(
-                f,
-                "&restrictToMyDrive={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("restrictToMyDrive", &v.to_string());
         }
         if let Some(ref v) = self.spaces {
-            write!(
-                f,
-                "&spaces={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("spaces", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.team_drive_id {
-            write!(
-                f,
-                "&teamDriveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("teamDriveId", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ChangesWatchParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_corpus_removals`.
+    pub fn include_corpus_removals<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_corpus_removals = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_items_from_all_drives`.
+    pub fn include_items_from_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_items_from_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<ChangesWatchParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_removed`.
+    pub fn include_removed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_removed = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_team_drive_items`.
+    pub fn include_team_drive_items<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_team_drive_items = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = value.into();
+        self
+    }
+    /// Builder-style setter for `restrict_to_my_drive`.
+    pub fn restrict_to_my_drive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.restrict_to_my_drive = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `spaces`.
+    pub fn spaces<T: Into<crate::spaces::Spaces>>(mut self, value: T) -> Self {
+        self.spaces = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `channels.stop` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ChannelsStopParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
 }
 
-impl std::fmt::Display for ChannelsStopParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl ChannelsStopParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl ChannelsStopParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `comments.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct CommentsCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
 }
 
-impl std::fmt::Display for CommentsCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl CommentsCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl CommentsCreateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `comments.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct CommentsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
 }
 
-impl std::fmt::Display for CommentsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl CommentsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl CommentsDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `comments.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct CommentsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Whether to return deleted comments. Deleted comments will not include their original content.
     #[serde(rename = "includeDeleted")]
     pub include_deleted: Option<bool>,
 }
 
-impl std::fmt::Display for CommentsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl CommentsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.include_deleted {
-            write!(
-                f,
-                "&includeDeleted={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeDeleted", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl CommentsGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_deleted`.
+    pub fn include_deleted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deleted = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `comments.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct CommentsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Whether to include deleted comments. Deleted comments will not include their original content.
     #[serde(rename = "includeDeleted")]
     pub include_deleted: Option<bool>,
@@ -2126,145 +3611,260 @@ pub struct CommentsListParams {
     pub start_modified_time: Option<String>,
 }
 
-impl std::fmt::Display for CommentsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl CommentsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.include_deleted {
-            write!(
-                f,
-                "&includeDeleted={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeDeleted", &v.to_string());
         }
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.start_modified_time {
-            write!(
-                f,
-                "&startModifiedTime={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("startModifiedTime", &v.to_string());
+        }
+    }
+}
+
+impl Default for CommentsListParams {
+    fn default() -> CommentsListParams {
+        CommentsListParams {
+            drive_params: Default::default(),
+            file_id: Default::default(),
+            include_deleted: Default::default(),
+            page_size: Some(100),
+            page_token: Default::default(),
+            start_modified_time: Default::default(),
         }
-        Ok(())
+    }
+}
+
+impl CommentsListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_deleted`.
+    pub fn include_deleted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deleted = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `start_modified_time`.
+    pub fn start_modified_time<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_modified_time = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `comments.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct CommentsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
 }
 
-impl std::fmt::Display for CommentsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl CommentsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl CommentsUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `drives.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DrivesCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a shared drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same shared drive. If the shared drive already exists a 409 error will be returned.
     #[serde(rename = "requestId")]
-    pub request_id: String,
+    pub request_id: RequestId,
 }
 
-impl std::fmt::Display for DrivesCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&requestId={}",
-            percent_encode(format!("{}", self.request_id).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
-        Ok(())
+impl DrivesCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("requestId", &self.request_id.to_string());
+    }
+}
+
+impl DrivesCreateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `request_id`.
+    pub fn request_id<T: Into<RequestId>>(mut self, value: T) -> Self {
+        self.request_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `drives.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DrivesDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the shared drive.
     #[serde(rename = "driveId")]
-    pub drive_id: String,
+    pub drive_id: DriveId,
 }
 
-impl std::fmt::Display for DrivesDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl DrivesDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl DrivesDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `drives.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DrivesGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the shared drive.
     #[serde(rename = "driveId")]
-    pub drive_id: String,
+    pub drive_id: DriveId,
     /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
     #[serde(rename = "useDomainAdminAccess")]
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for DrivesGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DrivesGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
     }
 }
 
-/// Parameters for the `drives.hide` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct DrivesHideParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
+impl DrivesGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = value.into();
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
+    }
+}
+
+/// Parameters for the `drives.hide` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct DrivesHideParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the shared drive.
     #[serde(rename = "driveId")]
-    pub drive_id: String,
+    pub drive_id: DriveId,
 }
 
-impl std::fmt::Display for DrivesHideParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl DrivesHideParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl DrivesHideParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `drives.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct DrivesListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2283,86 +3883,211 @@ pub struct DrivesListParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for DrivesListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DrivesListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.q {
-            write!(
-                f,
-                "&q={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("q", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
+        }
+    }
+}
+
+impl Default for DrivesListParams {
+    fn default() -> DrivesListParams {
+        DrivesListParams {
+            drive_params: Default::default(),
+            page_size: Some(100),
+            page_token: Default::default(),
+            q: Default::default(),
+            use_domain_admin_access: Default::default(),
         }
-        Ok(())
+    }
+}
+
+impl DrivesListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `q`.
+    pub fn q<T: Into<String>>(mut self, value: T) -> Self {
+        self.q = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `drives.unhide` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DrivesUnhideParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the shared drive.
     #[serde(rename = "driveId")]
-    pub drive_id: String,
+    pub drive_id: DriveId,
 }
 
-impl std::fmt::Display for DrivesUnhideParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl DrivesUnhideParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl DrivesUnhideParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `drives.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DrivesUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the shared drive.
     #[serde(rename = "driveId")]
-    pub drive_id: String,
+    pub drive_id: DriveId,
     /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
     #[serde(rename = "useDomainAdminAccess")]
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for DrivesUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DrivesUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DrivesUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = value.into();
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesCopyParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesCopyParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesCopyParamsIncludePermissionsForView::Published => "published",
+            FilesCopyParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesCopyParamsIncludePermissionsForView {
+    fn default() -> FilesCopyParamsIncludePermissionsForView {
+        FilesCopyParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesCopyParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesCopyParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesCopyParamsIncludePermissionsForView {
+    fn from(v: &str) -> FilesCopyParamsIncludePermissionsForView {
+        match v {
+            "published" => FilesCopyParamsIncludePermissionsForView::Published,
+            _ => FilesCopyParamsIncludePermissionsForView::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for FilesCopyParamsIncludePermissionsForView {
+    fn from(v: String) -> FilesCopyParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesCopyParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesCopyParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesCopyParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `files.copy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesCopyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2372,13 +4097,13 @@ pub struct FilesCopyParams {
     pub enforce_single_parent: Option<bool>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
     #[serde(rename = "ignoreDefaultVisibility")]
     pub ignore_default_visibility: Option<bool>,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<FilesCopyParamsIncludePermissionsForView>,
     /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
     #[serde(rename = "keepRevisionForever")]
     pub keep_revision_forever: Option<bool>,
@@ -2393,63 +4118,154 @@ pub struct FilesCopyParams {
     pub supports_team_drives: Option<bool>,
 }
 
-impl std::fmt::Display for FilesCopyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesCopyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
         if let Some(ref v) = self.ignore_default_visibility {
-            write!(
-                f,
-                "&ignoreDefaultVisibility={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ignoreDefaultVisibility", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.keep_revision_forever {
-            write!(
-                f,
-                "&keepRevisionForever={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("keepRevisionForever", &v.to_string());
         }
         if let Some(ref v) = self.ocr_language {
-            write!(
-                f,
-                "&ocrLanguage={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ocrLanguage", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
+        }
+    }
+}
+
+impl FilesCopyParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `ignore_default_visibility`.
+    pub fn ignore_default_visibility<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ignore_default_visibility = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<FilesCopyParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `keep_revision_forever`.
+    pub fn keep_revision_forever<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_revision_forever = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `ocr_language`.
+    pub fn ocr_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.ocr_language = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesCreateParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesCreateParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesCreateParamsIncludePermissionsForView::Published => "published",
+            FilesCreateParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesCreateParamsIncludePermissionsForView {
+    fn default() -> FilesCreateParamsIncludePermissionsForView {
+        FilesCreateParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesCreateParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesCreateParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesCreateParamsIncludePermissionsForView {
+    fn from(v: &str) -> FilesCreateParamsIncludePermissionsForView {
+        match v {
+            "published" => FilesCreateParamsIncludePermissionsForView::Published,
+            _ => FilesCreateParamsIncludePermissionsForView::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for FilesCreateParamsIncludePermissionsForView {
+    fn from(v: String) -> FilesCreateParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesCreateParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesCreateParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesCreateParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `files.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2462,7 +4278,7 @@ pub struct FilesCreateParams {
     pub ignore_default_visibility: Option<bool>,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<FilesCreateParamsIncludePermissionsForView>,
     /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
     #[serde(rename = "keepRevisionForever")]
     pub keep_revision_forever: Option<bool>,
@@ -2480,70 +4296,92 @@ pub struct FilesCreateParams {
     pub use_content_as_indexable_text: Option<bool>,
 }
 
-impl std::fmt::Display for FilesCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
         if let Some(ref v) = self.ignore_default_visibility {
-            write!(
-                f,
-                "&ignoreDefaultVisibility={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ignoreDefaultVisibility", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.keep_revision_forever {
-            write!(
-                f,
-                "&keepRevisionForever={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("keepRevisionForever", &v.to_string());
         }
         if let Some(ref v) = self.ocr_language {
-            write!(
-                f,
-                "&ocrLanguage={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ocrLanguage", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.use_content_as_indexable_text {
-            write!(
-                f,
-                "&useContentAsIndexableText={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useContentAsIndexableText", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesCreateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `ignore_default_visibility`.
+    pub fn ignore_default_visibility<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ignore_default_visibility = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<FilesCreateParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `keep_revision_forever`.
+    pub fn keep_revision_forever<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_revision_forever = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `ocr_language`.
+    pub fn ocr_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.ocr_language = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_content_as_indexable_text`.
+    pub fn use_content_as_indexable_text<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_content_as_indexable_text = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `files.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2553,7 +4391,7 @@ pub struct FilesDeleteParams {
     pub enforce_single_parent: Option<bool>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -2562,35 +4400,54 @@ pub struct FilesDeleteParams {
     pub supports_team_drives: Option<bool>,
 }
 
-impl std::fmt::Display for FilesDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `files.emptyTrash` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesEmptyTrashParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2600,46 +4457,75 @@ pub struct FilesEmptyTrashParams {
     pub enforce_single_parent: Option<bool>,
 }
 
-impl std::fmt::Display for FilesEmptyTrashParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesEmptyTrashParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesEmptyTrashParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `files.export` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesExportParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The MIME type of the format requested for this export.
     #[serde(rename = "mimeType")]
     pub mime_type: String,
 }
 
-impl std::fmt::Display for FilesExportParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&mimeType={}",
-            percent_encode(format!("{}", self.mime_type).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
-        Ok(())
+impl FilesExportParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("mimeType", &self.mime_type.to_string());
+    }
+}
+
+impl FilesExportParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `mime_type`.
+    pub fn mime_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.mime_type = value.into();
+        self
     }
 }
 
 /// Parameters for the `files.generateIds` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesGenerateIdsParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2652,28 +4538,106 @@ pub struct FilesGenerateIdsParams {
     pub space: Option<String>,
 }
 
-impl std::fmt::Display for FilesGenerateIdsParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesGenerateIdsParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.count {
-            write!(
-                f,
-                "&count={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("count", &v.to_string());
         }
         if let Some(ref v) = self.space {
-            write!(
-                f,
-                "&space={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("space", &v.to_string());
+        }
+    }
+}
+
+impl FilesGenerateIdsParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `count`.
+    pub fn count<T: Into<i32>>(mut self, value: T) -> Self {
+        self.count = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `space`.
+    pub fn space<T: Into<String>>(mut self, value: T) -> Self {
+        self.space = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesGetParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesGetParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesGetParamsIncludePermissionsForView::Published => "published",
+            FilesGetParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesGetParamsIncludePermissionsForView {
+    fn default() -> FilesGetParamsIncludePermissionsForView {
+        FilesGetParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesGetParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesGetParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesGetParamsIncludePermissionsForView {
+    fn from(v: &str) -> FilesGetParamsIncludePermissionsForView {
+        match v {
+            "published" => FilesGetParamsIncludePermissionsForView::Published,
+            _ => FilesGetParamsIncludePermissionsForView::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for FilesGetParamsIncludePermissionsForView {
+    fn from(v: String) -> FilesGetParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesGetParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesGetParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesGetParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `files.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2683,10 +4647,10 @@ pub struct FilesGetParams {
     pub acknowledge_abuse: Option<bool>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<FilesGetParamsIncludePermissionsForView>,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -2695,196 +4659,543 @@ pub struct FilesGetParams {
     pub supports_team_drives: Option<bool>,
 }
 
-impl std::fmt::Display for FilesGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.acknowledge_abuse {
-            write!(
-                f,
-                "&acknowledgeAbuse={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("acknowledgeAbuse", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
-        Ok(())
     }
 }
 
-/// Parameters for the `files.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct FilesListParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
-    pub drive_params: Option<DriveParams>,
-    /// Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
-    #[serde(rename = "corpora")]
-    pub corpora: Option<String>,
-    /// The source of files to list. Deprecated: use 'corpora' instead.
-    #[serde(rename = "corpus")]
-    pub corpus: Option<String>,
-    /// ID of the shared drive to search.
-    #[serde(rename = "driveId")]
-    pub drive_id: Option<String>,
-    /// Whether both My Drive and shared drive items should be included in results.
-    #[serde(rename = "includeItemsFromAllDrives")]
-    pub include_items_from_all_drives: Option<bool>,
-    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-    #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
-    /// Deprecated use includeItemsFromAllDrives instead.
-    #[serde(rename = "includeTeamDriveItems")]
-    pub include_team_drive_items: Option<bool>,
-    /// A comma-separated list of sort keys. Valid keys are 'createdTime', 'folder', 'modifiedByMeTime', 'modifiedTime', 'name', 'name_natural', 'quotaBytesUsed', 'recency', 'sharedWithMeTime', 'starred', and 'viewedByMeTime'. Each key sorts ascending by default, but may be reversed with the 'desc' modifier. Example usage: ?orderBy=folder,modifiedTime desc,name. Please note that there is a current limitation for users with approximately one million files in which the requested sort order is ignored.
-    #[serde(rename = "orderBy")]
-    pub order_by: Option<String>,
-    /// The maximum number of files to return per page. Partial or empty result pages are possible even before the end of the files list has been reached.
-    #[serde(rename = "pageSize")]
-    pub page_size: Option<i32>,
-    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-    #[serde(rename = "pageToken")]
-    pub page_token: Option<String>,
-    /// A query for filtering the file results. See the "Search for Files" guide for supported syntax.
-    #[serde(rename = "q")]
-    pub q: Option<String>,
-    /// A comma-separated list of spaces to query within the corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
-    #[serde(rename = "spaces")]
-    pub spaces: Option<String>,
-    /// Whether the requesting application supports both My Drives and shared drives.
-    #[serde(rename = "supportsAllDrives")]
-    pub supports_all_drives: Option<bool>,
-    /// Deprecated use supportsAllDrives instead.
-    #[serde(rename = "supportsTeamDrives")]
-    pub supports_team_drives: Option<bool>,
-    /// Deprecated use driveId instead.
-    #[serde(rename = "teamDriveId")]
-    pub team_drive_id: Option<String>,
+impl FilesGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `acknowledge_abuse`.
+    pub fn acknowledge_abuse<T: Into<bool>>(mut self, value: T) -> Self {
+        self.acknowledge_abuse = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<FilesGetParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
 }
 
-impl std::fmt::Display for FilesListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(ref v) = self.corpora {
-            write!(
-                f,
-                "&corpora={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.corpus {
-            write!(
-                f,
-                "&corpus={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.drive_id {
-            write!(
-                f,
-                "&driveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.include_items_from_all_drives {
-            write!(
-                f,
-                "&includeItemsFromAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.include_team_drive_items {
-            write!(
-                f,
-                "&includeTeamDriveItems={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.order_by {
-            write!(
-                f,
-                "&orderBy={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.q {
-            write!(
-                f,
-                "&q={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.spaces {
-            write!(
-                f,
-                "&spaces={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.team_drive_id {
-            write!(
-                f,
-                "&teamDriveId={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+/// Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesListParamsCorpora {
+    User,
+    Drive,
+    Domain,
+    AllDrives,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesListParamsCorpora {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesListParamsCorpora::User => "user",
+            FilesListParamsCorpora::Drive => "drive",
+            FilesListParamsCorpora::Domain => "domain",
+            FilesListParamsCorpora::AllDrives => "allDrives",
+            FilesListParamsCorpora::Other(v) => v.as_str(),
         }
-        Ok(())
     }
 }
 
-/// Parameters for the `files.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+impl Default for FilesListParamsCorpora {
+    fn default() -> FilesListParamsCorpora {
+        FilesListParamsCorpora::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesListParamsCorpora {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesListParamsCorpora {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesListParamsCorpora {
+    fn from(v: &str) -> FilesListParamsCorpora {
+        match v {
+            "user" => FilesListParamsCorpora::User,
+            "drive" => FilesListParamsCorpora::Drive,
+            "domain" => FilesListParamsCorpora::Domain,
+            "allDrives" => FilesListParamsCorpora::AllDrives,
+            _ => FilesListParamsCorpora::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for FilesListParamsCorpora {
+    fn from(v: String) -> FilesListParamsCorpora {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesListParamsCorpora {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesListParamsCorpora {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesListParamsCorpora, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// The source of files to list. Deprecated: use 'corpora' instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesListParamsCorpus {
+    /// Files shared to the user's domain.
+    Domain,
+    /// Files owned by or shared to the user. If a user has permissions on a Shared Drive, the files inside it won't be retrieved unless the user has created, opened, or shared the file.
+    User,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesListParamsCorpus {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesListParamsCorpus::Domain => "domain",
+            FilesListParamsCorpus::User => "user",
+            FilesListParamsCorpus::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesListParamsCorpus {
+    fn default() -> FilesListParamsCorpus {
+        FilesListParamsCorpus::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesListParamsCorpus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesListParamsCorpus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesListParamsCorpus {
+    fn from(v: &str) -> FilesListParamsCorpus {
+        match v {
+            "domain" => FilesListParamsCorpus::Domain,
+            "user" => FilesListParamsCorpus::User,
+            _ => FilesListParamsCorpus::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for FilesListParamsCorpus {
+    fn from(v: String) -> FilesListParamsCorpus {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesListParamsCorpus {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesListParamsCorpus {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesListParamsCorpus, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesListParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesListParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesListParamsIncludePermissionsForView::Published => "published",
+            FilesListParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesListParamsIncludePermissionsForView {
+    fn default() -> FilesListParamsIncludePermissionsForView {
+        FilesListParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesListParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesListParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesListParamsIncludePermissionsForView {
+    fn from(v: &str) -> FilesListParamsIncludePermissionsForView {
+        match v {
+            "published" => FilesListParamsIncludePermissionsForView::Published,
+            _ => FilesListParamsIncludePermissionsForView::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for FilesListParamsIncludePermissionsForView {
+    fn from(v: String) -> FilesListParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesListParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesListParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesListParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Parameters for the `files.list` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FilesListParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
+    pub drive_params: Option<DriveParams>,
+    /// Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
+    #[serde(rename = "corpora")]
+    pub corpora: Option<FilesListParamsCorpora>,
+    /// The source of files to list. Deprecated: use 'corpora' instead.
+    #[serde(rename = "corpus")]
+    pub corpus: Option<FilesListParamsCorpus>,
+    /// ID of the shared drive to search.
+    #[serde(rename = "driveId")]
+    pub drive_id: Option<DriveId>,
+    /// Whether both My Drive and shared drive items should be included in results.
+    #[serde(rename = "includeItemsFromAllDrives")]
+    pub include_items_from_all_drives: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    #[serde(rename = "includePermissionsForView")]
+    pub include_permissions_for_view: Option<FilesListParamsIncludePermissionsForView>,
+    /// Deprecated use includeItemsFromAllDrives instead.
+    #[serde(rename = "includeTeamDriveItems")]
+    pub include_team_drive_items: Option<bool>,
+    /// A comma-separated list of sort keys. Valid keys are 'createdTime', 'folder', 'modifiedByMeTime', 'modifiedTime', 'name', 'name_natural', 'quotaBytesUsed', 'recency', 'sharedWithMeTime', 'starred', and 'viewedByMeTime'. Each key sorts ascending by default, but may be reversed with the 'desc' modifier. Example usage: ?orderBy=folder,modifiedTime desc,name. Please note that there is a current limitation for users with approximately one million files in which the requested sort order is ignored.
+    #[serde(rename = "orderBy")]
+    pub order_by: Option<crate::listing::OrderBy>,
+    /// The maximum number of files to return per page. Partial or empty result pages are possible even before the end of the files list has been reached.
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i32>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    #[serde(rename = "pageToken")]
+    pub page_token: Option<String>,
+    /// A query for filtering the file results. See the "Search for Files" guide for supported syntax.
+    #[serde(rename = "q")]
+    pub q: Option<String>,
+    /// A comma-separated list of spaces to query within the corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
+    #[serde(rename = "spaces")]
+    pub spaces: Option<crate::spaces::Spaces>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    #[serde(rename = "supportsAllDrives")]
+    pub supports_all_drives: Option<bool>,
+    /// Deprecated use supportsAllDrives instead.
+    #[serde(rename = "supportsTeamDrives")]
+    pub supports_team_drives: Option<bool>,
+    /// Deprecated use driveId instead.
+    #[serde(rename = "teamDriveId")]
+    pub team_drive_id: Option<DriveId>,
+}
+
+impl FilesListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        if let Some(ref v) = self.corpora {
+            query.append_pair("corpora", &v.to_string());
+        }
+        if let Some(ref v) = self.corpus {
+            query.append_pair("corpus", &v.to_string());
+        }
+        if let Some(ref v) = self.drive_id {
+            query.append_pair("driveId", &v.to_string());
+        }
+        if let Some(ref v) = self.include_items_from_all_drives {
+            query.append_pair("includeItemsFromAllDrives", &v.to_string());
+        }
+        if let Some(ref v) = self.include_permissions_for_view {
+            query.append_pair("includePermissionsForView", &v.to_string());
+        }
+        if let Some(ref v) = self.include_team_drive_items {
+            query.append_pair("includeTeamDriveItems", &v.to_string());
+        }
+        if let Some(ref v) = self.order_by {
+            query.append_pair("orderBy", &v.to_string());
+        }
+        if let Some(ref v) = self.page_size {
+            query.append_pair("pageSize", &v.to_string());
+        }
+        if let Some(ref v) = self.page_token {
+            query.append_pair("pageToken", &v.to_string());
+        }
+        if let Some(ref v) = self.q {
+            query.append_pair("q", &v.to_string());
+        }
+        if let Some(ref v) = self.spaces {
+            query.append_pair("spaces", &v.to_string());
+        }
+        if let Some(ref v) = self.supports_all_drives {
+            query.append_pair("supportsAllDrives", &v.to_string());
+        }
+        if let Some(ref v) = self.supports_team_drives {
+            query.append_pair("supportsTeamDrives", &v.to_string());
+        }
+        if let Some(ref v) = self.team_drive_id {
+            query.append_pair("teamDriveId", &v.to_string());
+        }
+    }
+}
+
+impl Default for FilesListParams {
+    fn default() -> FilesListParams {
+        FilesListParams {
+            drive_params: Default::default(),
+            corpora: Default::default(),
+            corpus: Default::default(),
+            drive_id: Default::default(),
+            include_items_from_all_drives: Default::default(),
+            include_permissions_for_view: Default::default(),
+            include_team_drive_items: Default::default(),
+            order_by: Default::default(),
+            page_size: Some(1000),
+            page_token: Default::default(),
+            q: Default::default(),
+            spaces: Default::default(),
+            supports_all_drives: Default::default(),
+            supports_team_drives: Default::default(),
+            team_drive_id: Default::default(),
+        }
+    }
+}
+
+impl FilesListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `corpora`.
+    pub fn corpora<T: Into<FilesListParamsCorpora>>(mut self, value: T) -> Self {
+        self.corpora = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `corpus`.
+    pub fn corpus<T: Into<FilesListParamsCorpus>>(mut self, value: T) -> Self {
+        self.corpus = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `drive_id`.
+    pub fn drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_items_from_all_drives`.
+    pub fn include_items_from_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_items_from_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<FilesListParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_team_drive_items`.
+    pub fn include_team_drive_items<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_team_drive_items = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `order_by`.
+    pub fn order_by<T: Into<crate::listing::OrderBy>>(mut self, value: T) -> Self {
+        self.order_by = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `q`.
+    pub fn q<T: Into<String>>(mut self, value: T) -> Self {
+        self.q = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `spaces`.
+    pub fn spaces<T: Into<crate::spaces::Spaces>>(mut self, value: T) -> Self {
+        self.spaces = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesUpdateParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesUpdateParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesUpdateParamsIncludePermissionsForView::Published => "published",
+            FilesUpdateParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesUpdateParamsIncludePermissionsForView {
+    fn default() -> FilesUpdateParamsIncludePermissionsForView {
+        FilesUpdateParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesUpdateParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesUpdateParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesUpdateParamsIncludePermissionsForView {
+    fn from(v: &str) -> FilesUpdateParamsIncludePermissionsForView {
+        match v {
+            "published" => FilesUpdateParamsIncludePermissionsForView::Published,
+            _ => FilesUpdateParamsIncludePermissionsForView::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for FilesUpdateParamsIncludePermissionsForView {
+    fn from(v: String) -> FilesUpdateParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesUpdateParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesUpdateParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesUpdateParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Parameters for the `files.update` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2897,10 +5208,10 @@ pub struct FilesUpdateParams {
     pub enforce_single_parent: Option<bool>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<FilesUpdateParamsIncludePermissionsForView>,
     /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
     #[serde(rename = "keepRevisionForever")]
     pub keep_revision_forever: Option<bool>,
@@ -2921,77 +5232,170 @@ pub struct FilesUpdateParams {
     pub use_content_as_indexable_text: Option<bool>,
 }
 
-impl std::fmt::Display for FilesUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.add_parents {
-            write!(
-                f,
-                "&addParents={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("addParents", &v.to_string());
         }
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.keep_revision_forever {
-            write!(
-                f,
-                "&keepRevisionForever={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("keepRevisionForever", &v.to_string());
         }
         if let Some(ref v) = self.ocr_language {
-            write!(
-                f,
-                "&ocrLanguage={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ocrLanguage", &v.to_string());
         }
         if let Some(ref v) = self.remove_parents {
-            write!(
-                f,
-                "&removeParents={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("removeParents", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.use_content_as_indexable_text {
-            write!(
-                f,
-                "&useContentAsIndexableText={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useContentAsIndexableText", &v.to_string());
+        }
+    }
+}
+
+impl FilesUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `add_parents`.
+    pub fn add_parents<T: Into<String>>(mut self, value: T) -> Self {
+        self.add_parents = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<FilesUpdateParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `keep_revision_forever`.
+    pub fn keep_revision_forever<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_revision_forever = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `ocr_language`.
+    pub fn ocr_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.ocr_language = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `remove_parents`.
+    pub fn remove_parents<T: Into<String>>(mut self, value: T) -> Self {
+        self.remove_parents = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_content_as_indexable_text`.
+    pub fn use_content_as_indexable_text<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_content_as_indexable_text = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilesWatchParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl FilesWatchParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilesWatchParamsIncludePermissionsForView::Published => "published",
+            FilesWatchParamsIncludePermissionsForView::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for FilesWatchParamsIncludePermissionsForView {
+    fn default() -> FilesWatchParamsIncludePermissionsForView {
+        FilesWatchParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for FilesWatchParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FilesWatchParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for FilesWatchParamsIncludePermissionsForView {
+    fn from(v: &str) -> FilesWatchParamsIncludePermissionsForView {
+        match v {
+            "published" => FilesWatchParamsIncludePermissionsForView::Published,
+            _ => FilesWatchParamsIncludePermissionsForView::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for FilesWatchParamsIncludePermissionsForView {
+    fn from(v: String) -> FilesWatchParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for FilesWatchParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilesWatchParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<FilesWatchParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `files.watch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesWatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3001,10 +5405,10 @@ pub struct FilesWatchParams {
     pub acknowledge_abuse: Option<bool>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<FilesWatchParamsIncludePermissionsForView>,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -3013,42 +5417,65 @@ pub struct FilesWatchParams {
     pub supports_team_drives: Option<bool>,
 }
 
-impl std::fmt::Display for FilesWatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesWatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.acknowledge_abuse {
-            write!(
-                f,
-                "&acknowledgeAbuse={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("acknowledgeAbuse", &v.to_string());
         }
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesWatchParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `acknowledge_abuse`.
+    pub fn acknowledge_abuse<T: Into<bool>>(mut self, value: T) -> Self {
+        self.acknowledge_abuse = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<FilesWatchParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `permissions.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionsCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3061,7 +5488,7 @@ pub struct PermissionsCreateParams {
     pub enforce_single_parent: Option<bool>,
     /// The ID of the file or shared drive.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// This parameter only takes effect if the item is not in a shared drive and the request is attempting to transfer the ownership of the item. When set to true, the item is moved to the new owner's My Drive root folder and all prior parents removed. If set to false, when enforceSingleParent=true, parents are not changed. If set to false, when enforceSingleParent=false, existing parents are not changed; however, the file will be added to the new owner's My Drive root folder, unless it is already in the new owner's My Drive.
     #[serde(rename = "moveToNewOwnersRoot")]
     pub move_to_new_owners_root: Option<bool>,
@@ -3082,80 +5509,104 @@ pub struct PermissionsCreateParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for PermissionsCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PermissionsCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.email_message {
-            write!(
-                f,
-                "&emailMessage={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("emailMessage", &v.to_string());
         }
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
         if let Some(ref v) = self.move_to_new_owners_root {
-            write!(
-                f,
-                "&moveToNewOwnersRoot={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("moveToNewOwnersRoot", &v.to_string());
         }
         if let Some(ref v) = self.send_notification_email {
-            write!(
-                f,
-                "&sendNotificationEmail={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("sendNotificationEmail", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.transfer_ownership {
-            write!(
-                f,
-                "&transferOwnership={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("transferOwnership", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl PermissionsCreateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `email_message`.
+    pub fn email_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_message = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `move_to_new_owners_root`.
+    pub fn move_to_new_owners_root<T: Into<bool>>(mut self, value: T) -> Self {
+        self.move_to_new_owners_root = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `send_notification_email`.
+    pub fn send_notification_email<T: Into<bool>>(mut self, value: T) -> Self {
+        self.send_notification_email = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `transfer_ownership`.
+    pub fn transfer_ownership<T: Into<bool>>(mut self, value: T) -> Self {
+        self.transfer_ownership = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `permissions.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file or shared drive.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the permission.
     #[serde(rename = "permissionId")]
-    pub permission_id: String,
+    pub permission_id: PermissionId,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -3167,45 +5618,69 @@ pub struct PermissionsDeleteParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for PermissionsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PermissionsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl PermissionsDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `permission_id`.
+    pub fn permission_id<T: Into<PermissionId>>(mut self, value: T) -> Self {
+        self.permission_id = value.into();
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `permissions.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the permission.
     #[serde(rename = "permissionId")]
-    pub permission_id: String,
+    pub permission_id: PermissionId,
     /// Whether the requesting application supports both My Drives and shared drives.
     #[serde(rename = "supportsAllDrives")]
     pub supports_all_drives: Option<bool>,
@@ -3217,45 +5692,134 @@ pub struct PermissionsGetParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for PermissionsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PermissionsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
+        }
+    }
+}
+
+impl PermissionsGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `permission_id`.
+    pub fn permission_id<T: Into<PermissionId>>(mut self, value: T) -> Self {
+        self.permission_id = value.into();
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
+    }
+}
+
+/// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PermissionsListParamsIncludePermissionsForView {
+    Published,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl PermissionsListParamsIncludePermissionsForView {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionsListParamsIncludePermissionsForView::Published => "published",
+            PermissionsListParamsIncludePermissionsForView::Other(v) => v.as_str(),
         }
-        Ok(())
+    }
+}
+
+impl Default for PermissionsListParamsIncludePermissionsForView {
+    fn default() -> PermissionsListParamsIncludePermissionsForView {
+        PermissionsListParamsIncludePermissionsForView::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for PermissionsListParamsIncludePermissionsForView {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PermissionsListParamsIncludePermissionsForView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PermissionsListParamsIncludePermissionsForView {
+    fn from(v: &str) -> PermissionsListParamsIncludePermissionsForView {
+        match v {
+            "published" => PermissionsListParamsIncludePermissionsForView::Published,
+            _ => PermissionsListParamsIncludePermissionsForView::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for PermissionsListParamsIncludePermissionsForView {
+    fn from(v: String) -> PermissionsListParamsIncludePermissionsForView {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for PermissionsListParamsIncludePermissionsForView {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionsListParamsIncludePermissionsForView {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<PermissionsListParamsIncludePermissionsForView, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `permissions.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct PermissionsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file or shared drive.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
     #[serde(rename = "includePermissionsForView")]
-    pub include_permissions_for_view: Option<String>,
+    pub include_permissions_for_view: Option<PermissionsListParamsIncludePermissionsForView>,
     /// The maximum number of permissions to return per page. When not set for files in a shared drive, at most 100 results will be returned. When not set for files that are not in a shared drive, the entire list will be returned.
     #[serde(rename = "pageSize")]
     pub page_size: Option<i32>,
@@ -3273,66 +5837,106 @@ pub struct PermissionsListParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for PermissionsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PermissionsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.include_permissions_for_view {
-            write!(
-                f,
-                "&includePermissionsForView={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includePermissionsForView", &v.to_string());
         }
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl Default for PermissionsListParams {
+    fn default() -> PermissionsListParams {
+        PermissionsListParams {
+            drive_params: Default::default(),
+            file_id: Default::default(),
+            include_permissions_for_view: Default::default(),
+            page_size: Some(100),
+            page_token: Default::default(),
+            supports_all_drives: Default::default(),
+            supports_team_drives: Default::default(),
+            use_domain_admin_access: Default::default(),
+        }
+    }
+}
+
+impl PermissionsListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_permissions_for_view`.
+    pub fn include_permissions_for_view<T: Into<PermissionsListParamsIncludePermissionsForView>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `permissions.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PermissionsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file or shared drive.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the permission.
     #[serde(rename = "permissionId")]
-    pub permission_id: String,
+    pub permission_id: PermissionId,
     /// Whether to remove the expiration date.
     #[serde(rename = "removeExpiration")]
     pub remove_expiration: Option<bool>,
@@ -3350,135 +5954,235 @@ pub struct PermissionsUpdateParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for PermissionsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PermissionsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.remove_expiration {
-            write!(
-                f,
-                "&removeExpiration={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("removeExpiration", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
         if let Some(ref v) = self.transfer_ownership {
-            write!(
-                f,
-                "&transferOwnership={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("transferOwnership", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl PermissionsUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `permission_id`.
+    pub fn permission_id<T: Into<PermissionId>>(mut self, value: T) -> Self {
+        self.permission_id = value.into();
+        self
+    }
+    /// Builder-style setter for `remove_expiration`.
+    pub fn remove_expiration<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remove_expiration = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `transfer_ownership`.
+    pub fn transfer_ownership<T: Into<bool>>(mut self, value: T) -> Self {
+        self.transfer_ownership = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `replies.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RepliesCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
 }
 
-impl std::fmt::Display for RepliesCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl RepliesCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl RepliesCreateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `replies.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RepliesDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the reply.
     #[serde(rename = "replyId")]
-    pub reply_id: String,
+    pub reply_id: ReplyId,
 }
 
-impl std::fmt::Display for RepliesDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl RepliesDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl RepliesDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `reply_id`.
+    pub fn reply_id<T: Into<ReplyId>>(mut self, value: T) -> Self {
+        self.reply_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `replies.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RepliesGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Whether to return deleted replies. Deleted replies will not include their original content.
     #[serde(rename = "includeDeleted")]
     pub include_deleted: Option<bool>,
     /// The ID of the reply.
     #[serde(rename = "replyId")]
-    pub reply_id: String,
+    pub reply_id: ReplyId,
 }
 
-impl std::fmt::Display for RepliesGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl RepliesGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.include_deleted {
-            write!(
-                f,
-                "&includeDeleted={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeDeleted", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl RepliesGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_deleted`.
+    pub fn include_deleted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deleted = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `reply_id`.
+    pub fn reply_id<T: Into<ReplyId>>(mut self, value: T) -> Self {
+        self.reply_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `replies.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct RepliesListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// Whether to include deleted replies. Deleted replies will not include their original content.
     #[serde(rename = "includeDeleted")]
     pub include_deleted: Option<bool>,
@@ -3490,78 +6194,162 @@ pub struct RepliesListParams {
     pub page_token: Option<String>,
 }
 
-impl std::fmt::Display for RepliesListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl RepliesListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.include_deleted {
-            write!(
-                f,
-                "&includeDeleted={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeDeleted", &v.to_string());
         }
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
+        }
+    }
+}
+
+impl Default for RepliesListParams {
+    fn default() -> RepliesListParams {
+        RepliesListParams {
+            drive_params: Default::default(),
+            comment_id: Default::default(),
+            file_id: Default::default(),
+            include_deleted: Default::default(),
+            page_size: Some(100),
+            page_token: Default::default(),
         }
-        Ok(())
+    }
+}
+
+impl RepliesListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `include_deleted`.
+    pub fn include_deleted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deleted = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `replies.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RepliesUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the comment.
     #[serde(rename = "commentId")]
-    pub comment_id: String,
+    pub comment_id: CommentId,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the reply.
     #[serde(rename = "replyId")]
-    pub reply_id: String,
+    pub reply_id: ReplyId,
 }
 
-impl std::fmt::Display for RepliesUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl RepliesUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl RepliesUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `comment_id`.
+    pub fn comment_id<T: Into<CommentId>>(mut self, value: T) -> Self {
+        self.comment_id = value.into();
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `reply_id`.
+    pub fn reply_id<T: Into<ReplyId>>(mut self, value: T) -> Self {
+        self.reply_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `revisions.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RevisionsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the revision.
     #[serde(rename = "revisionId")]
-    pub revision_id: String,
+    pub revision_id: RevisionId,
 }
 
-impl std::fmt::Display for RevisionsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl RevisionsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl RevisionsDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `revision_id`.
+    pub fn revision_id<T: Into<RevisionId>>(mut self, value: T) -> Self {
+        self.revision_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `revisions.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RevisionsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3571,34 +6359,56 @@ pub struct RevisionsGetParams {
     pub acknowledge_abuse: Option<bool>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the revision.
     #[serde(rename = "revisionId")]
-    pub revision_id: String,
+    pub revision_id: RevisionId,
 }
 
-impl std::fmt::Display for RevisionsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl RevisionsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.acknowledge_abuse {
-            write!(
-                f,
-                "&acknowledgeAbuse={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("acknowledgeAbuse", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl RevisionsGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `acknowledge_abuse`.
+    pub fn acknowledge_abuse<T: Into<bool>>(mut self, value: T) -> Self {
+        self.acknowledge_abuse = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `revision_id`.
+    pub fn revision_id<T: Into<RevisionId>>(mut self, value: T) -> Self {
+        self.revision_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `revisions.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct RevisionsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The maximum number of revisions to return per page.
     #[serde(rename = "pageSize")]
     pub page_size: Option<i32>,
@@ -3607,114 +6417,209 @@ pub struct RevisionsListParams {
     pub page_token: Option<String>,
 }
 
-impl std::fmt::Display for RevisionsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl RevisionsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl Default for RevisionsListParams {
+    fn default() -> RevisionsListParams {
+        RevisionsListParams {
+            drive_params: Default::default(),
+            file_id: Default::default(),
+            page_size: Some(1000),
+            page_token: Default::default(),
+        }
+    }
+}
+
+impl RevisionsListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `revisions.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RevisionsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the file.
     #[serde(rename = "fileId")]
-    pub file_id: String,
+    pub file_id: FileId,
     /// The ID of the revision.
     #[serde(rename = "revisionId")]
-    pub revision_id: String,
+    pub revision_id: RevisionId,
 }
 
-impl std::fmt::Display for RevisionsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl RevisionsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl RevisionsUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<FileId>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `revision_id`.
+    pub fn revision_id<T: Into<RevisionId>>(mut self, value: T) -> Self {
+        self.revision_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `teamdrives.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TeamdrivesCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a Team Drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same Team Drive. If the Team Drive already exists a 409 error will be returned.
     #[serde(rename = "requestId")]
-    pub request_id: String,
+    pub request_id: RequestId,
 }
 
-impl std::fmt::Display for TeamdrivesCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&requestId={}",
-            percent_encode(format!("{}", self.request_id).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
-        Ok(())
+impl TeamdrivesCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("requestId", &self.request_id.to_string());
+    }
+}
+
+impl TeamdrivesCreateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `request_id`.
+    pub fn request_id<T: Into<RequestId>>(mut self, value: T) -> Self {
+        self.request_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `teamdrives.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TeamdrivesDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the Team Drive
     #[serde(rename = "teamDriveId")]
-    pub team_drive_id: String,
+    pub team_drive_id: DriveId,
 }
 
-impl std::fmt::Display for TeamdrivesDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl TeamdrivesDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl TeamdrivesDeleteParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `teamdrives.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TeamdrivesGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the Team Drive
     #[serde(rename = "teamDriveId")]
-    pub team_drive_id: String,
+    pub team_drive_id: DriveId,
     /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
     #[serde(rename = "useDomainAdminAccess")]
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for TeamdrivesGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TeamdrivesGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl TeamdrivesGetParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = value.into();
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `teamdrives.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct TeamdrivesListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3733,146 +6638,211 @@ pub struct TeamdrivesListParams {
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for TeamdrivesListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TeamdrivesListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.page_size {
-            write!(
-                f,
-                "&pageSize={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageSize", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.q {
-            write!(
-                f,
-                "&q={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("q", &v.to_string());
         }
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
+        }
+    }
+}
+
+impl Default for TeamdrivesListParams {
+    fn default() -> TeamdrivesListParams {
+        TeamdrivesListParams {
+            drive_params: Default::default(),
+            page_size: Some(100),
+            page_token: Default::default(),
+            q: Default::default(),
+            use_domain_admin_access: Default::default(),
         }
-        Ok(())
+    }
+}
+
+impl TeamdrivesListParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_size`.
+    pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `q`.
+    pub fn q<T: Into<String>>(mut self, value: T) -> Self {
+        self.q = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `teamdrives.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TeamdrivesUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub drive_params: Option<DriveParams>,
     /// The ID of the Team Drive
     #[serde(rename = "teamDriveId")]
-    pub team_drive_id: String,
+    pub team_drive_id: DriveId,
     /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
     #[serde(rename = "useDomainAdminAccess")]
     pub use_domain_admin_access: Option<bool>,
 }
 
-impl std::fmt::Display for TeamdrivesUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TeamdrivesUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.use_domain_admin_access {
-            write!(
-                f,
-                "&useDomainAdminAccess={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useDomainAdminAccess", &v.to_string());
         }
-        Ok(())
     }
 }
 
-impl std::fmt::Display for DriveParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TeamdrivesUpdateParams {
+    /// Builder-style setter for `drive_params`.
+    pub fn drive_params<T: Into<DriveParams>>(mut self, value: T) -> Self {
+        self.drive_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `team_drive_id`.
+    pub fn team_drive_id<T: Into<DriveId>>(mut self, value: T) -> Self {
+        self.team_drive_id = value.into();
+        self
+    }
+    /// Builder-style setter for `use_domain_admin_access`.
+    pub fn use_domain_admin_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_domain_admin_access = Some(value.into());
+        self
+    }
+}
+
+impl DriveParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.alt {
-            write!(
-                f,
-                "&alt={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("alt", &v.to_string());
         }
         if let Some(ref v) = self.fields {
-            write!(
-                f,
-                "&fields={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("fields", &v.to_string());
         }
         if let Some(ref v) = self.key {
-            write!(
-                f,
-                "&key={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("key", &v.to_string());
         }
         if let Some(ref v) = self.oauth_token {
-            write!(
-                f,
-                "&oauth_token={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("oauth_token", &v.to_string());
         }
         if let Some(ref v) = self.pretty_print {
-            write!(
-                f,
-                "&prettyPrint={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("prettyPrint", &v.to_string());
         }
         if let Some(ref v) = self.quota_user {
-            write!(
-                f,
-                "&quotaUser={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("quotaUser", &v.to_string());
         }
         if let Some(ref v) = self.user_ip {
-            write!(
-                f,
-                "&userIp={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userIp", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DriveParams {
+    /// Builder-style setter for `alt`.
+    pub fn alt<T: Into<DriveParamsAlt>>(mut self, value: T) -> Self {
+        self.alt = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `fields`.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `key`.
+    pub fn key<T: Into<String>>(mut self, value: T) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `oauth_token`.
+    pub fn oauth_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.oauth_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `pretty_print`.
+    pub fn pretty_print<T: Into<bool>>(mut self, value: T) -> Self {
+        self.pretty_print = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `quota_user`.
+    pub fn quota_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.quota_user = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_ip`.
+    pub fn user_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_ip = Some(value.into());
+        self
     }
 }
 
 /// The Drive About service represents the About resource.
-pub struct AboutService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct AboutService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl AboutService {
+impl<C: HttpClient> AboutService<C> {
     /// Create a new AboutService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> AboutService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> AboutService<C> {
         AboutService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -3899,18 +6869,39 @@ impl AboutService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -3919,60 +6910,128 @@ impl AboutService {
             .collect();
     }
 
-    /// Gets information about the user, the user's Drive, and system capabilities.
-    pub async fn get(&mut self, params: &AboutGetParams) -> Result<About> {
-        let rel_path = format!("about",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
+
+    /// Gets information about the user, the user's Drive, and system capabilities.
+    pub async fn get(&self, params: &AboutGetParams) -> Result<About> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(&self, params: &AboutGetParams) -> Result<ApiResponse<About>> {
+        traced("About", "get", async move {
+            let rel_path = format!("about",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Drive Changes service represents the Changes resource.
-pub struct ChangesService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ChangesService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ChangesService {
+impl<C: HttpClient> ChangesService<C> {
     /// Create a new ChangesService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> ChangesService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> ChangesService<C> {
         ChangesService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -3999,18 +7058,39 @@ impl ChangesService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4019,122 +7099,237 @@ impl ChangesService {
             .collect();
     }
 
-    /// Gets the starting pageToken for listing future changes.
-    pub async fn get_start_page_token(
-        &mut self,
-        params: &ChangesGetStartPageTokenParams,
-    ) -> Result<StartPageToken> {
-        let rel_path = format!("changes/startPageToken",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Lists the changes for a user or shared drive.
-    pub async fn list(&mut self, params: &ChangesListParams) -> Result<ChangeList> {
-        let rel_path = format!("changes",);
-        let path = self.format_path(rel_path.as_str());
+    /// Gets the starting pageToken for listing future changes.
+    pub async fn get_start_page_token(
+        &self,
+        params: &ChangesGetStartPageTokenParams,
+    ) -> Result<StartPageToken> {
+        Ok(self.get_start_page_token_with_response(params).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `get_start_page_token()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_start_page_token_with_response(
+        &self,
+        params: &ChangesGetStartPageTokenParams,
+    ) -> Result<ApiResponse<StartPageToken>> {
+        traced("Changes", "get_start_page_token", async move {
+            let rel_path = format!("changes/startPageToken",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Lists the changes for a user or shared drive.
+    pub async fn list(&self, params: &ChangesListParams) -> Result<ChangeList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &ChangesListParams,
+    ) -> Result<ApiResponse<ChangeList>> {
+        traced("Changes", "list", async move {
+            let rel_path = format!("changes",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Subscribes to changes for a user.
-    pub async fn watch(&mut self, params: &ChangesWatchParams, req: &Channel) -> Result<Channel> {
-        let rel_path = format!("changes/watch",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    pub async fn watch(&self, params: &ChangesWatchParams, req: &Channel) -> Result<Channel> {
+        Ok(self.watch_with_response(params, req).await?.body)
+    }
+
+    /// Like `watch()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn watch_with_response(
+        &self,
+        params: &ChangesWatchParams,
+        req: &Channel,
+    ) -> Result<ApiResponse<Channel>> {
+        traced("Changes", "watch", async move {
+            let rel_path = format!("changes/watch",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Channels service represents the Channels resource.
-pub struct ChannelsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ChannelsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ChannelsService {
+impl<C: HttpClient> ChannelsService<C> {
     /// Create a new ChannelsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> ChannelsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> ChannelsService<C> {
         ChannelsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -4161,18 +7356,39 @@ impl ChannelsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4181,61 +7397,134 @@ impl ChannelsService {
             .collect();
     }
 
-    /// Stop watching resources through this channel
-    pub async fn stop(&mut self, params: &ChannelsStopParams, req: &Channel) -> Result<()> {
-        let rel_path = format!("channels/stop",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+    /// Stop watching resources through this channel
+    pub async fn stop(&self, params: &ChannelsStopParams, req: &Channel) -> Result<()> {
+        Ok(self.stop_with_response(params, req).await?.body)
+    }
+
+    /// Like `stop()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn stop_with_response(
+        &self,
+        params: &ChannelsStopParams,
+        req: &Channel,
+    ) -> Result<ApiResponse<()>> {
+        traced("Channels", "stop", async move {
+            let rel_path = format!("channels/stop",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Comments service represents the Comments resource.
-pub struct CommentsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct CommentsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl CommentsService {
+impl<C: HttpClient> CommentsService<C> {
     /// Create a new CommentsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> CommentsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> CommentsService<C> {
         CommentsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -4262,18 +7551,39 @@ impl CommentsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4282,204 +7592,356 @@ impl CommentsService {
             .collect();
     }
 
-    /// Creates a new comment on a file.
-    pub async fn create(
-        &mut self,
-        params: &CommentsCreateParams,
-        req: &Comment,
-    ) -> Result<Comment> {
-        let rel_path = format!(
-            "files/{fileId}/comments",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Deletes a comment.
-    pub async fn delete(&mut self, params: &CommentsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Creates a new comment on a file.
+    pub async fn create(&self, params: &CommentsCreateParams, req: &Comment) -> Result<Comment> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &CommentsCreateParams,
+        req: &Comment,
+    ) -> Result<ApiResponse<Comment>> {
+        traced("Comments", "create", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Deletes a comment.
+    pub async fn delete(&self, params: &CommentsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &CommentsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Comments", "delete", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Gets a comment by ID.
-    pub async fn get(&mut self, params: &CommentsGetParams) -> Result<Comment> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn get(&self, params: &CommentsGetParams) -> Result<Comment> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &CommentsGetParams,
+    ) -> Result<ApiResponse<Comment>> {
+        traced("Comments", "get", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Lists a file's comments.
-    pub async fn list(&mut self, params: &CommentsListParams) -> Result<CommentList> {
-        let rel_path = format!(
-            "files/{fileId}/comments",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &CommentsListParams) -> Result<CommentList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &CommentsListParams,
+    ) -> Result<ApiResponse<CommentList>> {
+        traced("Comments", "list", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Updates a comment with patch semantics.
-    pub async fn update(
-        &mut self,
+    pub async fn update(&self, params: &CommentsUpdateParams, req: &Comment) -> Result<Comment> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
+
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
         params: &CommentsUpdateParams,
         req: &Comment,
-    ) -> Result<Comment> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    ) -> Result<ApiResponse<Comment>> {
+        traced("Comments", "update", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Drives service represents the Drives resource.
-pub struct DrivesService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct DrivesService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl DrivesService {
+impl<C: HttpClient> DrivesService<C> {
     /// Create a new DrivesService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> DrivesService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> DrivesService<C> {
         DrivesService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -4506,18 +7968,39 @@ impl DrivesService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4526,251 +8009,452 @@ impl DrivesService {
             .collect();
     }
 
-    /// Creates a new shared drive.
-    pub async fn create(&mut self, params: &DrivesCreateParams, req: &Drive) -> Result<Drive> {
-        let rel_path = format!("drives",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Permanently deletes a shared drive for which the user is an organizer. The shared drive cannot contain any untrashed items.
-    pub async fn delete(&mut self, params: &DrivesDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "drives/{driveId}",
-            driveId = percent_encode(params.drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Creates a new shared drive.
+    pub async fn create(&self, params: &DrivesCreateParams, req: &Drive) -> Result<Drive> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
+
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &DrivesCreateParams,
+        req: &Drive,
+    ) -> Result<ApiResponse<Drive>> {
+        traced("Drives", "create", async move {
+            let rel_path = format!("drives",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Permanently deletes a shared drive for which the user is an organizer. The shared drive cannot contain any untrashed items.
+    pub async fn delete(&self, params: &DrivesDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &DrivesDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Drives", "delete", async move {
+            let rel_path = format!(
+                "drives/{driveId}",
+                driveId = percent_encode(params.drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Gets a shared drive's metadata by ID.
-    pub async fn get(&mut self, params: &DrivesGetParams) -> Result<Drive> {
-        let rel_path = format!(
-            "drives/{driveId}",
-            driveId = percent_encode(params.drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn get(&self, params: &DrivesGetParams) -> Result<Drive> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(&self, params: &DrivesGetParams) -> Result<ApiResponse<Drive>> {
+        traced("Drives", "get", async move {
+            let rel_path = format!(
+                "drives/{driveId}",
+                driveId = percent_encode(params.drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Hides a shared drive from the default view.
-    pub async fn hide(&mut self, params: &DrivesHideParams) -> Result<Drive> {
-        let rel_path = format!(
-            "drives/{driveId}/hide",
-            driveId = percent_encode(params.drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn hide(&self, params: &DrivesHideParams) -> Result<Drive> {
+        Ok(self.hide_with_response(params).await?.body)
+    }
+
+    /// Like `hide()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn hide_with_response(
+        &self,
+        params: &DrivesHideParams,
+    ) -> Result<ApiResponse<Drive>> {
+        traced("Drives", "hide", async move {
+            let rel_path = format!(
+                "drives/{driveId}/hide",
+                driveId = percent_encode(params.drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Lists the user's shared drives.
-    pub async fn list(&mut self, params: &DrivesListParams) -> Result<DriveList> {
-        let rel_path = format!("drives",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &DrivesListParams) -> Result<DriveList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &DrivesListParams,
+    ) -> Result<ApiResponse<DriveList>> {
+        traced("Drives", "list", async move {
+            let rel_path = format!("drives",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Restores a shared drive to the default view.
-    pub async fn unhide(&mut self, params: &DrivesUnhideParams) -> Result<Drive> {
-        let rel_path = format!(
-            "drives/{driveId}/unhide",
-            driveId = percent_encode(params.drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn unhide(&self, params: &DrivesUnhideParams) -> Result<Drive> {
+        Ok(self.unhide_with_response(params).await?.body)
+    }
+
+    /// Like `unhide()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn unhide_with_response(
+        &self,
+        params: &DrivesUnhideParams,
+    ) -> Result<ApiResponse<Drive>> {
+        traced("Drives", "unhide", async move {
+            let rel_path = format!(
+                "drives/{driveId}/unhide",
+                driveId = percent_encode(params.drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Updates the metadate for a shared drive.
-    pub async fn update(&mut self, params: &DrivesUpdateParams, req: &Drive) -> Result<Drive> {
-        let rel_path = format!(
-            "drives/{driveId}",
-            driveId = percent_encode(params.drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn update(&self, params: &DrivesUpdateParams, req: &Drive) -> Result<Drive> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
+
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &DrivesUpdateParams,
+        req: &Drive,
+    ) -> Result<ApiResponse<Drive>> {
+        traced("Drives", "update", async move {
+            let rel_path = format!(
+                "drives/{driveId}",
+                driveId = percent_encode(params.drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Files service represents the Files resource.
-pub struct FilesService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct FilesService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl FilesService {
+impl<C: HttpClient> FilesService<C> {
     /// Create a new FilesService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> FilesService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> FilesService<C> {
         FilesService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -4797,18 +8481,39 @@ impl FilesService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4817,105 +8522,241 @@ impl FilesService {
             .collect();
     }
 
-    /// Creates a copy of a file and applies any requested updates with patch semantics. Folders cannot be copied.
-    pub async fn copy(&mut self, params: &FilesCopyParams, req: &File) -> Result<File> {
-        let rel_path = format!(
-            "files/{fileId}/copy",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DrivePhotosReadonly.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+    /// Creates a copy of a file and applies any requested updates with patch semantics. Folders cannot be copied.
+    pub async fn copy(&self, params: &FilesCopyParams, req: &File) -> Result<File> {
+        Ok(self.copy_with_response(params, req).await?.body)
     }
 
-    /// Creates a new file.
-    pub async fn create(&mut self, params: &FilesCreateParams, req: &File) -> Result<File> {
-        let rel_path = format!("files",);
-        let path = self.format_path(rel_path.as_str());
+    /// Like `copy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn copy_with_response(
+        &self,
+        params: &FilesCopyParams,
+        req: &File,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "copy", async move {
+            let rel_path = format!(
+                "files/{fileId}/copy",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_PHOTOS_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Creates a new file.
+    pub async fn create(&self, params: &FilesCreateParams, req: &File) -> Result<File> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &FilesCreateParams,
+        req: &File,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "create", async move {
+            let rel_path = format!("files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Creates a new file.
     ///
     /// This method is a variant of `create()`, taking data for upload. It performs a multipart upload.
     pub async fn create_upload(
-        &mut self,
+        &self,
         params: &FilesCreateParams,
         req: &File,
         data: hyper::body::Bytes,
     ) -> Result<File> {
-        let rel_path = format!("/upload/drive/v3/files",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?uploadType=multipart{params}", params = params);
+        self.create_upload_with_type(params, req, data, None).await
+    }
 
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Like `create_upload()`, but sends `content_type` as the MIME type of `data`. Without it,
+    /// the server detects the type from the content.
+    pub async fn create_upload_with_type(
+        &self,
+        params: &FilesCreateParams,
+        req: &File,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<File> {
+        Ok(self
+            .create_upload_with_response(params, req, data, content_type)
+            .await?
+            .body)
+    }
 
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
+    /// Like `create_upload_with_type()`, but also returns the status and headers of the response.
+    pub async fn create_upload_with_response(
+        &self,
+        params: &FilesCreateParams,
+        req: &File,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "create_upload", async move {
+            let rel_path = format!("/upload/drive/v3/files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "multipart");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
 
-        do_upload_multipart(&self.client, &full_uri, &headers, "POST", opt_request, data).await
+            do_upload_multipart_with_response(
+                &self.client,
+                url.as_str(),
+                &headers,
+                "POST",
+                opt_request,
+                data,
+                content_type,
+            )
+            .await
+        })
+        .await
     }
 
     /// Creates a new file.
@@ -4925,325 +8766,548 @@ impl FilesService {
     /// of data to the API. The result of this call will be returned by the `ResumableUpload` method
     /// you choose for the upload.
     pub async fn create_resumable_upload<'client>(
-        &'client mut self,
+        &'client self,
         params: &FilesCreateParams,
         req: &File,
-    ) -> Result<ResumableUpload<'client, File>> {
-        let rel_path = format!("/resumable/upload/drive/v3/files",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    ) -> Result<ResumableUpload<'client, File, C>> {
+        self.create_resumable_upload_with_type(params, req, None)
+            .await
+    }
 
-        let mut url_params = format!("?uploadType=resumable{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Like `create_resumable_upload()`, but sends `content_type` as the MIME type of the data
+    /// to be uploaded. Without it, the server detects the type from the content.
+    pub async fn create_resumable_upload_with_type<'client>(
+        &'client self,
+        params: &FilesCreateParams,
+        req: &File,
+        content_type: Option<&str>,
+    ) -> Result<ResumableUpload<'client, File, C>> {
+        traced("Files", "create_resumable_upload", async move {
+            let rel_path = format!("/resumable/upload/drive/v3/files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            if let Some(content_type) = content_type {
+                headers.push((
+                    hyper::header::HeaderName::from_static("x-upload-content-type"),
+                    content_type.to_string(),
+                ));
+            }
 
-        let full_uri = path + &url_params;
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "resumable");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
-            do_request_with_headers(&self.client, &full_uri, &headers, "POST", opt_request).await?;
-        if let Some(dest) = headers.get(hyper::header::LOCATION) {
-            use std::convert::TryFrom;
-            Ok(ResumableUpload::new(
-                hyper::Uri::try_from(dest.to_str()?)?,
-                &self.client,
-                5 * 1024 * 1024,
-            ))
-        } else {
-            Err(Error::from(ApiError::RedirectError(format!(
-                "Resumable upload response didn't contain Location: {:?}",
-                headers
-            )))
-            .context(format!("{:?}", headers)))?
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
+                do_request_with_headers(&self.client, url.as_str(), &headers, "POST", opt_request)
+                    .await?;
+            if let Some(dest) = headers.get(hyper::header::LOCATION) {
+                use std::convert::TryFrom;
+                Ok(ResumableUpload::new(
+                    hyper::Uri::try_from(dest.to_str()?)?,
+                    &self.client,
+                    5 * 1024 * 1024,
+                ))
+            } else {
+                Err(Error::from(ApiError::RedirectError(format!(
+                    "Resumable upload response didn't contain Location: {:?}",
+                    headers
+                )))
+                .context(format!("{:?}", headers)))?
+            }
+        })
+        .await
     }
 
     /// Permanently deletes a file owned by the user without moving it to the trash. If the file belongs to a shared drive the user must be an organizer on the parent. If the target is a folder, all descendants owned by the user are also deleted.
-    pub async fn delete(&mut self, params: &FilesDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "files/{fileId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn delete(&self, params: &FilesDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &FilesDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Files", "delete", async move {
+            let rel_path = format!(
+                "files/{fileId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Permanently deletes all of the user's trashed files.
-    pub async fn empty_trash(&mut self, params: &FilesEmptyTrashParams) -> Result<()> {
-        let rel_path = format!("files/trash",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn empty_trash(&self, params: &FilesEmptyTrashParams) -> Result<()> {
+        Ok(self.empty_trash_with_response(params).await?.body)
+    }
+
+    /// Like `empty_trash()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn empty_trash_with_response(
+        &self,
+        params: &FilesEmptyTrashParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Files", "empty_trash", async move {
+            let rel_path = format!("files/trash",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Exports a Google Doc to the requested MIME type and returns the exported content. Please note that the exported content is limited to 10MB.
     ///
     /// This method potentially downloads data. See documentation of `Download`.
     pub async fn export<'a>(
-        &'a mut self,
+        &'a self,
         params: &FilesExportParams,
-    ) -> Result<Download<'a, EmptyRequest, ()>> {
-        let rel_path = format!(
-            "files/{fileId}/export",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
+    ) -> Result<Download<'a, EmptyRequest, (), C>> {
+        traced("Files", "export", async move {
+            let rel_path = format!(
+                "files/{fileId}/export",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
 
-        do_download(&self.client, &full_uri, headers, "GET".into(), opt_request).await
+            do_download(
+                &self.client,
+                url.as_str(),
+                headers,
+                "GET".into(),
+                opt_request,
+            )
+            .await
+        })
+        .await
     }
 
     /// Generates a set of file IDs which can be provided in create or copy requests.
-    pub async fn generate_ids(&mut self, params: &FilesGenerateIdsParams) -> Result<GeneratedIds> {
-        let rel_path = format!("files/generateIds",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn generate_ids(&self, params: &FilesGenerateIdsParams) -> Result<GeneratedIds> {
+        Ok(self.generate_ids_with_response(params).await?.body)
+    }
+
+    /// Like `generate_ids()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn generate_ids_with_response(
+        &self,
+        params: &FilesGenerateIdsParams,
+    ) -> Result<ApiResponse<GeneratedIds>> {
+        traced("Files", "generate_ids", async move {
+            let rel_path = format!("files/generateIds",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Gets a file's metadata or content by ID.
     ///
     /// This method potentially downloads data. See documentation of `Download`.
     pub async fn get<'a>(
-        &'a mut self,
+        &'a self,
         params: &FilesGetParams,
-    ) -> Result<Download<'a, EmptyRequest, File>> {
-        let rel_path = format!(
-            "files/{fileId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
-
-        do_download(&self.client, &full_uri, headers, "GET".into(), opt_request).await
-    }
-
-    /// Lists or searches files.
-    pub async fn list(&mut self, params: &FilesListParams) -> Result<FileList> {
-        let rel_path = format!("files",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
-    }
-
-    /// Updates a file's metadata and/or content. This method supports patch semantics.
-    pub async fn update(&mut self, params: &FilesUpdateParams, req: &File) -> Result<File> {
-        let rel_path = format!(
-            "files/{fileId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    ) -> Result<Download<'a, EmptyRequest, File, C>> {
+        traced("Files", "get", async move {
+            let rel_path = format!(
+                "files/{fileId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveScripts.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            do_download(
+                &self.client,
+                url.as_str(),
+                headers,
+                "GET".into(),
+                opt_request,
+            )
+            .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Lists or searches files.
+    pub async fn list(&self, params: &FilesListParams) -> Result<FileList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &FilesListParams,
+    ) -> Result<ApiResponse<FileList>> {
+        traced("Files", "list", async move {
+            let rel_path = format!("files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
+
+    /// Updates a file's metadata and/or content. This method supports patch semantics.
+    pub async fn update(&self, params: &FilesUpdateParams, req: &File) -> Result<File> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &FilesUpdateParams,
+        req: &File,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "update", async move {
+            let rel_path = format!(
+                "files/{fileId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_SCRIPTS];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Updates a file's metadata and/or content. This method supports patch semantics.
     ///
     /// This method is a variant of `update()`, taking data for upload. It performs a multipart upload.
     pub async fn update_upload(
-        &mut self,
+        &self,
         params: &FilesUpdateParams,
         req: &File,
         data: hyper::body::Bytes,
     ) -> Result<File> {
-        let rel_path = format!(
-            "/upload/drive/v3/files/{fileId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveScripts.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?uploadType=multipart{params}", params = params);
+        self.update_upload_with_type(params, req, data, None).await
+    }
 
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Like `update_upload()`, but sends `content_type` as the MIME type of `data`. Without it,
+    /// the server detects the type from the content.
+    pub async fn update_upload_with_type(
+        &self,
+        params: &FilesUpdateParams,
+        req: &File,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<File> {
+        Ok(self
+            .update_upload_with_response(params, req, data, content_type)
+            .await?
+            .body)
+    }
 
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
+    /// Like `update_upload_with_type()`, but also returns the status and headers of the response.
+    pub async fn update_upload_with_response(
+        &self,
+        params: &FilesUpdateParams,
+        req: &File,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "update_upload", async move {
+            let rel_path = format!(
+                "/upload/drive/v3/files/{fileId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_SCRIPTS];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "multipart");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
 
-        do_upload_multipart(
-            &self.client,
-            &full_uri,
-            &headers,
-            "PATCH",
-            opt_request,
-            data,
-        )
+            do_upload_multipart_with_response(
+                &self.client,
+                url.as_str(),
+                &headers,
+                "PATCH",
+                opt_request,
+                data,
+                content_type,
+            )
+            .await
+        })
         .await
     }
 
@@ -5254,121 +9318,185 @@ impl FilesService {
     /// of data to the API. The result of this call will be returned by the `ResumableUpload` method
     /// you choose for the upload.
     pub async fn update_resumable_upload<'client>(
-        &'client mut self,
+        &'client self,
         params: &FilesUpdateParams,
         req: &File,
-    ) -> Result<ResumableUpload<'client, File>> {
-        let rel_path = format!(
-            "/resumable/upload/drive/v3/files/{fileId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveScripts.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    ) -> Result<ResumableUpload<'client, File, C>> {
+        self.update_resumable_upload_with_type(params, req, None)
+            .await
+    }
 
-        let mut url_params = format!("?uploadType=resumable{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Like `update_resumable_upload()`, but sends `content_type` as the MIME type of the data
+    /// to be uploaded. Without it, the server detects the type from the content.
+    pub async fn update_resumable_upload_with_type<'client>(
+        &'client self,
+        params: &FilesUpdateParams,
+        req: &File,
+        content_type: Option<&str>,
+    ) -> Result<ResumableUpload<'client, File, C>> {
+        traced("Files", "update_resumable_upload", async move {
+            let rel_path = format!(
+                "/resumable/upload/drive/v3/files/{fileId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_SCRIPTS];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            if let Some(content_type) = content_type {
+                headers.push((
+                    hyper::header::HeaderName::from_static("x-upload-content-type"),
+                    content_type.to_string(),
+                ));
+            }
 
-        let full_uri = path + &url_params;
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "resumable");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
-            do_request_with_headers(&self.client, &full_uri, &headers, "PATCH", opt_request)
-                .await?;
-        if let Some(dest) = headers.get(hyper::header::LOCATION) {
-            use std::convert::TryFrom;
-            Ok(ResumableUpload::new(
-                hyper::Uri::try_from(dest.to_str()?)?,
-                &self.client,
-                5 * 1024 * 1024,
-            ))
-        } else {
-            Err(Error::from(ApiError::RedirectError(format!(
-                "Resumable upload response didn't contain Location: {:?}",
-                headers
-            )))
-            .context(format!("{:?}", headers)))?
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
+                do_request_with_headers(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                    .await?;
+            if let Some(dest) = headers.get(hyper::header::LOCATION) {
+                use std::convert::TryFrom;
+                Ok(ResumableUpload::new(
+                    hyper::Uri::try_from(dest.to_str()?)?,
+                    &self.client,
+                    5 * 1024 * 1024,
+                ))
+            } else {
+                Err(Error::from(ApiError::RedirectError(format!(
+                    "Resumable upload response didn't contain Location: {:?}",
+                    headers
+                )))
+                .context(format!("{:?}", headers)))?
+            }
+        })
+        .await
     }
 
     /// Subscribes to changes to a file
     ///
     /// This method potentially downloads data. See documentation of `Download`.
     pub async fn watch<'a>(
-        &'a mut self,
+        &'a self,
         params: &FilesWatchParams,
         req: &'a Channel,
-    ) -> Result<Download<'a, Channel, Channel>> {
-        let rel_path = format!(
-            "files/{fileId}/watch",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
+    ) -> Result<Download<'a, Channel, Channel, C>> {
+        traced("Files", "watch", async move {
+            let rel_path = format!(
+                "files/{fileId}/watch",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
 
-        do_download(&self.client, &full_uri, headers, "POST".into(), opt_request).await
+            do_download(
+                &self.client,
+                url.as_str(),
+                headers,
+                "POST".into(),
+                opt_request,
+            )
+            .await
+        })
+        .await
     }
 }
 
 /// The Drive Permissions service represents the Permissions resource.
-pub struct PermissionsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct PermissionsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl PermissionsService {
+impl<C: HttpClient> PermissionsService<C> {
     /// Create a new PermissionsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> PermissionsService {
+    ) -> PermissionsService<C> {
         PermissionsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5395,18 +9523,39 @@ impl PermissionsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -5415,204 +9564,364 @@ impl PermissionsService {
             .collect();
     }
 
-    /// Creates a permission for a file or shared drive.
-    pub async fn create(
-        &mut self,
-        params: &PermissionsCreateParams,
-        req: &Permission,
-    ) -> Result<Permission> {
-        let rel_path = format!(
-            "files/{fileId}/permissions",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Deletes a permission.
-    pub async fn delete(&mut self, params: &PermissionsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "files/{fileId}/permissions/{permissionId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            permissionId = percent_encode(params.permission_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Creates a permission for a file or shared drive.
+    pub async fn create(
+        &self,
+        params: &PermissionsCreateParams,
+        req: &Permission,
+    ) -> Result<Permission> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &PermissionsCreateParams,
+        req: &Permission,
+    ) -> Result<ApiResponse<Permission>> {
+        traced("Permissions", "create", async move {
+            let rel_path = format!(
+                "files/{fileId}/permissions",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Deletes a permission.
+    pub async fn delete(&self, params: &PermissionsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &PermissionsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Permissions", "delete", async move {
+            let rel_path = format!(
+                "files/{fileId}/permissions/{permissionId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                permissionId = percent_encode(params.permission_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Gets a permission by ID.
-    pub async fn get(&mut self, params: &PermissionsGetParams) -> Result<Permission> {
-        let rel_path = format!(
-            "files/{fileId}/permissions/{permissionId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            permissionId = percent_encode(params.permission_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn get(&self, params: &PermissionsGetParams) -> Result<Permission> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &PermissionsGetParams,
+    ) -> Result<ApiResponse<Permission>> {
+        traced("Permissions", "get", async move {
+            let rel_path = format!(
+                "files/{fileId}/permissions/{permissionId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                permissionId = percent_encode(params.permission_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Lists a file's or shared drive's permissions.
-    pub async fn list(&mut self, params: &PermissionsListParams) -> Result<PermissionList> {
-        let rel_path = format!(
-            "files/{fileId}/permissions",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &PermissionsListParams) -> Result<PermissionList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &PermissionsListParams,
+    ) -> Result<ApiResponse<PermissionList>> {
+        traced("Permissions", "list", async move {
+            let rel_path = format!(
+                "files/{fileId}/permissions",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Updates a permission with patch semantics.
     pub async fn update(
-        &mut self,
+        &self,
         params: &PermissionsUpdateParams,
         req: &Permission,
     ) -> Result<Permission> {
-        let rel_path = format!(
-            "files/{fileId}/permissions/{permissionId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            permissionId = percent_encode(params.permission_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &PermissionsUpdateParams,
+        req: &Permission,
+    ) -> Result<ApiResponse<Permission>> {
+        traced("Permissions", "update", async move {
+            let rel_path = format!(
+                "files/{fileId}/permissions/{permissionId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                permissionId = percent_encode(params.permission_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Replies service represents the Replies resource.
-pub struct RepliesService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct RepliesService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl RepliesService {
+impl<C: HttpClient> RepliesService<C> {
     /// Create a new RepliesService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> RepliesService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> RepliesService<C> {
         RepliesService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5639,221 +9948,399 @@ impl RepliesService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
-    /// Explicitly select which scopes should be requested for authorization. Otherwise,
-    /// a possibly too large scope will be requested.
-    ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
-    pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
-        self.scopes = scopes
-            .as_ref()
-            .into_iter()
-            .map(|s| s.as_ref().to_string())
-            .collect();
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
     }
 
-    /// Creates a new reply to a comment.
-    pub async fn create(&mut self, params: &RepliesCreateParams, req: &Reply) -> Result<Reply> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}/replies",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
     }
 
-    /// Deletes a reply.
-    pub async fn delete(&mut self, params: &RepliesDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}/replies/{replyId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC),
-            replyId = percent_encode(params.reply_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
     }
 
-    /// Gets a reply by ID.
-    pub async fn get(&mut self, params: &RepliesGetParams) -> Result<Reply> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}/replies/{replyId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC),
-            replyId = percent_encode(params.reply_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+    /// Explicitly select which scopes should be requested for authorization. Otherwise,
+    /// a possibly too large scope will be requested.
+    ///
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
+    pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
+        self.scopes = scopes
+            .as_ref()
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
     }
 
-    /// Lists a comment's replies.
-    pub async fn list(&mut self, params: &RepliesListParams) -> Result<ReplyList> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}/replies",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
+
+    /// Creates a new reply to a comment.
+    pub async fn create(&self, params: &RepliesCreateParams, req: &Reply) -> Result<Reply> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
+
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &RepliesCreateParams,
+        req: &Reply,
+    ) -> Result<ApiResponse<Reply>> {
+        traced("Replies", "create", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}/replies",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
-    /// Updates a reply with patch semantics.
-    pub async fn update(&mut self, params: &RepliesUpdateParams, req: &Reply) -> Result<Reply> {
-        let rel_path = format!(
-            "files/{fileId}/comments/{commentId}/replies/{replyId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            commentId = percent_encode(params.comment_id.as_bytes(), NON_ALPHANUMERIC),
-            replyId = percent_encode(params.reply_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Deletes a reply.
+    pub async fn delete(&self, params: &RepliesDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &RepliesDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Replies", "delete", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}/replies/{replyId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT),
+                replyId = percent_encode(params.reply_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Gets a reply by ID.
+    pub async fn get(&self, params: &RepliesGetParams) -> Result<Reply> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(&self, params: &RepliesGetParams) -> Result<ApiResponse<Reply>> {
+        traced("Replies", "get", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}/replies/{replyId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT),
+                replyId = percent_encode(params.reply_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
+
+    /// Lists a comment's replies.
+    pub async fn list(&self, params: &RepliesListParams) -> Result<ReplyList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &RepliesListParams,
+    ) -> Result<ApiResponse<ReplyList>> {
+        traced("Replies", "list", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}/replies",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Updates a reply with patch semantics.
+    pub async fn update(&self, params: &RepliesUpdateParams, req: &Reply) -> Result<Reply> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
+
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &RepliesUpdateParams,
+        req: &Reply,
+    ) -> Result<ApiResponse<Reply>> {
+        traced("Replies", "update", async move {
+            let rel_path = format!(
+                "files/{fileId}/comments/{commentId}/replies/{replyId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                commentId = percent_encode(params.comment_id.as_bytes(), PATH_SEGMENT),
+                replyId = percent_encode(params.reply_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Revisions service represents the Revisions resource.
-pub struct RevisionsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct RevisionsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl RevisionsService {
+impl<C: HttpClient> RevisionsService<C> {
     /// Create a new RevisionsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> RevisionsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> RevisionsService<C> {
         RevisionsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5880,18 +10367,39 @@ impl RevisionsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -5900,172 +10408,304 @@ impl RevisionsService {
             .collect();
     }
 
-    /// Permanently deletes a file version. You can only delete revisions for files with binary content in Google Drive, like images or videos. Revisions for other files, like Google Docs or Sheets, and the last remaining file version can't be deleted.
-    pub async fn delete(&mut self, params: &RevisionsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "files/{fileId}/revisions/{revisionId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            revisionId = percent_encode(params.revision_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
         }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
+        }
+
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let full_uri = path + &url_params;
+    /// Permanently deletes a file version. You can only delete revisions for files with binary content in Google Drive, like images or videos. Revisions for other files, like Google Docs or Sheets, and the last remaining file version can't be deleted.
+    pub async fn delete(&self, params: &RevisionsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &RevisionsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Revisions", "delete", async move {
+            let rel_path = format!(
+                "files/{fileId}/revisions/{revisionId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                revisionId = percent_encode(params.revision_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Gets a revision's metadata or content by ID.
     ///
     /// This method potentially downloads data. See documentation of `Download`.
     pub async fn get<'a>(
-        &'a mut self,
+        &'a self,
         params: &RevisionsGetParams,
-    ) -> Result<Download<'a, EmptyRequest, Revision>> {
-        let rel_path = format!(
-            "files/{fileId}/revisions/{revisionId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            revisionId = percent_encode(params.revision_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
+    ) -> Result<Download<'a, EmptyRequest, Revision, C>> {
+        traced("Revisions", "get", async move {
+            let rel_path = format!(
+                "files/{fileId}/revisions/{revisionId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                revisionId = percent_encode(params.revision_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
 
-        do_download(&self.client, &full_uri, headers, "GET".into(), opt_request).await
+            do_download(
+                &self.client,
+                url.as_str(),
+                headers,
+                "GET".into(),
+                opt_request,
+            )
+            .await
+        })
+        .await
     }
 
     /// Lists a file's revisions.
-    pub async fn list(&mut self, params: &RevisionsListParams) -> Result<RevisionList> {
-        let rel_path = format!(
-            "files/{fileId}/revisions",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &RevisionsListParams) -> Result<RevisionList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &RevisionsListParams,
+    ) -> Result<ApiResponse<RevisionList>> {
+        traced("Revisions", "list", async move {
+            let rel_path = format!(
+                "files/{fileId}/revisions",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Updates a revision with patch semantics.
-    pub async fn update(
-        &mut self,
+    pub async fn update(&self, params: &RevisionsUpdateParams, req: &Revision) -> Result<Revision> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
+
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
         params: &RevisionsUpdateParams,
         req: &Revision,
-    ) -> Result<Revision> {
-        let rel_path = format!(
-            "files/{fileId}/revisions/{revisionId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC),
-            revisionId = percent_encode(params.revision_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveFile.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    ) -> Result<ApiResponse<Revision>> {
+        traced("Revisions", "update", async move {
+            let rel_path = format!(
+                "files/{fileId}/revisions/{revisionId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT),
+                revisionId = percent_encode(params.revision_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_FILE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Drive Teamdrives service represents the Teamdrives resource.
-pub struct TeamdrivesService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct TeamdrivesService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl TeamdrivesService {
+impl<C: HttpClient> TeamdrivesService<C> {
     /// Create a new TeamdrivesService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> TeamdrivesService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> TeamdrivesService<C> {
         TeamdrivesService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://www.googleapis.com/drive/v3/".into(),
             root_url: "https://www.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -6092,18 +10732,39 @@ impl TeamdrivesService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of DriveScopes enum values.
+    /// It is most convenient to supply a vec or slice of DriveScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -6112,167 +10773,389 @@ impl TeamdrivesService {
             .collect();
     }
 
-    /// Deprecated use drives.create instead.
-    pub async fn create(
-        &mut self,
-        params: &TeamdrivesCreateParams,
-        req: &TeamDrive,
-    ) -> Result<TeamDrive> {
-        let rel_path = format!("teamdrives",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
+
+    /// Deprecated use drives.create instead.
+    pub async fn create(
+        &self,
+        params: &TeamdrivesCreateParams,
+        req: &TeamDrive,
+    ) -> Result<TeamDrive> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
+
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &TeamdrivesCreateParams,
+        req: &TeamDrive,
+    ) -> Result<ApiResponse<TeamDrive>> {
+        traced("Teamdrives", "create", async move {
+            let rel_path = format!("teamdrives",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Deprecated use drives.delete instead.
-    pub async fn delete(&mut self, params: &TeamdrivesDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "teamdrives/{teamDriveId}",
-            teamDriveId = percent_encode(params.team_drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    pub async fn delete(&self, params: &TeamdrivesDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &TeamdrivesDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Teamdrives", "delete", async move {
+            let rel_path = format!(
+                "teamdrives/{teamDriveId}",
+                teamDriveId = percent_encode(params.team_drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Deprecated use drives.get instead.
+    pub async fn get(&self, params: &TeamdrivesGetParams) -> Result<TeamDrive> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &TeamdrivesGetParams,
+    ) -> Result<ApiResponse<TeamDrive>> {
+        traced("Teamdrives", "get", async move {
+            let rel_path = format!(
+                "teamdrives/{teamDriveId}",
+                teamDriveId = percent_encode(params.team_drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Deprecated use drives.list instead.
+    pub async fn list(&self, params: &TeamdrivesListParams) -> Result<TeamDriveList> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &TeamdrivesListParams,
+    ) -> Result<ApiResponse<TeamDriveList>> {
+        traced("Teamdrives", "list", async move {
+            let rel_path = format!("teamdrives",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE_READONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
-    /// Deprecated use drives.get instead.
-    pub async fn get(&mut self, params: &TeamdrivesGetParams) -> Result<TeamDrive> {
-        let rel_path = format!(
-            "teamdrives/{teamDriveId}",
-            teamDriveId = percent_encode(params.team_drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Deprecated use drives.update instead
+    pub async fn update(
+        &self,
+        params: &TeamdrivesUpdateParams,
+        req: &TeamDrive,
+    ) -> Result<TeamDrive> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &TeamdrivesUpdateParams,
+        req: &TeamDrive,
+    ) -> Result<ApiResponse<TeamDrive>> {
+        traced("Teamdrives", "update", async move {
+            let rel_path = format!(
+                "teamdrives/{teamDriveId}",
+                teamDriveId = percent_encode(params.team_drive_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DRIVE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
+    }
+}
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
+/// All services of the Drive API, sharing one HTTP client and one token source. Each
+/// accessor returns a new service; services are cheap to create and to clone.
+///
+/// ```ignore
+/// let hub = DriveHub::new(https_client, authenticator);
+/// let service = hub.about();
+/// ```
+#[derive(Clone)]
+pub struct DriveHub<C = TlsClient> {
+    apis: GoogleApis<C>,
+}
+
+impl<C: HttpClient> DriveHub<C> {
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> DriveHub<C> {
+        DriveHub {
+            apis: GoogleApis::new(client, auth),
         }
+    }
 
-        let full_uri = path + &url_params;
+    /// A hub using the client and token source of `apis`, e.g. to share them with other APIs.
+    pub fn from_apis(apis: GoogleApis<C>) -> DriveHub<C> {
+        DriveHub { apis }
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+    /// The shared client and token source.
+    pub fn apis(&self) -> &GoogleApis<C> {
+        &self.apis
     }
 
-    /// Deprecated use drives.list instead.
-    pub async fn list(&mut self, params: &TeamdrivesListParams) -> Result<TeamDriveList> {
-        let rel_path = format!("teamdrives",);
-        let path = self.format_path(rel_path.as_str());
+    pub fn about(&self) -> AboutService<C> {
+        self.apis.service(AboutService::new)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::DriveReadonly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    pub fn changes(&self) -> ChangesService<C> {
+        self.apis.service(ChangesService::new)
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    pub fn channels(&self) -> ChannelsService<C> {
+        self.apis.service(ChannelsService::new)
+    }
 
-        let full_uri = path + &url_params;
+    pub fn comments(&self) -> CommentsService<C> {
+        self.apis.service(CommentsService::new)
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+    pub fn drives(&self) -> DrivesService<C> {
+        self.apis.service(DrivesService::new)
     }
 
-    /// Deprecated use drives.update instead
-    pub async fn update(
-        &mut self,
-        params: &TeamdrivesUpdateParams,
-        req: &TeamDrive,
-    ) -> Result<TeamDrive> {
-        let rel_path = format!(
-            "teamdrives/{teamDriveId}",
-            teamDriveId = percent_encode(params.team_drive_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    pub fn files(&self) -> FilesService<C> {
+        self.apis.service(FilesService::new)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[DriveScopes::Drive.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    pub fn permissions(&self) -> PermissionsService<C> {
+        self.apis.service(PermissionsService::new)
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.drive_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    pub fn replies(&self) -> RepliesService<C> {
+        self.apis.service(RepliesService::new)
+    }
 
-        let full_uri = path + &url_params;
+    pub fn revisions(&self) -> RevisionsService<C> {
+        self.apis.service(RevisionsService::new)
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+    pub fn teamdrives(&self) -> TeamdrivesService<C> {
+        self.apis.service(TeamdrivesService::new)
     }
 }
@@ -0,0 +1,534 @@
+//! Consuming Drive events through the Google Workspace Events API, without an HTTPS endpoint.
+//!
+//! Unlike watch channels (see `channels`), which `POST` notifications to a public web server, a
+//! Workspace Events subscription publishes events about a Drive file or shared drive to a Pub/Sub
+//! topic. `EventSubscriptions` creates and manages such subscriptions, and `EventConsumer` pulls
+//! the events from a Pub/Sub subscription of that topic, decoded into `DriveEvent`s.
+//!
+//! Neither API has generated bindings in this crate; this module binds the few methods needed,
+//! on top of the same request functions the generated services use.
+//!
+//! ```ignore
+//! let subscriptions = EventSubscriptions::new(client.clone(), auth.clone());
+//! let subscription = Subscription::new(
+//!     drive_file_resource(file_id),
+//!     "projects/my-project/topics/drive-events",
+//!     &["google.workspace.drive.file.v3.contentChanged"],
+//! );
+//! subscriptions.create(&subscription).await?;
+//!
+//! let consumer = EventConsumer::new(client, auth, "projects/my-project/subscriptions/drive");
+//! loop {
+//!     let events = consumer.pull(10).await?;
+//!     for event in events.iter() {
+//!         println!("{} on {:?}", event.event_type, event.file_id());
+//!     }
+//!     consumer.acknowledge(events.iter().map(|e| e.ack_id.clone())).await?;
+//! }
+//! ```
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+use serde_json::Value;
+
+/// Base URL of the Workspace Events API.
+pub const WORKSPACE_EVENTS_URL: &str = "https://workspaceevents.googleapis.com/v1/";
+/// Base URL of the Pub/Sub API.
+pub const PUBSUB_URL: &str = "https://pubsub.googleapis.com/v1/";
+/// Scope for pulling and acknowledging Pub/Sub messages.
+pub const PUBSUB_SCOPE: &str = "https://www.googleapis.com/auth/pubsub";
+
+/// The target resource name of the Drive file `file_id`, for `Subscription::new()`.
+pub fn drive_file_resource(file_id: &str) -> String {
+    format!("//drive.googleapis.com/files/{}", file_id)
+}
+
+/// Where a subscription delivers events.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationEndpoint {
+    /// The Pub/Sub topic, as `projects/{project}/topics/{topic}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pubsub_topic: Option<String>,
+}
+
+/// What events contain about the changed resource.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayloadOptions {
+    /// Include the resource itself, not only its name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_resource: Option<bool>,
+    /// Fields of the resource to include, e.g. `file.name,file.modifiedTime`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_mask: Option<String>,
+}
+
+/// A Workspace Events subscription.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+    /// Assigned by the server, as `subscriptions/{uid}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// The resource whose events are delivered, e.g. from `drive_file_resource()`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_resource: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_options: Option<PayloadOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_endpoint: Option<NotificationEndpoint>,
+    /// `ACTIVE`, `SUSPENDED` or `DELETED`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Why a `SUSPENDED` subscription doesn't deliver events; see `EventSubscriptions::reactivate()`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspension_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_time: Option<DateTime<Utc>>,
+    /// Requested lifetime, e.g. `86400s`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+}
+
+impl Subscription {
+    /// A subscription to `event_types` of `target_resource`, published to `pubsub_topic`.
+    pub fn new(target_resource: String, pubsub_topic: &str, event_types: &[&str]) -> Subscription {
+        Subscription {
+            target_resource: Some(target_resource),
+            event_types: Some(event_types.iter().map(|t| t.to_string()).collect()),
+            notification_endpoint: Some(NotificationEndpoint {
+                pubsub_topic: Some(pubsub_topic.into()),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// The error of a failed `Operation`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct OperationError {
+    #[serde(default)]
+    pub code: i32,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// A long-running operation, returned by methods changing subscriptions.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Operation {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub done: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OperationError>,
+    /// The result once `done`, e.g. the created `Subscription`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+}
+
+impl Operation {
+    /// The subscription this operation resulted in, once it is done. Fails if the operation
+    /// failed.
+    pub fn subscription(&self) -> Result<Option<Subscription>> {
+        if let Some(error) = self.error.as_ref() {
+            return Err(ApiError::InputDataError(format!(
+                "events: operation {} failed ({}): {}",
+                self.name, error.code, error.message
+            ))
+            .into());
+        }
+        match self.response.as_ref() {
+            Some(response) if self.done => Ok(Some(serde_json::from_value(response.clone())?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct ListSubscriptionsResponse {
+    #[serde(default)]
+    subscriptions: Vec<Subscription>,
+    next_page_token: Option<String>,
+}
+
+/// An access token for `scopes` as `Authorization` header.
+async fn auth_headers(
+    authenticator: &(dyn TokenSource + Send + Sync),
+    scopes: &[&str],
+) -> Result<Vec<(hyper::header::HeaderName, String)>> {
+    let token = authenticator.token(scopes).await?;
+    Ok(vec![(
+        hyper::header::AUTHORIZATION,
+        format!("Bearer {}", token),
+    )])
+}
+
+/// Manages Workspace Events subscriptions; see the module documentation.
+pub struct EventSubscriptions<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
+    scopes: Vec<String>,
+    base_url: String,
+}
+
+impl<C: HttpClient> EventSubscriptions<C> {
+    /// Tokens are requested with the `drive.readonly` scope, which covers events about files;
+    /// use `set_scopes()` for others.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
+        auth: A,
+    ) -> EventSubscriptions<C> {
+        EventSubscriptions {
+            client,
+            authenticator: Arc::new(auth),
+            scopes: vec![drive::scopes::DRIVE_READONLY.into()],
+            base_url: WORKSPACE_EVENTS_URL.into(),
+        }
+    }
+
+    pub fn set_scopes<I: IntoIterator<Item = S>, S: AsRef<str>>(&mut self, scopes: I) {
+        self.scopes = scopes.into_iter().map(|s| s.as_ref().to_string()).collect();
+    }
+
+    /// Override the API URL, e.g. to use a test server.
+    pub fn set_base_url(&mut self, base: String) {
+        self.base_url = base;
+    }
+
+    async fn send<Req: Serialize + std::fmt::Debug, Resp: DeserializeOwned + Clone + Default>(
+        &self,
+        method: &str,
+        url: Url,
+        req: Option<Req>,
+    ) -> Result<Resp> {
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        let headers = auth_headers(self.authenticator.as_ref(), &scopes).await?;
+        do_request(&self.client, url.as_str(), &headers, method, req).await
+    }
+
+    fn url(&self, path: &str) -> Result<Url> {
+        Ok(Url::parse(&format!("{}{}", self.base_url, path))?)
+    }
+
+    /// Create `subscription`. The returned operation contains the subscription once it is
+    /// `done`, which is usually immediately.
+    pub async fn create(&self, subscription: &Subscription) -> Result<Operation> {
+        self.send("POST", self.url("subscriptions")?, Some(subscription))
+            .await
+    }
+
+    /// The subscription named `name` (`subscriptions/{uid}`).
+    pub async fn get(&self, name: &str) -> Result<Subscription> {
+        self.send("GET", self.url(name)?, None::<EmptyRequest>)
+            .await
+    }
+
+    /// All subscriptions matching `filter`, e.g. `event_types:"google.workspace.drive.file.v3.contentChanged"`.
+    pub async fn list(&self, filter: &str) -> Result<Vec<Subscription>> {
+        let mut subscriptions = vec![];
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut url = self.url("subscriptions")?;
+            url.query_pairs_mut().append_pair("filter", filter);
+            if let Some(token) = page_token.as_ref() {
+                url.query_pairs_mut().append_pair("pageToken", token);
+            }
+            let page: ListSubscriptionsResponse =
+                self.send("GET", url, None::<EmptyRequest>).await?;
+            subscriptions.extend(page.subscriptions);
+            page_token = page.next_page_token.filter(|t| !t.is_empty());
+            if page_token.is_none() {
+                return Ok(subscriptions);
+            }
+        }
+    }
+
+    /// Delete the subscription `name`; no more events are published for it.
+    pub async fn delete(&self, name: &str) -> Result<Operation> {
+        self.send("DELETE", self.url(name)?, None::<EmptyRequest>)
+            .await
+    }
+
+    /// Resume delivering events of a `SUSPENDED` subscription, after fixing the cause given
+    /// in `suspension_reason` (e.g. missing permissions on the topic).
+    pub async fn reactivate(&self, name: &str) -> Result<Operation> {
+        let url = self.url(&format!("{}:reactivate", name))?;
+        self.send("POST", url, Some(serde_json::json!({}))).await
+    }
+
+    /// The current state of the operation `name`.
+    pub async fn operation(&self, name: &str) -> Result<Operation> {
+        self.send("GET", self.url(name)?, None::<EmptyRequest>)
+            .await
+    }
+}
+
+/// An event about a Drive resource, delivered as a Pub/Sub message in CloudEvents format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriveEvent {
+    /// Acknowledges the message with `EventConsumer::acknowledge()`.
+    pub ack_id: String,
+    /// Unique per event; a redelivered event has the same ID.
+    pub id: String,
+    /// E.g. `google.workspace.drive.file.v3.contentChanged`.
+    pub event_type: String,
+    /// The subscription that published the event.
+    pub source: String,
+    /// The resource the event is about, e.g. `//drive.googleapis.com/files/{file_id}`.
+    pub subject: Option<String>,
+    pub time: Option<DateTime<Utc>>,
+    /// The event data, with the resource if requested by `PayloadOptions`; `Null` if empty.
+    pub payload: Value,
+}
+
+impl DriveEvent {
+    /// The ID of the file the event is about.
+    pub fn file_id(&self) -> Option<&str> {
+        self.subject
+            .as_deref()?
+            .strip_prefix("//drive.googleapis.com/files/")
+    }
+
+    /// Decode a Pub/Sub message; `None` if it isn't a CloudEvent.
+    fn from_message(received: &ReceivedMessage) -> Result<Option<DriveEvent>> {
+        let message = &received.message;
+        let attr = |name: &str| message.attributes.get(name).cloned();
+        let (id, event_type) = match (attr("ce-id"), attr("ce-type")) {
+            (Some(id), Some(event_type)) => (id, event_type),
+            _ => return Ok(None),
+        };
+        let payload = match message.data.as_deref() {
+            None | Some("") => Value::Null,
+            Some(data) => {
+                let data = radix64::STD.decode(data).map_err(|e| {
+                    ApiError::InputDataError(format!("events: invalid message data: {:?}", e))
+                })?;
+                serde_json::from_slice(&data)?
+            }
+        };
+        Ok(Some(DriveEvent {
+            ack_id: received.ack_id.clone(),
+            id,
+            event_type,
+            source: attr("ce-source").unwrap_or_default(),
+            subject: attr("ce-subject"),
+            time: attr("ce-time")
+                .and_then(|t| DateTime::parse_from_rfc3339(&t).ok())
+                .map(|t| t.with_timezone(&Utc)),
+            payload,
+        }))
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct PubsubMessage {
+    data: Option<String>,
+    #[serde(default)]
+    attributes: HashMap<String, String>,
+    #[serde(default)]
+    message_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct ReceivedMessage {
+    ack_id: String,
+    message: PubsubMessage,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct PullResponse {
+    #[serde(default)]
+    received_messages: Vec<ReceivedMessage>,
+}
+
+/// Pulls Drive events from a Pub/Sub subscription; see the module documentation.
+pub struct EventConsumer<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
+    subscription: String,
+    base_url: String,
+}
+
+impl<C: HttpClient> EventConsumer<C> {
+    /// `subscription` is the Pub/Sub subscription, as `projects/{project}/subscriptions/{name}`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
+        auth: A,
+        subscription: &str,
+    ) -> EventConsumer<C> {
+        EventConsumer {
+            client,
+            authenticator: Arc::new(auth),
+            subscription: subscription.into(),
+            base_url: PUBSUB_URL.into(),
+        }
+    }
+
+    /// Override the API URL, e.g. to use the Pub/Sub emulator.
+    pub fn set_base_url(&mut self, base: String) {
+        self.base_url = base;
+    }
+
+    async fn call<Resp: DeserializeOwned + Clone + Default>(
+        &self,
+        action: &str,
+        req: Value,
+    ) -> Result<Resp> {
+        let url = format!("{}{}:{}", self.base_url, self.subscription, action);
+        let headers = auth_headers(self.authenticator.as_ref(), &[PUBSUB_SCOPE]).await?;
+        do_request(&self.client, &url, &headers, "POST", Some(req)).await
+    }
+
+    /// Wait for up to `max_messages` events. Events must be acknowledged once handled, otherwise
+    /// they are delivered again. Messages that aren't CloudEvents, or whose data can't be decoded,
+    /// are acknowledged right away and dropped, so that they aren't delivered over and over.
+    pub async fn pull(&self, max_messages: i32) -> Result<Vec<DriveEvent>> {
+        let response: PullResponse = self
+            .call("pull", serde_json::json!({ "maxMessages": max_messages }))
+            .await?;
+        let mut events = vec![];
+        let mut dropped = vec![];
+        for received in response.received_messages.iter() {
+            match DriveEvent::from_message(received) {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => {
+                    warn!(
+                        "events: dropping message {}, which is not an event",
+                        received.message.message_id
+                    );
+                    dropped.push(received.ack_id.clone());
+                }
+                Err(e) => {
+                    warn!(
+                        "events: dropping message {}, which can't be decoded: {}",
+                        received.message.message_id, e
+                    );
+                    dropped.push(received.ack_id.clone());
+                }
+            }
+        }
+        if !dropped.is_empty() {
+            self.acknowledge(dropped).await?;
+        }
+        Ok(events)
+    }
+
+    /// Acknowledge handled events by their `ack_id`.
+    pub async fn acknowledge<I: IntoIterator<Item = String>>(&self, ack_ids: I) -> Result<()> {
+        let ack_ids: Vec<String> = ack_ids.into_iter().collect();
+        if ack_ids.is_empty() {
+            return Ok(());
+        }
+        let _: EmptyResponse = self
+            .call("acknowledge", serde_json::json!({ "ackIds": ack_ids }))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_subscription() {
+        let mock = MockClient::new();
+        mock.push(MockResponse::json(&serde_json::json!({
+            "name": "operations/op1",
+            "done": true,
+            "response": {"name": "subscriptions/s1", "state": "ACTIVE"}
+        })));
+        let subscriptions = EventSubscriptions::new(mock.clone(), StaticToken("t".into()));
+        let subscription = Subscription::new(
+            drive_file_resource("f1"),
+            "projects/p/topics/t",
+            &["google.workspace.drive.file.v3.contentChanged"],
+        );
+        let operation = futures::executor::block_on(subscriptions.create(&subscription)).unwrap();
+        let created = operation.subscription().unwrap().unwrap();
+        assert_eq!(created.name.as_deref(), Some("subscriptions/s1"));
+
+        let request = &mock.requests()[0];
+        assert_eq!(
+            request.uri.to_string(),
+            "https://workspaceevents.googleapis.com/v1/subscriptions"
+        );
+        assert_eq!(
+            request.json::<Value>().unwrap(),
+            serde_json::json!({
+                "targetResource": "//drive.googleapis.com/files/f1",
+                "eventTypes": ["google.workspace.drive.file.v3.contentChanged"],
+                "notificationEndpoint": {"pubsubTopic": "projects/p/topics/t"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_pull_events() {
+        let mock = MockClient::new();
+        let data = radix64::STD.encode(br#"{"file": {"id": "f1"}}"#);
+        mock.push(MockResponse::json(
+            &serde_json::json!({"receivedMessages": [
+                {"ackId": "a1", "message": {"data": data, "messageId": "m1", "attributes": {
+                    "ce-id": "e1",
+                    "ce-type": "google.workspace.drive.file.v3.contentChanged",
+                    "ce-source": "//workspaceevents.googleapis.com/subscriptions/s1",
+                    "ce-subject": "//drive.googleapis.com/files/f1",
+                    "ce-time": "2026-10-17T12:00:00Z"
+                }}},
+                {"ackId": "a2", "message": {"data": "", "messageId": "m2"}},
+                // Undecodable events don't fail the pull.
+                {"ackId": "a3", "message": {"data": "not base64!", "messageId": "m3", "attributes": {
+                    "ce-id": "e3",
+                    "ce-type": "google.workspace.drive.file.v3.contentChanged"
+                }}},
+                {"ackId": "a4", "message": {"data": radix64::STD.encode("not JSON"), "messageId": "m4",
+                    "attributes": {
+                        "ce-id": "e4",
+                        "ce-type": "google.workspace.drive.file.v3.contentChanged"
+                    }
+                }}
+            ]}),
+        ))
+        .push(MockResponse::json(&serde_json::json!({})));
+        let consumer = EventConsumer::new(
+            mock.clone(),
+            StaticToken("t".into()),
+            "projects/p/subscriptions/s",
+        );
+
+        let events = futures::executor::block_on(consumer.pull(10)).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].ack_id, "a1");
+        assert_eq!(events[0].file_id(), Some("f1"));
+        assert_eq!(events[0].payload["file"]["id"], "f1");
+        assert!(events[0].time.is_some());
+
+        let requests = mock.requests();
+        assert_eq!(
+            requests[0].uri.to_string(),
+            "https://pubsub.googleapis.com/v1/projects/p/subscriptions/s:pull"
+        );
+        assert_eq!(
+            requests[1].json::<Value>().unwrap(),
+            serde_json::json!({"ackIds": ["a2", "a3", "a4"]})
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Creating folders, and catching invalid folder metadata before the server does.
+
+use crate::drive_v3_types as drive;
+use crate::pages::Page;
+use crate::query::Query;
+use crate::shortcuts::SHORTCUT_MIME_TYPE;
+use async_google_apis_common::*;
+
+use std::collections::{HashSet, VecDeque};
+
+/// The MIME type of Drive folders.
+pub const FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+
+/// Check the metadata `req` of a file to be created, with content if `has_content` is set.
+/// Folders (and shortcuts) can't have content, and only folders have a `folderColorRgb`; the
+/// server responds to such requests with errors that are hard to interpret, whereas this returns
+/// an `ApiError::InputDataError` explaining the problem.
+pub fn validate_new_file(req: &drive::File, has_content: bool) -> Result<()> {
+    let mime_type = req.mime_type.as_deref().unwrap_or("");
+    if has_content && (mime_type == FOLDER_MIME_TYPE || mime_type == SHORTCUT_MIME_TYPE) {
+        return Err(ApiError::InputDataError(format!(
+            "validate_new_file: a file of type {} can't have content",
+            mime_type
+        ))
+        .into());
+    }
+    if req.folder_color_rgb.is_some() && mime_type != FOLDER_MIME_TYPE {
+        return Err(ApiError::InputDataError(format!(
+            "validate_new_file: folderColorRgb can only be set on folders, not on {}",
+            if mime_type.is_empty() {
+                "files without mimeType"
+            } else {
+                mime_type
+            }
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Create a folder named `name` in the folder `parent` (or the root folder), and return it.
+    pub async fn create_folder(&self, name: &str, parent: Option<&str>) -> Result<drive::File> {
+        let mut folder = drive::File::default();
+        folder.mime_type = Some(FOLDER_MIME_TYPE.into());
+        folder.name = Some(name.into());
+        folder.parents = parent.map(|p| vec![p.to_string()]);
+
+        let mut params = drive::FilesCreateParams::default();
+        params.supports_all_drives = Some(true);
+        self.create(&params, &folder).await
+    }
+
+    /// Like `create()`, but checks `req` with `validate_new_file()` first.
+    pub async fn create_validated(
+        &self,
+        params: &drive::FilesCreateParams,
+        req: &drive::File,
+    ) -> Result<drive::File> {
+        validate_new_file(req, false)?;
+        self.create(params, req).await
+    }
+
+    /// Like `create_upload()`, but checks `req` with `validate_new_file()` first.
+    pub async fn create_upload_validated(
+        &self,
+        params: &drive::FilesCreateParams,
+        req: &drive::File,
+        data: hyper::body::Bytes,
+    ) -> Result<drive::File> {
+        validate_new_file(req, true)?;
+        self.create_upload(params, req, data).await
+    }
+}
+
+/// The state of `FilesService::walk_folder()`.
+struct Walk {
+    params: drive::FilesListParams,
+    /// Folders still to be listed.
+    folders: VecDeque<drive::FileId>,
+    visited: HashSet<drive::FileId>,
+    /// The folder being listed, and the token of its next page.
+    folder: Option<drive::FileId>,
+    next_page: Option<String>,
+    pending: VecDeque<drive::File>,
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// All items in the folder `root` and its subfolders, breadth-first, each with the ID of the
+    /// folder it was found in. Folders are listed with `params`; its `q`, if set, is combined
+    /// with the parent condition (e.g. `trashed = false`), and only subfolders matching it are
+    /// walked. The selected `fields` must include `nextPageToken` and `files(id,mimeType)`.
+    ///
+    /// Folders reached more than once (with several parents) are yielded and walked only once.
+    pub fn walk_folder<'a>(
+        &'a self,
+        root: &str,
+        params: &drive::FilesListParams,
+    ) -> impl futures::Stream<Item = Result<(drive::FileId, drive::File)>> + 'a {
+        let walk = Walk {
+            params: params.clone(),
+            folders: vec![drive::FileId::from(root)].into(),
+            visited: vec![drive::FileId::from(root)].into_iter().collect(),
+            folder: None,
+            next_page: None,
+            pending: VecDeque::new(),
+        };
+        futures::stream::try_unfold(walk, move |mut walk| async move {
+            loop {
+                if let Some(file) = walk.pending.pop_front() {
+                    if file.mime_type.as_deref() == Some(FOLDER_MIME_TYPE) {
+                        match file.id.clone() {
+                            Some(id) if walk.visited.insert(id.clone()) => {
+                                walk.folders.push_back(id)
+                            }
+                            _ => continue,
+                        }
+                    }
+                    let parent = walk.folder.clone().unwrap_or_default();
+                    return Ok(Some(((parent, file), walk)));
+                }
+                // The next page of the current folder, or the first one of the next folder.
+                let token = match walk.next_page.take() {
+                    Some(token) => Some(token),
+                    None => match walk.folders.pop_front() {
+                        Some(folder) => {
+                            walk.folder = Some(folder);
+                            None
+                        }
+                        None => return Ok(None),
+                    },
+                };
+                let folder = walk.folder.clone().unwrap_or_default();
+                let mut params = walk.params.clone();
+                params.q = Some(match walk.params.q.as_ref() {
+                    Some(q) => format!("{} and ({})", Query::in_parents(&folder), q),
+                    None => Query::in_parents(&folder).to_string(),
+                });
+                params.page_token = token;
+                let list = self.list(&params).await?;
+                walk.next_page = list.next_page_token.clone();
+                walk.pending.extend(list.into_items());
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(mime_type: &str, color: Option<&str>) -> drive::File {
+        let mut f = drive::File::default();
+        if !mime_type.is_empty() {
+            f.mime_type = Some(mime_type.into());
+        }
+        f.folder_color_rgb = color.map(Into::into);
+        f
+    }
+
+    #[test]
+    fn test_validate_new_file() {
+        assert!(validate_new_file(&file(FOLDER_MIME_TYPE, Some("#ff0000")), false).is_ok());
+        assert!(validate_new_file(&file("text/plain", None), true).is_ok());
+        assert!(validate_new_file(&file("", None), true).is_ok());
+
+        assert!(validate_new_file(&file(FOLDER_MIME_TYPE, None), true).is_err());
+        assert!(validate_new_file(&file(SHORTCUT_MIME_TYPE, None), true).is_err());
+        let err = validate_new_file(&file("text/plain", Some("#ff0000")), false).unwrap_err();
+        assert!(err.to_string().contains("folderColorRgb"));
+        assert!(validate_new_file(&file("", Some("#ff0000")), false).is_err());
+    }
+
+    #[test]
+    fn test_walk_folder() {
+        use futures::TryStreamExt;
+
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        let folder = |id: &str| serde_json::json!({"id": id, "mimeType": FOLDER_MIME_TYPE});
+        let file = |id: &str| serde_json::json!({"id": id, "mimeType": "text/plain"});
+        mock.push(MockResponse::json(
+            &serde_json::json!({"files": [folder("sub"), file("a")]}),
+        ))
+        // `root` is found again below `sub`, and not walked again.
+        .push(MockResponse::json(&serde_json::json!({
+            "files": [file("b"), folder("root")],
+            "nextPageToken": "tok"
+        })))
+        .push(MockResponse::json(
+            &serde_json::json!({"files": [file("c")]}),
+        ));
+
+        let params = drive::FilesListParams::default().q("trashed = false");
+        let items: Vec<(drive::FileId, drive::File)> =
+            futures::executor::block_on(files.walk_folder("root", &params).try_collect()).unwrap();
+        let items: Vec<(String, String)> = items
+            .into_iter()
+            .map(|(parent, f)| (parent.to_string(), f.id.unwrap_or_default().to_string()))
+            .collect();
+        let pair = |p: &str, f: &str| (p.to_string(), f.to_string());
+        assert_eq!(
+            items,
+            vec![
+                pair("root", "sub"),
+                pair("root", "a"),
+                pair("sub", "b"),
+                pair("sub", "c")
+            ]
+        );
+
+        let queries: Vec<(Option<String>, Option<String>)> = mock
+            .requests()
+            .iter()
+            .map(|r| (r.query_param("q"), r.query_param("pageToken")))
+            .collect();
+        let q = |folder: &str| Some(format!("'{}' in parents and (trashed = false)", folder));
+        assert_eq!(
+            queries,
+            vec![
+                (q("root"), None),
+                (q("sub"), None),
+                (q("sub"), Some("tok".to_string()))
+            ]
+        );
+    }
+}
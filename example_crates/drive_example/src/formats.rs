@@ -0,0 +1,51 @@
+//! Lookups in the export and import format maps of `About`, e.g. to find out which formats a
+//! Google Doc can be exported to.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+/// Whether two MIME types are equal. Type and subtype are compared case-insensitively, and
+/// parameters (like `; charset=utf-8`) are ignored.
+pub fn mime_eq(a: &str, b: &str) -> bool {
+    let essence = |m: &str| m.split(';').next().unwrap_or("").trim().to_string();
+    essence(a).eq_ignore_ascii_case(&essence(b))
+}
+
+/// Look up `mime` in a map of source MIME types to target MIME types.
+fn targets<'a>(formats: &'a Option<HashMap<String, Vec<String>>>, mime: &str) -> &'a [String] {
+    let formats = match formats {
+        Some(f) => f,
+        None => return &[],
+    };
+    formats
+        .get(mime)
+        .or_else(|| {
+            formats
+                .iter()
+                .find(|(src, _)| mime_eq(src, mime))
+                .map(|(_, targets)| targets)
+        })
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+impl drive::About {
+    /// The MIME types that files of type `src_mime` can be exported to with `files.export`. Empty
+    /// if `exportFormats` wasn't requested, or if no export is possible.
+    pub fn export_targets(&self, src_mime: &str) -> &[String] {
+        targets(&self.export_formats, src_mime)
+    }
+
+    /// The Google Workspace MIME types that files of type `src_mime` can be converted to when
+    /// uploading. Empty if `importFormats` wasn't requested, or if no conversion is possible.
+    pub fn import_targets(&self, src_mime: &str) -> &[String] {
+        targets(&self.import_formats, src_mime)
+    }
+
+    /// Whether files of type `src_mime` can be exported as `dst_mime`.
+    pub fn can_export(&self, src_mime: &str, dst_mime: &str) -> bool {
+        self.export_targets(src_mime)
+            .iter()
+            .any(|t| mime_eq(t, dst_mime))
+    }
+}
@@ -0,0 +1,102 @@
+//! Importing files from other HTTP servers into Drive, without storing them locally.
+
+use crate::drive_v3_types as drive;
+use crate::folders::validate_new_file;
+use async_google_apis_common::*;
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Fetch `url` with `source`, and upload the response body as a new file into the folder
+    /// `parent` (or the root folder), streaming it through a resumable upload. Returns the new
+    /// file.
+    ///
+    /// `source` should be a client without Google credentials, e.g. a plain `TlsClient`, as the
+    /// URL may point to any server. Redirects are not followed. The source must announce the
+    /// size of the content with `Content-Length`; its `Content-Type` becomes the file's MIME
+    /// type. Without `name`, the file is named after the `filename` of a `Content-Disposition`
+    /// header, or else the last segment of the URL path.
+    pub async fn upload_from_url<H: HttpClient>(
+        &self,
+        source: &H,
+        url: &str,
+        parent: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<drive::File> {
+        let request = hyper::Request::get(url).body(hyper::Body::empty())?;
+        let response = source.send(request).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ApiError::HTTPResponseError(status, String::new())
+                .context(format!("upload_from_url: fetching {} failed", url)));
+        }
+        let headers = response.headers();
+
+        let size = headers
+            .get(hyper::header::CONTENT_LENGTH)
+            .and_then(|len| len.to_str().ok()?.parse::<usize>().ok());
+        let size = match size {
+            Some(size) => size,
+            None => {
+                return Err(ApiError::InputDataError(format!(
+                    "upload_from_url: {} sent no Content-Length",
+                    url
+                ))
+                .into())
+            }
+        };
+        let name = match name {
+            Some(name) => Some(name.to_string()),
+            None => headers
+                .get(hyper::header::CONTENT_DISPOSITION)
+                .and_then(|cd| disposition_filename(cd.to_str().ok()?))
+                .or_else(|| url_filename(url)),
+        };
+        let name = match name {
+            Some(name) => name,
+            None => {
+                return Err(ApiError::InputDataError(format!(
+                    "upload_from_url: no file name found for {}",
+                    url
+                ))
+                .into())
+            }
+        };
+
+        let mut req = drive::File::default();
+        req.name = Some(name);
+        req.parents = parent.map(|p| vec![p.to_string()]);
+        req.mime_type = headers
+            .get(hyper::header::CONTENT_TYPE)
+            .and_then(|ct| ct.to_str().ok())
+            .and_then(|ct| ct.split(';').next())
+            .map(|ct| ct.trim().to_string())
+            .filter(|ct| !ct.is_empty());
+        validate_new_file(&req, true)?;
+
+        let body = response
+            .into_body()
+            .map(|chunk| chunk.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
+        let mut params = drive::FilesCreateParams::default();
+        params.supports_all_drives = Some(true);
+        self.create_resumable_upload(&params, &req)
+            .await?
+            .upload(tokio::io::stream_reader(body), size)
+            .await
+    }
+}
+
+/// The `filename` parameter of a `Content-Disposition` header value.
+fn disposition_filename(cd: &str) -> Option<String> {
+    cd.split(';')
+        .filter_map(|param| param.trim().strip_prefix("filename="))
+        .map(|name| name.trim_matches('"').to_string())
+        .find(|name| !name.is_empty())
+}
+
+/// The last non-empty segment of the path of `url`.
+fn url_filename(url: &str) -> Option<String> {
+    let uri: hyper::Uri = url.parse().ok()?;
+    uri.path()
+        .rsplit('/')
+        .find(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
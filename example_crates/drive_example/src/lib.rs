@@ -0,0 +1,30 @@
+//! Generated Drive v3 bindings, plus hand-written helpers built on top of them.
+
+pub mod changes;
+pub mod channels;
+pub mod comments;
+pub mod compression;
+pub mod copy;
+pub mod directory;
+pub mod domain;
+pub mod events;
+pub mod drive_v3_types;
+pub mod folders;
+pub mod formats;
+pub mod import;
+pub mod links;
+pub mod listing;
+pub mod local;
+pub mod media;
+pub mod moves;
+pub mod pages;
+pub mod permissions;
+pub mod query;
+pub mod quota;
+pub mod resource_keys;
+pub mod revisions;
+pub mod shortcuts;
+pub mod snapshot;
+pub mod spaces;
+pub mod sync;
+pub mod upload;
@@ -0,0 +1,238 @@
+//! Links to files in the Drive and editor web interfaces, built from `File` metadata.
+//!
+//! The server only returns `webViewLink` and `webContentLink` if they are requested, and not
+//! for every kind of file. The functions here only need the `id`, `mimeType` and (for files
+//! shared by link) `resourceKey` fields. Links to shortcuts point to the shortcut's target if
+//! `shortcutDetails` is present; the target's resource key is not known in that case.
+//!
+//! All URL patterns used by Google's web interfaces are kept in this module.
+
+use crate::drive_v3_types as drive;
+use crate::folders::FOLDER_MIME_TYPE;
+
+const DRIVE_URL: &str = "https://drive.google.com";
+const DOCS_URL: &str = "https://docs.google.com";
+const ICON_URL: &str = "https://drive-thirdparty.googleusercontent.com";
+
+/// Path segment of the editor for a Google Workspace MIME type, e.g. `document`.
+fn editor_path(mime: &str) -> Option<&'static str> {
+    match mime {
+        "application/vnd.google-apps.document" => Some("document"),
+        "application/vnd.google-apps.spreadsheet" => Some("spreadsheets"),
+        "application/vnd.google-apps.presentation" => Some("presentation"),
+        "application/vnd.google-apps.form" => Some("forms"),
+        "application/vnd.google-apps.drawing" => Some("drawings"),
+        _ => None,
+    }
+}
+
+/// ID, MIME type and resource key of the file a link should point to.
+struct Target<'a> {
+    id: &'a str,
+    mime: &'a str,
+    resource_key: Option<&'a str>,
+}
+
+fn target(file: &drive::File) -> Option<Target<'_>> {
+    if let Some(details) = file.shortcut_details.as_ref() {
+        if let Some(id) = details.target_id.as_deref() {
+            return Some(Target {
+                id,
+                mime: details.target_mime_type.as_deref().unwrap_or_default(),
+                resource_key: None,
+            });
+        }
+    }
+    Some(Target {
+        id: file.id.as_deref()?,
+        mime: file.mime_type.as_deref().unwrap_or_default(),
+        resource_key: file.resource_key.as_deref(),
+    })
+}
+
+/// Append the `resourcekey` query parameter to `url`, if there is a key.
+fn with_resource_key(url: String, resource_key: Option<&str>) -> String {
+    match resource_key {
+        Some(key) => {
+            let sep = if url.contains('?') { '&' } else { '?' };
+            format!("{}{}resourcekey={}", url, sep, key)
+        }
+        None => url,
+    }
+}
+
+/// Link opening `file` in the browser: folders in Drive, Google Docs, Sheets, Slides, Forms and
+/// Drawings in their editor, and other files in the Drive viewer. `None` if the file has no ID.
+pub fn open_link(file: &drive::File) -> Option<String> {
+    let t = target(file)?;
+    let url = if t.mime == FOLDER_MIME_TYPE {
+        format!("{}/drive/folders/{}", DRIVE_URL, t.id)
+    } else if let Some(editor) = editor_path(t.mime) {
+        format!("{}/{}/d/{}/edit", DOCS_URL, editor, t.id)
+    } else {
+        format!("{}/file/d/{}/view", DRIVE_URL, t.id)
+    };
+    Some(with_resource_key(url, t.resource_key))
+}
+
+/// Link to a read-only preview of `file`, suitable for embedding in an `iframe`. `None` for
+/// folders and files without ID.
+pub fn preview_link(file: &drive::File) -> Option<String> {
+    let t = target(file)?;
+    if t.mime == FOLDER_MIME_TYPE {
+        return None;
+    }
+    let url = if let Some(editor) = editor_path(t.mime) {
+        format!("{}/{}/d/{}/preview", DOCS_URL, editor, t.id)
+    } else {
+        format!("{}/file/d/{}/preview", DRIVE_URL, t.id)
+    };
+    Some(with_resource_key(url, t.resource_key))
+}
+
+/// Link downloading the content of `file` directly. `None` for folders, Google Workspace
+/// documents (which have to be exported, see `exportLinks`) and other `vnd.google-apps` types
+/// without content, and for files without ID.
+pub fn download_link(file: &drive::File) -> Option<String> {
+    let t = target(file)?;
+    if t.mime.starts_with("application/vnd.google-apps.") {
+        return None;
+    }
+    let url = format!("{}/uc?id={}&export=download", DRIVE_URL, t.id);
+    Some(with_resource_key(url, t.resource_key))
+}
+
+/// Link to the icon Drive shows for the type of `file`, `size` pixels wide (e.g. 16, 32, 64 or
+/// 128). Unlike `iconLink`, which is only available in 16 pixels, this doesn't require a
+/// request. `None` if the MIME type is unknown.
+pub fn icon_link(file: &drive::File, size: u32) -> Option<String> {
+    let mime = match file.shortcut_details.as_ref() {
+        Some(details) => details.target_mime_type.as_deref(),
+        None => file.mime_type.as_deref(),
+    };
+    let mime = mime.filter(|m| !m.is_empty())?;
+    Some(format!("{}/{}/type/{}", ICON_URL, size, mime))
+}
+
+/// The color of a folder (`folderColorRgb`, like `#8f8f8f`) as red, green and blue components.
+/// `None` if no color is set or it isn't in that format.
+pub fn folder_color(file: &drive::File) -> Option<(u8, u8, u8)> {
+    let hex = file.folder_color_rgb.as_deref()?.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((component(0)?, component(2)?, component(4)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_google_apis_common::*;
+
+    fn file(value: serde_json::Value) -> drive::File {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_open_link() {
+        let folder = file(serde_json::json!({"id": "f1", "mimeType": FOLDER_MIME_TYPE}));
+        assert_eq!(
+            open_link(&folder).as_deref(),
+            Some("https://drive.google.com/drive/folders/f1")
+        );
+        let doc = file(serde_json::json!({
+            "id": "d1",
+            "mimeType": "application/vnd.google-apps.spreadsheet",
+            "resourceKey": "k"
+        }));
+        assert_eq!(
+            open_link(&doc).as_deref(),
+            Some("https://docs.google.com/spreadsheets/d/d1/edit?resourcekey=k")
+        );
+        let pdf = file(serde_json::json!({"id": "p1", "mimeType": "application/pdf"}));
+        assert_eq!(
+            open_link(&pdf).as_deref(),
+            Some("https://drive.google.com/file/d/p1/view")
+        );
+        assert_eq!(open_link(&drive::File::default()), None);
+    }
+
+    #[test]
+    fn test_shortcut_links() {
+        let shortcut = file(serde_json::json!({
+            "id": "s1",
+            "mimeType": "application/vnd.google-apps.shortcut",
+            "resourceKey": "shortcut-key",
+            "shortcutDetails": {
+                "targetId": "d1",
+                "targetMimeType": "application/vnd.google-apps.document"
+            }
+        }));
+        assert_eq!(
+            open_link(&shortcut).as_deref(),
+            Some("https://docs.google.com/document/d/d1/edit")
+        );
+        assert_eq!(
+            preview_link(&shortcut).as_deref(),
+            Some("https://docs.google.com/document/d/d1/preview")
+        );
+        assert_eq!(
+            icon_link(&shortcut, 32).as_deref(),
+            Some(
+                "https://drive-thirdparty.googleusercontent.com/32/type/\
+                 application/vnd.google-apps.document"
+            )
+        );
+    }
+
+    #[test]
+    fn test_preview_and_download_link() {
+        let folder = file(serde_json::json!({"id": "f1", "mimeType": FOLDER_MIME_TYPE}));
+        assert_eq!(preview_link(&folder), None);
+        assert_eq!(download_link(&folder), None);
+
+        let doc = file(serde_json::json!({
+            "id": "d1",
+            "mimeType": "application/vnd.google-apps.document"
+        }));
+        assert_eq!(download_link(&doc), None);
+
+        let pdf = file(serde_json::json!({
+            "id": "p1",
+            "mimeType": "application/pdf",
+            "resourceKey": "k"
+        }));
+        assert_eq!(
+            preview_link(&pdf).as_deref(),
+            Some("https://drive.google.com/file/d/p1/preview?resourcekey=k")
+        );
+        assert_eq!(
+            download_link(&pdf).as_deref(),
+            Some("https://drive.google.com/uc?id=p1&export=download&resourcekey=k")
+        );
+    }
+
+    #[test]
+    fn test_icon_link() {
+        let pdf = file(serde_json::json!({"id": "p1", "mimeType": "application/pdf"}));
+        assert_eq!(
+            icon_link(&pdf, 64).as_deref(),
+            Some("https://drive-thirdparty.googleusercontent.com/64/type/application/pdf")
+        );
+        let unknown = file(serde_json::json!({"id": "p1", "mimeType": ""}));
+        assert_eq!(icon_link(&unknown, 64), None);
+    }
+
+    #[test]
+    fn test_folder_color() {
+        let color = |rgb: &str| folder_color(&file(serde_json::json!({"folderColorRgb": rgb})));
+        assert_eq!(color("#8f8f8f"), Some((0x8f, 0x8f, 0x8f)));
+        assert_eq!(color("#FF0010"), Some((0xff, 0x00, 0x10)));
+        assert_eq!(color("8f8f8f"), None);
+        assert_eq!(color("#8f8f8"), None);
+        assert_eq!(color("#8f8f8g"), None);
+        assert_eq!(color("#8f8f8é"), None);
+        assert_eq!(folder_color(&drive::File::default()), None);
+    }
+}
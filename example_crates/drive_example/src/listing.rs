@@ -0,0 +1,727 @@
+//! Exporting large file listings for inventory and reporting.
+//!
+//! Listings are fetched page by page and every page is written out before the next one is
+//! requested, so that memory use doesn't grow with the number of files.
+//!
+//! `FilesService::list_files_incremental()` goes further and decodes files while a page is being
+//! received, so that a large page (e.g. with `fields=*`) is never held in memory as a whole.
+
+use crate::drive_v3_types as drive;
+use crate::pages::{page_stream, Page, PageStream};
+use crate::query::Query;
+use async_google_apis_common::*;
+
+use futures::TryStreamExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Output format of `FilesService::export_listing()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListingFormat {
+    /// One JSON object per line, containing the requested fields present on the file.
+    NdJson,
+    /// A header line with the field names, then one line per file. Missing fields are empty,
+    /// nested values (e.g. `owners`) are written as JSON.
+    Csv,
+}
+
+/// A key that `files.list` results can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderKey {
+    CreatedTime,
+    Folder,
+    ModifiedByMeTime,
+    ModifiedTime,
+    Name,
+    /// Like `Name`, but sorting numbers naturally (`file2` before `file10`).
+    NameNatural,
+    QuotaBytesUsed,
+    Recency,
+    SharedWithMeTime,
+    Starred,
+    ViewedByMeTime,
+}
+
+const ORDER_KEYS: &[OrderKey] = &[
+    OrderKey::CreatedTime,
+    OrderKey::Folder,
+    OrderKey::ModifiedByMeTime,
+    OrderKey::ModifiedTime,
+    OrderKey::Name,
+    OrderKey::NameNatural,
+    OrderKey::QuotaBytesUsed,
+    OrderKey::Recency,
+    OrderKey::SharedWithMeTime,
+    OrderKey::Starred,
+    OrderKey::ViewedByMeTime,
+];
+
+impl AsRef<str> for OrderKey {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrderKey::CreatedTime => "createdTime",
+            OrderKey::Folder => "folder",
+            OrderKey::ModifiedByMeTime => "modifiedByMeTime",
+            OrderKey::ModifiedTime => "modifiedTime",
+            OrderKey::Name => "name",
+            OrderKey::NameNatural => "name_natural",
+            OrderKey::QuotaBytesUsed => "quotaBytesUsed",
+            OrderKey::Recency => "recency",
+            OrderKey::SharedWithMeTime => "sharedWithMeTime",
+            OrderKey::Starred => "starred",
+            OrderKey::ViewedByMeTime => "viewedByMeTime",
+        }
+    }
+}
+
+impl std::str::FromStr for OrderKey {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> std::result::Result<OrderKey, ApiError> {
+        ORDER_KEYS
+            .iter()
+            .find(|k| k.as_ref() == s)
+            .copied()
+            .ok_or_else(|| ApiError::InputDataError(format!("unknown sort key {:?}", s)))
+    }
+}
+
+/// The sort order of a `files.list` call (`FilesListParams::order_by`), built from one or more
+/// keys:
+///
+/// ```ignore
+/// params.order_by = Some(OrderBy::new().asc(OrderKey::Folder).desc(OrderKey::ModifiedTime));
+/// ```
+///
+/// Existing values can be parsed with `"folder,modifiedTime desc".parse()`; unknown keys are
+/// rejected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrderBy {
+    keys: Vec<(OrderKey, bool)>,
+}
+
+impl OrderBy {
+    pub fn new() -> OrderBy {
+        OrderBy::default()
+    }
+    /// Sort by `key` in ascending order, after the keys added before.
+    pub fn asc(mut self, key: OrderKey) -> OrderBy {
+        self.keys.push((key, false));
+        self
+    }
+    /// Sort by `key` in descending order, after the keys added before.
+    pub fn desc(mut self, key: OrderKey) -> OrderBy {
+        self.keys.push((key, true));
+        self
+    }
+}
+
+/// Renders the `orderBy` parameter, e.g. `folder,modifiedTime desc`.
+impl std::fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (key, desc)) in self.keys.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", key.as_ref())?;
+            if *desc {
+                write!(f, " desc")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for OrderBy {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> std::result::Result<OrderBy, ApiError> {
+        let mut order = OrderBy::new();
+        for part in s.split(',').filter(|p| !p.trim().is_empty()) {
+            let mut words = part.split_whitespace();
+            let key = words.next().unwrap_or("").parse()?;
+            order = match (words.next(), words.next()) {
+                (None, _) => order.asc(key),
+                (Some("desc"), None) => order.desc(key),
+                _ => {
+                    return Err(ApiError::InputDataError(format!(
+                        "bad sort order {:?}",
+                        part.trim()
+                    )))
+                }
+            };
+        }
+        Ok(order)
+    }
+}
+
+impl Serialize for OrderBy {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderBy {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> std::result::Result<OrderBy, D::Error> {
+        String::deserialize(d)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Page sizes observed while following a listing's page tokens.
+///
+/// The server silently clamps `pageSize` to its maximum (currently 1000 for `files.list`), and
+/// may return partial pages anyway. Use the observed sizes rather than the requested one when
+/// tuning page sizes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageStats {
+    /// The requested `pageSize`, if any.
+    pub requested: Option<i32>,
+    /// Number of items in every page received, in order.
+    pub sizes: Vec<usize>,
+    /// Number of pages followed by another one.
+    continued: usize,
+    /// The size of all pages followed by another one, if they had the same size.
+    continued_size: Option<usize>,
+}
+
+impl PageStats {
+    fn new(requested: Option<i32>) -> PageStats {
+        PageStats {
+            requested,
+            sizes: vec![],
+            continued: 0,
+            continued_size: None,
+        }
+    }
+
+    fn record(&mut self, size: usize, more: bool) {
+        self.sizes.push(size);
+        if more {
+            self.continued_size = match self.continued {
+                0 => Some(size),
+                _ => self.continued_size.filter(|s| *s == size),
+            };
+            self.continued += 1;
+        }
+    }
+
+    /// The largest page received.
+    pub fn max(&self) -> Option<usize> {
+        self.sizes.iter().copied().max()
+    }
+
+    /// Total number of items received.
+    pub fn total(&self) -> usize {
+        self.sizes.iter().sum()
+    }
+
+    /// Whether the server apparently returned fewer items per page than requested: at least two
+    /// pages were followed by another page, and all of them had the same size, smaller than
+    /// `requested`. Pages of differing sizes are taken as the normal partial pages.
+    pub fn clamped(&self) -> bool {
+        match (self.requested, self.continued_size) {
+            (Some(r), Some(size)) if r > 0 && self.continued > 1 => size > 0 && size < r as usize,
+            _ => false,
+        }
+    }
+
+    /// Log a warning if the page size was clamped.
+    fn warn_if_clamped(&self) {
+        if self.clamped() {
+            warn!(
+                "requested page size {} exceeds the server's maximum; received pages of {} items",
+                self.requested.unwrap_or(0),
+                self.continued_size.unwrap_or(0)
+            );
+        }
+    }
+}
+
+/// Files decoded one by one from `files.list` responses while they are being received, following
+/// page tokens. Created by `FilesService::list_files_incremental()`.
+pub type FileStream<'a> = PageStream<'a, drive::FileList>;
+
+/// Maximum length of the percent-encoded `q` parameter sent in one request. Longer queries would
+/// exceed the URL length accepted by the API, and are split by `split_query()`.
+pub const MAX_QUERY_LEN: usize = 6000;
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Fetch all files matching `params`, following page tokens.
+    ///
+    /// If `params.q` is too long for a single request, it is split into several queries (see
+    /// `split_query()`) whose results are merged, without duplicates.
+    pub async fn list_all(&self, params: &drive::FilesListParams) -> Result<Vec<drive::File>> {
+        Ok(self.list_all_with_stats(params).await?.0)
+    }
+
+    /// Like `list_all()`, but also returning the sizes of the pages received. A warning is logged
+    /// if the server apparently clamped `params.page_size` (see `PageStats::clamped()`).
+    pub async fn list_all_with_stats(
+        &self,
+        params: &drive::FilesListParams,
+    ) -> Result<(Vec<drive::File>, PageStats)> {
+        self.list_all_until(params, None).await
+    }
+
+    /// Like `list_all()`, but stops before requesting the next page once `cancel` is cancelled,
+    /// failing with `ApiError::CancelledError` and the number of files received.
+    pub async fn list_all_cancellable(
+        &self,
+        params: &drive::FilesListParams,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<drive::File>> {
+        Ok(self.list_all_until(params, Some(cancel)).await?.0)
+    }
+
+    async fn list_all_until(
+        &self,
+        params: &drive::FilesListParams,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<(Vec<drive::File>, PageStats)> {
+        let mut files = vec![];
+        let mut seen = std::collections::HashSet::new();
+        let mut stats = PageStats::new(params.page_size);
+        for params in split_params(params)? {
+            // Pages are requested when polling `pages`; check for cancellation before each.
+            let mut pages = Box::pin(self.list_stream(&params));
+            let mut more = true;
+            while more {
+                if let Some(cancel) = cancel {
+                    cancel.check(files.len() as u64)?;
+                }
+                let list = match pages.try_next().await? {
+                    Some(list) => list,
+                    None => break,
+                };
+                more = list.next_page_token.is_some();
+                stats.record(list.files.as_ref().map_or(0, Vec::len), more);
+                for file in list.into_items() {
+                    match file.id.as_ref() {
+                        Some(id) if !seen.insert(id.clone()) => continue,
+                        _ => files.push(file),
+                    }
+                }
+            }
+        }
+        stats.warn_if_clamped();
+        Ok((files, stats))
+    }
+
+    /// Fetch the pages of the listing described by `params` one after another, following page
+    /// tokens. Each page is only requested once the previous one has been consumed.
+    pub fn list_stream<'a>(
+        &'a self,
+        params: &drive::FilesListParams,
+    ) -> impl futures::Stream<Item = Result<drive::FileList>> + 'a {
+        let params = params.clone();
+        page_stream(params.page_token.clone(), move |token| {
+            let mut params = params.clone();
+            params.page_token = token;
+            async move { self.list(&params).await }
+        })
+    }
+
+    /// Like `list_stream()`, but yielding the individual files.
+    pub fn list_files_stream<'a>(
+        &'a self,
+        params: &drive::FilesListParams,
+    ) -> impl futures::Stream<Item = Result<drive::File>> + 'a {
+        self.list_stream(params)
+            .map_ok(|list| {
+                futures::stream::iter(list.files.unwrap_or_default().into_iter().map(Ok))
+            })
+            .try_flatten()
+    }
+
+    /// Like `list_files_stream()`, but decodes the files of each page while it is being received
+    /// and returns them one by one, instead of waiting for the complete page. Use this for large
+    /// pages, e.g. with `fields=*`.
+    pub fn list_files_incremental(&self, params: &drive::FilesListParams) -> FileStream<'_> {
+        let params = params.clone();
+        PageStream::new("files", params.page_token.clone(), move |token| {
+            let mut params = params.clone();
+            params.page_token = token;
+            Box::pin(async move {
+                let mut query = form_urlencoded::Serializer::new(String::new());
+                params.append_to(&mut query);
+                if let Some(ref api_params) = params.drive_params {
+                    api_params.append_to(&mut query);
+                }
+                let scopes = [drive::scopes::DRIVE_READONLY];
+                self.request_raw::<EmptyRequest>("GET", "files", &query.finish(), &scopes, None)
+                    .await
+            })
+        })
+    }
+
+    /// Run `process` on all files matching `params`, at most `concurrency` at a time, and return
+    /// the number of files processed.
+    ///
+    /// Listed files are passed to the processors through a queue of `buffer` files. Once it is
+    /// full, no further pages are requested until processing catches up, so at most `buffer` +
+    /// `concurrency` files and one page are held in memory. The first error, of listing or
+    /// processing, stops both.
+    pub async fn process_files<F, Fut>(
+        &self,
+        params: &drive::FilesListParams,
+        buffer: usize,
+        concurrency: usize,
+        process: F,
+    ) -> Result<usize>
+    where
+        F: Fn(drive::File) -> Fut,
+        Fut: std::future::Future<Output = Result<()>>,
+    {
+        use futures::{SinkExt, StreamExt};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(buffer);
+        let list = async move {
+            let mut files = Box::pin(self.list_files_stream(params));
+            while let Some(file) = files.try_next().await? {
+                // The receiver is only dropped if processing has failed.
+                if tx.send(file).await.is_err() {
+                    break;
+                }
+            }
+            Ok::<(), Error>(())
+        };
+        let processed = AtomicUsize::new(0);
+        let n = &processed;
+        let work = rx
+            .map(Ok)
+            .try_for_each_concurrent(concurrency.max(1), move |file| {
+                let done = process(file);
+                async move {
+                    done.await?;
+                    n.fetch_add(1, Ordering::Relaxed);
+                    Ok::<(), Error>(())
+                }
+            });
+        futures::future::try_join(list, work).await?;
+        Ok(processed.into_inner())
+    }
+
+    /// Write all files matching `params` to `dst`, and return the number of files written.
+    ///
+    /// `fields` are the `File` fields to export, named as in the API (e.g. `["id", "name",
+    /// "modifiedTime", "owners(emailAddress)"]`); only these fields are requested from the API.
+    /// `params.page_token` is used as starting point, if set. Oversized queries are split like in
+    /// `list_all()`.
+    pub async fn export_listing<W: AsyncWrite + std::marker::Unpin>(
+        &self,
+        params: &drive::FilesListParams,
+        fields: &[&str],
+        format: ListingFormat,
+        dst: &mut W,
+    ) -> Result<usize> {
+        self.export_listing_until(params, fields, format, dst, None)
+            .await
+    }
+
+    /// Like `export_listing()`, but stops before requesting the next page once `cancel` is
+    /// cancelled, failing with `ApiError::CancelledError` and the number of files written. `dst`
+    /// is flushed before returning, so that it contains complete lines.
+    pub async fn export_listing_cancellable<W: AsyncWrite + std::marker::Unpin>(
+        &self,
+        params: &drive::FilesListParams,
+        fields: &[&str],
+        format: ListingFormat,
+        dst: &mut W,
+        cancel: &CancellationToken,
+    ) -> Result<usize> {
+        self.export_listing_until(params, fields, format, dst, Some(cancel))
+            .await
+    }
+
+    async fn export_listing_until<W: AsyncWrite + std::marker::Unpin>(
+        &self,
+        params: &drive::FilesListParams,
+        fields: &[&str],
+        format: ListingFormat,
+        dst: &mut W,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<usize> {
+        let mut params = params.clone();
+        let mut general_params = params.drive_params.take().unwrap_or_default();
+        // The ID is needed to remove duplicates from split queries, but must not be selected twice.
+        let mut selection = fields.to_vec();
+        if !selection.contains(&"id") {
+            selection.insert(0, "id");
+        }
+        general_params.fields = Some(format!("nextPageToken,files({})", selection.join(",")));
+        params.drive_params = Some(general_params);
+        if params.page_size.is_none() {
+            params.page_size = Some(1000);
+        }
+        // Column names are the top-level field names, without sub-selections.
+        let columns: Vec<&str> = fields
+            .iter()
+            .map(|f| f.split('(').next().unwrap_or(f).trim())
+            .collect();
+
+        if format == ListingFormat::Csv {
+            let header: Vec<String> = columns.iter().map(|c| csv_escape(c)).collect();
+            dst.write_all(format!("{}\n", header.join(",")).as_bytes())
+                .await?;
+        }
+
+        let split = split_params(&params)?;
+        let dedup = split.len() > 1;
+        let mut seen = std::collections::HashSet::new();
+        let mut n = 0;
+        let mut stats = PageStats::new(params.page_size);
+        for params in split {
+            let mut pages = Box::pin(self.list_stream(&params));
+            let mut more = true;
+            while more {
+                if let Some(cancel) = cancel.filter(|c| c.is_cancelled()) {
+                    dst.flush().await?;
+                    cancel.check(n as u64)?;
+                }
+                let list = match pages.try_next().await? {
+                    Some(list) => list,
+                    None => break,
+                };
+                more = list.next_page_token.is_some();
+                stats.record(list.files.as_ref().map_or(0, Vec::len), more);
+                for file in list.into_items() {
+                    if dedup && !seen.insert(file.id.clone().unwrap_or_default()) {
+                        continue;
+                    }
+                    let record = serde_json::to_value(&file)?;
+                    let line = match format {
+                        ListingFormat::NdJson => ndjson_line(&record, &columns)?,
+                        ListingFormat::Csv => csv_line(&record, &columns),
+                    };
+                    dst.write_all(line.as_bytes()).await?;
+                    n += 1;
+                }
+            }
+        }
+        stats.warn_if_clamped();
+        dst.flush().await?;
+        Ok(n)
+    }
+}
+
+/// One set of parameters per query returned by `split_query()`.
+fn split_params(params: &drive::FilesListParams) -> Result<Vec<drive::FilesListParams>> {
+    let q = match params.q.as_ref() {
+        Some(q) if encoded_len(q) > MAX_QUERY_LEN => q,
+        _ => return Ok(vec![params.clone()]),
+    };
+    let queries = split_query(q, MAX_QUERY_LEN).ok_or_else(|| {
+        ApiError::InputDataError(format!(
+            "query of {} characters is too long and can't be split",
+            q.len()
+        ))
+    })?;
+    debug!("split_params: split query into {} queries", queries.len());
+    Ok(queries
+        .into_iter()
+        .map(|q| {
+            let mut p = params.clone();
+            p.q = Some(q);
+            p
+        })
+        .collect())
+}
+
+fn encoded_len(q: &str) -> usize {
+    form_urlencoded::byte_serialize(q.as_bytes())
+        .map(str::len)
+        .sum()
+}
+
+/// Split the query `q` into several queries no longer than `max_len` (when percent-encoded),
+/// whose combined results are the results of `q`. This works for queries consisting of a
+/// disjunction, optionally combined with other conditions, e.g. `('a' in parents or 'b' in
+/// parents or ...) and trashed = false`: the longest disjunction is split up into several
+/// queries, each with the other conditions. Returns `None` if `q` can't be parsed (see
+/// `Query::parse()`), has no such structure or can't be split small enough.
+pub fn split_query(q: &str, max_len: usize) -> Option<Vec<String>> {
+    let mut conjuncts = match Query::parse(q).ok()? {
+        Query::And(qs) => qs,
+        q => vec![q],
+    };
+    // Index of the conjunct with the most disjunctive clauses.
+    let (i, _) = conjuncts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| match c {
+            Query::Or(clauses) => Some((i, clauses.len())),
+            _ => None,
+        })
+        .max_by_key(|(_, n)| *n)?;
+    let clauses = match conjuncts.remove(i) {
+        Query::Or(clauses) => clauses,
+        _ => unreachable!(),
+    };
+    let assemble = |chunk: &[Query]| -> String {
+        conjuncts
+            .iter()
+            .cloned()
+            .fold(Query::any(chunk.to_vec()), Query::and)
+            .to_string()
+    };
+
+    let mut queries = vec![];
+    let mut chunk: Vec<Query> = vec![];
+    for clause in clauses {
+        chunk.push(clause);
+        if encoded_len(&assemble(&chunk)) > max_len {
+            let clause = chunk.pop().unwrap();
+            if chunk.is_empty() {
+                return None;
+            }
+            queries.push(assemble(&chunk));
+            chunk = vec![clause];
+        }
+    }
+    let last = assemble(&chunk);
+    if encoded_len(&last) > max_len {
+        return None;
+    }
+    queries.push(last);
+    Some(queries)
+}
+
+fn ndjson_line(record: &serde_json::Value, columns: &[&str]) -> Result<String> {
+    let mut obj = serde_json::Map::new();
+    for c in columns {
+        if let Some(v) = record.get(c) {
+            obj.insert(c.to_string(), v.clone());
+        }
+    }
+    Ok(serde_json::to_string(&obj)? + "\n")
+}
+
+fn csv_line(record: &serde_json::Value, columns: &[&str]) -> String {
+    let cells: Vec<String> = columns
+        .iter()
+        .map(|c| match record.get(c) {
+            None | Some(serde_json::Value::Null) => String::new(),
+            Some(serde_json::Value::String(s)) => csv_escape(s),
+            Some(v) => csv_escape(&v.to_string()),
+        })
+        .collect();
+    cells.join(",") + "\n"
+}
+
+/// Quote a CSV cell if necessary, as described in RFC 4180.
+fn csv_escape(s: &str) -> String {
+    if s.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_stats() {
+        let mut stats = PageStats::new(Some(1000));
+        stats.record(100, true);
+        stats.record(100, true);
+        stats.record(30, false);
+        assert_eq!(stats.sizes, vec![100, 100, 30]);
+        assert_eq!(stats.max(), Some(100));
+        assert_eq!(stats.total(), 230);
+        assert!(stats.clamped());
+
+        // Full pages, and a short last page, don't indicate clamping.
+        let mut stats = PageStats::new(Some(100));
+        stats.record(100, true);
+        stats.record(30, false);
+        assert!(!stats.clamped());
+        let mut stats = PageStats::new(Some(1000));
+        stats.record(30, false);
+        assert!(!stats.clamped());
+
+        // Neither do partial pages of differing sizes, a single partial page, or empty pages.
+        let mut stats = PageStats::new(Some(1000));
+        stats.record(100, true);
+        stats.record(80, true);
+        stats.record(100, true);
+        stats.record(30, false);
+        assert!(!stats.clamped());
+        let mut stats = PageStats::new(Some(1000));
+        stats.record(100, true);
+        stats.record(30, false);
+        assert!(!stats.clamped());
+        let mut stats = PageStats::new(Some(1000));
+        stats.record(0, true);
+        stats.record(0, true);
+        stats.record(30, false);
+        assert!(!stats.clamped());
+
+        // Without a requested size, nothing can be clamped.
+        let mut stats = PageStats::new(None);
+        stats.record(100, true);
+        stats.record(100, false);
+        assert!(!stats.clamped());
+        assert_eq!(PageStats::new(Some(0)).max(), None);
+        assert_eq!(PageStats::new(Some(0)).total(), 0);
+    }
+
+    #[test]
+    fn test_order_by() {
+        let order = OrderBy::new()
+            .asc(OrderKey::Folder)
+            .desc(OrderKey::ModifiedTime)
+            .asc(OrderKey::NameNatural);
+        assert_eq!(order.to_string(), "folder,modifiedTime desc,name_natural");
+        assert_eq!(order.to_string().parse::<OrderBy>().unwrap(), order);
+        assert_eq!(
+            " folder , modifiedTime  desc,name_natural"
+                .parse::<OrderBy>()
+                .unwrap(),
+            order
+        );
+        assert_eq!("".parse::<OrderBy>().unwrap(), OrderBy::new());
+        assert!("modifedTime desc".parse::<OrderBy>().is_err());
+        assert!("name asc".parse::<OrderBy>().is_err());
+
+        let params = drive::FilesListParams::default().order_by(order.clone());
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["orderBy"], "folder,modifiedTime desc,name_natural");
+        let back: drive::FilesListParams = serde_json::from_value(json).unwrap();
+        assert_eq!(back.order_by, Some(order));
+    }
+
+    #[test]
+    fn test_split_query() {
+        let q = "('a' in parents OR 'b' in parents or 'c' in parents) and trashed = false";
+        // Short enough as is.
+        assert_eq!(
+            split_query(q, 1000).unwrap(),
+            vec!["('a' in parents or 'b' in parents or 'c' in parents) and trashed = false"]
+        );
+        let max = encoded_len("('a' in parents or 'b' in parents) and trashed = false");
+        assert_eq!(
+            split_query(q, max).unwrap(),
+            vec![
+                "('a' in parents or 'b' in parents) and trashed = false",
+                "'c' in parents and trashed = false",
+            ]
+        );
+        // A single clause doesn't fit.
+        assert_eq!(split_query(q, 10), None);
+
+        // Quoted keywords and parentheses don't split.
+        let q = "name = 'x or y' or name = '(z)'";
+        assert_eq!(
+            split_query(q, encoded_len("name = '(z)'")).unwrap(),
+            vec!["name = 'x or y'", "name = '(z)'"]
+        );
+
+        // Nothing to split up, or ambiguous.
+        assert_eq!(split_query("name = 'x' and trashed = false", 10), None);
+        assert_eq!(split_query("a or b and c", 1), None);
+    }
+}
@@ -0,0 +1,91 @@
+//! Transferring files between Drive and the local file system, optionally preserving
+//! modification times for faithful backups and restores.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+use std::path::Path;
+
+/// The modification time of the local file `path`.
+pub async fn local_modified_time(path: &Path) -> Result<DateTime<Utc>> {
+    let modified = tokio::fs::metadata(path).await?.modified()?;
+    Ok(DateTime::<Utc>::from(modified))
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Download the content of `file_id` into the local file `path`, which is created or
+    /// truncated. If `preserve_mtime` is set, the local file's modification time is set to the
+    /// file's `modifiedTime`. Returns the file's metadata (ID, name, MIME type, modification
+    /// time and size).
+    pub async fn download_to_path(
+        &self,
+        file_id: &str,
+        path: &Path,
+        preserve_mtime: bool,
+    ) -> Result<drive::File> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("id,name,mimeType,modifiedTime,size".into());
+        let mut params = drive::FilesGetParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        params.supports_all_drives = Some(true);
+        let file = match self.get(&params).await?.do_it(None).await? {
+            DownloadResult::Response(f) => f,
+            DownloadResult::Downloaded => {
+                return Err(ApiError::InputDataError(format!(
+                    "download_to_path: no metadata received for {}",
+                    file_id
+                ))
+                .into())
+            }
+        };
+
+        let mut dst = tokio::fs::File::create(path).await?;
+        self.get_media(file_id, &mut dst).await?;
+        if preserve_mtime {
+            if let Some(modified) = file.modified_time {
+                dst.into_std()
+                    .await
+                    .set_modified(std::time::SystemTime::from(modified))?;
+            }
+        }
+        Ok(file)
+    }
+
+    /// Upload the local file `path` as a new file named like it, into the folder `parent` (or
+    /// the root folder). If `preserve_mtime` is set, the new file's `modifiedTime` is set to
+    /// the local file's modification time. `content_type` is sent as the MIME type of the
+    /// content, e.g. `mime_type_for_extension(path)`; if it is `None`, Drive detects it.
+    pub async fn upload_from_path(
+        &self,
+        path: &Path,
+        parent: Option<&str>,
+        preserve_mtime: bool,
+        content_type: Option<&str>,
+    ) -> Result<drive::File> {
+        let name = match path.file_name().and_then(|n| n.to_str()) {
+            Some(name) => name.to_string(),
+            None => {
+                return Err(ApiError::InputDataError(format!(
+                    "upload_from_path: invalid file name {}",
+                    path.display()
+                ))
+                .into())
+            }
+        };
+        let mut req = drive::File::default();
+        req.name = Some(name);
+        req.parents = parent.map(|p| vec![p.to_string()]);
+        if preserve_mtime {
+            req.modified_time = Some(local_modified_time(path).await?);
+        }
+
+        let mut params = drive::FilesCreateParams::default();
+        params.supports_all_drives = Some(true);
+        let src = tokio::fs::File::open(path).await?;
+        self.create_resumable_upload_with_type(&params, &req, content_type)
+            .await?
+            .upload_file(src)
+            .await
+    }
+}
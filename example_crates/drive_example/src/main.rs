@@ -6,15 +6,14 @@
 //! When you specify a file name as command line argument, the given file is uploaded to your
 //! Google Drive.
 
-mod drive_v3_types;
-use drive_v3_types as drive;
+use drive_example::drive_v3_types as drive;
 
 use env_logger;
 
 use async_google_apis_common as common;
 
 use std::path::Path;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Create a new HTTPS client.
 fn https_client() -> common::TlsClient {
@@ -24,7 +23,7 @@ fn https_client() -> common::TlsClient {
 }
 
 /// Upload a local file `f` to your drive.
-async fn upload_file(mut cl: drive::FilesService, f: &Path) -> anyhow::Result<()> {
+async fn upload_file(cl: drive::FilesService, f: &Path) -> anyhow::Result<()> {
     let fname = f.file_name().unwrap().to_str().unwrap();
 
     let mut general_params = drive::DriveParams::default();
@@ -32,7 +31,8 @@ async fn upload_file(mut cl: drive::FilesService, f: &Path) -> anyhow::Result<()
 
     let mut params = drive::FilesCreateParams::default();
     params.drive_params = Some(general_params.clone());
-    params.include_permissions_for_view = Some("published".to_string());
+    params.include_permissions_for_view =
+        Some(drive::FilesCreateParamsIncludePermissionsForView::Published);
     let mut file = drive::File::default();
     file.name = Some(fname.to_string());
 
@@ -114,8 +114,8 @@ async fn main() {
     .await
     .expect("InstalledFlowAuthenticator failed to build");
 
-    let scopes = vec![drive::DriveScopes::Drive];
-    let mut cl = drive::FilesService::new(https, Rc::new(auth));
+    let scopes = vec![drive::Scope::Drive];
+    let mut cl = drive::FilesService::new(https, Arc::new(auth));
     cl.set_scopes(&scopes);
 
     let arg = std::env::args().skip(1).next();
@@ -0,0 +1,101 @@
+//! Moving and renaming files.
+//!
+//! Drive has no atomic "move if still in folder X": `move_file()` checks the current parents
+//! first and verifies the result, so that concurrent moves by others are detected instead of
+//! leaving a file in unexpected folders.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+/// Fields of the `File` returned by `move_file()` and `rename()`.
+const MOVED_FILE_FIELDS: &str = "id,name,mimeType,parents,modifiedTime";
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Move `file_id` from the folder `from_parent` into the folder `to_parent`, and return the
+    /// updated file. Other parents of the file (for items in several folders) are kept.
+    ///
+    /// Fails with an `ApiError::InputDataError` if the file is not in `from_parent` (e.g. because
+    /// someone else has moved it meanwhile), and if, after the move, it is not in `to_parent` or
+    /// still in `from_parent`.
+    pub async fn move_file(
+        &self,
+        file_id: &str,
+        from_parent: &str,
+        to_parent: &str,
+    ) -> Result<drive::File> {
+        if to_parent == file_id {
+            return Err(ApiError::InputDataError(format!(
+                "move_file: can't move {} into itself",
+                file_id
+            ))
+            .into());
+        }
+        let file = self.get_parents(file_id).await?;
+        let parents = file.parents.as_deref().unwrap_or_default();
+        if !parents.iter().any(|p| p == from_parent) {
+            return Err(ApiError::InputDataError(format!(
+                "move_file: {} is not in folder {}, but in {:?}",
+                file_id, from_parent, parents
+            ))
+            .into());
+        }
+        if from_parent == to_parent {
+            return Ok(file);
+        }
+
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(MOVED_FILE_FIELDS.into());
+        let mut params = drive::FilesUpdateParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        params.add_parents = Some(to_parent.into());
+        params.remove_parents = Some(from_parent.into());
+        params.supports_all_drives = Some(true);
+        let moved = self.update(&params, &drive::File::default()).await?;
+
+        let parents = moved.parents.as_deref().unwrap_or_default();
+        if !parents.iter().any(|p| p == to_parent) || parents.iter().any(|p| p == from_parent) {
+            return Err(ApiError::InputDataError(format!(
+                "move_file: {} was not moved from {} to {}; its parents are {:?}",
+                file_id, from_parent, to_parent, parents
+            ))
+            .into());
+        }
+        Ok(moved)
+    }
+
+    /// Rename `file_id` to `new_name`, and return the updated file.
+    pub async fn rename(&self, file_id: &str, new_name: &str) -> Result<drive::File> {
+        if new_name.trim().is_empty() {
+            return Err(
+                ApiError::InputDataError(format!("rename: empty name for {}", file_id)).into(),
+            );
+        }
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(MOVED_FILE_FIELDS.into());
+        let mut params = drive::FilesUpdateParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        params.supports_all_drives = Some(true);
+        let mut req = drive::File::default();
+        req.name = Some(new_name.into());
+        self.update(&params, &req).await
+    }
+
+    async fn get_parents(&self, file_id: &str) -> Result<drive::File> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(MOVED_FILE_FIELDS.into());
+        let mut params = drive::FilesGetParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        params.supports_all_drives = Some(true);
+        match self.get(&params).await?.do_it(None).await? {
+            DownloadResult::Response(f) => Ok(f),
+            DownloadResult::Downloaded => Err(ApiError::InputDataError(format!(
+                "move_file: no metadata received for {}",
+                file_id
+            ))
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Following the page tokens of list methods.
+//!
+//! Every `*List` response (`FileList`, `PermissionList`, ...) implements `Page`, so that the
+//! pages of any listing can be fetched with `page_stream()` or `list_all()`, given a closure
+//! requesting the page for a page token:
+//!
+//! ```ignore
+//! let permissions = list_all(None, |token| {
+//!     let mut params = params.clone();
+//!     params.page_token = token;
+//!     async move { service.list(&params).await }
+//! })
+//! .await?;
+//! ```
+//!
+//! `PageStream` instead decodes the items of each page while it is being received, for listings
+//! with large pages.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+use futures::future::BoxFuture;
+use futures::TryStreamExt;
+use std::collections::VecDeque;
+use std::future::Future;
+
+/// A page of results of a list method.
+pub trait Page {
+    type Item;
+
+    /// The token for requesting the next page, or `None` on the last page.
+    fn next_page_token(&self) -> Option<&str>;
+    /// The items on this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+macro_rules! impl_page {
+    ($($list:ty => $field:ident: $item:ty),*) => {
+        $(
+        impl Page for $list {
+            type Item = $item;
+
+            fn next_page_token(&self) -> Option<&str> {
+                self.next_page_token.as_deref()
+            }
+            fn into_items(self) -> Vec<$item> {
+                self.$field.unwrap_or_default()
+            }
+        }
+        )*
+    };
+}
+
+impl_page!(
+    drive::ChangeList => changes: drive::Change,
+    drive::CommentList => comments: drive::Comment,
+    drive::DriveList => drives: drive::Drive,
+    drive::FileList => files: drive::File,
+    drive::PermissionList => permissions: drive::Permission,
+    drive::ReplyList => replies: drive::Reply,
+    drive::RevisionList => revisions: drive::Revision,
+    drive::TeamDriveList => team_drives: drive::TeamDrive
+);
+
+/// The pages of a listing, one after another. `fetch` requests the page for a page token,
+/// starting with `first` (usually `None`), and is called again with the next page token until a
+/// page has none. Each page is only requested once the previous one has been consumed.
+pub fn page_stream<L, F, Fut>(
+    first: Option<String>,
+    fetch: F,
+) -> impl futures::Stream<Item = Result<L>>
+where
+    L: Page,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<L>>,
+{
+    futures::stream::try_unfold((fetch, Some(first)), |(mut fetch, token)| async move {
+        let token = match token {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+        let page = fetch(token).await?;
+        let next = page.next_page_token().map(|t| Some(t.to_string()));
+        Ok(Some((page, (fetch, next))))
+    })
+}
+
+/// The items of all pages of a listing; see `page_stream()`.
+pub async fn list_all<L, F, Fut>(first: Option<String>, fetch: F) -> Result<Vec<L::Item>>
+where
+    L: Page,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<L>>,
+{
+    let mut items = vec![];
+    let mut pages = Box::pin(page_stream(first, fetch));
+    while let Some(page) = pages.try_next().await? {
+        items.extend(page.into_items());
+    }
+    Ok(items)
+}
+
+type RequestFn<'a> =
+    Box<dyn Fn(Option<String>) -> BoxFuture<'a, Result<hyper::Body>> + Send + Sync + 'a>;
+
+/// Items decoded one by one from list responses while they are being received, following page
+/// tokens. `L` is the page type, whose items are in the array field given to `new()`.
+pub struct PageStream<'a, L: Page> {
+    field: &'static str,
+    request: RequestFn<'a>,
+    /// The token of the next page to request; `None` once the last page has been requested.
+    next_page: Option<Option<String>>,
+    response: Option<(hyper::Body, JsonArrayDecoder)>,
+    pending: VecDeque<L::Item>,
+    last_page: Option<L>,
+}
+
+impl<'a, L> PageStream<'a, L>
+where
+    L: Page + DeserializeOwned,
+    L::Item: DeserializeOwned,
+{
+    /// A stream over the items in the array `field` of the pages returned by `request`, which
+    /// sends the request for a page token and returns the raw response body, starting with
+    /// `first`.
+    pub fn new<F>(field: &'static str, first: Option<String>, request: F) -> PageStream<'a, L>
+    where
+        F: Fn(Option<String>) -> BoxFuture<'a, Result<hyper::Body>> + Send + Sync + 'a,
+    {
+        PageStream {
+            field,
+            request: Box::new(request),
+            next_page: Some(first),
+            response: None,
+            pending: VecDeque::new(),
+            last_page: None,
+        }
+    }
+
+    /// The next item, or `None` after the last one.
+    pub async fn next(&mut self) -> Result<Option<L::Item>> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Ok(Some(item));
+            }
+            let (body, decoder) = match self.response.as_mut() {
+                Some(r) => r,
+                None => match self.next_page.take() {
+                    None => return Ok(None),
+                    Some(token) => {
+                        let body = (self.request)(token).await?;
+                        self.response
+                            .get_or_insert((body, JsonArrayDecoder::new(self.field)))
+                    }
+                },
+            };
+            match StreamExt::next(body).await {
+                Some(chunk) => self.pending.extend(decoder.feed::<L::Item>(&chunk?)?),
+                None => {
+                    let (_, decoder) = self.response.take().unwrap();
+                    let page: L = decoder.finish()?;
+                    self.next_page = page.next_page_token().map(|t| Some(t.to_string()));
+                    self.last_page = Some(page);
+                }
+            }
+        }
+    }
+
+    /// The most recently received page, without its items (e.g. for fields only set on the last
+    /// page, once `next()` has returned `None`).
+    pub fn last_page(&self) -> Option<&L> {
+        self.last_page.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_all() {
+        let mock = MockClient::new();
+        let permissions = drive::PermissionsService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(MockResponse::json(&serde_json::json!({
+            "permissions": [{"id": "p1"}, {"id": "p2"}],
+            "nextPageToken": "tok"
+        })))
+        .push(MockResponse::json(
+            &serde_json::json!({"permissions": [{"id": "p3"}]}),
+        ));
+
+        let mut params = drive::PermissionsListParams::default();
+        params.file_id = "f".into();
+        let all = futures::executor::block_on(list_all(None, |token| {
+            let mut params = params.clone();
+            params.page_token = token;
+            let permissions = &permissions;
+            async move { permissions.list(&params).await }
+        }))
+        .unwrap();
+        let ids: Vec<String> = all
+            .into_iter()
+            .filter_map(|p| p.id)
+            .map(|id| id.to_string())
+            .collect();
+        assert_eq!(ids, vec!["p1", "p2", "p3"]);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query_param("pageToken"), None);
+        assert_eq!(requests[1].query_param("pageToken").as_deref(), Some("tok"));
+    }
+
+    #[test]
+    fn test_page_stream() {
+        let mock = MockClient::new();
+        let changes = drive::ChangesService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(MockResponse::json(&serde_json::json!({
+            "changes": [{"fileId": "a"}, {"fileId": "b"}],
+            "nextPageToken": "2"
+        })))
+        .push(MockResponse::json(&serde_json::json!({
+            "changes": [{"fileId": "c"}],
+            "newStartPageToken": "3"
+        })));
+
+        let mut params = drive::ChangesListParams::default();
+        params.page_token = "1".into();
+        let mut stream = changes.stream(&params);
+        let ids = futures::executor::block_on(async {
+            let mut ids = vec![];
+            while let Some(change) = stream.next().await.unwrap() {
+                ids.push(change.file_id.unwrap().to_string());
+            }
+            ids
+        });
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert_eq!(stream.new_start_page_token(), Some("3"));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query_param("pageToken").as_deref(), Some("1"));
+        assert_eq!(requests[1].query_param("pageToken").as_deref(), Some("2"));
+    }
+}
@@ -0,0 +1,883 @@
+//! Helpers for managing the permissions of files and shared drives.
+
+use crate::drive_v3_types as drive;
+use crate::pages;
+use async_google_apis_common::*;
+
+/// A grantee, identified the same way the Drive API does: by type (`user`, `group`, `domain`,
+/// `anyone`) and, depending on the type, an email address or domain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grantee {
+    pub typ: drive::PermissionType,
+    pub email_address: Option<String>,
+    pub domain: Option<String>,
+}
+
+impl Grantee {
+    fn of(p: &drive::Permission) -> Grantee {
+        Grantee {
+            typ: p.typ.clone().unwrap_or_default(),
+            email_address: p.email_address.as_ref().map(|e| e.to_lowercase()),
+            domain: p.domain.as_ref().map(|d| d.to_lowercase()),
+        }
+    }
+}
+
+/// One entry of a `PermissionTemplate`: `grantee` should have `role`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionEntry {
+    pub grantee: Grantee,
+    pub role: drive::PermissionRole,
+}
+
+impl PermissionEntry {
+    pub fn user<R: Into<drive::PermissionRole>>(email: &str, role: R) -> PermissionEntry {
+        PermissionEntry::new(drive::PermissionType::User, Some(email), None, role.into())
+    }
+    pub fn group<R: Into<drive::PermissionRole>>(email: &str, role: R) -> PermissionEntry {
+        PermissionEntry::new(drive::PermissionType::Group, Some(email), None, role.into())
+    }
+    pub fn domain<R: Into<drive::PermissionRole>>(domain: &str, role: R) -> PermissionEntry {
+        PermissionEntry::new(
+            drive::PermissionType::Domain,
+            None,
+            Some(domain),
+            role.into(),
+        )
+    }
+    pub fn anyone<R: Into<drive::PermissionRole>>(role: R) -> PermissionEntry {
+        PermissionEntry::new(drive::PermissionType::Anyone, None, None, role.into())
+    }
+
+    fn new(
+        typ: drive::PermissionType,
+        email: Option<&str>,
+        domain: Option<&str>,
+        role: drive::PermissionRole,
+    ) -> PermissionEntry {
+        PermissionEntry {
+            grantee: Grantee {
+                typ,
+                email_address: email.map(str::to_lowercase),
+                domain: domain.map(str::to_lowercase),
+            },
+            role,
+        }
+    }
+
+    fn to_permission(&self) -> drive::Permission {
+        let mut p = drive::Permission::default();
+        p.typ = Some(self.grantee.typ.clone());
+        p.email_address = self.grantee.email_address.clone();
+        p.domain = self.grantee.domain.clone();
+        p.role = Some(self.role.clone());
+        p
+    }
+}
+
+/// A named set of permissions that a file or shared drive is supposed to have. Use
+/// `PermissionsService::apply_template()` to enforce it.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionTemplate {
+    pub name: String,
+    pub entries: Vec<PermissionEntry>,
+}
+
+/// The changes necessary to make a set of existing permissions match a template.
+#[derive(Debug, Clone, Default)]
+pub struct ReconcilePlan {
+    /// Entries without a matching permission.
+    pub add: Vec<PermissionEntry>,
+    /// Existing permissions (by ID) whose role differs from the template.
+    pub update: Vec<(drive::PermissionId, PermissionEntry)>,
+    /// IDs of existing permissions not covered by the template.
+    pub remove: Vec<drive::PermissionId>,
+}
+
+impl ReconcilePlan {
+    /// Compare `existing` permissions with `template`. Owner permissions and permissions
+    /// inherited from a parent folder are never touched, as they can't be changed on this item;
+    /// they still satisfy a template entry for the same grantee, which is therefore not added.
+    pub fn new(existing: &[drive::Permission], template: &PermissionTemplate) -> ReconcilePlan {
+        let mut plan = ReconcilePlan::default();
+        let mut matched = vec![false; template.entries.len()];
+
+        for p in existing {
+            let id = match p.id.as_ref() {
+                Some(id) => id,
+                None => continue,
+            };
+            let fixed = p.role == Some(drive::PermissionRole::Owner) || is_inherited(p);
+            let grantee = Grantee::of(p);
+            match template.entries.iter().position(|e| e.grantee == grantee) {
+                Some(i) => {
+                    matched[i] = true;
+                    if !fixed && p.role.as_ref() != Some(&template.entries[i].role) {
+                        plan.update.push((id.clone(), template.entries[i].clone()));
+                    }
+                }
+                None if fixed => {}
+                None => plan.remove.push(id.clone()),
+            }
+        }
+        for (e, m) in template.entries.iter().zip(matched) {
+            if !m {
+                plan.add.push(e.clone());
+            }
+        }
+        plan
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.update.is_empty() && self.remove.is_empty()
+    }
+}
+
+/// Drive only grants the `owner` role with `transferOwnership` set.
+fn transfers_ownership(role: &drive::PermissionRole) -> Option<bool> {
+    Some(true).filter(|_| *role == drive::PermissionRole::Owner)
+}
+
+fn is_inherited(p: &drive::Permission) -> bool {
+    match p.permission_details.as_ref() {
+        Some(details) if !details.is_empty() => {
+            details.iter().all(|d| d.inherited.unwrap_or(false))
+        }
+        _ => false,
+    }
+}
+
+/// The item whose permissions are managed. Permissions of shared drives and of files are managed
+/// through the same API, but some parameters only apply to shared drives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermissionTarget {
+    /// A file or folder, in My Drive or in a shared drive.
+    File(String),
+    /// A shared drive. If `admin` is set, requests are issued with `useDomainAdminAccess`, which
+    /// lets domain administrators manage drives they are not a member of.
+    SharedDrive { id: String, admin: bool },
+}
+
+impl PermissionTarget {
+    /// Guess the kind of item from its ID: shared drive IDs are short and start with `0A`,
+    /// unlike file IDs. Use `lookup()` if the ID may be ambiguous.
+    pub fn from_id(id: &str) -> PermissionTarget {
+        if id.starts_with("0A") && id.len() < 25 {
+            PermissionTarget::SharedDrive {
+                id: id.into(),
+                admin: false,
+            }
+        } else {
+            PermissionTarget::File(id.into())
+        }
+    }
+
+    /// Determine the kind of item by fetching its metadata: the ID of a shared drive is also the
+    /// ID of its root folder, whose `driveId` is the ID itself. Falls back to `from_id()` if the
+    /// metadata is not found (e.g. for shared drives of which an administrator isn't a member);
+    /// other errors are returned.
+    pub async fn lookup<C: HttpClient>(
+        files: &drive::FilesService<C>,
+        id: &str,
+    ) -> Result<PermissionTarget> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("id,driveId".into());
+        let mut params = drive::FilesGetParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = id.into();
+        params.supports_all_drives = Some(true);
+
+        let file = match files.get(&params).await?.do_it(None).await {
+            Ok(DownloadResult::Response(f)) => f,
+            Ok(DownloadResult::Downloaded) => {
+                return Err(ApiError::InputDataError(format!(
+                    "lookup: received content instead of metadata for {}",
+                    id
+                ))
+                .into())
+            }
+            Err(e) if ApiError::is_not_found(&e) => {
+                warn!("lookup: could not fetch metadata of {}: {}", id, e);
+                return Ok(PermissionTarget::from_id(id));
+            }
+            Err(e) => return Err(e),
+        };
+        if file.drive_id.as_deref() == Some(id) {
+            Ok(PermissionTarget::SharedDrive {
+                id: id.into(),
+                admin: false,
+            })
+        } else {
+            Ok(PermissionTarget::File(id.into()))
+        }
+    }
+
+    /// Manage a shared drive as domain administrator.
+    pub fn as_admin(self) -> PermissionTarget {
+        match self {
+            PermissionTarget::SharedDrive { id, .. } => {
+                PermissionTarget::SharedDrive { id, admin: true }
+            }
+            t => t,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        match self {
+            PermissionTarget::File(id) => id,
+            PermissionTarget::SharedDrive { id, .. } => id,
+        }
+    }
+
+    /// `useDomainAdminAccess` is rejected for anything but shared drives, so it is only set for
+    /// those.
+    fn use_domain_admin_access(&self) -> Option<bool> {
+        match self {
+            PermissionTarget::SharedDrive { admin: true, .. } => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for PermissionTarget {
+    fn from(id: &str) -> PermissionTarget {
+        PermissionTarget::from_id(id)
+    }
+}
+
+impl<C: HttpClient> drive::PermissionsService<C> {
+    /// Fetch all permissions of a file or shared drive, following page tokens. `target` may be
+    /// a plain ID, which is classified by `PermissionTarget::from_id()`.
+    pub async fn list_all<T: Into<PermissionTarget>>(
+        &self,
+        target: T,
+    ) -> Result<Vec<drive::Permission>> {
+        let target = target.into();
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(
+            "nextPageToken,permissions(id,type,role,emailAddress,domain,permissionDetails)".into(),
+        );
+        let mut params = drive::PermissionsListParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = target.id().into();
+        params.supports_all_drives = Some(true);
+        params.use_domain_admin_access = target.use_domain_admin_access();
+
+        pages::list_all(None, |token| {
+            let mut params = params.clone();
+            params.page_token = token;
+            async move { self.list(&params).await }
+        })
+        .await
+    }
+
+    /// Make the permissions of `target` (a file, folder, or shared drive) match `template`:
+    /// missing entries are added, roles are updated, and extra permissions are removed. Returns
+    /// the plan that was carried out.
+    pub async fn apply_template<T: Into<PermissionTarget>>(
+        &self,
+        target: T,
+        template: &PermissionTemplate,
+    ) -> Result<ReconcilePlan> {
+        let target = target.into();
+        let file_id = target.id();
+        let existing = self.list_all(target.clone()).await?;
+        let plan = ReconcilePlan::new(&existing, template);
+        debug!(
+            "apply_template: applying template {} to {}: {:?}",
+            template.name, file_id, plan
+        );
+
+        for e in plan.add.iter() {
+            let mut params = drive::PermissionsCreateParams::default();
+            params.file_id = file_id.into();
+            params.supports_all_drives = Some(true);
+            params.use_domain_admin_access = target.use_domain_admin_access();
+            params.transfer_ownership = transfers_ownership(&e.role);
+            params.send_notification_email = Some(matches!(
+                e.grantee.typ,
+                drive::PermissionType::User | drive::PermissionType::Group
+            ));
+            self.create(&params, &e.to_permission()).await?;
+        }
+        for (id, e) in plan.update.iter() {
+            let mut params = drive::PermissionsUpdateParams::default();
+            params.file_id = file_id.into();
+            params.permission_id = id.clone();
+            params.supports_all_drives = Some(true);
+            params.use_domain_admin_access = target.use_domain_admin_access();
+            params.transfer_ownership = transfers_ownership(&e.role);
+            let mut p = drive::Permission::default();
+            p.role = Some(e.role.clone());
+            self.update(&params, &p).await?;
+        }
+        for id in plan.remove.iter() {
+            let mut params = drive::PermissionsDeleteParams::default();
+            params.file_id = file_id.into();
+            params.permission_id = id.clone();
+            params.supports_all_drives = Some(true);
+            params.use_domain_admin_access = target.use_domain_admin_access();
+            self.delete(&params).await?;
+        }
+        Ok(plan)
+    }
+}
+
+impl<C: HttpClient> drive::PermissionsService<C> {
+    /// Give the user (or group) with address `email` `role` on `target`. Drive sends them a
+    /// notification email. Returns the created permission. Sharing with `role` owner transfers
+    /// the ownership of `target`; the previous owner becomes a writer.
+    pub async fn share_with<T: Into<PermissionTarget>>(
+        &self,
+        target: T,
+        email: &str,
+        role: drive::PermissionRole,
+    ) -> Result<drive::Permission> {
+        self.create_entry(target.into(), &PermissionEntry::user(email, role), None)
+            .await
+    }
+
+    /// Give everyone with the link `role` on `target`. The item is not listed in search
+    /// results. Returns the created permission. Everyone can't be made owner.
+    pub async fn make_public<T: Into<PermissionTarget>>(
+        &self,
+        target: T,
+        role: drive::PermissionRole,
+    ) -> Result<drive::Permission> {
+        if role == drive::PermissionRole::Owner {
+            return Err(
+                ApiError::InputDataError("make_public: anyone can't be made owner".into()).into(),
+            );
+        }
+        self.create_entry(target.into(), &PermissionEntry::anyone(role), Some(false))
+            .await
+    }
+
+    /// Remove the permissions of the user or group with address `email` from `target`.
+    /// Inherited permissions can't be removed here and are left alone. Returns the number of
+    /// permissions removed.
+    pub async fn unshare<T: Into<PermissionTarget>>(
+        &self,
+        target: T,
+        email: &str,
+    ) -> Result<usize> {
+        let email = email.to_lowercase();
+        self.delete_matching(target.into(), |g| {
+            g.email_address.as_deref() == Some(email.as_str())
+        })
+        .await
+    }
+
+    /// Remove link sharing (`anyone` permissions) from `target`. Returns the number of
+    /// permissions removed.
+    pub async fn make_private<T: Into<PermissionTarget>>(&self, target: T) -> Result<usize> {
+        self.delete_matching(target.into(), |g| g.typ == drive::PermissionType::Anyone)
+            .await
+    }
+
+    async fn create_entry(
+        &self,
+        target: PermissionTarget,
+        entry: &PermissionEntry,
+        allow_file_discovery: Option<bool>,
+    ) -> Result<drive::Permission> {
+        let mut params = drive::PermissionsCreateParams::default();
+        params.file_id = target.id().into();
+        params.supports_all_drives = Some(true);
+        params.use_domain_admin_access = target.use_domain_admin_access();
+        params.transfer_ownership = transfers_ownership(&entry.role);
+        let mut p = entry.to_permission();
+        p.allow_file_discovery = allow_file_discovery;
+        self.create(&params, &p).await
+    }
+
+    async fn delete_matching<F: Fn(&Grantee) -> bool>(
+        &self,
+        target: PermissionTarget,
+        matches: F,
+    ) -> Result<usize> {
+        let existing = self.list_all(target.clone()).await?;
+        let mut removed = 0;
+        for p in existing.iter() {
+            let id = match p.id.as_ref() {
+                Some(id) => id,
+                None => continue,
+            };
+            if is_inherited(p) || !matches(&Grantee::of(p)) {
+                continue;
+            }
+            let mut params = drive::PermissionsDeleteParams::default();
+            params.file_id = target.id().into();
+            params.permission_id = id.clone();
+            params.supports_all_drives = Some(true);
+            params.use_domain_admin_access = target.use_domain_admin_access();
+            self.delete(&params).await?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+}
+
+/// How a grantee is matched by a permission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessVia {
+    /// A permission for the user's own email address.
+    User,
+    /// A permission for a group with the given address. Group membership can't be checked through
+    /// the Drive API; the user is assumed to be a member.
+    Group(String),
+    /// A permission for the user's domain.
+    Domain(String),
+    /// A permission for anyone (with the link).
+    Anyone,
+}
+
+/// Where a permission is defined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessSource {
+    /// On the item itself.
+    Direct,
+    /// On a parent folder with the given ID.
+    InheritedFrom(String),
+    /// Membership of the shared drive with the given ID.
+    SharedDriveMember(String),
+}
+
+/// One reason for a user having access to an item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessGrant {
+    pub permission_id: drive::PermissionId,
+    pub role: drive::PermissionRole,
+    pub via: AccessVia,
+    pub source: AccessSource,
+}
+
+/// The answer to "why does this user have access": all permissions granting access.
+#[derive(Debug, Clone, Default)]
+pub struct AccessExplanation {
+    pub email_address: String,
+    pub grants: Vec<AccessGrant>,
+    /// Ancestor folders whose permissions or parents couldn't be read (403 Forbidden), from
+    /// `explain_access_in_hierarchy()`. Grants may be inherited from them or beyond them.
+    pub inaccessible_ancestors: Vec<String>,
+}
+
+impl AccessExplanation {
+    /// The highest role granted, or `None` if the user has no access.
+    pub fn effective_role(&self) -> Option<&drive::PermissionRole> {
+        self.grants
+            .iter()
+            .max_by_key(|g| role_rank(&g.role))
+            .map(|g| &g.role)
+    }
+}
+
+fn role_rank(role: &drive::PermissionRole) -> usize {
+    match role {
+        drive::PermissionRole::Other(_) => 0,
+        drive::PermissionRole::Reader => 1,
+        drive::PermissionRole::Commenter => 2,
+        drive::PermissionRole::Writer => 3,
+        drive::PermissionRole::FileOrganizer => 4,
+        drive::PermissionRole::Organizer => 5,
+        drive::PermissionRole::Owner => 6,
+    }
+}
+
+fn access_via(p: &drive::Permission, email: &str) -> Option<AccessVia> {
+    let grantee = Grantee::of(p);
+    let domain = email.rsplit('@').next().unwrap_or("");
+    match grantee.typ {
+        drive::PermissionType::User if grantee.email_address.as_deref() == Some(email) => {
+            Some(AccessVia::User)
+        }
+        drive::PermissionType::Group => grantee.email_address.map(AccessVia::Group),
+        drive::PermissionType::Domain if grantee.domain.as_deref() == Some(domain) => {
+            Some(AccessVia::Domain(domain.into()))
+        }
+        drive::PermissionType::Anyone => Some(AccessVia::Anyone),
+        _ => None,
+    }
+}
+
+impl<C: HttpClient> drive::PermissionsService<C> {
+    /// Explain how the user `email` has access to `target`: every permission matching the user,
+    /// one of their groups, their domain, or anyone, together with the item it is inherited from
+    /// or the shared drive membership it stems from.
+    pub async fn explain_access<T: Into<PermissionTarget>>(
+        &self,
+        target: T,
+        email: &str,
+    ) -> Result<AccessExplanation> {
+        let email = email.to_lowercase();
+        let mut explanation = AccessExplanation {
+            email_address: email.clone(),
+            grants: vec![],
+            inaccessible_ancestors: vec![],
+        };
+        for p in self.list_all(target).await? {
+            let via = match access_via(&p, &email) {
+                Some(via) => via,
+                None => continue,
+            };
+            let id = p.id.clone().unwrap_or_default();
+            let details = p.permission_details.clone().unwrap_or_default();
+            if details.is_empty() {
+                explanation.grants.push(AccessGrant {
+                    permission_id: id,
+                    role: p.role.clone().unwrap_or_default(),
+                    via,
+                    source: AccessSource::Direct,
+                });
+                continue;
+            }
+            // Items in shared drives list each source of a permission separately.
+            for d in details {
+                let source = match (d.inherited.unwrap_or(false), d.inherited_from) {
+                    (true, Some(from))
+                        if d.permission_type
+                            == Some(drive::PermissionPermissionDetailsPermissionType::Member) =>
+                    {
+                        AccessSource::SharedDriveMember(from)
+                    }
+                    (true, Some(from)) => AccessSource::InheritedFrom(from),
+                    _ => AccessSource::Direct,
+                };
+                explanation.grants.push(AccessGrant {
+                    permission_id: id.clone(),
+                    role: d
+                        .role
+                        .map(|r| r.as_str().into())
+                        .or_else(|| p.role.clone())
+                        .unwrap_or_default(),
+                    via: via.clone(),
+                    source,
+                });
+            }
+        }
+        Ok(explanation)
+    }
+
+    /// Like `explain_access()`, but also walks up the folder hierarchy for items in My Drive.
+    /// There, inherited permissions are indistinguishable from direct ones; a permission is
+    /// attributed to the topmost ancestor folder that carries it without interruption.
+    ///
+    /// Ancestors the caller may not read are listed in `inaccessible_ancestors` and skipped:
+    /// a permission present both below and above such a folder is attributed to the folder
+    /// above, as inheritance can't leave out a folder in between.
+    pub async fn explain_access_in_hierarchy(
+        &self,
+        files: &drive::FilesService<C>,
+        file_id: &str,
+        email: &str,
+    ) -> Result<AccessExplanation> {
+        let mut explanation = self.explain_access(file_id, email).await?;
+        // Sources are only missing in My Drive, where all grants look direct.
+        if explanation.grants.is_empty()
+            || explanation
+                .grants
+                .iter()
+                .any(|g| g.source != AccessSource::Direct)
+        {
+            return Ok(explanation);
+        }
+
+        // Permission IDs of the ancestors, from the parent upwards; `None` if unreadable.
+        let mut ancestors: Vec<(String, Option<Vec<drive::PermissionId>>)> = vec![];
+        let mut current = file_id.to_string();
+        loop {
+            let parent = match parent_of(files, &current).await {
+                Ok(Some(parent)) => parent,
+                Ok(None) => break,
+                Err(e) if is_access_denied(&e) => {
+                    // Only ancestors are recorded; for the item itself, the search just ends.
+                    if current != file_id && !explanation.inaccessible_ancestors.contains(&current)
+                    {
+                        explanation.inaccessible_ancestors.push(current);
+                    }
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+            let ids = match self.list_all(PermissionTarget::File(parent.clone())).await {
+                Ok(permissions) => Some(permissions.into_iter().filter_map(|p| p.id).collect()),
+                Err(e) if is_access_denied(&e) => {
+                    explanation.inaccessible_ancestors.push(parent.clone());
+                    None
+                }
+                Err(e) => return Err(e),
+            };
+            ancestors.push((parent.clone(), ids));
+            current = parent;
+        }
+
+        for g in explanation.grants.iter_mut() {
+            if g.source != AccessSource::Direct {
+                continue;
+            }
+            if let Some((folder, _)) = ancestors
+                .iter()
+                .take_while(|(_, ids)| {
+                    ids.as_ref()
+                        .map_or(true, |ids| ids.contains(&g.permission_id))
+                })
+                .filter(|(_, ids)| ids.is_some())
+                .last()
+            {
+                g.source = AccessSource::InheritedFrom(folder.clone());
+            }
+        }
+        Ok(explanation)
+    }
+}
+
+/// Whether `err` is a 403 Forbidden for lacking permissions, as opposed to exceeded quotas.
+fn is_access_denied(err: &anyhow::Error) -> bool {
+    ApiError::is_forbidden(err)
+        && !ApiError::has_reason(err, "rateLimitExceeded")
+        && !ApiError::has_reason(err, "userRateLimitExceeded")
+}
+
+async fn parent_of<C: HttpClient>(
+    files: &drive::FilesService<C>,
+    file_id: &str,
+) -> Result<Option<String>> {
+    let mut general_params = drive::DriveParams::default();
+    general_params.fields = Some("parents".into());
+    let mut params = drive::FilesGetParams::default();
+    params.drive_params = Some(general_params);
+    params.file_id = file_id.into();
+    params.supports_all_drives = Some(true);
+    match files.get(&params).await?.do_it(None).await? {
+        DownloadResult::Response(f) => Ok(f.parents.and_then(|p| p.into_iter().next())),
+        DownloadResult::Downloaded => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfers_ownership() {
+        assert_eq!(
+            transfers_ownership(&drive::PermissionRole::Owner),
+            Some(true)
+        );
+        assert_eq!(transfers_ownership(&drive::PermissionRole::Writer), None);
+        let entry = PermissionEntry::user("A@example.com", "owner");
+        assert_eq!(entry.role, drive::PermissionRole::Owner);
+        assert_eq!(
+            entry.grantee.email_address.as_deref(),
+            Some("a@example.com")
+        );
+    }
+
+    #[test]
+    fn test_reconcile_plan() {
+        let existing: Vec<drive::Permission> = serde_json::from_value(serde_json::json!([
+            {"id": "owner", "type": "user", "emailAddress": "boss@example.com", "role": "owner"},
+            {"id": "keep", "type": "user", "emailAddress": "A@Example.com", "role": "reader"},
+            {"id": "change", "type": "group", "emailAddress": "team@example.com", "role": "reader"},
+            {"id": "extra", "type": "anyone", "role": "reader"},
+            {
+                "id": "inherited",
+                "type": "user",
+                "emailAddress": "parent@example.com",
+                "role": "writer",
+                "permissionDetails": [{"inherited": true, "role": "writer"}]
+            },
+            {"type": "user", "emailAddress": "noid@example.com", "role": "reader"}
+        ]))
+        .unwrap();
+        let template = PermissionTemplate {
+            name: "team".into(),
+            entries: vec![
+                PermissionEntry::user("a@example.com", "reader"),
+                PermissionEntry::group("team@example.com", "writer"),
+                PermissionEntry::domain("example.com", "commenter"),
+                // Already granted by the owner and inherited permissions, which are kept as they
+                // are.
+                PermissionEntry::user("boss@example.com", "writer"),
+                PermissionEntry::user("parent@example.com", "reader"),
+            ],
+        };
+
+        let plan = ReconcilePlan::new(&existing, &template);
+        assert_eq!(plan.add, vec![template.entries[2].clone()]);
+        assert_eq!(plan.update.len(), 1);
+        assert_eq!(plan.update[0].0.to_string(), "change");
+        assert_eq!(plan.update[0].1.role, drive::PermissionRole::Writer);
+        let removed: Vec<String> = plan.remove.iter().map(|id| id.to_string()).collect();
+        assert_eq!(removed, vec!["extra"]);
+        assert!(!plan.is_empty());
+
+        let empty = PermissionTemplate::default();
+        let plan = ReconcilePlan::new(&existing[..2], &empty);
+        assert!(plan.add.is_empty() && plan.update.is_empty());
+        assert_eq!(plan.remove.len(), 1);
+        assert!(ReconcilePlan::new(&existing[..1], &empty).is_empty());
+    }
+
+    #[test]
+    fn test_share() {
+        let mock = MockClient::new();
+        let permissions = drive::PermissionsService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(MockResponse::json(&serde_json::json!({"id": "p1"})))
+            .push(MockResponse::json(&serde_json::json!({"id": "p2"})));
+        futures::executor::block_on(permissions.share_with(
+            "f",
+            "B@example.com",
+            drive::PermissionRole::Owner,
+        ))
+        .unwrap();
+        futures::executor::block_on(permissions.make_public("f", drive::PermissionRole::Reader))
+            .unwrap();
+        assert!(futures::executor::block_on(
+            permissions.make_public("f", drive::PermissionRole::Owner)
+        )
+        .is_err());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, hyper::Method::POST);
+        assert_eq!(requests[0].uri.path(), "/drive/v3/files/f/permissions");
+        assert_eq!(
+            requests[0].query_param("transferOwnership").as_deref(),
+            Some("true")
+        );
+        let p: drive::Permission = requests[0].json().unwrap();
+        assert_eq!(p.email_address.as_deref(), Some("b@example.com"));
+        assert_eq!(p.role, Some(drive::PermissionRole::Owner));
+        assert_eq!(requests[1].query_param("transferOwnership"), None);
+        let p: drive::Permission = requests[1].json().unwrap();
+        assert_eq!(p.typ, Some(drive::PermissionType::Anyone));
+        assert_eq!(p.allow_file_discovery, Some(false));
+    }
+
+    #[test]
+    fn test_unshare() {
+        let mock = MockClient::new();
+        let permissions = drive::PermissionsService::new(mock.clone(), StaticToken("t".into()));
+        let existing = serde_json::json!({"permissions": [
+            {"id": "a", "type": "user", "emailAddress": "a@example.com", "role": "writer"},
+            {
+                "id": "a2",
+                "type": "user",
+                "emailAddress": "a@example.com",
+                "role": "reader",
+                "permissionDetails": [{"inherited": true, "role": "reader"}]
+            },
+            {"id": "link", "type": "anyone", "role": "reader"}
+        ]});
+        mock.push(MockResponse::json(&existing))
+            .push(MockResponse::status(204))
+            .push(MockResponse::json(&existing))
+            .push(MockResponse::status(204));
+
+        // Inherited permissions are left alone.
+        let removed =
+            futures::executor::block_on(permissions.unshare("f", "A@Example.com")).unwrap();
+        assert_eq!(removed, 1);
+        let removed = futures::executor::block_on(permissions.make_private("f")).unwrap();
+        assert_eq!(removed, 1);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[1].method, hyper::Method::DELETE);
+        assert_eq!(requests[1].uri.path(), "/drive/v3/files/f/permissions/a");
+        assert_eq!(requests[3].method, hyper::Method::DELETE);
+        assert_eq!(requests[3].uri.path(), "/drive/v3/files/f/permissions/link");
+    }
+
+    #[test]
+    fn test_lookup() {
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        let lookup = |id| futures::executor::block_on(PermissionTarget::lookup(&files, id));
+        let drive = PermissionTarget::SharedDrive {
+            id: "0AbcDrive".into(),
+            admin: false,
+        };
+
+        mock.push(MockResponse::json(
+            &serde_json::json!({"id": "0AbcDrive", "driveId": "0AbcDrive"}),
+        ));
+        assert_eq!(lookup("0AbcDrive").unwrap(), drive);
+        mock.push(MockResponse::json(
+            &serde_json::json!({"id": "file1", "driveId": "0AbcDrive"}),
+        ));
+        assert_eq!(
+            lookup("file1").unwrap(),
+            PermissionTarget::File("file1".into())
+        );
+
+        // Items that aren't found are classified by their ID.
+        mock.push(MockResponse::error(404, "notFound", "File not found"));
+        assert_eq!(lookup("0AbcDrive").unwrap(), drive);
+        // Other errors are returned.
+        mock.push(MockResponse::error(
+            403,
+            "insufficientFilePermissions",
+            "No",
+        ));
+        assert!(lookup("file1").is_err());
+        mock.push(MockResponse::error(500, "backendError", "Oops"));
+        assert!(lookup("file1").is_err());
+    }
+
+    #[test]
+    fn test_explain_access_in_hierarchy() {
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        let permissions = drive::PermissionsService::new(mock.clone(), StaticToken("t".into()));
+        let grant = serde_json::json!({
+            "id": "p1", "type": "user", "role": "writer", "emailAddress": "a@example.com"
+        });
+        // The file, its parent (unreadable) and grandparent (without parent).
+        mock.push(MockResponse::json(
+            &serde_json::json!({ "permissions": [grant] }),
+        ))
+        .push(MockResponse::json(&serde_json::json!({"parents": ["d1"]})))
+        .push(MockResponse::error(
+            403,
+            "insufficientFilePermissions",
+            "No",
+        ))
+        .push(MockResponse::json(&serde_json::json!({"parents": ["d2"]})))
+        .push(MockResponse::json(
+            &serde_json::json!({ "permissions": [grant] }),
+        ))
+        .push(MockResponse::json(&serde_json::json!({})));
+        let explanation = futures::executor::block_on(permissions.explain_access_in_hierarchy(
+            &files,
+            "f",
+            "A@example.com",
+        ))
+        .unwrap();
+        assert_eq!(explanation.inaccessible_ancestors, vec!["d1".to_string()]);
+        assert_eq!(explanation.grants.len(), 1);
+        assert_eq!(
+            explanation.grants[0].source,
+            AccessSource::InheritedFrom("d2".into())
+        );
+        assert_eq!(
+            explanation.effective_role(),
+            Some(&drive::PermissionRole::Writer)
+        );
+
+        // Other errors are returned.
+        mock.push(MockResponse::json(
+            &serde_json::json!({ "permissions": [grant] }),
+        ))
+        .push(MockResponse::error(500, "backendError", "Oops"));
+        assert!(
+            futures::executor::block_on(permissions.explain_access_in_hierarchy(
+                &files,
+                "f",
+                "a@example.com",
+            ))
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,539 @@
+//! Building search queries (the `q` parameter of `files.list` and `drives.list`) without
+//! formatting and escaping them by hand:
+//!
+//! ```ignore
+//! let q = Query::name().contains("report").and(Query::trashed(false));
+//! params.q = Some(q.to_string());
+//! ```
+//!
+//! renders as `name contains 'report' and trashed = false`. Strings are quoted and escaped, and
+//! conditions combined with `and()`, `or()` and `!` are parenthesized as needed.
+
+use async_google_apis_common::*;
+
+/// Escape `s` for use in a quoted string of a search query.
+pub fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+fn quote(s: &str) -> String {
+    format!("'{}'", escape(s))
+}
+
+fn time(t: &DateTime<Utc>) -> String {
+    quote(&t.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+}
+
+/// A search query, or a condition in one. Render it with `to_string()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    /// A single condition, like `trashed = false`.
+    Term(String),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Not(Box<Query>),
+}
+
+impl Query {
+    /// A condition given as string, used as is.
+    pub fn raw<S: Into<String>>(condition: S) -> Query {
+        Query::Term(condition.into())
+    }
+
+    /// The `name` of a file or shared drive.
+    pub fn name() -> TextField {
+        TextField("name")
+    }
+    /// The name, description, indexable text and content of a file. Only `contains()` is
+    /// supported by the server.
+    pub fn full_text() -> TextField {
+        TextField("fullText")
+    }
+    pub fn mime_type() -> TextField {
+        TextField("mimeType")
+    }
+    pub fn modified_time() -> TimeField {
+        TimeField("modifiedTime")
+    }
+    pub fn viewed_by_me_time() -> TimeField {
+        TimeField("viewedByMeTime")
+    }
+    /// The creation time of a shared drive (`drives.list` only).
+    pub fn created_time() -> TimeField {
+        TimeField("createdTime")
+    }
+    /// The number of members of a shared drive (`drives.list` only).
+    pub fn member_count() -> NumberField {
+        NumberField("memberCount")
+    }
+    /// The number of organizers of a shared drive (`drives.list` only).
+    pub fn organizer_count() -> NumberField {
+        NumberField("organizerCount")
+    }
+
+    pub fn trashed(trashed: bool) -> Query {
+        Query::Term(format!("trashed = {}", trashed))
+    }
+    pub fn starred(starred: bool) -> Query {
+        Query::Term(format!("starred = {}", starred))
+    }
+    pub fn shared_with_me(shared: bool) -> Query {
+        Query::Term(format!("sharedWithMe = {}", shared))
+    }
+    /// Whether a shared drive is hidden (`drives.list` only).
+    pub fn hidden(hidden: bool) -> Query {
+        Query::Term(format!("hidden = {}", hidden))
+    }
+
+    /// Files in the folder `folder_id`.
+    pub fn in_parents(folder_id: &str) -> Query {
+        Query::Term(format!("{} in parents", quote(folder_id)))
+    }
+    /// Files owned by the user with the email address `email`.
+    pub fn owner(email: &str) -> Query {
+        Query::Term(format!("{} in owners", quote(email)))
+    }
+    /// Files the user with the email address `email` may edit.
+    pub fn writer(email: &str) -> Query {
+        Query::Term(format!("{} in writers", quote(email)))
+    }
+    /// Files the user with the email address `email` may read.
+    pub fn reader(email: &str) -> Query {
+        Query::Term(format!("{} in readers", quote(email)))
+    }
+    /// Files with the public custom property `key` set to `value`.
+    pub fn property(key: &str, value: &str) -> Query {
+        Query::Term(format!(
+            "properties has {{ key={} and value={} }}",
+            quote(key),
+            quote(value)
+        ))
+    }
+    /// Files with the private custom property `key` set to `value`.
+    pub fn app_property(key: &str, value: &str) -> Query {
+        Query::Term(format!(
+            "appProperties has {{ key={} and value={} }}",
+            quote(key),
+            quote(value)
+        ))
+    }
+
+    /// Both this condition and `other`.
+    pub fn and(self, other: Query) -> Query {
+        match (self, other) {
+            (Query::And(mut a), Query::And(b)) => {
+                a.extend(b);
+                Query::And(a)
+            }
+            (Query::And(mut a), b) => {
+                a.push(b);
+                Query::And(a)
+            }
+            (a, b) => Query::And(vec![a, b]),
+        }
+    }
+
+    /// This condition or `other`.
+    pub fn or(self, other: Query) -> Query {
+        match (self, other) {
+            (Query::Or(mut a), Query::Or(b)) => {
+                a.extend(b);
+                Query::Or(a)
+            }
+            (Query::Or(mut a), b) => {
+                a.push(b);
+                Query::Or(a)
+            }
+            (a, b) => Query::Or(vec![a, b]),
+        }
+    }
+
+    /// Any of `queries`, e.g. files in one of several folders.
+    ///
+    /// # Panics
+    ///
+    /// If `queries` is empty, as there is no query matching nothing.
+    pub fn any<I: IntoIterator<Item = Query>>(queries: I) -> Query {
+        let queries: Vec<Query> = queries.into_iter().collect();
+        assert!(!queries.is_empty(), "Query::any() of no queries");
+        Query::Or(queries)
+    }
+
+    /// All of `queries`.
+    ///
+    /// # Panics
+    ///
+    /// If `queries` is empty, as there is no query matching everything.
+    pub fn all<I: IntoIterator<Item = Query>>(queries: I) -> Query {
+        let queries: Vec<Query> = queries.into_iter().collect();
+        assert!(!queries.is_empty(), "Query::all() of no queries");
+        Query::And(queries)
+    }
+
+    /// Parse the search query `q`, e.g. to add conditions to it or to split it up. Conditions are
+    /// kept as written, as `Query::Term`s; `and`, `or` and `not` are recognized in any case.
+    /// Mixing `and` and `or` without parentheses is rejected, as their precedence isn't
+    /// documented.
+    pub fn parse(q: &str) -> Result<Query> {
+        let err = |msg: String| ApiError::InputDataError(format!("invalid query {:?}: {}", q, msg));
+        let mut parser = Parser {
+            q,
+            tokens: tokenize(q).map_err(err)?,
+            pos: 0,
+        };
+        let query = parser.expr().map_err(err)?;
+        match parser.next() {
+            None => Ok(query),
+            Some(t) => Err(err(format!("unexpected {:?}", t))),
+        }
+    }
+
+    /// This condition, in parentheses if it consists of several.
+    fn operand(&self) -> String {
+        match self {
+            Query::And(qs) | Query::Or(qs) if qs.len() > 1 => format!("({})", self),
+            _ => self.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    /// A word, quoted string or `{ ... }` group of a condition, by its byte range.
+    Atom(usize, usize),
+}
+
+/// Split `q` into tokens. Quoted strings and braces (as in `properties has { key='a' and
+/// value='b' }`) are kept in one atom.
+fn tokenize(q: &str) -> std::result::Result<Vec<Token>, String> {
+    let chars: Vec<(usize, char)> = q.char_indices().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            _ => {
+                let (mut quoted, mut escaped, mut braces) = (false, false, 0);
+                while i < chars.len() {
+                    let c = chars[i].1;
+                    if escaped {
+                        escaped = false;
+                    } else if quoted {
+                        match c {
+                            '\\' => escaped = true,
+                            '\'' => quoted = false,
+                            _ => {}
+                        }
+                    } else {
+                        match c {
+                            '\'' => quoted = true,
+                            '{' => braces += 1,
+                            '}' if braces == 0 => return Err("unbalanced '}'".to_string()),
+                            '}' => braces -= 1,
+                            c if braces == 0 && (c.is_whitespace() || c == '(' || c == ')') => {
+                                break
+                            }
+                            _ => {}
+                        }
+                    }
+                    i += 1;
+                }
+                if quoted {
+                    return Err("unterminated string".to_string());
+                }
+                if braces > 0 {
+                    return Err("unbalanced '{'".to_string());
+                }
+                let end = chars.get(i).map_or(q.len(), |(p, _)| *p);
+                tokens.push(match q[start..end].to_ascii_lowercase().as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Atom(start, end),
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    q: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.peek();
+        self.pos += 1;
+        t
+    }
+
+    /// Operands joined by either `and` or `or`.
+    fn expr(&mut self) -> std::result::Result<Query, String> {
+        let mut query = self.unary()?;
+        let mut op = None;
+        while let Some(t @ Token::And) | Some(t @ Token::Or) = self.peek() {
+            if op.map_or(false, |op| op != t) {
+                return Err("'and' and 'or' mixed without parentheses".to_string());
+            }
+            op = Some(t);
+            self.pos += 1;
+            let operand = self.unary()?;
+            query = match t {
+                Token::And => query.and(operand),
+                _ => query.or(operand),
+            };
+        }
+        Ok(query)
+    }
+
+    /// A negated or parenthesized expression, or a condition.
+    fn unary(&mut self) -> std::result::Result<Query, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(!self.unary()?),
+            Some(Token::Open) => {
+                let query = self.expr()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(query),
+                    _ => Err("missing ')'".to_string()),
+                }
+            }
+            Some(Token::Atom(start, mut end)) => {
+                while let Some(Token::Atom(_, e)) = self.peek() {
+                    end = e;
+                    self.pos += 1;
+                }
+                Ok(Query::Term(self.q[start..end].to_string()))
+            }
+            Some(t) => Err(format!("unexpected {:?}", t)),
+            None => Err("unexpected end".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |qs: &[Query], sep: &str| {
+            qs.iter()
+                .map(Query::operand)
+                .collect::<Vec<String>>()
+                .join(sep)
+        };
+        match self {
+            Query::Term(t) => f.write_str(t),
+            Query::And(qs) => f.write_str(&join(qs, " and ")),
+            Query::Or(qs) => f.write_str(&join(qs, " or ")),
+            Query::Not(q) => write!(f, "not {}", q.operand()),
+        }
+    }
+}
+
+impl std::ops::Not for Query {
+    type Output = Query;
+
+    /// The negation of this condition.
+    fn not(self) -> Query {
+        match self {
+            Query::Not(q) => *q,
+            q => Query::Not(Box::new(q)),
+        }
+    }
+}
+
+impl From<Query> for String {
+    fn from(q: Query) -> String {
+        q.to_string()
+    }
+}
+
+/// A string field of a query, like `name`.
+#[derive(Debug, Clone, Copy)]
+pub struct TextField(&'static str);
+
+impl TextField {
+    pub fn eq(self, value: &str) -> Query {
+        Query::Term(format!("{} = {}", self.0, quote(value)))
+    }
+    pub fn ne(self, value: &str) -> Query {
+        Query::Term(format!("{} != {}", self.0, quote(value)))
+    }
+    pub fn contains(self, value: &str) -> Query {
+        Query::Term(format!("{} contains {}", self.0, quote(value)))
+    }
+}
+
+/// A date-time field of a query, like `modifiedTime`. Times are compared in UTC.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeField(&'static str);
+
+impl TimeField {
+    pub fn eq(self, t: &DateTime<Utc>) -> Query {
+        Query::Term(format!("{} = {}", self.0, time(t)))
+    }
+    pub fn ne(self, t: &DateTime<Utc>) -> Query {
+        Query::Term(format!("{} != {}", self.0, time(t)))
+    }
+    pub fn lt(self, t: &DateTime<Utc>) -> Query {
+        Query::Term(format!("{} < {}", self.0, time(t)))
+    }
+    pub fn le(self, t: &DateTime<Utc>) -> Query {
+        Query::Term(format!("{} <= {}", self.0, time(t)))
+    }
+    pub fn gt(self, t: &DateTime<Utc>) -> Query {
+        Query::Term(format!("{} > {}", self.0, time(t)))
+    }
+    pub fn ge(self, t: &DateTime<Utc>) -> Query {
+        Query::Term(format!("{} >= {}", self.0, time(t)))
+    }
+}
+
+/// A numeric field of a query, like `memberCount`.
+#[derive(Debug, Clone, Copy)]
+pub struct NumberField(&'static str);
+
+impl NumberField {
+    pub fn eq(self, n: i64) -> Query {
+        Query::Term(format!("{} = {}", self.0, n))
+    }
+    pub fn ne(self, n: i64) -> Query {
+        Query::Term(format!("{} != {}", self.0, n))
+    }
+    pub fn lt(self, n: i64) -> Query {
+        Query::Term(format!("{} < {}", self.0, n))
+    }
+    pub fn le(self, n: i64) -> Query {
+        Query::Term(format!("{} <= {}", self.0, n))
+    }
+    pub fn gt(self, n: i64) -> Query {
+        Query::Term(format!("{} > {}", self.0, n))
+    }
+    pub fn ge(self, n: i64) -> Query {
+        Query::Term(format!("{} >= {}", self.0, n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let q = Query::parse("'a' in parents OR 'b' in parents").unwrap();
+        assert_eq!(
+            q,
+            Query::raw("'a' in parents").or(Query::raw("'b' in parents"))
+        );
+
+        let q = Query::parse("(name = 'x' or NAME = 'y') and not trashed = true").unwrap();
+        assert_eq!(
+            q,
+            Query::raw("name = 'x'")
+                .or(Query::raw("NAME = 'y'"))
+                .and(!Query::raw("trashed = true"))
+        );
+        assert_eq!(
+            q.to_string(),
+            "(name = 'x' or NAME = 'y') and not trashed = true"
+        );
+
+        // Keywords in strings and braces are part of the condition.
+        let q = Query::parse("name contains 'a or b' and name contains 'it\\'s (and)'").unwrap();
+        assert_eq!(
+            q,
+            Query::raw("name contains 'a or b'").and(Query::raw("name contains 'it\\'s (and)'"))
+        );
+        let q = Query::parse("properties has { key='k' and value='v' } or starred = true").unwrap();
+        assert_eq!(
+            q,
+            Query::raw("properties has { key='k' and value='v' }").or(Query::raw("starred = true"))
+        );
+        // Words like `order` or `andrew` aren't keywords.
+        let q = Query::parse("name = 'x' and fullText contains andrew").unwrap();
+        assert_eq!(
+            q,
+            Query::raw("name = 'x'").and(Query::raw("fullText contains andrew"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for q in &[
+            "a or b and c",
+            "(a or b",
+            "a or b)",
+            "name = 'x",
+            "properties has { key='k'",
+            "a and",
+            "or a",
+            "",
+        ] {
+            assert!(Query::parse(q).is_err(), "{:?}", q);
+        }
+        assert!(Query::parse("a or (b and c)").is_ok());
+    }
+
+    #[test]
+    fn test_render() {
+        let q = Query::name()
+            .contains("it's")
+            .and(Query::in_parents("a").or(Query::in_parents("b")));
+        assert_eq!(
+            q.to_string(),
+            "name contains 'it\\'s' and ('a' in parents or 'b' in parents)"
+        );
+        assert_eq!(Query::parse(&q.to_string()).unwrap(), q);
+    }
+
+    #[test]
+    fn test_render_documented() {
+        let q = Query::name().contains("report").and(Query::trashed(false));
+        assert_eq!(q.to_string(), "name contains 'report' and trashed = false");
+
+        let q = !Query::starred(true).and(Query::in_parents("a"));
+        assert_eq!(q.to_string(), "not (starred = true and 'a' in parents)");
+        assert_eq!((!q).to_string(), "starred = true and 'a' in parents");
+
+        let q = Query::any(vec![Query::in_parents("a")]);
+        assert_eq!(q.to_string(), "'a' in parents");
+        let q = Query::all(vec![
+            Query::trashed(false),
+            Query::any(vec![Query::in_parents("a"), Query::in_parents("b")]),
+        ]);
+        assert_eq!(
+            q.to_string(),
+            "trashed = false and ('a' in parents or 'b' in parents)"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_any_empty() {
+        Query::any(vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_all_empty() {
+        Query::all(vec![]);
+    }
+}
@@ -0,0 +1,147 @@
+//! Checking the user's storage quota before uploading.
+//!
+//! Drive only rejects an upload exceeding the quota after all data has been sent. The
+//! `*_checked` upload methods look up `About.storageQuota` first, and fail with
+//! `ApiError::QuotaExceededError` instead.
+
+use crate::drive_v3_types as drive;
+use crate::folders::validate_new_file;
+use async_google_apis_common::*;
+
+/// The user's storage quota, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageQuota {
+    /// `None` if the user has unlimited storage.
+    pub limit: Option<u64>,
+    pub usage: u64,
+}
+
+impl StorageQuota {
+    fn from_about(quota: &drive::AboutStorageQuota) -> Result<StorageQuota> {
+        let parse = |v: Option<i64>| -> Result<Option<u64>> {
+            match v {
+                Some(n) if n < 0 => {
+                    Err(ApiError::InputDataError(format!("invalid quota value {}", n)).into())
+                }
+                n => Ok(n.map(|n| n as u64)),
+            }
+        };
+        Ok(StorageQuota {
+            limit: parse(quota.limit)?,
+            usage: parse(quota.usage)?.unwrap_or(0),
+        })
+    }
+
+    /// Remaining bytes, or `None` if storage is unlimited.
+    pub fn remaining(&self) -> Option<u64> {
+        self.limit.map(|l| l.saturating_sub(self.usage))
+    }
+
+    /// Returns `ApiError::QuotaExceededError` if `size` bytes don't fit into the remaining quota.
+    pub fn check(&self, size: u64) -> Result<()> {
+        match self.remaining() {
+            Some(remaining) if size > remaining => {
+                Err(ApiError::QuotaExceededError(size, remaining).into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<C: HttpClient> drive::AboutService<C> {
+    /// Fetch the user's current storage quota.
+    pub async fn storage_quota(&self) -> Result<StorageQuota> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("storageQuota".into());
+        let mut params = drive::AboutGetParams::default();
+        params.drive_params = Some(general_params);
+
+        let about = self.get(&params).await?;
+        match about.storage_quota {
+            Some(ref q) => StorageQuota::from_about(q),
+            None => Err(ApiError::InputDataError(
+                "storage_quota: response contained no storageQuota".into(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Like `create_upload()`, but fails with `ApiError::QuotaExceededError` without uploading
+    /// anything if `data` doesn't fit into the user's remaining storage quota. `req` is checked
+    /// with `validate_new_file()` first.
+    ///
+    /// Files in shared drives don't count against the user's quota; don't use this for them.
+    pub async fn create_upload_checked(
+        &self,
+        about: &drive::AboutService<C>,
+        params: &drive::FilesCreateParams,
+        req: &drive::File,
+        data: hyper::body::Bytes,
+    ) -> Result<drive::File> {
+        validate_new_file(req, true)?;
+        about.storage_quota().await?.check(data.len() as u64)?;
+        self.create_upload(params, req, data).await
+    }
+
+    /// Like `create_resumable_upload()`, but fails with `ApiError::QuotaExceededError` before
+    /// starting the upload if `size` bytes don't fit into the user's remaining storage quota.
+    /// `req` is checked with `validate_new_file()` first.
+    ///
+    /// Files in shared drives don't count against the user's quota; don't use this for them.
+    pub async fn create_resumable_upload_checked<'client>(
+        &'client self,
+        about: &drive::AboutService<C>,
+        params: &drive::FilesCreateParams,
+        req: &drive::File,
+        size: u64,
+    ) -> Result<ResumableUpload<'client, drive::File, C>> {
+        validate_new_file(req, true)?;
+        about.storage_quota().await?.check(size)?;
+        self.create_resumable_upload(params, req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::folders::FOLDER_MIME_TYPE;
+
+    #[test]
+    fn test_create_upload_checked() {
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        let about = drive::AboutService::new(mock.clone(), StaticToken("t".into()));
+        let upload = |req: &drive::File, data: &'static [u8]| {
+            futures::executor::block_on(files.create_upload_checked(
+                &about,
+                &drive::FilesCreateParams::default(),
+                req,
+                data.into(),
+            ))
+        };
+
+        // Invalid metadata is rejected before any request.
+        let mut folder = drive::File::default();
+        folder.mime_type = Some(FOLDER_MIME_TYPE.into());
+        assert!(upload(&folder, b"data").is_err());
+        assert!(mock.requests().is_empty());
+
+        let quota = serde_json::json!({"storageQuota": {"limit": "10", "usage": "8"}});
+        let mut req = drive::File::default();
+        req.name = Some("a.txt".into());
+        mock.push(MockResponse::json(&quota));
+        let err = upload(&req, b"abc").unwrap_err();
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::QuotaExceededError(3, 2)) => {}
+            _ => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(mock.requests().len(), 1);
+
+        mock.push(MockResponse::json(&quota))
+            .push(MockResponse::json(&serde_json::json!({"id": "f1"})));
+        let file = upload(&req, b"ab").unwrap();
+        assert_eq!(file.id.as_deref(), Some("f1"));
+    }
+}
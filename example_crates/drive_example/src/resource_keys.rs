@@ -0,0 +1,327 @@
+//! Resource keys for files shared by link.
+//!
+//! Some files shared via link can only be accessed if their resource key is supplied in the
+//! `X-Goog-Drive-Resource-Keys` header, formatted as `fileId/resourceKey[,fileId/resourceKey...]`.
+//! `ResourceKeyClient` takes care of this: it learns the keys of files in Drive responses and
+//! attaches the key of the file a request is about. For other keys (e.g. of folders searched
+//! with `'id' in parents`), `ResourceKeys` collects the keys found in `File` objects and
+//! `set_resource_keys()` attaches them to all subsequent requests of a service.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+/// Name of the header carrying resource keys.
+pub const RESOURCE_KEYS_HEADER: &str = "x-goog-drive-resource-keys";
+
+/// A set of known resource keys, indexed by file ID.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceKeys {
+    keys: HashMap<String, String>,
+}
+
+impl ResourceKeys {
+    pub fn new() -> ResourceKeys {
+        Default::default()
+    }
+
+    /// Remember the resource key for `file_id`.
+    pub fn insert<S: Into<String>, T: Into<String>>(&mut self, file_id: S, resource_key: T) {
+        self.keys.insert(file_id.into(), resource_key.into());
+    }
+
+    /// Remember the resource key of `file`, if it has one. Make sure to request the `id` and
+    /// `resourceKey` fields.
+    pub fn learn(&mut self, file: &drive::File) {
+        if let (Some(id), Some(key)) = (file.id.as_ref(), file.resource_key.as_ref()) {
+            self.insert(id.as_str(), key.as_str());
+        }
+    }
+
+    /// Remember the resource keys of all files in a listing.
+    pub fn learn_all(&mut self, list: &drive::FileList) {
+        for f in list.files.iter().flatten() {
+            self.learn(f);
+        }
+    }
+
+    /// Remember the resource keys in a JSON response, a file or a file listing.
+    fn learn_json(&mut self, value: &serde_json::Value) {
+        let files = value.get("files").and_then(|f| f.as_array());
+        for f in std::iter::once(value).chain(files.into_iter().flatten()) {
+            if let (Some(id), Some(key)) = (
+                f.get("id").and_then(|id| id.as_str()),
+                f.get("resourceKey").and_then(|key| key.as_str()),
+            ) {
+                self.insert(id, key);
+            }
+        }
+    }
+
+    pub fn get(&self, file_id: &str) -> Option<&str> {
+        self.keys.get(file_id).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Value of the `X-Goog-Drive-Resource-Keys` header containing all known keys.
+    pub fn header_value(&self) -> String {
+        let mut pairs: Vec<String> = self
+            .keys
+            .iter()
+            .map(|(id, key)| format!("{}/{}", id, key))
+            .collect();
+        pairs.sort();
+        pairs.join(",")
+    }
+}
+
+macro_rules! impl_set_resource_keys {
+    ($($service:ident),*) => {
+        $(
+        impl<C: HttpClient> drive::$service<C> {
+            /// Send the given resource keys with every subsequent request of this service.
+            pub fn set_resource_keys(&mut self, keys: &ResourceKeys) {
+                if keys.is_empty() {
+                    return;
+                }
+                self.set_extra_header(
+                    hyper::header::HeaderName::from_static(RESOURCE_KEYS_HEADER),
+                    keys.header_value(),
+                );
+            }
+        }
+        )*
+    };
+}
+
+impl_set_resource_keys!(
+    FilesService,
+    PermissionsService,
+    RevisionsService,
+    CommentsService,
+    RepliesService
+);
+
+/// An `HttpClient` passing on resource keys without the caller having to thread them through:
+/// keys of files in JSON responses of `client` are remembered, and requests about a file (like
+/// `files.get`, downloads, `permissions.*` and `revisions.*`) carry its key, if known. Clones
+/// share the known keys, so that one client can be used for all services:
+///
+/// ```ignore
+/// let client = ResourceKeyClient::new(https_client);
+/// let files = drive::FilesService::new(client.clone(), auth.clone());
+/// let permissions = drive::PermissionsService::new(client, auth);
+/// ```
+///
+/// Keys are only learned from responses that contain them, i.e. to requests without `fields`
+/// parameter or selecting `resourceKey`.
+#[derive(Clone)]
+pub struct ResourceKeyClient<C = TlsClient> {
+    client: C,
+    keys: Arc<std::sync::Mutex<ResourceKeys>>,
+}
+
+impl<C: HttpClient> ResourceKeyClient<C> {
+    pub fn new(client: C) -> ResourceKeyClient<C> {
+        ResourceKeyClient::with_keys(client, ResourceKeys::new())
+    }
+
+    /// Start out with `keys`, e.g. of files shared by link that haven't been fetched yet.
+    pub fn with_keys(client: C, keys: ResourceKeys) -> ResourceKeyClient<C> {
+        ResourceKeyClient {
+            client,
+            keys: Arc::new(std::sync::Mutex::new(keys)),
+        }
+    }
+
+    /// The keys learned so far.
+    pub fn keys(&self) -> ResourceKeys {
+        self.keys.lock().unwrap().clone()
+    }
+}
+
+impl<C: HttpClient> HttpClient for ResourceKeyClient<C> {
+    fn send(&self, mut request: hyper::Request<hyper::Body>) -> ResponseFuture {
+        if let Some(id) = path_file_id(request.uri().path()) {
+            if let Some(key) = self.keys.lock().unwrap().get(&id) {
+                add_resource_key(request.headers_mut(), &id, key);
+            }
+        }
+        let learn = selects_resource_key(request.uri());
+        let max = self.client.max_response_size();
+        let client = self.client.clone();
+        let keys = self.keys.clone();
+        Box::pin(async move {
+            let response = client.send(request).await?;
+            let is_json = response
+                .headers()
+                .get(hyper::header::CONTENT_TYPE)
+                .and_then(|t| t.to_str().ok())
+                .map_or(false, |t| t.starts_with("application/json"));
+            let announced = response
+                .headers()
+                .get(hyper::header::CONTENT_LENGTH)
+                .and_then(|len| len.to_str().ok()?.parse::<usize>().ok());
+            if !learn
+                || !response.status().is_success()
+                || !is_json
+                || announced.map_or(false, |len| len > max)
+            {
+                return Ok(response);
+            }
+            let (parts, body) = response.into_parts();
+            // Responses longer than the limit are passed on without learning their keys.
+            let body = match read_body_within(body, max, announced.unwrap_or(0)).await? {
+                Ok(body) => body,
+                Err(body) => return Ok(hyper::Response::from_parts(parts, body)),
+            };
+            if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&body) {
+                keys.lock().unwrap().learn_json(&value);
+            }
+            Ok(hyper::Response::from_parts(parts, hyper::Body::from(body)))
+        })
+    }
+
+    fn max_url_length(&self) -> usize {
+        self.client.max_url_length()
+    }
+
+    fn max_response_size(&self) -> usize {
+        self.client.max_response_size()
+    }
+}
+
+/// The ID of the file a request path like `/drive/v3/files/{fileId}/permissions` is about.
+fn path_file_id(path: &str) -> Option<String> {
+    let mut segments = path.split('/').skip_while(|s| *s != "files").skip(1);
+    match segments.next()? {
+        "" | "generateIds" | "trash" => None,
+        // File IDs consist of URL-safe characters only.
+        id => Some(id.to_string()),
+    }
+}
+
+/// Whether the response to a request for `uri` includes resource keys, if there are any.
+fn selects_resource_key(uri: &hyper::Uri) -> bool {
+    let fields = form_urlencoded::parse(uri.query().unwrap_or("").as_bytes())
+        .find(|(name, _)| name == "fields")
+        .map(|(_, value)| value.into_owned());
+    fields.map_or(true, |f| f.contains("resourceKey") || f.contains('*'))
+}
+
+/// Add `file_id/resource_key` to the resource keys header of a request, unless the header already
+/// has a key for `file_id` (e.g. one set with `set_resource_keys()`).
+fn add_resource_key(headers: &mut hyper::HeaderMap, file_id: &str, resource_key: &str) {
+    let name = hyper::header::HeaderName::from_static(RESOURCE_KEYS_HEADER);
+    let pair = format!("{}/{}", file_id, resource_key);
+    let value = match headers.get(&name).and_then(|v| v.to_str().ok()) {
+        Some(v)
+            if v.split(',')
+                .any(|p| p.starts_with(&format!("{}/", file_id))) =>
+        {
+            return
+        }
+        Some(v) => format!("{},{}", v, pair),
+        None => pair,
+    };
+    if let Ok(value) = hyper::header::HeaderValue::from_str(&value) {
+        headers.insert(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get<C: HttpClient>(client: &C, uri: &str) {
+        let request = hyper::Request::get(uri).body(hyper::Body::empty()).unwrap();
+        futures::executor::block_on(client.send(request)).unwrap();
+    }
+
+    #[test]
+    fn test_path_file_id() {
+        assert_eq!(path_file_id("/drive/v3/files/abc").as_deref(), Some("abc"));
+        assert_eq!(
+            path_file_id("/drive/v3/files/abc/permissions/p1").as_deref(),
+            Some("abc")
+        );
+        assert_eq!(
+            path_file_id("/upload/drive/v3/files/abc").as_deref(),
+            Some("abc")
+        );
+        assert_eq!(path_file_id("/drive/v3/files"), None);
+        assert_eq!(path_file_id("/drive/v3/files/generateIds"), None);
+        assert_eq!(path_file_id("/drive/v3/about"), None);
+    }
+
+    #[test]
+    fn test_resource_key_client() {
+        let mock = MockClient::new();
+        let client = ResourceKeyClient::new(mock.clone());
+        let base = "https://www.googleapis.com/drive/v3/files";
+        mock.push(MockResponse::json(&serde_json::json!({
+            "files": [{"id": "a", "resourceKey": "ka"}, {"id": "b"}]
+        })));
+        get(&client, &format!("{}?fields=files(id%2CresourceKey)", base));
+        // Not learned, as `resourceKey` wasn't selected.
+        mock.push(MockResponse::json(
+            &serde_json::json!({"id": "c", "resourceKey": "kc"}),
+        ));
+        get(&client, &format!("{}/c?fields=id", base));
+        assert_eq!(client.keys().get("a"), Some("ka"));
+        assert_eq!(client.keys().get("c"), None);
+
+        mock.clear_requests();
+        mock.push(MockResponse::json(&serde_json::json!({})))
+            .push(MockResponse::json(&serde_json::json!({})))
+            .push(MockResponse::json(&serde_json::json!({})));
+        get(&client, &format!("{}/a/permissions", base));
+        get(&client, &format!("{}/b?alt=media", base));
+        let request = hyper::Request::get(format!("{}/a", base))
+            .header(RESOURCE_KEYS_HEADER, "x/kx")
+            .body(hyper::Body::empty())
+            .unwrap();
+        futures::executor::block_on(client.send(request)).unwrap();
+
+        let headers: Vec<Option<String>> = mock
+            .requests()
+            .iter()
+            .map(|r| {
+                r.headers
+                    .get(RESOURCE_KEYS_HEADER)
+                    .map(|v| v.to_str().unwrap().to_string())
+            })
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                Some("a/ka".to_string()),
+                None,
+                Some("x/kx,a/ka".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resource_key_client_limit() {
+        let mock = MockClient::new();
+        let client =
+            ResourceKeyClient::new(LimitedClient::new(mock.clone()).with_max_response_size(32));
+        let file = serde_json::json!({"id": "a", "resourceKey": "ka", "name": "A long name"});
+        mock.push(MockResponse::json(&file));
+        let request = hyper::Request::get("https://www.googleapis.com/drive/v3/files/a")
+            .body(hyper::Body::empty())
+            .unwrap();
+        let response = futures::executor::block_on(client.send(request)).unwrap();
+        // The response is passed on unchanged, but its key isn't learned.
+        let body =
+            futures::executor::block_on(hyper::body::to_bytes(response.into_body())).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            file
+        );
+        assert!(client.keys().is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+//! Downloading the content of historical revisions.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+use md5::{Digest, Md5};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Passes data through to `inner`, computing its MD5 digest on the way.
+struct Md5Writer<'a> {
+    inner: &'a mut (dyn tokio::io::AsyncWrite + std::marker::Unpin),
+    md5: Md5,
+}
+
+impl tokio::io::AsyncWrite for Md5Writer<'_> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut *this.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = result {
+            this.md5.update(&buf[..n]);
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+impl<C: HttpClient> drive::RevisionsService<C> {
+    /// Download the content of revision `revision_id` of `file_id` to `dst`, and verify it
+    /// against the revision's `md5Checksum`. Returns the revision's metadata.
+    ///
+    /// On a mismatch, `ApiError::ChecksumMismatchError` is returned; `dst` then contains the
+    /// corrupt content. Revisions without checksum (e.g. of Google Docs, which can't be
+    /// downloaded this way anyway) are not verified.
+    pub async fn download_revision(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        dst: &mut (dyn tokio::io::AsyncWrite + std::marker::Unpin),
+    ) -> Result<drive::Revision> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("id,md5Checksum,mimeType,modifiedTime,size".into());
+        let mut params = drive::RevisionsGetParams::default();
+        params.drive_params = Some(general_params);
+        params.file_id = file_id.into();
+        params.revision_id = revision_id.into();
+        let revision = match self.get(&params).await?.do_it(None).await? {
+            DownloadResult::Response(r) => r,
+            DownloadResult::Downloaded => {
+                return Err(ApiError::InputDataError(format!(
+                    "download_revision: no metadata received for revision {} of {}",
+                    revision_id, file_id
+                ))
+                .into())
+            }
+        };
+
+        let mut general_params = drive::DriveParams::default();
+        general_params.alt = Some("media".into());
+        params.drive_params = Some(general_params);
+        let mut writer = Md5Writer {
+            inner: dst,
+            md5: Md5::new(),
+        };
+        match self.get(&params).await?.do_it(Some(&mut writer)).await? {
+            DownloadResult::Downloaded => {}
+            DownloadResult::Response(_) => {
+                return Err(ApiError::InputDataError(format!(
+                    "download_revision: received metadata instead of content for revision {} of {}",
+                    revision_id, file_id
+                ))
+                .into())
+            }
+        }
+
+        if let Some(expected) = revision.md5_checksum.as_ref() {
+            let actual = format!("{:x}", writer.md5.finalize());
+            if !expected.eq_ignore_ascii_case(&actual) {
+                return Err(ApiError::ChecksumMismatchError(expected.clone(), actual).into());
+            }
+        }
+        Ok(revision)
+    }
+}
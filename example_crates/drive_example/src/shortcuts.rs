@@ -0,0 +1,42 @@
+//! Shortcuts and stars, without having to know the MIME types and patch bodies involved.
+
+use crate::drive_v3_types as drive;
+use async_google_apis_common::*;
+
+/// The MIME type of Drive shortcuts.
+pub const SHORTCUT_MIME_TYPE: &str = "application/vnd.google-apps.shortcut";
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Create a shortcut named `name` to `target_id` in the folder `parent`, and return it.
+    /// Without `name`, the shortcut is named by the server (after the target).
+    pub async fn create_shortcut(
+        &self,
+        target_id: &str,
+        parent: &str,
+        name: Option<&str>,
+    ) -> Result<drive::File> {
+        let mut shortcut = drive::File::default();
+        shortcut.mime_type = Some(SHORTCUT_MIME_TYPE.into());
+        shortcut.name = name.map(|n| n.to_string());
+        shortcut.parents = Some(vec![parent.into()]);
+        let mut details = drive::FileShortcutDetails::default();
+        details.target_id = Some(target_id.into());
+        shortcut.shortcut_details = Some(details);
+
+        let mut params = drive::FilesCreateParams::default();
+        params.supports_all_drives = Some(true);
+        self.create(&params, &shortcut).await
+    }
+
+    /// Star or unstar `file_id` for the current user, and return the updated file.
+    pub async fn set_starred(&self, file_id: &str, starred: bool) -> Result<drive::File> {
+        let mut patch = drive::File::default();
+        patch.starred = Some(starred);
+
+        let mut params = drive::FilesUpdateParams::default();
+        params.file_id = file_id.into();
+        params.supports_all_drives = Some(true);
+        self.update(&params, &patch).await
+    }
+}
+
@@ -0,0 +1,132 @@
+//! Typed values for the `spaces` parameter of `files.list`, `changes.list` and `changes.watch`.
+//!
+//! The API expects a comma-separated list of space names, and silently ignores misspelled names
+//! (e.g. `appdatafolder`). The parameters structs therefore take `Spaces` instead of a string:
+//!
+//! ```ignore
+//! params.spaces = Some(Spaces::DRIVE | Spaces::APP_DATA_FOLDER);
+//! ```
+
+use async_google_apis_common::*;
+
+/// A set of Drive spaces.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Spaces(u8);
+
+impl Spaces {
+    pub const DRIVE: Spaces = Spaces(1);
+    pub const APP_DATA_FOLDER: Spaces = Spaces(2);
+    pub const PHOTOS: Spaces = Spaces(4);
+
+    const NAMES: [(Spaces, &'static str); 3] = [
+        (Spaces::DRIVE, "drive"),
+        (Spaces::APP_DATA_FOLDER, "appDataFolder"),
+        (Spaces::PHOTOS, "photos"),
+    ];
+
+    /// The empty set.
+    pub fn empty() -> Spaces {
+        Spaces(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether all spaces in `other` are contained in `self`.
+    pub fn contains(&self, other: Spaces) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Spaces {
+    type Output = Spaces;
+    fn bitor(self, other: Spaces) -> Spaces {
+        Spaces(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for Spaces {
+    fn bitor_assign(&mut self, other: Spaces) {
+        self.0 |= other.0;
+    }
+}
+
+/// Renders the `spaces` parameter, e.g. `drive,appDataFolder`.
+impl std::fmt::Display for Spaces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = Spaces::NAMES
+            .iter()
+            .filter(|(s, _)| self.contains(*s))
+            .map(|(_, name)| *name)
+            .collect();
+        write!(f, "{}", names.join(","))
+    }
+}
+
+/// Parses a comma-separated list of space names as used by the API, rejecting unknown names.
+impl std::str::FromStr for Spaces {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> std::result::Result<Spaces, ApiError> {
+        let mut spaces = Spaces::empty();
+        for name in s.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            spaces |= Spaces::NAMES
+                .iter()
+                .find(|(_, n)| *n == name)
+                .map(|(s, _)| *s)
+                .ok_or_else(|| ApiError::InputDataError(format!("unknown space {:?}", name)))?;
+        }
+        Ok(spaces)
+    }
+}
+
+impl Serialize for Spaces {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Spaces {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> std::result::Result<Spaces, D::Error> {
+        String::deserialize(d)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive_v3_types as drive;
+
+    #[test]
+    fn test_spaces() {
+        let spaces = Spaces::PHOTOS | Spaces::DRIVE;
+        assert_eq!(spaces.to_string(), "drive,photos");
+        assert!(spaces.contains(Spaces::DRIVE));
+        assert!(!spaces.contains(Spaces::APP_DATA_FOLDER));
+        assert!(!spaces.contains(Spaces::DRIVE | Spaces::APP_DATA_FOLDER));
+        assert_eq!(Spaces::empty().to_string(), "");
+
+        assert_eq!(" photos, drive".parse::<Spaces>().unwrap(), spaces);
+        assert_eq!("".parse::<Spaces>().unwrap(), Spaces::empty());
+        assert!("appdatafolder".parse::<Spaces>().is_err());
+    }
+
+    #[test]
+    fn test_spaces_param() {
+        let params = drive::FilesListParams::default().spaces(Spaces::APP_DATA_FOLDER);
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        params.append_to(&mut query);
+        assert!(query
+            .finish()
+            .split('&')
+            .any(|pair| pair == "spaces=appDataFolder"));
+
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["spaces"], "appDataFolder");
+        let back: drive::FilesListParams = serde_json::from_value(json).unwrap();
+        assert_eq!(back.spaces, Some(Spaces::APP_DATA_FOLDER));
+    }
+}
@@ -0,0 +1,985 @@
+//! Two-way synchronization of a local directory with a Drive folder.
+//!
+//! After every sync, the state of each synchronized file (its Drive ID and MD5 checksum) is
+//! recorded in the file `SYNC_STATE_NAME` in the local directory. The next sync compares both
+//! sides with this state: a file changed (or created, or deleted) on one side only is copied (or
+//! deleted) on the other side; a file changed differently on both sides is a conflict, resolved
+//! according to `SyncOptions::conflicts`.
+//!
+//! Files are compared by MD5 checksum. Local files whose size and modification time match the
+//! state are not hashed again. Google Docs and other native documents, which have no content,
+//! are ignored, as are empty folders and files with names occurring several times in a Drive
+//! folder.
+//!
+//! ```ignore
+//! let options = SyncOptions { dry_run: true, ..Default::default() };
+//! for action in files.sync(Path::new("Documents"), folder_id, &options).await?.actions {
+//!     println!("{:?}", action);
+//! }
+//! ```
+
+use crate::directory::{guess_mime_type, local_name};
+use crate::drive_v3_types as drive;
+use crate::folders::FOLDER_MIME_TYPE;
+use crate::local::local_modified_time;
+use crate::query::Query;
+use async_google_apis_common::*;
+
+use md5::{Digest, Md5};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncReadExt;
+
+/// Name of the sync state file in the synchronized local directory.
+pub const SYNC_STATE_NAME: &str = ".drive-sync.json";
+/// Version of the state format. States of newer versions are rejected.
+pub const SYNC_STATE_VERSION: u32 = 1;
+
+/// Fields of remote files needed for synchronizing.
+const SYNC_FILE_FIELDS: &str = "id,name,mimeType,md5Checksum,modifiedTime,size";
+
+/// A file as of the last sync.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SyncedFile {
+    pub file_id: drive::FileId,
+    pub md5: String,
+    pub size: u64,
+    /// Modification time of the local file.
+    pub local_modified: DateTime<Utc>,
+}
+
+/// The state of a synchronized directory after the last sync.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SyncState {
+    pub version: u32,
+    /// ID of the Drive folder the directory is synchronized with.
+    pub folder_id: String,
+    pub last_sync: Option<DateTime<Utc>>,
+    /// Synchronized files, by path relative to the directory with `/` as separator.
+    pub files: BTreeMap<String, SyncedFile>,
+}
+
+impl SyncState {
+    /// Read the state of the directory `dir`, or return an empty state for `folder_id` if it has
+    /// never been synchronized.
+    pub async fn load(dir: &Path, folder_id: &str) -> Result<SyncState> {
+        let content = match tokio::fs::read(dir.join(SYNC_STATE_NAME)).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(SyncState {
+                    version: SYNC_STATE_VERSION,
+                    folder_id: folder_id.into(),
+                    ..Default::default()
+                })
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let state: SyncState = serde_json::from_slice(&content)?;
+        if state.version > SYNC_STATE_VERSION {
+            return Err(ApiError::InputDataError(format!(
+                "sync: state version {} is newer than supported ({})",
+                state.version, SYNC_STATE_VERSION
+            ))
+            .into());
+        }
+        if state.folder_id != folder_id {
+            return Err(ApiError::InputDataError(format!(
+                "sync: {} is synchronized with folder {}, not {}",
+                dir.display(),
+                state.folder_id,
+                folder_id
+            ))
+            .into());
+        }
+        Ok(state)
+    }
+
+    /// Write the state into the directory `dir`, replacing the previous state atomically.
+    pub async fn save(&self, dir: &Path) -> Result<()> {
+        let tmp = dir.join(format!("{}.tmp", SYNC_STATE_NAME));
+        tokio::fs::write(&tmp, serde_json::to_vec_pretty(self)?).await?;
+        tokio::fs::rename(&tmp, dir.join(SYNC_STATE_NAME)).await?;
+        Ok(())
+    }
+}
+
+/// How `FilesService::sync()` resolves files changed on both sides since the last sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Leave both versions alone, and report a `SyncAction::Conflict`.
+    Skip,
+    /// Upload the local version.
+    KeepLocal,
+    /// Download the remote version.
+    KeepRemote,
+    /// Keep the version modified last.
+    KeepNewer,
+}
+
+/// Options for `FilesService::sync()`.
+#[derive(Debug, Clone)]
+pub struct SyncOptions {
+    /// Only compute the actions, without changing anything.
+    pub dry_run: bool,
+    pub conflicts: ConflictResolution,
+    /// Delete files on one side if they were deleted on the other (remote files are trashed). If
+    /// not set, deleted files are restored from the other side instead.
+    pub propagate_deletions: bool,
+    /// Stop the sync when cancelled; checked before each action. The state is saved as after a
+    /// failed action.
+    pub cancel: Option<CancellationToken>,
+}
+
+impl Default for SyncOptions {
+    fn default() -> SyncOptions {
+        SyncOptions {
+            dry_run: false,
+            conflicts: ConflictResolution::Skip,
+            propagate_deletions: true,
+            cancel: None,
+        }
+    }
+}
+
+/// A step of a sync. Paths are relative to the local directory, with `/` as separator.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncAction {
+    /// Upload a local file not present in Drive.
+    Upload {
+        path: String,
+    },
+    /// Upload a local file as new content of `file_id`.
+    Update {
+        path: String,
+        file_id: drive::FileId,
+    },
+    /// Download `file_id`, creating or replacing the local file.
+    Download {
+        path: String,
+        file_id: drive::FileId,
+    },
+    DeleteLocal {
+        path: String,
+    },
+    TrashRemote {
+        path: String,
+        file_id: drive::FileId,
+    },
+    /// Both sides have the same content; only the state is updated.
+    Record {
+        path: String,
+        file_id: drive::FileId,
+    },
+    /// Both sides were changed, and `ConflictResolution::Skip` is used.
+    Conflict {
+        path: String,
+        file_id: drive::FileId,
+    },
+}
+
+impl SyncAction {
+    pub fn path(&self) -> &str {
+        match self {
+            SyncAction::Upload { path }
+            | SyncAction::Update { path, .. }
+            | SyncAction::Download { path, .. }
+            | SyncAction::DeleteLocal { path }
+            | SyncAction::TrashRemote { path, .. }
+            | SyncAction::Record { path, .. }
+            | SyncAction::Conflict { path, .. } => path,
+        }
+    }
+}
+
+/// The result of `FilesService::sync()`.
+#[derive(Debug, Clone, Default)]
+pub struct SyncReport {
+    /// The actions taken, or with `dry_run`, the actions that would be taken.
+    pub actions: Vec<SyncAction>,
+}
+
+impl SyncReport {
+    pub fn conflicts(&self) -> impl Iterator<Item = &SyncAction> {
+        self.actions
+            .iter()
+            .filter(|a| matches!(a, SyncAction::Conflict { .. }))
+    }
+}
+
+/// A local file found by `scan_local()`.
+#[derive(Debug, Clone)]
+struct LocalFile {
+    md5: String,
+    size: u64,
+    modified: DateTime<Utc>,
+}
+
+/// A remote file found by `scan_remote()`.
+#[derive(Debug, Clone)]
+struct RemoteFile {
+    file_id: drive::FileId,
+    md5: Option<String>,
+    modified: Option<DateTime<Utc>>,
+}
+
+/// The files and folders below the synchronized Drive folder.
+#[derive(Debug, Clone, Default)]
+struct RemoteTree {
+    files: BTreeMap<String, RemoteFile>,
+    /// Folder IDs by path; `""` is the synchronized folder itself.
+    folders: BTreeMap<String, drive::FileId>,
+    /// Paths used by several items in Drive. They (and everything below them) are ignored.
+    ambiguous: BTreeSet<String>,
+}
+
+impl RemoteTree {
+    /// Remove ambiguous paths from the remote files and folders, from `local` and from `state`,
+    /// so that they are neither synchronized nor taken for deleted.
+    fn drop_ambiguous(&mut self, local: &mut BTreeMap<String, LocalFile>, state: &mut SyncState) {
+        let ambiguous = &self.ambiguous;
+        self.files.retain(|p, _| !is_ambiguous(ambiguous, p));
+        self.folders.retain(|p, _| !is_ambiguous(ambiguous, p));
+        local.retain(|p, _| !is_ambiguous(ambiguous, p));
+        state.files.retain(|p, _| !is_ambiguous(ambiguous, p));
+    }
+}
+
+/// Whether `path` or one of its parent folders is in `ambiguous`.
+fn is_ambiguous(ambiguous: &BTreeSet<String>, path: &str) -> bool {
+    let mut path = path;
+    loop {
+        if ambiguous.contains(path) {
+            return true;
+        }
+        match path.rfind('/') {
+            Some(i) => path = &path[..i],
+            None => return false,
+        }
+    }
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Synchronize the local directory `dir` with the Drive folder `folder_id` in both
+    /// directions; see the module documentation. Returns the actions taken (or, with
+    /// `options.dry_run`, those that would be taken).
+    ///
+    /// The state is saved after applying the actions, also if an action fails; a failed sync
+    /// can therefore simply be repeated.
+    pub async fn sync(
+        &self,
+        dir: &Path,
+        folder_id: &str,
+        options: &SyncOptions,
+    ) -> Result<SyncReport> {
+        let mut state = SyncState::load(dir, folder_id).await?;
+        let mut local = scan_local(dir, &state).await?;
+        let mut remote = self.scan_remote(folder_id).await?;
+        remote.drop_ambiguous(&mut local, &mut state);
+        let actions = plan(&local, &remote, &state, options);
+        if options.dry_run {
+            return Ok(SyncReport { actions });
+        }
+
+        let mut result = Ok(());
+        for (done, action) in actions.iter().enumerate() {
+            if let Some(cancel) = options.cancel.as_ref() {
+                result = cancel.check(done as u64);
+                if result.is_err() {
+                    break;
+                }
+            }
+            result = self
+                .apply(dir, action, &local, &mut remote, &mut state)
+                .await
+                .map_err(|e| e.context(format!("sync: {:?}", action)));
+            if result.is_err() {
+                break;
+            }
+        }
+        let paths: HashSet<&String> = local.keys().chain(remote.files.keys()).collect();
+        state.files.retain(|p, _| paths.contains(p));
+        if result.is_ok() {
+            state.last_sync = Some(Utc::now());
+        }
+        state.save(dir).await?;
+        result.map(|_| SyncReport { actions })
+    }
+
+    async fn apply(
+        &self,
+        dir: &Path,
+        action: &SyncAction,
+        local: &BTreeMap<String, LocalFile>,
+        remote: &mut RemoteTree,
+        state: &mut SyncState,
+    ) -> Result<()> {
+        let path = action.path();
+        let local_path = dir.join(path);
+        match action {
+            SyncAction::Upload { .. } | SyncAction::Update { .. } => {
+                let l = &local[path];
+                let mut req = drive::File::default();
+                req.modified_time = Some(l.modified);
+                let mut general_params = drive::DriveParams::default();
+                general_params.fields = Some(SYNC_FILE_FIELDS.into());
+                let src = tokio::fs::File::open(&local_path).await?;
+                let file = if let SyncAction::Update { file_id, .. } = action {
+                    let mut params = drive::FilesUpdateParams::default();
+                    params.drive_params = Some(general_params);
+                    params.file_id = file_id.clone();
+                    params.supports_all_drives = Some(true);
+                    self.update_resumable_upload(&params, &req)
+                        .await?
+                        .upload_file(src)
+                        .await?
+                } else {
+                    let (parent, name) = split_path(path);
+                    let parent_id = self.remote_folder(remote, parent).await?;
+                    req.name = Some(name.into());
+                    req.parents = Some(vec![parent_id.into()]);
+                    req.mime_type = Some(guess_mime_type(&local_path).into());
+                    let mut params = drive::FilesCreateParams::default();
+                    params.drive_params = Some(general_params);
+                    params.supports_all_drives = Some(true);
+                    self.create_resumable_upload(&params, &req)
+                        .await?
+                        .upload_file(src)
+                        .await?
+                };
+                if let Some(remote_md5) = file.md5_checksum.as_ref() {
+                    if !remote_md5.eq_ignore_ascii_case(&l.md5) {
+                        return Err(ApiError::ChecksumMismatchError(
+                            remote_md5.clone(),
+                            l.md5.clone(),
+                        )
+                        .into());
+                    }
+                }
+                state.files.insert(
+                    path.into(),
+                    SyncedFile {
+                        file_id: file.id.unwrap_or_default(),
+                        md5: l.md5.clone(),
+                        size: l.size,
+                        local_modified: l.modified,
+                    },
+                );
+            }
+            SyncAction::Download { file_id, .. } => {
+                if let Some(parent) = local_path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                let file = self.download_to_path(file_id, &local_path, true).await?;
+                let md5 = match remote.files.get(path).and_then(|r| r.md5.clone()) {
+                    Some(md5) => md5,
+                    None => local_md5(&local_path).await?,
+                };
+                state.files.insert(
+                    path.into(),
+                    SyncedFile {
+                        file_id: file_id.clone(),
+                        md5,
+                        size: file.size.unwrap_or_default() as u64,
+                        local_modified: local_modified_time(&local_path).await?,
+                    },
+                );
+            }
+            SyncAction::DeleteLocal { .. } => {
+                tokio::fs::remove_file(&local_path).await?;
+                state.files.remove(path);
+            }
+            SyncAction::TrashRemote { file_id, .. } => {
+                let mut params = drive::FilesUpdateParams::default();
+                params.file_id = file_id.clone();
+                params.supports_all_drives = Some(true);
+                let mut req = drive::File::default();
+                req.trashed = Some(true);
+                self.update(&params, &req).await?;
+                state.files.remove(path);
+            }
+            SyncAction::Record { file_id, .. } => {
+                let l = &local[path];
+                state.files.insert(
+                    path.into(),
+                    SyncedFile {
+                        file_id: file_id.clone(),
+                        md5: l.md5.clone(),
+                        size: l.size,
+                        local_modified: l.modified,
+                    },
+                );
+            }
+            SyncAction::Conflict { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// The ID of the remote folder at `path`, creating it (and its parents) if necessary.
+    async fn remote_folder(&self, remote: &mut RemoteTree, path: &str) -> Result<drive::FileId> {
+        if let Some(id) = remote.folders.get(path) {
+            return Ok(id.clone());
+        }
+        // Find the closest existing ancestor, then create the folders below it.
+        let mut missing = vec![path];
+        let mut parent_id = loop {
+            let (parent, _) = split_path(missing.last().unwrap());
+            match remote.folders.get(parent) {
+                Some(id) => break id.clone(),
+                None => missing.push(parent),
+            }
+        };
+        for folder in missing.into_iter().rev() {
+            let (_, name) = split_path(folder);
+            let created = self.create_folder(name, Some(parent_id.as_str())).await?;
+            parent_id = created.id.unwrap_or_default();
+            remote.folders.insert(folder.into(), parent_id.clone());
+        }
+        Ok(parent_id)
+    }
+
+    /// All files and folders below `folder_id`.
+    async fn scan_remote(&self, folder_id: &str) -> Result<RemoteTree> {
+        let mut tree = RemoteTree::default();
+        tree.folders.insert(String::new(), folder_id.into());
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some(format!("nextPageToken,files({})", SYNC_FILE_FIELDS));
+        let mut pending = vec![(drive::FileId::from(folder_id), String::new())];
+        while let Some((id, prefix)) = pending.pop() {
+            let mut params = drive::FilesListParams::default();
+            params.drive_params = Some(general_params.clone());
+            params.q = Some(
+                Query::in_parents(&id)
+                    .and(Query::trashed(false))
+                    .to_string(),
+            );
+            params.supports_all_drives = Some(true);
+            params.include_items_from_all_drives = Some(true);
+            let mut seen = HashSet::new();
+            for file in self.list_all(&params).await? {
+                let (id, mime) = match (file.id, file.mime_type) {
+                    (Some(id), Some(mime)) => (id, mime),
+                    _ => continue,
+                };
+                let path = format!(
+                    "{}{}",
+                    prefix,
+                    local_name(file.name.as_deref().unwrap_or(&id))
+                );
+                if !seen.insert(path.clone()) {
+                    warn!("sync: ignoring {} ({}), the name is used twice", path, id);
+                    tree.ambiguous.insert(path);
+                    continue;
+                }
+                if mime == FOLDER_MIME_TYPE {
+                    if !tree.folders.values().any(|f| *f == id) {
+                        tree.folders.insert(path.clone(), id.clone());
+                        pending.push((id, format!("{}/", path)));
+                    }
+                } else if !mime.starts_with("application/vnd.google-apps.") {
+                    tree.files.insert(
+                        path,
+                        RemoteFile {
+                            file_id: id,
+                            md5: file.md5_checksum,
+                            modified: file.modified_time,
+                        },
+                    );
+                }
+            }
+        }
+        Ok(tree)
+    }
+}
+
+/// Decide what to do with every file found locally, remotely, or in the state.
+fn plan(
+    local: &BTreeMap<String, LocalFile>,
+    remote: &RemoteTree,
+    state: &SyncState,
+    options: &SyncOptions,
+) -> Vec<SyncAction> {
+    let paths: BTreeSet<&String> = local.keys().chain(remote.files.keys()).collect();
+    let mut actions = vec![];
+    for path in paths {
+        let base = state.files.get(path);
+        let path = path.clone();
+        let action = match (local.get(&path), remote.files.get(&path)) {
+            (Some(l), Some(r)) => {
+                let file_id = r.file_id.clone();
+                let same = r
+                    .md5
+                    .as_ref()
+                    .map_or(false, |m| m.eq_ignore_ascii_case(&l.md5));
+                let local_changed = base.map_or(true, |b| b.md5 != l.md5);
+                let remote_changed = base.map_or(true, |b| {
+                    b.file_id != r.file_id || r.md5.as_ref() != Some(&b.md5)
+                });
+                if same {
+                    let recorded = base.map_or(false, |b| {
+                        b.file_id == r.file_id && b.md5 == l.md5 && b.local_modified == l.modified
+                    });
+                    if recorded {
+                        continue;
+                    }
+                    SyncAction::Record { path, file_id }
+                } else if local_changed && !remote_changed {
+                    SyncAction::Update { path, file_id }
+                } else if remote_changed && !local_changed {
+                    SyncAction::Download { path, file_id }
+                } else {
+                    let keep_local = match options.conflicts {
+                        ConflictResolution::Skip => {
+                            actions.push(SyncAction::Conflict { path, file_id });
+                            continue;
+                        }
+                        ConflictResolution::KeepLocal => true,
+                        ConflictResolution::KeepRemote => false,
+                        ConflictResolution::KeepNewer => {
+                            r.modified.map_or(true, |m| l.modified > m)
+                        }
+                    };
+                    if keep_local {
+                        SyncAction::Update { path, file_id }
+                    } else {
+                        SyncAction::Download { path, file_id }
+                    }
+                }
+            }
+            (Some(l), None) => match base {
+                // Deleted remotely, unchanged locally.
+                Some(b) if b.md5 == l.md5 && options.propagate_deletions => {
+                    SyncAction::DeleteLocal { path }
+                }
+                _ => SyncAction::Upload { path },
+            },
+            (None, Some(r)) => match base {
+                // Deleted locally, unchanged remotely.
+                Some(b)
+                    if b.file_id == r.file_id
+                        && r.md5.as_ref() == Some(&b.md5)
+                        && options.propagate_deletions =>
+                {
+                    SyncAction::TrashRemote {
+                        path,
+                        file_id: r.file_id.clone(),
+                    }
+                }
+                _ => SyncAction::Download {
+                    path,
+                    file_id: r.file_id.clone(),
+                },
+            },
+            (None, None) => continue,
+        };
+        actions.push(action);
+    }
+    actions
+}
+
+/// All regular files below `dir`, except the state file. Checksums are taken from `state` for
+/// files whose size and modification time haven't changed.
+async fn scan_local(dir: &Path, state: &SyncState) -> Result<BTreeMap<String, LocalFile>> {
+    let mut files = BTreeMap::new();
+    let mut dirs: Vec<(PathBuf, String)> = vec![(dir.to_path_buf(), String::new())];
+    while let Some((local, prefix)) = dirs.pop() {
+        let mut entries = match tokio::fs::read_dir(&local).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && prefix.is_empty() => {
+                return Ok(files)
+            }
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let name = match entry.file_name().into_string() {
+                Ok(name) => name,
+                Err(name) => {
+                    warn!("sync: skipping {:?} in {}", name, local.display());
+                    continue;
+                }
+            };
+            if prefix.is_empty() && name.starts_with(SYNC_STATE_NAME) {
+                continue;
+            }
+            let path = format!("{}{}", prefix, name);
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push((entry.path(), format!("{}/", path)));
+            } else if file_type.is_file() {
+                let meta = entry.metadata().await?;
+                let size = meta.len();
+                let modified = DateTime::<Utc>::from(meta.modified()?);
+                let md5 = match state.files.get(&path) {
+                    Some(s) if s.size == size && s.local_modified == modified => s.md5.clone(),
+                    _ => local_md5(&entry.path()).await?,
+                };
+                files.insert(
+                    path,
+                    LocalFile {
+                        md5,
+                        size,
+                        modified,
+                    },
+                );
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// The MD5 checksum of the local file `path`, in lower-case hex like `md5Checksum`.
+async fn local_md5(path: &Path) -> Result<String> {
+    let mut f = tokio::fs::File::open(path).await?;
+    let mut md5 = Md5::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = f.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        md5.update(&buf[..n]);
+    }
+    Ok(format!("{:x}", md5.finalize()))
+}
+
+/// Split `path` into the parent path (`""` for top-level files) and the name.
+fn split_path(path: &str) -> (&str, &str) {
+    match path.rfind('/') {
+        Some(i) => (&path[..i], &path[i + 1..]),
+        None => ("", path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::<Utc>::from(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs as u64))
+    }
+
+    fn local(md5: &str, modified: i64) -> LocalFile {
+        LocalFile {
+            md5: md5.into(),
+            size: 1,
+            modified: time(modified),
+        }
+    }
+
+    fn remote(file_id: &str, md5: &str, modified: i64) -> RemoteFile {
+        RemoteFile {
+            file_id: file_id.into(),
+            md5: Some(md5.into()),
+            modified: Some(time(modified)),
+        }
+    }
+
+    fn synced(file_id: &str, md5: &str, modified: i64) -> SyncedFile {
+        SyncedFile {
+            file_id: file_id.into(),
+            md5: md5.into(),
+            size: 1,
+            local_modified: time(modified),
+        }
+    }
+
+    struct Sides {
+        local: BTreeMap<String, LocalFile>,
+        remote: RemoteTree,
+        state: SyncState,
+    }
+
+    impl Sides {
+        fn new() -> Sides {
+            Sides {
+                local: BTreeMap::new(),
+                remote: RemoteTree::default(),
+                state: SyncState::default(),
+            }
+        }
+
+        fn local(mut self, path: &str, md5: &str, modified: i64) -> Sides {
+            self.local.insert(path.into(), local(md5, modified));
+            self
+        }
+
+        fn remote(mut self, path: &str, file_id: &str, md5: &str, modified: i64) -> Sides {
+            self.remote
+                .files
+                .insert(path.into(), remote(file_id, md5, modified));
+            self
+        }
+
+        fn synced(mut self, path: &str, file_id: &str, md5: &str, modified: i64) -> Sides {
+            self.state
+                .files
+                .insert(path.into(), synced(file_id, md5, modified));
+            self
+        }
+
+        fn plan(&self, options: &SyncOptions) -> Vec<SyncAction> {
+            plan(&self.local, &self.remote, &self.state, options)
+        }
+    }
+
+    #[test]
+    fn test_plan_one_side_changed() {
+        let options = SyncOptions::default();
+        let sides = Sides::new()
+            .local("same", "a", 1)
+            .remote("same", "f1", "a", 1)
+            .synced("same", "f1", "a", 1)
+            .local("local-changed", "b2", 2)
+            .remote("local-changed", "f2", "b", 1)
+            .synced("local-changed", "f2", "b", 1)
+            .local("remote-changed", "c", 1)
+            .remote("remote-changed", "f3", "c2", 2)
+            .synced("remote-changed", "f3", "c", 1)
+            .local("new-local", "d", 1)
+            .remote("new-remote", "f5", "e", 1)
+            .local("touched", "g", 5)
+            .remote("touched", "f6", "g", 1)
+            .synced("touched", "f6", "g", 1);
+        assert_eq!(
+            sides.plan(&options),
+            vec![
+                SyncAction::Update {
+                    path: "local-changed".into(),
+                    file_id: "f2".into()
+                },
+                SyncAction::Upload {
+                    path: "new-local".into()
+                },
+                SyncAction::Download {
+                    path: "new-remote".into(),
+                    file_id: "f5".into()
+                },
+                SyncAction::Download {
+                    path: "remote-changed".into(),
+                    file_id: "f3".into()
+                },
+                SyncAction::Record {
+                    path: "touched".into(),
+                    file_id: "f6".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_deletions() {
+        let sides = Sides::new()
+            .local("deleted-remotely", "a", 1)
+            .synced("deleted-remotely", "f1", "a", 1)
+            .remote("deleted-locally", "f2", "b", 1)
+            .synced("deleted-locally", "f2", "b", 1)
+            .local("changed-then-deleted", "c2", 2)
+            .synced("changed-then-deleted", "f3", "c", 1);
+        assert_eq!(
+            sides.plan(&SyncOptions::default()),
+            vec![
+                SyncAction::Upload {
+                    path: "changed-then-deleted".into()
+                },
+                SyncAction::TrashRemote {
+                    path: "deleted-locally".into(),
+                    file_id: "f2".into()
+                },
+                SyncAction::DeleteLocal {
+                    path: "deleted-remotely".into()
+                },
+            ]
+        );
+
+        let options = SyncOptions {
+            propagate_deletions: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            sides.plan(&options),
+            vec![
+                SyncAction::Upload {
+                    path: "changed-then-deleted".into()
+                },
+                SyncAction::Download {
+                    path: "deleted-locally".into(),
+                    file_id: "f2".into()
+                },
+                SyncAction::Upload {
+                    path: "deleted-remotely".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_conflicts() {
+        let sides = Sides::new()
+            .local("both", "a2", 3)
+            .remote("both", "f1", "a3", 2)
+            .synced("both", "f1", "a", 1);
+        let plan_with = |conflicts| {
+            sides.plan(&SyncOptions {
+                conflicts,
+                ..Default::default()
+            })
+        };
+        let update = SyncAction::Update {
+            path: "both".into(),
+            file_id: "f1".into(),
+        };
+        let download = SyncAction::Download {
+            path: "both".into(),
+            file_id: "f1".into(),
+        };
+        assert_eq!(
+            plan_with(ConflictResolution::Skip),
+            vec![SyncAction::Conflict {
+                path: "both".into(),
+                file_id: "f1".into()
+            }]
+        );
+        assert_eq!(
+            plan_with(ConflictResolution::KeepLocal),
+            vec![update.clone()]
+        );
+        assert_eq!(plan_with(ConflictResolution::KeepRemote), vec![download]);
+        assert_eq!(plan_with(ConflictResolution::KeepNewer), vec![update]);
+    }
+
+    #[test]
+    fn test_plan_ignores_ambiguous_names() {
+        // "dup" and "folder" are used twice in Drive; "folder/x" was scanned in one of them.
+        let mut sides = Sides::new()
+            .local("dup", "a", 1)
+            .synced("dup", "f1", "a", 1)
+            .local("folder/x", "b", 1)
+            .synced("folder/x", "f2", "b", 1)
+            .remote("folder/y", "f3", "c", 1)
+            .local("folder2/z", "d", 1)
+            .synced("folder2/z", "f4", "d", 1);
+        sides.remote.ambiguous.insert("dup".into());
+        sides.remote.ambiguous.insert("folder".into());
+        sides
+            .remote
+            .folders
+            .insert("folder".into(), "folder-id".into());
+        sides
+            .remote
+            .drop_ambiguous(&mut sides.local, &mut sides.state);
+        assert!(sides.remote.folders.is_empty());
+        assert_eq!(
+            sides.plan(&SyncOptions::default()),
+            vec![SyncAction::DeleteLocal {
+                path: "folder2/z".into()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_round_trip() {
+        let dir = std::env::temp_dir().join(format!("drive-sync-test-{}", std::process::id()));
+        tokio::fs::create_dir_all(dir.join("docs")).await.unwrap();
+        tokio::fs::write(dir.join("docs/a.txt"), "hello")
+            .await
+            .unwrap();
+
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        let remote_file = serde_json::json!({
+            "id": "fb",
+            "name": "b.txt",
+            "mimeType": "text/plain",
+            "md5Checksum": "2c18e486683a3db1e645ad8523223b72",
+            "modifiedTime": "2020-01-01T00:00:00Z",
+            "size": "6"
+        });
+        // Paths are handled in order: "b.txt" is downloaded, then the folder "docs" is created
+        // for uploading "docs/a.txt".
+        mock.push(MockResponse::json(
+            &serde_json::json!({ "files": [remote_file] }),
+        ))
+        .push(MockResponse::json(&remote_file))
+        .push(MockResponse::bytes("text/plain", "remote"))
+        .push(MockResponse::json(&serde_json::json!({"id": "D"})))
+        .push(MockResponse::status(200).with_header(
+            hyper::header::LOCATION,
+            "https://www.googleapis.com/upload/drive/v3/files?upload_id=u",
+        ))
+        .push(MockResponse::json(&serde_json::json!({
+            "id": "fa",
+            "md5Checksum": "5d41402abc4b2a76b9719d911017c592"
+        })));
+
+        let report = files
+            .sync(&dir, "F", &SyncOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            report.actions,
+            vec![
+                SyncAction::Download {
+                    path: "b.txt".into(),
+                    file_id: "fb".into()
+                },
+                SyncAction::Upload {
+                    path: "docs/a.txt".into()
+                },
+            ]
+        );
+        assert_eq!(tokio::fs::read(dir.join("b.txt")).await.unwrap(), b"remote");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 6);
+        assert!(requests[0]
+            .query_param("q")
+            .unwrap()
+            .contains("'F' in parents"));
+        assert_eq!(requests[2].query_param("alt").as_deref(), Some("media"));
+        let folder: drive::File = requests[3].json().unwrap();
+        assert_eq!(folder.name.as_deref(), Some("docs"));
+        assert_eq!(folder.mime_type.as_deref(), Some(FOLDER_MIME_TYPE));
+        assert_eq!(folder.parents, Some(vec!["F".to_string()]));
+        assert_eq!(
+            requests[4].query_param("uploadType").as_deref(),
+            Some("resumable")
+        );
+        let upload: drive::File = requests[4].json().unwrap();
+        assert_eq!(upload.name.as_deref(), Some("a.txt"));
+        assert_eq!(upload.parents, Some(vec!["D".to_string()]));
+        assert_eq!(requests[5].method, hyper::Method::PUT);
+        assert_eq!(&requests[5].body[..], b"hello");
+
+        let state = SyncState::load(&dir, "F").await.unwrap();
+        assert_eq!(state.files["b.txt"].file_id.to_string(), "fb");
+        assert_eq!(state.files["docs/a.txt"].file_id.to_string(), "fa");
+        assert_eq!(
+            state.files["docs/a.txt"].md5,
+            "5d41402abc4b2a76b9719d911017c592"
+        );
+
+        // Nothing is left to do.
+        mock.clear_requests();
+        mock.push(MockResponse::json(&serde_json::json!({
+            "files": [
+                remote_file,
+                {"id": "D", "name": "docs", "mimeType": FOLDER_MIME_TYPE}
+            ]
+        })))
+        .push(MockResponse::json(&serde_json::json!({
+            "files": [{
+                "id": "fa",
+                "name": "a.txt",
+                "mimeType": "text/plain",
+                "md5Checksum": "5d41402abc4b2a76b9719d911017c592"
+            }]
+        })));
+        let report = files
+            .sync(&dir, "F", &SyncOptions::default())
+            .await
+            .unwrap();
+        assert!(report.actions.is_empty());
+        assert_eq!(mock.requests().len(), 2);
+
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
@@ -0,0 +1,267 @@
+//! Uploads into a folder that may already contain a file of the same name.
+
+use crate::drive_v3_types as drive;
+use crate::folders::validate_new_file;
+use crate::listing::{OrderBy, OrderKey};
+use crate::query::Query;
+use async_google_apis_common::*;
+
+/// What `FilesService::upload_to_folder()` does if the folder already contains a file with the
+/// same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Replace the content (and set the metadata) of the existing file, keeping its ID. The
+    /// previous content remains available as a revision until Drive purges it.
+    Overwrite,
+    /// Like `Overwrite`, but the uploaded content becomes a revision that is kept forever.
+    NewRevision,
+    /// Create a new file, with a suffix added to the name: `report (1).pdf`.
+    KeepBoth,
+    /// Leave the existing file alone and don't upload anything.
+    Skip,
+}
+
+/// The result of `FilesService::upload_to_folder()`.
+#[derive(Debug, Clone)]
+pub enum UploadOutcome {
+    /// A new file was created (no conflict, or `ConflictPolicy::KeepBoth`).
+    Created(drive::File),
+    /// The existing file was updated (`ConflictPolicy::Overwrite` or `NewRevision`).
+    Updated(drive::File),
+    /// The existing file, which was left alone (`ConflictPolicy::Skip`).
+    Skipped(drive::File),
+}
+
+impl UploadOutcome {
+    pub fn file(&self) -> &drive::File {
+        match self {
+            UploadOutcome::Created(f) | UploadOutcome::Updated(f) | UploadOutcome::Skipped(f) => f,
+        }
+    }
+}
+
+/// `name` with ` (n)` inserted before the extension.
+pub(crate) fn numbered_name(name: &str, n: usize) -> String {
+    match name.rfind('.') {
+        Some(i) if i > 0 => format!("{} ({}){}", &name[..i], n, &name[i..]),
+        _ => format!("{} ({})", name, n),
+    }
+}
+
+impl<C: HttpClient> drive::FilesService<C> {
+    /// Upload `data` as a file with the metadata `req` (which must have a `name`) into the folder
+    /// `folder_id`, resolving a conflict with an existing, untrashed file of the same name
+    /// according to `policy`. If there are several such files, the most recently modified one is
+    /// used.
+    pub async fn upload_to_folder(
+        &self,
+        folder_id: &str,
+        req: &drive::File,
+        data: hyper::body::Bytes,
+        policy: ConflictPolicy,
+    ) -> Result<UploadOutcome> {
+        let name = match req.name.as_ref() {
+            Some(name) => name.clone(),
+            None => {
+                return Err(ApiError::InputDataError(
+                    "upload_to_folder: req must specify a name".into(),
+                )
+                .into())
+            }
+        };
+
+        validate_new_file(req, true)?;
+
+        let existing = self
+            .find_in_folder(folder_id, Query::name().eq(&name))
+            .await?;
+        let mut new_name = name.clone();
+        if let Some(existing) = existing.into_iter().next() {
+            let file_id = existing.id.clone().unwrap_or_default();
+            match policy {
+                ConflictPolicy::Skip => return Ok(UploadOutcome::Skipped(existing)),
+                ConflictPolicy::Overwrite | ConflictPolicy::NewRevision => {
+                    let mut params = drive::FilesUpdateParams::default();
+                    params.file_id = file_id;
+                    params.supports_all_drives = Some(true);
+                    params.keep_revision_forever = Some(policy == ConflictPolicy::NewRevision);
+                    // Parents can't be set by update().
+                    let mut req = req.clone();
+                    req.parents = None;
+                    let file = self.update_upload(&params, &req, data).await?;
+                    return Ok(UploadOutcome::Updated(file));
+                }
+                ConflictPolicy::KeepBoth => {
+                    let stem = match name.rfind('.') {
+                        Some(i) if i > 0 => &name[..i],
+                        _ => name.as_str(),
+                    };
+                    let taken: std::collections::HashSet<String> = self
+                        .find_in_folder(folder_id, Query::name().contains(stem))
+                        .await?
+                        .into_iter()
+                        .filter_map(|f| f.name)
+                        .collect();
+                    new_name = (1..)
+                        .map(|n| numbered_name(&name, n))
+                        .find(|n| !taken.contains(n))
+                        .unwrap();
+                }
+            }
+        }
+
+        let mut params = drive::FilesCreateParams::default();
+        params.supports_all_drives = Some(true);
+        let mut req = req.clone();
+        req.name = Some(new_name);
+        req.parents = Some(vec![folder_id.to_string()]);
+        let file = self.create_upload(&params, &req, data).await?;
+        Ok(UploadOutcome::Created(file))
+    }
+
+    /// Untrashed files in `folder_id` matching the query `cond`, most recently modified first.
+    async fn find_in_folder(&self, folder_id: &str, cond: Query) -> Result<Vec<drive::File>> {
+        let mut general_params = drive::DriveParams::default();
+        general_params.fields = Some("nextPageToken,files(id,name,modifiedTime)".into());
+        let mut params = drive::FilesListParams::default();
+        params.drive_params = Some(general_params);
+        params.q = Some(
+            Query::in_parents(folder_id)
+                .and(cond)
+                .and(Query::trashed(false))
+                .to_string(),
+        );
+        params.order_by = Some(OrderBy::new().desc(OrderKey::ModifiedTime));
+        params.supports_all_drives = Some(true);
+        params.include_items_from_all_drives = Some(true);
+        self.list_all(&params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(
+        files: &drive::FilesService<MockClient>,
+        policy: ConflictPolicy,
+    ) -> Result<UploadOutcome> {
+        let mut req = drive::File::default();
+        req.name = Some("a.txt".into());
+        futures::executor::block_on(files.upload_to_folder(
+            "F",
+            &req,
+            hyper::body::Bytes::from_static(b"content"),
+            policy,
+        ))
+    }
+
+    fn existing() -> MockResponse {
+        MockResponse::json(&serde_json::json!({
+            "files": [{"id": "E", "name": "a.txt", "modifiedTime": "2021-01-01T00:00:00Z"}]
+        }))
+    }
+
+    fn body(request: &RecordedRequest) -> String {
+        String::from_utf8_lossy(&request.body).into_owned()
+    }
+
+    #[test]
+    fn test_numbered_name() {
+        assert_eq!(numbered_name("report.pdf", 1), "report (1).pdf");
+        assert_eq!(numbered_name("a.tar.gz", 2), "a.tar (2).gz");
+        assert_eq!(numbered_name("notes", 3), "notes (3)");
+        assert_eq!(numbered_name(".bashrc", 1), ".bashrc (1)");
+    }
+
+    #[test]
+    fn test_upload_overwrite() {
+        for (policy, keep) in &[
+            (ConflictPolicy::Overwrite, "false"),
+            (ConflictPolicy::NewRevision, "true"),
+        ] {
+            let mock = MockClient::new();
+            let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+            mock.push(existing())
+                .push(MockResponse::json(&serde_json::json!({"id": "E"})));
+            match upload(&files, *policy).unwrap() {
+                UploadOutcome::Updated(f) => assert_eq!(f.id.as_deref(), Some("E")),
+                o => panic!("{:?}: unexpected outcome {:?}", policy, o),
+            }
+
+            let requests = mock.requests();
+            assert_eq!(requests.len(), 2);
+            assert_eq!(
+                requests[0].query_param("q").as_deref(),
+                Some("'F' in parents and name = 'a.txt' and trashed = false")
+            );
+            assert_eq!(
+                requests[0].query_param("orderBy").as_deref(),
+                Some("modifiedTime desc")
+            );
+            assert_eq!(requests[1].method, hyper::Method::PATCH);
+            assert_eq!(requests[1].uri.path(), "/upload/drive/v3/files/E");
+            assert_eq!(
+                requests[1].query_param("keepRevisionForever").as_deref(),
+                Some(*keep)
+            );
+            assert!(body(&requests[1]).contains("content"));
+            assert!(!body(&requests[1]).contains("parents"));
+        }
+    }
+
+    #[test]
+    fn test_upload_keep_both() {
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(existing())
+            .push(MockResponse::json(&serde_json::json!({
+                "files": [
+                    {"id": "E", "name": "a.txt"},
+                    {"id": "E1", "name": "a (1).txt"},
+                    {"id": "X", "name": "data.txt"}
+                ]
+            })))
+            .push(MockResponse::json(&serde_json::json!({"id": "N"})));
+        match upload(&files, ConflictPolicy::KeepBoth).unwrap() {
+            UploadOutcome::Created(f) => assert_eq!(f.id.as_deref(), Some("N")),
+            o => panic!("unexpected outcome {:?}", o),
+        }
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[1].query_param("q").as_deref(),
+            Some("'F' in parents and name contains 'a' and trashed = false")
+        );
+        assert_eq!(requests[2].method, hyper::Method::POST);
+        assert_eq!(requests[2].uri.path(), "/upload/drive/v3/files");
+        let created = body(&requests[2]);
+        assert!(created.contains("a (2).txt"));
+        assert!(created.contains("\"F\""));
+    }
+
+    #[test]
+    fn test_upload_skip() {
+        let mock = MockClient::new();
+        let files = drive::FilesService::new(mock.clone(), StaticToken("t".into()));
+        mock.push(existing());
+        match upload(&files, ConflictPolicy::Skip).unwrap() {
+            UploadOutcome::Skipped(f) => assert_eq!(f.id.as_deref(), Some("E")),
+            o => panic!("unexpected outcome {:?}", o),
+        }
+        assert_eq!(mock.requests().len(), 1);
+
+        // Without a conflict, the policy doesn't matter.
+        mock.clear_requests();
+        mock.push(MockResponse::json(&serde_json::json!({"files": []})))
+            .push(MockResponse::json(&serde_json::json!({"id": "N"})));
+        match upload(&files, ConflictPolicy::Skip).unwrap() {
+            UploadOutcome::Created(f) => assert_eq!(f.id.as_deref(), Some("N")),
+            o => panic!("unexpected outcome {:?}", o),
+        }
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(body(&requests[1]).contains("a.txt"));
+    }
+}
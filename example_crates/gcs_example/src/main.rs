@@ -14,7 +14,7 @@ fn https_client() -> common::TlsClient {
 }
 
 async fn upload_file(
-    mut cl: storage_v1_types::ObjectsService,
+    cl: storage_v1_types::ObjectsService,
     bucket: &str,
     p: &Path,
     prefix: &str,
@@ -39,7 +39,7 @@ async fn upload_file(
 }
 
 async fn download_file(
-    mut cl: storage_v1_types::ObjectsService,
+    cl: storage_v1_types::ObjectsService,
     bucket: &str,
     id: &str,
 ) -> common::Result<()> {
@@ -75,7 +75,9 @@ fn print_objects(objs: &storage_v1_types::Objects) {
             println!(
                 "{} ({} B), class {}. Created @ {} by {}. => {}",
                 obj.name.as_ref().unwrap_or(&"(unknown name)".into()),
-                obj.size.as_ref().unwrap_or(&"(unknown size)".into()),
+                obj.size
+                    .map(|s| s.to_string())
+                    .unwrap_or("(unknown size)".into()),
                 obj.storage_class
                     .as_ref()
                     .unwrap_or(&"(unknown class)".into()),
@@ -96,7 +98,7 @@ fn print_objects(objs: &storage_v1_types::Objects) {
 }
 
 async fn list_objects(
-    mut cl: storage_v1_types::ObjectsService,
+    cl: storage_v1_types::ObjectsService,
     bucket: &str,
     prefix: &str,
 ) -> common::Result<()> {
@@ -121,7 +123,7 @@ async fn list_objects(
 }
 
 async fn rm_object(
-    mut cl: storage_v1_types::ObjectsService,
+    cl: storage_v1_types::ObjectsService,
     bucket: &str,
     id: &str,
 ) -> common::Result<()> {
@@ -183,7 +185,7 @@ async fn main() {
             .build()
             .await
             .expect("ServiceAccount authenticator failed.");
-    let authenticator = std::rc::Rc::new(authenticator);
+    let authenticator = std::sync::Arc::new(authenticator);
 
     let action = matches.value_of("ACTION").expect("--action is required.");
     let buck = matches
@@ -12,11 +12,34 @@
 //! I'd be happy if you let me know about your use case of this code.
 //!
 //! THIS FILE HAS BEEN GENERATED -- SAVE ANY MODIFICATIONS BEFORE REPLACING.
+//!
+//! ## Renamed identifiers
+//!
+//! Names from the API that collide with Rust keywords have been renamed:
+//!
+//! * `type` → `typ`
 
 use async_google_apis_common::*;
 
-/// Scopes of this API. Convertible to their string representation with `AsRef`.
-#[derive(Debug, Clone, Copy)]
+/// The URLs of the scopes of this API, e.g. for `set_scopes(&[scopes::...])`.
+pub mod scopes {
+    /// View and manage your data across Google Cloud Platform services
+    pub const CLOUD_PLATFORM: &str = "https://www.googleapis.com/auth/cloud-platform";
+    /// View your data across Google Cloud Platform services
+    pub const CLOUD_PLATFORM_READ_ONLY: &str =
+        "https://www.googleapis.com/auth/cloud-platform.read-only";
+    /// Manage your data and permissions in Google Cloud Storage
+    pub const DEVSTORAGE_FULL_CONTROL: &str =
+        "https://www.googleapis.com/auth/devstorage.full_control";
+    /// View your data in Google Cloud Storage
+    pub const DEVSTORAGE_READ_ONLY: &str = "https://www.googleapis.com/auth/devstorage.read_only";
+    /// Manage your data in Google Cloud Storage
+    pub const DEVSTORAGE_READ_WRITE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+}
+
+/// Scopes of this API. Convertible to their string representation with `as_str()` or `AsRef`.
+/// Select them for a service with `set_scopes(&[...])`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum StorageScopes {
     /// View and manage your data across Google Cloud Platform services
     ///
@@ -40,28 +63,30 @@ pub enum StorageScopes {
     DevstorageReadWrite,
 }
 
-impl std::convert::AsRef<str> for StorageScopes {
-    fn as_ref(&self) -> &'static str {
+/// The scopes of this API, under a name shared by all generated APIs.
+pub type Scope = StorageScopes;
+
+impl StorageScopes {
+    /// The URL identifying this scope.
+    pub fn as_str(&self) -> &'static str {
         match self {
-            StorageScopes::CloudPlatform => "https://www.googleapis.com/auth/cloud-platform",
-            StorageScopes::CloudPlatformReadOnly => {
-                "https://www.googleapis.com/auth/cloud-platform.read-only"
-            }
-            StorageScopes::DevstorageFullControl => {
-                "https://www.googleapis.com/auth/devstorage.full_control"
-            }
-            StorageScopes::DevstorageReadOnly => {
-                "https://www.googleapis.com/auth/devstorage.read_only"
-            }
-            StorageScopes::DevstorageReadWrite => {
-                "https://www.googleapis.com/auth/devstorage.read_write"
-            }
+            StorageScopes::CloudPlatform => scopes::CLOUD_PLATFORM,
+            StorageScopes::CloudPlatformReadOnly => scopes::CLOUD_PLATFORM_READ_ONLY,
+            StorageScopes::DevstorageFullControl => scopes::DEVSTORAGE_FULL_CONTROL,
+            StorageScopes::DevstorageReadOnly => scopes::DEVSTORAGE_READ_ONLY,
+            StorageScopes::DevstorageReadWrite => scopes::DEVSTORAGE_READ_WRITE,
         }
     }
 }
 
+impl std::convert::AsRef<str> for StorageScopes {
+    fn as_ref(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
 /// The bucket's billing configuration.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketBilling {
     /// When set to true, Requester Pays is enabled for this bucket.
     #[serde(rename = "requesterPays")]
@@ -70,7 +95,7 @@ pub struct BucketBilling {
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketCors {
     /// The value, in seconds, to return in the  Access-Control-Max-Age header used in preflight responses.
     #[serde(rename = "maxAgeSeconds")]
@@ -91,7 +116,7 @@ pub struct BucketCors {
 }
 
 /// Encryption configuration for a bucket.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketEncryption {
     /// A Cloud KMS key that will be used to encrypt objects inserted into this bucket, if no encryption method is specified.
     #[serde(rename = "defaultKmsKeyName")]
@@ -100,7 +125,7 @@ pub struct BucketEncryption {
 }
 
 /// The bucket's uniform bucket-level access configuration. The feature was formerly known as Bucket Policy Only. For backward compatibility, this field will be populated with identical information as the uniformBucketLevelAccess field. We recommend using the uniformBucketLevelAccess field to enable and disable the feature.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketIamConfigurationBucketPolicyOnly {
     /// If set, access is controlled only by bucket-level or above IAM policies.
     #[serde(rename = "enabled")]
@@ -109,11 +134,12 @@ pub struct BucketIamConfigurationBucketPolicyOnly {
     /// DateTime: The deadline for changing iamConfiguration.bucketPolicyOnly.enabled from true to false in RFC 3339 format. iamConfiguration.bucketPolicyOnly.enabled may be changed from true to false until the locked time, after which the field is immutable.
     #[serde(rename = "lockedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub locked_time: Option<DateTime<Utc>>,
 }
 
 /// The bucket's uniform bucket-level access configuration.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketIamConfigurationUniformBucketLevelAccess {
     /// If set, access is controlled only by bucket-level or above IAM policies.
     #[serde(rename = "enabled")]
@@ -122,11 +148,12 @@ pub struct BucketIamConfigurationUniformBucketLevelAccess {
     /// DateTime: The deadline for changing iamConfiguration.uniformBucketLevelAccess.enabled from true to false in RFC 3339  format. iamConfiguration.uniformBucketLevelAccess.enabled may be changed from true to false until the locked time, after which the field is immutable.
     #[serde(rename = "lockedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub locked_time: Option<DateTime<Utc>>,
 }
 
 /// The bucket's IAM configuration.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketIamConfiguration {
     /// The bucket's uniform bucket-level access configuration. The feature was formerly known as Bucket Policy Only. For backward compatibility, this field will be populated with identical information as the uniformBucketLevelAccess field. We recommend using the uniformBucketLevelAccess field to enable and disable the feature.
     #[serde(rename = "bucketPolicyOnly")]
@@ -139,7 +166,7 @@ pub struct BucketIamConfiguration {
 }
 
 /// The action to take.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketLifecycleRuleAction {
     /// Target storage class. Required iff the type of the action is SetStorageClass.
     #[serde(rename = "storageClass")]
@@ -152,7 +179,7 @@ pub struct BucketLifecycleRuleAction {
 }
 
 /// The condition(s) under which the action will be taken.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketLifecycleRuleCondition {
     /// Age of an object (in days). This condition is satisfied when an object reaches the specified age.
     #[serde(rename = "age")]
@@ -197,7 +224,7 @@ pub struct BucketLifecycleRuleCondition {
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketLifecycleRule {
     /// The action to take.
     #[serde(rename = "action")]
@@ -210,7 +237,7 @@ pub struct BucketLifecycleRule {
 }
 
 /// The bucket's lifecycle configuration. See lifecycle management for more information.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketLifecycle {
     /// A lifecycle management rule, which is made of an action to take and the condition(s) under which the action will be taken.
     #[serde(rename = "rule")]
@@ -219,7 +246,7 @@ pub struct BucketLifecycle {
 }
 
 /// The bucket's logging configuration, which defines the destination bucket and optional name prefix for the current bucket's logs.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketLogging {
     /// The destination bucket where the current bucket's logs should be placed.
     #[serde(rename = "logBucket")]
@@ -232,7 +259,7 @@ pub struct BucketLogging {
 }
 
 /// The owner of the bucket. This is always the project team's owner group.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketOwner {
     /// The entity, in the form project-owner-projectId.
     #[serde(rename = "entity")]
@@ -245,11 +272,12 @@ pub struct BucketOwner {
 }
 
 /// The bucket's retention policy. The retention policy enforces a minimum retention time for all objects contained in the bucket, based on their creation time. Any attempt to overwrite or delete objects younger than the retention period will result in a PERMISSION_DENIED error. An unlocked retention policy can be modified or removed from the bucket via a storage.buckets.update operation. A locked retention policy cannot be removed or shortened in duration for the lifetime of the bucket. Attempting to remove or decrease period of a locked retention policy will result in a PERMISSION_DENIED error.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketRetentionPolicy {
     /// DateTime: Server-determined value that indicates the time from which policy was enforced and effective. This value is in RFC 3339 format.
     #[serde(rename = "effectiveTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub effective_time: Option<DateTime<Utc>>,
     /// Once locked, an object retention policy cannot be modified.
     #[serde(rename = "isLocked")]
@@ -258,11 +286,12 @@ pub struct BucketRetentionPolicy {
     /// i64: The duration in seconds that objects need to be retained. Retention duration must be greater than zero and less than 100 years. Note that enforcement of retention periods less than a day is not guaranteed. Such periods should only be used for testing purposes.
     #[serde(rename = "retentionPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub retention_period: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub retention_period: Option<i64>,
 }
 
 /// The bucket's versioning configuration.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketVersioning {
     /// While set to true, versioning is fully enabled for this bucket.
     #[serde(rename = "enabled")]
@@ -271,7 +300,7 @@ pub struct BucketVersioning {
 }
 
 /// The bucket's website configuration, controlling how the service behaves when accessing bucket contents as a web site. See the Static Website Examples for more information.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketWebsite {
     /// If the requested object path is missing, the service will ensure the path has a trailing '/', append this suffix, and attempt to retrieve the resulting object. This allows the creation of index.html objects to represent directory pages.
     #[serde(rename = "mainPageSuffix")]
@@ -284,7 +313,7 @@ pub struct BucketWebsite {
 }
 
 /// A bucket.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Bucket {
     /// Access controls on the bucket.
     #[serde(rename = "acl")]
@@ -349,7 +378,8 @@ pub struct Bucket {
     /// i64: The metadata generation of this bucket.
     #[serde(rename = "metageneration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metageneration: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub metageneration: Option<i64>,
     /// The name of the bucket.
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -361,7 +391,8 @@ pub struct Bucket {
     /// u64: The project number of the project the bucket belongs to.
     #[serde(rename = "projectNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_number: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub project_number: Option<u64>,
     /// The bucket's retention policy. The retention policy enforces a minimum retention time for all objects contained in the bucket, based on their creation time. Any attempt to overwrite or delete objects younger than the retention period will result in a PERMISSION_DENIED error. An unlocked retention policy can be modified or removed from the bucket via a storage.buckets.update operation. A locked retention policy cannot be removed or shortened in duration for the lifetime of the bucket. Attempting to remove or decrease period of a locked retention policy will result in a PERMISSION_DENIED error.
     #[serde(rename = "retentionPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -377,10 +408,12 @@ pub struct Bucket {
     /// DateTime: The creation time of the bucket in RFC 3339 format.
     #[serde(rename = "timeCreated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub time_created: Option<DateTime<Utc>>,
     /// DateTime: The modification time of the bucket in RFC 3339 format.
     #[serde(rename = "updated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub updated: Option<DateTime<Utc>>,
     /// The bucket's versioning configuration.
     #[serde(rename = "versioning")]
@@ -397,7 +430,7 @@ pub struct Bucket {
 }
 
 /// The project team associated with the entity, if any.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlProjectTeam {
     /// The project number.
     #[serde(rename = "projectNumber")]
@@ -410,7 +443,7 @@ pub struct BucketAccessControlProjectTeam {
 }
 
 /// An access-control entry.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControl {
     /// The name of the bucket.
     #[serde(rename = "bucket")]
@@ -459,7 +492,7 @@ pub struct BucketAccessControl {
 }
 
 /// An access-control list.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControls {
     /// The list of items.
     #[serde(rename = "items")]
@@ -472,7 +505,7 @@ pub struct BucketAccessControls {
 }
 
 /// A list of buckets.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Buckets {
     /// The list of items.
     #[serde(rename = "items")]
@@ -489,7 +522,7 @@ pub struct Buckets {
 }
 
 /// An notification channel used to watch for resource changes.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Channel {
     /// The address where notifications are delivered for this channel.
     #[serde(rename = "address")]
@@ -498,7 +531,8 @@ pub struct Channel {
     /// i64: Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
     #[serde(rename = "expiration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub expiration: Option<i64>,
     /// A UUID or similar unique string that identifies this channel.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -534,21 +568,23 @@ pub struct Channel {
 }
 
 /// Conditions that must be met for this operation to execute.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ComposeRequestSourceObjectsObjectPreconditions {
     /// i64: Only perform the composition if the generation of the source object that would be used matches this value. If this value and a generation are both specified, they must be the same value or the call will fail.
     #[serde(rename = "ifGenerationMatch")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub if_generation_match: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub if_generation_match: Option<i64>,
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ComposeRequestSourceObjects {
     /// i64: The generation of this object to use as the source.
     #[serde(rename = "generation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub generation: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub generation: Option<i64>,
     /// The source object's name. All source objects must reside in the same bucket.
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -560,7 +596,7 @@ pub struct ComposeRequestSourceObjects {
 }
 
 /// A Compose request.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ComposeRequest {
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -576,7 +612,7 @@ pub struct ComposeRequest {
 }
 
 /// Represents an expression text. Example: title: "User account presence" description: "Determines whether the request has a user account" expression: "size(request.user) > 0"
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Expr {
     /// An optional description of the expression. This is a longer text which describes the expression, e.g. when hovered over it in a UI.
     #[serde(rename = "description")]
@@ -597,7 +633,7 @@ pub struct Expr {
 }
 
 /// JSON template to produce a JSON-style HMAC Key resource for Create responses.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct HmacKey {
     /// The kind of item this is. For HMAC keys, this is always storage#hmacKey.
     #[serde(rename = "kind")]
@@ -613,7 +649,7 @@ pub struct HmacKey {
 }
 
 /// JSON template to produce a JSON-style HMAC Key metadata resource.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct HmacKeyMetadata {
     /// The ID of the HMAC Key.
     #[serde(rename = "accessId")]
@@ -650,15 +686,17 @@ pub struct HmacKeyMetadata {
     /// DateTime: The creation time of the HMAC key in RFC 3339 format.
     #[serde(rename = "timeCreated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub time_created: Option<DateTime<Utc>>,
     /// DateTime: The last modification time of the HMAC key metadata in RFC 3339 format.
     #[serde(rename = "updated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub updated: Option<DateTime<Utc>>,
 }
 
 /// A list of hmacKeys.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct HmacKeysMetadata {
     /// The list of items.
     #[serde(rename = "items")]
@@ -675,7 +713,7 @@ pub struct HmacKeysMetadata {
 }
 
 /// A subscription to receive Google PubSub notifications.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Notification {
     /// An optional list of additional attributes to attach to each Cloud PubSub message published for this notification subscription.
     #[serde(rename = "custom_attributes")]
@@ -716,7 +754,7 @@ pub struct Notification {
 }
 
 /// A list of notification subscriptions.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Notifications {
     /// The list of items.
     #[serde(rename = "items")]
@@ -729,7 +767,7 @@ pub struct Notifications {
 }
 
 /// Metadata of customer-supplied encryption key, if the object is encrypted by such a key.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectCustomerEncryption {
     /// The encryption algorithm.
     #[serde(rename = "encryptionAlgorithm")]
@@ -742,7 +780,7 @@ pub struct ObjectCustomerEncryption {
 }
 
 /// The owner of the object. This will always be the uploader of the object.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectOwner {
     /// The entity, in the form user-userId.
     #[serde(rename = "entity")]
@@ -755,7 +793,7 @@ pub struct ObjectOwner {
 }
 
 /// An object.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Object {
     /// Access controls on the object.
     #[serde(rename = "acl")]
@@ -796,6 +834,7 @@ pub struct Object {
     /// DateTime: A timestamp in RFC 3339 format specified by the user for an object.
     #[serde(rename = "customTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub custom_time: Option<DateTime<Utc>>,
     /// Metadata of customer-supplied encryption key, if the object is encrypted by such a key.
     #[serde(rename = "customerEncryption")]
@@ -812,7 +851,8 @@ pub struct Object {
     /// i64: The content generation of this object. Used for object versioning.
     #[serde(rename = "generation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub generation: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub generation: Option<i64>,
     /// The ID of the object, including the bucket name, object name, and generation number.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -840,7 +880,8 @@ pub struct Object {
     /// i64: The version of the metadata for this object at this generation. Used for preconditions and for detecting changes in metadata. A metageneration number is only meaningful in the context of a particular generation of a particular object.
     #[serde(rename = "metageneration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metageneration: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub metageneration: Option<i64>,
     /// The name of the object. Required if not specified by URL parameter.
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -852,6 +893,7 @@ pub struct Object {
     /// DateTime: A server-determined value that specifies the earliest time that the object's retention period expires. This value is in RFC 3339 format. Note 1: This field is not provided for objects with an active event-based hold, since retention expiration is unknown until the hold is removed. Note 2: This value can be provided even when temporary hold is set (so that the user can reason about policy without having to first unset the temporary hold).
     #[serde(rename = "retentionExpirationTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub retention_expiration_time: Option<DateTime<Utc>>,
     /// The link to this object.
     #[serde(rename = "selfLink")]
@@ -860,7 +902,8 @@ pub struct Object {
     /// u64: Content-Length of the data in bytes.
     #[serde(rename = "size")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub size: Option<u64>,
     /// Storage class of the object.
     #[serde(rename = "storageClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -872,23 +915,27 @@ pub struct Object {
     /// DateTime: The creation time of the object in RFC 3339 format.
     #[serde(rename = "timeCreated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub time_created: Option<DateTime<Utc>>,
     /// DateTime: The deletion time of the object in RFC 3339 format. Will be returned if and only if this version of the object has been deleted.
     #[serde(rename = "timeDeleted")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub time_deleted: Option<DateTime<Utc>>,
     /// DateTime: The time at which the object's storage class was last changed. When the object is initially created, it will be set to timeCreated.
     #[serde(rename = "timeStorageClassUpdated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub time_storage_class_updated: Option<DateTime<Utc>>,
     /// DateTime: The modification time of the object metadata in RFC 3339 format.
     #[serde(rename = "updated")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "lenient_time", default)]
     pub updated: Option<DateTime<Utc>>,
 }
 
 /// The project team associated with the entity, if any.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlProjectTeam {
     /// The project number.
     #[serde(rename = "projectNumber")]
@@ -901,7 +948,7 @@ pub struct ObjectAccessControlProjectTeam {
 }
 
 /// An access-control entry.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControl {
     /// The name of the bucket.
     #[serde(rename = "bucket")]
@@ -930,7 +977,8 @@ pub struct ObjectAccessControl {
     /// i64: The content generation of the object, if applied to an object.
     #[serde(rename = "generation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub generation: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub generation: Option<i64>,
     /// The ID of the access-control entry.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -958,7 +1006,7 @@ pub struct ObjectAccessControl {
 }
 
 /// An access-control list.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControls {
     /// The list of items.
     #[serde(rename = "items")]
@@ -971,7 +1019,7 @@ pub struct ObjectAccessControls {
 }
 
 /// A list of objects.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Objects {
     /// The list of items.
     #[serde(rename = "items")]
@@ -992,7 +1040,7 @@ pub struct Objects {
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct PolicyBindings {
     #[serde(rename = "condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1008,7 +1056,7 @@ pub struct PolicyBindings {
 }
 
 /// A bucket/object IAM policy.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Policy {
     /// An association between a role, which comes with a set of permissions, and members who may assume that role.
     #[serde(rename = "bindings")]
@@ -1033,7 +1081,7 @@ pub struct Policy {
 }
 
 /// A rewrite response.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct RewriteResponse {
     /// true if the copy is finished; otherwise, false if the copy is in progress. This property is always present in the response.
     #[serde(rename = "done")]
@@ -1046,7 +1094,8 @@ pub struct RewriteResponse {
     /// i64: The total size of the object being copied in bytes. This property is always present in the response.
     #[serde(rename = "objectSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_size: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub object_size: Option<i64>,
     #[serde(rename = "resource")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<Object>,
@@ -1057,11 +1106,12 @@ pub struct RewriteResponse {
     /// i64: The total bytes written so far, which can be used to provide a waiting user with a progress indicator. This property is always present in the response.
     #[serde(rename = "totalBytesRewritten")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_bytes_rewritten: Option<String>,
+    #[serde(with = "str_num", default)]
+    pub total_bytes_rewritten: Option<i64>,
 }
 
 /// A subscription to receive Google PubSub notifications.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ServiceAccount {
     /// The ID of the notification.
     #[serde(rename = "email_address")]
@@ -1074,7 +1124,7 @@ pub struct ServiceAccount {
 }
 
 /// A storage.(buckets|objects).testIamPermissions response.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct TestIamPermissionsResponse {
     /// The kind of item this is.
     #[serde(rename = "kind")]
@@ -1086,13 +1136,79 @@ pub struct TestIamPermissionsResponse {
     pub permissions: Option<Vec<String>>,
 }
 
+/// Data format for the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StorageParamsAlt {
+    /// Responses with Content-Type of application/json
+    Json,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl StorageParamsAlt {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            StorageParamsAlt::Json => "json",
+            StorageParamsAlt::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for StorageParamsAlt {
+    fn default() -> StorageParamsAlt {
+        StorageParamsAlt::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for StorageParamsAlt {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for StorageParamsAlt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for StorageParamsAlt {
+    fn from(v: &str) -> StorageParamsAlt {
+        match v {
+            "json" => StorageParamsAlt::Json,
+            _ => StorageParamsAlt::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for StorageParamsAlt {
+    fn from(v: String) -> StorageParamsAlt {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for StorageParamsAlt {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for StorageParamsAlt {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<StorageParamsAlt, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct StorageParams {
     /// Data format for the response.
     #[serde(rename = "alt")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alt: Option<String>,
+    pub alt: Option<StorageParamsAlt>,
     /// Selector specifying which fields to include in a partial response.
     #[serde(rename = "fields")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1119,8 +1235,165 @@ pub struct StorageParams {
     pub user_ip: Option<String>,
 }
 
+impl std::fmt::Debug for StorageParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageParams")
+            .field("alt", &self.alt)
+            .field("fields", &self.fields)
+            .field("key", &self.key.as_ref().map(|_| "redacted"))
+            .field(
+                "oauth_token",
+                &self.oauth_token.as_ref().map(|_| "redacted"),
+            )
+            .field("pretty_print", &self.pretty_print)
+            .field("quota_user", &self.quota_user)
+            .field("user_ip", &self.user_ip)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod round_trip_tests {
+    use super::*;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(json: &str) {
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+    }
+
+    #[test]
+    fn round_trip_bucket() {
+        round_trip::<Bucket>(
+            r##"{"acl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","id":"sample","kind":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"billing":{"requesterPays":true},"cors":[{"maxAgeSeconds":1,"method":["sample"],"origin":["sample"],"responseHeader":["sample"]}],"defaultEventBasedHold":true,"defaultObjectAcl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"encryption":{"defaultKmsKeyName":"sample"},"etag":"sample","iamConfiguration":{"bucketPolicyOnly":{"enabled":true,"lockedTime":"2020-06-01T12:30:45Z"},"uniformBucketLevelAccess":{"enabled":true,"lockedTime":"2020-06-01T12:30:45Z"}},"id":"sample","kind":"sample","labels":{"key":"sample"},"lifecycle":{"rule":[{"action":{"storageClass":"sample","type":"sample"},"condition":{"age":1,"createdBefore":"sample","customTimeBefore":"sample","daysSinceCustomTime":1,"daysSinceNoncurrentTime":1,"isLive":true,"matchesPattern":"sample","matchesStorageClass":["sample"],"noncurrentTimeBefore":"sample","numNewerVersions":1}}]},"location":"sample","locationType":"sample","logging":{"logBucket":"sample","logObjectPrefix":"sample"},"metageneration":"123","name":"sample","owner":{"entity":"sample","entityId":"sample"},"projectNumber":"123","retentionPolicy":{"effectiveTime":"2020-06-01T12:30:45Z","isLocked":true,"retentionPeriod":"123"},"selfLink":"sample","storageClass":"sample","timeCreated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z","versioning":{"enabled":true},"website":{"mainPageSuffix":"sample","notFoundPage":"sample"},"zoneAffinity":["sample"]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_bucket_access_control() {
+        round_trip::<BucketAccessControl>(
+            r##"{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","id":"sample","kind":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_bucket_access_controls() {
+        round_trip::<BucketAccessControls>(
+            r##"{"items":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","id":"sample","kind":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"kind":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_buckets() {
+        round_trip::<Buckets>(
+            r##"{"items":[{"acl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","id":"sample","kind":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"billing":{"requesterPays":true},"cors":[{"maxAgeSeconds":1,"method":["sample"],"origin":["sample"],"responseHeader":["sample"]}],"defaultEventBasedHold":true,"defaultObjectAcl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"encryption":{"defaultKmsKeyName":"sample"},"etag":"sample","iamConfiguration":{"bucketPolicyOnly":{"enabled":true,"lockedTime":"2020-06-01T12:30:45Z"},"uniformBucketLevelAccess":{"enabled":true,"lockedTime":"2020-06-01T12:30:45Z"}},"id":"sample","kind":"sample","labels":{"key":"sample"},"lifecycle":{"rule":[{"action":{"storageClass":"sample","type":"sample"},"condition":{"age":1,"createdBefore":"sample","customTimeBefore":"sample","daysSinceCustomTime":1,"daysSinceNoncurrentTime":1,"isLive":true,"matchesPattern":"sample","matchesStorageClass":["sample"],"noncurrentTimeBefore":"sample","numNewerVersions":1}}]},"location":"sample","locationType":"sample","logging":{"logBucket":"sample","logObjectPrefix":"sample"},"metageneration":"123","name":"sample","owner":{"entity":"sample","entityId":"sample"},"projectNumber":"123","retentionPolicy":{"effectiveTime":"2020-06-01T12:30:45Z","isLocked":true,"retentionPeriod":"123"},"selfLink":"sample","storageClass":"sample","timeCreated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z","versioning":{"enabled":true},"website":{"mainPageSuffix":"sample","notFoundPage":"sample"},"zoneAffinity":["sample"]}],"kind":"sample","nextPageToken":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_channel() {
+        round_trip::<Channel>(
+            r##"{"address":"sample","expiration":"123","id":"sample","kind":"sample","params":{"key":"sample"},"payload":true,"resourceId":"sample","resourceUri":"sample","token":"sample","type":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_compose_request() {
+        round_trip::<ComposeRequest>(
+            r##"{"destination":{"acl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"bucket":"sample","cacheControl":"sample","componentCount":1,"contentDisposition":"sample","contentEncoding":"sample","contentLanguage":"sample","contentType":"sample","crc32c":"sample","customTime":"2020-06-01T12:30:45Z","customerEncryption":{"encryptionAlgorithm":"sample","keySha256":"sample"},"etag":"sample","eventBasedHold":true,"generation":"123","id":"sample","kind":"sample","kmsKeyName":"sample","md5Hash":"sample","mediaLink":"sample","metadata":{"key":"sample"},"metageneration":"123","name":"sample","owner":{"entity":"sample","entityId":"sample"},"retentionExpirationTime":"2020-06-01T12:30:45Z","selfLink":"sample","size":"123","storageClass":"sample","temporaryHold":true,"timeCreated":"2020-06-01T12:30:45Z","timeDeleted":"2020-06-01T12:30:45Z","timeStorageClassUpdated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z"},"kind":"sample","sourceObjects":[{"generation":"123","name":"sample","objectPreconditions":{"ifGenerationMatch":"123"}}]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_expr() {
+        round_trip::<Expr>(
+            r##"{"description":"sample","expression":"sample","location":"sample","title":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_hmac_key() {
+        round_trip::<HmacKey>(
+            r##"{"kind":"sample","metadata":{"accessId":"sample","etag":"sample","id":"sample","kind":"sample","projectId":"sample","selfLink":"sample","serviceAccountEmail":"sample","state":"sample","timeCreated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z"},"secret":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_hmac_keys_metadata() {
+        round_trip::<HmacKeysMetadata>(
+            r##"{"items":[{"accessId":"sample","etag":"sample","id":"sample","kind":"sample","projectId":"sample","selfLink":"sample","serviceAccountEmail":"sample","state":"sample","timeCreated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z"}],"kind":"sample","nextPageToken":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_notification() {
+        round_trip::<Notification>(
+            r##"{"custom_attributes":{"key":"sample"},"etag":"sample","event_types":["sample"],"id":"sample","kind":"sample","object_name_prefix":"sample","payload_format":"sample","selfLink":"sample","topic":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_notifications() {
+        round_trip::<Notifications>(
+            r##"{"items":[{"custom_attributes":{"key":"sample"},"etag":"sample","event_types":["sample"],"id":"sample","kind":"sample","object_name_prefix":"sample","payload_format":"sample","selfLink":"sample","topic":"sample"}],"kind":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_object() {
+        round_trip::<Object>(
+            r##"{"acl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"bucket":"sample","cacheControl":"sample","componentCount":1,"contentDisposition":"sample","contentEncoding":"sample","contentLanguage":"sample","contentType":"sample","crc32c":"sample","customTime":"2020-06-01T12:30:45Z","customerEncryption":{"encryptionAlgorithm":"sample","keySha256":"sample"},"etag":"sample","eventBasedHold":true,"generation":"123","id":"sample","kind":"sample","kmsKeyName":"sample","md5Hash":"sample","mediaLink":"sample","metadata":{"key":"sample"},"metageneration":"123","name":"sample","owner":{"entity":"sample","entityId":"sample"},"retentionExpirationTime":"2020-06-01T12:30:45Z","selfLink":"sample","size":"123","storageClass":"sample","temporaryHold":true,"timeCreated":"2020-06-01T12:30:45Z","timeDeleted":"2020-06-01T12:30:45Z","timeStorageClassUpdated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_object_access_control() {
+        round_trip::<ObjectAccessControl>(
+            r##"{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_object_access_controls() {
+        round_trip::<ObjectAccessControls>(
+            r##"{"items":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"kind":"sample"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_objects() {
+        round_trip::<Objects>(
+            r##"{"items":[{"acl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"bucket":"sample","cacheControl":"sample","componentCount":1,"contentDisposition":"sample","contentEncoding":"sample","contentLanguage":"sample","contentType":"sample","crc32c":"sample","customTime":"2020-06-01T12:30:45Z","customerEncryption":{"encryptionAlgorithm":"sample","keySha256":"sample"},"etag":"sample","eventBasedHold":true,"generation":"123","id":"sample","kind":"sample","kmsKeyName":"sample","md5Hash":"sample","mediaLink":"sample","metadata":{"key":"sample"},"metageneration":"123","name":"sample","owner":{"entity":"sample","entityId":"sample"},"retentionExpirationTime":"2020-06-01T12:30:45Z","selfLink":"sample","size":"123","storageClass":"sample","temporaryHold":true,"timeCreated":"2020-06-01T12:30:45Z","timeDeleted":"2020-06-01T12:30:45Z","timeStorageClassUpdated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z"}],"kind":"sample","nextPageToken":"sample","prefixes":["sample"]}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_policy() {
+        round_trip::<Policy>(
+            r##"{"bindings":[{"condition":{"description":"sample","expression":"sample","location":"sample","title":"sample"},"members":["sample"],"role":"sample"}],"etag":"sample","kind":"sample","resourceId":"sample","version":1}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_rewrite_response() {
+        round_trip::<RewriteResponse>(
+            r##"{"done":true,"kind":"sample","objectSize":"123","resource":{"acl":[{"bucket":"sample","domain":"sample","email":"sample","entity":"sample","entityId":"sample","etag":"sample","generation":"123","id":"sample","kind":"sample","object":"sample","projectTeam":{"projectNumber":"sample","team":"sample"},"role":"sample","selfLink":"sample"}],"bucket":"sample","cacheControl":"sample","componentCount":1,"contentDisposition":"sample","contentEncoding":"sample","contentLanguage":"sample","contentType":"sample","crc32c":"sample","customTime":"2020-06-01T12:30:45Z","customerEncryption":{"encryptionAlgorithm":"sample","keySha256":"sample"},"etag":"sample","eventBasedHold":true,"generation":"123","id":"sample","kind":"sample","kmsKeyName":"sample","md5Hash":"sample","mediaLink":"sample","metadata":{"key":"sample"},"metageneration":"123","name":"sample","owner":{"entity":"sample","entityId":"sample"},"retentionExpirationTime":"2020-06-01T12:30:45Z","selfLink":"sample","size":"123","storageClass":"sample","temporaryHold":true,"timeCreated":"2020-06-01T12:30:45Z","timeDeleted":"2020-06-01T12:30:45Z","timeStorageClassUpdated":"2020-06-01T12:30:45Z","updated":"2020-06-01T12:30:45Z"},"rewriteToken":"sample","totalBytesRewritten":"123"}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_service_account() {
+        round_trip::<ServiceAccount>(r##"{"email_address":"sample","kind":"sample"}"##);
+    }
+
+    #[test]
+    fn round_trip_test_iam_permissions_response() {
+        round_trip::<TestIamPermissionsResponse>(r##"{"kind":"sample","permissions":["sample"]}"##);
+    }
+}
+
 /// Parameters for the `bucketAccessControls.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1139,28 +1412,51 @@ pub struct BucketAccessControlsDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketAccessControlsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketAccessControlsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketAccessControlsDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `bucketAccessControls.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1179,28 +1475,51 @@ pub struct BucketAccessControlsGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketAccessControlsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketAccessControlsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketAccessControlsGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `bucketAccessControls.insert` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlsInsertParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1216,28 +1535,46 @@ pub struct BucketAccessControlsInsertParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketAccessControlsInsertParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketAccessControlsInsertParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketAccessControlsInsertParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `bucketAccessControls.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1253,28 +1590,46 @@ pub struct BucketAccessControlsListParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketAccessControlsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketAccessControlsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketAccessControlsListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `bucketAccessControls.patch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlsPatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1293,28 +1648,51 @@ pub struct BucketAccessControlsPatchParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketAccessControlsPatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketAccessControlsPatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketAccessControlsPatchParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `bucketAccessControls.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketAccessControlsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1333,28 +1711,51 @@ pub struct BucketAccessControlsUpdateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketAccessControlsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketAccessControlsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketAccessControlsUpdateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `buckets.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1376,42 +1777,132 @@ pub struct BucketsDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl BucketsDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsGetParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit owner, acl and defaultObjectAcl properties.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsGetParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsGetParamsProjection::Full => "full",
+            BucketsGetParamsProjection::NoAcl => "noAcl",
+            BucketsGetParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsGetParamsProjection {
+    fn default() -> BucketsGetParamsProjection {
+        BucketsGetParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsGetParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsGetParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsGetParamsProjection {
+    fn from(v: &str) -> BucketsGetParamsProjection {
+        match v {
+            "full" => BucketsGetParamsProjection::Full,
+            "noAcl" => BucketsGetParamsProjection::NoAcl,
+            _ => BucketsGetParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for BucketsGetParamsProjection {
+    fn from(v: String) -> BucketsGetParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsGetParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsGetParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsGetParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `buckets.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1427,7 +1918,7 @@ pub struct BucketsGetParams {
     pub if_metageneration_not_match: Option<String>,
     /// Set of properties to return. Defaults to noAcl.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<BucketsGetParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -1436,49 +1927,70 @@ pub struct BucketsGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketsGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<BucketsGetParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `buckets.getIamPolicy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsGetIamPolicyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1497,107 +2009,458 @@ pub struct BucketsGetIamPolicyParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsGetIamPolicyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketsGetIamPolicyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.options_requested_policy_version {
-            write!(
-                f,
-                "&optionsRequestedPolicyVersion={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("optionsRequestedPolicyVersion", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
     }
 }
 
-/// Parameters for the `buckets.insert` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct BucketsInsertParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
-    pub storage_params: Option<StorageParams>,
-    /// Apply a predefined set of access controls to this bucket.
-    #[serde(rename = "predefinedAcl")]
-    pub predefined_acl: Option<String>,
-    /// Apply a predefined set of default object access controls to this bucket.
-    #[serde(rename = "predefinedDefaultObjectAcl")]
-    pub predefined_default_object_acl: Option<String>,
-    /// A valid API project identifier.
-    #[serde(rename = "project")]
-    pub project: String,
-    /// Set of properties to return. Defaults to noAcl, unless the bucket resource specifies acl or defaultObjectAcl properties, when it defaults to full.
-    #[serde(rename = "projection")]
-    pub projection: Option<String>,
-    /// The project to be billed for this request if the target bucket is requester-pays bucket.
-    #[serde(rename = "provisionalUserProject")]
-    pub provisional_user_project: Option<String>,
-    /// The project to be billed for this request.
-    #[serde(rename = "userProject")]
-    pub user_project: Option<String>,
+impl BucketsGetIamPolicyParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `options_requested_policy_version`.
+    pub fn options_requested_policy_version<T: Into<i32>>(mut self, value: T) -> Self {
+        self.options_requested_policy_version = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
 }
 
-impl std::fmt::Display for BucketsInsertParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&project={}",
-            percent_encode(format!("{}", self.project).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
-        if let Some(ref v) = self.predefined_acl {
-            write!(
-                f,
-                "&predefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.predefined_default_object_acl {
-            write!(
-                f,
-                "&predefinedDefaultObjectAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+/// Apply a predefined set of access controls to this bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsInsertParamsPredefinedAcl {
+    /// Project team owners get OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Project team owners get OWNER access.
+    Private,
+    /// Project team members get access according to their roles.
+    ProjectPrivate,
+    /// Project team owners get OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// Project team owners get OWNER access, and allUsers get WRITER access.
+    PublicReadWrite,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsInsertParamsPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsInsertParamsPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            BucketsInsertParamsPredefinedAcl::Private => "private",
+            BucketsInsertParamsPredefinedAcl::ProjectPrivate => "projectPrivate",
+            BucketsInsertParamsPredefinedAcl::PublicRead => "publicRead",
+            BucketsInsertParamsPredefinedAcl::PublicReadWrite => "publicReadWrite",
+            BucketsInsertParamsPredefinedAcl::Other(v) => v.as_str(),
         }
-        Ok(())
     }
 }
 
-/// Parameters for the `buckets.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+impl Default for BucketsInsertParamsPredefinedAcl {
+    fn default() -> BucketsInsertParamsPredefinedAcl {
+        BucketsInsertParamsPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsInsertParamsPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsInsertParamsPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsInsertParamsPredefinedAcl {
+    fn from(v: &str) -> BucketsInsertParamsPredefinedAcl {
+        match v {
+            "authenticatedRead" => BucketsInsertParamsPredefinedAcl::AuthenticatedRead,
+            "private" => BucketsInsertParamsPredefinedAcl::Private,
+            "projectPrivate" => BucketsInsertParamsPredefinedAcl::ProjectPrivate,
+            "publicRead" => BucketsInsertParamsPredefinedAcl::PublicRead,
+            "publicReadWrite" => BucketsInsertParamsPredefinedAcl::PublicReadWrite,
+            _ => BucketsInsertParamsPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsInsertParamsPredefinedAcl {
+    fn from(v: String) -> BucketsInsertParamsPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsInsertParamsPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsInsertParamsPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsInsertParamsPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Apply a predefined set of default object access controls to this bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsInsertParamsPredefinedDefaultObjectAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsInsertParamsPredefinedDefaultObjectAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsInsertParamsPredefinedDefaultObjectAcl::AuthenticatedRead => "authenticatedRead",
+            BucketsInsertParamsPredefinedDefaultObjectAcl::BucketOwnerFullControl => {
+                "bucketOwnerFullControl"
+            }
+            BucketsInsertParamsPredefinedDefaultObjectAcl::BucketOwnerRead => "bucketOwnerRead",
+            BucketsInsertParamsPredefinedDefaultObjectAcl::Private => "private",
+            BucketsInsertParamsPredefinedDefaultObjectAcl::ProjectPrivate => "projectPrivate",
+            BucketsInsertParamsPredefinedDefaultObjectAcl::PublicRead => "publicRead",
+            BucketsInsertParamsPredefinedDefaultObjectAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn default() -> BucketsInsertParamsPredefinedDefaultObjectAcl {
+        BucketsInsertParamsPredefinedDefaultObjectAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn from(v: &str) -> BucketsInsertParamsPredefinedDefaultObjectAcl {
+        match v {
+            "authenticatedRead" => BucketsInsertParamsPredefinedDefaultObjectAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => {
+                BucketsInsertParamsPredefinedDefaultObjectAcl::BucketOwnerFullControl
+            }
+            "bucketOwnerRead" => BucketsInsertParamsPredefinedDefaultObjectAcl::BucketOwnerRead,
+            "private" => BucketsInsertParamsPredefinedDefaultObjectAcl::Private,
+            "projectPrivate" => BucketsInsertParamsPredefinedDefaultObjectAcl::ProjectPrivate,
+            "publicRead" => BucketsInsertParamsPredefinedDefaultObjectAcl::PublicRead,
+            _ => BucketsInsertParamsPredefinedDefaultObjectAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn from(v: String) -> BucketsInsertParamsPredefinedDefaultObjectAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsInsertParamsPredefinedDefaultObjectAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsInsertParamsPredefinedDefaultObjectAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl, unless the bucket resource specifies acl or defaultObjectAcl properties, when it defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsInsertParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit owner, acl and defaultObjectAcl properties.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsInsertParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsInsertParamsProjection::Full => "full",
+            BucketsInsertParamsProjection::NoAcl => "noAcl",
+            BucketsInsertParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsInsertParamsProjection {
+    fn default() -> BucketsInsertParamsProjection {
+        BucketsInsertParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsInsertParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsInsertParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsInsertParamsProjection {
+    fn from(v: &str) -> BucketsInsertParamsProjection {
+        match v {
+            "full" => BucketsInsertParamsProjection::Full,
+            "noAcl" => BucketsInsertParamsProjection::NoAcl,
+            _ => BucketsInsertParamsProjection::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsInsertParamsProjection {
+    fn from(v: String) -> BucketsInsertParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsInsertParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsInsertParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsInsertParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Parameters for the `buckets.insert` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct BucketsInsertParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
+    pub storage_params: Option<StorageParams>,
+    /// Apply a predefined set of access controls to this bucket.
+    #[serde(rename = "predefinedAcl")]
+    pub predefined_acl: Option<BucketsInsertParamsPredefinedAcl>,
+    /// Apply a predefined set of default object access controls to this bucket.
+    #[serde(rename = "predefinedDefaultObjectAcl")]
+    pub predefined_default_object_acl: Option<BucketsInsertParamsPredefinedDefaultObjectAcl>,
+    /// A valid API project identifier.
+    #[serde(rename = "project")]
+    pub project: String,
+    /// Set of properties to return. Defaults to noAcl, unless the bucket resource specifies acl or defaultObjectAcl properties, when it defaults to full.
+    #[serde(rename = "projection")]
+    pub projection: Option<BucketsInsertParamsProjection>,
+    /// The project to be billed for this request if the target bucket is requester-pays bucket.
+    #[serde(rename = "provisionalUserProject")]
+    pub provisional_user_project: Option<String>,
+    /// The project to be billed for this request.
+    #[serde(rename = "userProject")]
+    pub user_project: Option<String>,
+}
+
+impl BucketsInsertParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("project", &self.project.to_string());
+        if let Some(ref v) = self.predefined_acl {
+            query.append_pair("predefinedAcl", &v.to_string());
+        }
+        if let Some(ref v) = self.predefined_default_object_acl {
+            query.append_pair("predefinedDefaultObjectAcl", &v.to_string());
+        }
+        if let Some(ref v) = self.projection {
+            query.append_pair("projection", &v.to_string());
+        }
+        if let Some(ref v) = self.provisional_user_project {
+            query.append_pair("provisionalUserProject", &v.to_string());
+        }
+        if let Some(ref v) = self.user_project {
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl BucketsInsertParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_acl`.
+    pub fn predefined_acl<T: Into<BucketsInsertParamsPredefinedAcl>>(mut self, value: T) -> Self {
+        self.predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_default_object_acl`.
+    pub fn predefined_default_object_acl<T: Into<BucketsInsertParamsPredefinedDefaultObjectAcl>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.predefined_default_object_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `project`.
+    pub fn project<T: Into<String>>(mut self, value: T) -> Self {
+        self.project = value.into();
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<BucketsInsertParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsListParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit owner, acl and defaultObjectAcl properties.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsListParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsListParamsProjection::Full => "full",
+            BucketsListParamsProjection::NoAcl => "noAcl",
+            BucketsListParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsListParamsProjection {
+    fn default() -> BucketsListParamsProjection {
+        BucketsListParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsListParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsListParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsListParamsProjection {
+    fn from(v: &str) -> BucketsListParamsProjection {
+        match v {
+            "full" => BucketsListParamsProjection::Full,
+            "noAcl" => BucketsListParamsProjection::NoAcl,
+            _ => BucketsListParamsProjection::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsListParamsProjection {
+    fn from(v: String) -> BucketsListParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsListParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsListParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsListParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Parameters for the `buckets.list` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1616,7 +2479,7 @@ pub struct BucketsListParams {
     pub project: String,
     /// Set of properties to return. Defaults to noAcl.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<BucketsListParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -1625,61 +2488,79 @@ pub struct BucketsListParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&project={}",
-            percent_encode(format!("{}", self.project).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
+impl BucketsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("project", &self.project.to_string());
         if let Some(ref v) = self.max_results {
-            write!(
-                f,
-                "&maxResults={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("maxResults", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.prefix {
-            write!(
-                f,
-                "&prefix={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("prefix", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketsListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `max_results`.
+    pub fn max_results<T: Into<u32>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `prefix`.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `project`.
+    pub fn project<T: Into<String>>(mut self, value: T) -> Self {
+        self.project = value.into();
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<BucketsListParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `buckets.lockRetentionPolicy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsLockRetentionPolicyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1698,37 +2579,297 @@ pub struct BucketsLockRetentionPolicyParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsLockRetentionPolicyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&ifMetagenerationMatch={}",
-            percent_encode(
-                format!("{}", self.if_metageneration_match).as_bytes(),
-                NON_ALPHANUMERIC
-            )
-            .to_string()
-        )?;
+impl BucketsLockRetentionPolicyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair(
+            "ifMetagenerationMatch",
+            &self.if_metageneration_match.to_string(),
+        );
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketsLockRetentionPolicyParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to this bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsPatchParamsPredefinedAcl {
+    /// Project team owners get OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Project team owners get OWNER access.
+    Private,
+    /// Project team members get access according to their roles.
+    ProjectPrivate,
+    /// Project team owners get OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// Project team owners get OWNER access, and allUsers get WRITER access.
+    PublicReadWrite,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsPatchParamsPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsPatchParamsPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            BucketsPatchParamsPredefinedAcl::Private => "private",
+            BucketsPatchParamsPredefinedAcl::ProjectPrivate => "projectPrivate",
+            BucketsPatchParamsPredefinedAcl::PublicRead => "publicRead",
+            BucketsPatchParamsPredefinedAcl::PublicReadWrite => "publicReadWrite",
+            BucketsPatchParamsPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsPatchParamsPredefinedAcl {
+    fn default() -> BucketsPatchParamsPredefinedAcl {
+        BucketsPatchParamsPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsPatchParamsPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsPatchParamsPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsPatchParamsPredefinedAcl {
+    fn from(v: &str) -> BucketsPatchParamsPredefinedAcl {
+        match v {
+            "authenticatedRead" => BucketsPatchParamsPredefinedAcl::AuthenticatedRead,
+            "private" => BucketsPatchParamsPredefinedAcl::Private,
+            "projectPrivate" => BucketsPatchParamsPredefinedAcl::ProjectPrivate,
+            "publicRead" => BucketsPatchParamsPredefinedAcl::PublicRead,
+            "publicReadWrite" => BucketsPatchParamsPredefinedAcl::PublicReadWrite,
+            _ => BucketsPatchParamsPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsPatchParamsPredefinedAcl {
+    fn from(v: String) -> BucketsPatchParamsPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsPatchParamsPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsPatchParamsPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsPatchParamsPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Apply a predefined set of default object access controls to this bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsPatchParamsPredefinedDefaultObjectAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsPatchParamsPredefinedDefaultObjectAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsPatchParamsPredefinedDefaultObjectAcl::AuthenticatedRead => "authenticatedRead",
+            BucketsPatchParamsPredefinedDefaultObjectAcl::BucketOwnerFullControl => {
+                "bucketOwnerFullControl"
+            }
+            BucketsPatchParamsPredefinedDefaultObjectAcl::BucketOwnerRead => "bucketOwnerRead",
+            BucketsPatchParamsPredefinedDefaultObjectAcl::Private => "private",
+            BucketsPatchParamsPredefinedDefaultObjectAcl::ProjectPrivate => "projectPrivate",
+            BucketsPatchParamsPredefinedDefaultObjectAcl::PublicRead => "publicRead",
+            BucketsPatchParamsPredefinedDefaultObjectAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn default() -> BucketsPatchParamsPredefinedDefaultObjectAcl {
+        BucketsPatchParamsPredefinedDefaultObjectAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn from(v: &str) -> BucketsPatchParamsPredefinedDefaultObjectAcl {
+        match v {
+            "authenticatedRead" => BucketsPatchParamsPredefinedDefaultObjectAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => {
+                BucketsPatchParamsPredefinedDefaultObjectAcl::BucketOwnerFullControl
+            }
+            "bucketOwnerRead" => BucketsPatchParamsPredefinedDefaultObjectAcl::BucketOwnerRead,
+            "private" => BucketsPatchParamsPredefinedDefaultObjectAcl::Private,
+            "projectPrivate" => BucketsPatchParamsPredefinedDefaultObjectAcl::ProjectPrivate,
+            "publicRead" => BucketsPatchParamsPredefinedDefaultObjectAcl::PublicRead,
+            _ => BucketsPatchParamsPredefinedDefaultObjectAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn from(v: String) -> BucketsPatchParamsPredefinedDefaultObjectAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsPatchParamsPredefinedDefaultObjectAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsPatchParamsPredefinedDefaultObjectAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsPatchParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit owner, acl and defaultObjectAcl properties.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsPatchParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsPatchParamsProjection::Full => "full",
+            BucketsPatchParamsProjection::NoAcl => "noAcl",
+            BucketsPatchParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsPatchParamsProjection {
+    fn default() -> BucketsPatchParamsProjection {
+        BucketsPatchParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsPatchParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsPatchParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsPatchParamsProjection {
+    fn from(v: &str) -> BucketsPatchParamsProjection {
+        match v {
+            "full" => BucketsPatchParamsProjection::Full,
+            "noAcl" => BucketsPatchParamsProjection::NoAcl,
+            _ => BucketsPatchParamsProjection::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsPatchParamsProjection {
+    fn from(v: String) -> BucketsPatchParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsPatchParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsPatchParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsPatchParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `buckets.patch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsPatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1744,13 +2885,13 @@ pub struct BucketsPatchParams {
     pub if_metageneration_not_match: Option<String>,
     /// Apply a predefined set of access controls to this bucket.
     #[serde(rename = "predefinedAcl")]
-    pub predefined_acl: Option<String>,
+    pub predefined_acl: Option<BucketsPatchParamsPredefinedAcl>,
     /// Apply a predefined set of default object access controls to this bucket.
     #[serde(rename = "predefinedDefaultObjectAcl")]
-    pub predefined_default_object_acl: Option<String>,
+    pub predefined_default_object_acl: Option<BucketsPatchParamsPredefinedDefaultObjectAcl>,
     /// Set of properties to return. Defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<BucketsPatchParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -1759,63 +2900,89 @@ pub struct BucketsPatchParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsPatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketsPatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.predefined_acl {
-            write!(
-                f,
-                "&predefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.predefined_default_object_acl {
-            write!(
-                f,
-                "&predefinedDefaultObjectAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedDefaultObjectAcl", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketsPatchParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_acl`.
+    pub fn predefined_acl<T: Into<BucketsPatchParamsPredefinedAcl>>(mut self, value: T) -> Self {
+        self.predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_default_object_acl`.
+    pub fn predefined_default_object_acl<T: Into<BucketsPatchParamsPredefinedDefaultObjectAcl>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.predefined_default_object_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<BucketsPatchParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `buckets.setIamPolicy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsSetIamPolicyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1831,28 +2998,46 @@ pub struct BucketsSetIamPolicyParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsSetIamPolicyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketsSetIamPolicyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketsSetIamPolicyParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `buckets.testIamPermissions` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsTestIamPermissionsParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1871,34 +3056,294 @@ pub struct BucketsTestIamPermissionsParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsTestIamPermissionsParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&permissions={}",
-            percent_encode(format!("{}", self.permissions).as_bytes(), NON_ALPHANUMERIC)
-                .to_string()
-        )?;
+impl BucketsTestIamPermissionsParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("permissions", &self.permissions.to_string());
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl BucketsTestIamPermissionsParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `permissions`.
+    pub fn permissions<T: Into<String>>(mut self, value: T) -> Self {
+        self.permissions = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to this bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsUpdateParamsPredefinedAcl {
+    /// Project team owners get OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Project team owners get OWNER access.
+    Private,
+    /// Project team members get access according to their roles.
+    ProjectPrivate,
+    /// Project team owners get OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// Project team owners get OWNER access, and allUsers get WRITER access.
+    PublicReadWrite,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsUpdateParamsPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsUpdateParamsPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            BucketsUpdateParamsPredefinedAcl::Private => "private",
+            BucketsUpdateParamsPredefinedAcl::ProjectPrivate => "projectPrivate",
+            BucketsUpdateParamsPredefinedAcl::PublicRead => "publicRead",
+            BucketsUpdateParamsPredefinedAcl::PublicReadWrite => "publicReadWrite",
+            BucketsUpdateParamsPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsUpdateParamsPredefinedAcl {
+    fn default() -> BucketsUpdateParamsPredefinedAcl {
+        BucketsUpdateParamsPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsUpdateParamsPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsUpdateParamsPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsUpdateParamsPredefinedAcl {
+    fn from(v: &str) -> BucketsUpdateParamsPredefinedAcl {
+        match v {
+            "authenticatedRead" => BucketsUpdateParamsPredefinedAcl::AuthenticatedRead,
+            "private" => BucketsUpdateParamsPredefinedAcl::Private,
+            "projectPrivate" => BucketsUpdateParamsPredefinedAcl::ProjectPrivate,
+            "publicRead" => BucketsUpdateParamsPredefinedAcl::PublicRead,
+            "publicReadWrite" => BucketsUpdateParamsPredefinedAcl::PublicReadWrite,
+            _ => BucketsUpdateParamsPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsUpdateParamsPredefinedAcl {
+    fn from(v: String) -> BucketsUpdateParamsPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsUpdateParamsPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsUpdateParamsPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsUpdateParamsPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Apply a predefined set of default object access controls to this bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::AuthenticatedRead => "authenticatedRead",
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::BucketOwnerFullControl => {
+                "bucketOwnerFullControl"
+            }
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::BucketOwnerRead => "bucketOwnerRead",
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::Private => "private",
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::ProjectPrivate => "projectPrivate",
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::PublicRead => "publicRead",
+            BucketsUpdateParamsPredefinedDefaultObjectAcl::Other(v) => v.as_str(),
         }
-        Ok(())
+    }
+}
+
+impl Default for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn default() -> BucketsUpdateParamsPredefinedDefaultObjectAcl {
+        BucketsUpdateParamsPredefinedDefaultObjectAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn from(v: &str) -> BucketsUpdateParamsPredefinedDefaultObjectAcl {
+        match v {
+            "authenticatedRead" => BucketsUpdateParamsPredefinedDefaultObjectAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => {
+                BucketsUpdateParamsPredefinedDefaultObjectAcl::BucketOwnerFullControl
+            }
+            "bucketOwnerRead" => BucketsUpdateParamsPredefinedDefaultObjectAcl::BucketOwnerRead,
+            "private" => BucketsUpdateParamsPredefinedDefaultObjectAcl::Private,
+            "projectPrivate" => BucketsUpdateParamsPredefinedDefaultObjectAcl::ProjectPrivate,
+            "publicRead" => BucketsUpdateParamsPredefinedDefaultObjectAcl::PublicRead,
+            _ => BucketsUpdateParamsPredefinedDefaultObjectAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn from(v: String) -> BucketsUpdateParamsPredefinedDefaultObjectAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsUpdateParamsPredefinedDefaultObjectAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsUpdateParamsPredefinedDefaultObjectAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BucketsUpdateParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit owner, acl and defaultObjectAcl properties.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl BucketsUpdateParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BucketsUpdateParamsProjection::Full => "full",
+            BucketsUpdateParamsProjection::NoAcl => "noAcl",
+            BucketsUpdateParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for BucketsUpdateParamsProjection {
+    fn default() -> BucketsUpdateParamsProjection {
+        BucketsUpdateParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for BucketsUpdateParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BucketsUpdateParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for BucketsUpdateParamsProjection {
+    fn from(v: &str) -> BucketsUpdateParamsProjection {
+        match v {
+            "full" => BucketsUpdateParamsProjection::Full,
+            "noAcl" => BucketsUpdateParamsProjection::NoAcl,
+            _ => BucketsUpdateParamsProjection::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for BucketsUpdateParamsProjection {
+    fn from(v: String) -> BucketsUpdateParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for BucketsUpdateParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BucketsUpdateParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<BucketsUpdateParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `buckets.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct BucketsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -1914,13 +3359,13 @@ pub struct BucketsUpdateParams {
     pub if_metageneration_not_match: Option<String>,
     /// Apply a predefined set of access controls to this bucket.
     #[serde(rename = "predefinedAcl")]
-    pub predefined_acl: Option<String>,
+    pub predefined_acl: Option<BucketsUpdateParamsPredefinedAcl>,
     /// Apply a predefined set of default object access controls to this bucket.
     #[serde(rename = "predefinedDefaultObjectAcl")]
-    pub predefined_default_object_acl: Option<String>,
+    pub predefined_default_object_acl: Option<BucketsUpdateParamsPredefinedDefaultObjectAcl>,
     /// Set of properties to return. Defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<BucketsUpdateParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -1929,77 +3374,114 @@ pub struct BucketsUpdateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for BucketsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl BucketsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.predefined_acl {
-            write!(
-                f,
-                "&predefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.predefined_default_object_acl {
-            write!(
-                f,
-                "&predefinedDefaultObjectAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedDefaultObjectAcl", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl BucketsUpdateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_acl`.
+    pub fn predefined_acl<T: Into<BucketsUpdateParamsPredefinedAcl>>(mut self, value: T) -> Self {
+        self.predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_default_object_acl`.
+    pub fn predefined_default_object_acl<T: Into<BucketsUpdateParamsPredefinedDefaultObjectAcl>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.predefined_default_object_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<BucketsUpdateParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `channels.stop` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ChannelsStopParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
     pub storage_params: Option<StorageParams>,
 }
 
-impl std::fmt::Display for ChannelsStopParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl ChannelsStopParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl ChannelsStopParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `defaultObjectAccessControls.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DefaultObjectAccessControlsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2018,28 +3500,51 @@ pub struct DefaultObjectAccessControlsDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for DefaultObjectAccessControlsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DefaultObjectAccessControlsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DefaultObjectAccessControlsDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `defaultObjectAccessControls.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DefaultObjectAccessControlsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2058,28 +3563,51 @@ pub struct DefaultObjectAccessControlsGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for DefaultObjectAccessControlsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DefaultObjectAccessControlsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DefaultObjectAccessControlsGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `defaultObjectAccessControls.insert` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DefaultObjectAccessControlsInsertParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2095,28 +3623,46 @@ pub struct DefaultObjectAccessControlsInsertParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for DefaultObjectAccessControlsInsertParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DefaultObjectAccessControlsInsertParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DefaultObjectAccessControlsInsertParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `defaultObjectAccessControls.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DefaultObjectAccessControlsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2138,42 +3684,62 @@ pub struct DefaultObjectAccessControlsListParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for DefaultObjectAccessControlsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DefaultObjectAccessControlsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DefaultObjectAccessControlsListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `defaultObjectAccessControls.patch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DefaultObjectAccessControlsPatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2192,28 +3758,51 @@ pub struct DefaultObjectAccessControlsPatchParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for DefaultObjectAccessControlsPatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DefaultObjectAccessControlsPatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DefaultObjectAccessControlsPatchParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `defaultObjectAccessControls.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct DefaultObjectAccessControlsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2232,28 +3821,51 @@ pub struct DefaultObjectAccessControlsUpdateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for DefaultObjectAccessControlsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DefaultObjectAccessControlsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl DefaultObjectAccessControlsUpdateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `notifications.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct NotificationsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2272,28 +3884,51 @@ pub struct NotificationsDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for NotificationsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl NotificationsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl NotificationsDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `notification`.
+    pub fn notification<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `notifications.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct NotificationsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2312,28 +3947,51 @@ pub struct NotificationsGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for NotificationsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl NotificationsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl NotificationsGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `notification`.
+    pub fn notification<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `notifications.insert` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct NotificationsInsertParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2349,28 +4007,46 @@ pub struct NotificationsInsertParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for NotificationsInsertParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl NotificationsInsertParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl NotificationsInsertParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `notifications.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct NotificationsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2386,28 +4062,46 @@ pub struct NotificationsListParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for NotificationsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl NotificationsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl NotificationsListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objectAccessControls.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2432,35 +4126,64 @@ pub struct ObjectAccessControlsDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectAccessControlsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectAccessControlsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectAccessControlsDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objectAccessControls.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2485,35 +4208,64 @@ pub struct ObjectAccessControlsGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectAccessControlsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectAccessControlsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectAccessControlsGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objectAccessControls.insert` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlsInsertParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2535,35 +4287,59 @@ pub struct ObjectAccessControlsInsertParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectAccessControlsInsertParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectAccessControlsInsertParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectAccessControlsInsertParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objectAccessControls.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2585,35 +4361,59 @@ pub struct ObjectAccessControlsListParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectAccessControlsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectAccessControlsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectAccessControlsListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objectAccessControls.patch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlsPatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2638,35 +4438,64 @@ pub struct ObjectAccessControlsPatchParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectAccessControlsPatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectAccessControlsPatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectAccessControlsPatchParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objectAccessControls.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectAccessControlsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2691,115 +4520,424 @@ pub struct ObjectAccessControlsUpdateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectAccessControlsUpdateParams {
+impl ObjectAccessControlsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        if let Some(ref v) = self.generation {
+            query.append_pair("generation", &v.to_string());
+        }
+        if let Some(ref v) = self.provisional_user_project {
+            query.append_pair("provisionalUserProject", &v.to_string());
+        }
+        if let Some(ref v) = self.user_project {
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectAccessControlsUpdateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `entity`.
+    pub fn entity<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to the destination object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsComposeParamsDestinationPredefinedAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsComposeParamsDestinationPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsComposeParamsDestinationPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            ObjectsComposeParamsDestinationPredefinedAcl::BucketOwnerFullControl => {
+                "bucketOwnerFullControl"
+            }
+            ObjectsComposeParamsDestinationPredefinedAcl::BucketOwnerRead => "bucketOwnerRead",
+            ObjectsComposeParamsDestinationPredefinedAcl::Private => "private",
+            ObjectsComposeParamsDestinationPredefinedAcl::ProjectPrivate => "projectPrivate",
+            ObjectsComposeParamsDestinationPredefinedAcl::PublicRead => "publicRead",
+            ObjectsComposeParamsDestinationPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn default() -> ObjectsComposeParamsDestinationPredefinedAcl {
+        ObjectsComposeParamsDestinationPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn from(v: &str) -> ObjectsComposeParamsDestinationPredefinedAcl {
+        match v {
+            "authenticatedRead" => ObjectsComposeParamsDestinationPredefinedAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => {
+                ObjectsComposeParamsDestinationPredefinedAcl::BucketOwnerFullControl
+            }
+            "bucketOwnerRead" => ObjectsComposeParamsDestinationPredefinedAcl::BucketOwnerRead,
+            "private" => ObjectsComposeParamsDestinationPredefinedAcl::Private,
+            "projectPrivate" => ObjectsComposeParamsDestinationPredefinedAcl::ProjectPrivate,
+            "publicRead" => ObjectsComposeParamsDestinationPredefinedAcl::PublicRead,
+            _ => ObjectsComposeParamsDestinationPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn from(v: String) -> ObjectsComposeParamsDestinationPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsComposeParamsDestinationPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsComposeParamsDestinationPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Parameters for the `objects.compose` method.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ObjectsComposeParams {
+    /// General attributes applying to any API call
+    #[serde(flatten)]
+    pub storage_params: Option<StorageParams>,
+    /// Name of the bucket containing the source objects. The destination object is stored in this bucket.
+    #[serde(rename = "destinationBucket")]
+    pub destination_bucket: String,
+    /// Name of the new object. For information about how to URL encode object names to be path safe, see Encoding URI Path Parts.
+    #[serde(rename = "destinationObject")]
+    pub destination_object: String,
+    /// Apply a predefined set of access controls to the destination object.
+    #[serde(rename = "destinationPredefinedAcl")]
+    pub destination_predefined_acl: Option<ObjectsComposeParamsDestinationPredefinedAcl>,
+    /// i64: Makes the operation conditional on whether the object's current generation matches the given value. Setting to 0 makes the operation succeed only if there are no live versions of the object.
+    #[serde(rename = "ifGenerationMatch")]
+    pub if_generation_match: Option<String>,
+    /// i64: Makes the operation conditional on whether the object's current metageneration matches the given value.
+    #[serde(rename = "ifMetagenerationMatch")]
+    pub if_metageneration_match: Option<String>,
+    /// Resource name of the Cloud KMS key, of the form projects/my-project/locations/global/keyRings/my-kr/cryptoKeys/my-key, that will be used to encrypt the object. Overrides the object metadata's kms_key_name value, if any.
+    #[serde(rename = "kmsKeyName")]
+    pub kms_key_name: Option<String>,
+    /// The project to be billed for this request if the target bucket is requester-pays bucket.
+    #[serde(rename = "provisionalUserProject")]
+    pub provisional_user_project: Option<String>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(rename = "userProject")]
+    pub user_project: Option<String>,
+}
+
+impl ObjectsComposeParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        if let Some(ref v) = self.destination_predefined_acl {
+            query.append_pair("destinationPredefinedAcl", &v.to_string());
+        }
+        if let Some(ref v) = self.if_generation_match {
+            query.append_pair("ifGenerationMatch", &v.to_string());
+        }
+        if let Some(ref v) = self.if_metageneration_match {
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
+        }
+        if let Some(ref v) = self.kms_key_name {
+            query.append_pair("kmsKeyName", &v.to_string());
+        }
+        if let Some(ref v) = self.provisional_user_project {
+            query.append_pair("provisionalUserProject", &v.to_string());
+        }
+        if let Some(ref v) = self.user_project {
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsComposeParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `destination_bucket`.
+    pub fn destination_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `destination_object`.
+    pub fn destination_object<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_object = value.into();
+        self
+    }
+    /// Builder-style setter for `destination_predefined_acl`.
+    pub fn destination_predefined_acl<T: Into<ObjectsComposeParamsDestinationPredefinedAcl>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.destination_predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `kms_key_name`.
+    pub fn kms_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_name = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to the destination object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsCopyParamsDestinationPredefinedAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsCopyParamsDestinationPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsCopyParamsDestinationPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            ObjectsCopyParamsDestinationPredefinedAcl::BucketOwnerFullControl => {
+                "bucketOwnerFullControl"
+            }
+            ObjectsCopyParamsDestinationPredefinedAcl::BucketOwnerRead => "bucketOwnerRead",
+            ObjectsCopyParamsDestinationPredefinedAcl::Private => "private",
+            ObjectsCopyParamsDestinationPredefinedAcl::ProjectPrivate => "projectPrivate",
+            ObjectsCopyParamsDestinationPredefinedAcl::PublicRead => "publicRead",
+            ObjectsCopyParamsDestinationPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn default() -> ObjectsCopyParamsDestinationPredefinedAcl {
+        ObjectsCopyParamsDestinationPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn from(v: &str) -> ObjectsCopyParamsDestinationPredefinedAcl {
+        match v {
+            "authenticatedRead" => ObjectsCopyParamsDestinationPredefinedAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => {
+                ObjectsCopyParamsDestinationPredefinedAcl::BucketOwnerFullControl
+            }
+            "bucketOwnerRead" => ObjectsCopyParamsDestinationPredefinedAcl::BucketOwnerRead,
+            "private" => ObjectsCopyParamsDestinationPredefinedAcl::Private,
+            "projectPrivate" => ObjectsCopyParamsDestinationPredefinedAcl::ProjectPrivate,
+            "publicRead" => ObjectsCopyParamsDestinationPredefinedAcl::PublicRead,
+            _ => ObjectsCopyParamsDestinationPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn from(v: String) -> ObjectsCopyParamsDestinationPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsCopyParamsDestinationPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsCopyParamsDestinationPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl, unless the object resource specifies the acl property, when it defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsCopyParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsCopyParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsCopyParamsProjection::Full => "full",
+            ObjectsCopyParamsProjection::NoAcl => "noAcl",
+            ObjectsCopyParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsCopyParamsProjection {
+    fn default() -> ObjectsCopyParamsProjection {
+        ObjectsCopyParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsCopyParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsCopyParamsProjection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsCopyParamsProjection {
+    fn from(v: &str) -> ObjectsCopyParamsProjection {
+        match v {
+            "full" => ObjectsCopyParamsProjection::Full,
+            "noAcl" => ObjectsCopyParamsProjection::NoAcl,
+            _ => ObjectsCopyParamsProjection::Other(v.to_string()),
         }
-        Ok(())
     }
 }
 
-/// Parameters for the `objects.compose` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
-pub struct ObjectsComposeParams {
-    /// General attributes applying to any API call
-    #[serde(flatten)]
-    pub storage_params: Option<StorageParams>,
-    /// Name of the bucket containing the source objects. The destination object is stored in this bucket.
-    #[serde(rename = "destinationBucket")]
-    pub destination_bucket: String,
-    /// Name of the new object. For information about how to URL encode object names to be path safe, see Encoding URI Path Parts.
-    #[serde(rename = "destinationObject")]
-    pub destination_object: String,
-    /// Apply a predefined set of access controls to the destination object.
-    #[serde(rename = "destinationPredefinedAcl")]
-    pub destination_predefined_acl: Option<String>,
-    /// i64: Makes the operation conditional on whether the object's current generation matches the given value. Setting to 0 makes the operation succeed only if there are no live versions of the object.
-    #[serde(rename = "ifGenerationMatch")]
-    pub if_generation_match: Option<String>,
-    /// i64: Makes the operation conditional on whether the object's current metageneration matches the given value.
-    #[serde(rename = "ifMetagenerationMatch")]
-    pub if_metageneration_match: Option<String>,
-    /// Resource name of the Cloud KMS key, of the form projects/my-project/locations/global/keyRings/my-kr/cryptoKeys/my-key, that will be used to encrypt the object. Overrides the object metadata's kms_key_name value, if any.
-    #[serde(rename = "kmsKeyName")]
-    pub kms_key_name: Option<String>,
-    /// The project to be billed for this request if the target bucket is requester-pays bucket.
-    #[serde(rename = "provisionalUserProject")]
-    pub provisional_user_project: Option<String>,
-    /// The project to be billed for this request. Required for Requester Pays buckets.
-    #[serde(rename = "userProject")]
-    pub user_project: Option<String>,
+impl From<String> for ObjectsCopyParamsProjection {
+    fn from(v: String) -> ObjectsCopyParamsProjection {
+        v.as_str().into()
+    }
 }
 
-impl std::fmt::Display for ObjectsComposeParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(ref v) = self.destination_predefined_acl {
-            write!(
-                f,
-                "&destinationPredefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.kms_key_name {
-            write!(
-                f,
-                "&kmsKeyName={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        Ok(())
+impl Serialize for ObjectsCopyParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsCopyParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsCopyParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.copy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsCopyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2815,7 +4953,7 @@ pub struct ObjectsCopyParams {
     pub destination_object: String,
     /// Apply a predefined set of access controls to the destination object.
     #[serde(rename = "destinationPredefinedAcl")]
-    pub destination_predefined_acl: Option<String>,
+    pub destination_predefined_acl: Option<ObjectsCopyParamsDestinationPredefinedAcl>,
     /// i64: Makes the operation conditional on whether the destination object's current generation matches the given value. Setting to 0 makes the operation succeed only if there are no live versions of the object.
     #[serde(rename = "ifGenerationMatch")]
     pub if_generation_match: Option<String>,
@@ -2842,7 +4980,7 @@ pub struct ObjectsCopyParams {
     pub if_source_metageneration_not_match: Option<String>,
     /// Set of properties to return. Defaults to noAcl, unless the object resource specifies the acl property, when it defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsCopyParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -2860,112 +4998,160 @@ pub struct ObjectsCopyParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsCopyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsCopyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.destination_kms_key_name {
-            write!(
-                f,
-                "&destinationKmsKeyName={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("destinationKmsKeyName", &v.to_string());
         }
         if let Some(ref v) = self.destination_predefined_acl {
-            write!(
-                f,
-                "&destinationPredefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("destinationPredefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_generation_match {
-            write!(
-                f,
-                "&ifSourceGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_generation_not_match {
-            write!(
-                f,
-                "&ifSourceGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_metageneration_match {
-            write!(
-                f,
-                "&ifSourceMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_metageneration_not_match {
-            write!(
-                f,
-                "&ifSourceMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.source_generation {
-            write!(
-                f,
-                "&sourceGeneration={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("sourceGeneration", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        Ok(())
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsCopyParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `destination_bucket`.
+    pub fn destination_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `destination_kms_key_name`.
+    pub fn destination_kms_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_kms_key_name = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `destination_object`.
+    pub fn destination_object<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_object = value.into();
+        self
+    }
+    /// Builder-style setter for `destination_predefined_acl`.
+    pub fn destination_predefined_acl<T: Into<ObjectsCopyParamsDestinationPredefinedAcl>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.destination_predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_generation_match`.
+    pub fn if_source_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_generation_not_match`.
+    pub fn if_source_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_metageneration_match`.
+    pub fn if_source_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_metageneration_not_match`.
+    pub fn if_source_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsCopyParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `source_bucket`.
+    pub fn source_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `source_generation`.
+    pub fn source_generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `source_object`.
+    pub fn source_object<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_object = value.into();
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objects.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -2999,63 +5185,161 @@ pub struct ObjectsDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsGetParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsGetParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsGetParamsProjection::Full => "full",
+            ObjectsGetParamsProjection::NoAcl => "noAcl",
+            ObjectsGetParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsGetParamsProjection {
+    fn default() -> ObjectsGetParamsProjection {
+        ObjectsGetParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsGetParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsGetParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsGetParamsProjection {
+    fn from(v: &str) -> ObjectsGetParamsProjection {
+        match v {
+            "full" => ObjectsGetParamsProjection::Full,
+            "noAcl" => ObjectsGetParamsProjection::NoAcl,
+            _ => ObjectsGetParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsGetParamsProjection {
+    fn from(v: String) -> ObjectsGetParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsGetParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsGetParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsGetParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3083,7 +5367,7 @@ pub struct ObjectsGetParams {
     pub object: String,
     /// Set of properties to return. Defaults to noAcl.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsGetParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -3092,70 +5376,99 @@ pub struct ObjectsGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectsGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsGetParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objects.getIamPolicy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsGetIamPolicyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3177,35 +5490,215 @@ pub struct ObjectsGetIamPolicyParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsGetIamPolicyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsGetIamPolicyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsGetIamPolicyParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to this object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsInsertParamsPredefinedAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsInsertParamsPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsInsertParamsPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            ObjectsInsertParamsPredefinedAcl::BucketOwnerFullControl => "bucketOwnerFullControl",
+            ObjectsInsertParamsPredefinedAcl::BucketOwnerRead => "bucketOwnerRead",
+            ObjectsInsertParamsPredefinedAcl::Private => "private",
+            ObjectsInsertParamsPredefinedAcl::ProjectPrivate => "projectPrivate",
+            ObjectsInsertParamsPredefinedAcl::PublicRead => "publicRead",
+            ObjectsInsertParamsPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsInsertParamsPredefinedAcl {
+    fn default() -> ObjectsInsertParamsPredefinedAcl {
+        ObjectsInsertParamsPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsInsertParamsPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsInsertParamsPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsInsertParamsPredefinedAcl {
+    fn from(v: &str) -> ObjectsInsertParamsPredefinedAcl {
+        match v {
+            "authenticatedRead" => ObjectsInsertParamsPredefinedAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => ObjectsInsertParamsPredefinedAcl::BucketOwnerFullControl,
+            "bucketOwnerRead" => ObjectsInsertParamsPredefinedAcl::BucketOwnerRead,
+            "private" => ObjectsInsertParamsPredefinedAcl::Private,
+            "projectPrivate" => ObjectsInsertParamsPredefinedAcl::ProjectPrivate,
+            "publicRead" => ObjectsInsertParamsPredefinedAcl::PublicRead,
+            _ => ObjectsInsertParamsPredefinedAcl::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsInsertParamsPredefinedAcl {
+    fn from(v: String) -> ObjectsInsertParamsPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsInsertParamsPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsInsertParamsPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsInsertParamsPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl, unless the object resource specifies the acl property, when it defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsInsertParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsInsertParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsInsertParamsProjection::Full => "full",
+            ObjectsInsertParamsProjection::NoAcl => "noAcl",
+            ObjectsInsertParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsInsertParamsProjection {
+    fn default() -> ObjectsInsertParamsProjection {
+        ObjectsInsertParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsInsertParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsInsertParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsInsertParamsProjection {
+    fn from(v: &str) -> ObjectsInsertParamsProjection {
+        match v {
+            "full" => ObjectsInsertParamsProjection::Full,
+            "noAcl" => ObjectsInsertParamsProjection::NoAcl,
+            _ => ObjectsInsertParamsProjection::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ObjectsInsertParamsProjection {
+    fn from(v: String) -> ObjectsInsertParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsInsertParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsInsertParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsInsertParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.insert` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsInsertParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3236,10 +5729,10 @@ pub struct ObjectsInsertParams {
     pub name: Option<String>,
     /// Apply a predefined set of access controls to this object.
     #[serde(rename = "predefinedAcl")]
-    pub predefined_acl: Option<String>,
+    pub predefined_acl: Option<ObjectsInsertParamsPredefinedAcl>,
     /// Set of properties to return. Defaults to noAcl, unless the object resource specifies the acl property, when it defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsInsertParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -3248,91 +5741,188 @@ pub struct ObjectsInsertParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsInsertParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsInsertParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.content_encoding {
-            write!(
-                f,
-                "&contentEncoding={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("contentEncoding", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.kms_key_name {
-            write!(
-                f,
-                "&kmsKeyName={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("kmsKeyName", &v.to_string());
         }
         if let Some(ref v) = self.name {
-            write!(
-                f,
-                "&name={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("name", &v.to_string());
         }
         if let Some(ref v) = self.predefined_acl {
-            write!(
-                f,
-                "&predefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsInsertParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `content_encoding`.
+    pub fn content_encoding<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_encoding = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `kms_key_name`.
+    pub fn kms_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_name = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `name`.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `predefined_acl`.
+    pub fn predefined_acl<T: Into<ObjectsInsertParamsPredefinedAcl>>(mut self, value: T) -> Self {
+        self.predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsInsertParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsListParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsListParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsListParamsProjection::Full => "full",
+            ObjectsListParamsProjection::NoAcl => "noAcl",
+            ObjectsListParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsListParamsProjection {
+    fn default() -> ObjectsListParamsProjection {
+        ObjectsListParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsListParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsListParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsListParamsProjection {
+    fn from(v: &str) -> ObjectsListParamsProjection {
+        match v {
+            "full" => ObjectsListParamsProjection::Full,
+            "noAcl" => ObjectsListParamsProjection::NoAcl,
+            _ => ObjectsListParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsListParamsProjection {
+    fn from(v: String) -> ObjectsListParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsListParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsListParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsListParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3360,7 +5950,7 @@ pub struct ObjectsListParams {
     pub prefix: Option<String>,
     /// Set of properties to return. Defaults to noAcl.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsListParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -3375,91 +5965,274 @@ pub struct ObjectsListParams {
     pub versions: Option<bool>,
 }
 
-impl std::fmt::Display for ObjectsListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(ref v) = self.delimiter {
-            write!(
-                f,
-                "&delimiter={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.end_offset {
-            write!(
-                f,
-                "&endOffset={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.include_trailing_delimiter {
-            write!(
-                f,
-                "&includeTrailingDelimiter={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.max_results {
-            write!(
-                f,
-                "&maxResults={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.prefix {
-            write!(
-                f,
-                "&prefix={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.start_offset {
-            write!(
-                f,
-                "&startOffset={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+impl ObjectsListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        if let Some(ref v) = self.delimiter {
+            query.append_pair("delimiter", &v.to_string());
+        }
+        if let Some(ref v) = self.end_offset {
+            query.append_pair("endOffset", &v.to_string());
+        }
+        if let Some(ref v) = self.include_trailing_delimiter {
+            query.append_pair("includeTrailingDelimiter", &v.to_string());
+        }
+        if let Some(ref v) = self.max_results {
+            query.append_pair("maxResults", &v.to_string());
+        }
+        if let Some(ref v) = self.page_token {
+            query.append_pair("pageToken", &v.to_string());
+        }
+        if let Some(ref v) = self.prefix {
+            query.append_pair("prefix", &v.to_string());
+        }
+        if let Some(ref v) = self.projection {
+            query.append_pair("projection", &v.to_string());
+        }
+        if let Some(ref v) = self.provisional_user_project {
+            query.append_pair("provisionalUserProject", &v.to_string());
+        }
+        if let Some(ref v) = self.start_offset {
+            query.append_pair("startOffset", &v.to_string());
+        }
+        if let Some(ref v) = self.user_project {
+            query.append_pair("userProject", &v.to_string());
+        }
+        if let Some(ref v) = self.versions {
+            query.append_pair("versions", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `delimiter`.
+    pub fn delimiter<T: Into<String>>(mut self, value: T) -> Self {
+        self.delimiter = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `end_offset`.
+    pub fn end_offset<T: Into<String>>(mut self, value: T) -> Self {
+        self.end_offset = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_trailing_delimiter`.
+    pub fn include_trailing_delimiter<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_trailing_delimiter = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `max_results`.
+    pub fn max_results<T: Into<u32>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `prefix`.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsListParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `start_offset`.
+    pub fn start_offset<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_offset = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `versions`.
+    pub fn versions<T: Into<bool>>(mut self, value: T) -> Self {
+        self.versions = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to this object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsPatchParamsPredefinedAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsPatchParamsPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsPatchParamsPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            ObjectsPatchParamsPredefinedAcl::BucketOwnerFullControl => "bucketOwnerFullControl",
+            ObjectsPatchParamsPredefinedAcl::BucketOwnerRead => "bucketOwnerRead",
+            ObjectsPatchParamsPredefinedAcl::Private => "private",
+            ObjectsPatchParamsPredefinedAcl::ProjectPrivate => "projectPrivate",
+            ObjectsPatchParamsPredefinedAcl::PublicRead => "publicRead",
+            ObjectsPatchParamsPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsPatchParamsPredefinedAcl {
+    fn default() -> ObjectsPatchParamsPredefinedAcl {
+        ObjectsPatchParamsPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsPatchParamsPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsPatchParamsPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsPatchParamsPredefinedAcl {
+    fn from(v: &str) -> ObjectsPatchParamsPredefinedAcl {
+        match v {
+            "authenticatedRead" => ObjectsPatchParamsPredefinedAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => ObjectsPatchParamsPredefinedAcl::BucketOwnerFullControl,
+            "bucketOwnerRead" => ObjectsPatchParamsPredefinedAcl::BucketOwnerRead,
+            "private" => ObjectsPatchParamsPredefinedAcl::Private,
+            "projectPrivate" => ObjectsPatchParamsPredefinedAcl::ProjectPrivate,
+            "publicRead" => ObjectsPatchParamsPredefinedAcl::PublicRead,
+            _ => ObjectsPatchParamsPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ObjectsPatchParamsPredefinedAcl {
+    fn from(v: String) -> ObjectsPatchParamsPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsPatchParamsPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsPatchParamsPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsPatchParamsPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsPatchParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsPatchParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsPatchParamsProjection::Full => "full",
+            ObjectsPatchParamsProjection::NoAcl => "noAcl",
+            ObjectsPatchParamsProjection::Other(v) => v.as_str(),
         }
-        if let Some(ref v) = self.versions {
-            write!(
-                f,
-                "&versions={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+    }
+}
+
+impl Default for ObjectsPatchParamsProjection {
+    fn default() -> ObjectsPatchParamsProjection {
+        ObjectsPatchParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsPatchParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsPatchParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsPatchParamsProjection {
+    fn from(v: &str) -> ObjectsPatchParamsProjection {
+        match v {
+            "full" => ObjectsPatchParamsProjection::Full,
+            "noAcl" => ObjectsPatchParamsProjection::NoAcl,
+            _ => ObjectsPatchParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsPatchParamsProjection {
+    fn from(v: String) -> ObjectsPatchParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsPatchParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsPatchParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsPatchParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.patch` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsPatchParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3487,10 +6260,10 @@ pub struct ObjectsPatchParams {
     pub object: String,
     /// Apply a predefined set of access controls to this object.
     #[serde(rename = "predefinedAcl")]
-    pub predefined_acl: Option<String>,
+    pub predefined_acl: Option<ObjectsPatchParamsPredefinedAcl>,
     /// Set of properties to return. Defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsPatchParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -3499,77 +6272,267 @@ pub struct ObjectsPatchParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsPatchParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsPatchParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.predefined_acl {
-            write!(
-                f,
-                "&predefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsPatchParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `predefined_acl`.
+    pub fn predefined_acl<T: Into<ObjectsPatchParamsPredefinedAcl>>(mut self, value: T) -> Self {
+        self.predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsPatchParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to the destination object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsRewriteParamsDestinationPredefinedAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsRewriteParamsDestinationPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsRewriteParamsDestinationPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            ObjectsRewriteParamsDestinationPredefinedAcl::BucketOwnerFullControl => {
+                "bucketOwnerFullControl"
+            }
+            ObjectsRewriteParamsDestinationPredefinedAcl::BucketOwnerRead => "bucketOwnerRead",
+            ObjectsRewriteParamsDestinationPredefinedAcl::Private => "private",
+            ObjectsRewriteParamsDestinationPredefinedAcl::ProjectPrivate => "projectPrivate",
+            ObjectsRewriteParamsDestinationPredefinedAcl::PublicRead => "publicRead",
+            ObjectsRewriteParamsDestinationPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn default() -> ObjectsRewriteParamsDestinationPredefinedAcl {
+        ObjectsRewriteParamsDestinationPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn from(v: &str) -> ObjectsRewriteParamsDestinationPredefinedAcl {
+        match v {
+            "authenticatedRead" => ObjectsRewriteParamsDestinationPredefinedAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => {
+                ObjectsRewriteParamsDestinationPredefinedAcl::BucketOwnerFullControl
+            }
+            "bucketOwnerRead" => ObjectsRewriteParamsDestinationPredefinedAcl::BucketOwnerRead,
+            "private" => ObjectsRewriteParamsDestinationPredefinedAcl::Private,
+            "projectPrivate" => ObjectsRewriteParamsDestinationPredefinedAcl::ProjectPrivate,
+            "publicRead" => ObjectsRewriteParamsDestinationPredefinedAcl::PublicRead,
+            _ => ObjectsRewriteParamsDestinationPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn from(v: String) -> ObjectsRewriteParamsDestinationPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsRewriteParamsDestinationPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsRewriteParamsDestinationPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl, unless the object resource specifies the acl property, when it defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsRewriteParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsRewriteParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsRewriteParamsProjection::Full => "full",
+            ObjectsRewriteParamsProjection::NoAcl => "noAcl",
+            ObjectsRewriteParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsRewriteParamsProjection {
+    fn default() -> ObjectsRewriteParamsProjection {
+        ObjectsRewriteParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsRewriteParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsRewriteParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsRewriteParamsProjection {
+    fn from(v: &str) -> ObjectsRewriteParamsProjection {
+        match v {
+            "full" => ObjectsRewriteParamsProjection::Full,
+            "noAcl" => ObjectsRewriteParamsProjection::NoAcl,
+            _ => ObjectsRewriteParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsRewriteParamsProjection {
+    fn from(v: String) -> ObjectsRewriteParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsRewriteParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsRewriteParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsRewriteParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.rewrite` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsRewriteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3585,7 +6548,7 @@ pub struct ObjectsRewriteParams {
     pub destination_object: String,
     /// Apply a predefined set of access controls to the destination object.
     #[serde(rename = "destinationPredefinedAcl")]
-    pub destination_predefined_acl: Option<String>,
+    pub destination_predefined_acl: Option<ObjectsRewriteParamsDestinationPredefinedAcl>,
     /// i64: Makes the operation conditional on whether the object's current generation matches the given value. Setting to 0 makes the operation succeed only if there are no live versions of the object.
     #[serde(rename = "ifGenerationMatch")]
     pub if_generation_match: Option<String>,
@@ -3615,7 +6578,7 @@ pub struct ObjectsRewriteParams {
     pub max_bytes_rewritten_per_call: Option<String>,
     /// Set of properties to return. Defaults to noAcl, unless the object resource specifies the acl property, when it defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsRewriteParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -3636,126 +6599,176 @@ pub struct ObjectsRewriteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsRewriteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsRewriteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.destination_kms_key_name {
-            write!(
-                f,
-                "&destinationKmsKeyName={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("destinationKmsKeyName", &v.to_string());
         }
         if let Some(ref v) = self.destination_predefined_acl {
-            write!(
-                f,
-                "&destinationPredefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("destinationPredefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_generation_match {
-            write!(
-                f,
-                "&ifSourceGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_generation_not_match {
-            write!(
-                f,
-                "&ifSourceGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_metageneration_match {
-            write!(
-                f,
-                "&ifSourceMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_source_metageneration_not_match {
-            write!(
-                f,
-                "&ifSourceMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifSourceMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.max_bytes_rewritten_per_call {
-            write!(
-                f,
-                "&maxBytesRewrittenPerCall={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("maxBytesRewrittenPerCall", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.rewrite_token {
-            write!(
-                f,
-                "&rewriteToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("rewriteToken", &v.to_string());
         }
         if let Some(ref v) = self.source_generation {
-            write!(
-                f,
-                "&sourceGeneration={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("sourceGeneration", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
-        }
-        Ok(())
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsRewriteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `destination_bucket`.
+    pub fn destination_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `destination_kms_key_name`.
+    pub fn destination_kms_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_kms_key_name = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `destination_object`.
+    pub fn destination_object<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_object = value.into();
+        self
+    }
+    /// Builder-style setter for `destination_predefined_acl`.
+    pub fn destination_predefined_acl<T: Into<ObjectsRewriteParamsDestinationPredefinedAcl>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.destination_predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_generation_match`.
+    pub fn if_source_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_generation_not_match`.
+    pub fn if_source_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_metageneration_match`.
+    pub fn if_source_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_source_metageneration_not_match`.
+    pub fn if_source_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_source_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `max_bytes_rewritten_per_call`.
+    pub fn max_bytes_rewritten_per_call<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_bytes_rewritten_per_call = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsRewriteParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `rewrite_token`.
+    pub fn rewrite_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.rewrite_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `source_bucket`.
+    pub fn source_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `source_generation`.
+    pub fn source_generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `source_object`.
+    pub fn source_object<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_object = value.into();
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objects.setIamPolicy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsSetIamPolicyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3777,35 +6790,59 @@ pub struct ObjectsSetIamPolicyParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsSetIamPolicyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsSetIamPolicyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectsSetIamPolicyParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `objects.testIamPermissions` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsTestIamPermissionsParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3830,41 +6867,221 @@ pub struct ObjectsTestIamPermissionsParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsTestIamPermissionsParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&permissions={}",
-            percent_encode(format!("{}", self.permissions).as_bytes(), NON_ALPHANUMERIC)
-                .to_string()
-        )?;
+impl ObjectsTestIamPermissionsParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("permissions", &self.permissions.to_string());
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsTestIamPermissionsParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `permissions`.
+    pub fn permissions<T: Into<String>>(mut self, value: T) -> Self {
+        self.permissions = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Apply a predefined set of access controls to this object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsUpdateParamsPredefinedAcl {
+    /// Object owner gets OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Object owner gets OWNER access, and project team owners get OWNER access.
+    BucketOwnerFullControl,
+    /// Object owner gets OWNER access, and project team owners get READER access.
+    BucketOwnerRead,
+    /// Object owner gets OWNER access.
+    Private,
+    /// Object owner gets OWNER access, and project team members get access according to their roles.
+    ProjectPrivate,
+    /// Object owner gets OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsUpdateParamsPredefinedAcl {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsUpdateParamsPredefinedAcl::AuthenticatedRead => "authenticatedRead",
+            ObjectsUpdateParamsPredefinedAcl::BucketOwnerFullControl => "bucketOwnerFullControl",
+            ObjectsUpdateParamsPredefinedAcl::BucketOwnerRead => "bucketOwnerRead",
+            ObjectsUpdateParamsPredefinedAcl::Private => "private",
+            ObjectsUpdateParamsPredefinedAcl::ProjectPrivate => "projectPrivate",
+            ObjectsUpdateParamsPredefinedAcl::PublicRead => "publicRead",
+            ObjectsUpdateParamsPredefinedAcl::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsUpdateParamsPredefinedAcl {
+    fn default() -> ObjectsUpdateParamsPredefinedAcl {
+        ObjectsUpdateParamsPredefinedAcl::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsUpdateParamsPredefinedAcl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsUpdateParamsPredefinedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsUpdateParamsPredefinedAcl {
+    fn from(v: &str) -> ObjectsUpdateParamsPredefinedAcl {
+        match v {
+            "authenticatedRead" => ObjectsUpdateParamsPredefinedAcl::AuthenticatedRead,
+            "bucketOwnerFullControl" => ObjectsUpdateParamsPredefinedAcl::BucketOwnerFullControl,
+            "bucketOwnerRead" => ObjectsUpdateParamsPredefinedAcl::BucketOwnerRead,
+            "private" => ObjectsUpdateParamsPredefinedAcl::Private,
+            "projectPrivate" => ObjectsUpdateParamsPredefinedAcl::ProjectPrivate,
+            "publicRead" => ObjectsUpdateParamsPredefinedAcl::PublicRead,
+            _ => ObjectsUpdateParamsPredefinedAcl::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for ObjectsUpdateParamsPredefinedAcl {
+    fn from(v: String) -> ObjectsUpdateParamsPredefinedAcl {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsUpdateParamsPredefinedAcl {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsUpdateParamsPredefinedAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsUpdateParamsPredefinedAcl, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+
+/// Set of properties to return. Defaults to full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsUpdateParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsUpdateParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsUpdateParamsProjection::Full => "full",
+            ObjectsUpdateParamsProjection::NoAcl => "noAcl",
+            ObjectsUpdateParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsUpdateParamsProjection {
+    fn default() -> ObjectsUpdateParamsProjection {
+        ObjectsUpdateParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsUpdateParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsUpdateParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsUpdateParamsProjection {
+    fn from(v: &str) -> ObjectsUpdateParamsProjection {
+        match v {
+            "full" => ObjectsUpdateParamsProjection::Full,
+            "noAcl" => ObjectsUpdateParamsProjection::NoAcl,
+            _ => ObjectsUpdateParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsUpdateParamsProjection {
+    fn from(v: String) -> ObjectsUpdateParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsUpdateParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsUpdateParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsUpdateParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -3892,10 +7109,10 @@ pub struct ObjectsUpdateParams {
     pub object: String,
     /// Apply a predefined set of access controls to this object.
     #[serde(rename = "predefinedAcl")]
-    pub predefined_acl: Option<String>,
+    pub predefined_acl: Option<ObjectsUpdateParamsPredefinedAcl>,
     /// Set of properties to return. Defaults to full.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsUpdateParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -3904,77 +7121,177 @@ pub struct ObjectsUpdateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ObjectsUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.generation {
-            write!(
-                f,
-                "&generation={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("generation", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_match {
-            write!(
-                f,
-                "&ifGenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_generation_not_match {
-            write!(
-                f,
-                "&ifGenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifGenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_match {
-            write!(
-                f,
-                "&ifMetagenerationMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationMatch", &v.to_string());
         }
         if let Some(ref v) = self.if_metageneration_not_match {
-            write!(
-                f,
-                "&ifMetagenerationNotMatch={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("ifMetagenerationNotMatch", &v.to_string());
         }
         if let Some(ref v) = self.predefined_acl {
-            write!(
-                f,
-                "&predefinedAcl={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("predefinedAcl", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
+        }
+    }
+}
+
+impl ObjectsUpdateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `generation`.
+    pub fn generation<T: Into<String>>(mut self, value: T) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_match`.
+    pub fn if_generation_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_generation_not_match`.
+    pub fn if_generation_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_generation_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_match`.
+    pub fn if_metageneration_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `if_metageneration_not_match`.
+    pub fn if_metageneration_not_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_metageneration_not_match = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `object`.
+    pub fn object<T: Into<String>>(mut self, value: T) -> Self {
+        self.object = value.into();
+        self
+    }
+    /// Builder-style setter for `predefined_acl`.
+    pub fn predefined_acl<T: Into<ObjectsUpdateParamsPredefinedAcl>>(mut self, value: T) -> Self {
+        self.predefined_acl = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsUpdateParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+/// Set of properties to return. Defaults to noAcl.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectsWatchAllParamsProjection {
+    /// Include all properties.
+    Full,
+    /// Omit the owner, acl property.
+    NoAcl,
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl ObjectsWatchAllParamsProjection {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectsWatchAllParamsProjection::Full => "full",
+            ObjectsWatchAllParamsProjection::NoAcl => "noAcl",
+            ObjectsWatchAllParamsProjection::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for ObjectsWatchAllParamsProjection {
+    fn default() -> ObjectsWatchAllParamsProjection {
+        ObjectsWatchAllParamsProjection::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for ObjectsWatchAllParamsProjection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ObjectsWatchAllParamsProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ObjectsWatchAllParamsProjection {
+    fn from(v: &str) -> ObjectsWatchAllParamsProjection {
+        match v {
+            "full" => ObjectsWatchAllParamsProjection::Full,
+            "noAcl" => ObjectsWatchAllParamsProjection::NoAcl,
+            _ => ObjectsWatchAllParamsProjection::Other(v.to_string()),
         }
-        Ok(())
+    }
+}
+
+impl From<String> for ObjectsWatchAllParamsProjection {
+    fn from(v: String) -> ObjectsWatchAllParamsProjection {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for ObjectsWatchAllParamsProjection {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectsWatchAllParamsProjection {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<ObjectsWatchAllParamsProjection, D::Error> {
+        Ok(String::deserialize(d)?.into())
     }
 }
 
 /// Parameters for the `objects.watchAll` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ObjectsWatchAllParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4002,7 +7319,7 @@ pub struct ObjectsWatchAllParams {
     pub prefix: Option<String>,
     /// Set of properties to return. Defaults to noAcl.
     #[serde(rename = "projection")]
-    pub projection: Option<String>,
+    pub projection: Option<ObjectsWatchAllParamsProjection>,
     /// The project to be billed for this request if the target bucket is requester-pays bucket.
     #[serde(rename = "provisionalUserProject")]
     pub provisional_user_project: Option<String>,
@@ -4017,91 +7334,118 @@ pub struct ObjectsWatchAllParams {
     pub versions: Option<bool>,
 }
 
-impl std::fmt::Display for ObjectsWatchAllParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ObjectsWatchAllParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.delimiter {
-            write!(
-                f,
-                "&delimiter={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("delimiter", &v.to_string());
         }
         if let Some(ref v) = self.end_offset {
-            write!(
-                f,
-                "&endOffset={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("endOffset", &v.to_string());
         }
         if let Some(ref v) = self.include_trailing_delimiter {
-            write!(
-                f,
-                "&includeTrailingDelimiter={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("includeTrailingDelimiter", &v.to_string());
         }
         if let Some(ref v) = self.max_results {
-            write!(
-                f,
-                "&maxResults={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("maxResults", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.prefix {
-            write!(
-                f,
-                "&prefix={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("prefix", &v.to_string());
         }
         if let Some(ref v) = self.projection {
-            write!(
-                f,
-                "&projection={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("projection", &v.to_string());
         }
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.start_offset {
-            write!(
-                f,
-                "&startOffset={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("startOffset", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
         if let Some(ref v) = self.versions {
-            write!(
-                f,
-                "&versions={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("versions", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ObjectsWatchAllParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `bucket`.
+    pub fn bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket = value.into();
+        self
+    }
+    /// Builder-style setter for `delimiter`.
+    pub fn delimiter<T: Into<String>>(mut self, value: T) -> Self {
+        self.delimiter = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `end_offset`.
+    pub fn end_offset<T: Into<String>>(mut self, value: T) -> Self {
+        self.end_offset = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `include_trailing_delimiter`.
+    pub fn include_trailing_delimiter<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_trailing_delimiter = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `max_results`.
+    pub fn max_results<T: Into<u32>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `prefix`.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `projection`.
+    pub fn projection<T: Into<ObjectsWatchAllParamsProjection>>(mut self, value: T) -> Self {
+        self.projection = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `start_offset`.
+    pub fn start_offset<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_offset = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `versions`.
+    pub fn versions<T: Into<bool>>(mut self, value: T) -> Self {
+        self.versions = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `hmacKeys.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ProjectsHmacKeysCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4117,30 +7461,47 @@ pub struct ProjectsHmacKeysCreateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ProjectsHmacKeysCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&serviceAccountEmail={}",
-            percent_encode(
-                format!("{}", self.service_account_email).as_bytes(),
-                NON_ALPHANUMERIC
-            )
-            .to_string()
-        )?;
+impl ProjectsHmacKeysCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair(
+            "serviceAccountEmail",
+            &self.service_account_email.to_string(),
+        );
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ProjectsHmacKeysCreateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `project_id`.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = value.into();
+        self
+    }
+    /// Builder-style setter for `service_account_email`.
+    pub fn service_account_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_account_email = value.into();
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `hmacKeys.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ProjectsHmacKeysDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4156,21 +7517,43 @@ pub struct ProjectsHmacKeysDeleteParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ProjectsHmacKeysDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProjectsHmacKeysDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ProjectsHmacKeysDeleteParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `access_id`.
+    pub fn access_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_id = value.into();
+        self
+    }
+    /// Builder-style setter for `project_id`.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = value.into();
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `hmacKeys.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ProjectsHmacKeysGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4186,21 +7569,43 @@ pub struct ProjectsHmacKeysGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ProjectsHmacKeysGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProjectsHmacKeysGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ProjectsHmacKeysGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `access_id`.
+    pub fn access_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_id = value.into();
+        self
+    }
+    /// Builder-style setter for `project_id`.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = value.into();
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `hmacKeys.list` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ProjectsHmacKeysListParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4225,49 +7630,70 @@ pub struct ProjectsHmacKeysListParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ProjectsHmacKeysListParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProjectsHmacKeysListParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.max_results {
-            write!(
-                f,
-                "&maxResults={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("maxResults", &v.to_string());
         }
         if let Some(ref v) = self.page_token {
-            write!(
-                f,
-                "&pageToken={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("pageToken", &v.to_string());
         }
         if let Some(ref v) = self.service_account_email {
-            write!(
-                f,
-                "&serviceAccountEmail={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("serviceAccountEmail", &v.to_string());
         }
         if let Some(ref v) = self.show_deleted_keys {
-            write!(
-                f,
-                "&showDeletedKeys={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("showDeletedKeys", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ProjectsHmacKeysListParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `max_results`.
+    pub fn max_results<T: Into<u32>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `page_token`.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `project_id`.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = value.into();
+        self
+    }
+    /// Builder-style setter for `service_account_email`.
+    pub fn service_account_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_account_email = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `show_deleted_keys`.
+    pub fn show_deleted_keys<T: Into<bool>>(mut self, value: T) -> Self {
+        self.show_deleted_keys = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `hmacKeys.update` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ProjectsHmacKeysUpdateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4283,21 +7709,43 @@ pub struct ProjectsHmacKeysUpdateParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ProjectsHmacKeysUpdateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProjectsHmacKeysUpdateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl ProjectsHmacKeysUpdateParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `access_id`.
+    pub fn access_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_id = value.into();
+        self
+    }
+    /// Builder-style setter for `project_id`.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = value.into();
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `serviceAccount.get` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct ProjectsServiceAccountGetParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -4313,105 +7761,147 @@ pub struct ProjectsServiceAccountGetParams {
     pub user_project: Option<String>,
 }
 
-impl std::fmt::Display for ProjectsServiceAccountGetParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProjectsServiceAccountGetParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.provisional_user_project {
-            write!(
-                f,
-                "&provisionalUserProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("provisionalUserProject", &v.to_string());
         }
         if let Some(ref v) = self.user_project {
-            write!(
-                f,
-                "&userProject={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userProject", &v.to_string());
         }
-        Ok(())
     }
 }
 
-impl std::fmt::Display for StorageParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProjectsServiceAccountGetParams {
+    /// Builder-style setter for `storage_params`.
+    pub fn storage_params<T: Into<StorageParams>>(mut self, value: T) -> Self {
+        self.storage_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `project_id`.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = value.into();
+        self
+    }
+    /// Builder-style setter for `provisional_user_project`.
+    pub fn provisional_user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisional_user_project = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_project`.
+    pub fn user_project<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_project = Some(value.into());
+        self
+    }
+}
+
+impl StorageParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.alt {
-            write!(
-                f,
-                "&alt={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("alt", &v.to_string());
         }
         if let Some(ref v) = self.fields {
-            write!(
-                f,
-                "&fields={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("fields", &v.to_string());
         }
         if let Some(ref v) = self.key {
-            write!(
-                f,
-                "&key={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("key", &v.to_string());
         }
         if let Some(ref v) = self.oauth_token {
-            write!(
-                f,
-                "&oauth_token={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("oauth_token", &v.to_string());
         }
         if let Some(ref v) = self.pretty_print {
-            write!(
-                f,
-                "&prettyPrint={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("prettyPrint", &v.to_string());
         }
         if let Some(ref v) = self.quota_user {
-            write!(
-                f,
-                "&quotaUser={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("quotaUser", &v.to_string());
         }
         if let Some(ref v) = self.user_ip {
-            write!(
-                f,
-                "&userIp={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userIp", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl StorageParams {
+    /// Builder-style setter for `alt`.
+    pub fn alt<T: Into<StorageParamsAlt>>(mut self, value: T) -> Self {
+        self.alt = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `fields`.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `key`.
+    pub fn key<T: Into<String>>(mut self, value: T) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `oauth_token`.
+    pub fn oauth_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.oauth_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `pretty_print`.
+    pub fn pretty_print<T: Into<bool>>(mut self, value: T) -> Self {
+        self.pretty_print = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `quota_user`.
+    pub fn quota_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.quota_user = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_ip`.
+    pub fn user_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_ip = Some(value.into());
+        self
     }
 }
 
 /// The Storage BucketAccessControls service represents the BucketAccessControls resource.
-pub struct BucketAccessControlsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct BucketAccessControlsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl BucketAccessControlsService {
+impl<C: HttpClient> BucketAccessControlsService<C> {
     /// Create a new BucketAccessControlsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> BucketAccessControlsService {
+    ) -> BucketAccessControlsService<C> {
         BucketAccessControlsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -4438,18 +7928,39 @@ impl BucketAccessControlsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4458,248 +7969,427 @@ impl BucketAccessControlsService {
             .collect();
     }
 
-    /// Permanently deletes the ACL entry for the specified entity on the specified bucket.
-    pub async fn delete(&mut self, params: &BucketAccessControlsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "b/{bucket}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Returns the ACL entry for the specified entity on the specified bucket.
-    pub async fn get(
-        &mut self,
-        params: &BucketAccessControlsGetParams,
-    ) -> Result<BucketAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Permanently deletes the ACL entry for the specified entity on the specified bucket.
+    pub async fn delete(&self, params: &BucketAccessControlsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &BucketAccessControlsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("BucketAccessControls", "delete", async move {
+            let rel_path = format!(
+                "b/{bucket}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Returns the ACL entry for the specified entity on the specified bucket.
+    pub async fn get(&self, params: &BucketAccessControlsGetParams) -> Result<BucketAccessControl> {
+        Ok(self.get_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &BucketAccessControlsGetParams,
+    ) -> Result<ApiResponse<BucketAccessControl>> {
+        traced("BucketAccessControls", "get", async move {
+            let rel_path = format!(
+                "b/{bucket}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Creates a new ACL entry on the specified bucket.
     pub async fn insert(
-        &mut self,
+        &self,
         params: &BucketAccessControlsInsertParams,
         req: &BucketAccessControl,
     ) -> Result<BucketAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/acl",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.insert_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `insert()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn insert_with_response(
+        &self,
+        params: &BucketAccessControlsInsertParams,
+        req: &BucketAccessControl,
+    ) -> Result<ApiResponse<BucketAccessControl>> {
+        traced("BucketAccessControls", "insert", async move {
+            let rel_path = format!(
+                "b/{bucket}/acl",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Retrieves ACL entries on the specified bucket.
     pub async fn list(
-        &mut self,
+        &self,
         params: &BucketAccessControlsListParams,
     ) -> Result<BucketAccessControls> {
-        let rel_path = format!(
-            "b/{bucket}/acl",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.list_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &BucketAccessControlsListParams,
+    ) -> Result<ApiResponse<BucketAccessControls>> {
+        traced("BucketAccessControls", "list", async move {
+            let rel_path = format!(
+                "b/{bucket}/acl",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Patches an ACL entry on the specified bucket.
     pub async fn patch(
-        &mut self,
+        &self,
         params: &BucketAccessControlsPatchParams,
         req: &BucketAccessControl,
     ) -> Result<BucketAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.patch_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `patch()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn patch_with_response(
+        &self,
+        params: &BucketAccessControlsPatchParams,
+        req: &BucketAccessControl,
+    ) -> Result<ApiResponse<BucketAccessControl>> {
+        traced("BucketAccessControls", "patch", async move {
+            let rel_path = format!(
+                "b/{bucket}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Updates an ACL entry on the specified bucket.
     pub async fn update(
-        &mut self,
+        &self,
         params: &BucketAccessControlsUpdateParams,
         req: &BucketAccessControl,
     ) -> Result<BucketAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &BucketAccessControlsUpdateParams,
+        req: &BucketAccessControl,
+    ) -> Result<ApiResponse<BucketAccessControl>> {
+        traced("BucketAccessControls", "update", async move {
+            let rel_path = format!(
+                "b/{bucket}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Storage Buckets service represents the Buckets resource.
-pub struct BucketsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct BucketsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl BucketsService {
+impl<C: HttpClient> BucketsService<C> {
     /// Create a new BucketsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> BucketsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> BucketsService<C> {
         BucketsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -4726,18 +8416,39 @@ impl BucketsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -4746,359 +8457,627 @@ impl BucketsService {
             .collect();
     }
 
-    /// Permanently deletes an empty bucket.
-    pub async fn delete(&mut self, params: &BucketsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "b/{bucket}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Returns metadata for the specified bucket.
-    pub async fn get(&mut self, params: &BucketsGetParams) -> Result<Bucket> {
-        let rel_path = format!(
-            "b/{bucket}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Permanently deletes an empty bucket.
+    pub async fn delete(&self, params: &BucketsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &BucketsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Buckets", "delete", async move {
+            let rel_path = format!(
+                "b/{bucket}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Returns metadata for the specified bucket.
+    pub async fn get(&self, params: &BucketsGetParams) -> Result<Bucket> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &BucketsGetParams,
+    ) -> Result<ApiResponse<Bucket>> {
+        traced("Buckets", "get", async move {
+            let rel_path = format!(
+                "b/{bucket}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Returns an IAM policy for the specified bucket.
-    pub async fn get_iam_policy(&mut self, params: &BucketsGetIamPolicyParams) -> Result<Policy> {
-        let rel_path = format!(
-            "b/{bucket}/iam",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn get_iam_policy(&self, params: &BucketsGetIamPolicyParams) -> Result<Policy> {
+        Ok(self.get_iam_policy_with_response(params).await?.body)
+    }
+
+    /// Like `get_iam_policy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_iam_policy_with_response(
+        &self,
+        params: &BucketsGetIamPolicyParams,
+    ) -> Result<ApiResponse<Policy>> {
+        traced("Buckets", "get_iam_policy", async move {
+            let rel_path = format!(
+                "b/{bucket}/iam",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Creates a new bucket.
-    pub async fn insert(&mut self, params: &BucketsInsertParams, req: &Bucket) -> Result<Bucket> {
-        let rel_path = format!("b",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
-    }
-
-    /// Retrieves a list of buckets for a given project.
-    pub async fn list(&mut self, params: &BucketsListParams) -> Result<Buckets> {
-        let rel_path = format!("b",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    pub async fn insert(&self, params: &BucketsInsertParams, req: &Bucket) -> Result<Bucket> {
+        Ok(self.insert_with_response(params, req).await?.body)
+    }
+
+    /// Like `insert()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn insert_with_response(
+        &self,
+        params: &BucketsInsertParams,
+        req: &Bucket,
+    ) -> Result<ApiResponse<Bucket>> {
+        traced("Buckets", "insert", async move {
+            let rel_path = format!("b",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Retrieves a list of buckets for a given project.
+    pub async fn list(&self, params: &BucketsListParams) -> Result<Buckets> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &BucketsListParams,
+    ) -> Result<ApiResponse<Buckets>> {
+        traced("Buckets", "list", async move {
+            let rel_path = format!("b",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Locks retention policy on a bucket.
     pub async fn lock_retention_policy(
-        &mut self,
+        &self,
         params: &BucketsLockRetentionPolicyParams,
     ) -> Result<Bucket> {
-        let rel_path = format!(
-            "b/{bucket}/lockRetentionPolicy",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.lock_retention_policy_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `lock_retention_policy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn lock_retention_policy_with_response(
+        &self,
+        params: &BucketsLockRetentionPolicyParams,
+    ) -> Result<ApiResponse<Bucket>> {
+        traced("Buckets", "lock_retention_policy", async move {
+            let rel_path = format!(
+                "b/{bucket}/lockRetentionPolicy",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Patches a bucket. Changes to the bucket will be readable immediately after writing, but configuration changes may take time to propagate.
-    pub async fn patch(&mut self, params: &BucketsPatchParams, req: &Bucket) -> Result<Bucket> {
-        let rel_path = format!(
-            "b/{bucket}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn patch(&self, params: &BucketsPatchParams, req: &Bucket) -> Result<Bucket> {
+        Ok(self.patch_with_response(params, req).await?.body)
+    }
+
+    /// Like `patch()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn patch_with_response(
+        &self,
+        params: &BucketsPatchParams,
+        req: &Bucket,
+    ) -> Result<ApiResponse<Bucket>> {
+        traced("Buckets", "patch", async move {
+            let rel_path = format!(
+                "b/{bucket}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Updates an IAM policy for the specified bucket.
     pub async fn set_iam_policy(
-        &mut self,
+        &self,
         params: &BucketsSetIamPolicyParams,
         req: &Policy,
     ) -> Result<Policy> {
-        let rel_path = format!(
-            "b/{bucket}/iam",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.set_iam_policy_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `set_iam_policy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn set_iam_policy_with_response(
+        &self,
+        params: &BucketsSetIamPolicyParams,
+        req: &Policy,
+    ) -> Result<ApiResponse<Policy>> {
+        traced("Buckets", "set_iam_policy", async move {
+            let rel_path = format!(
+                "b/{bucket}/iam",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 
     /// Tests a set of permissions on the given bucket to see which, if any, are held by the caller.
     pub async fn test_iam_permissions(
-        &mut self,
+        &self,
         params: &BucketsTestIamPermissionsParams,
     ) -> Result<TestIamPermissionsResponse> {
-        let rel_path = format!(
-            "b/{bucket}/iam/testPermissions",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.test_iam_permissions_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `test_iam_permissions()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn test_iam_permissions_with_response(
+        &self,
+        params: &BucketsTestIamPermissionsParams,
+    ) -> Result<ApiResponse<TestIamPermissionsResponse>> {
+        traced("Buckets", "test_iam_permissions", async move {
+            let rel_path = format!(
+                "b/{bucket}/iam/testPermissions",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Updates a bucket. Changes to the bucket will be readable immediately after writing, but configuration changes may take time to propagate.
-    pub async fn update(&mut self, params: &BucketsUpdateParams, req: &Bucket) -> Result<Bucket> {
-        let rel_path = format!(
-            "b/{bucket}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn update(&self, params: &BucketsUpdateParams, req: &Bucket) -> Result<Bucket> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
+
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &BucketsUpdateParams,
+        req: &Bucket,
+    ) -> Result<ApiResponse<Bucket>> {
+        traced("Buckets", "update", async move {
+            let rel_path = format!(
+                "b/{bucket}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Storage Channels service represents the Channels resource.
-pub struct ChannelsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ChannelsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ChannelsService {
+impl<C: HttpClient> ChannelsService<C> {
     /// Create a new ChannelsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> ChannelsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> ChannelsService<C> {
         ChannelsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5125,18 +9104,39 @@ impl ChannelsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -5145,61 +9145,137 @@ impl ChannelsService {
             .collect();
     }
 
-    /// Stop watching resources through this channel
-    pub async fn stop(&mut self, params: &ChannelsStopParams, req: &Channel) -> Result<()> {
-        let rel_path = format!("channels/stop",);
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+    /// Stop watching resources through this channel
+    pub async fn stop(&self, params: &ChannelsStopParams, req: &Channel) -> Result<()> {
+        Ok(self.stop_with_response(params, req).await?.body)
+    }
+
+    /// Like `stop()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn stop_with_response(
+        &self,
+        params: &ChannelsStopParams,
+        req: &Channel,
+    ) -> Result<ApiResponse<()>> {
+        traced("Channels", "stop", async move {
+            let rel_path = format!("channels/stop",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Storage DefaultObjectAccessControls service represents the DefaultObjectAccessControls resource.
-pub struct DefaultObjectAccessControlsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct DefaultObjectAccessControlsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl DefaultObjectAccessControlsService {
+impl<C: HttpClient> DefaultObjectAccessControlsService<C> {
     /// Create a new DefaultObjectAccessControlsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> DefaultObjectAccessControlsService {
+    ) -> DefaultObjectAccessControlsService<C> {
         DefaultObjectAccessControlsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5226,18 +9302,39 @@ impl DefaultObjectAccessControlsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -5246,248 +9343,433 @@ impl DefaultObjectAccessControlsService {
             .collect();
     }
 
-    /// Permanently deletes the default object ACL entry for the specified entity on the specified bucket.
-    pub async fn delete(&mut self, params: &DefaultObjectAccessControlsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "b/{bucket}/defaultObjectAcl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+    /// Permanently deletes the default object ACL entry for the specified entity on the specified bucket.
+    pub async fn delete(&self, params: &DefaultObjectAccessControlsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &DefaultObjectAccessControlsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("DefaultObjectAccessControls", "delete", async move {
+            let rel_path = format!(
+                "b/{bucket}/defaultObjectAcl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Returns the default object ACL entry for the specified entity on the specified bucket.
     pub async fn get(
-        &mut self,
+        &self,
         params: &DefaultObjectAccessControlsGetParams,
     ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/defaultObjectAcl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.get_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &DefaultObjectAccessControlsGetParams,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("DefaultObjectAccessControls", "get", async move {
+            let rel_path = format!(
+                "b/{bucket}/defaultObjectAcl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Creates a new default object ACL entry on the specified bucket.
     pub async fn insert(
-        &mut self,
+        &self,
         params: &DefaultObjectAccessControlsInsertParams,
         req: &ObjectAccessControl,
     ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/defaultObjectAcl",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.insert_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `insert()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn insert_with_response(
+        &self,
+        params: &DefaultObjectAccessControlsInsertParams,
+        req: &ObjectAccessControl,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("DefaultObjectAccessControls", "insert", async move {
+            let rel_path = format!(
+                "b/{bucket}/defaultObjectAcl",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Retrieves default object ACL entries on the specified bucket.
     pub async fn list(
-        &mut self,
+        &self,
         params: &DefaultObjectAccessControlsListParams,
     ) -> Result<ObjectAccessControls> {
-        let rel_path = format!(
-            "b/{bucket}/defaultObjectAcl",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.list_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &DefaultObjectAccessControlsListParams,
+    ) -> Result<ApiResponse<ObjectAccessControls>> {
+        traced("DefaultObjectAccessControls", "list", async move {
+            let rel_path = format!(
+                "b/{bucket}/defaultObjectAcl",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Patches a default object ACL entry on the specified bucket.
     pub async fn patch(
-        &mut self,
+        &self,
         params: &DefaultObjectAccessControlsPatchParams,
         req: &ObjectAccessControl,
     ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/defaultObjectAcl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+        Ok(self.patch_with_response(params, req).await?.body)
     }
 
-    /// Updates a default object ACL entry on the specified bucket.
-    pub async fn update(
-        &mut self,
-        params: &DefaultObjectAccessControlsUpdateParams,
+    /// Like `patch()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn patch_with_response(
+        &self,
+        params: &DefaultObjectAccessControlsPatchParams,
         req: &ObjectAccessControl,
-    ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/defaultObjectAcl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("DefaultObjectAccessControls", "patch", async move {
+            let rel_path = format!(
+                "b/{bucket}/defaultObjectAcl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Updates a default object ACL entry on the specified bucket.
+    pub async fn update(
+        &self,
+        params: &DefaultObjectAccessControlsUpdateParams,
+        req: &ObjectAccessControl,
+    ) -> Result<ObjectAccessControl> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &DefaultObjectAccessControlsUpdateParams,
+        req: &ObjectAccessControl,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("DefaultObjectAccessControls", "update", async move {
+            let rel_path = format!(
+                "b/{bucket}/defaultObjectAcl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Storage Notifications service represents the Notifications resource.
-pub struct NotificationsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct NotificationsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl NotificationsService {
+impl<C: HttpClient> NotificationsService<C> {
     /// Create a new NotificationsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> NotificationsService {
+    ) -> NotificationsService<C> {
         NotificationsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5514,18 +9796,39 @@ impl NotificationsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -5534,166 +9837,306 @@ impl NotificationsService {
             .collect();
     }
 
-    /// Permanently deletes a notification subscription.
-    pub async fn delete(&mut self, params: &NotificationsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "b/{bucket}/notificationConfigs/{notification}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            notification = percent_encode(params.notification.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// View a notification configuration.
-    pub async fn get(&mut self, params: &NotificationsGetParams) -> Result<Notification> {
-        let rel_path = format!(
-            "b/{bucket}/notificationConfigs/{notification}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            notification = percent_encode(params.notification.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Permanently deletes a notification subscription.
+    pub async fn delete(&self, params: &NotificationsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &NotificationsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Notifications", "delete", async move {
+            let rel_path = format!(
+                "b/{bucket}/notificationConfigs/{notification}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                notification = percent_encode(params.notification.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// View a notification configuration.
+    pub async fn get(&self, params: &NotificationsGetParams) -> Result<Notification> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &NotificationsGetParams,
+    ) -> Result<ApiResponse<Notification>> {
+        traced("Notifications", "get", async move {
+            let rel_path = format!(
+                "b/{bucket}/notificationConfigs/{notification}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                notification = percent_encode(params.notification.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Creates a notification subscription for a given bucket.
     pub async fn insert(
-        &mut self,
+        &self,
         params: &NotificationsInsertParams,
         req: &Notification,
     ) -> Result<Notification> {
-        let rel_path = format!(
-            "b/{bucket}/notificationConfigs",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.insert_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `insert()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn insert_with_response(
+        &self,
+        params: &NotificationsInsertParams,
+        req: &Notification,
+    ) -> Result<ApiResponse<Notification>> {
+        traced("Notifications", "insert", async move {
+            let rel_path = format!(
+                "b/{bucket}/notificationConfigs",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Retrieves a list of notification subscriptions for a given bucket.
-    pub async fn list(&mut self, params: &NotificationsListParams) -> Result<Notifications> {
-        let rel_path = format!(
-            "b/{bucket}/notificationConfigs",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &NotificationsListParams) -> Result<Notifications> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &NotificationsListParams,
+    ) -> Result<ApiResponse<Notifications>> {
+        traced("Notifications", "list", async move {
+            let rel_path = format!(
+                "b/{bucket}/notificationConfigs",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Storage ObjectAccessControls service represents the ObjectAccessControls resource.
-pub struct ObjectAccessControlsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ObjectAccessControlsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ObjectAccessControlsService {
+impl<C: HttpClient> ObjectAccessControlsService<C> {
     /// Create a new ObjectAccessControlsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> ObjectAccessControlsService {
+    ) -> ObjectAccessControlsService<C> {
         ObjectAccessControlsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -5720,18 +10163,39 @@ impl ObjectAccessControlsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -5740,254 +10204,433 @@ impl ObjectAccessControlsService {
             .collect();
     }
 
-    /// Permanently deletes the ACL entry for the specified entity on the specified object.
-    pub async fn delete(&mut self, params: &ObjectAccessControlsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Returns the ACL entry for the specified entity on the specified object.
-    pub async fn get(
-        &mut self,
-        params: &ObjectAccessControlsGetParams,
-    ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Permanently deletes the ACL entry for the specified entity on the specified object.
+    pub async fn delete(&self, params: &ObjectAccessControlsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &ObjectAccessControlsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("ObjectAccessControls", "delete", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Returns the ACL entry for the specified entity on the specified object.
+    pub async fn get(&self, params: &ObjectAccessControlsGetParams) -> Result<ObjectAccessControl> {
+        Ok(self.get_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &ObjectAccessControlsGetParams,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("ObjectAccessControls", "get", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Creates a new ACL entry on the specified object.
     pub async fn insert(
-        &mut self,
+        &self,
         params: &ObjectAccessControlsInsertParams,
         req: &ObjectAccessControl,
     ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/acl",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.insert_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `insert()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn insert_with_response(
+        &self,
+        params: &ObjectAccessControlsInsertParams,
+        req: &ObjectAccessControl,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("ObjectAccessControls", "insert", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/acl",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Retrieves ACL entries on the specified object.
     pub async fn list(
-        &mut self,
+        &self,
         params: &ObjectAccessControlsListParams,
     ) -> Result<ObjectAccessControls> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/acl",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.list_with_response(params).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &ObjectAccessControlsListParams,
+    ) -> Result<ApiResponse<ObjectAccessControls>> {
+        traced("ObjectAccessControls", "list", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/acl",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Patches an ACL entry on the specified object.
     pub async fn patch(
-        &mut self,
+        &self,
         params: &ObjectAccessControlsPatchParams,
         req: &ObjectAccessControl,
     ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.patch_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `patch()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn patch_with_response(
+        &self,
+        params: &ObjectAccessControlsPatchParams,
+        req: &ObjectAccessControl,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("ObjectAccessControls", "patch", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Updates an ACL entry on the specified object.
     pub async fn update(
-        &mut self,
+        &self,
         params: &ObjectAccessControlsUpdateParams,
         req: &ObjectAccessControl,
     ) -> Result<ObjectAccessControl> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/acl/{entity}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC),
-            entity = percent_encode(params.entity.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &ObjectAccessControlsUpdateParams,
+        req: &ObjectAccessControl,
+    ) -> Result<ApiResponse<ObjectAccessControl>> {
+        traced("ObjectAccessControls", "update", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/acl/{entity}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT),
+                entity = percent_encode(params.entity.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Storage Objects service represents the Objects resource.
-pub struct ObjectsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ObjectsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ObjectsService {
+impl<C: HttpClient> ObjectsService<C> {
     /// Create a new ObjectsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> ObjectsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> ObjectsService<C> {
         ObjectsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -6014,18 +10657,39 @@ impl ObjectsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -6034,260 +10698,480 @@ impl ObjectsService {
             .collect();
     }
 
-    /// Concatenates a list of existing objects into a new object in the same bucket.
-    pub async fn compose(
-        &mut self,
-        params: &ObjectsComposeParams,
-        req: &ComposeRequest,
-    ) -> Result<Object> {
-        let rel_path = format!(
-            "b/{destinationBucket}/o/{destinationObject}/compose",
-            destinationBucket =
-                percent_encode(params.destination_bucket.as_bytes(), NON_ALPHANUMERIC),
-            destinationObject =
-                percent_encode(params.destination_object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
-    }
-
-    /// Copies a source object to a destination object. Optionally overrides metadata.
-    pub async fn copy(&mut self, params: &ObjectsCopyParams, req: &Object) -> Result<Object> {
-        let rel_path = format!(
-            "b/{sourceBucket}/o/{sourceObject}/copyTo/b/{destinationBucket}/o/{destinationObject}",
-            sourceBucket = percent_encode(params.source_bucket.as_bytes(), NON_ALPHANUMERIC),
-            sourceObject = percent_encode(params.source_object.as_bytes(), NON_ALPHANUMERIC),
-            destinationBucket =
-                percent_encode(params.destination_bucket.as_bytes(), NON_ALPHANUMERIC),
-            destinationObject =
-                percent_encode(params.destination_object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+    /// Concatenates a list of existing objects into a new object in the same bucket.
+    pub async fn compose(
+        &self,
+        params: &ObjectsComposeParams,
+        req: &ComposeRequest,
+    ) -> Result<Object> {
+        Ok(self.compose_with_response(params, req).await?.body)
     }
 
-    /// Deletes an object and its metadata. Deletions are permanent if versioning is not enabled for the bucket, or if the generation parameter is used.
-    pub async fn delete(&mut self, params: &ObjectsDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Like `compose()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn compose_with_response(
+        &self,
+        params: &ObjectsComposeParams,
+        req: &ComposeRequest,
+    ) -> Result<ApiResponse<Object>> {
+        traced("Objects", "compose", async move {
+            let rel_path = format!(
+                "b/{destinationBucket}/o/{destinationObject}/compose",
+                destinationBucket =
+                    percent_encode(params.destination_bucket.as_bytes(), PATH_SEGMENT),
+                destinationObject =
+                    percent_encode(params.destination_object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Copies a source object to a destination object. Optionally overrides metadata.
+    pub async fn copy(&self, params: &ObjectsCopyParams, req: &Object) -> Result<Object> {
+        Ok(self.copy_with_response(params, req).await?.body)
+    }
+
+    /// Like `copy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn copy_with_response(
+        &self,
+        params: &ObjectsCopyParams,
+        req: &Object,
+    ) -> Result<ApiResponse<Object>> {
+        traced("Objects", "copy", async move {
+            let rel_path = format!(
+            "b/{sourceBucket}/o/{sourceObject}/copyTo/b/{destinationBucket}/o/{destinationObject}",
+            sourceBucket = percent_encode(params.source_bucket.as_bytes(), PATH_SEGMENT),
+            sourceObject = percent_encode(params.source_object.as_bytes(), PATH_SEGMENT),
+            destinationBucket = percent_encode(params.destination_bucket.as_bytes(), PATH_SEGMENT),
+            destinationObject = percent_encode(params.destination_object.as_bytes(), PATH_SEGMENT)
+        );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let full_uri = path + &url_params;
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+    /// Deletes an object and its metadata. Deletions are permanent if versioning is not enabled for the bucket, or if the generation parameter is used.
+    pub async fn delete(&self, params: &ObjectsDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &ObjectsDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Objects", "delete", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Retrieves an object or its metadata.
     ///
     /// This method potentially downloads data. See documentation of `Download`.
     pub async fn get<'a>(
-        &'a mut self,
+        &'a self,
         params: &ObjectsGetParams,
-    ) -> Result<Download<'a, EmptyRequest, Object>> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
+    ) -> Result<Download<'a, EmptyRequest, Object, C>> {
+        traced("Objects", "get", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
 
-        do_download(&self.client, &full_uri, headers, "GET".into(), opt_request).await
+            do_download(
+                &self.client,
+                url.as_str(),
+                headers,
+                "GET".into(),
+                opt_request,
+            )
+            .await
+        })
+        .await
     }
 
     /// Returns an IAM policy for the specified object.
-    pub async fn get_iam_policy(&mut self, params: &ObjectsGetIamPolicyParams) -> Result<Policy> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/iam",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn get_iam_policy(&self, params: &ObjectsGetIamPolicyParams) -> Result<Policy> {
+        Ok(self.get_iam_policy_with_response(params).await?.body)
+    }
+
+    /// Like `get_iam_policy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_iam_policy_with_response(
+        &self,
+        params: &ObjectsGetIamPolicyParams,
+    ) -> Result<ApiResponse<Policy>> {
+        traced("Objects", "get_iam_policy", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/iam",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Stores a new object and metadata.
-    pub async fn insert(&mut self, params: &ObjectsInsertParams, req: &Object) -> Result<Object> {
-        let rel_path = format!(
-            "b/{bucket}/o",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    pub async fn insert(&self, params: &ObjectsInsertParams, req: &Object) -> Result<Object> {
+        Ok(self.insert_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `insert()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn insert_with_response(
+        &self,
+        params: &ObjectsInsertParams,
+        req: &Object,
+    ) -> Result<ApiResponse<Object>> {
+        traced("Objects", "insert", async move {
+            let rel_path = format!(
+                "b/{bucket}/o",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Stores a new object and metadata.
     ///
     /// This method is a variant of `insert()`, taking data for upload. It performs a multipart upload.
     pub async fn insert_upload(
-        &mut self,
+        &self,
         params: &ObjectsInsertParams,
         req: &Object,
         data: hyper::body::Bytes,
     ) -> Result<Object> {
-        let rel_path = format!(
-            "/upload/storage/v1/b/{bucket}/o",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?uploadType=multipart{params}", params = params);
+        self.insert_upload_with_type(params, req, data, None).await
+    }
 
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Like `insert_upload()`, but sends `content_type` as the MIME type of `data`. Without it,
+    /// the server detects the type from the content.
+    pub async fn insert_upload_with_type(
+        &self,
+        params: &ObjectsInsertParams,
+        req: &Object,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<Object> {
+        Ok(self
+            .insert_upload_with_response(params, req, data, content_type)
+            .await?
+            .body)
+    }
 
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
+    /// Like `insert_upload_with_type()`, but also returns the status and headers of the response.
+    pub async fn insert_upload_with_response(
+        &self,
+        params: &ObjectsInsertParams,
+        req: &Object,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<ApiResponse<Object>> {
+        traced("Objects", "insert_upload", async move {
+            let rel_path = format!(
+                "/upload/storage/v1/b/{bucket}/o",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "multipart");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
 
-        do_upload_multipart(&self.client, &full_uri, &headers, "POST", opt_request, data).await
+            do_upload_multipart_with_response(
+                &self.client,
+                url.as_str(),
+                &headers,
+                "POST",
+                opt_request,
+                data,
+                content_type,
+            )
+            .await
+        })
+        .await
     }
 
     /// Stores a new object and metadata.
@@ -6297,326 +11181,521 @@ impl ObjectsService {
     /// of data to the API. The result of this call will be returned by the `ResumableUpload` method
     /// you choose for the upload.
     pub async fn insert_resumable_upload<'client>(
-        &'client mut self,
+        &'client self,
         params: &ObjectsInsertParams,
         req: &Object,
-    ) -> Result<ResumableUpload<'client, Object>> {
-        let rel_path = format!(
-            "/resumable/upload/storage/v1/b/{bucket}/o",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    ) -> Result<ResumableUpload<'client, Object, C>> {
+        self.insert_resumable_upload_with_type(params, req, None)
+            .await
+    }
 
-        let mut url_params = format!("?uploadType=resumable{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    /// Like `insert_resumable_upload()`, but sends `content_type` as the MIME type of the data
+    /// to be uploaded. Without it, the server detects the type from the content.
+    pub async fn insert_resumable_upload_with_type<'client>(
+        &'client self,
+        params: &ObjectsInsertParams,
+        req: &Object,
+        content_type: Option<&str>,
+    ) -> Result<ResumableUpload<'client, Object, C>> {
+        traced("Objects", "insert_resumable_upload", async move {
+            let rel_path = format!(
+                "/resumable/upload/storage/v1/b/{bucket}/o",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            if let Some(content_type) = content_type {
+                headers.push((
+                    hyper::header::HeaderName::from_static("x-upload-content-type"),
+                    content_type.to_string(),
+                ));
+            }
 
-        let full_uri = path + &url_params;
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "resumable");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
-            do_request_with_headers(&self.client, &full_uri, &headers, "POST", opt_request).await?;
-        if let Some(dest) = headers.get(hyper::header::LOCATION) {
-            use std::convert::TryFrom;
-            Ok(ResumableUpload::new(
-                hyper::Uri::try_from(dest.to_str()?)?,
-                &self.client,
-                5 * 1024 * 1024,
-            ))
-        } else {
-            Err(Error::from(ApiError::RedirectError(format!(
-                "Resumable upload response didn't contain Location: {:?}",
-                headers
-            )))
-            .context(format!("{:?}", headers)))?
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
+                do_request_with_headers(&self.client, url.as_str(), &headers, "POST", opt_request)
+                    .await?;
+            if let Some(dest) = headers.get(hyper::header::LOCATION) {
+                use std::convert::TryFrom;
+                Ok(ResumableUpload::new(
+                    hyper::Uri::try_from(dest.to_str()?)?,
+                    &self.client,
+                    5 * 1024 * 1024,
+                ))
+            } else {
+                Err(Error::from(ApiError::RedirectError(format!(
+                    "Resumable upload response didn't contain Location: {:?}",
+                    headers
+                )))
+                .context(format!("{:?}", headers)))?
+            }
+        })
+        .await
     }
 
     /// Retrieves a list of objects matching the criteria.
-    pub async fn list(&mut self, params: &ObjectsListParams) -> Result<Objects> {
-        let rel_path = format!(
-            "b/{bucket}/o",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &ObjectsListParams) -> Result<Objects> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &ObjectsListParams,
+    ) -> Result<ApiResponse<Objects>> {
+        traced("Objects", "list", async move {
+            let rel_path = format!(
+                "b/{bucket}/o",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Patches an object's metadata.
-    pub async fn patch(&mut self, params: &ObjectsPatchParams, req: &Object) -> Result<Object> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+    pub async fn patch(&self, params: &ObjectsPatchParams, req: &Object) -> Result<Object> {
+        Ok(self.patch_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `patch()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn patch_with_response(
+        &self,
+        params: &ObjectsPatchParams,
+        req: &Object,
+    ) -> Result<ApiResponse<Object>> {
+        traced("Objects", "patch", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PATCH", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PATCH", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Rewrites a source object to a destination object. Optionally overrides metadata.
     pub async fn rewrite(
-        &mut self,
+        &self,
         params: &ObjectsRewriteParams,
         req: &Object,
     ) -> Result<RewriteResponse> {
-        let rel_path = format!("b/{sourceBucket}/o/{sourceObject}/rewriteTo/b/{destinationBucket}/o/{destinationObject}", sourceBucket=percent_encode(params.source_bucket.as_bytes(), NON_ALPHANUMERIC),sourceObject=percent_encode(params.source_object.as_bytes(), NON_ALPHANUMERIC),destinationBucket=percent_encode(params.destination_bucket.as_bytes(), NON_ALPHANUMERIC),destinationObject=percent_encode(params.destination_object.as_bytes(), NON_ALPHANUMERIC));
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
-    }
-
-    /// Updates an IAM policy for the specified object.
-    pub async fn set_iam_policy(
-        &mut self,
-        params: &ObjectsSetIamPolicyParams,
-        req: &Policy,
-    ) -> Result<Policy> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/iam",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+        Ok(self.rewrite_with_response(params, req).await?.body)
     }
 
-    /// Tests a set of permissions on the given object to see which, if any, are held by the caller.
-    pub async fn test_iam_permissions(
-        &mut self,
-        params: &ObjectsTestIamPermissionsParams,
-    ) -> Result<TestIamPermissionsResponse> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}/iam/testPermissions",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
+    /// Like `rewrite()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn rewrite_with_response(
+        &self,
+        params: &ObjectsRewriteParams,
+        req: &Object,
+    ) -> Result<ApiResponse<RewriteResponse>> {
+        traced("Objects", "rewrite", async move {
+        let rel_path = format!("b/{sourceBucket}/o/{sourceObject}/rewriteTo/b/{destinationBucket}/o/{destinationObject}", sourceBucket=percent_encode(params.source_bucket.as_bytes(), PATH_SEGMENT),sourceObject=percent_encode(params.source_object.as_bytes(), PATH_SEGMENT),destinationBucket=percent_encode(params.destination_bucket.as_bytes(), PATH_SEGMENT),destinationObject=percent_encode(params.destination_object.as_bytes(), PATH_SEGMENT));
         let path = self.format_path(rel_path.as_str());
+        trace_path(&rel_path);
 
-        let mut headers = vec![];
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
+            let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+        let mut url = Url::parse(&path)?;
+{
+let mut query = url.query_pairs_mut();
+params.append_to(&mut query);
+if let Some(ref api_params) = &params.storage_params {
+api_params.append_to(&mut query);
+}
+}
+self.standard_params.append_to(&mut url);
+if let Some(ref fields) = self.default_fields {
+if !url.query_pairs().any(|(name, _)| name == "fields") {
+url.query_pairs_mut().append_pair("fields", fields);
+}
+}
 
         let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+        let opt_request = Some(req);
+        do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request).await
+        }).await
     }
 
-    /// Updates an object's metadata.
-    pub async fn update(&mut self, params: &ObjectsUpdateParams, req: &Object) -> Result<Object> {
-        let rel_path = format!(
-            "b/{bucket}/o/{object}",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC),
-            object = percent_encode(params.object.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    /// Updates an IAM policy for the specified object.
+    pub async fn set_iam_policy(
+        &self,
+        params: &ObjectsSetIamPolicyParams,
+        req: &Policy,
+    ) -> Result<Policy> {
+        Ok(self.set_iam_policy_with_response(params, req).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `set_iam_policy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn set_iam_policy_with_response(
+        &self,
+        params: &ObjectsSetIamPolicyParams,
+        req: &Policy,
+    ) -> Result<ApiResponse<Policy>> {
+        traced("Objects", "set_iam_policy", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/iam",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Tests a set of permissions on the given object to see which, if any, are held by the caller.
+    pub async fn test_iam_permissions(
+        &self,
+        params: &ObjectsTestIamPermissionsParams,
+    ) -> Result<TestIamPermissionsResponse> {
+        Ok(self.test_iam_permissions_with_response(params).await?.body)
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+    /// Like `test_iam_permissions()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn test_iam_permissions_with_response(
+        &self,
+        params: &ObjectsTestIamPermissionsParams,
+    ) -> Result<ApiResponse<TestIamPermissionsResponse>> {
+        traced("Objects", "test_iam_permissions", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}/iam/testPermissions",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
+
+    /// Updates an object's metadata.
+    pub async fn update(&self, params: &ObjectsUpdateParams, req: &Object) -> Result<Object> {
+        Ok(self.update_with_response(params, req).await?.body)
+    }
+
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &ObjectsUpdateParams,
+        req: &Object,
+    ) -> Result<ApiResponse<Object>> {
+        traced("Objects", "update", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/{object}",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT),
+                object = percent_encode(params.object.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 
     /// Watch for changes on all objects in a bucket.
     pub async fn watch_all(
-        &mut self,
+        &self,
         params: &ObjectsWatchAllParams,
         req: &Channel,
     ) -> Result<Channel> {
-        let rel_path = format!(
-            "b/{bucket}/o/watch",
-            bucket = percent_encode(params.bucket.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.watch_all_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `watch_all()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn watch_all_with_response(
+        &self,
+        params: &ObjectsWatchAllParams,
+        req: &Channel,
+    ) -> Result<ApiResponse<Channel>> {
+        traced("Objects", "watch_all", async move {
+            let rel_path = format!(
+                "b/{bucket}/o/watch",
+                bucket = percent_encode(params.bucket.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 }
 
 /// The Storage Projects service represents the Projects resource.
-pub struct ProjectsService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ProjectsService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ProjectsService {
+impl<C: HttpClient> ProjectsService<C> {
     /// Create a new ProjectsService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> ProjectsService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> ProjectsService<C> {
         ProjectsService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -6643,18 +11722,39 @@ impl ProjectsService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -6662,32 +11762,85 @@ impl ProjectsService {
             .map(|s| s.as_ref().to_string())
             .collect();
     }
+
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
+        let tok;
+        if self.scopes.is_empty() {
+            tok = self.authenticator.token(scopes).await?;
+        } else {
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
+        }
+        headers.push((
+            hyper::header::AUTHORIZATION,
+            format!("Bearer {token}", token = tok.as_str()),
+        ));
+
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
+        }
+
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 }
 
 /// The Storage ProjectsHmacKeys service represents the ProjectsHmacKeys resource.
-pub struct ProjectsHmacKeysService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ProjectsHmacKeysService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ProjectsHmacKeysService {
+impl<C: HttpClient> ProjectsHmacKeysService<C> {
     /// Create a new ProjectsHmacKeysService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> ProjectsHmacKeysService {
+    ) -> ProjectsHmacKeysService<C> {
         ProjectsHmacKeysService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -6714,18 +11867,39 @@ impl ProjectsHmacKeysService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -6734,199 +11908,360 @@ impl ProjectsHmacKeysService {
             .collect();
     }
 
-    /// Creates a new HMAC key for the specified service account.
-    pub async fn create(&mut self, params: &ProjectsHmacKeysCreateParams) -> Result<HmacKey> {
-        let rel_path = format!(
-            "projects/{projectId}/hmacKeys",
-            projectId = percent_encode(params.project_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
-
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
     }
 
-    /// Deletes an HMAC key.
-    pub async fn delete(&mut self, params: &ProjectsHmacKeysDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "projects/{projectId}/hmacKeys/{accessId}",
-            projectId = percent_encode(params.project_id.as_bytes(), NON_ALPHANUMERIC),
-            accessId = percent_encode(params.access_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Creates a new HMAC key for the specified service account.
+    pub async fn create(&self, params: &ProjectsHmacKeysCreateParams) -> Result<HmacKey> {
+        Ok(self.create_with_response(params).await?.body)
+    }
+
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &ProjectsHmacKeysCreateParams,
+    ) -> Result<ApiResponse<HmacKey>> {
+        traced("ProjectsHmacKeys", "create", async move {
+            let rel_path = format!(
+                "projects/{projectId}/hmacKeys",
+                projectId = percent_encode(params.project_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
+    }
 
-        let full_uri = path + &url_params;
+    /// Deletes an HMAC key.
+    pub async fn delete(&self, params: &ProjectsHmacKeysDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
+
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &ProjectsHmacKeysDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("ProjectsHmacKeys", "delete", async move {
+            let rel_path = format!(
+                "projects/{projectId}/hmacKeys/{accessId}",
+                projectId = percent_encode(params.project_id.as_bytes(), PATH_SEGMENT),
+                accessId = percent_encode(params.access_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Retrieves an HMAC key's metadata
-    pub async fn get(&mut self, params: &ProjectsHmacKeysGetParams) -> Result<HmacKeyMetadata> {
-        let rel_path = format!(
-            "projects/{projectId}/hmacKeys/{accessId}",
-            projectId = percent_encode(params.project_id.as_bytes(), NON_ALPHANUMERIC),
-            accessId = percent_encode(params.access_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadOnly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn get(&self, params: &ProjectsHmacKeysGetParams) -> Result<HmacKeyMetadata> {
+        Ok(self.get_with_response(params).await?.body)
+    }
+
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &ProjectsHmacKeysGetParams,
+    ) -> Result<ApiResponse<HmacKeyMetadata>> {
+        traced("ProjectsHmacKeys", "get", async move {
+            let rel_path = format!(
+                "projects/{projectId}/hmacKeys/{accessId}",
+                projectId = percent_encode(params.project_id.as_bytes(), PATH_SEGMENT),
+                accessId = percent_encode(params.access_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_ONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Retrieves a list of HMAC keys matching the criteria.
-    pub async fn list(&mut self, params: &ProjectsHmacKeysListParams) -> Result<HmacKeysMetadata> {
-        let rel_path = format!(
-            "projects/{projectId}/hmacKeys",
-            projectId = percent_encode(params.project_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadOnly.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
-
-        let full_uri = path + &url_params;
+    pub async fn list(&self, params: &ProjectsHmacKeysListParams) -> Result<HmacKeysMetadata> {
+        Ok(self.list_with_response(params).await?.body)
+    }
+
+    /// Like `list()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn list_with_response(
+        &self,
+        params: &ProjectsHmacKeysListParams,
+    ) -> Result<ApiResponse<HmacKeysMetadata>> {
+        traced("ProjectsHmacKeys", "list", async move {
+            let rel_path = format!(
+                "projects/{projectId}/hmacKeys",
+                projectId = percent_encode(params.project_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_ONLY];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
     }
 
     /// Updates the state of an HMAC key. See the HMAC Key resource descriptor for valid states.
     pub async fn update(
-        &mut self,
+        &self,
         params: &ProjectsHmacKeysUpdateParams,
         req: &HmacKeyMetadata,
     ) -> Result<HmacKeyMetadata> {
-        let rel_path = format!(
-            "projects/{projectId}/hmacKeys/{accessId}",
-            projectId = percent_encode(params.project_id.as_bytes(), NON_ALPHANUMERIC),
-            accessId = percent_encode(params.access_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageFullControl.as_ref().to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
-
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+        Ok(self.update_with_response(params, req).await?.body)
+    }
 
-        let full_uri = path + &url_params;
+    /// Like `update()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn update_with_response(
+        &self,
+        params: &ProjectsHmacKeysUpdateParams,
+        req: &HmacKeyMetadata,
+    ) -> Result<ApiResponse<HmacKeyMetadata>> {
+        traced("ProjectsHmacKeys", "update", async move {
+            let rel_path = format!(
+                "projects/{projectId}/hmacKeys/{accessId}",
+                projectId = percent_encode(params.project_id.as_bytes(), PATH_SEGMENT),
+                accessId = percent_encode(params.access_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_FULL_CONTROL];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "PUT", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "PUT", opt_request).await
+        })
+        .await
     }
 }
 
 /// The Storage ProjectsServiceAccount service represents the ProjectsServiceAccount resource.
-pub struct ProjectsServiceAccountService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct ProjectsServiceAccountService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl ProjectsServiceAccountService {
+impl<C: HttpClient> ProjectsServiceAccountService<C> {
     /// Create a new ProjectsServiceAccountService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
         auth: A,
-    ) -> ProjectsServiceAccountService {
+    ) -> ProjectsServiceAccountService<C> {
         ProjectsServiceAccountService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://storage.googleapis.com/storage/v1/".into(),
             root_url: "https://storage.googleapis.com/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -6953,18 +12288,39 @@ impl ProjectsServiceAccountService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of StorageScopes enum values.
+    /// It is most convenient to supply a vec or slice of StorageScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -6973,38 +12329,168 @@ impl ProjectsServiceAccountService {
             .collect();
     }
 
-    /// Get the email address of this project's Google Cloud Storage service account.
-    pub async fn get(
-        &mut self,
-        params: &ProjectsServiceAccountGetParams,
-    ) -> Result<ServiceAccount> {
-        let rel_path = format!(
-            "projects/{projectId}/serviceAccount",
-            projectId = percent_encode(params.project_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-
-        let mut headers = vec![];
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &[StorageScopes::DevstorageReadWrite.as_ref().to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.storage_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
+
+    /// Get the email address of this project's Google Cloud Storage service account.
+    pub async fn get(&self, params: &ProjectsServiceAccountGetParams) -> Result<ServiceAccount> {
+        Ok(self.get_with_response(params).await?.body)
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "GET", opt_request).await
+    /// Like `get()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn get_with_response(
+        &self,
+        params: &ProjectsServiceAccountGetParams,
+    ) -> Result<ApiResponse<ServiceAccount>> {
+        traced("ProjectsServiceAccount", "get", async move {
+            let rel_path = format!(
+                "projects/{projectId}/serviceAccount",
+                projectId = percent_encode(params.project_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &[scopes::DEVSTORAGE_READ_WRITE];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.storage_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "GET", opt_request).await
+        })
+        .await
+    }
+}
+
+/// All services of the Storage API, sharing one HTTP client and one token source. Each
+/// accessor returns a new service; services are cheap to create and to clone.
+///
+/// ```ignore
+/// let hub = StorageHub::new(https_client, authenticator);
+/// let service = hub.bucket_access_controls();
+/// ```
+#[derive(Clone)]
+pub struct StorageHub<C = TlsClient> {
+    apis: GoogleApis<C>,
+}
+
+impl<C: HttpClient> StorageHub<C> {
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> StorageHub<C> {
+        StorageHub {
+            apis: GoogleApis::new(client, auth),
+        }
+    }
+
+    /// A hub using the client and token source of `apis`, e.g. to share them with other APIs.
+    pub fn from_apis(apis: GoogleApis<C>) -> StorageHub<C> {
+        StorageHub { apis }
+    }
+
+    /// The shared client and token source.
+    pub fn apis(&self) -> &GoogleApis<C> {
+        &self.apis
+    }
+
+    pub fn bucket_access_controls(&self) -> BucketAccessControlsService<C> {
+        self.apis.service(BucketAccessControlsService::new)
+    }
+
+    pub fn buckets(&self) -> BucketsService<C> {
+        self.apis.service(BucketsService::new)
+    }
+
+    pub fn channels(&self) -> ChannelsService<C> {
+        self.apis.service(ChannelsService::new)
+    }
+
+    pub fn default_object_access_controls(&self) -> DefaultObjectAccessControlsService<C> {
+        self.apis.service(DefaultObjectAccessControlsService::new)
+    }
+
+    pub fn notifications(&self) -> NotificationsService<C> {
+        self.apis.service(NotificationsService::new)
+    }
+
+    pub fn object_access_controls(&self) -> ObjectAccessControlsService<C> {
+        self.apis.service(ObjectAccessControlsService::new)
+    }
+
+    pub fn objects(&self) -> ObjectsService<C> {
+        self.apis.service(ObjectsService::new)
+    }
+
+    pub fn projects(&self) -> ProjectsService<C> {
+        self.apis.service(ProjectsService::new)
+    }
+
+    pub fn projects_hmac_keys(&self) -> ProjectsHmacKeysService<C> {
+        self.apis.service(ProjectsHmacKeysService::new)
+    }
+
+    pub fn projects_service_account(&self) -> ProjectsServiceAccountService<C> {
+        self.apis.service(ProjectsServiceAccountService::new)
     }
 }
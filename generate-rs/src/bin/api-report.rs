@@ -0,0 +1,55 @@
+//! Print the public API of generated code (see `generate_rs::api_report()`), or the differences
+//! between two versions of it. Keeping a report next to the generated code shows what changed
+//! when an API is regenerated:
+//!
+//! ```shell
+//! $ api-report gen/drive_v3_types.rs > drive_v3.api
+//! $ api-report --diff drive_v3.api gen/drive_v3_types.rs
+//! ```
+
+use anyhow::{Context, Result};
+use clap::{App, Arg};
+
+/// Read a report, or create one if `path` is Rust source code.
+fn read_report(path: &str) -> Result<String> {
+    let text = std::fs::read_to_string(path).context(format!("Could not read {}", path))?;
+    if path.ends_with(".rs") {
+        Ok(generate_rs::api_report(&text))
+    } else {
+        Ok(text)
+    }
+}
+
+fn main() -> Result<()> {
+    let matches = App::new("api-report")
+        .about("Report the public API of generated code, or compare two reports.")
+        .arg(
+            Arg::with_name("diff")
+                .long("diff")
+                .help("Compare OLD and NEW (reports, or .rs files to create them from)"),
+        )
+        .arg(
+            Arg::with_name("files")
+                .required(true)
+                .multiple(true)
+                .value_name("FILE")
+                .help("Generated .rs file; with --diff, OLD and NEW"),
+        )
+        .get_matches();
+
+    let files: Vec<&str> = matches.values_of("files").into_iter().flatten().collect();
+    if matches.is_present("diff") {
+        if files.len() != 2 {
+            return Err(anyhow::anyhow!("--diff expects OLD and NEW"));
+        }
+        print!(
+            "{}",
+            generate_rs::diff_api_reports(&read_report(files[0])?, &read_report(files[1])?)
+        );
+    } else {
+        for f in files {
+            print!("{}", read_report(f)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//! Helpers for generating bindings from a `build.rs` script.
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+
+use std::path::{Path, PathBuf};
+
+/// Options for `generate_in_build_rs_with()`.
+#[derive(Debug, Clone)]
+pub struct BuildOptions {
+    /// Directory containing vendored Discovery documents, named `{api}.{version}.json`. If a
+    /// document is found there, it is used instead of fetching the current one.
+    pub vendor_dir: Option<PathBuf>,
+    /// Never fetch Discovery documents from the network. Without a vendored document, the file
+    /// generated by an earlier build is used; if there is none, generation fails.
+    pub offline: bool,
+}
+
+impl Default for BuildOptions {
+    /// Vendored documents are looked up in the `discovery` directory of the crate being built.
+    /// Offline mode is enabled by setting the `GENERATE_RS_OFFLINE` environment variable.
+    fn default() -> BuildOptions {
+        BuildOptions {
+            vendor_dir: std::env::var_os("CARGO_MANIFEST_DIR")
+                .map(|d| PathBuf::from(d).join("discovery")),
+            offline: std::env::var_os("GENERATE_RS_OFFLINE").is_some(),
+        }
+    }
+}
+
+/// Generate bindings for `api` at `version` (e.g. `"drive"`, `"v3"`) into `out_dir`, using the
+/// default `BuildOptions`. Returns the path of the generated file, which is named like the module
+/// generated by `generate-rs`, e.g. `drive_v3_types.rs`.
+///
+/// The generated file is meant to be included into a module:
+///
+/// ```ignore
+/// mod drive {
+///     #![allow(unused_variables, unused_mut, dead_code)]
+///     include!(concat!(env!("OUT_DIR"), "/drive_v3_types.rs"));
+/// }
+/// ```
+pub fn generate_in_build_rs(api: &str, version: &str, out_dir: &Path) -> Result<PathBuf> {
+    generate_in_build_rs_with(api, version, out_dir, &BuildOptions::default())
+}
+
+/// Like `generate_in_build_rs()`, with explicit options.
+///
+/// Code is only regenerated if the `revision` of the Discovery document (or the version of this
+/// generator) differs from the one used for the existing file. If no document is vendored and
+/// fetching it fails (or is disabled), the existing file is used as it is, so that builds work
+/// without network access once the bindings have been generated.
+pub fn generate_in_build_rs_with(
+    api: &str,
+    version: &str,
+    out_dir: &Path,
+    opts: &BuildOptions,
+) -> Result<PathBuf> {
+    println!("cargo:rerun-if-env-changed=GENERATE_RS_OFFLINE");
+    let discdoc = match vendored_discovery_doc(api, version, opts)? {
+        Some(discdoc) => discdoc,
+        None => match fetch_discovery_doc(api, version, opts) {
+            Ok(discdoc) => discdoc,
+            Err(e) => {
+                // Named like `module_name()` of the document.
+                let previous =
+                    out_dir.join(format!("{}:{}_types.rs", api, version).replace(':', "_"));
+                if !previous.exists() {
+                    return Err(e);
+                }
+                println!(
+                    "cargo:warning=Using previously generated {}: {:#}",
+                    previous.display(),
+                    e
+                );
+                return Ok(previous);
+            }
+        },
+    };
+
+    let out_path = out_dir.join(crate::module_name(&discdoc) + ".rs");
+    let stamp_path = out_path.with_extension("revision");
+    let stamp = format!(
+        "{} {}",
+        discdoc
+            .get("revision")
+            .and_then(Value::as_str)
+            .unwrap_or(""),
+        env!("CARGO_PKG_VERSION")
+    );
+    if out_path.exists() && std::fs::read_to_string(&stamp_path).ok().as_ref() == Some(&stamp) {
+        return Ok(out_path);
+    }
+
+    let code = strip_inner_attributes(&crate::generate(&discdoc)?);
+    std::fs::write(&out_path, code).context(format!("Could not write {}", out_path.display()))?;
+    std::fs::write(&stamp_path, stamp)?;
+    Ok(out_path)
+}
+
+/// Download the current Discovery document for `api` at `version` into `vendor_dir`, where
+/// `generate_in_build_rs()` picks it up. This pins the bindings to the downloaded revision until
+/// the document is vendored again.
+pub fn vendor_discovery_doc(api: &str, version: &str, vendor_dir: &Path) -> Result<PathBuf> {
+    let discdoc = crate::fetch_discovery_doc(&discovery_url(api, version), None)?;
+    std::fs::create_dir_all(vendor_dir)?;
+    let path = vendor_dir.join(format!("{}.{}.json", api, version));
+    std::fs::write(&path, serde_json::to_string_pretty(&discdoc)?)?;
+    Ok(path)
+}
+
+fn discovery_url(api: &str, version: &str) -> String {
+    format!(
+        "https://www.googleapis.com/discovery/v1/apis/{}/{}/rest",
+        api, version
+    )
+}
+
+/// The vendored Discovery document for `api` at `version`, if there is one.
+fn vendored_discovery_doc(api: &str, version: &str, opts: &BuildOptions) -> Result<Option<Value>> {
+    if let Some(dir) = opts.vendor_dir.as_ref() {
+        let vendored = dir.join(format!("{}.{}.json", api, version));
+        println!("cargo:rerun-if-changed={}", vendored.display());
+        if vendored.exists() {
+            return crate::fetch_discovery_doc(&vendored.to_string_lossy(), None).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+fn fetch_discovery_doc(api: &str, version: &str, opts: &BuildOptions) -> Result<Value> {
+    if opts.offline {
+        return Err(anyhow!(
+            "No vendored Discovery document for {}:{} found in {:?}, and fetching is disabled",
+            api,
+            version,
+            opts.vendor_dir
+        ));
+    }
+    crate::fetch_discovery_doc(&discovery_url(api, version), None)
+}
+
+/// `include!()` doesn't accept inner attributes and doc comments, which the generated header
+/// contains.
+fn strip_inner_attributes(code: &str) -> String {
+    code.lines()
+        .filter(|l| !l.starts_with("#![") && !l.starts_with("//!"))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_uses_previous_output() {
+        let out_dir =
+            std::env::temp_dir().join(format!("generate-rs-build-test-{}", std::process::id()));
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let opts = BuildOptions {
+            vendor_dir: Some(out_dir.join("discovery")),
+            offline: true,
+        };
+        assert!(generate_in_build_rs_with("drive", "v3", &out_dir, &opts).is_err());
+
+        let previous = out_dir.join("drive_v3_types.rs");
+        std::fs::write(&previous, "// generated").unwrap();
+        assert_eq!(
+            generate_in_build_rs_with("drive", "v3", &out_dir, &opts).unwrap(),
+            previous
+        );
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
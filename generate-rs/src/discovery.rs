@@ -0,0 +1,84 @@
+//! Fetching Discovery documents, optionally caching them on disk.
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde_json::Value;
+use std::path::Path;
+
+fn from_cache(cache_dir: &Path, api_id: &str) -> Option<Value> {
+    let p = cache_dir.join(format!("{}.json", api_id));
+    match std::fs::read(&p) {
+        Ok(contents) => {
+            info!("Found API description in cache for {}", api_id);
+            serde_json::from_slice(&contents).ok()
+        }
+        Err(e) => {
+            warn!("Fetching description from cache failed: {}", e);
+            None
+        }
+    }
+}
+
+fn to_cache(cache_dir: &Path, api_id: &str, doc: &Value) -> Result<()> {
+    std::fs::create_dir_all(cache_dir)?;
+    std::fs::write(
+        cache_dir.join(format!("{}.json", api_id)),
+        serde_json::to_vec(doc)?,
+    )?;
+    Ok(())
+}
+
+fn fetch_json(url: &str) -> Result<Value> {
+    let body = reqwest::blocking::get(url)?.text()?;
+    serde_json::from_str(&body).context(format!("Invalid JSON document at {}", url))
+}
+
+/// Fetch the discovery base document from `url`, and return the directory entries (containing
+/// e.g. `id` and `discoveryRestUrl`) of the APIs with IDs in `apis`, or all APIs if `apis` is
+/// empty.
+pub fn fetch_discovery_base(
+    url: &str,
+    apis: &[&str],
+    cache_dir: Option<&Path>,
+) -> Result<Vec<Value>> {
+    let doc = match cache_dir.and_then(|d| from_cache(d, "_global_discovery")) {
+        Some(doc) => doc,
+        None => {
+            let doc = fetch_json(url)?;
+            if let Some(d) = cache_dir {
+                to_cache(d, "_global_discovery", &doc)?;
+            }
+            doc
+        }
+    };
+    Ok(doc
+        .get("items")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|it| {
+            apis.is_empty() || apis.contains(&it.get("id").and_then(Value::as_str).unwrap_or(""))
+        })
+        .cloned()
+        .collect())
+}
+
+/// Fetch the Discovery document for one API, either from a URL or from a local file.
+/// Documents fetched from a URL are cached in `cache_dir`, if given.
+pub fn fetch_discovery_doc(url_or_path: &str, cache_dir: Option<&Path>) -> Result<Value> {
+    let cachekey = url_or_path.replace('/', "_");
+    if let Some(cached) = cache_dir.and_then(|d| from_cache(d, &cachekey)) {
+        return Ok(cached);
+    }
+
+    if url_or_path.starts_with("http") {
+        let js = fetch_json(url_or_path)?;
+        if let Some(d) = cache_dir {
+            to_cache(d, &cachekey, &js)?;
+        }
+        Ok(js)
+    } else {
+        let contents = std::fs::read(url_or_path)?;
+        serde_json::from_slice(&contents).context(format!("Invalid JSON in {}", url_or_path))
+    }
+}
@@ -0,0 +1,39 @@
+//! Generate asynchronous Rust stubs for Google APIs from their
+//! [Discovery documents](https://developers.google.com/discovery/v1/reference).
+//!
+//! This is a Rust port of the `generate.py` script, usable both as a binary (`generate-rs`) and as
+//! a library, e.g. from a `build.rs` script:
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! let doc = generate_rs::fetch_discovery_doc(
+//!     "https://www.googleapis.com/discovery/v1/apis/drive/v3/rest",
+//!     None,
+//! )?;
+//! let code = generate_rs::generate(&doc)?;
+//! let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+//! std::fs::write(out_dir.join(generate_rs::module_name(&doc) + ".rs"), code)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! For `build.rs` scripts, `generate_in_build_rs()` takes care of fetching (or reading vendored)
+//! Discovery documents and only regenerates code when the document's revision changes.
+//!
+//! The generated code depends on the `async-google-apis-common` crate.
+
+mod build_support;
+mod discovery;
+mod parser;
+mod report;
+mod templates;
+
+pub use build_support::{
+    generate_in_build_rs, generate_in_build_rs_with, vendor_discovery_doc, BuildOptions,
+};
+pub use discovery::{fetch_discovery_base, fetch_discovery_doc};
+pub use parser::{generate, generate_split_with, generate_with, module_name, GenerateOptions};
+pub use report::{api_report, diff_api_reports};
+
+/// The default location of the Discovery directory, listing all Google APIs.
+pub const DISCOVERY_BASE: &str = "https://www.googleapis.com/discovery/v1/apis";
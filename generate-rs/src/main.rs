@@ -1,51 +1,205 @@
-use anyhow::Result;
-
-fn main() {
-    // p = argparse.ArgumentParser(description='Generate Rust code for asynchronous REST Google APIs.')
-    // p.add_argument('--discovery_base',
-    //                default='https://www.googleapis.com/discovery/v1/apis',
-    //                help='Base Discovery document.')
-    // p.add_argument('--only_apis', default='drive:v3', help='Only process APIs with these IDs (comma-separated)')
-    // p.add_argument('--doc', default='', help='Directly process Discovery document from this URL')
-    // p.add_argument('--list', default=False, help='List available APIs', action='store_true')
-
-    // args = p.parse_args()
-
-    // if args.only_apis:
-    //     apilist = args.only_apis.split(',')
-    // else:
-    //     apilist = []
-
-    // if args.list:
-    //     docs = fetch_discovery_base(args.discovery_base, [])
-    //     for doc in docs:
-    //         print('API:', doc['title'], 'ID:', doc['id'])
-    //     return
-
-    // if args.doc:
-    //     discdoc = fetch_discovery_doc(args.doc)
-    //     if 'error' in discdoc:
-    //         print('Error while fetching document for', doc['id'], ':', discdoc)
-    //         return
-    //     if 'methods' in discdoc:
-    //         #raise NotImplementedError("top-level methods are not yet implemented properly. Please take care.")
-    //         pass
-    //     generate_all(discdoc)
-    //     return
-
-    // docs = fetch_discovery_base(args.discovery_base, apilist)
-
-    // for doc in docs:
-    //     try:
-    //         discdoc = fetch_discovery_doc(doc['discoveryRestUrl'])
-    //         if 'methods' in discdoc:
-    //             raise NotImplementedError("top-level methods are not yet implemented properly. Please take care.")
-    //         if 'error' in discdoc:
-    //             print('Error while fetching document for', doc['id'], ':', discdoc)
-    //             continue
-    //         generate_all(discdoc)
-    //     except Exception as e:
-    //         print("Error while processing", discdoc)
-    //         raise e
-    //         continue
+use anyhow::{Context, Result};
+use clap::{App, Arg};
+use log::{error, info, warn};
+use serde_json::Value;
+
+use std::path::Path;
+
+/// Generate code for `discdoc` into the `gen` directory, and format it using rustfmt. With `split`,
+/// a module tree is generated into a directory named like the module.
+fn generate_all(
+    discdoc: &Value,
+    options: &generate_rs::GenerateOptions,
+    split: bool,
+) -> Result<()> {
+    info!(
+        "Processing: {}",
+        discdoc.get("id").and_then(Value::as_str).unwrap_or("")
+    );
+    let out_path = if split {
+        let dir = Path::new("gen").join(generate_rs::module_name(discdoc));
+        std::fs::create_dir_all(&dir)?;
+        for (name, code) in generate_rs::generate_split_with(discdoc, options)? {
+            std::fs::write(dir.join(name), code)?;
+        }
+        dir.join("mod.rs")
+    } else {
+        let code = generate_rs::generate_with(discdoc, options)?;
+        std::fs::create_dir_all("gen")?;
+        let out_path = Path::new("gen").join(generate_rs::module_name(discdoc) + ".rs");
+        std::fs::write(&out_path, code)?;
+        out_path
+    };
+    // rustfmt also formats the files of a module tree, following the `mod` declarations.
+    if let Err(e) = std::process::Command::new("rustfmt")
+        .arg(&out_path)
+        .arg("--edition=2018")
+        .status()
+    {
+        warn!("Could not run rustfmt: {}", e);
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let matches = App::new("generate-rs")
+        .about("Generate Rust code for asynchronous REST Google APIs.")
+        .arg(
+            Arg::with_name("discovery_base")
+                .long("discovery_base")
+                .takes_value(true)
+                .default_value(generate_rs::DISCOVERY_BASE)
+                .help("Base Discovery document."),
+        )
+        .arg(
+            Arg::with_name("only_apis")
+                .long("only_apis")
+                .takes_value(true)
+                .default_value("drive:v3")
+                .help("Only process APIs with these IDs (comma-separated)"),
+        )
+        .arg(
+            Arg::with_name("doc")
+                .long("doc")
+                .takes_value(true)
+                .help("Directly process Discovery document from this URL"),
+        )
+        .arg(
+            Arg::with_name("list")
+                .long("list")
+                .help("List available APIs"),
+        )
+        .arg(
+            Arg::with_name("rename")
+                .long("rename")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("NAME=IDENT")
+                .help("Use IDENT for API names NAME that collide with Rust keywords (repeatable)"),
+        )
+        .arg(
+            Arg::with_name("typed_enums")
+                .long("typed_enums")
+                .help("Generate enums for string fields and parameters with enumerated values"),
+        )
+        .arg(
+            Arg::with_name("typed_ids")
+                .long("typed_ids")
+                .help("Generate newtypes like FileId for string IDs instead of using String"),
+        )
+        .arg(
+            Arg::with_name("page_size")
+                .long("page_size")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("METHOD=N")
+                .help("Default pageSize for the method with ID METHOD, e.g. drive.files.list=1000 (repeatable)"),
+        )
+        .arg(
+            Arg::with_name("param_type")
+                .long("param_type")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("METHOD.PARAM=TYPE")
+                .help("Use the Rust type TYPE for a parameter, e.g. drive.files.list.orderBy=crate::listing::OrderBy (repeatable)"),
+        )
+        .arg(
+            Arg::with_name("resource_features")
+                .long("resource_features")
+                .help("Compile the code of each resource only with a Cargo feature like drive-files"),
+        )
+        .arg(
+            Arg::with_name("split")
+                .long("split")
+                .help("Generate a module tree (types, parameters, one file per service) instead of a single file"),
+        )
+        .get_matches();
+
+    let cache = Some(Path::new("cache"));
+    let apilist: Vec<&str> = matches
+        .value_of("only_apis")
+        .unwrap_or("")
+        .split(',')
+        .filter(|a| !a.is_empty())
+        .collect();
+    let base = matches.value_of("discovery_base").unwrap();
+    let split = matches.is_present("split");
+    let mut options = generate_rs::GenerateOptions {
+        typed_enums: matches.is_present("typed_enums"),
+        resource_features: matches.is_present("resource_features"),
+        typed_ids: matches.is_present("typed_ids"),
+        ..Default::default()
+    };
+    for r in matches.values_of("rename").into_iter().flatten() {
+        match r.split_once('=') {
+            Some((name, ident)) if !name.is_empty() && !ident.is_empty() => {
+                options.renames.push((name.into(), ident.into()))
+            }
+            _ => return Err(anyhow::anyhow!("--rename expects NAME=IDENT, got {}", r)),
+        }
+    }
+    for ps in matches.values_of("page_size").into_iter().flatten() {
+        match ps.split_once('=').map(|(m, n)| (m, n.parse::<i64>())) {
+            Some((method, Ok(size))) if !method.is_empty() => {
+                options.page_sizes.push((method.into(), size))
+            }
+            _ => return Err(anyhow::anyhow!("--page_size expects METHOD=N, got {}", ps)),
+        }
+    }
+    for pt in matches.values_of("param_type").into_iter().flatten() {
+        match pt.split_once('=') {
+            Some((param, typ)) if param.contains('.') && !typ.is_empty() => {
+                options.param_types.push((param.into(), typ.into()))
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "--param_type expects METHOD.PARAM=TYPE, got {}",
+                    pt
+                ))
+            }
+        }
+    }
+
+    if matches.is_present("list") {
+        for doc in generate_rs::fetch_discovery_base(base, &[], cache)? {
+            println!(
+                "API: {} ID: {}",
+                doc["title"].as_str().unwrap_or(""),
+                doc["id"].as_str().unwrap_or("")
+            );
+        }
+        return Ok(());
+    }
+
+    if let Some(url) = matches.value_of("doc") {
+        let discdoc = generate_rs::fetch_discovery_doc(url, cache)?;
+        if discdoc.get("error").is_some() {
+            error!("Error while fetching document for {}: {}", url, discdoc);
+            return Ok(());
+        }
+        return generate_all(&discdoc, &options, split);
+    }
+
+    for doc in generate_rs::fetch_discovery_base(base, &apilist, cache)? {
+        let url = doc
+            .get("discoveryRestUrl")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        let discdoc = generate_rs::fetch_discovery_doc(url, cache)?;
+        if discdoc.get("error").is_some() {
+            error!(
+                "Error while fetching document for {}: {}",
+                doc["id"], discdoc
+            );
+            continue;
+        }
+        generate_all(&discdoc, &options, split)
+            .context(format!("Error while processing {}", doc["id"]))?;
+    }
+    Ok(())
 }
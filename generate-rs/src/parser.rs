@@ -1,593 +1,1968 @@
+//! Translation of Discovery documents into Rust source code. This is a port of `generate.py`;
+//! the functions carry the same names and work the same way.
+
+use crate::templates::*;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A field of a generated struct, as expected by `SCHEMA_STRUCT_TMPL`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub(crate) struct Field {
+    pub name: String,
+    pub original_name: String,
+    pub attr: String,
+    pub typ: String,
+    pub comment: Option<String>,
+    /// Whether the field holds a credential, hidden by `REDACTED_DEBUG_TMPL`.
+    pub sensitive: bool,
+}
+
+/// A generated struct, as expected by `SCHEMA_STRUCT_TMPL` and `PARAMS_QUERY_TMPL`. If `values`
+/// is not empty, it is a generated enum as expected by `ENUM_TMPL`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub(crate) struct Struct {
+    pub name: String,
+    pub description: String,
+    pub fields: Vec<Field>,
+    pub required_fields: Vec<Field>,
+    pub optional_fields: Vec<Field>,
+    pub values: Vec<EnumValue>,
+    /// If set, `Default` is implemented by `PARAMS_DEFAULT_TMPL` using `defaults` instead of
+    /// being derived.
+    pub custom_default: bool,
+    pub defaults: Vec<FieldDefault>,
+    /// Whether `Eq` is derived; not possible if the struct contains floating-point numbers.
+    pub eq: bool,
+    /// If set, `Debug` is implemented by `REDACTED_DEBUG_TMPL` instead of being derived.
+    pub redacted: bool,
+}
+
+/// The default value of a field, as expected by `PARAMS_DEFAULT_TMPL`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub(crate) struct FieldDefault {
+    pub name: String,
+    pub value: String,
+}
+
+/// A setter generated by `PARAMS_BUILDER_TMPL`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub(crate) struct Setter {
+    pub name: String,
+    /// The type of the field, without `Option`.
+    pub typ: String,
+    pub optional: bool,
+}
+
+/// A variant of a generated enum, as expected by `ENUM_TMPL`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub(crate) struct EnumValue {
+    pub variant: String,
+    pub value: String,
+    pub desc: String,
+}
+
+/// Options for `generate_with()`.
+#[derive(Debug, Clone, Default)]
+pub struct GenerateOptions {
+    /// Identifiers to use for API names, in addition to the default renames (e.g. `type` to
+    /// `typ`) of names colliding with Rust keywords.
+    pub renames: Vec<(String, String)>,
+    /// Generate enums for string fields and parameters with enumerated values, instead of using
+    /// `String`.
+    pub typed_enums: bool,
+    /// Default `pageSize` of the parameters of list methods, by method ID (e.g.
+    /// `("drive.files.list", 1000)`). Without a default, the server's page size applies, which
+    /// is often small.
+    pub page_sizes: Vec<(String, i64)>,
+    /// Types to use for parameters instead of the ones derived from the Discovery document, by
+    /// method ID and parameter name (e.g. `("drive.files.list.orderBy",
+    /// "crate::listing::OrderBy")`). The type is rendered into the query with `Display`, and must
+    /// implement the traits derived for parameters structs (`Serialize`, `Deserialize`, `Debug`,
+    /// `Clone`, `PartialEq` and `Eq`).
+    pub param_types: Vec<(String, String)>,
+    /// Compile the services of each top-level resource, their parameter types, and the schema
+    /// types only they use only with a Cargo feature named like `drive-files` enabled.
+    pub resource_features: bool,
+    /// Generate newtypes like `FileId(String)` for string IDs: `id` fields of schemas (e.g.
+    /// `File.id`), and fields and parameters named like `fileId`, instead of using `String`.
+    pub typed_ids: bool,
+}
+
+/// Render chainable setters for the fields of the *Params struct `st`.
+fn render_builder(st: &Struct) -> Result<String> {
+    let setters: Vec<Setter> = st
+        .fields
+        .iter()
+        .map(|f| {
+            match f
+                .typ
+                .strip_prefix("Option<")
+                .and_then(|t| t.strip_suffix('>'))
+            {
+                Some(inner) => Setter {
+                    name: f.name.clone(),
+                    typ: inner.to_string(),
+                    optional: true,
+                },
+                None => Setter {
+                    name: f.name.clone(),
+                    typ: f.typ.clone(),
+                    optional: false,
+                },
+            }
+        })
+        .collect();
+    render(
+        PARAMS_BUILDER_TMPL,
+        &json!({"name": st.name, "setters": setters}),
+    )
+}
+
+/// Standard parameters carrying credentials, which `Debug` doesn't show.
+const CREDENTIAL_PARAMS: &[&str] = &["oauth_token", "access_token", "key"];
+
+/// A Rust type, with a comment describing its use if available.
+type Typ = (String, Option<String>);
+
+fn render<T: Serialize>(template: &str, data: &T) -> Result<String> {
+    Ok(mustache::compile_str(template)?.render_to_string(data)?)
+}
+
+fn get_str<'a>(v: &'a Value, key: &str) -> &'a str {
+    v.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+fn get_obj<'a>(v: &'a Value, key: &str) -> Option<&'a Map<String, Value>> {
+    v.get(key).and_then(Value::as_object)
+}
+
 fn optionalize(name: &str, optional: bool) -> String {
-    unimplemented!()
-    //return 'Option<{}>'.format(name) if optional else name
+    if optional {
+        format!("Option<{}>", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Names that are replaced by a fixed identifier. Extended by `GenerateOptions::renames`.
+const RENAMES: &[(&str, &str)] = &[("type", "typ"), ("enum", "enums")];
+
+/// Rust keywords; not in `RENAMES`, they are emitted as raw identifiers (`r#match`).
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+/// Keywords that can't be raw identifiers; they get an underscore appended.
+const RUST_RESERVED: &[&str] = &["self", "Self", "super", "crate"];
+
+thread_local! {
+    /// Options for the file currently being generated.
+    static OPTIONS: RefCell<GenerateOptions> = const {
+        RefCell::new(GenerateOptions {
+            renames: Vec::new(),
+            typed_enums: false,
+            page_sizes: Vec::new(),
+            param_types: Vec::new(),
+            resource_features: false,
+            typed_ids: false,
+        })
+    };
+    /// Renames applied while generating the current file, listed in its documentation.
+    static RENAMED: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+    /// With `typed_ids`: the ID types used in the current file, and the schema names, which
+    /// ID types must not collide with.
+    static IDS: RefCell<(BTreeSet<String>, BTreeSet<String>)> =
+        const { RefCell::new((BTreeSet::new(), BTreeSet::new())) };
+}
+
+pub(crate) fn replace_keywords(name: &str) -> String {
+    let new = OPTIONS
+        .with(|o| {
+            o.borrow()
+                .renames
+                .iter()
+                .rev()
+                .find(|(from, _)| from == name)
+                .map(|(_, to)| to.clone())
+        })
+        .or_else(|| {
+            RENAMES
+                .iter()
+                .find(|(from, _)| *from == name)
+                .map(|(_, to)| to.to_string())
+        });
+    let new = match new {
+        Some(new) => new,
+        None if RUST_RESERVED.contains(&name) => format!("{}_", name),
+        None if RUST_KEYWORDS.contains(&name) => format!("r#{}", name),
+        None => return name.to_string(),
+    };
+    RENAMED.with(|r| r.borrow_mut().insert(name.to_string(), new.clone()));
+    new
 }
 
+/// With `typed_ids`, ID types of fields and parameters whose name doesn't say which kind of ID
+/// they hold, by name: `Schema.property` for a property of one (possibly nested) schema, or the
+/// bare name for all properties and parameters of that name.
+const ID_TYPES: &[(&str, &str)] = &[
+    ("headRevisionId", "RevisionId"),
+    ("FileShortcutDetails.targetId", "FileId"),
+    ("teamDriveId", "DriveId"),
+    ("TeamDrive.id", "DriveId"),
+];
 
-fn replace_keywords(name: &str) -> String {
-    unimplemented!()
-    // return {
-    //     'type': 'typ',
-    //     'enum': 'enums',
-    // }.get(name, name)
+/// With `typed_ids`, the ID type for the property or parameter `name` of type `schema`, e.g.
+/// `FileId` for `fileId`, or for the `id` of the schema `File` (`owner`). `parent` is the struct
+/// a property belongs to, for looking up `ID_TYPES`.
+fn id_type(
+    name: &str,
+    owner: Option<&str>,
+    parent: Option<&str>,
+    schema: &Value,
+) -> Option<String> {
+    if !OPTIONS.with(|o| o.borrow().typed_ids)
+        || get_str(schema, "type") != "string"
+        || schema.get("format").is_some()
+        || schema.get("enum").is_some()
+    {
+        return None;
+    }
+    let qualified = parent.map(|p| format!("{}.{}", p, name));
+    let listed = ID_TYPES
+        .iter()
+        .find(|(n, _)| Some(*n) == qualified.as_deref())
+        .or_else(|| ID_TYPES.iter().find(|(n, _)| *n == name));
+    let id = match (name, owner, listed) {
+        (_, _, Some((_, id))) => id.to_string(),
+        ("id", Some(owner), _) => format!("{}Id", owner),
+        _ if name.len() > 2 && name.ends_with("Id") => capitalize_first(name),
+        _ => return None,
+    };
+    if !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    IDS.with(|ids| {
+        let (used, schemas) = &mut *ids.borrow_mut();
+        if schemas.contains(&id) {
+            return None;
+        }
+        used.insert(id.clone());
+        Some(id)
+    })
 }
 
-fn capitalize_first(name: &str) -> String {
-    unimplemented!()
-    // if len(name) == 0:
-    //     return name
-    // return name[0].upper() + name[1:]
+pub(crate) fn capitalize_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+    }
 }
 
-fn rust_identifier(name: &str) -> String {
-    unimplemented!()
-    // def sanitize(s):
-    //     return s.replace('$', 'dollar').replace('#', 'hash').replace('.', '_')
+pub(crate) fn rust_identifier(name: &str) -> String {
+    let sanitized = name
+        .replace('$', "dollar")
+        .replace('#', "hash")
+        .replace('.', "_");
+    let mut ident = String::with_capacity(sanitized.len());
+    for (i, c) in sanitized.chars().enumerate() {
+        if i == 0 {
+            ident.extend(c.to_lowercase());
+        } else if c.is_uppercase() {
+            ident.push('_');
+            ident.extend(c.to_lowercase());
+        } else {
+            ident.push(c);
+        }
+    }
+    ident
+}
 
-    // def r(c):
-    //     if not c.isupper():
-    //         return c
-    //     return '_' + c.lower()
+pub(crate) fn snake_to_camel(name: &str) -> String {
+    let mut dest = String::with_capacity(name.len());
+    let mut capitalize = true;
+    for c in name.chars() {
+        if c == '_' {
+            capitalize = true;
+            continue;
+        }
+        if capitalize {
+            dest.extend(c.to_uppercase());
+            capitalize = false;
+            continue;
+        }
+        dest.push(c);
+    }
+    dest
+}
 
-    // return ''.join([(r(c) if i > 0 else c.lower()) for i, c in enumerate(sanitize(name))])
+/// Whether the Rust type `typ` mentions one of `names`.
+fn mentions(typ: &str, names: &BTreeSet<String>) -> bool {
+    typ.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .any(|t| names.contains(t))
 }
 
+/// The floating-point types, which don't implement `Eq`.
+fn float_types() -> BTreeSet<String> {
+    ["f32", "f64"].iter().map(|t| t.to_string()).collect()
+}
 
-fn snake_to_camel(name: &str) -> String {
-    unimplemented!()
-    // dest = []
-    // capitalize = True
-    // for c in name:
-    //     if c == '_':
-    //         capitalize = True
-    //         continue
-    //     if capitalize:
-    //         dest.append(c.upper())
-    //         capitalize = False
-    //         continue
-    //     dest.append(c)
-    // return ''.join(dest)
+/// The names of the `structs` that can't derive `Eq`, as they contain floating-point numbers,
+/// directly or through other structs.
+fn non_eq_structs<'a, I: Iterator<Item = &'a Struct> + Clone>(structs: I) -> BTreeSet<String> {
+    let mut non_eq = float_types();
+    loop {
+        let new: Vec<String> = structs
+            .clone()
+            .filter(|s| !non_eq.contains(&s.name))
+            .filter(|s| s.fields.iter().any(|f| mentions(&f.typ, &non_eq)))
+            .map(|s| s.name.clone())
+            .collect();
+        if new.is_empty() {
+            return non_eq;
+        }
+        non_eq.extend(new);
+    }
 }
 
 fn global_params_name(api_name: &str) -> String {
-    unimplemented!()
-    // return snake_to_camel(api_name + 'Params')
-}
-
-
-fn parse_schema_types(name: &str, schema: &str, optional: bool, parents: Vec<String>) {
-    // TODO: There should be a return type here
-    unimplemented!();
-    // """Translate a JSON schema type into Rust types, recursively.
-
-    // This function takes a schema entry from the `schemas` section of a Discovery document,
-    // and generates all Rust structs needed to represent the schema, recursively.
-
-    // Arguments:
-    //     name: Name of the property. If the property is an object with fixed fields, generate a struct with this name.
-    //     schema: A JSON object from a discovery document representing a type.
-
-    // Returns:
-    //     (tuple, [dict])
-
-    //     where type is a tuple where the first element is a Rust type and the
-    //     second element is a comment detailing the use of the field. The list of
-    //     dicts returned as second element are any structs that need to be separately
-    //     implemented and that the generated struct (if it was a struct) depends
-    //     on. The dict contains elements as expected by templates.SchemaStructTmpl.
-    // """
-    // typ = ''
-    // comment = ''
-    // structs = []
-    // try:
-    //     if '$ref' in schema:
-    //         # We just assume that there is already a type generated for the reference.
-    //         if schema['$ref'] not in parents:
-    //             return optionalize(schema['$ref'], optional), structs
-    //         return optionalize('Box<' + schema['$ref'] + '>', optional), structs
-    //     if 'type' in schema and schema['type'] == 'object':
-    //         # There are two types of objects: those with `properties` are translated into a Rust struct,
-    //         # and those with `additionalProperties` into a HashMap<String, ...>.
-
-    //         # Structs are represented as dicts that can be used to render the SchemaStructTmpl.
-    //         if 'properties' in schema:
-    //             name = replace_keywords(name)
-    //             typ = name
-    //             struct = {'name': name, 'description': schema.get('description', ''), 'fields': []}
-    //             for pn, pp in schema['properties'].items():
-    //                 subtyp, substructs = parse_schema_types(name + capitalize_first(pn),
-    //                                                         pp,
-    //                                                         optional=True,
-    //                                                         parents=parents + [name])
-    //                 if type(subtyp) is tuple:
-    //                     subtyp, comment = subtyp
-    //                 else:
-    //                     comment = None
-    //                 cleaned_pn = replace_keywords(pn)
-    //                 jsonname = pn
-    //                 cleaned_pn = rust_identifier(cleaned_pn)
-    //                 struct['fields'].append({
-    //                     'name':
-    //                     cleaned_pn,
-    //                     'original_name':
-    //                     jsonname,
-    //                     'attr':
-    //                     '#[serde(rename = "{}")]'.format(jsonname) +
-    //                     '\n    #[serde(skip_serializing_if = "Option::is_none")]'
-    //                     if subtyp.startswith('Option') else '',
-    //                     'typ':
-    //                     subtyp,
-    //                     'comment':
-    //                     comment
-    //                 })
-    //                 structs.extend(substructs)
-    //             structs.append(struct)
-    //             return (optionalize(typ, optional), schema.get('description', '')), structs
-
-    //         if 'additionalProperties' in schema:
-    //             field, substructs = parse_schema_types(name,
-    //                                                    schema['additionalProperties'],
-    //                                                    optional=False,
-    //                                                    parents=parents + [name])
-    //             structs.extend(substructs)
-    //             if type(field) is tuple:
-    //                 typ = field[0]
-    //             else:
-    //                 typ = field
-    //             return (optionalize('HashMap<String,' + typ + '>', optional), schema.get('description', '')), structs
-
-    //     if schema['type'] == 'array':
-    //         typ, substructs = parse_schema_types(name, schema['items'], optional=False, parents=parents + [name])
-    //         if type(typ) is tuple:
-    //             typ = typ[0]
-    //         return (optionalize('Vec<' + typ + '>', optional), schema.get('description', '')), structs + substructs
-
-    //     if schema['type'] == 'string':
-
-    //         def build(intt, typ='String'):
-    //             return (optionalize(typ, optional), intt + ': ' + schema.get('description', '')), structs
-
-    //         if 'format' in schema:
-    //             if schema['format'] == 'int64':
-    //                 return build('i64')
-    //             if schema['format'] == 'int32':
-    //                 return build('i32')
-    //             if schema['format'] == 'uint64':
-    //                 return build('u64')
-    //             if schema['format'] == 'uint32':
-    //                 return build('u32')
-    //             if schema['format'] == 'double':
-    //                 return build('f64')
-    //             if schema['format'] == 'float':
-    //                 return build('f32')
-    //             if schema['format'] == 'date-time':
-    //                 return build('DateTime', typ='DateTime<Utc>')
-    //         return (optionalize('String', optional), schema.get('description', '')), structs
-
-    //     if schema['type'] == 'boolean':
-    //         return (optionalize('bool', optional), schema.get('description', '')), structs
-
-    //     if schema['type'] in ('number', 'integer'):
-
-    //         def build(intt):
-    //             return (optionalize(intt, optional), schema.get('description', '')), structs
-
-    //         if schema['format'] == 'float':
-    //             return build('f32')
-    //         if schema['format'] == 'double':
-    //             return build('f64')
-    //         if schema['format'] == 'int32':
-    //             return build('i32')
-    //         if schema['format'] == 'int64':
-    //             return build('i64')
-    //         if schema['format'] == 'uint32':
-    //             return build('u32')
-    //         if schema['format'] == 'uint64':
-    //             return build('u64')
-
-    //     if schema['type'] == 'any':
-    //         return (optionalize('String', optional), 'ANY data: ' + schema.get('description', '')), structs
-
-    //     raise Exception('unimplemented schema type!', name, schema)
-    // except KeyError as e:
-    //     print('KeyError while processing:', name, schema)
-    //     raise e
-}
-
-fn generate_params_structs(resources: Vec<String>, super_name: Option<String>, global_params: Option<String>) -> Vec<String> {
-    unimplemented!()
-    // """Generate parameter structs from the resources list.
-
-    // Returns a list of source code strings.
-    // """
-    // frags = []
-    // for resourcename, resource in resources.items():
-    //     for methodname, method in resource.get('methods', {}).items():
-    //         param_type_name = snake_to_camel(super_name + capitalize_first(resourcename) +
-    //                                          capitalize_first(methodname) + 'Params')
-    //         print("processed:", resourcename, methodname, param_type_name)
-    //         struct = {
-    //             'name': param_type_name,
-    //             'description': 'Parameters for the `{}.{}` method.'.format(resourcename, methodname),
-    //             'fields': []
-    //         }
-    //         req_query_parameters = []
-    //         opt_query_parameters = []
-    //         if global_params:
-    //             struct['fields'].append({
-    //                 'name': replace_keywords(rust_identifier(global_params)),
-    //                 'typ': optionalize(global_params, True),
-    //                 'attr': '#[serde(flatten)]',
-    //                 'comment': 'General attributes applying to any API call'
-    //             })
-    //         # Build struct dict for rendering.
-    //         if 'parameters' in method:
-    //             for paramname, param in method['parameters'].items():
-    //                 (typ, desc), substructs = parse_schema_types('', param, optional=False, parents=[])
-    //                 field = {
-    //                     'name': replace_keywords(rust_identifier(paramname)),
-    //                     'original_name': paramname,
-    //                     'typ': optionalize(typ, not param.get('required', False)),
-    //                     'comment': desc,
-    //                     'attr': '#[serde(rename = "{}")]'.format(paramname),
-    //                 }
-    //                 struct['fields'].append(field)
-    //                 if param.get('location', '') == 'query':
-    //                     if param.get('required', False):
-    //                         req_query_parameters.append(field)
-    //                     else:
-    //                         opt_query_parameters.append(field)
-    //         frags.append(chevron.render(SchemaStructTmpl, struct))
-    //         struct['required_fields'] = req_query_parameters
-    //         struct['optional_fields'] = opt_query_parameters
-    //         frags.append(chevron.render(SchemaDisplayTmpl, struct))
-    //     # Generate parameter types for subresources.
-    //     frags.extend(
-    //         generate_params_structs(resource.get('resources', {}), super_name=resourcename,
-    //                                 global_params=global_params))
-    // return frags
-}
-
-fn resolve_parameters(string: &str, paramsname: Option<String>) -> String {
-    unimplemented!()
-    // """Returns a Rust syntax for formatting the given string with API
-    // parameters, and a list of (snake-case) API parameters that are used. This
-    // is typically used to format URL paths containing required parameters for an
-    // API call.
-    // """
-    // pat = re.compile('\{\+?(\w+)\}')
-    // params = re.findall(pat, string)
-    // snakeparams = [rust_identifier(p) for p in params]
-    // format_params = ','.join([
-    //     '{}=percent_encode({}.{}.as_bytes(), NON_ALPHANUMERIC)'.format(p, paramsname, sp)
-    //     for (p, sp) in zip(params, snakeparams)
-    // ])
-    // string = string.replace('{+', '{')
-    // # Some required parameters are in the URL. This rust syntax formats the relative URL part appropriately.
-    // return 'format!("{}", {})'.format(string, format_params), snakeparams
-}
-
-fn generate_service(resource: &str, methods: &str, discdoc: &str, generate_subresources: bool) -> String {
-    unimplemented!()
-    // """Generate the code for all methods in a resource.
-
-    // Returns a rendered string with source code.
-    // """
-    // service = capitalize_first(resource)
-    // # Source code fragments implementing the methods.
-    // method_fragments = []
-    // # Source code fragments for impls of subordinate resources.
-    // subresource_fragments = []
-
-    // # Generate methods for subresources.
-    // if generate_subresources:
-    //     for subresname, subresource in methods.get('resources', {}).items():
-    //         subresource_fragments.append(generate_service(service + capitalize_first(subresname), subresource, discdoc))
-
-    // for methodname, method in methods.get('methods', {}).items():
-    //     # Goal: Instantiate the templates for upload and non-upload methods.
-
-    //     # e.g. FilesGetParams
-    //     params_type_name = service + capitalize_first(methodname) + 'Params'
-    //     # All parameters that are optional (as URL parameters)
-    //     parameters = {
-    //         p: rust_identifier(p)
-    //         for p, pp in method.get('parameters', {}).items() if ('required' not in pp and pp['location'] != 'path')
-    //     }
-    //     # All required parameters not represented in the path.
-    //     required_parameters = {
-    //         p: rust_identifier(p)
-    //         for p, pp in method.get('parameters', {}).items() if ('required' in pp and pp['location'] != 'path')
-    //     }
-    //     # Types of the function
-    //     in_type = method['request']['$ref'] if 'request' in method else None
-    //     out_type = method['response']['$ref'] if 'response' in method else '()'
-
-    //     is_download = method.get('supportsMediaDownload', False)
-    //     is_authd = 'scopes' in method
-
-    //     media_upload = method.get('mediaUpload', {})
-    //     supported_uploads = []
-    //     if 'simple' in media_upload.get('protocols', {}):
-    //         simple_upload_path = media_upload['protocols']['simple']['path']
-    //         supported_uploads.append('simple')
-    //     else:
-    //         simple_upload_path = ''
-    //     if 'resumable' in media_upload.get('protocols', {}):
-    //         resumable_upload_path = media_upload['protocols']['resumable']['path']
-    //         supported_uploads.append('resumable')
-    //     else:
-    //         resumable_upload_path = ''
-
-    //     http_method = method['httpMethod']
-    //     has_global_params = 'parameters' in discdoc
-    //     # This relies on URL path parameters being required parameters (not
-    //     # optional). If this invariant is not fulfilled, the Rust code may not
-    //     # compile.
-    //     formatted_path, required_params = resolve_parameters(method['path'])
-    //     formatted_simple_upload_path, required_params = resolve_parameters(simple_upload_path)
-    //     formatted_resumable_upload_path, required_params = resolve_parameters(resumable_upload_path)
-
-    //     scopetype, scopeval = scopes_url_to_enum_val(discdoc['name'], method.get('scopes', [''])[-1])
-    //     scope_enum = scopetype + '::' + scopeval
-
-    //     if is_download:
-    //         data_download = {
-    //             'name':
-    //             rust_identifier(methodname),
-    //             'param_type':
-    //             params_type_name,
-    //             'in_type':
-    //             in_type,
-    //             'download_in_type':
-    //             in_type if in_type else 'EmptyRequest',
-    //             'out_type':
-    //             out_type,
-    //             'base_path':
-    //             discdoc['baseUrl'],
-    //             'root_path':
-    //             discdoc['rootUrl'],
-    //             'rel_path_expr':
-    //             formatted_path,
-    //             'params': [{
-    //                 'param': p,
-    //                 'snake_param': sp
-    //             } for (p, sp) in parameters.items()],
-    //             'required_params': [{
-    //                 'param': p,
-    //                 'snake_param': sp
-    //             } for (p, sp) in required_parameters.items()],
-    //             'global_params_name':
-    //             rust_identifier(global_params_name(discdoc.get('name', ''))) if has_global_params else None,
-    //             'scopes': [{
-    //                 'scope': scope_enum,
-    //             }],
-    //             'description':
-    //             method.get('description', ''),
-    //             'http_method':
-    //             http_method,
-    //             'wants_auth':
-    //             is_authd,
-    //         }
-    //         method_fragments.append(chevron.render(DownloadMethodTmpl, data_download))
-    //     else:
-    //         data_normal = {
-    //             'name':
-    //             rust_identifier(methodname),
-    //             'param_type':
-    //             params_type_name,
-    //             'in_type':
-    //             in_type,
-    //             'out_type':
-    //             out_type,
-    //             'base_path':
-    //             discdoc['baseUrl'],
-    //             'root_path':
-    //             discdoc['rootUrl'],
-    //             'rel_path_expr':
-    //             formatted_path,
-    //             'params': [{
-    //                 'param': p,
-    //                 'snake_param': sp
-    //             } for (p, sp) in parameters.items()],
-    //             'global_params_name':
-    //             rust_identifier(global_params_name(discdoc.get('name', ''))) if has_global_params else None,
-    //             'required_params': [{
-    //                 'param': p,
-    //                 'snake_param': sp
-    //             } for (p, sp) in required_parameters.items()],
-    //             'scopes': [{
-    //                 'scope': scope_enum,
-    //             }],
-    //             'description':
-    //             method.get('description', ''),
-    //             'http_method':
-    //             http_method,
-    //             'wants_auth':
-    //             is_authd,
-    //         }
-    //         method_fragments.append(chevron.render(NormalMethodTmpl, data_normal))
-
-    //     # We generate an additional implementation with the option of uploading data.
-    //     data_upload = {
-    //         'name': rust_identifier(methodname),
-    //         'param_type': params_type_name,
-    //         'in_type': in_type,
-    //         'out_type': out_type,
-    //         'base_path': discdoc['baseUrl'],
-    //         'root_path': discdoc['rootUrl'],
-    //         'simple_rel_path_expr': formatted_simple_upload_path.lstrip('/'),
-    //         'resumable_rel_path_expr': formatted_resumable_upload_path.lstrip('/'),
-    //         'global_params_name':
-    //         rust_identifier(global_params_name(discdoc.get('name', ''))) if has_global_params else None,
-    //         'params': [{
-    //             'param': p,
-    //             'snake_param': sp
-    //         } for (p, sp) in parameters.items()],
-    //         'required_params': [{
-    //             'param': p,
-    //             'snake_param': sp
-    //         } for (p, sp) in required_parameters.items()],
-    //         'scopes': [{
-    //             'scope': scope_enum,
-    //         }],
-    //         'description': method.get('description', ''),
-    //         'http_method': http_method,
-    //         'wants_auth': is_authd,
-    //     }
-    //     if 'simple' in supported_uploads:
-    //         method_fragments.append(chevron.render(UploadMethodTmpl, data_upload))
-    //     if 'resumable' in supported_uploads:
-    //         method_fragments.append(chevron.render(ResumableUploadMethodTmpl, data_upload))
-
-    // return chevron.render(
-    //     ServiceImplementationTmpl, {
-    //         'service': service,
-    //         'name': capitalize_first(discdoc.get('name', '')),
-    //         'base_path': discdoc['baseUrl'],
-    //         'root_path': discdoc['rootUrl'],
-    //         'wants_auth': 'auth' in discdoc,
-    //         'methods': [{
-    //             'text': t
-    //         } for t in method_fragments]
-    //     }) + '\n'.join(subresource_fragments)
-}
-
-fn scopes_url_to_enum_val(apiname: &str, url: &str) -> String {
-    unimplemented()
-    // rawname = url.split('/')[-1]
-    // fancy_name = snake_to_camel(rawname.replace('-', '_').replace('.', '_'))
-    // return (snake_to_camel(apiname)+'Scopes', fancy_name)
-}
-
-fn generate_scopes_type(name: &str, scopes: &str) -> String {
-    // """Generate types for the `scopes` dictionary (path: auth.oauth2.scopes in a discovery document),
-    // containing { scope_url: { description: "..." } }.
-    // """
-    // if len(scopes) == 0:
-    //     return ''
-    // parameters = {'scopes': []}
-    // for url, desc in scopes.items():
-    //     enum_type_name, fancy_name = scopes_url_to_enum_val(name, url)
-    //     parameters['name'] = enum_type_name
-    //     parameters['scopes'].append({'scope_name': fancy_name, 'desc': desc.get('description', ''), 'url': url})
-    // return chevron.render(OauthScopesType, parameters)
-}
-
-fn generate_all(discdoc: &str) -> String {
-    unimplemented!()
-    // """Generate all structs and impls, and render them into a file."""
-    // print('Processing:', discdoc.get('id', ''))
-    // schemas = discdoc.get('schemas', {})
-    // resources = discdoc.get('resources', {})
-    // # Generate scopes.
-    // scopes_type = generate_scopes_type(discdoc['name'], discdoc.get('auth', {}).get('oauth2', {}).get('scopes', {}))
-
-    // # Generate parameter types (*Params - those are used as "side inputs" to requests)
-    // params_struct_name = global_params_name(discdoc.get('name'))
-    // parameter_types = generate_params_structs(resources, global_params=params_struct_name)
-
-    // # Generate service impls.
-    // services = []
-    // for resource, methods in resources.items():
-    //     services.append(generate_service(resource, methods, discdoc))
-    // if 'methods' in discdoc:
-    //     services.append(generate_service('Global', discdoc, discdoc, generate_subresources=False))
-
-    // # Generate schema types.
-    // structs = []
-    // for name, desc in schemas.items():
-    //     typ, substructs = parse_schema_types(name, desc)
-    //     structs.extend(substructs)
-
-    // # Generate global parameters struct and its Display impl.
-    // if 'parameters' in discdoc:
-    //     schema = {'type': 'object', 'properties': discdoc['parameters']}
-    //     name = replace_keywords(snake_to_camel(params_struct_name))
-    //     typ, substructs = parse_schema_types(name, schema)
-    //     for s in substructs:
-    //         s['optional_fields'] = s['fields']
-    //         parameter_types.append(chevron.render(SchemaDisplayTmpl, s))
-    //     structs.extend(substructs)
-
-    // # Assemble everything into a file.
-    // modname = (discdoc['id'] + '_types').replace(':', '_')
-    // out_path = path.join('gen', modname + '.rs')
-    // with open(out_path, 'w') as f:
-    //     f.write(RustHeader)
-    //     f.write(scopes_type)
-    //     # Render resource structs.
-    //     for s in structs:
-    //         for field in s['fields']:
-    //             if field.get('comment', None):
-    //                 field['comment'] = field.get('comment', '').replace('\n', ' ')
-    //         if not s['name']:
-    //             print('WARN', s)
-    //         f.write(chevron.render(SchemaStructTmpl, s))
-    //     # Render *Params structs.
-    //     for pt in parameter_types:
-    //         f.write(pt)
-    //     # Render service impls.
-    //     for s in services:
-    //         f.write(s)
-    // try:
-    //     subprocess.run(['rustfmt', out_path, '--edition=2018'])
-    // except:
-    //     return
-}
-
-fn from_cache(api_id: &str) -> String {
-    unimplemented!()
-    // try:
-    //     with open(path.join('cache', apiId + '.json'), 'r') as f:
-    //         print('Found API description in cache for', apiId)
-    //         return json.load(f)
-    // except Exception as e:
-    //     print('Fetching description from cache failed:', e)
-    //     return None
-}
-
-fn to_cache(api_id: &str, doc: &str) -> Result<()> {
-    unimplemented!()
-    // try:
-    //     os.makedirs('cache', exist_ok=True)
-    //     with open(path.join('cache', apiId + '.json'), 'w') as f:
-    //         json.dump(doc, f)
-    // except Exception as e:
-    //     print(e)
-    //     return None
-    // return None
-}
-
-
-fn fetch_discovery_base(url: &str, apis: &str) -> Vec<String> {
-    unimplemented!()
-    // """Fetch the discovery base document from `url`. Return api documents for APIs with IDs in `apis`.
-
-    // Returns:
-    //     List of API JSON documents.
-    // """
-    // doc = from_cache('_global_discovery')
-    // if not doc:
-    //     doc = json.loads(requests.get(url).text)
-    //     to_cache('_global_discovery', doc)
-    // return [it for it in doc['items'] if (not apis or it['id'] in apis)]
-}
-
-
-fn fetch_discovery_doc(url_or_path: &str) -> String {
-    unimplemented!()
-    // """Fetch discovery document for a given (short) API doc from the overall discovery document."""
-    // cachekey = url_or_path.replace('/', '_')
-    // cached = from_cache(cachekey)
-    // if cached:
-    //     return cached
-
-    // if url_or_path.startswith('http'):
-    //     js = json.loads(requests.get(url_or_path).text)
-    //     to_cache(cachekey, js)
-    // else:
-    //     with open(url_or_path, 'r') as f:
-    //         js = json.load(f)
-    // return js
+    snake_to_camel(&format!("{}Params", api_name))
+}
+
+/// Translate a JSON schema type into Rust types, recursively.
+///
+/// This function takes a schema entry from the `schemas` section of a Discovery document, and
+/// generates all Rust structs needed to represent the schema, recursively.
+///
+/// `name` is the name of the property; if the property is an object with fixed fields, a struct
+/// with this name is generated. Returns the Rust type (with a comment, if available) and all
+/// structs that need to be separately implemented.
+fn parse_schema_types(
+    name: &str,
+    schema: &Value,
+    optional: bool,
+    parents: &[String],
+) -> Result<(Typ, Vec<Struct>)> {
+    let mut structs = vec![];
+    let description = get_str(schema, "description").to_string();
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        // We just assume that there is already a type generated for the reference.
+        if !parents.iter().any(|p| p == reference) {
+            return Ok(((optionalize(reference, optional), None), structs));
+        }
+        return Ok((
+            (optionalize(&format!("Box<{}>", reference), optional), None),
+            structs,
+        ));
+    }
+
+    let mut parents = parents.to_vec();
+    parents.push(name.to_string());
+    let typ = get_str(schema, "type");
+
+    if typ == "object" {
+        // There are two types of objects: those with `properties` are translated into a Rust
+        // struct, and those with `additionalProperties` into a HashMap<String, ...>.
+        if let Some(properties) = get_obj(schema, "properties") {
+            let name = replace_keywords(name);
+            let mut parents = parents.clone();
+            *parents.last_mut().unwrap() = name.clone();
+            let mut st = Struct {
+                name: name.clone(),
+                description: description.clone(),
+                ..Default::default()
+            };
+            for (pn, pp) in properties {
+                let mut pp = pp.clone();
+                if pp.get("enum").is_none()
+                    && get_str(&pp, "type") == "string"
+                    && get_str(&pp, "format").is_empty()
+                {
+                    let values = documented_values(get_str(&pp, "description"));
+                    if !values.is_empty() {
+                        pp["enum"] = json!(values);
+                    }
+                }
+                let pp = &pp;
+                let ((subtyp, comment), substructs) = parse_schema_types(
+                    &format!("{}{}", name, snake_to_camel(&rust_identifier(pn))),
+                    pp,
+                    true,
+                    &parents,
+                )?;
+                let mut attr = if subtyp.starts_with("Option") {
+                    format!(
+                        "#[serde(rename = \"{}\")]\n    #[serde(skip_serializing_if = \"Option::is_none\")]",
+                        pn
+                    )
+                } else {
+                    String::new()
+                };
+                // Integers transmitted as strings are represented as integers.
+                let subtyp = match (get_str(pp, "type"), string_int_type(get_str(pp, "format"))) {
+                    ("string", Some(int)) => {
+                        attr.push_str("\n    #[serde(with = \"str_num\", default)]");
+                        optionalize(int, true)
+                    }
+                    // Timestamps are parsed leniently.
+                    ("string", None) if get_str(pp, "format") == "date-time" => {
+                        attr.push_str("\n    #[serde(with = \"lenient_time\", default)]");
+                        subtyp
+                    }
+                    _ => subtyp,
+                };
+                let owner = Some(name.as_str()).filter(|_| parents.len() == 1);
+                let subtyp = match id_type(pn, owner, Some(&name), pp) {
+                    Some(id) => optionalize(&id, true),
+                    None => subtyp,
+                };
+                st.fields.push(Field {
+                    name: replace_keywords(&rust_identifier(pn)),
+                    original_name: pn.clone(),
+                    attr,
+                    typ: subtyp,
+                    comment,
+                    ..Default::default()
+                });
+                structs.extend(substructs);
+            }
+            structs.push(st);
+            return Ok(((optionalize(&name, optional), Some(description)), structs));
+        }
+
+        if let Some(additional) = schema.get("additionalProperties") {
+            let ((field, _), substructs) = parse_schema_types(name, additional, false, &parents)?;
+            structs.extend(substructs);
+            return Ok((
+                (
+                    optionalize(&format!("HashMap<String,{}>", field), optional),
+                    Some(description),
+                ),
+                structs,
+            ));
+        }
+    }
+
+    let build =
+        |typ: &str, comment: String| Ok(((optionalize(typ, optional), Some(comment)), vec![]));
+    match typ {
+        "array" => {
+            let items = schema
+                .get("items")
+                .ok_or_else(|| anyhow!("array without items: {} {}", name, schema))?;
+            let ((typ, _), substructs) = parse_schema_types(name, items, false, &parents)?;
+            Ok((
+                (
+                    optionalize(&format!("Vec<{}>", typ), optional),
+                    Some(description),
+                ),
+                substructs,
+            ))
+        }
+        "string" => {
+            let typed_enums = OPTIONS.with(|o| o.borrow().typed_enums);
+            if let (true, Some(values), "") = (
+                typed_enums,
+                schema.get("enum").and_then(Value::as_array),
+                get_str(schema, "format"),
+            ) {
+                let enum_name = snake_to_camel(&rust_identifier(name));
+                let e = generate_enum(&enum_name, schema, values);
+                return Ok((
+                    (optionalize(&enum_name, optional), Some(description)),
+                    vec![e],
+                ));
+            }
+            let with_format =
+                |intt: &str, typ: &str| build(typ, format!("{}: {}", intt, description));
+            match get_str(schema, "format") {
+                "int64" => with_format("i64", "String"),
+                "int32" => with_format("i32", "String"),
+                "uint64" => with_format("u64", "String"),
+                "uint32" => with_format("u32", "String"),
+                "double" => with_format("f64", "String"),
+                "float" => with_format("f32", "String"),
+                "date-time" => with_format("DateTime", "DateTime<Utc>"),
+                _ => build("String", description.clone()),
+            }
+        }
+        "boolean" => build("bool", description),
+        "number" | "integer" => match get_str(schema, "format") {
+            "float" => build("f32", description),
+            "double" => build("f64", description),
+            "int32" => build("i32", description),
+            "int64" => build("i64", description),
+            "uint32" => build("u32", description),
+            "uint64" => build("u64", description),
+            f => Err(anyhow!(
+                "unimplemented number format {}: {} {}",
+                f,
+                name,
+                schema
+            )),
+        },
+        "any" => build("String", format!("ANY data: {}", description)),
+        _ => Err(anyhow!("unimplemented schema type! {} {}", name, schema)),
+    }
+}
+
+/// The Rust type used for struct fields of string type with integer `format`.
+fn string_int_type(format: &str) -> Option<&'static str> {
+    match format {
+        "int64" => Some("i64"),
+        "int32" => Some("i32"),
+        "uint64" => Some("u64"),
+        "uint32" => Some("u32"),
+        _ => None,
+    }
+}
+
+fn enum_variant(value: &str) -> String {
+    let cleaned: String = rust_identifier(value)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let variant = snake_to_camel(&cleaned);
+    match variant.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => variant,
+        _ => format!("V{}", variant),
+    }
+}
+
+/// Build an enum for rendering with `ENUM_TMPL` from a string schema with enumerated `values`.
+fn generate_enum(name: &str, schema: &Value, values: &[Value]) -> Struct {
+    let descs = schema.get("enumDescriptions").and_then(Value::as_array);
+    let mut used = vec!["Other".to_string()];
+    let mut e = Struct {
+        name: name.to_string(),
+        description: get_str(schema, "description").replace('\n', " "),
+        ..Default::default()
+    };
+    for (i, v) in values.iter().enumerate() {
+        let value = v.as_str().unwrap_or("");
+        let mut variant = enum_variant(value);
+        while used.contains(&variant) {
+            variant.push('_');
+        }
+        used.push(variant.clone());
+        let desc = descs
+            .and_then(|d| d.get(i))
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        e.values.push(EnumValue {
+            variant,
+            value: value.to_string(),
+            desc: desc.replace('\n', " "),
+        });
+    }
+    e
+}
+
+/// Intros of dash lists of values, as in "the following are currently allowed:   - owner  - writer".
+const VALUE_LIST_INTROS: &[&str] = &[
+    "are currently possible:",
+    "are currently allowed:",
+    "Valid values are:",
+];
+
+/// The values a string parameter or property accepts according to its description, for those
+/// without `enum` whose description reads like "Only 'published' is supported", "Supported
+/// groupings are: 'user' (...), 'drive' (...) and 'allDrives' (...)" or "Valid values are:
+/// - user  - group". Empty if the description doesn't enumerate values.
+fn documented_values(description: &str) -> Vec<String> {
+    if let Some((i, intro)) = VALUE_LIST_INTROS
+        .iter()
+        .find_map(|intro| description.find(intro).map(|i| (i, intro)))
+    {
+        // `- value` pairs up to the first word that isn't a dash.
+        let mut values = vec![];
+        let mut words = description[i + intro.len()..].split_whitespace();
+        while let (Some("-"), Some(value)) = (words.next(), words.next()) {
+            if !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return vec![];
+            }
+            values.push(value.to_string());
+        }
+        return values;
+    }
+    let only = description.find("Only '");
+    let list = if let Some(i) = only {
+        &description[i + "Only ".len()..]
+    } else if let Some(i) = description.find("Supported ") {
+        let rest = &description[i..];
+        match rest.find(" are: ") {
+            Some(j) if !rest[..j].contains(". ") => &rest[j + " are: ".len()..],
+            _ => return vec![],
+        }
+    } else {
+        return vec![];
+    };
+    // Quoted values outside of parentheses, up to the end of the sentence.
+    let mut values = vec![];
+    let mut depth = 0;
+    let mut end = list.len();
+    let mut chars = list.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '.' if depth == 0 && !matches!(chars.peek(), Some((_, n)) if *n != ' ') => {
+                end = i;
+                break;
+            }
+            '\'' if depth == 0 => {
+                values.push(
+                    chars
+                        .by_ref()
+                        .map(|(_, c)| c)
+                        .take_while(|c| *c != '\'')
+                        .collect(),
+                );
+            }
+            _ => {}
+        }
+    }
+    let valid = |v: &String| {
+        !v.is_empty()
+            && v.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    };
+    if !values.iter().all(valid) || (only.is_some() && !list[..end].contains("supported")) {
+        return vec![];
+    }
+    values
+}
+
+/// Generate parameter structs from the resources list. Returns a list of source code strings.
+fn generate_params_structs(
+    resources: &Map<String, Value>,
+    super_name: &str,
+    global_params: Option<&str>,
+) -> Result<Vec<String>> {
+    let mut frags = vec![];
+    for (resourcename, resource) in resources {
+        for (methodname, method) in get_obj(resource, "methods").into_iter().flatten() {
+            let param_type_name = snake_to_camel(&format!(
+                "{}{}{}Params",
+                super_name,
+                capitalize_first(resourcename),
+                capitalize_first(methodname)
+            ));
+            let mut st = Struct {
+                name: param_type_name,
+                description: format!(
+                    "Parameters for the `{}.{}` method.",
+                    resourcename, methodname
+                ),
+                ..Default::default()
+            };
+            if let Some(global_params) = global_params {
+                st.fields.push(Field {
+                    name: replace_keywords(&rust_identifier(global_params)),
+                    typ: optionalize(global_params, true),
+                    attr: "#[serde(flatten)]".into(),
+                    comment: Some("General attributes applying to any API call".into()),
+                    ..Default::default()
+                });
+            }
+            // Build struct for rendering.
+            for (paramname, param) in get_obj(method, "parameters").into_iter().flatten() {
+                let mut param = param.clone();
+                if get_str(&param, "location") == "path" {
+                    // Path parameters are used as strings.
+                    param.as_object_mut().map(|p| p.remove("enum"));
+                } else if param.get("enum").is_none() && get_str(&param, "type") == "string" {
+                    let values = documented_values(get_str(&param, "description"));
+                    if !values.is_empty() {
+                        param["enum"] = json!(values);
+                    }
+                }
+                let ((typ, desc), enums) = parse_schema_types(
+                    &format!("{}{}", st.name, snake_to_camel(&rust_identifier(paramname))),
+                    &param,
+                    false,
+                    &[],
+                )?;
+                let typ = match param_type(get_str(method, "id"), paramname) {
+                    Some(typ) => typ,
+                    None => {
+                        for e in enums {
+                            frags.push(render(ENUM_TMPL, &e)?);
+                        }
+                        id_type(paramname, None, None, &param).unwrap_or(typ)
+                    }
+                };
+                let required = param
+                    .get("required")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false);
+                let field = Field {
+                    name: replace_keywords(&rust_identifier(paramname)),
+                    original_name: paramname.clone(),
+                    typ: optionalize(&typ, !required),
+                    comment: desc,
+                    attr: format!("#[serde(rename = \"{}\")]", paramname),
+                    ..Default::default()
+                };
+                st.fields.push(field.clone());
+                if get_str(&param, "location") == "query" {
+                    if required {
+                        st.required_fields.push(field);
+                    } else {
+                        st.optional_fields.push(field);
+                    }
+                }
+            }
+            let page_size = OPTIONS.with(|o| {
+                o.borrow()
+                    .page_sizes
+                    .iter()
+                    .rev()
+                    .find(|(id, _)| id == get_str(method, "id"))
+                    .map(|(_, size)| *size)
+            });
+            if let Some(page_size) = page_size {
+                if st.fields.iter().any(|f| f.original_name == "pageSize") {
+                    st.custom_default = true;
+                    st.defaults = st
+                        .fields
+                        .iter()
+                        .map(|f| FieldDefault {
+                            name: f.name.clone(),
+                            value: if f.original_name == "pageSize" {
+                                format!("Some({})", page_size)
+                            } else {
+                                "Default::default()".into()
+                            },
+                        })
+                        .collect();
+                }
+            }
+            // The global parameters are strings and booleans.
+            st.eq = !st.fields.iter().any(|f| mentions(&f.typ, &float_types()));
+            frags.push(render(SCHEMA_STRUCT_TMPL, &st)?);
+            frags.push(render(PARAMS_QUERY_TMPL, &st)?);
+            if st.custom_default {
+                frags.push(render(PARAMS_DEFAULT_TMPL, &st)?);
+            }
+            frags.push(render_builder(&st)?);
+        }
+        // Generate parameter types for subresources.
+        if let Some(subresources) = get_obj(resource, "resources") {
+            frags.extend(generate_params_structs(
+                subresources,
+                resourcename,
+                global_params,
+            )?);
+        }
+    }
+    Ok(frags)
+}
+
+/// The type configured in `GenerateOptions::param_types` for the parameter `name` of the method
+/// with ID `method`.
+fn param_type(method: &str, name: &str) -> Option<String> {
+    let key = format!("{}.{}", method, name);
+    OPTIONS.with(|o| {
+        o.borrow()
+            .param_types
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map(|(_, typ)| typ.clone())
+    })
+}
+
+/// Returns a Rust expression formatting the given string with API parameters, and a list of
+/// (snake-case) API parameters that are used. This is typically used to format URL paths
+/// containing required parameters for an API call.
+fn resolve_parameters(string: &str, paramsname: &str) -> (String, Vec<String>) {
+    let pat = regex::Regex::new(r"\{(\+?)(\w+)\}").unwrap();
+    // `{+param}` is a reserved expansion, which may contain slashes.
+    let params: Vec<(bool, &str)> = pat
+        .captures_iter(string)
+        .map(|c| (!c[1].is_empty(), c.get(2).unwrap().as_str()))
+        .collect();
+    let snakeparams: Vec<String> = params
+        .iter()
+        .map(|(_, p)| replace_keywords(&rust_identifier(p)))
+        .collect();
+    let mut string = string.to_string();
+    let format_params: Vec<String> = params
+        .iter()
+        .zip(snakeparams.iter())
+        .map(|((reserved, p), sp)| {
+            let encode_set = if *reserved {
+                "RESERVED_PATH"
+            } else {
+                "PATH_SEGMENT"
+            };
+            // Keywords can't name format arguments either.
+            let mut name = p.to_string();
+            if *sp != rust_identifier(p) {
+                name = format!("{}_", rust_identifier(p));
+                string = string.replace(&format!("{{{}}}", p), &format!("{{{}}}", name));
+                string = string.replace(&format!("{{+{}}}", p), &format!("{{+{}}}", name));
+            }
+            format!(
+                "{}=percent_encode({}.{}.as_bytes(), {})",
+                name, paramsname, sp, encode_set
+            )
+        })
+        .collect();
+    let string = string.replace("{+", "{");
+    // Some required parameters are in the URL. This rust syntax formats the relative URL part
+    // appropriately.
+    (
+        format!("format!(\"{}\", {})", string, format_params.join(",")),
+        snakeparams,
+    )
+}
+
+/// The part of the scope `url` its names are derived from: the last path segment, or the host
+/// name if the path is empty (e.g. `https://mail.google.com/`).
+fn scope_raw_name(url: &str) -> String {
+    let rest = url.splitn(2, "://").last().unwrap_or("");
+    let mut segments = rest.split('/').filter(|s| !s.is_empty());
+    let host = segments.next().unwrap_or("");
+    segments
+        .next_back()
+        .unwrap_or(host)
+        .replace(['-', '.'], "_")
+}
+
+/// Names of the constant in the `scopes` module (e.g. `DRIVE_READONLY`) and of the variant of
+/// the scopes enum (e.g. `DriveReadonly`) for each scope in `scopes`, by URL. Scopes whose
+/// names would collide with an earlier one get a numeric suffix.
+fn scope_names(scopes: &Map<String, Value>) -> BTreeMap<String, (String, String)> {
+    let mut names = BTreeMap::new();
+    let mut taken = BTreeSet::new();
+    for url in scopes.keys() {
+        let raw = scope_raw_name(url);
+        let (const_name, variant_name) =
+            (rust_identifier(&raw).to_uppercase(), snake_to_camel(&raw));
+        let mut n = 1;
+        let mut name = (const_name.clone(), variant_name.clone());
+        while !taken.insert(name.0.clone()) {
+            n += 1;
+            name = (
+                format!("{}_{}", const_name, n),
+                format!("{}{}", variant_name, n),
+            );
+        }
+        names.insert(url.clone(), name);
+    }
+    names
+}
+
+/// Generate the code for all methods in a resource. Returns a rendered string with source code.
+fn generate_service(
+    resource: &str,
+    methods: &Value,
+    discdoc: &Value,
+    generate_subresources: bool,
+) -> Result<String> {
+    let service = capitalize_first(resource);
+    // Source code fragments implementing the methods.
+    let mut method_fragments = vec![];
+    // Source code fragments for impls of subordinate resources.
+    let mut subresource_fragments = vec![];
+
+    // Generate methods for subresources.
+    if generate_subresources {
+        for (subresname, subresource) in get_obj(methods, "resources").into_iter().flatten() {
+            subresource_fragments.push(generate_service(
+                &format!("{}{}", service, capitalize_first(subresname)),
+                subresource,
+                discdoc,
+                true,
+            )?);
+        }
+    }
+
+    let api_name = get_str(discdoc, "name");
+    let has_global_params = discdoc.get("parameters").is_some();
+    let global_params_name = if has_global_params {
+        Value::from(rust_identifier(&global_params_name(api_name)))
+    } else {
+        Value::Null
+    };
+    let scope_names = discdoc
+        .pointer("/auth/oauth2/scopes")
+        .and_then(Value::as_object)
+        .map(scope_names)
+        .unwrap_or_default();
+
+    for (methodname, method) in get_obj(methods, "methods").into_iter().flatten() {
+        // Goal: Instantiate the templates for upload and non-upload methods.
+
+        // e.g. FilesGetParams
+        let params_type_name = format!("{}{}Params", service, capitalize_first(methodname));
+        let method_params = get_obj(method, "parameters");
+        let param_list = |required: bool| -> Vec<Value> {
+            method_params
+                .into_iter()
+                .flatten()
+                .filter(|(_, pp)| {
+                    pp.get("required").is_some() == required && get_str(pp, "location") != "path"
+                })
+                .map(|(p, _)| json!({"param": p, "snake_param": rust_identifier(p)}))
+                .collect()
+        };
+        // All parameters that are optional (as URL parameters)
+        let parameters = param_list(false);
+        // All required parameters not represented in the path.
+        let required_parameters = param_list(true);
+        // Types of the function
+        let in_type = method
+            .get("request")
+            .map(|r| Value::from(get_str(r, "$ref")))
+            .unwrap_or(Value::Null);
+        let out_type = method
+            .get("response")
+            .map(|r| get_str(r, "$ref"))
+            .unwrap_or("()");
+
+        let is_download = method
+            .get("supportsMediaDownload")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let is_authd = method.get("scopes").is_some();
+
+        let protocols = method.get("mediaUpload").and_then(|m| m.get("protocols"));
+        let upload_path = |protocol: &str| {
+            protocols
+                .and_then(|p| p.get(protocol))
+                .map(|p| get_str(p, "path").to_string())
+        };
+        let simple_upload_path = upload_path("simple");
+        let resumable_upload_path = upload_path("resumable");
+
+        let http_method = get_str(method, "httpMethod");
+        // This relies on URL path parameters being required parameters (not optional). If this
+        // invariant is not fulfilled, the Rust code may not compile.
+        let (formatted_path, _) = resolve_parameters(get_str(method, "path"), "params");
+        let (formatted_simple_upload_path, _) =
+            resolve_parameters(simple_upload_path.as_deref().unwrap_or(""), "params");
+        let (formatted_resumable_upload_path, _) =
+            resolve_parameters(resumable_upload_path.as_deref().unwrap_or(""), "params");
+
+        let last_scope = method
+            .get("scopes")
+            .and_then(Value::as_array)
+            .and_then(|s| s.last())
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        // Scopes not declared in the document have no constant.
+        let scope_const = match scope_names.get(last_scope) {
+            Some((const_name, _)) => format!("scopes::{}", const_name),
+            None => format!("{:?}", last_scope),
+        };
+
+        let mut data = json!({
+            "name": rust_identifier(methodname),
+            "service": service,
+            "param_type": params_type_name,
+            "in_type": in_type,
+            "out_type": out_type,
+            "base_path": get_str(discdoc, "baseUrl"),
+            "root_path": get_str(discdoc, "rootUrl"),
+            "rel_path_expr": formatted_path,
+            "params": parameters,
+            "required_params": required_parameters,
+            "global_params_name": global_params_name,
+            "scopes": [{"scope": scope_const}],
+            "description": get_str(method, "description"),
+            "http_method": http_method,
+            "wants_auth": is_authd,
+        });
+
+        if is_download {
+            data["download_in_type"] = if in_type.is_null() {
+                Value::from("EmptyRequest")
+            } else {
+                in_type.clone()
+            };
+            method_fragments.push(render(DOWNLOAD_METHOD_TMPL, &data)?);
+        } else {
+            method_fragments.push(render(NORMAL_METHOD_TMPL, &data)?);
+        }
+
+        // We generate an additional implementation with the option of uploading data.
+        data["simple_rel_path_expr"] = formatted_simple_upload_path.trim_start_matches('/').into();
+        data["resumable_rel_path_expr"] = formatted_resumable_upload_path
+            .trim_start_matches('/')
+            .into();
+        if simple_upload_path.is_some() {
+            method_fragments.push(render(UPLOAD_METHOD_TMPL, &data)?);
+        }
+        if resumable_upload_path.is_some() {
+            method_fragments.push(render(RESUMABLE_UPLOAD_METHOD_TMPL, &data)?);
+        }
+    }
+
+    let methods: Vec<Value> = method_fragments
+        .into_iter()
+        .map(|t| json!({ "text": t }))
+        .collect();
+    Ok(render(
+        SERVICE_IMPLEMENTATION_TMPL,
+        &json!({
+            "service": service,
+            "name": capitalize_first(api_name),
+            "base_path": get_str(discdoc, "baseUrl"),
+            "root_path": get_str(discdoc, "rootUrl"),
+            "wants_auth": discdoc.get("auth").is_some(),
+            "methods": methods,
+        }),
+    )? + &subresource_fragments.join("\n"))
+}
+
+/// Append the names of the services generated for `resource` and its subresources by
+/// `generate_service()`, in the same order, e.g. `Projects`, `ProjectsHmacKeys`.
+fn service_names(resource: &str, methods: &Value, names: &mut Vec<String>) {
+    let service = capitalize_first(resource);
+    names.push(service.clone());
+    for (subresname, subresource) in get_obj(methods, "resources").into_iter().flatten() {
+        let subservice = format!("{}{}", service, capitalize_first(subresname));
+        service_names(&subservice, subresource, names);
+    }
+}
+
+/// Generate the hub giving access to all services of the API.
+///
+/// `services` are the names of the services, with the `#[cfg]` predicate of their accessor.
+fn generate_hub(discdoc: &Value, services: &[(String, Option<String>)]) -> Result<String> {
+    let first = match services.first() {
+        Some((first, _)) => first,
+        None => return Ok(String::new()),
+    };
+    let wants_auth = discdoc.get("auth").is_some();
+    let entries: Vec<Value> = services
+        .iter()
+        .map(|(s, cfg)| {
+            json!({"service": s, "accessor": rust_identifier(s), "wants_auth": wants_auth, "cfg": cfg})
+        })
+        .collect();
+    render(
+        HUB_TMPL,
+        &json!({
+            "name": snake_to_camel(get_str(discdoc, "name")),
+            "first_accessor": rust_identifier(first),
+            "services": entries,
+        }),
+    )
+}
+
+/// The Cargo feature enabling the services of `resource`, e.g. `drive-files`.
+fn resource_feature(api_name: &str, resource: &str) -> String {
+    let kebab = |name: &str| rust_identifier(name).replace('_', "-");
+    format!("{}-{}", kebab(api_name), kebab(resource))
+}
+
+/// The predicate of a `#[cfg]` attribute requiring one of `features`; `None` if `features` is
+/// empty, i.e. the code is always compiled.
+fn feature_cfg(features: &BTreeSet<String>) -> Option<String> {
+    let features: Vec<String> = features
+        .iter()
+        .map(|f| format!("feature = \"{}\"", f))
+        .collect();
+    match features.len() {
+        0 => None,
+        1 => Some(features[0].clone()),
+        _ => Some(format!("any({})", features.join(", "))),
+    }
+}
+
+/// Wrap `code` into a module `module` that is only compiled if `cfg` holds.
+fn feature_gate(cfg: Option<&String>, module: &str, code: String) -> Result<String> {
+    match cfg {
+        None => Ok(code),
+        Some(cfg) => render(
+            FEATURE_GATE_TMPL,
+            &json!({"cfg": cfg, "module": module, "code": code}),
+        ),
+    }
+}
+
+/// Add all schemas referenced by `value` to `refs`.
+fn collect_refs(value: &Value, refs: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(obj) => {
+            if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+                refs.insert(reference.to_string());
+            }
+            obj.values().for_each(|v| collect_refs(v, refs));
+        }
+        Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+        _ => {}
+    }
+}
+
+/// The schemas used by the methods of `resource` and its subresources, including the schemas
+/// those reference.
+fn resource_schemas(resource: &Value, schemas: &Map<String, Value>) -> BTreeSet<String> {
+    let mut used = BTreeSet::new();
+    collect_refs(resource, &mut used);
+    let mut pending: Vec<String> = used.iter().cloned().collect();
+    while let Some(name) = pending.pop() {
+        let mut refs = BTreeSet::new();
+        if let Some(schema) = schemas.get(&name) {
+            collect_refs(schema, &mut refs);
+        }
+        for r in refs {
+            if used.insert(r.clone()) {
+                pending.push(r);
+            }
+        }
+    }
+    used
+}
+
+/// The features of the resources using each schema. Schemas that are not used only by
+/// resources (e.g. by global methods, or by no method) are missing; they are always compiled.
+fn schema_features(discdoc: &Value) -> BTreeMap<String, BTreeSet<String>> {
+    let api_name = get_str(discdoc, "name");
+    let empty = Map::new();
+    let schemas = get_obj(discdoc, "schemas").unwrap_or(&empty);
+    let resources = get_obj(discdoc, "resources").unwrap_or(&empty);
+    let mut features: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (resource, methods) in resources {
+        for schema in resource_schemas(methods, schemas) {
+            features
+                .entry(schema)
+                .or_default()
+                .insert(resource_feature(api_name, resource));
+        }
+    }
+    // Schemas used otherwise, and all schemas they reference.
+    let mut always = vec![discdoc.get("methods").cloned().unwrap_or(Value::Null)];
+    always.extend(
+        schemas
+            .keys()
+            .filter(|n| !features.contains_key(*n))
+            .map(|n| json!({ "$ref": n })),
+    );
+    for schema in resource_schemas(&Value::Array(always), schemas) {
+        features.remove(&schema);
+    }
+    features
+}
+
+/// Generate types for the `scopes` dictionary (path: auth.oauth2.scopes in a discovery
+/// document), containing { scope_url: { description: "..." } }.
+/// How many `$ref`s deep sample values are expanded; deeper references are left out.
+const SAMPLE_MAX_REFS: usize = 3;
+
+/// A JSON value of `schema` with all properties set, for round-trip tests. `refs` are the
+/// schemas being expanded; recursive and too deeply nested references are left out (`None`).
+fn sample_value(schema: &Value, schemas: &Map<String, Value>, refs: &[String]) -> Option<Value> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        if refs.len() >= SAMPLE_MAX_REFS || refs.iter().any(|r| r == reference) {
+            return None;
+        }
+        let mut refs = refs.to_vec();
+        refs.push(reference.to_string());
+        return sample_value(schemas.get(reference)?, schemas, &refs);
+    }
+    match get_str(schema, "type") {
+        "object" => {
+            if let Some(properties) = get_obj(schema, "properties") {
+                let sample: Map<String, Value> = properties
+                    .iter()
+                    .filter_map(|(pn, pp)| Some((pn.clone(), sample_value(pp, schemas, refs)?)))
+                    .collect();
+                return Some(Value::Object(sample));
+            }
+            let additional = schema.get("additionalProperties")?;
+            Some(match sample_value(additional, schemas, refs) {
+                Some(v) => json!({ "key": v }),
+                None => json!({}),
+            })
+        }
+        "array" => {
+            let item = sample_value(schema.get("items")?, schemas, refs);
+            Some(Value::Array(item.into_iter().collect()))
+        }
+        "string" => {
+            if let Some(first) = schema
+                .get("enum")
+                .and_then(Value::as_array)
+                .and_then(|v| v.first())
+            {
+                return Some(first.clone());
+            }
+            Some(match get_str(schema, "format") {
+                "int64" | "int32" | "uint64" | "uint32" => json!("123"),
+                "double" | "float" => json!("1.5"),
+                "date-time" => json!("2020-06-01T12:30:45Z"),
+                _ => json!("sample"),
+            })
+        }
+        "boolean" => Some(json!(true)),
+        "number" | "integer" => match get_str(schema, "format") {
+            "float" | "double" => Some(json!(1.5)),
+            _ => Some(json!(1)),
+        },
+        "any" => Some(json!("sample")),
+        _ => None,
+    }
+}
+
+/// Generate round-trip tests for the struct types generated from `schemas`. Tests of schemas in
+/// `features` are only compiled with one of the features enabled.
+fn generate_round_trip_tests(
+    schemas: &Map<String, Value>,
+    features: &BTreeMap<String, BTreeSet<String>>,
+) -> Result<String> {
+    let mut tests = vec![];
+    for (name, schema) in schemas {
+        if get_str(schema, "type") != "object" || get_obj(schema, "properties").is_none() {
+            continue;
+        }
+        let sample = match sample_value(schema, schemas, std::slice::from_ref(name)) {
+            Some(sample) => sample,
+            None => continue,
+        };
+        tests.push(json!({
+            "name": replace_keywords(name),
+            "fn_name": format!("round_trip_{}", rust_identifier(name)),
+            "json": sample.to_string(),
+            "cfg": features.get(name).and_then(feature_cfg),
+        }));
+    }
+    if tests.is_empty() {
+        return Ok(String::new());
+    }
+    render(ROUND_TRIP_TESTS_TMPL, &json!({ "tests": tests }))
+}
+
+fn generate_scopes_type(name: &str, scopes: Option<&Map<String, Value>>) -> Result<String> {
+    let scopes = match scopes {
+        Some(scopes) if !scopes.is_empty() => scopes,
+        _ => return Ok(String::new()),
+    };
+    let enum_type_name = format!("{}Scopes", snake_to_camel(name));
+    let names = scope_names(scopes);
+    let mut entries = vec![];
+    for (url, desc) in scopes {
+        let (const_name, variant_name) = &names[url];
+        entries.push(json!({
+            "scope_name": variant_name,
+            "const_name": const_name,
+            "desc": get_str(desc, "description"),
+            "url": url,
+        }));
+    }
+    render(
+        OAUTH_SCOPES_TYPE,
+        &json!({"name": enum_type_name, "scopes": entries}),
+    )
+}
+
+/// The name of the module generated for `discdoc`, e.g. `drive_v3_types`.
+pub fn module_name(discdoc: &Value) -> String {
+    format!("{}_types", get_str(discdoc, "id")).replace(':', "_")
+}
+
+/// Generate all structs and impls for the API described by the Discovery document `discdoc`,
+/// and return them as Rust source code.
+///
+/// The code is not formatted; run `rustfmt` on it if it is meant to be read by humans.
+pub fn generate(discdoc: &Value) -> Result<String> {
+    generate_with(discdoc, &GenerateOptions::default())
+}
+
+/// Like `generate()`, with non-default options.
+///
+/// By default, `type` and `enum` become `typ` and `enums`, other names colliding with Rust
+/// keywords become raw identifiers (`r#match`), and `self`, `super` etc. get an underscore
+/// appended. All renames are listed in the documentation of the generated module.
+pub fn generate_with(discdoc: &Value, options: &GenerateOptions) -> Result<String> {
+    let parts = generate_parts(discdoc, options)?;
+    let mut out = parts.header;
+    out.push_str(&parts.types);
+    out.push_str(&parts.params);
+    for (_, s) in parts.services {
+        out.push_str(&s);
+    }
+    out.push_str(&parts.hub);
+    Ok(out)
+}
+
+/// Like `generate_with()`, but split the code into a module tree, which is easier on IDEs than a
+/// single file of tens of thousands of lines. Returns `(file name, code)` pairs: `mod.rs`
+/// containing the module documentation, `types.rs` (scopes and schema types), `params.rs`
+/// (parameter types), one `{resource}_service.rs` per top-level resource, and `hub.rs`. Write them into a
+/// directory named like `module_name()`.
+pub fn generate_split_with(
+    discdoc: &Value,
+    options: &GenerateOptions,
+) -> Result<Vec<(String, String)>> {
+    let parts = generate_parts(discdoc, options)?;
+    let mut files = vec![
+        ("types".to_string(), parts.types),
+        ("params".to_string(), parts.params),
+    ];
+    for (resource, s) in parts.services {
+        files.push((format!("{}_service", rust_identifier(&resource)), s));
+    }
+    if !parts.hub.is_empty() {
+        files.push(("hub".to_string(), parts.hub));
+    }
+
+    let modules: Vec<Value> = files.iter().map(|(m, _)| json!({ "name": m })).collect();
+    let mut root = parts.header;
+    root.push_str(&render(MODULE_TREE_TMPL, &json!({ "modules": modules }))?);
+    let mut out = vec![("mod.rs".to_string(), root)];
+    for (m, code) in files {
+        out.push((m + ".rs", SUBMODULE_HEADER.to_string() + &code));
+    }
+    Ok(out)
+}
+
+/// The generated code, in the order in which it is assembled into a file.
+struct Parts {
+    /// Module documentation and imports.
+    header: String,
+    /// Scopes and schema types.
+    types: String,
+    /// `*Params` structs.
+    params: String,
+    /// Service impls by top-level resource.
+    services: Vec<(String, String)>,
+    /// The `{Api}Hub` struct.
+    hub: String,
+}
+
+/// Render schema structs and enums. Structs in `non_eq` don't derive `Eq`.
+fn render_structs(structs: Vec<Struct>, non_eq: &BTreeSet<String>) -> Result<String> {
+    let mut code = String::new();
+    for mut s in structs {
+        if !s.values.is_empty() {
+            code.push_str(&render(ENUM_TMPL, &s)?);
+            continue;
+        }
+        for field in s.fields.iter_mut() {
+            if let Some(comment) = field.comment.as_mut() {
+                *comment = comment.replace('\n', " ");
+            }
+        }
+        s.eq = !non_eq.contains(&s.name);
+        code.push_str(&render(SCHEMA_STRUCT_TMPL, &s)?);
+        if s.redacted {
+            code.push_str(&render(REDACTED_DEBUG_TMPL, &s)?);
+        }
+    }
+    Ok(code)
+}
+
+fn generate_parts(discdoc: &Value, options: &GenerateOptions) -> Result<Parts> {
+    OPTIONS.with(|o| *o.borrow_mut() = options.clone());
+    RENAMED.with(|r| r.borrow_mut().clear());
+    IDS.with(|ids| {
+        let (used, schemas) = &mut *ids.borrow_mut();
+        used.clear();
+        *schemas = get_obj(discdoc, "schemas")
+            .map(|s| s.keys().map(|k| replace_keywords(k)).collect())
+            .unwrap_or_default();
+    });
+    let api_name = get_str(discdoc, "name");
+    let empty = Map::new();
+    let schemas = get_obj(discdoc, "schemas").unwrap_or(&empty);
+    let resources = get_obj(discdoc, "resources").unwrap_or(&empty);
+    // Generate scopes.
+    let scopes_type = generate_scopes_type(
+        api_name,
+        discdoc
+            .pointer("/auth/oauth2/scopes")
+            .and_then(Value::as_object),
+    )?;
+
+    // With resource features, the code of each resource is only compiled with its feature.
+    let resource_features = options.resource_features;
+    let feature_of = |resource: &str| -> Option<String> {
+        if resource_features {
+            feature_cfg(&std::iter::once(resource_feature(api_name, resource)).collect())
+        } else {
+            None
+        }
+    };
+    let schema_features = if resource_features {
+        schema_features(discdoc)
+    } else {
+        BTreeMap::new()
+    };
+
+    // Generate parameter types (*Params - those are used as "side inputs" to requests)
+    let params_struct_name = global_params_name(api_name);
+    let mut parameter_types = vec![];
+    for (resource, methods) in resources {
+        let single = std::iter::once((resource.clone(), methods.clone())).collect();
+        let code = generate_params_structs(&single, "", Some(&params_struct_name))?.concat();
+        let module = format!("{}_params", rust_identifier(resource));
+        parameter_types.push(feature_gate(feature_of(resource).as_ref(), &module, code)?);
+    }
+
+    // Generate service impls.
+    let mut services = vec![];
+    let mut names = vec![];
+    for (resource, methods) in resources {
+        let code = generate_service(resource, methods, discdoc, true)?;
+        let module = format!("{}_service", rust_identifier(resource));
+        let cfg = feature_of(resource);
+        services.push((resource.clone(), feature_gate(cfg.as_ref(), &module, code)?));
+        let mut resource_names = vec![];
+        service_names(resource, methods, &mut resource_names);
+        names.extend(resource_names.into_iter().map(|n| (n, cfg.clone())));
+    }
+    if discdoc.get("methods").is_some() {
+        services.push((
+            "Global".to_string(),
+            generate_service("Global", discdoc, discdoc, false)?,
+        ));
+        names.push(("Global".to_string(), None));
+    }
+    let hub = generate_hub(discdoc, &names)?;
+
+    // Generate schema types, each with the structs of its nested objects.
+    let mut schema_structs = vec![];
+    for (name, desc) in schemas {
+        let (_, substructs) = parse_schema_types(name, desc, true, &[])?;
+        schema_structs.push((name, substructs));
+    }
+
+    // Generate global parameters struct and its query serialization.
+    let mut global_structs = vec![];
+    if let Some(parameters) = discdoc.get("parameters") {
+        let schema = json!({"type": "object", "properties": parameters});
+        let name = replace_keywords(&snake_to_camel(&params_struct_name));
+        let (_, substructs) = parse_schema_types(&name, &schema, true, &[])?;
+        for mut s in substructs {
+            if s.values.is_empty() {
+                for field in s.fields.iter_mut() {
+                    field.sensitive = CREDENTIAL_PARAMS.contains(&field.original_name.as_str());
+                }
+                s.redacted = s.fields.iter().any(|f| f.sensitive);
+                s.optional_fields = s.fields.clone();
+                parameter_types.push(render(PARAMS_QUERY_TMPL, &s)?);
+                parameter_types.push(render_builder(&s)?);
+            }
+            global_structs.push(s);
+        }
+    }
+
+    // Assemble everything.
+    let renamed: Vec<Value> = RENAMED.with(|r| {
+        r.borrow()
+            .iter()
+            .map(|(name, rust)| json!({"name": name, "rust": rust}))
+            .collect()
+    });
+    let features: Vec<Value> = if resource_features {
+        resources
+            .keys()
+            .map(|r| json!({ "name": resource_feature(api_name, r) }))
+            .collect()
+    } else {
+        vec![]
+    };
+    let header = render(
+        RUST_HEADER,
+        &json!({
+            "has_renames": !renamed.is_empty(),
+            "renames": renamed,
+            "has_features": !features.is_empty(),
+            "features": features,
+        }),
+    )?;
+    let mut types = scopes_type;
+    // Render ID types.
+    for id in IDS.with(|ids| ids.borrow().0.clone()) {
+        types.push_str(&render(ID_TMPL, &json!({ "name": id }))?);
+    }
+    // Render resource structs.
+    let non_eq = non_eq_structs(
+        schema_structs
+            .iter()
+            .flat_map(|(_, structs)| structs)
+            .chain(global_structs.iter()),
+    );
+    for (name, structs) in schema_structs {
+        let cfg = schema_features.get(name).and_then(feature_cfg);
+        let module = format!("{}_schema", rust_identifier(name));
+        types.push_str(&feature_gate(
+            cfg.as_ref(),
+            &module,
+            render_structs(structs, &non_eq)?,
+        )?);
+    }
+    types.push_str(&render_structs(global_structs, &non_eq)?);
+    types.push_str(&generate_round_trip_tests(schemas, &schema_features)?);
+    // Render *Params structs.
+    let params = parameter_types.concat();
+    Ok(Parts {
+        header,
+        types,
+        params,
+        services,
+        hub,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"{
+        "id": "example:v1",
+        "name": "example",
+        "rootUrl": "https://example.googleapis.com/",
+        "baseUrl": "https://example.googleapis.com/example/v1/",
+        "auth": {"oauth2": {"scopes": {
+            "https://www.googleapis.com/auth/example.readonly": {"description": "Read things"}
+        }}},
+        "parameters": {
+            "fields": {"type": "string", "location": "query", "description": "Selector"},
+            "oauth_token": {"type": "string", "location": "query"}
+        },
+        "schemas": {
+            "Item": {
+                "id": "Item",
+                "type": "object",
+                "properties": {
+                    "id": {"type": "string", "description": "The ID."},
+                    "headRevisionId": {"type": "string"},
+                    "size": {"type": "string", "format": "int64"},
+                    "modifiedTime": {"type": "string", "format": "date-time"},
+                    "type": {"type": "string", "description": "The kind. Valid values are:   - user  - group  Prefer user."},
+                    "role": {"type": "string", "enum": ["owner", "reader"], "enumDescriptions": ["Owns it", "Reads it"]},
+                    "labels": {"type": "object", "additionalProperties": {"type": "string"}},
+                    "owner": {"type": "object", "properties": {"name": {"type": "string"}, "id": {"type": "string"}}},
+                    "children": {"type": "array", "items": {"$ref": "Item"}}
+                }
+            }
+        },
+        "resources": {
+            "items": {
+                "methods": {
+                    "get": {
+                        "id": "example.items.get",
+                        "httpMethod": "GET",
+                        "path": "items/{itemId}",
+                        "parameters": {
+                            "itemId": {"type": "string", "location": "path", "required": true},
+                            "pageSize": {"type": "integer", "format": "int32", "location": "query"},
+                            "teamDriveId": {"type": "string", "location": "query"},
+                            "view": {"type": "string", "location": "query", "enum": ["BASIC", "full", "1"]}
+                        },
+                        "response": {"$ref": "Item"},
+                        "scopes": ["https://www.googleapis.com/auth/example.readonly"]
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(rust_identifier("pageSize"), "page_size");
+        assert_eq!(rust_identifier("$.xgafv"), "dollar_xgafv");
+        assert_eq!(snake_to_camel("drive_params"), "DriveParams");
+        assert_eq!(capitalize_first("files"), "Files");
+        assert_eq!(replace_keywords("type"), "typ");
+        assert_eq!(replace_keywords("match"), "r#match");
+        assert_eq!(replace_keywords("self"), "self_");
+        assert_eq!(replace_keywords("kind"), "kind");
+    }
+
+    #[test]
+    fn test_generate() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let code = generate(&doc).unwrap();
+        assert_eq!(module_name(&doc), "example_v1_types");
+
+        assert!(code.contains("pub enum ExampleScopes"));
+        assert!(code.contains("pub type Scope = ExampleScopes;"));
+        assert!(code.contains(
+            "pub const EXAMPLE_READONLY: &str = \"https://www.googleapis.com/auth/example.readonly\";"
+        ));
+        assert!(code.contains("ExampleScopes::ExampleReadonly => scopes::EXAMPLE_READONLY,"));
+        assert!(code.contains("let scopes: &'static [&'static str] = &[scopes::EXAMPLE_READONLY,"));
+        assert!(code.contains("pub struct Item {"));
+        assert!(code.contains("pub struct ItemOwner {"));
+        assert!(code.contains("pub typ: Option<String>,"));
+        assert!(code.contains("//! * `type` → `typ`"));
+        assert!(code.contains("/// i64: "));
+        assert!(code.contains("#[serde(with = \"str_num\", default)]\n    pub size: Option<i64>,"));
+        assert!(code.contains(
+            "#[serde(with = \"lenient_time\", default)]\n    pub modified_time: Option<DateTime<Utc>>,"
+        ));
+        assert!(code.contains("pub labels: Option<HashMap<String,String>>,"));
+        assert!(code.contains("pub children: Option<Vec<Box<Item>>>,"));
+        assert!(code.contains("pub struct ExampleParams {"));
+        assert!(code.contains("pub struct ItemsGetParams {"));
+        assert!(code.contains("pub item_id: String,"));
+        assert!(code.contains("pub page_size: Option<i32>,"));
+        assert!(code.contains("pub struct ItemsService<C = TlsClient> {"));
+        assert!(code.contains("impl<C: HttpClient> ItemsService<C> {"));
+        assert!(code.contains("itemId=percent_encode(params.item_id.as_bytes(), PATH_SEGMENT)"));
+        assert!(code.contains("-> Result<Item>"));
+        assert!(code.contains("traced(\"Items\", \"get\", async move {"));
+        assert!(code.contains("Ok(self.get_with_response(params).await?.body)"));
+        assert!(code.contains("-> Result<ApiResponse<Item>>"));
+        assert!(code.contains("self.standard_params.append_to(&mut url);"));
+        assert!(code.contains("query.append_pair(\"pageSize\", &v.to_string());"));
+        assert!(code.contains("impl ItemsGetParams {"));
+        assert!(code.contains(
+            "pub fn page_size<T: Into<i32>>(mut self, value: T) -> Self {\n        self.page_size = Some(value.into());"
+        ));
+        assert!(code.contains(
+            "pub fn item_id<T: Into<String>>(mut self, value: T) -> Self {\n        self.item_id = value.into();"
+        ));
+        assert!(code.contains("pub fn example_params<T: Into<ExampleParams>>(mut self"));
+        assert!(code.contains("impl ExampleParams {"));
+        assert!(code.contains(
+            "#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default)]\npub struct ExampleParams {"
+        ));
+        assert!(code.contains("impl std::fmt::Debug for ExampleParams {"));
+        assert!(code.contains(".field(\"fields\", &self.fields)"));
+        assert!(code
+            .contains(".field(\"oauth_token\", &self.oauth_token.as_ref().map(|_| \"redacted\"))"));
+    }
+
+    #[test]
+    fn test_generate_round_trip_tests() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let code = generate(&doc).unwrap();
+        assert!(code.contains("#[cfg(test)]\nmod round_trip_tests {"));
+        assert!(code.contains("fn round_trip_item() {\n        round_trip::<Item>(r##\"{"));
+
+        let schemas = get_obj(&doc, "schemas").unwrap();
+        let sample = sample_value(&doc["schemas"]["Item"], schemas, &["Item".into()]).unwrap();
+        assert_eq!(sample["size"], json!("123"));
+        assert_eq!(sample["modifiedTime"], json!("2020-06-01T12:30:45Z"));
+        assert_eq!(sample["role"], json!("owner"));
+        assert_eq!(sample["labels"], json!({"key": "sample"}));
+        assert_eq!(sample["owner"], json!({"name": "sample", "id": "sample"}));
+        // Recursive references are left out.
+        assert_eq!(sample["children"], json!([]));
+    }
+
+    #[test]
+    fn test_generate_resource_features() {
+        let mut doc: Value = serde_json::from_str(DOC).unwrap();
+        doc["resources"]["noteLists"] = json!({"methods": {"get": {
+            "id": "example.noteLists.get",
+            "httpMethod": "GET",
+            "path": "notes",
+            "response": {"$ref": "NoteList"}
+        }}});
+        doc["schemas"]["NoteList"] = json!({"type": "object", "properties": {
+            "notes": {"type": "array", "items": {"$ref": "Note"}}
+        }});
+        doc["schemas"]["Note"] = json!({"type": "object", "properties": {
+            "item": {"$ref": "Item"}
+        }});
+        let code = generate(&doc).unwrap();
+        assert!(!code.contains("#[cfg(feature"));
+
+        let options = GenerateOptions {
+            resource_features: true,
+            ..Default::default()
+        };
+        let code = generate_with(&doc, &options).unwrap();
+        assert!(code.contains("//! example-items = []\n//! example-note-lists = []"));
+        assert!(code.contains("#[cfg(feature = \"example-items\")]\nmod items_params {"));
+        assert!(code.contains("#[cfg(feature = \"example-items\")]\nmod items_service {"));
+        assert!(code.contains("#[cfg(feature = \"example-items\")]\npub use items_service::*;"));
+        assert!(code.contains("#[cfg(feature = \"example-note-lists\")]\nmod note_list_schema {"));
+        assert!(code.contains(
+            "#[cfg(any(feature = \"example-items\", feature = \"example-note-lists\"))]\nmod item_schema {"
+        ));
+        assert!(code.contains("#[cfg(feature = \"example-items\")]\n    pub fn items(&self)"));
+        assert!(code
+            .contains("#[cfg(feature = \"example-note-lists\")]\n    fn round_trip_note_list() {"));
+
+        // Schemas not used by methods, and those they reference, are always compiled.
+        doc["schemas"]["Orphan"] = json!({"type": "object", "properties": {
+            "note": {"$ref": "Note"}
+        }});
+        let code = generate_with(&doc, &options).unwrap();
+        assert!(!code.contains("mod item_schema"));
+        assert!(!code.contains("mod note_schema"));
+        assert!(code.contains("mod note_list_schema {"));
+    }
+
+    #[test]
+    fn test_generate_keyword_path_param() {
+        let mut doc: Value = serde_json::from_str(DOC).unwrap();
+        doc["resources"]["items"]["methods"]["byType"] = json!({
+            "id": "example.items.byType",
+            "httpMethod": "GET",
+            "path": "items/{type}/{+match}",
+            "parameters": {
+                "type": {"type": "string", "location": "path", "required": true},
+                "match": {"type": "string", "location": "path", "required": true}
+            },
+            "response": {"$ref": "Item"}
+        });
+        let code = generate(&doc).unwrap();
+        assert!(code.contains("pub typ: String,"));
+        assert!(code.contains(
+            "format!(\"items/{type_}/{match_}\", type_=percent_encode(params.typ.as_bytes(), PATH_SEGMENT),match_=percent_encode(params.r#match.as_bytes(), RESERVED_PATH))"
+        ));
+    }
+
+    #[test]
+    fn test_generate_upload() {
+        let mut doc: Value = serde_json::from_str(DOC).unwrap();
+        doc["resources"]["items"]["methods"]["insert"] = json!({
+            "id": "example.items.insert",
+            "httpMethod": "POST",
+            "path": "items",
+            "request": {"$ref": "Item"},
+            "response": {"$ref": "Item"},
+            "mediaUpload": {"protocols": {
+                "simple": {"path": "/upload/example/v1/items"},
+                "resumable": {"path": "/resumable/upload/example/v1/items"}
+            }}
+        });
+        let code = generate(&doc).unwrap();
+        assert!(code.contains("self.insert_upload_with_type(params, req, data, None).await"));
+        assert!(code
+            .contains("data: hyper::body::Bytes, content_type: Option<&str>) -> Result<Item> {"));
+        assert!(code.contains(
+            "Ok(self.insert_upload_with_response(params, req, data, content_type).await?.body)"
+        ));
+        assert!(code.contains("-> Result<ApiResponse<Item>> {"));
+        assert!(code.contains("opt_request, data, content_type).await"));
+        assert!(code.contains("self.insert_resumable_upload_with_type(params, req, None).await"));
+        assert!(code.contains("HeaderName::from_static(\"x-upload-content-type\")"));
+    }
+
+    #[test]
+    fn test_generate_eq() {
+        let mut doc: Value = serde_json::from_str(DOC).unwrap();
+        let code = generate(&doc).unwrap();
+        assert!(code.contains(
+            "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]\npub struct Item {"
+        ));
+
+        // Floating-point numbers prevent deriving `Eq`, also in the structs containing them.
+        doc["schemas"]["Item"]["properties"]["owner"]["properties"]["score"] =
+            json!({"type": "number", "format": "double"});
+        let code = generate(&doc).unwrap();
+        assert!(code.contains(
+            "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]\npub struct ItemOwner {"
+        ));
+        assert!(code.contains(
+            "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]\npub struct Item {"
+        ));
+    }
+
+    #[test]
+    fn test_generate_typed_ids() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let code = generate(&doc).unwrap();
+        assert!(!code.contains("ItemId"));
+
+        let options = GenerateOptions {
+            typed_ids: true,
+            ..Default::default()
+        };
+        let code = generate_with(&doc, &options).unwrap();
+        assert!(code.contains("pub struct ItemId(pub String);"));
+        assert!(code.contains("impl From<&str> for ItemId {"));
+        assert!(code.contains("impl std::fmt::Display for ItemId {"));
+        assert!(code.contains("pub id: Option<ItemId>,"));
+        assert!(code.contains("pub item_id: ItemId,"));
+        assert!(code.contains("pub fn item_id<T: Into<ItemId>>(mut self, value: T) -> Self {"));
+        // Nested objects have no ID type of their own.
+        assert!(!code.contains("ItemOwnerId"));
+        // Names listed in `ID_TYPES` get the listed type.
+        assert!(code.contains("pub head_revision_id: Option<RevisionId>,"));
+        assert!(code.contains("pub team_drive_id: Option<DriveId>,"));
+        assert!(!code.contains("HeadRevisionId"));
+    }
+
+    #[test]
+    fn test_generate_hub() {
+        let mut doc: Value = serde_json::from_str(DOC).unwrap();
+        doc["resources"]["items"]["resources"] = json!({"versions": {"methods": {}}});
+        let code = generate(&doc).unwrap();
+        assert!(code.contains("pub struct ExampleHub<C = TlsClient> {"));
+        assert!(code.contains(
+            "pub fn items(&self) -> ItemsService<C> {\n        self.apis.service(ItemsService::new)"
+        ));
+        assert!(code.contains("pub fn items_versions(&self) -> ItemsVersionsService<C> {"));
+
+        doc.as_object_mut().unwrap().remove("auth");
+        let code = generate(&doc).unwrap();
+        assert!(code.contains("ItemsService::new(self.apis.client())"));
+    }
+
+    #[test]
+    fn test_generate_typed_enums() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let code = generate(&doc).unwrap();
+        assert!(code.contains("pub role: Option<String>,"));
+
+        let options = GenerateOptions {
+            typed_enums: true,
+            ..Default::default()
+        };
+        let code = generate_with(&doc, &options).unwrap();
+        assert!(code.contains("pub role: Option<ItemRole>,"));
+        assert!(code.contains("pub enum ItemRole {"));
+        assert!(code.contains("/// Owns it"));
+        assert!(code.contains("ItemRole::Owner => \"owner\","));
+        assert!(code.contains("pub view: Option<ItemsGetParamsView>,"));
+        assert!(code.contains("ItemsGetParamsView::BASIC => \"BASIC\","));
+        assert!(code.contains("ItemsGetParamsView::V1 => \"1\","));
+        assert!(code.contains("pub typ: Option<ItemType>,"));
+        assert!(code.contains("ItemType::Group => \"group\","));
+    }
+
+    #[test]
+    fn test_documented_values() {
+        assert_eq!(
+            documented_values("Specifies which view to include. Only 'published' is supported."),
+            vec!["published"]
+        );
+        assert_eq!(
+            documented_values(
+                "Groupings of files. Supported groupings are: 'user' (files of the user), 'drive' \
+                 (files in the drive indicated by the 'driveId'), and 'allDrives'. Prefer 'user'."
+            ),
+            vec!["user", "drive", "allDrives"]
+        );
+        assert!(documented_values("The name. Only 'a b' is supported.").is_empty());
+        assert!(documented_values("Only 'x' items are listed.").is_empty());
+        assert!(documented_values("Supported. Values are: 'a'.").is_empty());
+        assert_eq!(
+            documented_values(
+                "The role. While new values may be supported in the future, the following are \
+                 currently allowed:   - owner  - fileOrganizer  When creating, ..."
+            ),
+            vec!["owner", "fileOrganizer"]
+        );
+        assert_eq!(
+            documented_values("The type. Valid values are:   - user  - group"),
+            vec!["user", "group"]
+        );
+        assert!(documented_values("Valid values are:   - a.b  - c").is_empty());
+    }
+
+    #[test]
+    fn test_generate_page_sizes() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let code = generate(&doc).unwrap();
+        assert!(!code.contains("impl Default for ItemsGetParams {"));
+
+        let options = GenerateOptions {
+            page_sizes: vec![("example.items.get".into(), 1000)],
+            ..Default::default()
+        };
+        let code = generate_with(&doc, &options).unwrap();
+        assert!(code.contains(
+            "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]\npub struct ItemsGetParams {"
+        ));
+        assert!(code.contains("impl Default for ItemsGetParams {"));
+        assert!(code.contains("page_size: Some(1000),"));
+        assert!(code.contains("item_id: Default::default(),"));
+    }
+
+    #[test]
+    fn test_scope_names() {
+        let scopes = json!({
+            "https://mail.google.com/": {},
+            "https://www.googleapis.com/auth/gmail.readonly": {},
+            "https://www.googleapis.com/auth/cloud-platform": {},
+            "https://www.example.com/auth/cloud-platform": {},
+        });
+        let names = scope_names(scopes.as_object().unwrap());
+        let name = |url: &str| {
+            let (c, v) = &names[url];
+            (c.as_str(), v.as_str())
+        };
+        assert_eq!(
+            name("https://mail.google.com/"),
+            ("MAIL_GOOGLE_COM", "MailGoogleCom")
+        );
+        assert_eq!(
+            name("https://www.googleapis.com/auth/gmail.readonly"),
+            ("GMAIL_READONLY", "GmailReadonly")
+        );
+        assert_eq!(
+            name("https://www.googleapis.com/auth/cloud-platform"),
+            ("CLOUD_PLATFORM", "CloudPlatform")
+        );
+        assert_eq!(
+            name("https://www.example.com/auth/cloud-platform"),
+            ("CLOUD_PLATFORM_2", "CloudPlatform2")
+        );
+    }
+
+    #[test]
+    fn test_generate_param_types() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let options = GenerateOptions {
+            param_types: vec![(
+                "example.items.get.teamDriveId".into(),
+                "crate::drives::TeamDrive".into(),
+            )],
+            typed_ids: true,
+            ..Default::default()
+        };
+        let code = generate_with(&doc, &options).unwrap();
+        assert!(code.contains("pub team_drive_id: Option<crate::drives::TeamDrive>,"));
+        assert!(code.contains(
+            "pub fn team_drive_id<T: Into<crate::drives::TeamDrive>>(mut self, value: T) -> Self {"
+        ));
+        assert!(code.contains("query.append_pair(\"teamDriveId\", &v.to_string());"));
+        // Other parameters are unaffected.
+        assert!(code.contains("pub item_id: ItemId,"));
+    }
+
+    #[test]
+    fn test_generate_split() {
+        let doc: Value = serde_json::from_str(DOC).unwrap();
+        let files = generate_split_with(&doc, &GenerateOptions::default()).unwrap();
+        let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "mod.rs",
+                "types.rs",
+                "params.rs",
+                "items_service.rs",
+                "hub.rs"
+            ]
+        );
+        let file = |name: &str| &files.iter().find(|(n, _)| n == name).unwrap().1;
+        assert!(file("mod.rs").contains("//! * `type` → `typ`"));
+        assert!(file("mod.rs").contains("mod items_service;\npub use items_service::*;"));
+        assert!(file("types.rs").starts_with("//! Part of the generated module"));
+        assert!(file("types.rs").contains("pub struct Item {"));
+        assert!(file("params.rs").contains("pub struct ItemsGetParams {"));
+        assert!(file("items_service.rs").contains("pub struct ItemsService<C = TlsClient> {"));
+
+        // The files contain the same code as the single-file module.
+        let code = generate(&doc).unwrap();
+        for (_, part) in &files[1..] {
+            let part = part.trim_start_matches(SUBMODULE_HEADER);
+            assert!(code.contains(part));
+        }
+    }
 }
@@ -0,0 +1,245 @@
+//! Reports of the public API of generated code, for reviewing what changes when an API is
+//! regenerated from a newer Discovery document.
+//!
+//! A report lists one item per line, sorted: modules, types (`struct`, `enum`, `type`), their
+//! public fields and variants, trait impls, public methods with their signatures, and public
+//! constants with their types (in modules and impls, too). Whitespace in
+//! signatures is normalized, so reports of formatted and unformatted code are identical.
+
+use regex::Regex;
+
+use std::collections::BTreeSet;
+
+/// Characters next to which whitespace is removed when normalizing signatures.
+const PUNCTUATION: &[char] = &[
+    '(', ')', '<', '>', '[', ']', '&', ':', ';', ',', '=', '+', '{',
+];
+
+fn normalize(s: &str) -> String {
+    let words: Vec<&str> = s.split_whitespace().collect();
+    let mut out = String::new();
+    for w in words {
+        let glue = out.ends_with(PUNCTUATION) || w.starts_with(PUNCTUATION) || w.starts_with("->");
+        if !out.is_empty() && !glue {
+            out.push(' ');
+        }
+        out.push_str(w);
+    }
+    out.replace(",)", ")").replace(",>", ">")
+}
+
+/// Remove string literals and comments, which may contain braces.
+fn strip_line(line: &str, strings: &Regex) -> String {
+    let line = strings.replace_all(line, "\"\"");
+    match line.find("//") {
+        Some(i) => line[..i].to_string(),
+        None => line.to_string(),
+    }
+}
+
+/// Extract the public API of the generated Rust source `code`.
+pub fn api_report(code: &str) -> String {
+    let strings = Regex::new(r#""(?:[^"\\]|\\.)*""#).unwrap();
+    let impl_re = Regex::new(r"^impl(?:<.*?>)?\s+(?:(.+?)\s+for\s+)?([\w:]+)").unwrap();
+    let field_re = Regex::new(r"^pub\s+(r#\w+|\w+)\s*:\s*(.+),$").unwrap();
+    let variant_re = Regex::new(r"^(\w+)(\(.*\))?,$").unwrap();
+    let mod_re = Regex::new(r"^pub\s+mod\s+(r#\w+|\w+)").unwrap();
+    let const_re = Regex::new(r"^pub\s+const\s+(\w+)\s*:\s*([^=;]+)").unwrap();
+
+    let mut items = BTreeSet::new();
+    // The item whose body is being read: ("struct" | "enum" | "impl" | "mod", name), and the
+    // depth of braces within it.
+    let mut current: Option<(&str, String)> = None;
+    let mut depth = 0;
+    // A method signature spanning several lines.
+    let mut signature: Option<String> = None;
+
+    for line in code.lines() {
+        let line = strip_line(line, &strings);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("#[") {
+            continue;
+        }
+        let opens = line.matches('{').count();
+        let closes = line.matches('}').count();
+
+        if let Some(sig) = signature.as_mut() {
+            sig.push(' ');
+            sig.push_str(line);
+        } else if depth == 0 {
+            if let Some(rest) = line.strip_prefix("pub struct ") {
+                let name = normalize(rest.trim_end_matches('{'));
+                items.insert(format!("struct {}", name));
+                current = Some(("struct", name));
+            } else if let Some(rest) = line.strip_prefix("pub enum ") {
+                let name = normalize(rest.trim_end_matches('{'));
+                items.insert(format!("enum {}", name));
+                current = Some(("enum", name));
+            } else if let Some(rest) = line.strip_prefix("pub type ") {
+                items.insert(format!("type {}", normalize(rest.trim_end_matches(';'))));
+            } else if let Some(c) = mod_re.captures(line) {
+                let name = c[1].to_string();
+                items.insert(format!("mod {}", name));
+                current = Some(("mod", name));
+            } else if let Some(c) = const_re.captures(line) {
+                items.insert(format!("const {}: {}", &c[1], normalize(&c[2])));
+            } else if let Some(c) = impl_re.captures(line) {
+                let name = c[2].to_string();
+                if let Some(tr) = c.get(1) {
+                    items.insert(format!("impl {} for {}", normalize(tr.as_str()), name));
+                }
+                current = Some(("impl", name));
+            }
+        } else if depth == 1 {
+            match current.as_ref() {
+                Some(("struct", name)) => {
+                    if let Some(c) = field_re.captures(line) {
+                        items.insert(format!("struct {}.{}: {}", name, &c[1], normalize(&c[2])));
+                    }
+                }
+                Some(("enum", name)) => {
+                    if let Some(c) = variant_re.captures(line) {
+                        items.insert(format!("enum {}::{}", name, &c[1]));
+                    }
+                }
+                Some(("impl", _))
+                    if line.starts_with("pub fn") || line.starts_with("pub async fn") =>
+                {
+                    signature = Some(line.to_string());
+                }
+                Some(("impl", name)) | Some(("mod", name)) => {
+                    if let Some(c) = const_re.captures(line) {
+                        items.insert(format!("const {}::{}: {}", name, &c[1], normalize(&c[2])));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(sig) = signature.as_ref() {
+            if sig.ends_with('{') {
+                let sig = normalize(sig.trim_start_matches("pub ").trim_end_matches('{'));
+                let name = current.as_ref().map(|(_, n)| n.as_str()).unwrap_or("");
+                items.insert(match sig.strip_prefix("async fn ") {
+                    Some(rest) => format!("async fn {}::{}", name, rest),
+                    None => format!("fn {}::{}", name, sig.trim_start_matches("fn ")),
+                });
+                signature = None;
+            }
+        }
+
+        depth = (depth + opens).saturating_sub(closes);
+        if depth == 0 {
+            current = None;
+        }
+    }
+    items.into_iter().map(|i| i + "\n").collect()
+}
+
+/// Compare two reports created by `api_report()`. Returns the removed items, prefixed with `-`,
+/// and the added items, prefixed with `+`, in the order of the reports.
+pub fn diff_api_reports(old: &str, new: &str) -> String {
+    let old: BTreeSet<&str> = old.lines().filter(|l| !l.is_empty()).collect();
+    let new: BTreeSet<&str> = new.lines().filter(|l| !l.is_empty()).collect();
+    let mut out = String::new();
+    for item in old.union(&new) {
+        if !new.contains(item) {
+            out.push_str(&format!("- {}\n", item));
+        } else if !old.contains(item) {
+            out.push_str(&format!("+ {}\n", item));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = r#"
+pub mod scopes {
+    /// Full access.
+    pub const FULL: &str = "https://example.com/auth/full";
+    pub const READONLY: &str =
+        "https://example.com/auth/readonly";
+}
+
+pub const VERSION: &str = "v1";
+
+/// An item.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Item {
+    /// The ID, e.g. "{id}".
+    #[serde(rename = "id")]
+    pub id: Option<String>,
+    pub labels: Option<HashMap<String,String>>,
+}
+
+pub enum ItemRole {
+    /// Owns it
+    Owner,
+    Other(String),
+}
+
+impl std::fmt::Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", "}")
+    }
+}
+
+impl<C: HttpClient> ItemsService<C> {
+    pub const MAX_PAGE_SIZE: i32 = 100;
+
+    pub fn new
+    <A: 'static + TokenSource + Send + Sync>
+    (client: C, auth: A) -> ItemsService<C> {
+        ItemsService { client: client }
+    }
+    fn format_path(&self, path: &str) -> String {
+        path.into()
+    }
+    pub async fn get(
+        &self,
+        params: &ItemsGetParams,
+    ) -> Result<Item> {
+        unimplemented!()
+    }
+}
+"#;
+
+    #[test]
+    fn test_api_report() {
+        let report = api_report(CODE);
+        assert_eq!(
+            report.lines().collect::<Vec<_>>(),
+            vec![
+                "async fn ItemsService::get(&self,params:&ItemsGetParams)->Result<Item>",
+                "const ItemsService::MAX_PAGE_SIZE: i32",
+                "const VERSION: &str",
+                "const scopes::FULL: &str",
+                "const scopes::READONLY: &str",
+                "enum ItemRole",
+                "enum ItemRole::Other",
+                "enum ItemRole::Owner",
+                "fn ItemsService::new<A:'static+TokenSource+Send+Sync>(client:C,auth:A)->ItemsService<C>",
+                "impl std::fmt::Display for Item",
+                "mod scopes",
+                "struct Item",
+                "struct Item.id: Option<String>",
+                "struct Item.labels: Option<HashMap<String,String>>",
+            ]
+        );
+        let formatted = CODE.replace("HashMap<String,String>", "HashMap<String, String>");
+        assert_eq!(api_report(&formatted), report);
+    }
+
+    #[test]
+    fn test_diff_api_reports() {
+        let old = "struct Item\nstruct Item.id: Option<String>\n";
+        let new = "struct Item\nstruct Item.id: Option<i64>\n";
+        assert_eq!(
+            diff_api_reports(old, new),
+            "- struct Item.id: Option<String>\n+ struct Item.id: Option<i64>\n"
+        );
+    }
+}
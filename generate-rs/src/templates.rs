@@ -1,5 +1,5 @@
 /// General imports and error type.
-const RUST_HEADER: &'static str = r###"
+pub(crate) const RUST_HEADER: &str = r###"
 #![allow(unused_variables, unused_mut, dead_code)]
 //! This file was generated by async-google-apis. (https://github.com/dermesser/async-google-apis)
 //!
@@ -14,16 +14,72 @@ const RUST_HEADER: &'static str = r###"
 //! I'd be happy if you let me know about your use case of this code.
 //!
 //! THIS FILE HAS BEEN GENERATED -- SAVE ANY MODIFICATIONS BEFORE REPLACING.
+{{#has_renames}}//!
+//! ## Renamed identifiers
+//!
+//! Names from the API that collide with Rust keywords have been renamed:
+//!
+{{/has_renames}}{{#renames}}//! * `{{{name}}}` → `{{{rust}}}`
+{{/renames}}{{#has_features}}//!
+//! ## Features
+//!
+//! The services of each resource, their parameter types, and the schema types only they use
+//! are compiled only with the resource's feature enabled. Declare the features in the
+//! `Cargo.toml` of the crate containing this file, and enable those of the resources you use:
+//!
+//! ```toml
+//! [features]
+{{/has_features}}{{#features}}//! {{{name}}} = []
+{{/features}}{{#has_features}}//! ```
+{{/has_features}}
 
 use async_google_apis_common::*;
 "###;
 
+/// Used with --split, after RUST_HEADER in mod.rs.
+/// Dict contents --
+/// modules: [{name}]
+pub(crate) const MODULE_TREE_TMPL: &str = r###"
+{{#modules}}
+mod {{{name}}};
+pub use {{{name}}}::*;
+{{/modules}}
+"###;
+
+/// Code compiled only with some features enabled, when generating with `resource_features`.
+/// Dict contents --
+/// cfg (e.g. `feature = "drive-files"`), module, code
+pub(crate) const FEATURE_GATE_TMPL: &str = r###"
+#[cfg({{{cfg}}})]
+mod {{{module}}} {
+use super::*;
+{{{code}}}
+}
+#[cfg({{{cfg}}})]
+pub use {{{module}}}::*;
+"###;
+
+/// Beginning of the files of the module tree other than mod.rs.
+pub(crate) const SUBMODULE_HEADER: &str = r###"//! Part of the generated module; see its documentation.
+
+use super::*;
+"###;
+
 /// Dict contents --
 /// name (of API, Capitalized)
 /// scopes: [{name, url, desc}]
-const OAUTH_SCOPES_TYPE: &'static str = r###""
-/// Scopes of this API. Convertible to their string representation with `AsRef`.
-#[derive(Debug, Clone, Copy)]
+pub(crate) const OAUTH_SCOPES_TYPE: &str = r###"
+/// The URLs of the scopes of this API, e.g. for `set_scopes(&[scopes::...])`.
+pub mod scopes {
+    {{#scopes}}
+    /// {{{desc}}}
+    pub const {{{const_name}}}: &str = "{{{url}}}";
+    {{/scopes}}
+}
+
+/// Scopes of this API. Convertible to their string representation with `as_str()` or `AsRef`.
+/// Select them for a service with `set_scopes(&[...])`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum {{{name}}} {
     {{#scopes}}
     /// {{{desc}}}
@@ -33,25 +89,167 @@ pub enum {{{name}}} {
     {{/scopes}}
 }
 
-impl std::convert::AsRef<str> for {{{name}}} {
-    fn as_ref(&self) -> &'static str {
+/// The scopes of this API, under a name shared by all generated APIs.
+pub type Scope = {{{name}}};
+
+impl {{{name}}} {
+    /// The URL identifying this scope.
+    pub fn as_str(&self) -> &'static str {
         match self {
             {{#scopes}}
-            {{{name}}}::{{{scope_name}}} => "{{{url}}}",
+            {{{name}}}::{{{scope_name}}} => scopes::{{{const_name}}},
             {{/scopes}}
         }
     }
 }
 
+impl std::convert::AsRef<str> for {{{name}}} {
+    fn as_ref(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
 "###;
 
-/// A struct for parameters or input/output API types.
+/// An enum for string fields with enumerated values. Unknown values are kept as `Other`.
+/// Dict contents --
+/// name, description
+/// values: [{variant, value, desc}]
+pub(crate) const ENUM_TMPL: &str = r###"
+/// {{{description}}}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum {{{name}}} {
+    {{#values}}
+    {{#desc}}
+    /// {{{desc}}}
+    {{/desc}}
+    {{{variant}}},
+    {{/values}}
+    /// A value not known when this code was generated.
+    Other(String),
+}
+
+impl {{{name}}} {
+    /// The value as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            {{#values}}
+            {{{name}}}::{{{variant}}} => "{{{value}}}",
+            {{/values}}
+            {{{name}}}::Other(v) => v.as_str(),
+        }
+    }
+}
+
+impl Default for {{{name}}} {
+    fn default() -> {{{name}}} {
+        {{{name}}}::Other(String::new())
+    }
+}
+
+impl std::convert::AsRef<str> for {{{name}}} {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for {{{name}}} {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for {{{name}}} {
+    fn from(v: &str) -> {{{name}}} {
+        match v {
+            {{#values}}
+            "{{{value}}}" => {{{name}}}::{{{variant}}},
+            {{/values}}
+            _ => {{{name}}}::Other(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for {{{name}}} {
+    fn from(v: String) -> {{{name}}} {
+        v.as_str().into()
+    }
+}
+
+impl Serialize for {{{name}}} {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for {{{name}}} {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> std::result::Result<{{{name}}}, D::Error> {
+        Ok(String::deserialize(d)?.into())
+    }
+}
+"###;
+
+/// A newtype for IDs, when generating with `typed_ids`.
 /// Dict contents --
 /// name
+pub(crate) const ID_TMPL: &str = r###"
+/// An ID, distinct from other kinds of IDs so that they can't be mixed up. Converts from strings
+/// and dereferences to `str`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct {{{name}}}(pub String);
+
+impl {{{name}}} {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for {{{name}}} {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::AsRef<str> for {{{name}}} {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for {{{name}}} {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for {{{name}}} {
+    fn from(s: &str) -> {{{name}}} {
+        {{{name}}}(s.to_string())
+    }
+}
+
+impl From<String> for {{{name}}} {
+    fn from(s: String) -> {{{name}}} {
+        {{{name}}}(s)
+    }
+}
+
+impl From<{{{name}}}> for String {
+    fn from(id: {{{name}}}) -> String {
+        id.0
+    }
+}
+"###;
+
+/// A struct for parameters or input/output API types.
+/// Dict contents --
+/// name, eq (whether `Eq` can be derived)
 /// fields: [{name, comment, attr, typ}]
-const SCHEMA_STRUCT_TMPL: &'static str = r###"
+pub(crate) const SCHEMA_STRUCT_TMPL: &str = r###"
 /// {{{description}}}
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize{{^redacted}}, Debug{{/redacted}}, Clone, PartialEq{{#eq}}, Eq{{/eq}}{{^custom_default}}, Default{{/custom_default}})]
 pub struct {{{name}}} {
 {{#fields}}
     {{#comment}}
@@ -65,51 +263,135 @@ pub struct {{{name}}} {
 }
 "###;
 
-/// Serialize a global params struct to a URL query string.
-const SCHEMA_DISPLAY_TMPL: &'static str = r###"
-impl std::fmt::Display for {{{name}}} {
+/// `Debug` for a struct with credentials (e.g. `oauth_token`), which are not shown.
+/// Dict contents --
+/// name
+/// fields: [{name, sensitive}]
+pub(crate) const REDACTED_DEBUG_TMPL: &str = r###"
+impl std::fmt::Debug for {{{name}}} {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("{{{name}}}")
+            {{#fields}}
+            .field("{{{name}}}", {{#sensitive}}&self.{{{name}}}.as_ref().map(|_| "redacted"){{/sensitive}}{{^sensitive}}&self.{{{name}}}{{/sensitive}})
+            {{/fields}}
+            .finish()
+    }
+}
+"###;
+
+/// Default for a params struct with non-default field values, e.g. a `pageSize`.
+/// Dict contents --
+/// name
+/// defaults: [{name, value}]
+pub(crate) const PARAMS_DEFAULT_TMPL: &str = r###"
+impl Default for {{{name}}} {
+    fn default() -> {{{name}}} {
+        {{{name}}} {
+            {{#defaults}}
+            {{{name}}}: {{{value}}},
+            {{/defaults}}
+        }
+    }
+}
+"###;
+
+/// Chainable setters for the fields of a *Params struct.
+/// Dict contents --
+/// name
+/// setters: [{name, typ, optional}]
+pub(crate) const PARAMS_BUILDER_TMPL: &str = r###"
+impl {{{name}}} {
+    {{#setters}}
+    /// Builder-style setter for `{{{name}}}`.
+    pub fn {{{name}}}<T: Into<{{{typ}}}>>(mut self, value: T) -> Self {
+        self.{{{name}}} = {{#optional}}Some(value.into()){{/optional}}{{^optional}}value.into(){{/optional}};
+        self
+    }
+    {{/setters}}
+}
+"###;
+
+/// Serialize a params struct into a URL query.
+pub(crate) const PARAMS_QUERY_TMPL: &str = r###"
+impl {{{name}}} {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(&self, query: &mut form_urlencoded::Serializer<'_, T>) {
         {{#required_fields}}
-        write!(f, "&{{{original_name}}}={}", percent_encode(format!("{}", self.{{{name}}}).as_bytes(), NON_ALPHANUMERIC).to_string())?;
+        query.append_pair("{{{original_name}}}", &self.{{{name}}}.to_string());
         {{/required_fields}}
         {{#optional_fields}}
         if let Some(ref v) = self.{{{name}}} {
-            write!(f, "&{{{original_name}}}={}", percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string())?;
+            query.append_pair("{{{original_name}}}", &v.to_string());
         }
         {{/optional_fields}}
-        Ok(())
     }
 }
 "###;
 
+/// Round-trip tests of the schema types: sample JSON is parsed into the type and serialized
+/// again, which must result in the same JSON.
 /// Dict contents --
-/// 
+/// tests: [{name, fn_name, json, cfg}]
+pub(crate) const ROUND_TRIP_TESTS_TMPL: &str = r###"
+#[cfg(test)]
+mod round_trip_tests {
+    use super::*;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(json: &str) {
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+    }
+    {{#tests}}
+
+    #[test]
+    {{#cfg}}
+    #[cfg({{{cfg}}})]
+    {{/cfg}}
+    fn {{{fn_name}}}() {
+        round_trip::<{{{name}}}>(r##"{{{json}}}"##);
+    }
+    {{/tests}}
+}
+"###;
+
+/// Dict contents --
+///
 /// api, service (names: e.g. Files)
 /// methods: [{text}] (the method implementations as {'text': ...} dicts)
 /// name (API name)
-const SERVICE_IMPLEMENTATION_TMPL: &'static str = r###"
+pub(crate) const SERVICE_IMPLEMENTATION_TMPL: &str = r###"
 /// The {{{name}}} {{{service}}} service represents the {{{service}}} resource.
-pub struct {{{service}}}Service {
-    client: TlsClient,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct {{{service}}}Service<C = TlsClient> {
+    client: C,
     {{#wants_auth}}
-    authenticator: Box<dyn 'static + std::ops::Deref<Target=Authenticator>>,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
     {{/wants_auth}}
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl {{{service}}}Service {
+impl<C: HttpClient> {{{service}}}Service<C> {
     /// Create a new {{service}}Service object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
     pub fn new
-    {{#wants_auth}}<A: 'static + std::ops::Deref<Target=Authenticator>>
-    {{/wants_auth}}(client: TlsClient{{#wants_auth}}, auth: A{{/wants_auth}}) -> {{service}}Service {
+    {{#wants_auth}}<A: 'static + TokenSource + Send + Sync>
+    {{/wants_auth}}(client: C{{#wants_auth}}, auth: A{{/wants_auth}}) -> {{service}}Service<C> {
         {{{service}}}Service { client: client
-            {{#wants_auth}}, authenticator: Box::new(auth), scopes: vec![]{{/wants_auth}},
-            base_url: "{{{base_path}}}".into(), root_url: "{{{root_path}}}".into() }
+            {{#wants_auth}}, authenticator: Arc::new(auth), scopes: vec![]{{/wants_auth}},
+            base_url: "{{{base_path}}}".into(), root_url: "{{{root_path}}}".into(),
+            extra_headers: vec![], default_fields: None, standard_params: Default::default() }
     }
 
     /// Provide the base URL of this API. The returned URL is guaranteed to end with a '/'.
@@ -135,24 +417,81 @@ impl {{{service}}}Service {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     {{#wants_auth}}
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of {{{name}}}Scopes enum values.
+    /// It is most convenient to supply a vec or slice of {{{name}}}Scopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes.as_ref().into_iter().map(|s| s.as_ref().to_string()).collect();
     }
     {{/wants_auth}}
 
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self, http_method: &str, rel_path: &str, query: &str, scopes: &[&str], req: Option<&Req>)
+        -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
+
+        let mut headers = self.extra_headers.clone();
+        {{#wants_auth}}
+        let tok;
+        if self.scopes.is_empty() {
+            tok = self.authenticator.token(scopes).await?;
+        } else {
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
+        }
+        headers.push((hyper::header::AUTHORIZATION, format!("Bearer {token}", token=tok.as_str())));
+        {{/wants_auth}}
+
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
+        }
+
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
+
     {{#methods}}
     {{{text}}}
     {{/methods}}
@@ -164,43 +503,61 @@ impl {{{service}}}Service {
 /// base_path, rel_path_expr, scopes (string repr. of rust string array),
 /// params: [{param, snake_param}]
 /// http_method
-const NORMAL_METHOD_TMPL: &'static str = r###"
+pub(crate) const NORMAL_METHOD_TMPL: &str = r###"
 /// {{{description}}}
 pub async fn {{{name}}}(
-    &mut self, params: &{{{param_type}}}{{#in_type}}, req: &{{{in_type}}}{{/in_type}}) -> Result<{{{out_type}}}> {
+    &self, params: &{{{param_type}}}{{#in_type}}, req: &{{{in_type}}}{{/in_type}}) -> Result<{{{out_type}}}> {
+    Ok(self.{{{name}}}_with_response(params{{#in_type}}, req{{/in_type}}).await?.body)
+}
+
+/// Like `{{{name}}}()`, but also returns the status and headers of the response, e.g. its `ETag`.
+pub async fn {{{name}}}_with_response(
+    &self, params: &{{{param_type}}}{{#in_type}}, req: &{{{in_type}}}{{/in_type}}) -> Result<ApiResponse<{{{out_type}}}>> {
+  traced("{{{service}}}", "{{{name}}}", async move {
 
     let rel_path = {{{rel_path_expr}}};
     let path = self.format_path(rel_path.as_str());
+    trace_path(&rel_path);
 
-    let mut headers = vec![];
+    let mut headers = self.extra_headers.clone();
     {{#wants_auth}}
     let tok;
     if self.scopes.is_empty() {
-        let scopes = &[{{#scopes}}{{{scope}}}.as_ref().to_string(),
+        let scopes: &'static [&'static str] = &[{{#scopes}}{{{scope}}},
         {{/scopes}}];
         tok = self.authenticator.token(scopes).await?;
     } else {
-        tok = self.authenticator.token(&self.scopes).await?;
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        tok = self.authenticator.token(&scopes).await?;
     }
     headers.push((hyper::header::AUTHORIZATION, format!("Bearer {token}", token=tok.as_str())));
     {{/wants_auth}}
 
-    let mut url_params = format!("?{params}", params=params);
-    {{#global_params_name}}
-    if let Some(ref api_params) = &params.{{{global_params_name}}} {
-        url_params.push_str(&format!("{}", api_params));
+    let mut url = Url::parse(&path)?;
+    {
+        let mut query = url.query_pairs_mut();
+        params.append_to(&mut query);
+        {{#global_params_name}}
+        if let Some(ref api_params) = &params.{{{global_params_name}}} {
+            api_params.append_to(&mut query);
+        }
+        {{/global_params_name}}
+    }
+    self.standard_params.append_to(&mut url);
+    if let Some(ref fields) = self.default_fields {
+        if !url.query_pairs().any(|(name, _)| name == "fields") {
+            url.query_pairs_mut().append_pair("fields", fields);
+        }
     }
-    {{/global_params_name}}
-
-    let full_uri = path + &url_params;
 
     let opt_request: Option<&EmptyRequest> = None;
     {{#in_type}}
     let opt_request = Some(req);
     {{/in_type}}
-    do_request(&self.client, &full_uri,
+    do_request_with_response(&self.client, url.as_str(),
         &headers,
         "{{{http_method}}}", opt_request).await
+  }).await
   }
 "###;
 
@@ -209,45 +566,71 @@ pub async fn {{{name}}}(
 // base_path, rel_path_expr
 // params: [{param, snake_param}]
 // http_method
-const UPLOAD_METHOD_TMPL: &'static str = r###"
+pub(crate) const UPLOAD_METHOD_TMPL: &str = r###"
 /// {{{description}}}
 ///
 /// This method is a variant of `{{{name}}}()`, taking data for upload. It performs a multipart upload.
 pub async fn {{{name}}}_upload(
-    &mut self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}},{{/in_type}} data: hyper::body::Bytes) -> Result<{{out_type}}> {
+    &self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}},{{/in_type}} data: hyper::body::Bytes) -> Result<{{out_type}}> {
+    self.{{{name}}}_upload_with_type(params, {{#in_type}}req, {{/in_type}}data, None).await
+}
+
+/// Like `{{{name}}}_upload()`, but sends `content_type` as the MIME type of `data`. Without it,
+/// the server detects the type from the content.
+pub async fn {{{name}}}_upload_with_type(
+    &self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}},{{/in_type}} data: hyper::body::Bytes, content_type: Option<&str>) -> Result<{{out_type}}> {
+    Ok(self.{{{name}}}_upload_with_response(params, {{#in_type}}req, {{/in_type}}data, content_type).await?.body)
+}
+
+/// Like `{{{name}}}_upload_with_type()`, but also returns the status and headers of the response.
+pub async fn {{{name}}}_upload_with_response(
+    &self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}},{{/in_type}} data: hyper::body::Bytes, content_type: Option<&str>) -> Result<ApiResponse<{{out_type}}>> {
+  traced("{{{service}}}", "{{{name}}}_upload", async move {
     let rel_path = {{{simple_rel_path_expr}}};
     let path = self.format_path(rel_path.as_str());
+    trace_path(&rel_path);
 
-    let mut headers = vec![];
+    let mut headers = self.extra_headers.clone();
     {{#wants_auth}}
     let tok;
     if self.scopes.is_empty() {
-        let scopes = &[{{#scopes}}{{{scope}}}.as_ref().to_string(),
+        let scopes: &'static [&'static str] = &[{{#scopes}}{{{scope}}},
         {{/scopes}}];
         tok = self.authenticator.token(scopes).await?;
     } else {
-        tok = self.authenticator.token(&self.scopes).await?;
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        tok = self.authenticator.token(&scopes).await?;
     }
     headers.push((hyper::header::AUTHORIZATION, format!("Bearer {token}", token=tok.as_str())));
     {{/wants_auth}}
 
-    let mut url_params = format!("?uploadType=multipart{params}", params=params);
-
-    {{#global_params_name}}
-    if let Some(ref api_params) = &params.{{{global_params_name}}} {
-        url_params.push_str(&format!("{}", api_params));
+    let mut url = Url::parse(&path)?;
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("uploadType", "multipart");
+        params.append_to(&mut query);
+        {{#global_params_name}}
+        if let Some(ref api_params) = &params.{{{global_params_name}}} {
+            api_params.append_to(&mut query);
+        }
+        {{/global_params_name}}
+    }
+    self.standard_params.append_to(&mut url);
+    if let Some(ref fields) = self.default_fields {
+        if !url.query_pairs().any(|(name, _)| name == "fields") {
+            url.query_pairs_mut().append_pair("fields", fields);
+        }
     }
-    {{/global_params_name}}
 
-    let full_uri = path + &url_params;
     let opt_request: Option<&EmptyRequest> = None;
     {{#in_type}}
     let opt_request = Some(req);
     {{/in_type}}
 
-    do_upload_multipart(&self.client, &full_uri,
+    do_upload_multipart_with_response(&self.client, url.as_str(),
         &headers,
-        "{{{http_method}}}", opt_request, data).await
+        "{{{http_method}}}", opt_request, data, content_type).await
+  }).await
   }
 "###;
 
@@ -256,7 +639,7 @@ pub async fn {{{name}}}_upload(
 /// base_path, rel_path_expr
 /// params: [{param, snake_param}]
 /// http_method
-const RESUMABLE_UPLOAD_METHOD_TMPL: &'static str = r###"
+pub(crate) const RESUMABLE_UPLOAD_METHOD_TMPL: &str = r###"
 /// {{{description}}}
 ///
 /// This method is a variant of `{{{name}}}()`, taking data for upload.
@@ -264,39 +647,63 @@ const RESUMABLE_UPLOAD_METHOD_TMPL: &'static str = r###"
 /// of data to the API. The result of this call will be returned by the `ResumableUpload` method
 /// you choose for the upload.
 pub async fn {{{name}}}_resumable_upload<'client>(
-    &'client mut self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}}{{/in_type}}) -> Result<ResumableUpload<'client, {{{out_type}}}>> {
+    &'client self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}}{{/in_type}}) -> Result<ResumableUpload<'client, {{{out_type}}}, C>> {
+    self.{{{name}}}_resumable_upload_with_type(params, {{#in_type}}req, {{/in_type}}None).await
+}
+
+/// Like `{{{name}}}_resumable_upload()`, but sends `content_type` as the MIME type of the data
+/// to be uploaded. Without it, the server detects the type from the content.
+pub async fn {{{name}}}_resumable_upload_with_type<'client>(
+    &'client self, params: &{{{param_type}}}, {{#in_type}}req: &{{{in_type}}}, {{/in_type}}content_type: Option<&str>) -> Result<ResumableUpload<'client, {{{out_type}}}, C>> {
+  traced("{{{service}}}", "{{{name}}}_resumable_upload", async move {
 
     let rel_path = {{{resumable_rel_path_expr}}};
     let path = self.format_path(rel_path.as_str());
+    trace_path(&rel_path);
 
-    let mut headers = vec![];
+    let mut headers = self.extra_headers.clone();
     {{#wants_auth}}
     let tok;
     if self.scopes.is_empty() {
-        let scopes = &[{{#scopes}}{{{scope}}}.as_ref().to_string(),
+        let scopes: &'static [&'static str] = &[{{#scopes}}{{{scope}}},
         {{/scopes}}];
         tok = self.authenticator.token(scopes).await?;
     } else {
-        tok = self.authenticator.token(&self.scopes).await?;
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        tok = self.authenticator.token(&scopes).await?;
     }
     headers.push((hyper::header::AUTHORIZATION, format!("Bearer {token}", token=tok.as_str())));
     {{/wants_auth}}
 
-    let mut url_params = format!("?uploadType=resumable{params}", params=params);
-    {{#global_params_name}}
-    if let Some(ref api_params) = &params.{{{global_params_name}}} {
-        url_params.push_str(&format!("{}", api_params));
+    if let Some(content_type) = content_type {
+        headers.push((hyper::header::HeaderName::from_static("x-upload-content-type"), content_type.to_string()));
+    }
+
+    let mut url = Url::parse(&path)?;
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("uploadType", "resumable");
+        params.append_to(&mut query);
+        {{#global_params_name}}
+        if let Some(ref api_params) = &params.{{{global_params_name}}} {
+            api_params.append_to(&mut query);
+        }
+        {{/global_params_name}}
+    }
+    self.standard_params.append_to(&mut url);
+    if let Some(ref fields) = self.default_fields {
+        if !url.query_pairs().any(|(name, _)| name == "fields") {
+            url.query_pairs_mut().append_pair("fields", fields);
+        }
     }
-    {{/global_params_name}}
 
-    let full_uri = path + &url_params;
 
     let opt_request: Option<&EmptyRequest> = None;
     {{#in_type}}
     let opt_request = Some(req);
     {{/in_type}}
     let (_resp, headers): (EmptyResponse, hyper::HeaderMap) = do_request_with_headers(
-        &self.client, &full_uri, &headers, "{{{http_method}}}", opt_request).await?;
+        &self.client, url.as_str(), &headers, "{{{http_method}}}", opt_request).await?;
     if let Some(dest) = headers.get(hyper::header::LOCATION) {
         use std::convert::TryFrom;
         Ok(ResumableUpload::new(hyper::Uri::try_from(dest.to_str()?)?, &self.client, 5*1024*1024))
@@ -304,6 +711,7 @@ pub async fn {{{name}}}_resumable_upload<'client>(
         Err(Error::from(ApiError::RedirectError(format!("Resumable upload response didn't contain Location: {:?}", headers)))
         .context(format!("{:?}", headers)))?
     }
+  }).await
   }
 "###;
 
@@ -312,45 +720,101 @@ pub async fn {{{name}}}_resumable_upload<'client>(
 /// base_path, rel_path_expr
 /// params: [{param, snake_param}]
 /// http_method
-const DOWNLOAD_METHOD_TMPL: &'static str = r###"
+pub(crate) const DOWNLOAD_METHOD_TMPL: &str = r###"
 /// {{{description}}}
 ///
 /// This method potentially downloads data. See documentation of `Download`.
 pub async fn {{{name}}}<'a>(
-    &'a mut self, params: &{{{param_type}}}, {{#in_type}}req: &'a {{{in_type}}}{{/in_type}})
-    -> Result<Download<'a, {{{download_in_type}}}, {{{out_type}}}>> {
+    &'a self, params: &{{{param_type}}}, {{#in_type}}req: &'a {{{in_type}}}{{/in_type}})
+    -> Result<Download<'a, {{{download_in_type}}}, {{{out_type}}}, C>> {
+  traced("{{{service}}}", "{{{name}}}", async move {
 
     let rel_path = {{{rel_path_expr}}};
     let path = self.format_path(rel_path.as_str());
+    trace_path(&rel_path);
 
-    let mut headers = vec![];
+    let mut headers = self.extra_headers.clone();
     {{#wants_auth}}
     let tok;
     if self.scopes.is_empty() {
-        let scopes = &[{{#scopes}}{{{scope}}}.as_ref().to_string(),
+        let scopes: &'static [&'static str] = &[{{#scopes}}{{{scope}}},
         {{/scopes}}];
         tok = self.authenticator.token(scopes).await?;
     } else {
-        tok = self.authenticator.token(&self.scopes).await?;
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        tok = self.authenticator.token(&scopes).await?;
     }
     headers.push((hyper::header::AUTHORIZATION, format!("Bearer {token}", token=tok.as_str())));
     {{/wants_auth}}
 
-    let mut url_params = format!("?{params}", params=params);
-    {{#global_params_name}}
-    if let Some(ref api_params) = &params.{{{global_params_name}}} {
-        url_params.push_str(&format!("{}", api_params));
+    let mut url = Url::parse(&path)?;
+    {
+        let mut query = url.query_pairs_mut();
+        params.append_to(&mut query);
+        {{#global_params_name}}
+        if let Some(ref api_params) = &params.{{{global_params_name}}} {
+            api_params.append_to(&mut query);
+        }
+        {{/global_params_name}}
+    }
+    self.standard_params.append_to(&mut url);
+    if let Some(ref fields) = self.default_fields {
+        if !url.query_pairs().any(|(name, _)| name == "fields") {
+            url.query_pairs_mut().append_pair("fields", fields);
+        }
     }
-    {{/global_params_name}}
 
-    let full_uri = path + &url_params;
     let opt_request: Option<&EmptyRequest> = None;
     {{#in_type}}
     let opt_request = Some(req);
     {{/in_type}}
 
-    do_download(&self.client, &full_uri,
+    do_download(&self.client, url.as_str(),
         headers,
         "{{{http_method}}}".into(), opt_request).await
+  }).await
   }
 "###;
+
+/// A hub owning the client and token source shared by all services of an API.
+/// Dict contents --
+/// name (of API, camel-cased), first_accessor
+/// services: [{service, accessor, wants_auth, cfg}]
+pub(crate) const HUB_TMPL: &str = r###"
+/// All services of the {{{name}}} API, sharing one HTTP client and one token source. Each
+/// accessor returns a new service; services are cheap to create and to clone.
+///
+/// ```ignore
+/// let hub = {{{name}}}Hub::new(https_client, authenticator);
+/// let service = hub.{{{first_accessor}}}();
+/// ```
+#[derive(Clone)]
+pub struct {{{name}}}Hub<C = TlsClient> {
+    apis: GoogleApis<C>,
+}
+
+impl<C: HttpClient> {{{name}}}Hub<C> {
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> {{{name}}}Hub<C> {
+        {{{name}}}Hub { apis: GoogleApis::new(client, auth) }
+    }
+
+    /// A hub using the client and token source of `apis`, e.g. to share them with other APIs.
+    pub fn from_apis(apis: GoogleApis<C>) -> {{{name}}}Hub<C> {
+        {{{name}}}Hub { apis }
+    }
+
+    /// The shared client and token source.
+    pub fn apis(&self) -> &GoogleApis<C> {
+        &self.apis
+    }
+    {{#services}}
+
+    {{#cfg}}
+    #[cfg({{{cfg}}})]
+    {{/cfg}}
+    pub fn {{{accessor}}}(&self) -> {{{service}}}Service<C> {
+        {{#wants_auth}}self.apis.service({{{service}}}Service::new){{/wants_auth}}{{^wants_auth}}{{{service}}}Service::new(self.apis.client()){{/wants_auth}}
+    }
+    {{/services}}
+}
+"###;
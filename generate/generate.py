@@ -21,11 +21,102 @@ def optionalize(name, optional=True):
     return 'Option<{}>'.format(name) if optional else name
 
 
+# Names that are replaced by a fixed identifier. Extend with --rename.
+RENAMES = {
+    'type': 'typ',
+    'enum': 'enums',
+}
+
+# Rust keywords; not in RENAMES, they are emitted as raw identifiers (`r#match`).
+RUST_KEYWORDS = {
+    'as', 'async', 'await', 'break', 'const', 'continue', 'dyn', 'else', 'enum', 'extern', 'false', 'fn', 'for',
+    'if', 'impl', 'in', 'let', 'loop', 'match', 'mod', 'move', 'mut', 'pub', 'ref', 'return', 'static', 'struct',
+    'trait', 'true', 'type', 'unsafe', 'use', 'where', 'while', 'abstract', 'become', 'box', 'do', 'final',
+    'macro', 'override', 'priv', 'try', 'typeof', 'unsized', 'virtual', 'yield'
+}
+
+# Keywords that can't be raw identifiers; they get an underscore appended.
+RUST_RESERVED = {'self', 'Self', 'super', 'crate'}
+
+# Standard parameters carrying credentials, which `Debug` doesn't show.
+CREDENTIAL_PARAMS = {'oauth_token', 'access_token', 'key'}
+
+# Renames applied while generating the current file, listed in its documentation.
+renamed = {}
+
+# Integer formats of string fields, and the Rust types used for such fields of structs.
+STRING_INT_FORMATS = {'int64': 'i64', 'int32': 'i32', 'uint64': 'u64', 'uint32': 'u32'}
+
+# Whether to generate enums for string fields with enumerated values. Set with --typed_enums.
+TYPED_ENUMS = False
+
+# Default pageSize of the parameters of list methods, by method ID (e.g. drive.files.list). Set
+# with --page_size.
+PAGE_SIZES = {}
+
+# Rust types to use for parameters, by method ID and parameter name (e.g.
+# drive.files.list.orderBy). Set with --param_type.
+PARAM_TYPES = {}
+
+# Whether to generate a module tree instead of a single file. Set with --split.
+SPLIT = False
+
+# Whether to compile the code of each top-level resource only with a Cargo feature like
+# `drive-files`. Set with --resource_features.
+RESOURCE_FEATURES = False
+
+# Whether to generate newtypes like `FileId` for string IDs. Set with --typed_ids.
+TYPED_IDS = False
+
+# With TYPED_IDS: the ID types used in the current file, and the schema names, which ID types
+# must not collide with.
+ids_used = set()
+schema_names = set()
+
+# With TYPED_IDS: ID types of fields and parameters whose name doesn't say which kind of ID they
+# hold, by name: `Schema.property` for a property of one (possibly nested) schema, or the bare
+# name for all properties and parameters of that name.
+ID_TYPES = {
+    'headRevisionId': 'RevisionId',
+    'FileShortcutDetails.targetId': 'FileId',
+    'teamDriveId': 'DriveId',
+    'TeamDrive.id': 'DriveId',
+}
+
+
 def replace_keywords(name):
-    return {
-        'type': 'typ',
-        'enum': 'enums',
-    }.get(name, name)
+    if name in RENAMES:
+        new = RENAMES[name]
+    elif name in RUST_RESERVED:
+        new = name + '_'
+    elif name in RUST_KEYWORDS:
+        new = 'r#' + name
+    else:
+        return name
+    renamed[name] = new
+    return new
+
+
+def id_type(name, owner, parent, schema):
+    """With TYPED_IDS, the ID type for the property or parameter `name` of type `schema`, e.g.
+    `FileId` for `fileId`, or for the `id` of the schema `File` (`owner`). `parent` is the struct
+    a property belongs to, for looking up ID_TYPES."""
+    if not TYPED_IDS or schema.get('type', '') != 'string' or 'format' in schema or 'enum' in schema:
+        return None
+    listed = ID_TYPES.get('{}.{}'.format(parent, name)) if parent else None
+    listed = listed or ID_TYPES.get(name)
+    if listed:
+        typ = listed
+    elif name == 'id' and owner:
+        typ = owner + 'Id'
+    elif len(name) > 2 and name.endswith('Id'):
+        typ = capitalize_first(name)
+    else:
+        return None
+    if not typ.isalnum() or not typ.isascii() or typ in schema_names:
+        return None
+    ids_used.add(typ)
+    return typ
 
 
 def capitalize_first(name):
@@ -61,6 +152,51 @@ def snake_to_camel(name):
     return ''.join(dest)
 
 
+def enum_variant(value):
+    variant = snake_to_camel(re.sub('[^A-Za-z0-9_]', '_', rust_identifier(value)))
+    if not variant or not variant[0].isalpha():
+        variant = 'V' + variant
+    return variant
+
+
+def generate_enum(name, schema):
+    """Build a dict for rendering the EnumTmpl from a string schema with enumerated values."""
+    descs = schema.get('enumDescriptions', [])
+    values = []
+    used = {'Other'}
+    for i, v in enumerate(schema['enum']):
+        variant = enum_variant(v)
+        while variant in used:
+            variant += '_'
+        used.add(variant)
+        desc = descs[i] if i < len(descs) else ''
+        values.append({'variant': variant, 'value': v, 'desc': desc.replace('\n', ' ')})
+    return {'name': name, 'description': schema.get('description', '').replace('\n', ' '), 'values': values}
+
+
+def mentions(typ, names):
+    """Whether the Rust type `typ` mentions one of `names`."""
+    return any(t in names for t in re.split(r'[^A-Za-z0-9_]+', typ))
+
+
+# The floating-point types, which don't implement `Eq`.
+FLOAT_TYPES = {'f32', 'f64'}
+
+
+def non_eq_structs(structs):
+    """The names of the `structs` that can't derive `Eq`, as they contain floating-point numbers,
+    directly or through other structs."""
+    non_eq = set(FLOAT_TYPES)
+    while True:
+        new = [
+            s['name'] for s in structs if 'fields' in s and s['name'] not in non_eq and any(
+                mentions(f['typ'], non_eq) for f in s['fields'])
+        ]
+        if not new:
+            return non_eq
+        non_eq.update(new)
+
+
 def global_params_name(api_name):
     return snake_to_camel(api_name + 'Params')
 
@@ -103,7 +239,11 @@ def parse_schema_types(name, schema, optional=True, parents=[]):
                 typ = name
                 struct = {'name': name, 'description': schema.get('description', ''), 'fields': []}
                 for pn, pp in schema['properties'].items():
-                    subtyp, substructs = parse_schema_types(name + capitalize_first(pn),
+                    if 'enum' not in pp and pp.get('type', '') == 'string' and not pp.get('format'):
+                        values = documented_values(pp.get('description', ''))
+                        if values:
+                            pp = dict(pp, enum=values)
+                    subtyp, substructs = parse_schema_types(name + snake_to_camel(rust_identifier(pn)),
                                                             pp,
                                                             optional=True,
                                                             parents=parents + [name])
@@ -111,18 +251,29 @@ def parse_schema_types(name, schema, optional=True, parents=[]):
                         subtyp, comment = subtyp
                     else:
                         comment = None
-                    cleaned_pn = replace_keywords(pn)
                     jsonname = pn
-                    cleaned_pn = rust_identifier(cleaned_pn)
+                    cleaned_pn = replace_keywords(rust_identifier(pn))
+                    attr = ''
+                    if subtyp.startswith('Option'):
+                        attr = ('#[serde(rename = "{}")]'.format(jsonname) +
+                                '\n    #[serde(skip_serializing_if = "Option::is_none")]')
+                    # Integers transmitted as strings are represented as integers.
+                    if pp.get('type', '') == 'string' and pp.get('format', '') in STRING_INT_FORMATS:
+                        subtyp = optionalize(STRING_INT_FORMATS[pp['format']])
+                        attr += '\n    #[serde(with = "str_num", default)]'
+                    # Timestamps are parsed leniently.
+                    elif pp.get('type', '') == 'string' and pp.get('format', '') == 'date-time':
+                        attr += '\n    #[serde(with = "lenient_time", default)]'
+                    idtyp = id_type(pn, None if parents else name, name, pp)
+                    if idtyp:
+                        subtyp = optionalize(idtyp)
                     struct['fields'].append({
                         'name':
                         cleaned_pn,
                         'original_name':
                         jsonname,
                         'attr':
-                        '#[serde(rename = "{}")]'.format(jsonname) +
-                        '\n    #[serde(skip_serializing_if = "Option::is_none")]'
-                        if subtyp.startswith('Option') else '',
+                        attr,
                         'typ':
                         subtyp,
                         'comment':
@@ -151,6 +302,9 @@ def parse_schema_types(name, schema, optional=True, parents=[]):
             return (optionalize('Vec<' + typ + '>', optional), schema.get('description', '')), structs + substructs
 
         if schema['type'] == 'string':
+            if TYPED_ENUMS and 'enum' in schema and 'format' not in schema:
+                enum_name = snake_to_camel(rust_identifier(name))
+                return (optionalize(enum_name, optional), schema.get('description', '')), [generate_enum(enum_name, schema)]
 
             def build(intt, typ='String'):
                 return (optionalize(typ, optional), intt + ': ' + schema.get('description', '')), structs
@@ -202,6 +356,81 @@ def parse_schema_types(name, schema, optional=True, parents=[]):
         raise e
 
 
+def render_builder(struct):
+    """Render chainable setters for the fields of the *Params struct `struct`."""
+    setters = []
+    for f in struct['fields']:
+        typ = f['typ']
+        if typ.startswith('Option<') and typ.endswith('>'):
+            setters.append({'name': f['name'], 'typ': typ[len('Option<'):-1], 'optional': True})
+        else:
+            setters.append({'name': f['name'], 'typ': typ, 'optional': False})
+    return chevron.render(ParamsBuilderTmpl, {'name': struct['name'], 'setters': setters})
+
+
+# Intros of dash lists of values, as in "the following are currently allowed:   - owner  - writer".
+VALUE_LIST_INTROS = ['are currently possible:', 'are currently allowed:', 'Valid values are:']
+
+
+def documented_values(description):
+    """The values a string parameter or property accepts according to its description.
+
+    For those without `enum` whose description reads like "Only 'published' is supported",
+    "Supported groupings are: 'user' (...), 'drive' (...) and 'allDrives' (...)" or "Valid values
+    are:   - user  - group". Empty if the description doesn't enumerate values.
+    """
+    for intro in VALUE_LIST_INTROS:
+        i = description.find(intro)
+        if i < 0:
+            continue
+        # `- value` pairs up to the first word that isn't a dash.
+        values = []
+        words = description[i + len(intro):].split()
+        while len(words) >= 2 and words[0] == '-':
+            if not re.fullmatch('[A-Za-z0-9_]+', words[1]):
+                return []
+            values.append(words[1])
+            words = words[2:]
+        return values
+    only = description.find("Only '")
+    if only >= 0:
+        lst = description[only + len('Only '):]
+    else:
+        i = description.find('Supported ')
+        if i < 0:
+            return []
+        rest = description[i:]
+        j = rest.find(' are: ')
+        if j < 0 or '. ' in rest[:j]:
+            return []
+        lst = rest[j + len(' are: '):]
+    # Quoted values outside of parentheses, up to the end of the sentence.
+    values = []
+    depth = 0
+    end = len(lst)
+    i = 0
+    while i < len(lst):
+        c = lst[i]
+        if c == '(':
+            depth += 1
+        elif c == ')':
+            depth -= 1
+        elif c == '.' and depth == 0 and (i + 1 == len(lst) or lst[i + 1] == ' '):
+            end = i
+            break
+        elif c == "'" and depth == 0:
+            close = lst.find("'", i + 1)
+            close = len(lst) if close < 0 else close
+            values.append(lst[i + 1:close])
+            i = close
+        i += 1
+    if not all(re.fullmatch('[A-Za-z0-9_-]+', v) for v in values):
+        return []
+    if only >= 0 and 'supported' not in lst[:end]:
+        return []
+    return values
+
+
 def generate_params_structs(resources, super_name='', global_params=None):
     """Generate parameter structs from the resources list.
 
@@ -230,7 +459,23 @@ def generate_params_structs(resources, super_name='', global_params=None):
             # Build struct dict for rendering.
             if 'parameters' in method:
                 for paramname, param in method['parameters'].items():
-                    (typ, desc), substructs = parse_schema_types('', param, optional=False, parents=[])
+                    if param.get('location', '') == 'path':
+                        # Path parameters are used as strings.
+                        param = {k: v for k, v in param.items() if k != 'enum'}
+                    elif 'enum' not in param and param.get('type', '') == 'string':
+                        values = documented_values(param.get('description', ''))
+                        if values:
+                            param = dict(param, enum=values)
+                    (typ, desc), substructs = parse_schema_types(param_type_name + snake_to_camel(rust_identifier(paramname)),
+                                                                 param,
+                                                                 optional=False,
+                                                                 parents=[])
+                    param_type = PARAM_TYPES.get('{}.{}'.format(method.get('id', ''), paramname))
+                    if param_type:
+                        typ = param_type
+                    else:
+                        frags.extend([chevron.render(EnumTmpl, e) for e in substructs])
+                        typ = id_type(paramname, None, None, param) or typ
                     field = {
                         'name': replace_keywords(rust_identifier(paramname)),
                         'original_name': paramname,
@@ -244,10 +489,22 @@ def generate_params_structs(resources, super_name='', global_params=None):
                             req_query_parameters.append(field)
                         else:
                             opt_query_parameters.append(field)
+            page_size = PAGE_SIZES.get(method.get('id', ''))
+            if page_size is not None and any(f.get('original_name') == 'pageSize' for f in struct['fields']):
+                struct['custom_default'] = True
+                struct['defaults'] = [{
+                    'name': f['name'],
+                    'value': 'Some({})'.format(page_size) if f.get('original_name') == 'pageSize' else 'Default::default()'
+                } for f in struct['fields']]
+            # The global parameters are strings and booleans.
+            struct['eq'] = not any(mentions(f['typ'], FLOAT_TYPES) for f in struct['fields'])
             frags.append(chevron.render(SchemaStructTmpl, struct))
             struct['required_fields'] = req_query_parameters
             struct['optional_fields'] = opt_query_parameters
-            frags.append(chevron.render(SchemaDisplayTmpl, struct))
+            frags.append(chevron.render(ParamsQueryTmpl, struct))
+            if struct.get('custom_default'):
+                frags.append(chevron.render(ParamsDefaultTmpl, struct))
+            frags.append(render_builder(struct))
         # Generate parameter types for subresources.
         frags.extend(
             generate_params_structs(resource.get('resources', {}), super_name=resourcename,
@@ -261,12 +518,22 @@ def resolve_parameters(string, paramsname='params'):
     is typically used to format URL paths containing required parameters for an
     API call.
     """
-    pat = re.compile('\{\+?(\w+)\}')
+    pat = re.compile('\{(\+?)(\w+)\}')
+    # `{+param}` is a reserved expansion, which may contain slashes.
     params = re.findall(pat, string)
-    snakeparams = [rust_identifier(p) for p in params]
+    snakeparams = [replace_keywords(rust_identifier(p)) for (_, p) in params]
+    names = []
+    for ((plus, p), sp) in zip(params, snakeparams):
+        # Keywords can't name format arguments either.
+        name = p
+        if sp != rust_identifier(p):
+            name = rust_identifier(p) + '_'
+            string = string.replace('{' + p + '}', '{' + name + '}').replace('{+' + p + '}', '{+' + name + '}')
+        names.append(name)
     format_params = ','.join([
-        '{}=percent_encode({}.{}.as_bytes(), NON_ALPHANUMERIC)'.format(p, paramsname, sp)
-        for (p, sp) in zip(params, snakeparams)
+        '{}=percent_encode({}.{}.as_bytes(), {})'.format(name, paramsname, sp,
+                                                         'RESERVED_PATH' if plus else 'PATH_SEGMENT')
+        for ((plus, p), sp, name) in zip(params, snakeparams, names)
     ])
     string = string.replace('{+', '{')
     # Some required parameters are in the URL. This rust syntax formats the relative URL part appropriately.
@@ -289,6 +556,8 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
         for subresname, subresource in methods.get('resources', {}).items():
             subresource_fragments.append(generate_service(service + capitalize_first(subresname), subresource, discdoc))
 
+    names_of_scopes = scope_names(discdoc.get('auth', {}).get('oauth2', {}).get('scopes', {}))
+
     for methodname, method in methods.get('methods', {}).items():
         # Goal: Instantiate the templates for upload and non-upload methods.
 
@@ -333,13 +602,19 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
         formatted_simple_upload_path, required_params = resolve_parameters(simple_upload_path)
         formatted_resumable_upload_path, required_params = resolve_parameters(resumable_upload_path)
 
-        scopetype, scopeval = scopes_url_to_enum_val(discdoc['name'], method.get('scopes', [''])[-1])
-        scope_enum = scopetype + '::' + scopeval
+        last_scope = method.get('scopes', [''])[-1]
+        # Scopes not declared in the document have no constant.
+        if last_scope in names_of_scopes:
+            scope_const = 'scopes::' + names_of_scopes[last_scope][0]
+        else:
+            scope_const = json.dumps(last_scope)
 
         if is_download:
             data_download = {
                 'name':
                 rust_identifier(methodname),
+                'service':
+                service,
                 'param_type':
                 params_type_name,
                 'in_type':
@@ -365,7 +640,7 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
                 'global_params_name':
                 rust_identifier(global_params_name(discdoc.get('name', ''))) if has_global_params else None,
                 'scopes': [{
-                    'scope': scope_enum,
+                    'scope': scope_const,
                 }],
                 'description':
                 method.get('description', ''),
@@ -379,6 +654,8 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
             data_normal = {
                 'name':
                 rust_identifier(methodname),
+                'service':
+                service,
                 'param_type':
                 params_type_name,
                 'in_type':
@@ -402,7 +679,7 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
                     'snake_param': sp
                 } for (p, sp) in required_parameters.items()],
                 'scopes': [{
-                    'scope': scope_enum,
+                    'scope': scope_const,
                 }],
                 'description':
                 method.get('description', ''),
@@ -416,6 +693,7 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
         # We generate an additional implementation with the option of uploading data.
         data_upload = {
             'name': rust_identifier(methodname),
+            'service': service,
             'param_type': params_type_name,
             'in_type': in_type,
             'out_type': out_type,
@@ -434,7 +712,7 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
                 'snake_param': sp
             } for (p, sp) in required_parameters.items()],
             'scopes': [{
-                'scope': scope_enum,
+                'scope': scope_const,
             }],
             'description': method.get('description', ''),
             'http_method': http_method,
@@ -457,10 +735,185 @@ def generate_service(resource, methods, discdoc, generate_subresources=True):
             } for t in method_fragments]
         }) + '\n'.join(subresource_fragments)
 
-def scopes_url_to_enum_val(apiname, url):
-    rawname = url.split('/')[-1]
-    fancy_name = snake_to_camel(rawname.replace('-', '_').replace('.', '_'))
-    return (snake_to_camel(apiname)+'Scopes', fancy_name)
+def scope_raw_name(url):
+    """The part of the scope `url` its names are derived from: the last path segment, or the host
+    name if the path is empty (e.g. https://mail.google.com/)."""
+    segments = [s for s in url.split('://', 1)[-1].split('/') if s]
+    name = segments[-1] if segments else ''
+    return name.replace('-', '_').replace('.', '_')
+
+def scope_names(scopes):
+    """Names of the constant in the `scopes` module (e.g. DRIVE_READONLY) and of the variant of the
+    scopes enum (e.g. DriveReadonly) for each scope in `scopes`, by URL. Scopes whose names would
+    collide with an earlier one get a numeric suffix."""
+    names = {}
+    taken = set()
+    for url in scopes:
+        raw = scope_raw_name(url)
+        const_name, variant_name = rust_identifier(raw).upper(), snake_to_camel(raw)
+        name, n = (const_name, variant_name), 1
+        while name[0] in taken:
+            n += 1
+            name = ('{}_{}'.format(const_name, n), '{}{}'.format(variant_name, n))
+        taken.add(name[0])
+        names[url] = name
+    return names
+
+# How many `$ref`s deep sample values are expanded; deeper references are left out.
+SAMPLE_MAX_REFS = 3
+
+
+def sample_value(schema, schemas, refs):
+    """A JSON value of `schema` with all properties set, for round-trip tests. `refs` are the
+    schemas being expanded; recursive and too deeply nested references are left out (None)."""
+    if '$ref' in schema:
+        ref = schema['$ref']
+        if len(refs) >= SAMPLE_MAX_REFS or ref in refs or ref not in schemas:
+            return None
+        return sample_value(schemas[ref], schemas, refs + [ref])
+    typ = schema.get('type', '')
+    if typ == 'object':
+        if 'properties' in schema:
+            sample = {}
+            for pn, pp in schema['properties'].items():
+                v = sample_value(pp, schemas, refs)
+                if v is not None:
+                    sample[pn] = v
+            return sample
+        if 'additionalProperties' not in schema:
+            return None
+        v = sample_value(schema['additionalProperties'], schemas, refs)
+        return {} if v is None else {'key': v}
+    if typ == 'array':
+        if 'items' not in schema:
+            return None
+        v = sample_value(schema['items'], schemas, refs)
+        return [] if v is None else [v]
+    if typ == 'string':
+        if schema.get('enum'):
+            return schema['enum'][0]
+        fmt = schema.get('format', '')
+        if fmt in ('int64', 'int32', 'uint64', 'uint32'):
+            return '123'
+        if fmt in ('double', 'float'):
+            return '1.5'
+        if fmt == 'date-time':
+            return '2020-06-01T12:30:45Z'
+        return 'sample'
+    if typ == 'boolean':
+        return True
+    if typ in ('number', 'integer'):
+        return 1.5 if schema.get('format', '') in ('float', 'double') else 1
+    if typ == 'any':
+        return 'sample'
+    return None
+
+
+def generate_round_trip_tests(schemas, features):
+    """Generate round-trip tests for the struct types generated from `schemas`. Tests of schemas
+    in `features` are only compiled with one of the features enabled."""
+    tests = []
+    for name, schema in schemas.items():
+        if schema.get('type', '') != 'object' or 'properties' not in schema:
+            continue
+        sample = sample_value(schema, schemas, [name])
+        if sample is None:
+            continue
+        tests.append({
+            'name': replace_keywords(name),
+            'fn_name': 'round_trip_' + rust_identifier(name),
+            'json': json.dumps(sample, separators=(',', ':'), ensure_ascii=False),
+            'cfg': feature_cfg(features.get(name, []))
+        })
+    if not tests:
+        return ''
+    return chevron.render(RoundTripTestsTmpl, {'tests': tests})
+
+
+def resource_feature(api_name, resource):
+    """The Cargo feature enabling the services of `resource`, e.g. `drive-files`."""
+    kebab = lambda name: rust_identifier(name).replace('_', '-')
+    return kebab(api_name) + '-' + kebab(resource)
+
+
+def feature_cfg(features):
+    """The predicate of a `#[cfg]` attribute requiring one of `features`; None if `features` is
+    empty, i.e. the code is always compiled."""
+    features = ['feature = "{}"'.format(f) for f in sorted(features)]
+    if not features:
+        return None
+    if len(features) == 1:
+        return features[0]
+    return 'any({})'.format(', '.join(features))
+
+
+def feature_gate(cfg, module, code):
+    """Wrap `code` into a module `module` that is only compiled if `cfg` holds."""
+    if cfg is None:
+        return code
+    return chevron.render(FeatureGateTmpl, {'cfg': cfg, 'module': module, 'code': code})
+
+
+def collect_refs(value, refs):
+    """Add all schemas referenced by `value` to the set `refs`."""
+    if isinstance(value, dict):
+        if isinstance(value.get('$ref'), str):
+            refs.add(value['$ref'])
+        for v in value.values():
+            collect_refs(v, refs)
+    elif isinstance(value, list):
+        for v in value:
+            collect_refs(v, refs)
+
+
+def resource_schemas(resource, schemas):
+    """The schemas used by the methods of `resource` and its subresources, including the schemas
+    those reference."""
+    used = set()
+    collect_refs(resource, used)
+    pending = list(used)
+    while pending:
+        refs = set()
+        collect_refs(schemas.get(pending.pop(), {}), refs)
+        for r in refs - used:
+            used.add(r)
+            pending.append(r)
+    return used
+
+
+def schema_features(discdoc):
+    """The features of the resources using each schema. Schemas that are not used only by
+    resources (e.g. by global methods, or by no method) are missing; they are always compiled."""
+    schemas = discdoc.get('schemas', {})
+    features = {}
+    for resource, methods in discdoc.get('resources', {}).items():
+        for schema in resource_schemas(methods, schemas):
+            features.setdefault(schema, set()).add(resource_feature(discdoc['name'], resource))
+    # Schemas used otherwise, and all schemas they reference.
+    always = [discdoc.get('methods')] + [{'$ref': n} for n in schemas if n not in features]
+    for schema in resource_schemas(always, schemas):
+        features.pop(schema, None)
+    return features
+
+
+def render_structs(structs, non_eq):
+    """Render schema structs and enums. Structs in `non_eq` don't derive `Eq`."""
+    code = ''
+    for s in structs:
+        if 'values' in s:
+            code += chevron.render(EnumTmpl, s)
+            continue
+        for field in s['fields']:
+            if field.get('comment', None):
+                field['comment'] = field.get('comment', '').replace('\n', ' ')
+        if not s['name']:
+            print('WARN', s)
+        s['eq'] = s['name'] not in non_eq
+        code += chevron.render(SchemaStructTmpl, s)
+        if s.get('redacted'):
+            code += chevron.render(RedactedDebugTmpl, s)
+    return code
+
 
 def generate_scopes_type(name, scopes):
     """Generate types for the `scopes` dictionary (path: auth.oauth2.scopes in a discovery document),
@@ -468,69 +921,163 @@ def generate_scopes_type(name, scopes):
     """
     if len(scopes) == 0:
         return ''
-    parameters = {'scopes': []}
+    parameters = {'name': snake_to_camel(name) + 'Scopes', 'scopes': []}
+    names = scope_names(scopes)
     for url, desc in scopes.items():
-        enum_type_name, fancy_name = scopes_url_to_enum_val(name, url)
-        parameters['name'] = enum_type_name
-        parameters['scopes'].append({'scope_name': fancy_name, 'desc': desc.get('description', ''), 'url': url})
+        const_name, variant_name = names[url]
+        parameters['scopes'].append({
+            'scope_name': variant_name,
+            'const_name': const_name,
+            'desc': desc.get('description', ''),
+            'url': url
+        })
     return chevron.render(OauthScopesType, parameters)
 
+def service_names(resource, methods):
+    """Names of the services generated for `resource` and its subresources by generate_service(),
+    in the same order, e.g. Projects, ProjectsHmacKeys."""
+    service = capitalize_first(resource)
+    names = [service]
+    for subresname, subresource in methods.get('resources', {}).items():
+        names.extend(service_names(service + capitalize_first(subresname), subresource))
+    return names
+
+def generate_hub(discdoc, services):
+    """Generate the hub giving access to all services of the API.
+
+    `services` are the names of the services, with the `#[cfg]` predicate of their accessor."""
+    if not services:
+        return ''
+    wants_auth = 'auth' in discdoc
+    return chevron.render(
+        HubTmpl, {
+            'name': snake_to_camel(discdoc['name']),
+            'first_accessor': rust_identifier(services[0][0]),
+            'services': [{
+                'service': s,
+                'accessor': rust_identifier(s),
+                'wants_auth': wants_auth,
+                'cfg': cfg
+            } for s, cfg in services]
+        })
+
 
 def generate_all(discdoc):
     """Generate all structs and impls, and render them into a file."""
     print('Processing:', discdoc.get('id', ''))
+    renamed.clear()
+    ids_used.clear()
+    schema_names.clear()
+    schema_names.update(replace_keywords(n) for n in discdoc.get('schemas', {}))
     schemas = discdoc.get('schemas', {})
     resources = discdoc.get('resources', {})
     # Generate scopes.
     scopes_type = generate_scopes_type(discdoc['name'], discdoc.get('auth', {}).get('oauth2', {}).get('scopes', {}))
 
+    # With resource features, the code of each resource is only compiled with its feature.
+    def feature_of(resource):
+        if RESOURCE_FEATURES:
+            return feature_cfg([resource_feature(discdoc['name'], resource)])
+        return None
+
+    features = schema_features(discdoc) if RESOURCE_FEATURES else {}
+
     # Generate parameter types (*Params - those are used as "side inputs" to requests)
     params_struct_name = global_params_name(discdoc.get('name'))
-    parameter_types = generate_params_structs(resources, global_params=params_struct_name)
+    parameter_types = []
+    for resource, methods in resources.items():
+        code = ''.join(generate_params_structs({resource: methods}, global_params=params_struct_name))
+        parameter_types.append(feature_gate(feature_of(resource), rust_identifier(resource) + '_params', code))
 
     # Generate service impls.
     services = []
+    names = []
     for resource, methods in resources.items():
-        services.append(generate_service(resource, methods, discdoc))
+        code = generate_service(resource, methods, discdoc)
+        services.append((resource, feature_gate(feature_of(resource), rust_identifier(resource) + '_service', code)))
+        names.extend((n, feature_of(resource)) for n in service_names(resource, methods))
     if 'methods' in discdoc:
-        services.append(generate_service('Global', discdoc, discdoc, generate_subresources=False))
+        services.append(('Global', generate_service('Global', discdoc, discdoc, generate_subresources=False)))
+        names.append(('Global', None))
+    hub = generate_hub(discdoc, names)
 
-    # Generate schema types.
-    structs = []
+    # Generate schema types, each with the structs of its nested objects.
+    schema_structs = []
     for name, desc in schemas.items():
         typ, substructs = parse_schema_types(name, desc)
-        structs.extend(substructs)
+        schema_structs.append((name, substructs))
 
-    # Generate global parameters struct and its Display impl.
+    # Generate global parameters struct and its query serialization.
+    global_structs = []
     if 'parameters' in discdoc:
         schema = {'type': 'object', 'properties': discdoc['parameters']}
         name = replace_keywords(snake_to_camel(params_struct_name))
         typ, substructs = parse_schema_types(name, schema)
         for s in substructs:
-            s['optional_fields'] = s['fields']
-            parameter_types.append(chevron.render(SchemaDisplayTmpl, s))
-        structs.extend(substructs)
+            if 'fields' in s:
+                for field in s['fields']:
+                    field['sensitive'] = field.get('original_name') in CREDENTIAL_PARAMS
+                s['redacted'] = any(f['sensitive'] for f in s['fields'])
+                s['optional_fields'] = s['fields']
+                parameter_types.append(chevron.render(ParamsQueryTmpl, s))
+                parameter_types.append(render_builder(s))
+        global_structs.extend(substructs)
+
+    # Assemble everything.
+    header = chevron.render(
+        RustHeader, {
+            'has_renames': bool(renamed),
+            'renames': [{
+                'name': k,
+                'rust': v
+            } for k, v in sorted(renamed.items())],
+            'has_features': RESOURCE_FEATURES and bool(resources),
+            'features': [{
+                'name': resource_feature(discdoc['name'], r)
+            } for r in resources] if RESOURCE_FEATURES else []
+        })
+    types = scopes_type
+    # Render ID types.
+    for idtyp in sorted(ids_used):
+        types += chevron.render(IdTmpl, {'name': idtyp})
+    # Render resource structs.
+    non_eq = non_eq_structs([s for _, structs in schema_structs for s in structs] + global_structs)
+    for name, structs in schema_structs:
+        cfg = feature_cfg(features.get(name, []))
+        types += feature_gate(cfg, rust_identifier(name) + '_schema', render_structs(structs, non_eq))
+    types += render_structs(global_structs, non_eq)
+    types += generate_round_trip_tests(schemas, features)
+    # Render *Params structs.
+    params = ''.join(parameter_types)
 
-    # Assemble everything into a file.
     modname = (discdoc['id'] + '_types').replace(':', '_')
-    out_path = path.join('gen', modname + '.rs')
-    with open(out_path, 'w') as f:
-        f.write(RustHeader)
-        f.write(scopes_type)
-        # Render resource structs.
-        for s in structs:
-            for field in s['fields']:
-                if field.get('comment', None):
-                    field['comment'] = field.get('comment', '').replace('\n', ' ')
-            if not s['name']:
-                print('WARN', s)
-            f.write(chevron.render(SchemaStructTmpl, s))
-        # Render *Params structs.
-        for pt in parameter_types:
-            f.write(pt)
-        # Render service impls.
-        for s in services:
-            f.write(s)
+    if SPLIT:
+        # A module tree: mod.rs, types.rs, params.rs, one file per top-level resource, and hub.rs.
+        out_dir = path.join('gen', modname)
+        os.makedirs(out_dir, exist_ok=True)
+        files = [('types', types), ('params', params)]
+        files.extend((rust_identifier(resource) + '_service', s) for resource, s in services)
+        if hub:
+            files.append(('hub', hub))
+        with open(path.join(out_dir, 'mod.rs'), 'w') as f:
+            f.write(header)
+            f.write(chevron.render(ModuleTreeTmpl, {'modules': [{'name': m} for m, _ in files]}))
+        for m, code in files:
+            with open(path.join(out_dir, m + '.rs'), 'w') as f:
+                f.write(SubmoduleHeader)
+                f.write(code)
+        out_path = path.join(out_dir, 'mod.rs')
+    else:
+        out_path = path.join('gen', modname + '.rs')
+        with open(out_path, 'w') as f:
+            f.write(header)
+            f.write(types)
+            f.write(params)
+            # Render service impls.
+            for _, s in services:
+                f.write(s)
+            f.write(hub)
+    # rustfmt also formats the files of a module tree, following the `mod` declarations.
     try:
         subprocess.run(['rustfmt', out_path, '--edition=2018'])
     except:
@@ -595,9 +1142,64 @@ def main():
     p.add_argument('--only_apis', default='drive:v3', help='Only process APIs with these IDs (comma-separated)')
     p.add_argument('--doc', default='', help='Directly process Discovery document from this URL')
     p.add_argument('--list', default=False, help='List available APIs', action='store_true')
+    p.add_argument('--rename',
+                   default=[],
+                   action='append',
+                   metavar='NAME=IDENT',
+                   help='Use IDENT for API names NAME that collide with Rust keywords (repeatable)')
+    p.add_argument('--typed_enums',
+                   default=False,
+                   action='store_true',
+                   help='Generate enums for string fields and parameters with enumerated values')
+    p.add_argument('--typed_ids',
+                   default=False,
+                   action='store_true',
+                   help='Generate newtypes like FileId for string IDs instead of using String')
+    p.add_argument('--page_size',
+                   default=[],
+                   action='append',
+                   metavar='METHOD=N',
+                   help='Default pageSize for the method with ID METHOD, e.g. drive.files.list=1000 (repeatable)')
+    p.add_argument('--param_type',
+                   default=[],
+                   action='append',
+                   metavar='METHOD.PARAM=TYPE',
+                   help='Use the Rust type TYPE for a parameter, e.g. drive.files.list.orderBy=crate::listing::OrderBy (repeatable)')
+    p.add_argument('--resource_features',
+                   default=False,
+                   action='store_true',
+                   help='Compile the code of each resource only with a Cargo feature like drive-files')
+    p.add_argument('--split',
+                   default=False,
+                   action='store_true',
+                   help='Generate a module tree (types, parameters, one file per service) instead of a single file')
 
     args = p.parse_args()
 
+    for r in args.rename:
+        name, _, ident = r.partition('=')
+        if not name or not ident:
+            p.error('--rename expects NAME=IDENT, got {}'.format(r))
+        RENAMES[name] = ident
+    global TYPED_ENUMS
+    TYPED_ENUMS = args.typed_enums
+    global TYPED_IDS
+    TYPED_IDS = args.typed_ids
+    global SPLIT
+    SPLIT = args.split
+    global RESOURCE_FEATURES
+    RESOURCE_FEATURES = args.resource_features
+    for ps in args.page_size:
+        method, _, size = ps.partition('=')
+        if not method or not size.isdigit():
+            p.error('--page_size expects METHOD=N, got {}'.format(ps))
+        PAGE_SIZES[method] = int(size)
+    for pt in args.param_type:
+        param, _, typ = pt.partition('=')
+        if '.' not in param or not typ:
+            p.error('--param_type expects METHOD.PARAM=TYPE, got {}'.format(pt))
+        PARAM_TYPES[param] = typ
+
     if args.only_apis:
         apilist = args.only_apis.split(',')
     else:
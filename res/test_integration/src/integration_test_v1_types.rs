@@ -15,8 +15,18 @@
 
 use async_google_apis_common::*;
 
-/// Scopes of this API. Convertible to their string representation with `AsRef`.
-#[derive(Debug, Clone, Copy)]
+/// The URLs of the scopes of this API, e.g. for `set_scopes(&[scopes::...])`.
+pub mod scopes {
+    /// See, edit, create, and delete all of your Google integrationtest files
+    pub const INTEGRATIONTEST: &str = "https://example.borgac.net/auth/integrationtest";
+    /// View and manage metadata of files in your Google integrationtest
+    pub const INTEGRATIONTEST_METADATA: &str =
+        "https://example.borgac.net/auth/integrationtest.metadata";
+}
+
+/// Scopes of this API. Convertible to their string representation with `as_str()` or `AsRef`.
+/// Select them for a service with `set_scopes(&[...])`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntegrationTestScopes {
     /// See, edit, create, and delete all of your Google integrationtest files
     ///
@@ -28,21 +38,27 @@ pub enum IntegrationTestScopes {
     IntegrationtestMetadata,
 }
 
-impl std::convert::AsRef<str> for IntegrationTestScopes {
-    fn as_ref(&self) -> &'static str {
+/// The scopes of this API, under a name shared by all generated APIs.
+pub type Scope = IntegrationTestScopes;
+
+impl IntegrationTestScopes {
+    /// The URL identifying this scope.
+    pub fn as_str(&self) -> &'static str {
         match self {
-            IntegrationTestScopes::Integrationtest => {
-                "https://example.borgac.net/auth/integrationtest"
-            }
-            IntegrationTestScopes::IntegrationtestMetadata => {
-                "https://example.borgac.net/auth/integrationtest.metadata"
-            }
+            IntegrationTestScopes::Integrationtest => scopes::INTEGRATIONTEST,
+            IntegrationTestScopes::IntegrationtestMetadata => scopes::INTEGRATIONTEST_METADATA,
         }
     }
 }
 
+impl std::convert::AsRef<str> for IntegrationTestScopes {
+    fn as_ref(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
 /// Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileCapabilities {
     /// Whether the current user can add children to this folder. This is always false when the item is not a folder.
     #[serde(rename = "canAddChildren")]
@@ -55,7 +71,7 @@ pub struct FileCapabilities {
 }
 
 /// The metadata for a file.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct File {
     /// A collection of arbitrary key-value pairs which are private to the requesting app. Entries with null values are cleared in update and copy requests.
     #[serde(rename = "appProperties")]
@@ -68,7 +84,7 @@ pub struct File {
 }
 
 /// A list of files.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FileList {
     /// The list of files. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
     #[serde(rename = "files")]
@@ -89,7 +105,7 @@ pub struct FileList {
 }
 
 ///
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct IntegrationTestParams {
     /// Data format for the response.
     #[serde(rename = "alt")]
@@ -121,8 +137,50 @@ pub struct IntegrationTestParams {
     pub user_ip: Option<String>,
 }
 
+impl std::fmt::Debug for IntegrationTestParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntegrationTestParams")
+            .field("alt", &self.alt)
+            .field("fields", &self.fields)
+            .field("key", &self.key.as_ref().map(|_| "redacted"))
+            .field(
+                "oauth_token",
+                &self.oauth_token.as_ref().map(|_| "redacted"),
+            )
+            .field("pretty_print", &self.pretty_print)
+            .field("quota_user", &self.quota_user)
+            .field("user_ip", &self.user_ip)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod round_trip_tests {
+    use super::*;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(json: &str) {
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+    }
+
+    #[test]
+    fn round_trip_file() {
+        round_trip::<File>(
+            r##"{"appProperties":{"key":"sample"},"capabilities":{"canAddChildren":true,"canComment":true}}"##,
+        );
+    }
+
+    #[test]
+    fn round_trip_file_list() {
+        round_trip::<FileList>(
+            r##"{"files":[{"appProperties":{"key":"sample"},"capabilities":{"canAddChildren":true,"canComment":true}}],"incompleteSearch":true,"kind":"sample","nextPageToken":"sample"}"##,
+        );
+    }
+}
+
 /// Parameters for the `files.copy` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesCopyParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -132,14 +190,30 @@ pub struct FilesCopyParams {
     pub file_id: String,
 }
 
-impl std::fmt::Display for FilesCopyParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl FilesCopyParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+    }
+}
+
+impl FilesCopyParams {
+    /// Builder-style setter for `integration_test_params`.
+    pub fn integration_test_params<T: Into<IntegrationTestParams>>(mut self, value: T) -> Self {
+        self.integration_test_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
     }
 }
 
 /// Parameters for the `files.create` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesCreateParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -149,21 +223,33 @@ pub struct FilesCreateParams {
     pub use_content_as_indexable_text: Option<bool>,
 }
 
-impl std::fmt::Display for FilesCreateParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesCreateParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.use_content_as_indexable_text {
-            write!(
-                f,
-                "&useContentAsIndexableText={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("useContentAsIndexableText", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesCreateParams {
+    /// Builder-style setter for `integration_test_params`.
+    pub fn integration_test_params<T: Into<IntegrationTestParams>>(mut self, value: T) -> Self {
+        self.integration_test_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `use_content_as_indexable_text`.
+    pub fn use_content_as_indexable_text<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_content_as_indexable_text = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `files.delete` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesDeleteParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -182,35 +268,54 @@ pub struct FilesDeleteParams {
     pub supports_team_drives: Option<bool>,
 }
 
-impl std::fmt::Display for FilesDeleteParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesDeleteParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
         if let Some(ref v) = self.supports_all_drives {
-            write!(
-                f,
-                "&supportsAllDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsAllDrives", &v.to_string());
         }
         if let Some(ref v) = self.supports_team_drives {
-            write!(
-                f,
-                "&supportsTeamDrives={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("supportsTeamDrives", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesDeleteParams {
+    /// Builder-style setter for `integration_test_params`.
+    pub fn integration_test_params<T: Into<IntegrationTestParams>>(mut self, value: T) -> Self {
+        self.integration_test_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `supports_all_drives`.
+    pub fn supports_all_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_all_drives = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `supports_team_drives`.
+    pub fn supports_team_drives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.supports_team_drives = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `files.emptyTrash` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesEmptyTrashParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -220,21 +325,33 @@ pub struct FilesEmptyTrashParams {
     pub enforce_single_parent: Option<bool>,
 }
 
-impl std::fmt::Display for FilesEmptyTrashParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesEmptyTrashParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.enforce_single_parent {
-            write!(
-                f,
-                "&enforceSingleParent={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("enforceSingleParent", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl FilesEmptyTrashParams {
+    /// Builder-style setter for `integration_test_params`.
+    pub fn integration_test_params<T: Into<IntegrationTestParams>>(mut self, value: T) -> Self {
+        self.integration_test_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `enforce_single_parent`.
+    pub fn enforce_single_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_single_parent = Some(value.into());
+        self
     }
 }
 
 /// Parameters for the `files.export` method.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct FilesExportParams {
     /// General attributes applying to any API call
     #[serde(flatten)]
@@ -247,96 +364,134 @@ pub struct FilesExportParams {
     pub mime_type: String,
 }
 
-impl std::fmt::Display for FilesExportParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "&mimeType={}",
-            percent_encode(format!("{}", self.mime_type).as_bytes(), NON_ALPHANUMERIC).to_string()
-        )?;
-        Ok(())
+impl FilesExportParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
+        query.append_pair("mimeType", &self.mime_type.to_string());
     }
 }
 
-impl std::fmt::Display for IntegrationTestParams {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl FilesExportParams {
+    /// Builder-style setter for `integration_test_params`.
+    pub fn integration_test_params<T: Into<IntegrationTestParams>>(mut self, value: T) -> Self {
+        self.integration_test_params = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `file_id`.
+    pub fn file_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_id = value.into();
+        self
+    }
+    /// Builder-style setter for `mime_type`.
+    pub fn mime_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.mime_type = value.into();
+        self
+    }
+}
+
+impl IntegrationTestParams {
+    /// Append the parameters that are set to `query`, e.g. `url.query_pairs_mut()`.
+    pub fn append_to<T: form_urlencoded::Target>(
+        &self,
+        query: &mut form_urlencoded::Serializer<'_, T>,
+    ) {
         if let Some(ref v) = self.alt {
-            write!(
-                f,
-                "&alt={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("alt", &v.to_string());
         }
         if let Some(ref v) = self.fields {
-            write!(
-                f,
-                "&fields={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("fields", &v.to_string());
         }
         if let Some(ref v) = self.key {
-            write!(
-                f,
-                "&key={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("key", &v.to_string());
         }
         if let Some(ref v) = self.oauth_token {
-            write!(
-                f,
-                "&oauth_token={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("oauth_token", &v.to_string());
         }
         if let Some(ref v) = self.pretty_print {
-            write!(
-                f,
-                "&prettyPrint={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("prettyPrint", &v.to_string());
         }
         if let Some(ref v) = self.quota_user {
-            write!(
-                f,
-                "&quotaUser={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("quotaUser", &v.to_string());
         }
         if let Some(ref v) = self.user_ip {
-            write!(
-                f,
-                "&userIp={}",
-                percent_encode(format!("{}", v).as_bytes(), NON_ALPHANUMERIC).to_string()
-            )?;
+            query.append_pair("userIp", &v.to_string());
         }
-        Ok(())
+    }
+}
+
+impl IntegrationTestParams {
+    /// Builder-style setter for `alt`.
+    pub fn alt<T: Into<String>>(mut self, value: T) -> Self {
+        self.alt = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `fields`.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `key`.
+    pub fn key<T: Into<String>>(mut self, value: T) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `oauth_token`.
+    pub fn oauth_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.oauth_token = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `pretty_print`.
+    pub fn pretty_print<T: Into<bool>>(mut self, value: T) -> Self {
+        self.pretty_print = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `quota_user`.
+    pub fn quota_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.quota_user = Some(value.into());
+        self
+    }
+    /// Builder-style setter for `user_ip`.
+    pub fn user_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_ip = Some(value.into());
+        self
     }
 }
 
 /// The Integration_test Files service represents the Files resource.
-pub struct FilesService {
-    client: TlsClient,
-    authenticator: Box<dyn 'static + std::ops::Deref<Target = Authenticator>>,
+///
+/// Services are cheap to clone, and can be used concurrently from several tasks.
+#[derive(Clone)]
+pub struct FilesService<C = TlsClient> {
+    client: C,
+    authenticator: Arc<dyn 'static + TokenSource + Send + Sync>,
     scopes: Vec<String>,
 
     base_url: String,
     root_url: String,
+    extra_headers: Vec<(hyper::header::HeaderName, String)>,
+    default_fields: Option<String>,
+    standard_params: StandardParams,
 }
 
-impl FilesService {
+impl<C: HttpClient> FilesService<C> {
     /// Create a new FilesService object. The easiest way to call this is wrapping the Authenticator
-    /// into an `Rc`: `new(client.clone(), Rc::new(authenticator))`.
-    /// This way, one authenticator can be shared among several services.
-    pub fn new<A: 'static + std::ops::Deref<Target = Authenticator>>(
-        client: TlsClient,
-        auth: A,
-    ) -> FilesService {
+    /// into an `Arc`: `new(client.clone(), Arc::new(authenticator))`.
+    /// This way, one authenticator can be shared among several services. Any other `TokenSource`
+    /// can be used instead of an Authenticator. Instead of a `TlsClient`, any `HttpClient` can be
+    /// used, e.g. a `hyper::Client` with another `Connector`, or a `TowerClient`.
+    pub fn new<A: 'static + TokenSource + Send + Sync>(client: C, auth: A) -> FilesService<C> {
         FilesService {
             client: client,
-            authenticator: Box::new(auth),
+            authenticator: Arc::new(auth),
             scopes: vec![],
             base_url: "https://example.borgac.net/integrationAPI".into(),
             root_url: "https://example.borgac.net/".into(),
+            extra_headers: vec![],
+            default_fields: None,
+            standard_params: Default::default(),
         }
     }
 
@@ -363,18 +518,39 @@ impl FilesService {
         }
     }
 
-    #[cfg(test)]
-    /// Override API URLs. `base` is the base path relative to which (relative) method paths are interpreted,
+    /// Override API URLs, e.g. to use a test server, an emulator, or a private access endpoint.
+    /// `base` is the base path relative to which (relative) method paths are interpreted,
     /// whereas `root` is the URL relative to which absolute paths are interpreted.
     pub fn set_urls(&mut self, base: String, root: String) {
         self.base_url = base;
         self.root_url = root;
     }
 
+    /// Send an additional header with every request issued by this service. A value set earlier
+    /// for the same header is replaced.
+    pub fn set_extra_header(&mut self, name: hyper::header::HeaderName, value: String) {
+        self.extra_headers.retain(|(n, _)| n != &name);
+        self.extra_headers.push((name, value));
+    }
+
+    /// Request only the given fields (e.g. `"id,name"`) in all responses, unless a call
+    /// specifies its own `fields` parameter. By default, the API decides which fields to return.
+    pub fn set_default_fields(&mut self, fields: Option<&str>) {
+        self.default_fields = fields.map(|f| f.to_string());
+    }
+
+    /// Set standard parameters (API key, quota user, ...) for all requests issued by this
+    /// service. Parameters set in a call's parameters take precedence; `fields` set here takes
+    /// precedence over `set_default_fields()`.
+    pub fn set_standard_params(&mut self, params: StandardParams) {
+        self.standard_params = params;
+    }
+
     /// Explicitly select which scopes should be requested for authorization. Otherwise,
     /// a possibly too large scope will be requested.
     ///
-    /// It is most convenient to supply a vec or slice of Integration_testScopes enum values.
+    /// It is most convenient to supply a vec or slice of Integration_testScopes (also known as `Scope`)
+    /// enum values.
     pub fn set_scopes<S: AsRef<str>, T: AsRef<[S]>>(&mut self, scopes: T) {
         self.scopes = scopes
             .as_ref()
@@ -383,106 +559,245 @@ impl FilesService {
             .collect();
     }
 
-    /// Creates a copy of a file and applies any requested updates with patch semantics. Folders cannot be copied.
-    pub async fn copy(&mut self, params: &FilesCopyParams, req: &File) -> Result<File> {
-        let rel_path = format!(
-            "files/{fileId}/copy",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
-        println!("{}", path);
+    /// Send a request to `rel_path` (interpreted like the paths of methods) with this service's
+    /// credentials, headers and default fields, and return the response body without reading it,
+    /// e.g. to decode large responses incrementally. `query` is the encoded query string without
+    /// `?`, e.g. built with a `form_urlencoded::Serializer` and the `append_to()` method of
+    /// parameter types. `scopes` are requested unless `set_scopes()` was called.
+    pub async fn request_raw<Req: Serialize + std::fmt::Debug>(
+        &self,
+        http_method: &str,
+        rel_path: &str,
+        query: &str,
+        scopes: &[&str],
+        req: Option<&Req>,
+    ) -> Result<hyper::Body> {
+        let path = self.format_path(rel_path);
 
-        let mut headers = vec![];
+        let mut headers = self.extra_headers.clone();
         let tok;
         if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive.photos.readonly".to_string()];
             tok = self.authenticator.token(scopes).await?;
         } else {
-            tok = self.authenticator.token(&self.scopes).await?;
+            let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+            tok = self.authenticator.token(&scopes).await?;
         }
         headers.push((
             hyper::header::AUTHORIZATION,
             format!("Bearer {token}", token = tok.as_str()),
         ));
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
+        let mut url = Url::parse(&path)?;
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+        self.standard_params.append_to(&mut url);
+        if let Some(ref fields) = self.default_fields {
+            if !url.query_pairs().any(|(name, _)| name == "fields") {
+                url.query_pairs_mut().append_pair("fields", fields);
+            }
         }
 
-        let full_uri = path + &url_params;
+        do_request_body(&self.client, url.as_str(), &headers, http_method, req).await
+    }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+    /// Creates a copy of a file and applies any requested updates with patch semantics. Folders cannot be copied.
+    pub async fn copy(&self, params: &FilesCopyParams, req: &File) -> Result<File> {
+        Ok(self.copy_with_response(params, req).await?.body)
+    }
+
+    /// Like `copy()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn copy_with_response(
+        &self,
+        params: &FilesCopyParams,
+        req: &File,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "copy", async move {
+            let rel_path = format!(
+                "files/{fileId}/copy",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
+            println!("{}", path);
+
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] =
+                    &["https://www.googleapis.com/auth/drive.photos.readonly"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Creates a new file.
-    pub async fn create(&mut self, params: &FilesCreateParams, req: &File) -> Result<File> {
-        let rel_path = format!("files",);
-        let path = self.format_path(rel_path.as_str());
+    pub async fn create(&self, params: &FilesCreateParams, req: &File) -> Result<File> {
+        Ok(self.create_with_response(params, req).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive.file".to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `create()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn create_with_response(
+        &self,
+        params: &FilesCreateParams,
+        req: &File,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "create", async move {
+            let rel_path = format!("files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] =
+                    &["https://www.googleapis.com/auth/drive.file"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
 
-        let full_uri = path + &url_params;
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        do_request(&self.client, &full_uri, &headers, "POST", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            do_request_with_response(&self.client, url.as_str(), &headers, "POST", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Creates a new file.
     ///
     /// This method is a variant of `create()`, taking data for upload. It performs a multipart upload.
     pub async fn create_upload(
-        &mut self,
+        &self,
         params: &FilesCreateParams,
         req: &File,
         data: hyper::body::Bytes,
     ) -> Result<File> {
-        let rel_path = format!("/upload/drive/v3/files",);
-        let path = self.format_path(rel_path.as_str());
+        self.create_upload_with_type(params, req, data, None).await
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive.file".to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `create_upload()`, but sends `content_type` as the MIME type of `data`. Without it,
+    /// the server detects the type from the content.
+    pub async fn create_upload_with_type(
+        &self,
+        params: &FilesCreateParams,
+        req: &File,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<File> {
+        Ok(self
+            .create_upload_with_response(params, req, data, content_type)
+            .await?
+            .body)
+    }
 
-        let mut url_params = format!("?uploadType=multipart{params}", params = params);
+    /// Like `create_upload_with_type()`, but also returns the status and headers of the response.
+    pub async fn create_upload_with_response(
+        &self,
+        params: &FilesCreateParams,
+        req: &File,
+        data: hyper::body::Bytes,
+        content_type: Option<&str>,
+    ) -> Result<ApiResponse<File>> {
+        traced("Files", "create_upload", async move {
+            let rel_path = format!("/upload/drive/v3/files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
 
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] =
+                    &["https://www.googleapis.com/auth/drive.file"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
 
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "multipart");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
 
-        do_upload_multipart(&self.client, &full_uri, &headers, "POST", opt_request, data).await
+            do_upload_multipart_with_response(
+                &self.client,
+                url.as_str(),
+                &headers,
+                "POST",
+                opt_request,
+                data,
+                content_type,
+            )
+            .await
+        })
+        .await
     }
 
     /// Creates a new file.
@@ -492,148 +807,286 @@ impl FilesService {
     /// of data to the API. The result of this call will be returned by the `ResumableUpload` method
     /// you choose for the upload.
     pub async fn create_resumable_upload<'client>(
-        &'client mut self,
+        &'client self,
         params: &FilesCreateParams,
         req: &File,
-    ) -> Result<ResumableUpload<'client, File>> {
-        let rel_path = format!("/resumable/upload/drive/v3/files",);
-        let path = self.format_path(rel_path.as_str());
+    ) -> Result<ResumableUpload<'client, File, C>> {
+        self.create_resumable_upload_with_type(params, req, None)
+            .await
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive.file".to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `create_resumable_upload()`, but sends `content_type` as the MIME type of the data
+    /// to be uploaded. Without it, the server detects the type from the content.
+    pub async fn create_resumable_upload_with_type<'client>(
+        &'client self,
+        params: &FilesCreateParams,
+        req: &File,
+        content_type: Option<&str>,
+    ) -> Result<ResumableUpload<'client, File, C>> {
+        traced("Files", "create_resumable_upload", async move {
+            let rel_path = format!("/resumable/upload/drive/v3/files",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
 
-        let mut url_params = format!("?uploadType=resumable{params}", params = params);
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] =
+                    &["https://www.googleapis.com/auth/drive.file"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
 
-        let full_uri = path + &url_params;
+            if let Some(content_type) = content_type {
+                headers.push((
+                    hyper::header::HeaderName::from_static("x-upload-content-type"),
+                    content_type.to_string(),
+                ));
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        let opt_request = Some(req);
-        let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
-            do_request_with_headers(&self.client, &full_uri, &headers, "POST", opt_request).await?;
-        if let Some(dest) = headers.get(hyper::header::LOCATION) {
-            use std::convert::TryFrom;
-            Ok(ResumableUpload::new(
-                hyper::Uri::try_from(dest.to_str()?)?,
-                &self.client,
-                5 * 1024 * 1024,
-            ))
-        } else {
-            Err(Error::from(ApiError::RedirectError(format!(
-                "Resumable upload response didn't contain Location: {:?}",
-                headers
-            )))
-            .context(format!("{:?}", headers)))?
-        }
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("uploadType", "resumable");
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+
+            let opt_request: Option<&EmptyRequest> = None;
+            let opt_request = Some(req);
+            let (_resp, headers): (EmptyResponse, hyper::HeaderMap) =
+                do_request_with_headers(&self.client, url.as_str(), &headers, "POST", opt_request)
+                    .await?;
+            if let Some(dest) = headers.get(hyper::header::LOCATION) {
+                use std::convert::TryFrom;
+                Ok(ResumableUpload::new(
+                    hyper::Uri::try_from(dest.to_str()?)?,
+                    &self.client,
+                    5 * 1024 * 1024,
+                ))
+            } else {
+                Err(Error::from(ApiError::RedirectError(format!(
+                    "Resumable upload response didn't contain Location: {:?}",
+                    headers
+                )))
+                .context(format!("{:?}", headers)))?
+            }
+        })
+        .await
     }
 
     /// Permanently deletes a file owned by the user without moving it to the trash. If the file belongs to a shared drive the user must be an organizer on the parent. If the target is a folder, all descendants owned by the user are also deleted.
-    pub async fn delete(&mut self, params: &FilesDeleteParams) -> Result<()> {
-        let rel_path = format!(
-            "files/{fileId}",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    pub async fn delete(&self, params: &FilesDeleteParams) -> Result<()> {
+        Ok(self.delete_with_response(params).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive.file".to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `delete()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn delete_with_response(
+        &self,
+        params: &FilesDeleteParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Files", "delete", async move {
+            let rel_path = format!(
+                "files/{fileId}",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] =
+                    &["https://www.googleapis.com/auth/drive.file"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
 
-        let full_uri = path + &url_params;
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Permanently deletes all of the user's trashed files.
-    pub async fn empty_trash(&mut self, params: &FilesEmptyTrashParams) -> Result<()> {
-        let rel_path = format!("files/trash",);
-        let path = self.format_path(rel_path.as_str());
+    pub async fn empty_trash(&self, params: &FilesEmptyTrashParams) -> Result<()> {
+        Ok(self.empty_trash_with_response(params).await?.body)
+    }
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive".to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+    /// Like `empty_trash()`, but also returns the status and headers of the response, e.g. its `ETag`.
+    pub async fn empty_trash_with_response(
+        &self,
+        params: &FilesEmptyTrashParams,
+    ) -> Result<ApiResponse<()>> {
+        traced("Files", "empty_trash", async move {
+            let rel_path = format!("files/trash",);
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
-        }
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] = &["https://www.googleapis.com/auth/drive"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
 
-        let full_uri = path + &url_params;
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
 
-        let opt_request: Option<&EmptyRequest> = None;
-        do_request(&self.client, &full_uri, &headers, "DELETE", opt_request).await
+            let opt_request: Option<&EmptyRequest> = None;
+            do_request_with_response(&self.client, url.as_str(), &headers, "DELETE", opt_request)
+                .await
+        })
+        .await
     }
 
     /// Exports a Google Doc to the requested MIME type and returns the exported content. Please note that the exported content is limited to 10MB.
     ///
     /// This method potentially downloads data. See documentation of `Download`.
     pub async fn export<'a>(
-        &'a mut self,
+        &'a self,
         params: &FilesExportParams,
-    ) -> Result<Download<'a, EmptyRequest, ()>> {
-        let rel_path = format!(
-            "files/{fileId}/export",
-            fileId = percent_encode(params.file_id.as_bytes(), NON_ALPHANUMERIC)
-        );
-        let path = self.format_path(rel_path.as_str());
+    ) -> Result<Download<'a, EmptyRequest, (), C>> {
+        traced("Files", "export", async move {
+            let rel_path = format!(
+                "files/{fileId}/export",
+                fileId = percent_encode(params.file_id.as_bytes(), PATH_SEGMENT)
+            );
+            let path = self.format_path(rel_path.as_str());
+            trace_path(&rel_path);
 
-        let mut headers = vec![];
-        let tok;
-        if self.scopes.is_empty() {
-            let scopes = &["https://www.googleapis.com/auth/drive.readonly".to_string()];
-            tok = self.authenticator.token(scopes).await?;
-        } else {
-            tok = self.authenticator.token(&self.scopes).await?;
-        }
-        headers.push((
-            hyper::header::AUTHORIZATION,
-            format!("Bearer {token}", token = tok.as_str()),
-        ));
+            let mut headers = self.extra_headers.clone();
+            let tok;
+            if self.scopes.is_empty() {
+                let scopes: &'static [&'static str] =
+                    &["https://www.googleapis.com/auth/drive.readonly"];
+                tok = self.authenticator.token(scopes).await?;
+            } else {
+                let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+                tok = self.authenticator.token(&scopes).await?;
+            }
+            headers.push((
+                hyper::header::AUTHORIZATION,
+                format!("Bearer {token}", token = tok.as_str()),
+            ));
+
+            let mut url = Url::parse(&path)?;
+            {
+                let mut query = url.query_pairs_mut();
+                params.append_to(&mut query);
+                if let Some(ref api_params) = &params.integration_test_params {
+                    api_params.append_to(&mut query);
+                }
+            }
+            self.standard_params.append_to(&mut url);
+            if let Some(ref fields) = self.default_fields {
+                if !url.query_pairs().any(|(name, _)| name == "fields") {
+                    url.query_pairs_mut().append_pair("fields", fields);
+                }
+            }
+            let opt_request: Option<&EmptyRequest> = None;
 
-        let mut url_params = format!("?{params}", params = params);
-        if let Some(ref api_params) = &params.integration_test_params {
-            url_params.push_str(&format!("{}", api_params));
+            do_download(
+                &self.client,
+                url.as_str(),
+                headers,
+                "GET".into(),
+                opt_request,
+            )
+            .await
+        })
+        .await
+    }
+}
+
+/// All services of the IntegrationTest API, sharing one HTTP client and one token source. Each
+/// accessor returns a new service; services are cheap to create and to clone.
+///
+/// ```ignore
+/// let hub = IntegrationTestHub::new(https_client, authenticator);
+/// let service = hub.files();
+/// ```
+#[derive(Clone)]
+pub struct IntegrationTestHub<C = TlsClient> {
+    apis: GoogleApis<C>,
+}
+
+impl<C: HttpClient> IntegrationTestHub<C> {
+    pub fn new<A: 'static + TokenSource + Send + Sync>(
+        client: C,
+        auth: A,
+    ) -> IntegrationTestHub<C> {
+        IntegrationTestHub {
+            apis: GoogleApis::new(client, auth),
         }
+    }
 
-        let full_uri = path + &url_params;
-        let opt_request: Option<&EmptyRequest> = None;
+    /// A hub using the client and token source of `apis`, e.g. to share them with other APIs.
+    pub fn from_apis(apis: GoogleApis<C>) -> IntegrationTestHub<C> {
+        IntegrationTestHub { apis }
+    }
+
+    /// The shared client and token source.
+    pub fn apis(&self) -> &GoogleApis<C> {
+        &self.apis
+    }
 
-        do_download(&self.client, &full_uri, headers, "GET".into(), opt_request).await
+    pub fn files(&self) -> FilesService<C> {
+        self.apis.service(FilesService::new)
     }
 }
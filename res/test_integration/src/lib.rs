@@ -115,7 +115,7 @@ mod tests {
         mockito::start();
         let cl = hyper_client();
         let auth = authenticator(cl.clone()).await;
-        let mut svc = files_service(cl, auth);
+        let svc = files_service(cl, auth);
 
         // Mandatory for token fetching.
         let _om = oauth_mock();
@@ -131,4 +131,129 @@ mod tests {
 
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_concurrent_requests() {
+        mockito::start();
+        let cl = hyper_client();
+        let auth = authenticator(cl.clone()).await;
+        let svc = files_service(cl, auth);
+
+        // Mandatory for token fetching.
+        let _om = oauth_mock();
+
+        let mock = mockito::mock("PUT", "/integrationAPI/files/file_id_to_copy/copy")
+            .with_status(200)
+            .expect(2)
+            .create();
+
+        let mut fsp = inttest::FilesCopyParams::default();
+        fsp.file_id = "file_id_to_copy".into();
+        let tasks: Vec<_> = (0..2)
+            .map(|_| {
+                let svc = svc.clone();
+                let fsp = fsp.clone();
+                tokio::spawn(async move { svc.copy(&fsp, &inttest::File::default()).await })
+            })
+            .collect();
+        for t in tasks {
+            t.await.unwrap().unwrap();
+        }
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_request_raw() {
+        mockito::start();
+        let cl = hyper_client();
+        let auth = authenticator(cl.clone()).await;
+        let svc = files_service(cl, auth);
+
+        // Mandatory for token fetching.
+        let _om = oauth_mock();
+
+        let mock = mockito::mock("GET", "/integrationAPI/files?pageSize=2")
+            .with_status(200)
+            .with_body(r#"{"files": [{"appProperties": {"a": "b"}}, {}], "nextPageToken": "tok"}"#)
+            .create();
+
+        let mut body = svc
+            .request_raw::<agac::EmptyRequest>("GET", "files", "pageSize=2", &[], None)
+            .await
+            .unwrap();
+        let mut dec = agac::JsonArrayDecoder::new("files");
+        let mut files: Vec<inttest::File> = vec![];
+        while let Some(chunk) = agac::StreamExt::next(&mut body).await {
+            files.extend(dec.feed::<inttest::File>(&chunk.unwrap()).unwrap());
+        }
+        let list: inttest::FileList = dec.finish().unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].app_properties.as_ref().unwrap()["a"], "b");
+        assert_eq!(list.next_page_token.as_deref(), Some("tok"));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_url_encoding() {
+        mockito::start();
+        let cl = hyper_client();
+        let auth = authenticator(cl.clone()).await;
+        let svc = files_service(cl, auth);
+
+        // Mandatory for token fetching.
+        let _om = oauth_mock();
+
+        let mock = mockito::mock(
+            "PUT",
+            "/integrationAPI/files/folder%2Fmy%20file@example.com/copy",
+        )
+        .match_query(mockito::Matcher::Regex(
+            "&fields=files\\(id,name\\)&quotaUser=user@example.com%2Btest$".into(),
+        ))
+        .with_status(200)
+        .create();
+
+        let mut fsp = inttest::FilesCopyParams::default();
+        fsp.file_id = "folder/my file@example.com".into();
+        let mut gp = inttest::IntegrationTestParams::default();
+        gp.fields = Some("files(id,name)".into());
+        gp.quota_user = Some("user@example.com+test".into());
+        fsp.integration_test_params = Some(gp);
+        let f = inttest::File::default();
+        let result = svc.copy(&fsp, &f).await.unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_error_response() {
+        mockito::start();
+        let cl = hyper_client();
+        let auth = authenticator(cl.clone()).await;
+        let svc = files_service(cl, auth);
+
+        // Mandatory for token fetching.
+        let _om = oauth_mock();
+
+        let mock = mockito::mock("PUT", "/integrationAPI/files/file_id_to_copy/copy")
+            .with_status(403)
+            .with_body(r#"{"error": {"code": 403, "message": "Rate Limit Exceeded", "errors": [{"domain": "usageLimits", "reason": "rateLimitExceeded", "message": "Rate Limit Exceeded"}]}}"#)
+            .create();
+
+        let mut fsp = inttest::FilesCopyParams::default();
+        fsp.file_id = "file_id_to_copy".into();
+        let f = inttest::File::default();
+        let err = svc.copy(&fsp, &f).await.unwrap_err();
+
+        assert!(agac::ApiError::has_reason(&err, "rateLimitExceeded"));
+        let resp = err
+            .downcast_ref::<agac::ApiError>()
+            .and_then(agac::ApiError::error_response)
+            .unwrap();
+        assert_eq!(resp.code, 403);
+        assert_eq!(resp.errors[0].domain.as_deref(), Some("usageLimits"));
+        mock.assert();
+    }
 }